@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Display;
@@ -29,7 +30,7 @@ use solana_client::rpc_response::RpcSimulateTransactionResult;
 use solana_sdk::address_lookup_table::instruction::{create_lookup_table, extend_lookup_table};
 use solana_sdk::address_lookup_table::state::AddressLookupTable;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::message::Message;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
@@ -51,37 +52,49 @@ use tokio::time::sleep;
 
 use crate::position_generator::gen_unhealthy_positions;
 use super_lendy::instruction::{
-    AlterCurator, AlterPool, AlterReserve, AlterTextureConfig, ApplyConfigProposal, Borrow,
-    ClaimCuratorPerformanceFees, ClaimReward, ClaimTexturePerformanceFees, ClosePosition,
-    CreateCurator, CreatePool, CreatePosition, CreateReserve, CreateTextureConfig, DeleteReserve,
-    DepositLiquidity, FlashBorrow, FlashRepay, InitRewardSupply, Liquidate, LockCollateral,
-    LpTokenMetadata, ProposeConfig, RefreshPosition, RefreshReserve, Repay, SetLpMetadata,
-    SetRewardRules, TransferTextureConfigOwnership, UnlockCollateral, Version, WithdrawLiquidity,
-    WithdrawReward, WriteOffBadDebt,
+    AcceptPositionOwnership, AlterCurator, AlterPool, AlterReserve, AlterTextureConfig,
+    ApplyConfigProposal, ApplyEmodeCategoryProposal, Borrow, BorrowFixed,
+    ClaimCuratorLiquidationFees, ClaimCuratorPerformanceFees, ClaimInsuranceFees, ClaimReward,
+    ClaimTextureLiquidationFees, ClaimTexturePerformanceFees, ClosePosition, CreateCurator,
+    CreatePool, CreatePosition, CreatePositionPda, CreateReserve, CreateTextureConfig,
+    DeleteReserve, DepositAndLockCollateral, DepositLiquidity, FlashBorrow, FlashRepay,
+    InitInsuranceFund, InitRewardSupply, Liquidate, LiquidateAndRedeem, LockCollateral,
+    LpTokenMetadata, ProposeConfig, ProposeEmodeCategory, RefreshPosition, RefreshReserve, Repay,
+    RepayFixed, RepayWithCollateral, ResizePosition, SetEmodeCategory, SetLpMetadata,
+    SetPositionDelegate, SetPositionEmode, SetRewardRules, SocializeLoss, SyncReserve,
+    TransferPositionOwnership, TransferTextureConfigOwnership, UnlockAndWithdrawLiquidity,
+    UnlockCollateral, Version, WithdrawLiquidity, WithdrawReward, WriteOffBadDebt,
 };
 use super_lendy::pda::{
-    find_collateral_supply, find_liquidity_supply, find_lp_token_mint, find_metadata,
-    find_program_authority, find_reward_supply, find_rewards_program_authority,
+    find_collateral_supply, find_insurance_fund, find_liquidity_supply, find_lp_token_mint,
+    find_metadata, find_position, find_program_authority, find_reward_supply,
+    find_rewards_program_authority,
 };
 use super_lendy::state::curator::{
     Curator, CuratorParams, CURATOR_LOGO_URL_MAX_LEN, CURATOR_NAME_MAX_LEN,
     CURATOR_WEBSITE_URL_MAX_LEN,
 };
-use super_lendy::state::pool::{Pool, PoolParams, CURRENCY_SYMBOL_MAX_LEN, POOL_NAME_MAX_LEN};
+use super_lendy::state::pool::{
+    EmodeCategory, Pool, PoolParams, CURRENCY_SYMBOL_MAX_LEN, EMODE_CATEGORY_NAME_MAX_LEN,
+    POOL_NAME_MAX_LEN,
+};
 use super_lendy::state::position::{
     Position, BORROW_MEMO_LEN, COLLATERAL_MEMO_LEN, POSITION_TYPE_CLASSIC,
     POSITION_TYPE_LONG_SHORT, POSITION_TYPE_LST_LEVERAGE,
 };
 use super_lendy::state::reserve::{
-    ConfigFields, ConfigProposal, LpExchangeRate, Reserve, ReserveConfig, MAX_REWARD_RULES,
-    RESERVE_MODE_BORROW_DISABLED, RESERVE_MODE_NORMAL, RESERVE_MODE_RETAIN_LIQUIDITY,
-    RESERVE_TYPE_NORMAL, RESERVE_TYPE_NOT_A_COLLATERAL, RESERVE_TYPE_PROTECTED_COLLATERAL,
-    REWARD_FOR_BORROW, REWARD_FOR_LIQUIDITY, REWARD_RULE_NAME_MAX_LEN,
+    ConfigFields, ConfigProposal, InterestRateModel, LpExchangeRate, Reserve, ReserveConfig,
+    MAX_REWARD_RULES, RESERVE_MODE_BORROW_DISABLED, RESERVE_MODE_NORMAL,
+    RESERVE_MODE_RETAIN_LIQUIDITY, RESERVE_TYPE_ISOLATED_COLLATERAL, RESERVE_TYPE_NORMAL,
+    RESERVE_TYPE_NOT_A_COLLATERAL, RESERVE_TYPE_PROTECTED_COLLATERAL, REWARD_FOR_BORROW,
+    REWARD_FOR_LIQUIDITY, REWARD_RULE_NAME_MAX_LEN, SURPLUS_TO_CURATOR, SURPLUS_TO_LPS,
 };
 use super_lendy::state::texture_cfg::{TextureConfig, TextureConfigParams};
 use super_lendy::state::{SCALE, WAD};
 use super_lendy::{MAX_AMOUNT, SUPER_LENDY_ID, TEXTURE_CONFIG_ID};
-use utils::loaders::{load_curators, load_pools, load_positions, load_reserves};
+use utils::loaders::{
+    load_curators, load_pools, load_positions, load_reserves, load_user_positions,
+};
 
 pub struct App {
     pub rpc: RpcClient,
@@ -616,6 +629,15 @@ impl App {
             "borrow_fee_rate_bps                : {}",
             cfg.borrow_fee_rate_bps
         );
+        println!(
+            "flash_loan_fee_share_bps           : {}",
+            cfg.flash_loan_fee_share_bps
+        );
+        println!(
+            "liquidation_fee_share_bps          : {}",
+            cfg.liquidation_fee_share_bps
+        );
+        println!("swap_program                       : {}", cfg.swap_program);
 
         println!(
             "market_price_feed_lock_sec         : {}",
@@ -661,6 +683,14 @@ impl App {
             "fees_lock_sec                      : {}",
             cfg.reserve_timelock.fees_lock_sec
         );
+        println!(
+            "max_total_borrow_lock_sec          : {}",
+            cfg.reserve_timelock.max_total_borrow_lock_sec
+        );
+        println!(
+            "borrow_weight_lock_sec             : {}",
+            cfg.reserve_timelock.borrow_weight_lock_sec
+        );
         println!("-------------------------------------");
     }
 
@@ -670,6 +700,9 @@ impl App {
         performance_fee_authority: Option<Pubkey>,
         performance_fee_rate_bps: Option<u16>,
         borrow_fee_rate_bps: Option<u16>,
+        flash_loan_fee_share_bps: Option<u16>,
+        liquidation_fee_share_bps: Option<u16>,
+        swap_program: Option<Pubkey>,
         market_price_feed_lock_sec: Option<u32>,
         irm_lock_sec: Option<u32>,
         liquidation_bonus_lock_sec: Option<u32>,
@@ -681,6 +714,8 @@ impl App {
         price_stale_threshold_lock_sec: Option<u32>,
         max_withdraw_utilization_lock_sec: Option<u32>,
         fees_lock_sec: Option<u32>,
+        max_total_borrow_lock_sec: Option<u32>,
+        borrow_weight_lock_sec: Option<u32>,
     ) {
         let cfg_data = self
             .rpc
@@ -692,6 +727,9 @@ impl App {
         let mut params = TextureConfigParams {
             borrow_fee_rate_bps: cfg.borrow_fee_rate_bps,
             performance_fee_rate_bps: cfg.performance_fee_rate_bps,
+            flash_loan_fee_share_bps: cfg.flash_loan_fee_share_bps,
+            liquidation_fee_share_bps: cfg.liquidation_fee_share_bps,
+            swap_program: cfg.swap_program,
             fees_authority: cfg.fees_authority,
             reserve_timelock: cfg.reserve_timelock,
         };
@@ -708,6 +746,18 @@ impl App {
             params.borrow_fee_rate_bps = borrow_fee_rate_bps;
         }
 
+        if let Some(flash_loan_fee_share_bps) = flash_loan_fee_share_bps {
+            params.flash_loan_fee_share_bps = flash_loan_fee_share_bps;
+        }
+
+        if let Some(liquidation_fee_share_bps) = liquidation_fee_share_bps {
+            params.liquidation_fee_share_bps = liquidation_fee_share_bps;
+        }
+
+        if let Some(swap_program) = swap_program {
+            params.swap_program = swap_program;
+        }
+
         if let Some(market_price_feed_lock_sec) = market_price_feed_lock_sec {
             params.reserve_timelock.market_price_feed_lock_sec = market_price_feed_lock_sec;
         }
@@ -755,6 +805,14 @@ impl App {
             params.reserve_timelock.fees_lock_sec = fees_lock_sec;
         }
 
+        if let Some(max_total_borrow_lock_sec) = max_total_borrow_lock_sec {
+            params.reserve_timelock.max_total_borrow_lock_sec = max_total_borrow_lock_sec;
+        }
+
+        if let Some(borrow_weight_lock_sec) = borrow_weight_lock_sec {
+            params.reserve_timelock.borrow_weight_lock_sec = borrow_weight_lock_sec;
+        }
+
         let ix = AlterTextureConfig {
             owner: self.authority.pubkey(),
             params,
@@ -993,6 +1051,22 @@ impl App {
             );
             println!("Curator                      : {}", pool.curator);
             println!("Visible                      : {}", pool.visible);
+            println!(
+                "Auto write-off of bad debt   : {}",
+                pool.auto_write_off_bad_debt
+            );
+            for (index, category) in pool.emode_categories.iter().enumerate() {
+                if category.is_configured() {
+                    println!(
+                        "E-mode category {}            : {}  max borrow LTV {}  partly unhealthy LTV {}  fully unhealthy LTV {}",
+                        index + 1,
+                        String::from_utf8_lossy(&category.name),
+                        category.max_borrow_ltv_bps,
+                        category.partly_unhealthy_ltv_bps,
+                        category.fully_unhealthy_ltv_bps
+                    );
+                }
+            }
             println!("-------------------------------------");
         }
 
@@ -1005,6 +1079,7 @@ impl App {
         name: Option<String>,
         market_price_currency_symbol: Option<String>,
         visible: Option<bool>,
+        auto_write_off_bad_debt: Option<bool>,
     ) {
         let pool_data = self
             .rpc
@@ -1017,6 +1092,7 @@ impl App {
             name: pool.name,
             market_price_currency_symbol: pool.market_price_currency_symbol,
             visible: pool.visible,
+            auto_write_off_bad_debt: pool.auto_write_off_bad_debt,
         };
 
         let curator_data = self
@@ -1049,6 +1125,10 @@ impl App {
             params.visible = u8::from(enabled);
         }
 
+        if let Some(enabled) = auto_write_off_bad_debt {
+            params.auto_write_off_bad_debt = u8::from(enabled);
+        }
+
         let ix = AlterPool {
             pool: pool_key,
             curator_pools_authority: curator.pools_authority,
@@ -1064,6 +1144,112 @@ impl App {
         println!("Altered pool: {}", pool_key);
     }
 
+    pub async fn set_emode_category(
+        &self,
+        pool_key: Pubkey,
+        category_id: u8,
+        name: String,
+        max_borrow_ltv_bps: u16,
+        partly_unhealthy_ltv_bps: u16,
+        fully_unhealthy_ltv_bps: u16,
+        propose: bool,
+    ) {
+        if name.len() > EMODE_CATEGORY_NAME_MAX_LEN {
+            println!(
+                "Name is too long. {} symbols max.",
+                EMODE_CATEGORY_NAME_MAX_LEN
+            );
+            return;
+        }
+
+        let pool_data = self
+            .rpc
+            .get_account_data(&pool_key)
+            .await
+            .expect("getting Pool account");
+        let pool = Pool::try_from_bytes(&pool_data).expect("unpacking Pool");
+
+        let curator_data = self
+            .rpc
+            .get_account_data(&pool.curator)
+            .await
+            .expect("getting Curator account");
+        let curator = Curator::try_from_bytes(&curator_data).expect("unpacking Curator");
+
+        let category = EmodeCategory {
+            name: str_to_array(&name),
+            max_borrow_ltv_bps,
+            partly_unhealthy_ltv_bps,
+            fully_unhealthy_ltv_bps,
+            _padding: Zeroable::zeroed(),
+        };
+
+        let ix = if propose {
+            ProposeEmodeCategory {
+                pool: pool_key,
+                curator_pools_authority: curator.pools_authority,
+                curator: pool.curator,
+                category_id,
+                category,
+            }
+            .into_instruction()
+        } else {
+            SetEmodeCategory {
+                pool: pool_key,
+                curator_pools_authority: curator.pools_authority,
+                curator: pool.curator,
+                category_id,
+                category,
+            }
+            .into_instruction()
+        };
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        if propose {
+            println!(
+                "Proposed e-mode category {} in pool: {}",
+                category_id, pool_key
+            );
+        } else {
+            println!("Set e-mode category {} in pool: {}", category_id, pool_key);
+        }
+    }
+
+    pub async fn apply_emode_category_proposal(&self, pool_key: Pubkey) {
+        let pool_data = self
+            .rpc
+            .get_account_data(&pool_key)
+            .await
+            .expect("getting Pool account");
+        let pool = Pool::try_from_bytes(&pool_data).expect("unpacking Pool");
+
+        let curator_data = self
+            .rpc
+            .get_account_data(&pool.curator)
+            .await
+            .expect("getting Curator account");
+        let curator = Curator::try_from_bytes(&curator_data).expect("unpacking Curator");
+
+        let ix = ApplyEmodeCategoryProposal {
+            pool: pool_key,
+            curator_pools_authority: curator.pools_authority,
+            curator: pool.curator,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Applied e-mode category {} proposal in pool: {}",
+            pool.emode_category_proposal.category_id, pool_key
+        );
+    }
+
     pub async fn token_program_by_mint(&self, liquidity_mint: &Pubkey) -> Pubkey {
         let mint_account = self
             .rpc
@@ -1164,6 +1350,14 @@ impl App {
                     "Reserve Type                     : BORROW ONLY (not accepted as collateral)"
                 );
             }
+            if reserve.reserve_type == RESERVE_TYPE_ISOLATED_COLLATERAL {
+                println!("Reserve Type                     : ISOLATED COLLATERAL");
+                println!(
+                    "Isolated debt                    : {} (ceiling {})",
+                    reserve.isolated_debt().expect("isolated_debt"),
+                    reserve.isolated_debt_ceiling
+                );
+            }
             if reserve.mode == RESERVE_MODE_NORMAL {
                 println!("Reserve Mode                     : Normal");
             }
@@ -1178,6 +1372,31 @@ impl App {
             } else {
                 println!("Flash loans                      : Enabled");
             }
+            if reserve.surplus_destination == SURPLUS_TO_LPS {
+                println!("Supply surplus goes to           : LPs");
+            }
+            if reserve.surplus_destination == SURPLUS_TO_CURATOR {
+                println!("Supply surplus goes to           : Curator");
+            }
+            if reserve.isolation_borrowable == 1 {
+                println!("Isolation borrowable             : Yes");
+            } else {
+                println!("Isolation borrowable             : No");
+            }
+            if reserve.config.emode_category != 0 {
+                println!(
+                    "E-mode category                  : {}",
+                    reserve.config.emode_category
+                );
+            }
+            if reserve.fixed_rate_bps != 0 {
+                println!(
+                    "Fixed-rate loans                 : {} bps, max term {} sec, grace {} sec",
+                    reserve.fixed_rate_bps,
+                    reserve.fixed_max_term_sec,
+                    reserve.fixed_grace_period_sec
+                );
+            }
             println!("Pool                             : {}", reserve.pool);
             println!(
                 "Liquidity Mint                   : {}",
@@ -1230,6 +1449,13 @@ impl App {
                     .to_lamports_round(reserve.liquidity.mint_decimals)
                     .unwrap_or_default()
             );
+            println!(
+                "Fixed-rate borrowed amount       : {}",
+                reserve
+                    .liquidity
+                    .fixed_borrowed_amount()
+                    .unwrap_or_default()
+            );
             println!(
                 "Total liquidity                  : {}",
                 reserve.liquidity.total_liquidity().unwrap_or_default()
@@ -1238,6 +1464,14 @@ impl App {
                 "Utilization rate                 : {}",
                 reserve.liquidity.utilization_rate().unwrap_or_default()
             );
+            println!(
+                "Borrow rate                      : {}",
+                reserve.liquidity.borrow_rate().unwrap_or_default()
+            );
+            println!(
+                "Supply rate                      : {}",
+                reserve.liquidity.supply_rate().unwrap_or_default()
+            );
             println!(
                 "Cumulative borrow rate           : {}",
                 reserve
@@ -1267,13 +1501,50 @@ impl App {
                     .texture_performance_fee()
                     .unwrap_or_default()
             );
+            println!(
+                "Accrued insurance fee            : {}",
+                reserve.liquidity.insurance_fee().unwrap_or_default()
+            );
+            println!(
+                "Accrued Curator's liq. fee (LP)  : {}",
+                reserve.curator_liquidation_fee
+            );
+            println!(
+                "Accrued Texture's liq. fee (LP)  : {}",
+                reserve.texture_liquidation_fee
+            );
+
+            println!("--------------------------- Rate snapshots --------------------------");
+            for snapshot in reserve.rate_snapshots() {
+                println!("{}", snapshot);
+            }
 
             println!("----------------------------- Settings ----------------------------");
             println!(
                 "Price feed                       : {}",
                 reserve.config.market_price_feed
             );
-            println!("Interest Rate model              : {}", reserve.config.irm);
+            match reserve.config.interest_rate_model() {
+                Ok(InterestRateModel::Curve(curve)) => {
+                    println!("Interest Rate model              : {}", curve)
+                }
+                Ok(InterestRateModel::Kinked(kinked_irm)) => {
+                    println!("Interest Rate model              : {}", kinked_irm)
+                }
+                Ok(InterestRateModel::Adaptive(adaptive_irm)) => {
+                    println!("Interest Rate model              : {}", adaptive_irm);
+                    println!(
+                        "Current rate at target           : {}",
+                        reserve
+                            .adaptive_rate_at_target(&adaptive_irm)
+                            .expect("adaptive_rate_at_target")
+                    )
+                }
+                Err(_) => println!(
+                    "Interest Rate model              : unknown kind {}",
+                    reserve.config.irm_kind
+                ),
+            }
             println!(
                 "Liquidation bonus (bps)          : {}",
                 reserve.config.liquidation_bonus_bps
@@ -1298,6 +1569,26 @@ impl App {
                 "Maximum total liquidity          : {}",
                 reserve.config.max_total_liquidity
             );
+            println!(
+                "Maximum total borrow             : {}",
+                reserve.config.max_total_borrow
+            );
+            println!(
+                "Borrow weight (bps)              : {}",
+                reserve.config.borrow_weight_bps
+            );
+            println!(
+                "Max liquidation bonus (bps)      : {}",
+                reserve.config.max_liquidation_bonus_bps
+            );
+            println!(
+                "Min borrow rate (bps)            : {}",
+                reserve.config.min_borrow_rate_bps
+            );
+            println!(
+                "Max borrow rate (bps)            : {}",
+                reserve.config.max_borrow_rate_bps
+            );
             println!(
                 "Partly unhealthy LTV (bps)       : {}",
                 reserve.config.partly_unhealthy_ltv_bps
@@ -1318,6 +1609,18 @@ impl App {
                 "Curator's perf. fee        (bps) : {}",
                 reserve.config.fees.curator_performance_fee_rate_bps
             );
+            println!(
+                "Flash loan fee             (bps) : {}",
+                reserve.config.fees.flash_loan_fee_bps
+            );
+            println!(
+                "Curator's liq. fee share   (bps) : {}",
+                reserve.config.fees.curator_liquidation_fee_share_bps
+            );
+            println!(
+                "Insurance fee rate         (bps) : {}",
+                reserve.config.fees.insurance_fee_rate_bps
+            );
 
             println!("------------------------- Reward rules ----------------------------");
             for (index, rule) in reserve.reward_rules.rules.iter().enumerate() {
@@ -1364,20 +1667,35 @@ impl App {
         &self,
         reserve_key: Pubkey,
         market_price_feed: Option<Pubkey>,
-        irm: Option<Pubkey>,
+        irm: Option<InterestRateModel>,
         liquidation_bonus_bps: Option<u16>,
         partly_unhealthy_ltv_bps: Option<u16>,
         partial_liquidation_amount_bps: Option<u16>,
         fully_unhealthy_ltv_bps: Option<u16>,
         curator_borrow_fee_bps: Option<u16>,
         curator_performance_fee_bps: Option<u16>,
+        flash_loan_fee_bps: Option<u16>,
+        curator_liquidation_fee_share_bps: Option<u16>,
+        insurance_fee_bps: Option<u16>,
         max_borrow_utilization_bps: Option<u16>,
         max_withdraw_utilization_bps: Option<u16>,
         max_total_liquidity: Option<u64>,
+        max_total_borrow: Option<u64>,
+        borrow_weight_bps: Option<u16>,
+        max_liquidation_bonus_bps: Option<u16>,
+        min_borrow_rate_bps: Option<u32>,
+        max_borrow_rate_bps: Option<u32>,
         max_borrow_ltv_bps: Option<u16>,
         price_stale_threshold_sec: Option<u32>,
         mode: Option<u8>,
         flash_loans_enabled: Option<bool>,
+        surplus_destination: Option<u8>,
+        isolation_borrowable: Option<bool>,
+        isolated_debt_ceiling: Option<u64>,
+        emode_category: Option<u8>,
+        fixed_rate_bps: Option<u32>,
+        fixed_max_term_sec: Option<u32>,
+        fixed_grace_period_sec: Option<u32>,
     ) {
         let reserve_data = self
             .rpc
@@ -1393,7 +1711,7 @@ impl App {
         }
 
         if let Some(irm) = irm {
-            new_config.irm = irm;
+            new_config.set_interest_rate_model(irm);
         }
 
         if let Some(liquidation_bonus_bps) = liquidation_bonus_bps {
@@ -1420,6 +1738,18 @@ impl App {
             new_config.fees.curator_performance_fee_rate_bps = curator_performance_fee_bps;
         }
 
+        if let Some(flash_loan_fee_bps) = flash_loan_fee_bps {
+            new_config.fees.flash_loan_fee_bps = flash_loan_fee_bps;
+        }
+
+        if let Some(curator_liquidation_fee_share_bps) = curator_liquidation_fee_share_bps {
+            new_config.fees.curator_liquidation_fee_share_bps = curator_liquidation_fee_share_bps;
+        }
+
+        if let Some(insurance_fee_bps) = insurance_fee_bps {
+            new_config.fees.insurance_fee_rate_bps = insurance_fee_bps;
+        }
+
         if let Some(max_utilization_bps) = max_borrow_utilization_bps {
             new_config.max_borrow_utilization_bps = max_utilization_bps;
         }
@@ -1432,6 +1762,26 @@ impl App {
             new_config.max_total_liquidity = max_total_liquidity;
         }
 
+        if let Some(max_total_borrow) = max_total_borrow {
+            new_config.max_total_borrow = max_total_borrow;
+        }
+
+        if let Some(borrow_weight_bps) = borrow_weight_bps {
+            new_config.borrow_weight_bps = borrow_weight_bps;
+        }
+
+        if let Some(max_liquidation_bonus_bps) = max_liquidation_bonus_bps {
+            new_config.max_liquidation_bonus_bps = max_liquidation_bonus_bps;
+        }
+
+        if let Some(min_borrow_rate_bps) = min_borrow_rate_bps {
+            new_config.min_borrow_rate_bps = min_borrow_rate_bps;
+        }
+
+        if let Some(max_borrow_rate_bps) = max_borrow_rate_bps {
+            new_config.max_borrow_rate_bps = max_borrow_rate_bps;
+        }
+
         if let Some(max_borrow_ltv_bps) = max_borrow_ltv_bps {
             new_config.max_borrow_ltv_bps = max_borrow_ltv_bps;
         }
@@ -1448,6 +1798,25 @@ impl App {
             0
         };
 
+        let surplus_destination = surplus_destination.unwrap_or(reserve.surplus_destination);
+
+        let isolation_borrowable = if let Some(isolation_borrowable) = isolation_borrowable {
+            isolation_borrowable as u8
+        } else {
+            reserve.isolation_borrowable
+        };
+
+        let isolated_debt_ceiling = isolated_debt_ceiling.unwrap_or(reserve.isolated_debt_ceiling);
+
+        if let Some(emode_category) = emode_category {
+            new_config.emode_category = emode_category;
+        }
+
+        let fixed_rate_bps = fixed_rate_bps.unwrap_or(reserve.fixed_rate_bps);
+        let fixed_max_term_sec = fixed_max_term_sec.unwrap_or(reserve.fixed_max_term_sec);
+        let fixed_grace_period_sec =
+            fixed_grace_period_sec.unwrap_or(reserve.fixed_grace_period_sec);
+
         let pool_data = self
             .rpc
             .get_account_data(&reserve.pool)
@@ -1464,6 +1833,12 @@ impl App {
             curator: pool.curator,
             mode,
             flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable,
+            isolated_debt_ceiling,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
         }
         .into_instruction();
 
@@ -1480,18 +1855,27 @@ impl App {
         reserve_key: Pubkey,
         index: u8,
         market_price_feed: Option<Pubkey>,
-        irm: Option<Pubkey>,
+        irm: Option<InterestRateModel>,
         liquidation_bonus_bps: Option<u16>,
         partly_unhealthy_ltv_bps: Option<u16>,
         partial_liquidation_amount_bps: Option<u16>,
         fully_unhealthy_ltv_bps: Option<u16>,
         curator_borrow_fee_bps: Option<u16>,
         curator_performance_fee_bps: Option<u16>,
+        flash_loan_fee_bps: Option<u16>,
+        curator_liquidation_fee_share_bps: Option<u16>,
+        insurance_fee_bps: Option<u16>,
         max_borrow_utilization_bps: Option<u16>,
         max_withdraw_utilization_bps: Option<u16>,
         max_total_liquidity: Option<u64>,
+        max_total_borrow: Option<u64>,
+        borrow_weight_bps: Option<u16>,
+        max_liquidation_bonus_bps: Option<u16>,
+        min_borrow_rate_bps: Option<u32>,
+        max_borrow_rate_bps: Option<u32>,
         max_borrow_ltv_bps: Option<u16>,
         price_stale_threshold_sec: Option<u32>,
+        emode_category: Option<u8>,
     ) {
         let reserve_data = self
             .rpc
@@ -1513,7 +1897,7 @@ impl App {
 
         if let Some(irm) = irm {
             change_map.insert(ConfigFields::IRM);
-            new_config.irm = irm;
+            new_config.set_interest_rate_model(irm);
         }
 
         if let Some(liquidation_bonus_bps) = liquidation_bonus_bps {
@@ -1546,6 +1930,21 @@ impl App {
             new_config.fees.curator_performance_fee_rate_bps = curator_performance_fee_bps;
         }
 
+        if let Some(flash_loan_fee_bps) = flash_loan_fee_bps {
+            change_map.insert(ConfigFields::FLASH_LOAN_FEE_RATE);
+            new_config.fees.flash_loan_fee_bps = flash_loan_fee_bps;
+        }
+
+        if let Some(curator_liquidation_fee_share_bps) = curator_liquidation_fee_share_bps {
+            change_map.insert(ConfigFields::CURATOR_LIQUIDATION_FEE_SHARE);
+            new_config.fees.curator_liquidation_fee_share_bps = curator_liquidation_fee_share_bps;
+        }
+
+        if let Some(insurance_fee_bps) = insurance_fee_bps {
+            change_map.insert(ConfigFields::INSURANCE_FEE_RATE);
+            new_config.fees.insurance_fee_rate_bps = insurance_fee_bps;
+        }
+
         if let Some(max_utilization_bps) = max_borrow_utilization_bps {
             change_map.insert(ConfigFields::MAX_BORROW_UTILIZATION);
             new_config.max_borrow_utilization_bps = max_utilization_bps;
@@ -1561,6 +1960,30 @@ impl App {
             new_config.max_total_liquidity = max_total_liquidity;
         }
 
+        if let Some(max_total_borrow) = max_total_borrow {
+            change_map.insert(ConfigFields::MAX_TOTAL_BORROW);
+            new_config.max_total_borrow = max_total_borrow;
+        }
+
+        if let Some(borrow_weight_bps) = borrow_weight_bps {
+            change_map.insert(ConfigFields::BORROW_WEIGHT);
+            new_config.borrow_weight_bps = borrow_weight_bps;
+        }
+
+        if let Some(max_liquidation_bonus_bps) = max_liquidation_bonus_bps {
+            change_map.insert(ConfigFields::MAX_LIQUIDATION_BONUS);
+            new_config.max_liquidation_bonus_bps = max_liquidation_bonus_bps;
+        }
+
+        // Both bounds are changed together. Missing one is kept as is.
+        if min_borrow_rate_bps.is_some() || max_borrow_rate_bps.is_some() {
+            change_map.insert(ConfigFields::BORROW_RATE_BOUNDS);
+            new_config.min_borrow_rate_bps =
+                min_borrow_rate_bps.unwrap_or(reserve.config.min_borrow_rate_bps);
+            new_config.max_borrow_rate_bps =
+                max_borrow_rate_bps.unwrap_or(reserve.config.max_borrow_rate_bps);
+        }
+
         if let Some(max_borrow_ltv_bps) = max_borrow_ltv_bps {
             change_map.insert(ConfigFields::MAX_BORROW_LTV);
             new_config.max_borrow_ltv_bps = max_borrow_ltv_bps;
@@ -1571,6 +1994,11 @@ impl App {
             new_config.price_stale_threshold_sec = price_stale_threshold_sec;
         }
 
+        if let Some(emode_category) = emode_category {
+            change_map.insert(ConfigFields::EMODE_CATEGORY);
+            new_config.emode_category = emode_category;
+        }
+
         let pool_data = self
             .rpc
             .get_account_data(&reserve.pool)
@@ -1679,7 +2107,7 @@ impl App {
         let refresh = RefreshReserve {
             reserve: reserve_key,
             market_price_feed: reserve.config.market_price_feed,
-            irm: reserve.config.irm,
+            irm: reserve.config.irm_account(),
         }
         .into_instruction();
 
@@ -2086,7 +2514,7 @@ impl App {
         let ix = RefreshReserve {
             reserve: reserve_key,
             market_price_feed: reserve.config.market_price_feed,
-            irm: reserve.config.irm,
+            irm: reserve.config.irm_account(),
         }
         .into_instruction();
 
@@ -2099,6 +2527,62 @@ impl App {
         println!("Refreshed reserve: {}", reserve_key);
     }
 
+    pub async fn sync_reserve(&self, reserve_key: Pubkey) {
+        let ix = SyncReserve {
+            reserve: reserve_key,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Synced reserve: {}", reserve_key);
+    }
+
+    pub async fn check_reserves(&self, pool: Option<Pubkey>) {
+        let reserves = load_reserves(&self.rpc).await.expect("loading reserves");
+
+        let mut discrepancies = 0;
+        for (key, reserve) in reserves.iter().filter(|&(_, reserve)| {
+            if let Some(pool) = pool {
+                reserve.pool == pool
+            } else {
+                true
+            }
+        }) {
+            let liquidity_supply = find_liquidity_supply(key).0;
+            let supply_amount = self
+                .rpc
+                .get_token_account_balance(&liquidity_supply)
+                .await
+                .expect("getting liquidity supply balance")
+                .amount
+                .parse::<u64>()
+                .expect("parsing liquidity supply balance");
+            let available_amount = reserve.liquidity.available_amount;
+
+            let status = match supply_amount.cmp(&available_amount) {
+                Ordering::Equal => "OK".to_string(),
+                Ordering::Greater => {
+                    discrepancies += 1;
+                    format!("SURPLUS {}", supply_amount - available_amount)
+                }
+                Ordering::Less => {
+                    discrepancies += 1;
+                    format!("DEFICIT {}", available_amount - supply_amount)
+                }
+            };
+
+            println!(
+                "Reserve {}  mint {}  supply balance {}  available amount {}  {}",
+                key, reserve.liquidity.mint, supply_amount, available_amount, status
+            );
+        }
+
+        println!("Reserves with discrepancies: {}", discrepancies);
+    }
+
     pub async fn delete_reserve(&self, reserve_key: Pubkey) {
         let reserve_data = self
             .rpc
@@ -2222,7 +2706,7 @@ impl App {
         let refresh = RefreshReserve {
             reserve: reserve_key,
             market_price_feed: reserve.config.market_price_feed,
-            irm: reserve.config.irm,
+            irm: reserve.config.irm_account(),
         }
         .into_instruction();
 
@@ -2296,7 +2780,7 @@ impl App {
         let refresh = RefreshReserve {
             reserve: reserve_key,
             market_price_feed: reserve.config.market_price_feed,
-            irm: reserve.config.irm,
+            irm: reserve.config.irm_account(),
         }
         .into_instruction();
 
@@ -2320,7 +2804,33 @@ impl App {
         println!("Withdrawn liquidity from reserve: {}", reserve_key);
     }
 
-    pub async fn create_position(&self, pool: Pubkey, long_short: bool) {
+    pub async fn create_position(&self, pool: Pubkey, long_short: bool, index: Option<u8>) {
+        let position_type = if long_short {
+            POSITION_TYPE_LONG_SHORT
+        } else {
+            POSITION_TYPE_CLASSIC
+        };
+
+        if let Some(index) = index {
+            let position = find_position(&pool, &self.authority.pubkey(), index).0;
+
+            let ix = CreatePositionPda {
+                position,
+                pool,
+                owner: self.authority.pubkey(),
+                position_type,
+                index,
+            }
+            .into_instruction();
+
+            self.send_transaction_by(vec![ix], &[&self.authority])
+                .await
+                .expect("Sending TX");
+
+            println!("Created user position: {}", position);
+            return;
+        }
+
         let new_keypair = Keypair::new();
 
         let create_ix = system_instruction::create_account(
@@ -2338,11 +2848,7 @@ impl App {
             position: new_keypair.pubkey(),
             pool,
             owner: self.authority.pubkey(),
-            position_type: if long_short {
-                POSITION_TYPE_LONG_SHORT
-            } else {
-                POSITION_TYPE_CLASSIC
-            },
+            position_type,
         }
         .into_instruction();
 
@@ -2385,7 +2891,7 @@ impl App {
                     }
                 }) {
                     println!("Repay for reserve {}", borrow_reserve);
-                    self.repay(key, borrow_reserve, None).await
+                    self.repay(key, borrow_reserve, None, false).await
                 }
                 for deposit_reserve in position.collateral.iter().filter_map(|collateral| {
                     if collateral.deposited_amount != 0 {
@@ -2456,13 +2962,157 @@ impl App {
         println!("Refreshed");
     }
 
-    pub async fn list_positions(
+    pub async fn set_position_emode(&self, position_key: Pubkey, category_id: u8) {
+        let position_data = self
+            .rpc
+            .get_account_data(&position_key)
+            .await
+            .expect("getting Position account");
+        let position = Position::unpack(&position_data).expect("unpacking Position");
+
+        let ix = SetPositionEmode {
+            position: position_key,
+            pool: position.pool,
+            owner: self.authority.pubkey(),
+            category_id,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Position {} e-mode category set to {}",
+            position_key, category_id
+        );
+    }
+
+    pub async fn resize_position(
         &self,
-        position_addr: Option<Pubkey>,
-        owner: Option<Pubkey>,
-        pool: Option<Pubkey>,
+        position_key: Pubkey,
+        extra_deposits: u8,
+        extra_borrows: u8,
+        extra_rewards: u8,
+        extra_fixed_loans: u8,
+    ) {
+        let ix = ResizePosition {
+            position: position_key,
+            owner: self.authority.pubkey(),
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Position {} resized. Account size {}",
+            position_key,
+            Position::account_size(
+                extra_deposits,
+                extra_borrows,
+                extra_rewards,
+                extra_fixed_loans
+            )
+        );
+    }
+
+    pub async fn set_position_delegate(
+        &self,
+        position_key: Pubkey,
+        delegate: Pubkey,
+        permissions: u8,
+        borrow_allowance: u64,
     ) {
-        let positions = load_positions(&self.rpc).await.expect("loading positions");
+        let ix = SetPositionDelegate {
+            position: position_key,
+            owner: self.authority.pubkey(),
+            delegate,
+            permissions,
+            borrow_allowance,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        if permissions == 0 {
+            println!(
+                "Delegate {} removed from position {}",
+                delegate, position_key
+            );
+        } else {
+            println!(
+                "Delegate {} set for position {} with permissions {:#06b}",
+                delegate, position_key, permissions
+            );
+        }
+    }
+
+    pub async fn transfer_position_ownership(&self, position_key: Pubkey, new_owner: Pubkey) {
+        let ix = TransferPositionOwnership {
+            position: position_key,
+            owner: self.authority.pubkey(),
+            new_owner,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Position {} ownership proposed to {}. New owner should accept it.",
+            position_key, new_owner
+        );
+    }
+
+    pub async fn accept_position_ownership(&self, position_key: Pubkey) {
+        let (mut ixs, reserves) = self.refresh_position_ix(position_key).await;
+
+        self.update_prices(&reserves).await;
+
+        ixs.push(
+            AcceptPositionOwnership {
+                position: position_key,
+                new_owner: self.authority.pubkey(),
+            }
+            .into_instruction(),
+        );
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Position {} is now owned by {}",
+            position_key,
+            self.authority.pubkey()
+        );
+    }
+
+    pub async fn list_positions(
+        &self,
+        position_addr: Option<Pubkey>,
+        owner: Option<Pubkey>,
+        pool: Option<Pubkey>,
+        derived: bool,
+    ) {
+        let positions = if derived {
+            let pool = pool.expect("pool is required to find derived positions");
+            let owner = owner.unwrap_or(self.authority.pubkey());
+            load_user_positions(&self.rpc, &pool, &owner)
+                .await
+                .expect("loading positions")
+        } else {
+            load_positions(&self.rpc).await.expect("loading positions")
+        };
         let filtered_positions = positions
             .into_iter()
             .filter(|&(position_key, _)| {
@@ -2503,7 +3153,26 @@ impl App {
                 }
             );
             println!("Owner                : {}", position.owner);
+            if position.pending_owner != Pubkey::default() {
+                println!("Pending owner        : {}", position.pending_owner);
+            }
             println!("Pool                 : {}", position.pool);
+            println!("E-mode category      : {}", position.emode_category);
+            println!(
+                "Extra records        : deposits {}  borrows {}  rewards {}  fixed loans {}",
+                position.extra_deposits,
+                position.extra_borrows,
+                position.extra_rewards,
+                position.extra_fixed_loans
+            );
+            for delegate in position.delegates.iter().filter(|d| d.is_active()) {
+                println!(
+                    "Delegate             : {}  permissions {:#06b}  borrow allowance {}",
+                    delegate.delegate,
+                    delegate.permissions,
+                    delegate.borrow_allowance().unwrap()
+                );
+            }
             println!("Last update (slot)   : {}", position.last_update.slot);
             println!(
                 "Collateral value     : {}",
@@ -2513,6 +3182,10 @@ impl App {
                 "Borrowed value       : {}",
                 position.borrowed_value().unwrap_or_default()
             );
+            println!(
+                "Weighted borrowed    : {}",
+                position.weighted_borrowed_value().unwrap_or_default()
+            );
             println!(
                 "Allowed borrow value : {}",
                 position.allowed_borrow_value().unwrap_or_default()
@@ -2522,6 +3195,21 @@ impl App {
                 position.ltv().unwrap_or_default()
             );
 
+            // Positions are loaded without extension records. Fetch them for extended Position.
+            let position_data = if position.extra_deposits != 0
+                || position.extra_borrows != 0
+                || position.extra_rewards != 0
+                || position.extra_fixed_loans != 0
+            {
+                self.rpc
+                    .get_account_data(key)
+                    .await
+                    .expect("getting Position account")
+            } else {
+                bytemuck::bytes_of(position).to_vec()
+            };
+            let unpacked_position = Position::unpack(&position_data).expect("unpacking Position");
+
             let mut total_borrowed_value = Decimal::ZERO;
             let mut total_locked_collateral_value = Decimal::ZERO;
 
@@ -2530,7 +3218,7 @@ impl App {
             } else {
                 println!("-------------------- Collateral ---------------------");
             }
-            for (index, collateral) in position.collateral.iter().enumerate() {
+            for (index, collateral) in unpacked_position.collateral().enumerate() {
                 if collateral.deposited_amount > 0 {
                     print!(
                         "{}: reserve {}  amount {}  value {}  memo {}",
@@ -2557,7 +3245,7 @@ impl App {
             } else {
                 println!("-------------------- Borrowings ---------------------");
             }
-            for (index, borrow) in position.borrows.iter().enumerate() {
+            for (index, borrow) in unpacked_position.borrows().enumerate() {
                 if borrow.borrowed_amount().unwrap_or_default() > Decimal::ZERO {
                     print!(
                         "{}: reserve {}  amount {}  value {}  memo {}",
@@ -2579,8 +3267,29 @@ impl App {
                         .expect("total_borrowed_value");
                 }
             }
+            if position.extra_fixed_loans != 0 {
+                println!("------------------ Fixed-rate loans -----------------");
+            }
+            for (index, loan) in unpacked_position.fixed_loans.iter().enumerate() {
+                if loan.is_active() {
+                    println!(
+                        "{}: reserve {}  amount {}  value {}  rate (bps) {}  maturity {}  memo {}",
+                        index,
+                        loan.liquidity.borrow_reserve,
+                        loan.liquidity.borrowed_amount().unwrap_or_default(),
+                        loan.liquidity.market_value().unwrap_or_default(),
+                        loan.rate_bps,
+                        loan.maturity_timestamp,
+                        String::from_utf8_lossy(&loan.liquidity.memo)
+                    );
+
+                    total_borrowed_value = total_borrowed_value
+                        .checked_add(loan.liquidity.market_value().unwrap_or_default())
+                        .expect("total_borrowed_value");
+                }
+            }
             println!("--------------------- Rewards ----------------------");
-            for (index, reward) in position.rewards.rewards.iter().enumerate() {
+            for (index, reward) in unpacked_position.rewards().enumerate() {
                 if reward.accrued_amount > 0 {
                     println!(
                         "{}: amount {}   reward mint {}",
@@ -2625,8 +3334,7 @@ impl App {
             .get_account_data(&position)
             .await
             .expect("getting position account");
-        let unpacked_position =
-            Position::try_from_bytes(&position_data).expect("unpacking position");
+        let unpacked_position = Position::unpack(&position_data).expect("unpacking position");
 
         let memo = if let Some(memo) = memo {
             let mut memo_bytes_zero_ended = [0; COLLATERAL_MEMO_LEN];
@@ -2654,7 +3362,7 @@ impl App {
             let refresh_reserve = RefreshReserve {
                 reserve: reserve_key,
                 market_price_feed: reserve.config.market_price_feed,
-                irm: reserve.config.irm,
+                irm: reserve.config.irm_account(),
             }
             .into_instruction();
 
@@ -2712,7 +3420,7 @@ impl App {
             let refresh_reserve = RefreshReserve {
                 reserve: reserve_key,
                 market_price_feed: reserve.config.market_price_feed,
-                irm: reserve.config.irm,
+                irm: reserve.config.irm_account(),
             }
             .into_instruction();
 
@@ -2746,6 +3454,146 @@ impl App {
         println!("Unlocked {} LPs from reserve: {}", amount, reserve_key);
     }
 
+    pub async fn deposit_and_lock(
+        &self,
+        position: Pubkey,
+        reserve_key: Pubkey,
+        amount: u64,
+        memo: Option<String>,
+    ) {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let liquidity_token_program = self.token_program_by_mint(&reserve.liquidity.mint).await;
+        let source_liquidity_wallet = get_associated_token_address_with_program_id(
+            &self.authority.pubkey(),
+            &reserve.liquidity.mint,
+            &liquidity_token_program,
+        );
+
+        let position_data = self
+            .rpc
+            .get_account_data(&position)
+            .await
+            .expect("getting position account");
+        let unpacked_position = Position::unpack(&position_data).expect("unpacking position");
+
+        let memo = if let Some(memo) = memo {
+            let mut memo_bytes_zero_ended = [0; COLLATERAL_MEMO_LEN];
+            let memo_bytes = memo.as_bytes();
+            memo_bytes_zero_ended[..memo_bytes.len()].copy_from_slice(memo_bytes);
+            memo_bytes_zero_ended
+        } else {
+            match unpacked_position.find_collateral(reserve_key) {
+                Ok((collateral, _index)) => collateral.memo,
+                Err(_) => {
+                    // collateral not found. Just provide zeroed memo.
+                    [0; COLLATERAL_MEMO_LEN]
+                }
+            }
+        };
+
+        let refresh_position_info = self.refresh_position_ix(position).await;
+        let mut ixs = refresh_position_info.0;
+
+        if !refresh_position_info
+            .1
+            .iter()
+            .any(|key| key == &reserve_key)
+        {
+            let refresh_reserve = RefreshReserve {
+                reserve: reserve_key,
+                market_price_feed: reserve.config.market_price_feed,
+                irm: reserve.config.irm_account(),
+            }
+            .into_instruction();
+
+            ixs.push(refresh_reserve);
+        }
+
+        let mut reserves_to_updates_prices_for = refresh_position_info.1;
+        reserves_to_updates_prices_for.push(reserve_key);
+
+        let ix = DepositAndLockCollateral {
+            position,
+            reserve: reserve_key,
+            source_liquidity_wallet,
+            liquidity_mint: reserve.liquidity.mint,
+            owner: self.authority.pubkey(),
+            liquidity_token_program,
+            amount,
+            memo,
+        }
+        .into_instruction();
+
+        ixs.push(ix);
+
+        let version = Version { no_error: true }.into_instruction();
+        ixs.push(version);
+
+        self.update_prices(&reserves_to_updates_prices_for).await;
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Deposited and locked {} tokens in reserve: {}",
+            amount, reserve_key
+        );
+    }
+
+    pub async fn unlock_and_withdraw(
+        &self,
+        position: Pubkey,
+        reserve_key: Pubkey,
+        amount: Option<u64>,
+    ) {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let liquidity_token_program = self.token_program_by_mint(&reserve.liquidity.mint).await;
+        let destination_liquidity_wallet = get_associated_token_address_with_program_id(
+            &self.authority.pubkey(),
+            &reserve.liquidity.mint,
+            &liquidity_token_program,
+        );
+
+        let refresh_position_info = self.refresh_position_ix(position).await;
+        let mut ixs = refresh_position_info.0;
+
+        let ix = UnlockAndWithdrawLiquidity {
+            position,
+            reserve: reserve_key,
+            destination_liquidity_wallet,
+            liquidity_mint: reserve.liquidity.mint,
+            owner: self.authority.pubkey(),
+            liquidity_token_program,
+            lp_amount: amount.unwrap_or(MAX_AMOUNT),
+        }
+        .into_instruction();
+
+        ixs.push(ix);
+
+        let version = Version { no_error: true }.into_instruction();
+        ixs.push(version);
+
+        self.update_prices(&refresh_position_info.1).await;
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Unlocked and withdrawn from reserve: {}", reserve_key);
+    }
     pub async fn refresh_reserves_ix(&self, reserves: &[Pubkey]) -> Vec<Instruction> {
         let mut ixs = Vec::new();
 
@@ -2761,7 +3609,7 @@ impl App {
             let refresh_reserve = RefreshReserve {
                 reserve: *reserve,
                 market_price_feed: unpacked_reserve.config.market_price_feed,
-                irm: unpacked_reserve.config.irm,
+                irm: unpacked_reserve.config.irm_account(),
             }
             .into_instruction();
 
@@ -2781,11 +3629,10 @@ impl App {
             .get_account_data(&position_key)
             .await
             .expect("getting Position account");
-        let position = Position::try_from_bytes(&position_data).expect("unpacking Position");
+        let position = Position::unpack(&position_data).expect("unpacking Position");
 
         let deposits_reserves: Vec<Pubkey> = position
-            .collateral
-            .iter()
+            .collateral()
             .filter_map(|dep| {
                 if dep.deposited_amount > 0 {
                     Some(dep.deposit_reserve)
@@ -2796,8 +3643,8 @@ impl App {
             .collect();
 
         let borrows_reserves: Vec<Pubkey> = position
-            .borrows
-            .iter()
+            .borrows()
+            .chain(position.fixed_loans.iter().map(|loan| &loan.liquidity))
             .filter_map(|bor| {
                 if bor.borrowed_amount().unwrap_or_default() > Decimal::ZERO {
                     Some(bor.borrow_reserve)
@@ -2807,13 +3654,19 @@ impl App {
             })
             .collect();
 
-        let ix = RefreshPosition {
+        let mut ix = RefreshPosition {
             position: position_key,
             deposits: deposits_reserves.clone(),
             borrows: borrows_reserves.clone(),
         }
         .into_instruction();
 
+        if position.emode_category != 0 {
+            // Position in e-mode passes its Pool to get category LTVs
+            ix.accounts
+                .push(AccountMeta::new_readonly(position.pool, false));
+        }
+
         let mut refresh_ixs = self.refresh_reserves_ix(&deposits_reserves).await;
         let refresh_borrows = self.refresh_reserves_ix(&borrows_reserves).await;
 
@@ -2826,6 +3679,8 @@ impl App {
         (refresh_ixs, all_reserves)
     }
 
+    /// `fixed_terms` - term (in seconds) and max rate (in basis points) of fixed-rate loan
+    #[allow(clippy::too_many_arguments)]
     pub async fn borrow(
         &self,
         position: Pubkey,
@@ -2833,6 +3688,7 @@ impl App {
         amount: u64,
         slippage: u64,
         memo: Option<String>,
+        fixed_terms: Option<(u32, u32)>,
     ) {
         let reserve_data = self
             .rpc
@@ -2937,14 +3793,16 @@ impl App {
             .get_account_data(&position)
             .await
             .expect("getting position account");
-        let unpacked_position =
-            Position::try_from_bytes(&position_data).expect("unpacking position");
+        let unpacked_position = Position::unpack(&position_data).expect("unpacking position");
 
         let memo = if let Some(memo) = memo {
             let mut memo_bytes_zero_ended = [0; BORROW_MEMO_LEN];
             let memo_bytes = memo.as_bytes();
             memo_bytes_zero_ended[..memo_bytes.len()].copy_from_slice(memo_bytes);
             memo_bytes_zero_ended
+        } else if fixed_terms.is_some() {
+            // Every fixed-rate loan is a new record
+            [0; BORROW_MEMO_LEN]
         } else {
             match unpacked_position.find_borrowed_liquidity(reserve_key) {
                 Ok((borrow, _index)) => borrow.memo,
@@ -2955,22 +3813,45 @@ impl App {
             }
         };
 
-        let borrow_ix = Borrow {
-            position,
-            reserve: reserve_key,
-            pool: reserve.pool,
-            destination_liquidity_wallet,
-            curator_fee_receiver,
-            texture_fee_receiver,
-            borrower: self.authority.pubkey(),
-            amount,
-            slippage_limit: slippage,
-            curator: pool.curator,
-            memo,
-            token_program: self.token_program_by_mint(&reserve.liquidity.mint).await,
-            liquidity_mint: reserve.liquidity.mint,
-        }
-        .into_instruction();
+        let token_program = self.token_program_by_mint(&reserve.liquidity.mint).await;
+        let mut borrow_ix = if let Some((term_sec, max_rate_bps)) = fixed_terms {
+            BorrowFixed {
+                position,
+                reserve: reserve_key,
+                pool: reserve.pool,
+                destination_liquidity_wallet,
+                curator_fee_receiver,
+                texture_fee_receiver,
+                borrower: self.authority.pubkey(),
+                amount,
+                slippage_limit: slippage,
+                term_sec,
+                max_rate_bps,
+                curator: pool.curator,
+                memo,
+                token_program,
+                liquidity_mint: reserve.liquidity.mint,
+            }
+            .into_instruction()
+        } else {
+            Borrow {
+                position,
+                reserve: reserve_key,
+                pool: reserve.pool,
+                destination_liquidity_wallet,
+                curator_fee_receiver,
+                texture_fee_receiver,
+                borrower: self.authority.pubkey(),
+                amount,
+                slippage_limit: slippage,
+                curator: pool.curator,
+                memo,
+                token_program,
+                liquidity_mint: reserve.liquidity.mint,
+            }
+            .into_instruction()
+        };
+        push_isolated_collateral(&mut borrow_ix, &unpacked_position);
 
         let refresh_position_info = self.refresh_position_ix(position).await;
         let mut ixs = refresh_position_info.0;
@@ -2990,7 +3871,14 @@ impl App {
         println!("Borrowed");
     }
 
-    pub async fn repay(&self, position: Pubkey, reserve_key: Pubkey, amount: Option<u64>) {
+    /// `fixed` - repay fixed-rate loan instead of variable rate borrow
+    pub async fn repay(
+        &self,
+        position: Pubkey,
+        reserve_key: Pubkey,
+        amount: Option<u64>,
+        fixed: bool,
+    ) {
         let reserve_data = self
             .rpc
             .get_account_data(&reserve_key)
@@ -3004,14 +3892,65 @@ impl App {
             &self.token_program_by_mint(&reserve.liquidity.mint).await,
         );
 
-        let repay_ix = Repay {
+        let position_data = self
+            .rpc
+            .get_account_data(&position)
+            .await
+            .expect("getting position account");
+        let unpacked_position = Position::unpack(&position_data).expect("unpacking position");
+
+        let token_program = self.token_program_by_mint(&reserve.liquidity.mint).await;
+        let mut repay_ix = if fixed {
+            RepayFixed {
+                position,
+                reserve: reserve_key,
+                source_liquidity_wallet,
+                user_authority: self.authority.pubkey(),
+                token_program,
+                amount: amount.unwrap_or(MAX_AMOUNT),
+                liquidity_mint: reserve.liquidity.mint,
+            }
+            .into_instruction()
+        } else {
+            Repay {
+                position,
+                reserve: reserve_key,
+                source_liquidity_wallet,
+                user_authority: self.authority.pubkey(),
+                token_program,
+                amount: amount.unwrap_or(MAX_AMOUNT),
+                liquidity_mint: reserve.liquidity.mint,
+            }
+            .into_instruction()
+        };
+        push_isolated_collateral(&mut repay_ix, &unpacked_position);
+
+        let refresh_position_info = self.refresh_position_ix(position).await;
+        let mut ixs = refresh_position_info.0;
+        ixs.push(repay_ix);
+
+        ixs.push(Version { no_error: true }.into_instruction());
+
+        self.update_prices(&refresh_position_info.1).await;
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Repaid");
+    }
+
+    pub async fn repay_with_collateral(
+        &self,
+        position: Pubkey,
+        reserve_key: Pubkey,
+        amount: Option<u64>,
+    ) {
+        let repay_ix = RepayWithCollateral {
             position,
             reserve: reserve_key,
-            source_liquidity_wallet,
-            user_authority: self.authority.pubkey(),
-            token_program: self.token_program_by_mint(&reserve.liquidity.mint).await,
+            owner: self.authority.pubkey(),
             amount: amount.unwrap_or(MAX_AMOUNT),
-            liquidity_mint: reserve.liquidity.mint,
         }
         .into_instruction();
 
@@ -3027,7 +3966,7 @@ impl App {
             .await
             .expect("Sending TX");
 
-        println!("Repaid");
+        println!("Repaid with collateral");
     }
 
     pub async fn claim_curator_perf_fee(&self, reserve_key: Pubkey) {
@@ -3150,12 +4089,210 @@ impl App {
         println!("Claimed");
     }
 
+    pub async fn init_insurance_fund(&self, pool_key: Pubkey, liquidity_mint: Pubkey) {
+        let pool_data = self
+            .rpc
+            .get_account_data(&pool_key)
+            .await
+            .expect("getting Pool account");
+        let pool = Pool::try_from_bytes(&pool_data).expect("unpacking Pool");
+
+        let ix = InitInsuranceFund {
+            liquidity_mint,
+            pool: pool_key,
+            curator_pools_authority: self.authority.pubkey(),
+            curator: pool.curator,
+            token_program: self.token_program_by_mint(&liquidity_mint).await,
+        }
+        .into_instruction();
+
+        self.send_transaction_by(vec![ix], &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!(
+            "Created insurance fund: {}",
+            find_insurance_fund(&pool_key, &liquidity_mint).0
+        );
+    }
+
+    pub async fn claim_insurance_fee(&self, reserve_key: Pubkey) {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let claim_ix = ClaimInsuranceFees {
+            reserve: reserve_key,
+            pool: reserve.pool,
+            liquidity_mint: reserve.liquidity.mint,
+            token_program: self.token_program_by_mint(&reserve.liquidity.mint).await,
+        }
+        .into_instruction();
+
+        let mut ixs = self.refresh_reserves_ix(&[reserve_key]).await;
+
+        ixs.push(claim_ix);
+
+        self.update_prices(&[reserve_key]).await;
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Claimed");
+    }
+
+    pub async fn insurance_funds(&self, pool: Option<Pubkey>) {
+        let reserves = load_reserves(&self.rpc).await.expect("loading reserves");
+
+        let mut funds = reserves
+            .iter()
+            .filter(|&(_, reserve)| pool.map_or(true, |pool| reserve.pool == pool))
+            .map(|(_, reserve)| (reserve.pool, reserve.liquidity.mint))
+            .collect::<Vec<_>>();
+        funds.sort();
+        funds.dedup();
+
+        for (pool_key, mint) in funds {
+            let insurance_fund = find_insurance_fund(&pool_key, &mint).0;
+            let balance = if self
+                .account_exists(&insurance_fund)
+                .await
+                .expect("check insurance_fund existance")
+            {
+                self.rpc
+                    .get_token_account_balance(&insurance_fund)
+                    .await
+                    .expect("getting insurance fund balance")
+                    .amount
+            } else {
+                "NOT INITIALIZED".to_string()
+            };
+
+            println!(
+                "Pool {}  mint {}  insurance fund {}  balance {}",
+                pool_key, mint, insurance_fund, balance
+            );
+        }
+    }
+
+    pub async fn claim_curator_liquidation_fee(&self, reserve_key: Pubkey) {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let pool_data = self
+            .rpc
+            .get_account_data(&reserve.pool)
+            .await
+            .expect("getting Pool account");
+        let pool = Pool::try_from_bytes(&pool_data).expect("unpacking Pool");
+
+        let curator_data = self
+            .rpc
+            .get_account_data(&pool.curator)
+            .await
+            .expect("getting Curator account");
+        let curator = Curator::try_from_bytes(&curator_data).expect("unpacking Curator");
+
+        let lp_mint = find_lp_token_mint(&reserve_key).0;
+        let fee_receiver = get_associated_token_address(&curator.fees_authority, &lp_mint);
+
+        let mut ixs = vec![];
+
+        if !self
+            .account_exists(&fee_receiver)
+            .await
+            .expect("check fee_receiver existance")
+        {
+            println!("Creating LP wallet {} for fees", fee_receiver);
+            ixs.push(create_associated_token_account(
+                &self.authority.pubkey(),
+                &curator.fees_authority,
+                &lp_mint,
+                &spl_token::id(),
+            ));
+        }
+
+        ixs.push(
+            ClaimCuratorLiquidationFees {
+                reserve: reserve_key,
+                pool: reserve.pool,
+                curator: pool.curator,
+                fee_receiver,
+            }
+            .into_instruction(),
+        );
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Claimed {} LP tokens", reserve.curator_liquidation_fee);
+    }
+
+    pub async fn claim_texture_liquidation_fee(&self, reserve_key: Pubkey) {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let cfg_data = self
+            .rpc
+            .get_account_data(&TEXTURE_CONFIG_ID)
+            .await
+            .expect("getting Global Config account");
+        let cfg = TextureConfig::try_from_bytes(&cfg_data).expect("unpacking Global Config");
+
+        let lp_mint = find_lp_token_mint(&reserve_key).0;
+        let fee_receiver = get_associated_token_address(&cfg.fees_authority, &lp_mint);
+
+        let mut ixs = vec![];
+
+        if !self
+            .account_exists(&fee_receiver)
+            .await
+            .expect("check fee_receiver existance")
+        {
+            println!("Creating LP wallet {} for fees", fee_receiver);
+            ixs.push(create_associated_token_account(
+                &self.authority.pubkey(),
+                &cfg.fees_authority,
+                &lp_mint,
+                &spl_token::id(),
+            ));
+        }
+
+        ixs.push(
+            ClaimTextureLiquidationFees {
+                reserve: reserve_key,
+                fee_receiver,
+            }
+            .into_instruction(),
+        );
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Claimed {} LP tokens", reserve.texture_liquidation_fee);
+    }
+
     pub async fn liquidate(
         &self,
         position: Pubkey,
         principal_reserve_key: Pubkey,
         collateral_reserve_key: Pubkey,
         principal_amount: Option<u64>,
+        redeem: bool,
     ) {
         let principal_reserve_data = self
             .rpc
@@ -3212,20 +4349,79 @@ impl App {
                 .expect("Sending create ATA TX");
         }
 
-        let liquidate_ix = Liquidate {
-            repayment_source_wallet,
-            destination_lp_wallet,
-            principal_reserve: principal_reserve_key,
-            collateral_reserve: collateral_reserve_key,
-            position,
-            liquidator: self.authority.pubkey(),
-            principal_reserve_liquidity_mint: principal_reserve.liquidity.mint,
-            principal_token_program: self
-                .token_program_by_mint(&principal_reserve.liquidity.mint)
-                .await,
-            liquidity_amount: principal_amount.unwrap_or(MAX_AMOUNT),
-        }
-        .into_instruction();
+        let liquidate_ix = if redeem {
+            let collateral_reserve_data = self
+                .rpc
+                .get_account_data(&collateral_reserve_key)
+                .await
+                .expect("getting collateral_reserve account");
+            let collateral_reserve = Reserve::try_from_bytes(&collateral_reserve_data)
+                .expect("unpacking collateral Reserve");
+            let collateral_token_program = self
+                .token_program_by_mint(&collateral_reserve.liquidity.mint)
+                .await;
+
+            let destination_liquidity_wallet = get_associated_token_address_with_program_id(
+                &self.authority.pubkey(),
+                &collateral_reserve.liquidity.mint,
+                &collateral_token_program,
+            );
+
+            if !self
+                .account_exists(&destination_liquidity_wallet)
+                .await
+                .expect("check destination_liquidity_wallet existance")
+            {
+                println!(
+                    "Creating wallet {} for {} tokens",
+                    destination_liquidity_wallet, collateral_reserve.liquidity.mint
+                );
+
+                let ix = create_associated_token_account(
+                    &self.authority.pubkey(),
+                    &self.authority.pubkey(),
+                    &collateral_reserve.liquidity.mint,
+                    &collateral_token_program,
+                );
+
+                self.send_transaction_by(vec![ix], &vec![&self.authority])
+                    .await
+                    .expect("Sending create ATA TX");
+            }
+
+            LiquidateAndRedeem {
+                repayment_source_wallet,
+                destination_lp_wallet,
+                destination_liquidity_wallet,
+                principal_reserve: principal_reserve_key,
+                collateral_reserve: collateral_reserve_key,
+                position,
+                liquidator: self.authority.pubkey(),
+                principal_reserve_liquidity_mint: principal_reserve.liquidity.mint,
+                collateral_reserve_liquidity_mint: collateral_reserve.liquidity.mint,
+                principal_token_program: self
+                    .token_program_by_mint(&principal_reserve.liquidity.mint)
+                    .await,
+                collateral_liquidity_token_program: collateral_token_program,
+                liquidity_amount: principal_amount.unwrap_or(MAX_AMOUNT),
+            }
+            .into_instruction()
+        } else {
+            Liquidate {
+                repayment_source_wallet,
+                destination_lp_wallet,
+                principal_reserve: principal_reserve_key,
+                collateral_reserve: collateral_reserve_key,
+                position,
+                liquidator: self.authority.pubkey(),
+                principal_reserve_liquidity_mint: principal_reserve.liquidity.mint,
+                principal_token_program: self
+                    .token_program_by_mint(&principal_reserve.liquidity.mint)
+                    .await,
+                liquidity_amount: principal_amount.unwrap_or(MAX_AMOUNT),
+            }
+            .into_instruction()
+        };
 
         let refresh_position_info = self.refresh_position_ix(position).await;
         let mut ixs = refresh_position_info.0;
@@ -3251,7 +4447,7 @@ impl App {
             .get_account_data(&position_key)
             .await
             .expect("getting position account");
-        let position = Position::try_from_bytes(&position_data).expect("unpacking Position");
+        let position = Position::unpack(&position_data).expect("unpacking Position");
 
         let pool_data = self
             .rpc
@@ -3260,15 +4456,25 @@ impl App {
             .expect("getting Pool account");
         let pool = Pool::try_from_bytes(&pool_data).expect("unpacking Pool");
 
-        let write_off_ix = WriteOffBadDebt {
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let mut write_off_ix = WriteOffBadDebt {
             pool: position.pool,
             reserve: reserve_key,
             position: position_key,
             curator_pools_authority: self.authority.pubkey(),
             curator: pool.curator,
+            liquidity_mint: reserve.liquidity.mint,
+            token_program: self.token_program_by_mint(&reserve.liquidity.mint).await,
             amount: amount.unwrap_or(MAX_AMOUNT),
         }
         .into_instruction();
+        push_isolated_collateral(&mut write_off_ix, &position);
 
         let refresh_position_info = self.refresh_position_ix(position_key).await;
         let mut ixs = refresh_position_info.0;
@@ -3286,6 +4492,44 @@ impl App {
         println!("Bad debt written off");
     }
 
+    pub async fn socialize_loss(&self, position_key: Pubkey, reserve_key: Pubkey) {
+        let position_data = self
+            .rpc
+            .get_account_data(&position_key)
+            .await
+            .expect("getting position account");
+        let position = Position::unpack(&position_data).expect("unpacking Position");
+
+        let reserve_data = self
+            .rpc
+            .get_account_data(&reserve_key)
+            .await
+            .expect("getting Reserve account");
+        let reserve = Reserve::try_from_bytes(&reserve_data).expect("unpacking Reserve");
+
+        let mut socialize_loss_ix = SocializeLoss {
+            pool: position.pool,
+            reserve: reserve_key,
+            position: position_key,
+            liquidity_mint: reserve.liquidity.mint,
+            token_program: self.token_program_by_mint(&reserve.liquidity.mint).await,
+        }
+        .into_instruction();
+        push_isolated_collateral(&mut socialize_loss_ix, &position);
+
+        let refresh_position_info = self.refresh_position_ix(position_key).await;
+        let mut ixs = refresh_position_info.0;
+        ixs.push(socialize_loss_ix);
+
+        self.update_prices(&refresh_position_info.1).await;
+
+        self.send_transaction_by(ixs, &[&self.authority])
+            .await
+            .expect("Sending TX");
+
+        println!("Loss socialized");
+    }
+
     pub async fn set_lp_metadata(
         &self,
         reserve_key: Pubkey,
@@ -3902,6 +5146,15 @@ pub struct LutCfgEntry {
     pub lut: Pubkey,
 }
 
+/// Isolated position passes its isolated collateral Reserve as the last account of Borrow, Repay
+/// and WriteOffBadDebt.
+fn push_isolated_collateral(ix: &mut Instruction, position: &Position) {
+    if position.is_isolated() {
+        ix.accounts
+            .push(AccountMeta::new(position.isolated_collateral, false));
+    }
+}
+
 pub fn read_lut_config(path: &str) -> Result<Vec<LutCfgEntry>> {
     let text = std::fs::read_to_string(path).expect("Can't read LUT config provided");
     let config: Vec<LutCfgEntry> = serde_json::from_str(&text)?;
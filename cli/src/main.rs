@@ -14,8 +14,13 @@ use sup::app::{read_lut_config, App};
 use super_lendy::pda::find_lp_token_mint;
 use super_lendy::state::curator::{CuratorParams, CURATOR_NAME_MAX_LEN};
 use super_lendy::state::pool::{PoolParams, CURRENCY_SYMBOL_MAX_LEN, POOL_NAME_MAX_LEN};
+use super_lendy::state::position::{
+    DELEGATE_PERMISSION_BORROW, DELEGATE_PERMISSION_LOCK, DELEGATE_PERMISSION_REPAY,
+    DELEGATE_PERMISSION_UNLOCK,
+};
 use super_lendy::state::reserve::{
-    ReserveConfig, ReserveFeesConfig, RESERVE_TYPE_NORMAL, RESERVE_TYPE_NOT_A_COLLATERAL,
+    AdaptiveIrm, InterestRateModel, KinkedIrm, ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE,
+    RESERVE_TYPE_ISOLATED_COLLATERAL, RESERVE_TYPE_NORMAL, RESERVE_TYPE_NOT_A_COLLATERAL,
     RESERVE_TYPE_PROTECTED_COLLATERAL,
 };
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
@@ -67,6 +72,15 @@ enum Command {
         /// Texture loan origination fees for all pools/reserves
         #[structopt(long)]
         borrow_fee_rate_bps: u16,
+        /// Texture share of flash loan fees for all pools/reserves
+        #[structopt(long, default_value = "0")]
+        flash_loan_fee_share_bps: u16,
+        /// Texture share of liquidation bonuses for all pools/reserves
+        #[structopt(long, default_value = "0")]
+        liquidation_fee_share_bps: u16,
+        /// Swap program allowed for swap-based deleveraging. Disabled when not set.
+        #[structopt(long)]
+        swap_program: Option<Pubkey>,
         #[structopt(long)]
         fees_authority: Pubkey,
         #[structopt(long)]
@@ -91,6 +105,10 @@ enum Command {
         max_withdraw_utilization_lock_sec: u32,
         #[structopt(long)]
         fees_lock_sec: u32,
+        #[structopt(long, default_value = "0")]
+        max_total_borrow_lock_sec: u32,
+        #[structopt(long, default_value = "0")]
+        borrow_weight_lock_sec: u32,
     },
     /// Change global Texture config. Only config owner allowed to do this.
     AlterTextureConfig {
@@ -100,6 +118,15 @@ enum Command {
         /// Texture loan origination fees for all pools/reserves
         #[structopt(long)]
         borrow_fee_rate_bps: Option<u16>,
+        /// Texture share of flash loan fees for all pools/reserves
+        #[structopt(long)]
+        flash_loan_fee_share_bps: Option<u16>,
+        /// Texture share of liquidation bonuses for all pools/reserves
+        #[structopt(long)]
+        liquidation_fee_share_bps: Option<u16>,
+        /// Swap program allowed for swap-based deleveraging
+        #[structopt(long)]
+        swap_program: Option<Pubkey>,
         #[structopt(long)]
         performance_fee_authority: Option<Pubkey>,
         #[structopt(long)]
@@ -124,6 +151,10 @@ enum Command {
         max_withdraw_utilization_lock_sec: Option<u32>,
         #[structopt(long)]
         fees_lock_sec: Option<u32>,
+        #[structopt(long)]
+        max_total_borrow_lock_sec: Option<u32>,
+        #[structopt(long)]
+        borrow_weight_lock_sec: Option<u32>,
     },
     /// Transfer Texture Global Config ownership to new authority. This command must be executed
     /// with authority of current Config owner. New authority also must sign thus it should be
@@ -209,6 +240,37 @@ enum Command {
 
         #[structopt(long)]
         visible: Option<bool>,
+        /// Allow anyone to write off bad debt of Positions without collateral (SocializeLoss IX)
+        #[structopt(long)]
+        auto_write_off_bad_debt: Option<bool>,
+    },
+    /// Configures e-mode category in the Pool. Must be called with curator.pools_authority authority.
+    /// Zero LTVs disable the category. Changes raising time locked LTVs must be proposed.
+    SetEmodeCategory {
+        /// Address of the Pool to configure
+        #[structopt(long)]
+        pool: Pubkey,
+        /// Category ID in range [1, 8]
+        #[structopt(long)]
+        category_id: u8,
+        /// Category name e.g. "SOL-correlated"
+        #[structopt(long)]
+        name: String,
+        #[structopt(long)]
+        max_borrow_ltv_bps: u16,
+        #[structopt(long)]
+        partly_unhealthy_ltv_bps: u16,
+        #[structopt(long)]
+        fully_unhealthy_ltv_bps: u16,
+        /// Propose time locked change instead of applying it right away
+        #[structopt(long)]
+        propose: bool,
+    },
+    /// Applies pending e-mode category proposal once its time lock expired
+    ApplyEmodeCategoryProposal {
+        /// Address of the Pool with the proposal
+        #[structopt(long)]
+        pool: Pubkey,
     },
     /// Print all configured pairs
     Pools {
@@ -235,6 +297,10 @@ enum Command {
         /// This reserve can only be used to Borrow but NOT as collateral.
         #[structopt(long)]
         not_a_collateral: bool,
+        /// Collateral from this reserve can only be used in isolation: position can't hold any
+        /// other collateral and can only borrow from isolation borrowable reserves.
+        #[structopt(long)]
+        isolated_collateral: bool,
         /// Mint address of the token which will be used as Liquidity in created reserve
         #[structopt(long)]
         liquidity_mint: Pubkey,
@@ -242,8 +308,17 @@ enum Command {
         #[structopt(long)]
         market_price_feed: Pubkey,
         /// Interest rate model (IRM) account
-        #[structopt(long)]
-        irm: Pubkey,
+        #[structopt(long, required_unless_one = &["kinked-irm", "adaptive-irm"])]
+        irm: Option<Pubkey>,
+        /// Built-in kinked interest rate model to use instead of IRM account. Format:
+        /// <base_rate_bps>,<slope1_bps>,<optimal_utilization_bps>,<slope2_bps>
+        #[structopt(long, conflicts_with = "irm")]
+        kinked_irm: Option<KinkedIrmArg>,
+        /// Built-in adaptive interest rate model to use instead of IRM account. Format:
+        /// <target_utilization_bps>,<initial_rate_at_target_bps>,<min_rate_at_target_bps>,
+        /// <max_rate_at_target_bps>,<adjustment_speed_bps>,<curve_steepness_bps>
+        #[structopt(long, conflicts_with_all = &["irm", "kinked-irm"])]
+        adaptive_irm: Option<AdaptiveIrmArg>,
         /// Bonus a liquidator gets when repaying part of an unhealthy position, as a basis points - bps (0.01%)
         #[structopt(long)]
         liquidation_bonus_bps: u16,
@@ -260,6 +335,15 @@ enum Command {
         curator_borrow_fee_bps: u16,
         #[structopt(long)]
         curator_performance_fee_bps: u16,
+        /// Fee charged on flash loans, as a basis points - bps (0.01%)
+        #[structopt(long, default_value = "0")]
+        flash_loan_fee_bps: u16,
+        /// Curator's share of liquidation bonuses, as a basis points - bps (0.01%)
+        #[structopt(long, default_value = "0")]
+        curator_liquidation_fee_share_bps: u16,
+        /// Part of pool yield which goes to Pool's insurance fund, as a basis points - bps (0.01%)
+        #[structopt(long, default_value = "0")]
+        insurance_fee_bps: u16,
         /// Max LTV (of some position) till which this reserve will give to borrow when used as collateral.
         #[structopt(long)]
         max_borrow_ltv_bps: u16,
@@ -273,6 +357,22 @@ enum Command {
         /// Max liquidity Reserve can accept and hold
         #[structopt(long)]
         max_total_liquidity: u64,
+        /// Max total amount which can be borrowed from the Reserve. 0 - no limit.
+        #[structopt(long, default_value = "0")]
+        max_total_borrow: u64,
+        /// Risk weight of liquidity borrowed from the Reserve. 10000 - borrowed value is taken as is.
+        #[structopt(long, default_value = "10000")]
+        borrow_weight_bps: u16,
+        /// Liquidation bonus for positions at fully unhealthy LTV. Bonus grows from
+        /// liquidation_bonus_bps to this value along with position's LTV. 0 - flat bonus.
+        #[structopt(long, default_value = "0")]
+        max_liquidation_bonus_bps: u16,
+        /// Floor of the borrow rate given by IRM, as a basis points.
+        #[structopt(long, default_value = "0")]
+        min_borrow_rate_bps: u32,
+        /// Cap of the borrow rate given by IRM, as a basis points. 0 - no cap.
+        #[structopt(long, default_value = "0")]
+        max_borrow_rate_bps: u32,
         /// Maximum market price age (in seconds) to be accepted by the contract.
         #[structopt(long, default_value = "1")]
         price_stale_threshold_sec: u32,
@@ -298,6 +398,15 @@ enum Command {
         /// Interest rate model (IRM) account
         #[structopt(long)]
         irm: Option<Pubkey>,
+        /// Built-in kinked interest rate model to use instead of IRM account. Format:
+        /// <base_rate_bps>,<slope1_bps>,<optimal_utilization_bps>,<slope2_bps>
+        #[structopt(long, conflicts_with = "irm")]
+        kinked_irm: Option<KinkedIrmArg>,
+        /// Built-in adaptive interest rate model to use instead of IRM account. Format:
+        /// <target_utilization_bps>,<initial_rate_at_target_bps>,<min_rate_at_target_bps>,
+        /// <max_rate_at_target_bps>,<adjustment_speed_bps>,<curve_steepness_bps>
+        #[structopt(long, conflicts_with_all = &["irm", "kinked-irm"])]
+        adaptive_irm: Option<AdaptiveIrmArg>,
         /// Bonus a liquidator gets when repaying part of an unhealthy position, as a basis points - bps (0.01%)
         #[structopt(long)]
         liquidation_bonus_bps: Option<u16>,
@@ -314,6 +423,15 @@ enum Command {
         curator_borrow_fee_bps: Option<u16>,
         #[structopt(long)]
         curator_performance_fee_bps: Option<u16>,
+        /// Fee charged on flash loans, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        flash_loan_fee_bps: Option<u16>,
+        /// Curator's share of liquidation bonuses, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        curator_liquidation_fee_share_bps: Option<u16>,
+        /// Part of pool yield which goes to Pool's insurance fund, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        insurance_fee_bps: Option<u16>,
         /// Max utilization after which this pool stops giving borrows. Though it is possible to withdraw
         /// liquidity from it making utilization even bigger.
         #[structopt(long)]
@@ -324,6 +442,22 @@ enum Command {
         /// Max liquidity Reserve can accept and hold
         #[structopt(long)]
         max_total_liquidity: Option<u64>,
+        /// Max total amount which can be borrowed from the Reserve. 0 - no limit.
+        #[structopt(long)]
+        max_total_borrow: Option<u64>,
+        /// Risk weight of liquidity borrowed from the Reserve. 10000 - borrowed value is taken as is.
+        #[structopt(long)]
+        borrow_weight_bps: Option<u16>,
+        /// Liquidation bonus for positions at fully unhealthy LTV. Bonus grows from
+        /// liquidation_bonus_bps to this value along with position's LTV. 0 - flat bonus.
+        #[structopt(long)]
+        max_liquidation_bonus_bps: Option<u16>,
+        /// Floor of the borrow rate given by IRM, as a basis points.
+        #[structopt(long)]
+        min_borrow_rate_bps: Option<u32>,
+        /// Cap of the borrow rate given by IRM, as a basis points. 0 - no cap.
+        #[structopt(long)]
+        max_borrow_rate_bps: Option<u32>,
         /// Max LTV (of some position) till which this reserve will give to borrow when used as collateral.
         #[structopt(long)]
         max_borrow_ltv_bps: Option<u16>,
@@ -338,6 +472,31 @@ enum Command {
         /// Enables/disables flash loans for that Reserve
         #[structopt(long)]
         flash_loans_enabled: Option<bool>,
+        /// What SyncReserve does with tokens found in liquidity supply on top of available amount
+        /// 0 - SURPLUS_TO_LPS - add to Reserve's liquidity
+        /// 1 - SURPLUS_TO_CURATOR - accrue as Curator's performance fee
+        #[structopt(long)]
+        surplus_destination: Option<u8>,
+        /// Allows/disallows positions with isolated collateral to borrow from that Reserve
+        #[structopt(long)]
+        isolation_borrowable: Option<bool>,
+        /// Max value (in whole units of quote currency) of debt backed by isolated collateral
+        /// Reserve. 0 - no limit.
+        #[structopt(long)]
+        isolated_debt_ceiling: Option<u64>,
+        /// E-mode category (configured in the Pool) the Reserve belongs to. 0 - no category.
+        #[structopt(long)]
+        emode_category: Option<u8>,
+        /// Fixed borrow rate (in basis points per year) offered by BorrowFixed. 0 - fixed-rate
+        /// loans are disabled.
+        #[structopt(long)]
+        fixed_rate_bps: Option<u32>,
+        /// Max term (in seconds) of a fixed-rate loan
+        #[structopt(long)]
+        fixed_max_term_sec: Option<u32>,
+        /// Seconds after maturity before an unpaid fixed-rate loan becomes liquidatable
+        #[structopt(long)]
+        fixed_grace_period_sec: Option<u32>,
     },
     DeleteReserve {
         /// Reserve to delete
@@ -349,6 +508,17 @@ enum Command {
         #[structopt(long)]
         reserve: Pubkey,
     },
+    SyncReserve {
+        /// Reserve to reconcile with its liquidity supply balance
+        #[structopt(long)]
+        reserve: Pubkey,
+    },
+    /// Compare liquidity supply balance with available amount for every Reserve
+    CheckReserves {
+        /// Pool to check reserves of
+        #[structopt(long)]
+        pool: Option<Pubkey>,
+    },
     ProposeConfig {
         /// Reserve to change
         #[structopt(long)]
@@ -362,6 +532,15 @@ enum Command {
         /// Interest rate model (IRM) account
         #[structopt(long)]
         irm: Option<Pubkey>,
+        /// Built-in kinked interest rate model to use instead of IRM account. Format:
+        /// <base_rate_bps>,<slope1_bps>,<optimal_utilization_bps>,<slope2_bps>
+        #[structopt(long, conflicts_with = "irm")]
+        kinked_irm: Option<KinkedIrmArg>,
+        /// Built-in adaptive interest rate model to use instead of IRM account. Format:
+        /// <target_utilization_bps>,<initial_rate_at_target_bps>,<min_rate_at_target_bps>,
+        /// <max_rate_at_target_bps>,<adjustment_speed_bps>,<curve_steepness_bps>
+        #[structopt(long, conflicts_with_all = &["irm", "kinked-irm"])]
+        adaptive_irm: Option<AdaptiveIrmArg>,
         /// Bonus a liquidator gets when repaying part of an unhealthy position, as a basis points - bps (0.01%)
         #[structopt(long)]
         liquidation_bonus_bps: Option<u16>,
@@ -378,6 +557,15 @@ enum Command {
         curator_borrow_fee_bps: Option<u16>,
         #[structopt(long)]
         curator_performance_fee_bps: Option<u16>,
+        /// Fee charged on flash loans, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        flash_loan_fee_bps: Option<u16>,
+        /// Curator's share of liquidation bonuses, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        curator_liquidation_fee_share_bps: Option<u16>,
+        /// Part of pool yield which goes to Pool's insurance fund, as a basis points - bps (0.01%)
+        #[structopt(long)]
+        insurance_fee_bps: Option<u16>,
         /// Max utilization after which this pool stops giving borrows. Though it is possible to withdraw
         /// liquidity from it making utilization even bigger.
         #[structopt(long)]
@@ -388,12 +576,31 @@ enum Command {
         /// Max liquidity Reserve can accept and hold
         #[structopt(long)]
         max_total_liquidity: Option<u64>,
+        /// Max total amount which can be borrowed from the Reserve. 0 - no limit.
+        #[structopt(long)]
+        max_total_borrow: Option<u64>,
+        /// Risk weight of liquidity borrowed from the Reserve. 10000 - borrowed value is taken as is.
+        #[structopt(long)]
+        borrow_weight_bps: Option<u16>,
+        /// Liquidation bonus for positions at fully unhealthy LTV. Bonus grows from
+        /// liquidation_bonus_bps to this value along with position's LTV. 0 - flat bonus.
+        #[structopt(long)]
+        max_liquidation_bonus_bps: Option<u16>,
+        /// Floor of the borrow rate given by IRM, as a basis points.
+        #[structopt(long)]
+        min_borrow_rate_bps: Option<u32>,
+        /// Cap of the borrow rate given by IRM, as a basis points. 0 - no cap.
+        #[structopt(long)]
+        max_borrow_rate_bps: Option<u32>,
         /// Max LTV (of some position) till which this reserve will give to borrow when used as collateral.
         #[structopt(long)]
         max_borrow_ltv_bps: Option<u16>,
         /// Maximum market price age (in seconds) to be accepted by the contract.
         #[structopt(long)]
         price_stale_threshold_sec: Option<u32>,
+        /// E-mode category (configured in the Pool) the Reserve belongs to. 0 - no category.
+        #[structopt(long)]
+        emode_category: Option<u8>,
     },
     /// Clear (delete, deactivate) config change proposal
     ClearConfigProposal {
@@ -438,6 +645,9 @@ enum Command {
         /// Create position of `long-short` type - change UI interpretation of it
         #[structopt(long)]
         long_short: bool,
+        /// Create position at PDA address derived from pool, owner and this index
+        #[structopt(long)]
+        index: Option<u8>,
     },
     ClosePosition {
         /// Position to close
@@ -454,6 +664,73 @@ enum Command {
         #[structopt(long)]
         position: Pubkey,
     },
+    /// Opt position in to e-mode category. Position must not have borrowings.
+    SetPositionEmode {
+        /// Position to change
+        #[structopt(long)]
+        position: Pubkey,
+        /// E-mode category configured in the Pool. 0 - opt out of e-mode.
+        #[structopt(long)]
+        category_id: u8,
+    },
+    /// Set number of extra deposit, borrow, reward and fixed-rate loan records position can hold
+    ResizePosition {
+        /// Position to resize
+        #[structopt(long)]
+        position: Pubkey,
+        /// Extra collateral records on top of the default limit
+        #[structopt(long, default_value = "0")]
+        extra_deposits: u8,
+        /// Extra borrow records on top of the default limit
+        #[structopt(long, default_value = "0")]
+        extra_borrows: u8,
+        /// Extra reward records on top of the default limit
+        #[structopt(long, default_value = "0")]
+        extra_rewards: u8,
+        /// Fixed-rate loan records. Position can't have fixed-rate loans without them.
+        #[structopt(long, default_value = "0")]
+        extra_fixed_loans: u8,
+    },
+    /// Add, update or remove (when no permissions given) position delegate
+    SetPositionDelegate {
+        /// Position to set delegate for
+        #[structopt(long)]
+        position: Pubkey,
+        /// Delegate's authority
+        #[structopt(long)]
+        delegate: Pubkey,
+        /// Allow delegate to repay with collateral
+        #[structopt(long)]
+        repay: bool,
+        /// Allow delegate to lock collateral
+        #[structopt(long)]
+        lock: bool,
+        /// Allow delegate to borrow up to borrow_allowance
+        #[structopt(long)]
+        borrow: bool,
+        /// Allow delegate to unlock collateral to owner's wallets
+        #[structopt(long)]
+        unlock: bool,
+        /// Market value (in cents of quote currency) delegate can borrow. Consumed by
+        /// delegate's borrows.
+        #[structopt(long, default_value = "0")]
+        borrow_allowance: u64,
+    },
+    /// Propose new owner for the position. Proposing current owner cancels the transfer.
+    TransferPositionOwnership {
+        /// Position to transfer
+        #[structopt(long)]
+        position: Pubkey,
+        /// Proposed owner
+        #[structopt(long)]
+        new_owner: Pubkey,
+    },
+    /// Accept position ownership proposed to current authority
+    AcceptPositionOwnership {
+        /// Position to accept
+        #[structopt(long)]
+        position: Pubkey,
+    },
     /// List all existing users positions
     Positions {
         /// Position address
@@ -465,6 +742,9 @@ enum Command {
         /// Position owner
         #[structopt(long)]
         owner: Option<Pubkey>,
+        /// Find owner's PDA positions in the pool by derived addresses instead of scanning all positions
+        #[structopt(long)]
+        derived: bool,
     },
     /// Place LP tokens as collateral
     LockCollateral {
@@ -493,6 +773,33 @@ enum Command {
         #[structopt(long)]
         amount: u64,
     },
+    /// Deposit liquidity to the Reserve and lock minted LP tokens as collateral in one step
+    DepositAndLock {
+        /// Position to lock collateral on
+        #[structopt(long)]
+        position: Pubkey,
+        /// Reserve key to deposit liquidity to
+        #[structopt(long)]
+        reserve: Pubkey,
+        /// Amount of liquidity tokens to deposit
+        #[structopt(long)]
+        amount: u64,
+        /// Memo data which is stored in to Collateral record
+        #[structopt(long)]
+        memo: Option<String>,
+    },
+    /// Unlock collateral and withdraw liquidity for it in one step
+    UnlockAndWithdraw {
+        /// Position to unlock collateral on
+        #[structopt(long)]
+        position: Pubkey,
+        /// Reserve key to unlock collateral from
+        #[structopt(long)]
+        reserve: Pubkey,
+        /// Amount of LP tokens to unlock. If not specified then maximum possible amount will be unlocked.
+        #[structopt(long)]
+        amount: Option<u64>,
+    },
     /// Borrow liquidity against previously deposited collateral
     Borrow {
         /// Position to borrow to
@@ -524,6 +831,55 @@ enum Command {
         #[structopt(long)]
         amount: Option<u64>,
     },
+    /// Borrow liquidity at Reserve's fixed rate for given term
+    BorrowFixed {
+        /// Position to borrow to
+        #[structopt(long)]
+        position: Pubkey,
+        /// Reserve key to borrow liquidity from
+        #[structopt(long)]
+        reserve: Pubkey,
+        /// Amount of liquidity tokens to borrow. Default value is u64::MAX which means - use max
+        /// available borrowing power.
+        #[structopt(long, default_value = "u64::MAX")]
+        amount: u64,
+        /// Slippage - min. tokens amount borrower wants to receive when using max borrowing power
+        #[structopt(long, default_value = "1")]
+        slippage: u64,
+        /// Loan term in seconds
+        #[structopt(long)]
+        term_sec: u32,
+        /// Max fixed rate (in basis points) borrower agrees to
+        #[structopt(long)]
+        max_rate_bps: u32,
+        /// Memo data which is stored in to the loan record
+        #[structopt(long)]
+        memo: Option<String>,
+    },
+    /// Repay fixed-rate loan
+    RepayFixed {
+        /// Position to repay to
+        #[structopt(long)]
+        position: Pubkey,
+        /// Reserve key the loan was taken from
+        #[structopt(long)]
+        reserve: Pubkey,
+        /// Amount of liquidity tokens to repay. If not specified then full repay of the loan will be done.
+        #[structopt(long)]
+        amount: Option<u64>,
+    },
+    /// Repay debt by burning LP tokens locked as collateral in the same Reserve
+    RepayWithCollateral {
+        /// Position to repay to
+        #[structopt(long)]
+        position: Pubkey,
+        /// Reserve key which is both collateral and borrowed one
+        #[structopt(long)]
+        reserve: Pubkey,
+        /// Amount of liquidity tokens to repay. If not specified then full repay for the Reserve will be done.
+        #[structopt(long)]
+        amount: Option<u64>,
+    },
     /// Claim Curator's performance fees. Anyone can call this command.
     ClaimCuratorFee {
         /// Reserve claim fees from
@@ -536,6 +892,38 @@ enum Command {
         #[structopt(long)]
         reserve: Pubkey,
     },
+    /// Claim LP tokens accrued as Curator's part of liquidation bonuses. Anyone can call this command.
+    ClaimCuratorLiquidationFee {
+        /// Reserve (collateral one) claim fees from
+        #[structopt(long)]
+        reserve: Pubkey,
+    },
+    /// Claim LP tokens accrued as Texture's part of liquidation bonuses. Anyone can call this command.
+    ClaimTextureLiquidationFee {
+        /// Reserve (collateral one) claim fees from
+        #[structopt(long)]
+        reserve: Pubkey,
+    },
+    /// Init SPL token account to hold insurance fund for given pool and liquidity mint. Call it from pools mgmt authority.
+    InitInsuranceFund {
+        /// Pool to init insurance fund for
+        #[structopt(long)]
+        pool: Pubkey,
+        #[structopt(long)]
+        liquidity_mint: Pubkey,
+    },
+    /// Move accrued insurance fee from the Reserve to its Pool's insurance fund. Anyone can call this command.
+    ClaimInsuranceFee {
+        /// Reserve to claim insurance fee from
+        #[structopt(long)]
+        reserve: Pubkey,
+    },
+    /// Print insurance funds and their balances
+    InsuranceFunds {
+        /// Pool to print insurance funds of
+        #[structopt(long)]
+        pool: Option<Pubkey>,
+    },
     /// Liquidate unhealthy position
     Liquidate {
         /// Position to liquidate
@@ -550,6 +938,10 @@ enum Command {
         /// Principal amount to liquidate.
         #[structopt(long)]
         principal_amount: Option<u64>,
+        /// Redeem seized collateral and receive collateral Reserve's liquidity instead of LP tokens.
+        /// LP tokens are received when collateral Reserve can not redeem them.
+        #[structopt(long)]
+        redeem: bool,
     },
     /// Write off (i.e. make it acknowledged loss of the whole Reserve) bad debt.
     /// Call it with Curator / Pool owner authority.
@@ -564,6 +956,16 @@ enum Command {
         #[structopt(long)]
         amount: Option<u64>,
     },
+    /// Write off all remaining debt of the position without collateral. Anyone can call this command
+    /// in pools with enabled auto write-off of bad debt.
+    SocializeLoss {
+        /// Position to write off debt of
+        #[structopt(long)]
+        position: Pubkey,
+        /// Principal reserve to write off debt in. One of position borrowings.
+        #[structopt(long)]
+        reserve: Pubkey,
+    },
     /// Init SPL token account to hold reward tokens for given pool. Call it from pools mgmt authority.
     InitRewardSupply {
         /// Pool to init reward supply for
@@ -748,6 +1150,9 @@ async fn main() {
             keypair: global_cfg_keypair,
             performance_fee_rate_bps,
             borrow_fee_rate_bps,
+            flash_loan_fee_share_bps,
+            liquidation_fee_share_bps,
+            swap_program,
             fees_authority,
             market_price_feed_lock_sec,
             irm_lock_sec,
@@ -760,10 +1165,15 @@ async fn main() {
             price_stale_threshold_lock_sec,
             max_withdraw_utilization_lock_sec,
             fees_lock_sec,
+            max_total_borrow_lock_sec,
+            borrow_weight_lock_sec,
         } => {
             let params = TextureConfigParams {
                 borrow_fee_rate_bps,
                 performance_fee_rate_bps,
+                flash_loan_fee_share_bps,
+                liquidation_fee_share_bps,
+                swap_program: swap_program.unwrap_or_default(),
                 fees_authority,
                 reserve_timelock: ReserveTimelock {
                     market_price_feed_lock_sec,
@@ -777,7 +1187,8 @@ async fn main() {
                     price_stale_threshold_lock_sec,
                     max_withdraw_utilization_lock_sec,
                     fees_lock_sec,
-                    _padding: 0,
+                    max_total_borrow_lock_sec,
+                    borrow_weight_lock_sec,
                 },
             };
 
@@ -797,6 +1208,9 @@ async fn main() {
         Command::AlterTextureConfig {
             performance_fee_rate_bps,
             borrow_fee_rate_bps,
+            flash_loan_fee_share_bps,
+            liquidation_fee_share_bps,
+            swap_program,
             performance_fee_authority,
             market_price_feed_lock_sec,
             irm_lock_sec,
@@ -809,11 +1223,16 @@ async fn main() {
             price_stale_threshold_lock_sec,
             max_withdraw_utilization_lock_sec,
             fees_lock_sec,
+            max_total_borrow_lock_sec,
+            borrow_weight_lock_sec,
         } => {
             app.alter_texture_config(
                 performance_fee_authority,
                 performance_fee_rate_bps,
                 borrow_fee_rate_bps,
+                flash_loan_fee_share_bps,
+                liquidation_fee_share_bps,
+                swap_program,
                 market_price_feed_lock_sec,
                 irm_lock_sec,
                 liquidation_bonus_lock_sec,
@@ -825,6 +1244,8 @@ async fn main() {
                 price_stale_threshold_lock_sec,
                 max_withdraw_utilization_lock_sec,
                 fees_lock_sec,
+                max_total_borrow_lock_sec,
+                borrow_weight_lock_sec,
             )
             .await;
         }
@@ -910,6 +1331,7 @@ async fn main() {
                 name: str_to_array(&name),
                 market_price_currency_symbol: str_to_array(&market_price_currency_symbol),
                 visible: 0,
+                auto_write_off_bad_debt: 0,
             };
 
             app.create_pool(curator, params).await;
@@ -919,9 +1341,39 @@ async fn main() {
             name,
             market_price_currency_symbol,
             visible,
+            auto_write_off_bad_debt,
         } => {
-            app.alter_pool(pool, name, market_price_currency_symbol, visible)
-                .await;
+            app.alter_pool(
+                pool,
+                name,
+                market_price_currency_symbol,
+                visible,
+                auto_write_off_bad_debt,
+            )
+            .await;
+        }
+        Command::SetEmodeCategory {
+            pool,
+            category_id,
+            name,
+            max_borrow_ltv_bps,
+            partly_unhealthy_ltv_bps,
+            fully_unhealthy_ltv_bps,
+            propose,
+        } => {
+            app.set_emode_category(
+                pool,
+                category_id,
+                name,
+                max_borrow_ltv_bps,
+                partly_unhealthy_ltv_bps,
+                fully_unhealthy_ltv_bps,
+                propose,
+            )
+            .await;
+        }
+        Command::ApplyEmodeCategoryProposal { pool } => {
+            app.apply_emode_category_proposal(pool).await;
         }
         Command::Pools { pool, curator } => {
             app.list_pools(pool, curator).await;
@@ -934,24 +1386,35 @@ async fn main() {
             pool,
             protected_collateral,
             not_a_collateral,
+            isolated_collateral,
             liquidity_mint,
             market_price_feed,
             irm,
+            kinked_irm,
+            adaptive_irm,
             liquidation_bonus_bps,
             partly_unhealthy_ltv_bps,
             partial_liquidation_factor_bps,
             fully_unhealthy_ltv_bps,
             curator_borrow_fee_bps,
             curator_performance_fee_bps,
+            flash_loan_fee_bps,
+            curator_liquidation_fee_share_bps,
+            insurance_fee_bps,
             max_borrow_ltv_bps,
             max_borrow_utilization_bps,
             max_withdraw_utilization_bps,
             max_total_liquidity,
+            max_total_borrow,
+            borrow_weight_bps,
+            max_liquidation_bonus_bps,
+            min_borrow_rate_bps,
+            max_borrow_rate_bps,
             price_stale_threshold_sec,
         } => {
-            let config = ReserveConfig {
+            let mut config = ReserveConfig {
                 market_price_feed,
-                irm,
+                irm: Pubkey::default(),
                 liquidation_bonus_bps,
                 max_borrow_ltv_bps,
                 partly_unhealthy_ltv_bps,
@@ -960,18 +1423,35 @@ async fn main() {
                 fees: ReserveFeesConfig {
                     curator_borrow_fee_rate_bps: curator_borrow_fee_bps,
                     curator_performance_fee_rate_bps: curator_performance_fee_bps,
+                    flash_loan_fee_bps,
+                    curator_liquidation_fee_share_bps,
+                    insurance_fee_rate_bps: insurance_fee_bps,
                     _padding: Zeroable::zeroed(),
                 },
-                _padding: Zeroable::zeroed(),
                 max_total_liquidity,
+                max_total_borrow,
+                borrow_weight_bps,
+                max_liquidation_bonus_bps,
+                min_borrow_rate_bps,
+                max_borrow_rate_bps,
                 max_borrow_utilization_bps,
                 price_stale_threshold_sec,
                 max_withdraw_utilization_bps,
+                irm_kind: IRM_KIND_CURVE,
+                emode_category: 0,
             };
+            config.set_interest_rate_model(
+                interest_rate_model(irm, kinked_irm, adaptive_irm).expect("IRM must be specified"),
+            );
 
-            if protected_collateral && not_a_collateral {
+            if [protected_collateral, not_a_collateral, isolated_collateral]
+                .iter()
+                .filter(|flag| **flag)
+                .count()
+                > 1
+            {
                 println!(
-                    "Choose either --protected-collateral OR --not-a-collateral reserve mode!"
+                    "Choose either --protected-collateral OR --not-a-collateral OR --isolated-collateral reserve mode!"
                 );
                 return;
             }
@@ -980,6 +1460,8 @@ async fn main() {
                 RESERVE_TYPE_PROTECTED_COLLATERAL
             } else if not_a_collateral {
                 RESERVE_TYPE_NOT_A_COLLATERAL
+            } else if isolated_collateral {
+                RESERVE_TYPE_ISOLATED_COLLATERAL
             } else {
                 RESERVE_TYPE_NORMAL
             };
@@ -1005,37 +1487,69 @@ async fn main() {
             reserve,
             market_price_feed,
             irm,
+            kinked_irm,
+            adaptive_irm,
             liquidation_bonus_bps,
             partly_unhealthy_ltv_bps,
             partial_liquidation_factor_bps,
             fully_unhealthy_ltv_bps,
             curator_borrow_fee_bps,
             curator_performance_fee_bps,
+            flash_loan_fee_bps,
+            curator_liquidation_fee_share_bps,
+            insurance_fee_bps,
             max_borrow_utilization_bps,
             max_withdraw_utilization_bps,
             max_total_liquidity,
+            max_total_borrow,
+            borrow_weight_bps,
+            max_liquidation_bonus_bps,
+            min_borrow_rate_bps,
+            max_borrow_rate_bps,
             max_borrow_ltv_bps,
             price_stale_threshold_sec,
             mode,
             flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable,
+            isolated_debt_ceiling,
+            emode_category,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
         } => {
             app.alter_reserve(
                 reserve,
                 market_price_feed,
-                irm,
+                interest_rate_model(irm, kinked_irm, adaptive_irm),
                 liquidation_bonus_bps,
                 partly_unhealthy_ltv_bps,
                 partial_liquidation_factor_bps,
                 fully_unhealthy_ltv_bps,
                 curator_borrow_fee_bps,
                 curator_performance_fee_bps,
+                flash_loan_fee_bps,
+                curator_liquidation_fee_share_bps,
+                insurance_fee_bps,
                 max_borrow_utilization_bps,
                 max_withdraw_utilization_bps,
                 max_total_liquidity,
+                max_total_borrow,
+                borrow_weight_bps,
+                max_liquidation_bonus_bps,
+                min_borrow_rate_bps,
+                max_borrow_rate_bps,
                 max_borrow_ltv_bps,
                 price_stale_threshold_sec,
                 mode,
                 flash_loans_enabled,
+                surplus_destination,
+                isolation_borrowable,
+                isolated_debt_ceiling,
+                emode_category,
+                fixed_rate_bps,
+                fixed_max_term_sec,
+                fixed_grace_period_sec,
             )
             .await;
         }
@@ -1044,34 +1558,54 @@ async fn main() {
             index,
             market_price_feed,
             irm,
+            kinked_irm,
+            adaptive_irm,
             liquidation_bonus_bps,
             partly_unhealthy_ltv_bps,
             partial_liquidation_factor_bps,
             fully_unhealthy_ltv_bps,
             curator_borrow_fee_bps,
             curator_performance_fee_bps,
+            flash_loan_fee_bps,
+            curator_liquidation_fee_share_bps,
+            insurance_fee_bps,
             max_borrow_utilization_bps,
             max_withdraw_utilization_bps,
             max_total_liquidity,
+            max_total_borrow,
+            borrow_weight_bps,
+            max_liquidation_bonus_bps,
+            min_borrow_rate_bps,
+            max_borrow_rate_bps,
             max_borrow_ltv_bps,
             price_stale_threshold_sec,
+            emode_category,
         } => {
             app.propose_config_change(
                 reserve,
                 index,
                 market_price_feed,
-                irm,
+                interest_rate_model(irm, kinked_irm, adaptive_irm),
                 liquidation_bonus_bps,
                 partly_unhealthy_ltv_bps,
                 partial_liquidation_factor_bps,
                 fully_unhealthy_ltv_bps,
                 curator_borrow_fee_bps,
                 curator_performance_fee_bps,
+                flash_loan_fee_bps,
+                curator_liquidation_fee_share_bps,
+                insurance_fee_bps,
                 max_borrow_utilization_bps,
                 max_withdraw_utilization_bps,
                 max_total_liquidity,
+                max_total_borrow,
+                borrow_weight_bps,
+                max_liquidation_bonus_bps,
+                min_borrow_rate_bps,
+                max_borrow_rate_bps,
                 max_borrow_ltv_bps,
                 price_stale_threshold_sec,
+                emode_category,
             )
             .await;
         }
@@ -1087,24 +1621,87 @@ async fn main() {
         Command::RefreshReserve { reserve } => {
             app.refresh_reserve(reserve).await;
         }
+        Command::SyncReserve { reserve } => {
+            app.sync_reserve(reserve).await;
+        }
+        Command::CheckReserves { pool } => {
+            app.check_reserves(pool).await;
+        }
         Command::Deposit { reserve, amount } => {
             app.deposit(reserve, amount).await;
         }
         Command::Withdraw { reserve, lp_amount } => {
             app.withdraw(reserve, lp_amount).await;
         }
-        Command::CreatePosition { pool, long_short } => {
-            app.create_position(pool, long_short).await;
+        Command::CreatePosition {
+            pool,
+            long_short,
+            index,
+        } => {
+            app.create_position(pool, long_short, index).await;
         }
         Command::ClosePosition { position, pool } => {
             app.close_position(position, pool).await;
         }
         Command::RefreshPosition { position } => app.refresh_position(position).await,
+        Command::SetPositionEmode {
+            position,
+            category_id,
+        } => app.set_position_emode(position, category_id).await,
+        Command::ResizePosition {
+            position,
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        } => {
+            app.resize_position(
+                position,
+                extra_deposits,
+                extra_borrows,
+                extra_rewards,
+                extra_fixed_loans,
+            )
+            .await
+        }
+        Command::SetPositionDelegate {
+            position,
+            delegate,
+            repay,
+            lock,
+            borrow,
+            unlock,
+            borrow_allowance,
+        } => {
+            let mut permissions = 0;
+            if repay {
+                permissions |= DELEGATE_PERMISSION_REPAY;
+            }
+            if lock {
+                permissions |= DELEGATE_PERMISSION_LOCK;
+            }
+            if borrow {
+                permissions |= DELEGATE_PERMISSION_BORROW;
+            }
+            if unlock {
+                permissions |= DELEGATE_PERMISSION_UNLOCK;
+            }
+            app.set_position_delegate(position, delegate, permissions, borrow_allowance)
+                .await
+        }
+        Command::TransferPositionOwnership {
+            position,
+            new_owner,
+        } => app.transfer_position_ownership(position, new_owner).await,
+        Command::AcceptPositionOwnership { position } => {
+            app.accept_position_ownership(position).await
+        }
         Command::Positions {
             position,
             owner,
             pool,
-        } => app.list_positions(position, owner, pool).await,
+            derived,
+        } => app.list_positions(position, owner, pool, derived).await,
         Command::LockCollateral {
             position,
             reserve,
@@ -1120,6 +1717,21 @@ async fn main() {
         } => {
             app.unlock(position, reserve, amount).await;
         }
+        Command::DepositAndLock {
+            position,
+            reserve,
+            amount,
+            memo,
+        } => {
+            app.deposit_and_lock(position, reserve, amount, memo).await;
+        }
+        Command::UnlockAndWithdraw {
+            position,
+            reserve,
+            amount,
+        } => {
+            app.unlock_and_withdraw(position, reserve, amount).await;
+        }
         Command::Borrow {
             position,
             reserve,
@@ -1127,14 +1739,48 @@ async fn main() {
             slippage,
             memo,
         } => {
-            app.borrow(position, reserve, amount, slippage, memo).await;
+            app.borrow(position, reserve, amount, slippage, memo, None)
+                .await;
+        }
+        Command::BorrowFixed {
+            position,
+            reserve,
+            amount,
+            slippage,
+            term_sec,
+            max_rate_bps,
+            memo,
+        } => {
+            app.borrow(
+                position,
+                reserve,
+                amount,
+                slippage,
+                memo,
+                Some((term_sec, max_rate_bps)),
+            )
+            .await;
         }
         Command::Repay {
             position,
             reserve,
             amount,
         } => {
-            app.repay(position, reserve, amount).await;
+            app.repay(position, reserve, amount, false).await;
+        }
+        Command::RepayFixed {
+            position,
+            reserve,
+            amount,
+        } => {
+            app.repay(position, reserve, amount, true).await;
+        }
+        Command::RepayWithCollateral {
+            position,
+            reserve,
+            amount,
+        } => {
+            app.repay_with_collateral(position, reserve, amount).await;
         }
         Command::ClaimCuratorFee { reserve } => {
             app.claim_curator_perf_fee(reserve).await;
@@ -1142,17 +1788,37 @@ async fn main() {
         Command::ClaimTextureFee { reserve } => {
             app.claim_texture_perf_fee(reserve).await;
         }
+        Command::ClaimCuratorLiquidationFee { reserve } => {
+            app.claim_curator_liquidation_fee(reserve).await;
+        }
+        Command::ClaimTextureLiquidationFee { reserve } => {
+            app.claim_texture_liquidation_fee(reserve).await;
+        }
+        Command::InitInsuranceFund {
+            pool,
+            liquidity_mint,
+        } => {
+            app.init_insurance_fund(pool, liquidity_mint).await;
+        }
+        Command::ClaimInsuranceFee { reserve } => {
+            app.claim_insurance_fee(reserve).await;
+        }
+        Command::InsuranceFunds { pool } => {
+            app.insurance_funds(pool).await;
+        }
         Command::Liquidate {
             position,
             principal_reserve,
             collateral_reserve,
             principal_amount,
+            redeem,
         } => {
             app.liquidate(
                 position,
                 principal_reserve,
                 collateral_reserve,
                 principal_amount,
+                redeem,
             )
             .await;
         }
@@ -1163,6 +1829,9 @@ async fn main() {
         } => {
             app.write_off_bad_debt(position, reserve, amount).await;
         }
+        Command::SocializeLoss { position, reserve } => {
+            app.socialize_loss(position, reserve).await;
+        }
         Command::SetRewardRule {
             reserve,
             index,
@@ -1259,6 +1928,82 @@ async fn main() {
     }
 }
 
+/// Parameters of built-in kinked IRM in form of
+/// <base_rate_bps>,<slope1_bps>,<optimal_utilization_bps>,<slope2_bps>
+struct KinkedIrmArg(KinkedIrm);
+
+impl std::str::FromStr for KinkedIrmArg {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let params = s
+            .split(',')
+            .map(|param| param.trim().parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match params[..] {
+            [base_rate_bps, slope1_bps, optimal_utilization_bps, slope2_bps] => {
+                Ok(Self(KinkedIrm::new(
+                    base_rate_bps,
+                    slope1_bps,
+                    optimal_utilization_bps,
+                    slope2_bps,
+                )))
+            }
+            _ => Err(anyhow!(
+                "expected <base_rate_bps>,<slope1_bps>,<optimal_utilization_bps>,<slope2_bps>"
+            )),
+        }
+    }
+}
+
+/// Parameters of built-in adaptive IRM in form of
+/// <target_utilization_bps>,<initial_rate_at_target_bps>,<min_rate_at_target_bps>,
+/// <max_rate_at_target_bps>,<adjustment_speed_bps>,<curve_steepness_bps>
+struct AdaptiveIrmArg(AdaptiveIrm);
+
+impl std::str::FromStr for AdaptiveIrmArg {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let params = s
+            .split(',')
+            .map(|param| param.trim().parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match params[..] {
+            [target_utilization_bps, initial_rate_at_target_bps, min_rate_at_target_bps, max_rate_at_target_bps, adjustment_speed_bps, curve_steepness_bps] => {
+                Ok(Self(AdaptiveIrm::new(
+                    target_utilization_bps,
+                    initial_rate_at_target_bps,
+                    min_rate_at_target_bps,
+                    max_rate_at_target_bps,
+                    adjustment_speed_bps,
+                    curve_steepness_bps,
+                )))
+            }
+            _ => Err(anyhow!(
+                "expected <target_utilization_bps>,<initial_rate_at_target_bps>,<min_rate_at_target_bps>,<max_rate_at_target_bps>,<adjustment_speed_bps>,<curve_steepness_bps>"
+            )),
+        }
+    }
+}
+
+fn interest_rate_model(
+    irm: Option<Pubkey>,
+    kinked_irm: Option<KinkedIrmArg>,
+    adaptive_irm: Option<AdaptiveIrmArg>,
+) -> Option<InterestRateModel> {
+    match (irm, kinked_irm, adaptive_irm) {
+        (_, _, Some(AdaptiveIrmArg(adaptive_irm))) => {
+            Some(InterestRateModel::Adaptive(adaptive_irm))
+        }
+        (_, Some(KinkedIrmArg(kinked_irm)), None) => Some(InterestRateModel::Kinked(kinked_irm)),
+        (Some(irm), None, None) => Some(InterestRateModel::Curve(irm)),
+        (None, None, None) => None,
+    }
+}
+
 impl Default for KeypairPath {
     fn default() -> Self {
         let mut path = dirs_next::home_dir().expect("home dir");
@@ -96,7 +96,9 @@ pub async fn position_after_refresh(app: &App, position_key: Pubkey) -> Position
         .get_account_data(&position_key)
         .await
         .expect("getting Position account");
-    *Position::try_from_bytes(&position_data).expect("unpacking Position")
+    *Position::unpack(&position_data)
+        .expect("unpacking Position")
+        .header
 }
 
 pub async fn create_position(app: &App, position_keypair: &Keypair, pool: Pubkey) {
@@ -252,7 +254,7 @@ pub async fn deposit(
         let refresh_reserve = RefreshReserve {
             reserve: collateral_reserve_key,
             market_price_feed: collateral_reserve.config.market_price_feed,
-            irm: collateral_reserve.config.irm,
+            irm: collateral_reserve.config.irm_account(),
         }
         .into_instruction();
         refresh_position_ixs.push(refresh_reserve);
@@ -512,7 +514,8 @@ pub async fn gen_unhealthy_positions(
     } else {
         return;
     };
-    let borrowed_value = position.borrowed_value().unwrap();
+    // Health is checked against risk-adjusted borrowed value
+    let borrowed_value = position.weighted_borrowed_value().unwrap();
     let deposited_value = position.deposited_value().unwrap();
 
     println!("--------------------- Write Price ---------------------");
@@ -132,6 +132,12 @@ pub enum SuperLendyError {
     #[error("metaplex error: {0}")]
     MetaplexError(ProgramError),
 
+    #[error("liquidity supply balance {0} is less then available amount {1}")]
+    SupplyDeficit(/*supply balance*/ u64, /*available amount*/ u64),
+
+    #[error("swap program error: {0}")]
+    SwapError(ProgramError),
+
     // NaN
     #[error("spl-token error: {0}")]
     SplToken(#[from] RemoteError<TokenError>),
@@ -175,6 +181,8 @@ impl From<SuperLendyError> for ProgramError {
             SuperLendyError::AttemptToLiquidateHealthyPosition(..) => Custom(27),
             SuperLendyError::SysvarError(..) => Custom(28),
             SuperLendyError::MetaplexError(..) => Custom(29),
+            SuperLendyError::SupplyDeficit(..) => Custom(30),
+            SuperLendyError::SwapError(..) => Custom(31),
 
             SuperLendyError::SplToken(err) => err.into(),
             SuperLendyError::SystemProgram(RemoteError::Unrecognized(err)) => err,
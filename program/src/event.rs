@@ -0,0 +1,38 @@
+//! Events emitted by the contract. Event is Borsh serialized [SuperLendyEvent] logged with
+//! `sol_log_data` so off-chain apps can find it in `Program data:` entries of transaction logs.
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum SuperLendyEvent {
+    /// Accrued insurance fee transferred from Reserve's liquidity supply to Pool's insurance fund.
+    InsuranceFunded {
+        pool: Pubkey,
+        reserve: Pubkey,
+        insurance_fund: Pubkey,
+        /// Transferred amount of liquidity tokens
+        amount: u64,
+    },
+    /// Bad debt written off from the Reserve. Loss is covered by Pool's insurance fund as much as
+    /// possible and only the rest is socialized on LPs.
+    BadDebtWrittenOff {
+        pool: Pubkey,
+        reserve: Pubkey,
+        position: Pubkey,
+        /// Written off amount of liquidity tokens
+        amount: u64,
+        /// Part of `amount` transferred from insurance fund to Reserve's liquidity supply
+        covered_amount: u64,
+        /// Part of `amount` LPs lose
+        socialized_amount: u64,
+    },
+}
+
+impl SuperLendyEvent {
+    pub fn emit(&self) {
+        match borsh::to_vec(self) {
+            Ok(data) => solana_program::log::sol_log_data(&[&data]),
+            Err(err) => solana_program::msg!("event serialization failed: {}", err),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::state::curator::CuratorParams;
 use borsh::{BorshDeserialize, BorshSerialize};
 use texture_common::macros::Instruction;
 
-use crate::state::pool::PoolParams;
+use crate::state::pool::{EmodeCategory, PoolParams};
 use crate::state::position::{BORROW_MEMO_LEN, COLLATERAL_MEMO_LEN};
 use crate::state::reserve::{ConfigProposal, ReserveConfig, RewardRules};
 use crate::state::texture_cfg::TextureConfigParams;
@@ -287,6 +287,21 @@ pub enum SuperLendyInstruction {
         params: ReserveConfig,
         mode: u8,
         flash_loans_enabled: u8,
+        /// What to do with liquidity supply surplus found by `SyncReserve`. SURPLUS_TO_LPS, etc.
+        surplus_destination: u8,
+        /// 1 - positions with isolated collateral can borrow from this Reserve.
+        isolation_borrowable: u8,
+        /// Max value (in whole units of quote currency) of debt backed by this Reserve when it is of
+        /// RESERVE_TYPE_ISOLATED_COLLATERAL type. 0 - no limit.
+        isolated_debt_ceiling: u64,
+        /// Annual interest rate (in basis points) of fixed-rate loans originated from this Reserve.
+        /// 0 - fixed-rate borrowing is disabled.
+        fixed_rate_bps: u32,
+        /// Maximum term (in seconds) of fixed-rate loan.
+        fixed_max_term_sec: u32,
+        /// Time (in seconds) after maturity of fixed-rate loan till it can be liquidated regardless
+        /// of Position health.
+        fixed_grace_period_sec: u32,
     },
 
     // 8
@@ -306,9 +321,11 @@ pub enum SuperLendyInstruction {
             checks(owner = price_proxy::ID),
         ),
         account(
-            docs = ["Interest Rate Model account."],
+            docs = [
+                "Interest Rate Model account. Curve account for Reserve with external IRM.",
+                "Super-lendy program id for Reserve with built-in IRM.",
+            ],
             name = "irm",
-            checks(owner = curvy::ID),
         ),
         account(
             docs = ["Global config account"],
@@ -495,6 +512,11 @@ pub enum SuperLendyInstruction {
     // 13
     /// Refresh existing user position. Requires refreshed reserves (all deposits and borrowings).
     ///
+    /// Position opted in to e-mode category must additionally pass its Pool as the last account.
+    /// Reserves of the records from Position's extension (see ResizePosition) go after reserves of
+    /// the records stored in the Position itself. Reserves of active fixed-rate loans (see
+    /// BorrowFixed) are listed among borrowings after all of them.
+    ///
     #[doc = ix_docs::refresh_position!()]
     #[accounts(
         account(
@@ -552,7 +574,7 @@ pub enum SuperLendyInstruction {
             pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
         ),
         account(
-            docs = ["Position owner and also authority for source_lp_wallet"],
+            docs = ["Position owner or its delegate. Also authority for source_lp_wallet"],
             name = "owner",
             flags(signer),
         ),
@@ -600,7 +622,7 @@ pub enum SuperLendyInstruction {
             flags(writable),
         ),
         account(
-            docs = ["Position owner"],
+            docs = ["Position owner or its delegate"],
             name = "owner",
             flags(signer),
         ),
@@ -629,6 +651,9 @@ pub enum SuperLendyInstruction {
     // 16
     /// Borrow liquidity from the pool
     ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
+    ///
     #[doc = ix_docs::borrow!()]
     #[accounts(
         account(
@@ -654,7 +679,7 @@ pub enum SuperLendyInstruction {
             flags(writable),
         ),
         account(
-            docs = ["Position owner who borrow"],
+            docs = ["Position owner or its delegate who borrow"],
             name = "borrower",
             flags(signer),
         ),
@@ -715,6 +740,9 @@ pub enum SuperLendyInstruction {
     // 17
     /// Repay existing loan.
     ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
+    ///
     #[doc = ix_docs::repay!()]
     #[accounts(
         account(
@@ -760,7 +788,12 @@ pub enum SuperLendyInstruction {
     },
 
     // 18
-    /// Writes off bad debt for particular unhealthy Position.
+    /// Writes off bad debt for particular unhealthy Position. Loss is covered from Pool's insurance
+    /// fund (see InitInsuranceFund) as much as fund's balance allows. Only the rest is socialized
+    /// on LPs. Insurance fund which is not initialized yet is treated as empty.
+    ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
     ///
     #[doc = ix_docs::write_off_bad_debt!()]
     #[accounts(
@@ -793,6 +826,31 @@ pub enum SuperLendyInstruction {
             name = "curator",
             checks(owner = "self", exempt),
         ),
+        account(
+            docs = ["Contract managed SPL token wallet with Reserve's liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Pool's insurance fund for Reserve's liquidity mint. PDA."],
+            name = "insurance_fund",
+            flags(writable),
+            pda_seeds = [pool, liquidity_mint, crate::pda::INSURANCE_FUND_SEED],
+        ),
+        account(
+            docs = ["Liquidity tokens mint"],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "token_program",
+        ),
     )]
     WriteOffBadDebt {
         /// principal (borrowed as bad debt) token amount to write off
@@ -831,6 +889,7 @@ pub enum SuperLendyInstruction {
         account(
             docs = ["Reserve account to repay principal tokens owed by unhealthy Position. Refreshed."],
             name = "collateral_reserve",
+            flags(writable),
             checks(owner = "self"),
         ),
         account(
@@ -854,6 +913,12 @@ pub enum SuperLendyInstruction {
             docs = ["Liquidity tokens mint in principal Reserve."],
             name = "principal_reserve_liquidity_mint",
         ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
         account(
             docs = ["Contract's authority. PDA."],
             name = "program_authority",
@@ -1239,9 +1304,16 @@ pub enum SuperLendyInstruction {
             docs = ["SPL Token program"],
             name = "token_program",
         ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
     )]
     FlashRepay {
         /// Amount of liquidity to flash repay. Must be the same as in paired FlashBorrow IX.
+        /// Reserve's flash loan fee is charged on top of that amount.
         amount: u64,
     },
 
@@ -1454,6 +1526,1231 @@ pub enum SuperLendyInstruction {
         ),
     )]
     SetLpMetadata { metadata: LpTokenMetadata },
+
+    // 34
+    /// Permissionless IX to reconcile Reserve's `available_amount` with actual balance of its
+    /// liquidity supply. Fails when supply holds less than `available_amount` minus claimed
+    /// performance fees. Surplus (e.g. tokens sent to the supply directly) goes either to LPs or to
+    /// Curator's performance fee depending on Reserve's `surplus_destination`.
+    ///
+    #[doc = ix_docs::sync_reserve!()]
+    #[accounts(
+        account(
+            docs = ["Reserve account to sync."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with Reserve's liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+    )]
+    SyncReserve,
+
+    // 35
+    /// Configure Pool's efficiency mode (e-mode) category. Zeroed category disables it. When LTV
+    /// changes are time locked (see ReserveTimelock) only changes which disable the category or
+    /// don't raise locked LTVs are allowed. Others go through ProposeEmodeCategory.
+    ///
+    #[doc = ix_docs::set_emode_category!()]
+    #[accounts(
+        account(
+            docs = ["Pool account to configure e-mode category in."],
+            name = "pool",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Pools authority configured in `curator` account."],
+            name = "curator_pools_authority",
+            flags(signer),
+        ),
+        account(
+            docs = ["Curator account."],
+            name = "curator",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+    )]
+    SetEmodeCategory {
+        /// Category ID in range 1..=MAX_EMODE_CATEGORIES
+        category_id: u8,
+        category: EmodeCategory,
+    },
+
+    // 36
+    /// Opt position in to (or out of) e-mode category. Position must not have borrowings.
+    ///
+    #[doc = ix_docs::set_position_emode!()]
+    #[accounts(
+        account(
+            docs = ["Position account."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Pool the position belongs to."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Owner of the position"],
+            name = "owner",
+            flags(signer),
+        ),
+    )]
+    SetPositionEmode {
+        /// E-mode category ID configured in the Pool. 0 - opt out of e-mode.
+        category_id: u8,
+    },
+
+    // 37
+    /// Permissionless IX to transfer LP tokens accrued as Curator's part of liquidation bonuses
+    /// on to ATA of [Curator.fees_authority]
+    ///
+    #[doc = ix_docs::claim_curator_liquidation_fees!()]
+    #[accounts(
+        account(
+            docs = ["Reserve account to claim liquidation fees from."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Pool."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Curator account."],
+            name = "curator",
+            checks(owner = "self", exempt),
+        ),
+        account(
+            docs = ["SPL token wallet to receive claimed LP tokens. Must be ATA from curator.fees_authority."],
+            name = "fee_receiver",
+            flags(writable),
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program used to manage LP tokens - always classic SPL Token"],
+            name = "token_program",
+            id = spl_token::ID,
+        ),
+    )]
+    ClaimCuratorLiquidationFees,
+
+    // 38
+    /// Permissionless IX to transfer LP tokens accrued as Texture's part of liquidation bonuses
+    /// on to ATA of [TextureConfig.fees_authority]
+    ///
+    #[doc = ix_docs::claim_texture_liquidation_fees!()]
+    #[accounts(
+        account(
+            docs = ["Reserve account to claim liquidation fees from."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["SPL token wallet to receive claimed LP tokens. Must be ATA from [TextureConfig.fees_authority]"],
+            name = "fee_receiver",
+            flags(writable),
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program used to manage LP tokens - always classic SPL Token"],
+            name = "token_program",
+            id = spl_token::ID,
+        ),
+    )]
+    ClaimTextureLiquidationFees,
+
+    // 39
+    /// Same as [SuperLendyInstruction::Liquidate] but seized collateral LP tokens are burned and
+    /// underlying liquidity of the collateral Reserve is sent to the liquidator in the same IX.
+    /// When collateral Reserve has not enough available liquidity (or retains it) the liquidator
+    /// receives LP tokens in `destination_lp_wallet` as with regular Liquidate.
+    ///
+    #[doc = ix_docs::liquidate_and_redeem!()]
+    #[accounts(
+        account(
+            docs = ["SPL token wallet to get repayment liquidity from."],
+            name = "repayment_source_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["SPL token wallet to receive LP tokens when redeem is not possible."],
+            name = "destination_lp_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["SPL token wallet to receive collateral Reserve's liquidity."],
+            name = "destination_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Reserve account to repay principal tokens owed by unhealthy Position. Refreshed."],
+            name = "principal_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet to return principal liquidity to. PDA."],
+            name = "principal_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [principal_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Reserve account to take collateral from. Refreshed."],
+            name = "collateral_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "collateral_reserve_lp_supply",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with collateral Reserve's liquidity. PDA."],
+            name = "collateral_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint of collateral Reserve. PDA."],
+            name = "collateral_reserve_lp_mint",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Borrower Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Liquidator's authority which controls `repayment_source_wallet`"],
+            name = "liquidator",
+            flags(signer),
+        ),
+        account(
+            docs = ["Liquidity tokens mint in principal Reserve."],
+            name = "principal_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Liquidity tokens mint in collateral Reserve."],
+            name = "collateral_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program used to manage principal tokens"],
+            name = "principal_token_program",
+        ),
+        program(
+            docs = ["SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token"],
+            name = "collateral_token_program",
+            id = spl_token::ID,
+        ),
+        program(
+            docs = ["SPL Token program used to manage collateral Reserve's liquidity"],
+            name = "collateral_liquidity_token_program",
+        ),
+    )]
+    LiquidateAndRedeem {
+        /// liquidity amount to repay
+        liquidity_amount: u64,
+    },
+
+    // 40
+    /// Repay Position's debt with its collateral locked from the same Reserve. LP tokens are
+    /// burned from the collateral supply for the exact liquidity value of the repaid debt thus no
+    /// liquidation bonus involved. Works for any Position state - healthy or not.
+    ///
+    #[doc = ix_docs::repay_with_collateral!()]
+    #[accounts(
+        account(
+            docs = ["Borrower's Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account the Position borrowed from and locked collateral in. Refreshed."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint of the Reserve. PDA."],
+            name = "lp_mint",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Position owner or its delegate"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "lp_token_program",
+            id = spl_token::ID,
+        ),
+    )]
+    RepayWithCollateral {
+        /// Amount of liquidity to repay. u64::MAX - repay as much as collateral allows.
+        amount: u64,
+    },
+
+    // 41
+    /// Deleverage Position across assets. `lp_amount` of collateral LP tokens are unlocked from the
+    /// Position and redeemed to `collateral_liquidity_wallet`. Then swap program (see
+    /// TextureConfig.swap_program) is called with `swap_data` and all accounts following the listed
+    /// ones. Principal liquidity received by `principal_liquidity_wallet` from the swap is used to
+    /// repay Position's debt. Any excess (above the debt) stays in `principal_liquidity_wallet`.
+    /// Resulting Position must stay within its borrowing power.
+    ///
+    #[doc = ix_docs::repay_with_collateral_swap!()]
+    #[accounts(
+        account(
+            docs = ["Borrower's Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account to take collateral from. Refreshed."],
+            name = "collateral_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "collateral_reserve_lp_supply",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with collateral Reserve's liquidity. PDA."],
+            name = "collateral_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint of collateral Reserve. PDA."],
+            name = "collateral_reserve_lp_mint",
+            flags(writable),
+            pda_seeds = [collateral_reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Owner's SPL token wallet to receive redeemed collateral liquidity. Swap input."],
+            name = "collateral_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Reserve account to repay principal tokens to. Refreshed."],
+            name = "principal_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet to return principal liquidity to. PDA."],
+            name = "principal_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [principal_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Owner's SPL token wallet to receive principal liquidity. Swap output."],
+            name = "principal_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Position owner"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Liquidity tokens mint in collateral Reserve."],
+            name = "collateral_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Liquidity tokens mint in principal Reserve."],
+            name = "principal_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token"],
+            name = "lp_token_program",
+            id = spl_token::ID,
+        ),
+        program(
+            docs = ["SPL Token program used to manage collateral Reserve's liquidity"],
+            name = "collateral_liquidity_token_program",
+        ),
+        program(
+            docs = ["SPL Token program used to manage principal tokens"],
+            name = "principal_token_program",
+        ),
+        program(
+            docs = ["Swap program. Must be the one set in TextureConfig.swap_program"],
+            name = "swap_program",
+        ),
+    )]
+    RepayWithCollateralSwap {
+        /// Amount of collateral LP tokens to unlock and redeem for the swap.
+        lp_amount: u64,
+        /// Minimum amount of principal liquidity the swap must produce.
+        min_repay_amount: u64,
+        /// Instruction data passed to the swap program as is.
+        swap_data: Vec<u8>,
+    },
+    // 42
+    /// Rotate Position's collateral from one Reserve to another atomically. `lp_amount` of source
+    /// collateral LP tokens are unlocked from the Position and redeemed to `source_liquidity_wallet`.
+    /// Then swap program (see TextureConfig.swap_program) is called with `swap_data` and all
+    /// accounts following the listed ones. Target liquidity received by `target_liquidity_wallet`
+    /// from the swap is deposited to target Reserve and resulting LP tokens are locked in the
+    /// Position. Position health is checked only after new collateral is locked.
+    ///
+    #[doc = ix_docs::swap_collateral!()]
+    #[accounts(
+        account(
+            docs = ["Borrower's Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account to take collateral from. Refreshed."],
+            name = "source_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds source Reserve's locked LP tokens. PDA."],
+            name = "source_reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [source_reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with source Reserve's liquidity. PDA."],
+            name = "source_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [source_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint of source Reserve. PDA."],
+            name = "source_reserve_lp_mint",
+            flags(writable),
+            pda_seeds = [source_reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Owner's SPL token wallet to receive redeemed source liquidity. Swap input."],
+            name = "source_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Reserve account to lock new collateral in. Refreshed."],
+            name = "target_reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds target Reserve's locked LP tokens. PDA."],
+            name = "target_reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [target_reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with target Reserve's liquidity. PDA."],
+            name = "target_reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [target_reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint of target Reserve. PDA."],
+            name = "target_reserve_lp_mint",
+            flags(writable),
+            pda_seeds = [target_reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Owner's SPL token wallet to receive target liquidity. Swap output."],
+            name = "target_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Position owner"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Liquidity tokens mint in source Reserve."],
+            name = "source_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Liquidity tokens mint in target Reserve."],
+            name = "target_reserve_liquidity_mint",
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token"],
+            name = "lp_token_program",
+            id = spl_token::ID,
+        ),
+        program(
+            docs = ["SPL Token program used to manage source Reserve's liquidity"],
+            name = "source_liquidity_token_program",
+        ),
+        program(
+            docs = ["SPL Token program used to manage target Reserve's liquidity"],
+            name = "target_liquidity_token_program",
+        ),
+        program(
+            docs = ["Swap program. Must be the one set in TextureConfig.swap_program"],
+            name = "swap_program",
+        ),
+    )]
+    SwapCollateral {
+        /// Amount of source collateral LP tokens to unlock and redeem for the swap.
+        lp_amount: u64,
+        /// Minimum amount of target liquidity the swap must produce.
+        min_target_amount: u64,
+        /// Instruction data passed to the swap program as is.
+        swap_data: Vec<u8>,
+    },
+    // 43
+    /// Deposit liquidity in to reserve and lock minted LP tokens as Position's collateral. LP tokens
+    /// are minted straight to reserve's collateral supply thus user needs no LP wallet.
+    ///
+    #[doc = ix_docs::deposit_and_lock_collateral!()]
+    #[accounts(
+        account(
+            docs = ["Position account to lock collateral in."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account to deposit to. Must be refreshed beforehand."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Source SPL Token wallet to transfer liquidity from."],
+            name = "source_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet to hold locked LP tokens. PDA."],
+            name = "reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint. PDA."],
+            name = "lp_mint",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["Liquidity tokens mint"],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Position owner or its delegate. Also authority for source_liquidity_wallet"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "lp_token_program",
+            id = spl_token::ID,
+        ),
+        program(
+            docs = ["SPL Token program - either classic or 2022"],
+            name = "liquidity_token_program",
+        ),
+    )]
+    DepositAndLockCollateral {
+        /// Amount of liquidity to deposit. When u64::MAX is passed - all liquidity from provided
+        /// wallet will be deposited.
+        amount: u64,
+        /// Arbitrary bytes stored along with that Collateral. Same as in LockCollateral.
+        memo: [u8; COLLATERAL_MEMO_LEN],
+    },
+
+    // 44
+    /// Unlock Position's collateral and redeem it for liquidity. LP tokens are burned straight from
+    /// reserve's collateral supply thus user needs no LP wallet.
+    ///
+    #[doc = ix_docs::unlock_and_withdraw_liquidity!()]
+    #[accounts(
+        account(
+            docs = ["Position account to unlock collateral from"],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account to withdraw from. Must be refreshed beforehand."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds locked LP tokens. PDA."],
+            name = "reserve_collateral_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::COLLATERAL_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Source SPL Token wallet controlled by contract which holds the liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["LP tokens mint. PDA."],
+            name = "lp_mint",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LP_TOKEN_SEED],
+        ),
+        account(
+            docs = ["User's SPL Token wallet to receive the liquidity."],
+            name = "destination_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Liquidity tokens mint"],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Position owner or its delegate"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "lp_token_program",
+            id = spl_token::ID,
+        ),
+        program(
+            docs = ["SPL Token program - either classic or 2022"],
+            name = "liquidity_token_program",
+        ),
+    )]
+    UnlockAndWithdrawLiquidity {
+        /// Amount of LP tokens user wants to unlock and redeem.
+        /// When u64::MAX is passed - maximum possible amount of LPs from user's position will be unlocked.
+        lp_amount: u64,
+    },
+
+    // 45
+    /// Create new user position at PDA address derived from pool, owner and `index` (see
+    /// pda::find_position). Account is allocated by the contract and paid by the owner.
+    ///
+    #[doc = ix_docs::create_position_pda!()]
+    #[accounts(
+        account(
+            docs = ["Position account to create. PDA of [pool, owner, POSITION_SEED, index]."],
+            name = "position",
+            flags(writable),
+            checks(owner = "system"),
+        ),
+        account(
+            docs = ["Pool the position will belong to."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Owner of the position. Pays for position account."],
+            name = "owner",
+            flags(writable, signer),
+        ),
+        program(
+            docs = ["System Program."],
+            id = "system",
+        ),
+    )]
+    CreatePositionPda {
+        /// Position type: POSITION_TYPE_CLASSIC or POSITION_TYPE_TRADING
+        position_type: u8,
+        /// Index of the Position among owner's Positions in the Pool.
+        index: u8,
+    },
+
+    // 46
+    /// Set number of extra collateral, borrow and reward records the Position can hold on top of
+    /// MAX_DEPOSITS, MAX_BORROWS and MAX_REWARDS and number of fixed-rate loan records (these are
+    /// stored in the extension only). Account is reallocated and its rent exempt
+    /// balance is topped up by the owner or returned to the owner when Position shrinks. Records
+    /// being removed must be unused. Account can grow by MAX_PERMITTED_DATA_INCREASE bytes at most
+    /// per one instruction.
+    ///
+    #[doc = ix_docs::resize_position!()]
+    #[accounts(
+        account(
+            docs = ["Position account to resize."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Owner of the position. Pays for increased account size."],
+            name = "owner",
+            flags(writable, signer),
+        ),
+        program(
+            docs = ["System Program."],
+            id = "system",
+        ),
+    )]
+    ResizePosition {
+        /// Number of collateral records in addition to MAX_DEPOSITS
+        extra_deposits: u8,
+        /// Number of borrow records in addition to MAX_BORROWS
+        extra_borrows: u8,
+        /// Number of reward records in addition to MAX_REWARDS
+        extra_rewards: u8,
+        /// Number of fixed-rate loan records
+        extra_fixed_loans: u8,
+    },
+
+    // 47
+    /// Add, update or remove Position delegate. Delegate is an operator which can act on behalf
+    /// of the owner according to granted permissions (see DELEGATE_PERMISSION_*): repay with
+    /// collateral, lock collateral, borrow to owner's wallets within delegate's allowance and
+    /// unlock collateral to owner's wallets only. Setting existing delegate resets its allowance.
+    /// Zero `permissions` removes the delegate. Position can have up to MAX_DELEGATES delegates.
+    ///
+    #[doc = ix_docs::set_position_delegate!()]
+    #[accounts(
+        account(
+            docs = ["Position account to set delegate for."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Owner of the position"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Delegate's authority"],
+            name = "delegate",
+        ),
+    )]
+    SetPositionDelegate {
+        /// Bit set of DELEGATE_PERMISSION_* flags. 0 - remove the delegate.
+        permissions: u8,
+        /// Market value (in cents of quote currency) the delegate can borrow. Each delegate's
+        /// borrow consumes it. Owner's own borrows don't. Max is MAX_DELEGATE_BORROW_ALLOWANCE.
+        borrow_allowance: u64,
+    },
+
+    // 48
+    /// Propose new owner for the Position. Ownership is transferred once the new owner accepts it
+    /// with AcceptPositionOwnership. Proposing current owner cancels transfer in progress.
+    ///
+    #[doc = ix_docs::transfer_position_ownership!()]
+    #[accounts(
+        account(
+            docs = ["Position account to transfer."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Current owner of the position"],
+            name = "owner",
+            flags(signer),
+        ),
+        account(
+            docs = ["Proposed owner of the position"],
+            name = "new_owner",
+        ),
+    )]
+    TransferPositionOwnership,
+
+    // 49
+    /// Accept Position ownership proposed by TransferPositionOwnership. Position must be refreshed
+    /// in the same transaction so that all rewards are accrued at the moment of transfer. Reward
+    /// records stay with the Position thus unclaimed rewards become claimable by the new owner.
+    /// Delegates set by previous owner are removed.
+    ///
+    #[doc = ix_docs::accept_position_ownership!()]
+    #[accounts(
+        account(
+            docs = ["Position account to accept. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["New owner of the position"],
+            name = "new_owner",
+            flags(signer),
+        ),
+    )]
+    AcceptPositionOwnership,
+
+    // 50
+    /// Borrow liquidity from the pool at Reserve's fixed rate (see AlterReserve) for `term_sec`.
+    /// Interest accrues linearly on the borrowed amount at the rate locked at origination. Loan is
+    /// recorded in Position's extension (see ResizePosition) and is repaid with RepayFixed. When
+    /// it's not repaid within Reserve's grace period after maturity, the loan can be liquidated
+    /// in full regardless of Position health. Position can have only one fixed-rate loan per
+    /// Reserve.
+    ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
+    ///
+    #[doc = ix_docs::borrow_fixed!()]
+    #[accounts(
+        account(
+            docs = ["Borrowers Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet which holds liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["User's SPL token wallet which will receive borrowed liquidity tokens"],
+            name = "destination_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority"],
+            name = "curator_fee_receiver",
+            flags(writable),
+        ),
+        account(
+            docs = ["Position owner or its delegate who borrow"],
+            name = "borrower",
+            flags(signer),
+        ),
+        account(
+            docs = ["Reserve account to borrow from. Refreshed."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Pool borrow happens in."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Curator of the pool."],
+            name = "curator",
+            checks(owner = "self", exempt),
+        ),
+        account(
+            docs = ["SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority"],
+            name = "texture_fee_receiver",
+            flags(writable),
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+        account(
+            docs = ["Liquidity tokens mint."],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program - either classic or 2022"],
+            name = "token_program",
+        ),
+    )]
+    BorrowFixed {
+        /// Amount of liquidity to borrow.
+        /// u64::MAX - uses 100% of user's borrowing power taking in to account Reserve's liquidity
+        /// limitations.
+        amount: u64,
+        /// Minimum amount of liquidity to receive, if borrowing at 100% of borrowing power
+        slippage_limit: u64,
+        /// Loan term in seconds. Must not exceed Reserve's `fixed_max_term_sec`.
+        term_sec: u32,
+        /// Max fixed rate (in basis points) borrower agrees to. Protects from rate change by
+        /// Curator.
+        max_rate_bps: u32,
+        /// Arbitrary bytes stored along with the loan.
+        memo: [u8; BORROW_MEMO_LEN],
+    },
+
+    // 51
+    /// Repay fixed-rate loan taken with BorrowFixed. Loan can be repaid partially or in full at
+    /// any time.
+    ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
+    ///
+    #[doc = ix_docs::repay_fixed!()]
+    #[accounts(
+        account(
+            docs = ["Borrowers Position account. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["User's SPL token wallet with liquidity tokens to be used as repayment"],
+            name = "source_liquidity_wallet",
+            flags(writable),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet to return liquidity to. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Authority to transfer funds from `source_liquidity_wallet`"],
+            name = "user_authority",
+            flags(signer),
+        ),
+        account(
+            docs = ["Reserve account the loan was taken from. Refreshed."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Liquidity tokens mint."],
+            name = "liquidity_mint",
+        ),
+        program(
+            docs = ["SPL Token program - either classic or 2022"],
+            name = "token_program",
+        ),
+    )]
+    RepayFixed {
+        /// Amount of principal token to repay. Set to u64::MAX to repay all borrowed amount.
+        amount: u64,
+    },
+
+    // 52
+    /// Initialize contract controlled SPL wallet which serves as Pool's insurance fund for
+    /// particular liquidity token. Fund is replenished by ClaimInsuranceFees and used by
+    /// WriteOffBadDebt to cover losses of all Pool's Reserves with that liquidity token.
+    ///
+    #[doc = ix_docs::init_insurance_fund!()]
+    #[accounts(
+        account(
+            docs = ["Insurance fund account to initialize. Uninitialized. PDA"],
+            name = "insurance_fund",
+            flags(writable),
+            checks(owner = "system"),
+            pda_seeds = [pool, liquidity_mint, crate::pda::INSURANCE_FUND_SEED],
+        ),
+        account(
+            docs = ["Liquidity tokens mint."],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Pool to init insurance fund for."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = [
+            "Authority who can manage pool."
+            ],
+            name = "curator_pools_authority",
+            flags(writable, signer),
+        ),
+        account(
+            docs = ["Curator account."],
+            name = "curator",
+            checks(owner = "self", exempt),
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "token_program",
+        ),
+        program(
+            docs = ["System Program."],
+            id = "system",
+        ),
+    )]
+    InitInsuranceFund,
+
+    // 53
+    /// Permissionless IX to transfer accrued insurance fee from Reserve's liquidity supply to
+    /// Pool's insurance fund. Insurance fund must be initialized with InitInsuranceFund.
+    ///
+    #[doc = ix_docs::claim_insurance_fees!()]
+    #[accounts(
+        account(
+            docs = ["Reserve account to claim insurance fees from. Refreshed."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with Reserve's liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Pool's insurance fund for Reserve's liquidity mint. PDA."],
+            name = "insurance_fund",
+            flags(writable),
+            pda_seeds = [pool, liquidity_mint, crate::pda::INSURANCE_FUND_SEED],
+        ),
+        account(
+            docs = ["Pool the Reserve belongs to."],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Liquidity tokens mint"],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "token_program",
+        ),
+    )]
+    ClaimInsuranceFees,
+
+    // 54
+    /// Permissionless version of WriteOffBadDebt. Writes off all remaining debt of the Position in
+    /// the Reserve once all Position's collateral is gone. Pool must opt in to it via AlterPool
+    /// (see `auto_write_off_bad_debt` in PoolParams). Loss is covered from Pool's insurance fund the
+    /// same way as in WriteOffBadDebt. Liquidators are expected to append this IX right after
+    /// Liquidate which seizes the last collateral.
+    ///
+    /// Isolated Position (see RESERVE_TYPE_ISOLATED_COLLATERAL) must additionally pass its isolated
+    /// collateral Reserve as the last writable account.
+    ///
+    #[doc = ix_docs::socialize_loss!()]
+    #[accounts(
+        account(
+            docs = ["Pool to which bad debt position belongs"],
+            name = "pool",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Reserve account to write off bad debt in. Refreshed."],
+            name = "reserve",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Position without collateral. Refreshed."],
+            name = "position",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Contract managed SPL token wallet with Reserve's liquidity. PDA."],
+            name = "reserve_liquidity_supply",
+            flags(writable),
+            pda_seeds = [reserve, crate::pda::LIQUIDITY_SUPPLY_SEED],
+        ),
+        account(
+            docs = ["Pool's insurance fund for Reserve's liquidity mint. PDA."],
+            name = "insurance_fund",
+            flags(writable),
+            pda_seeds = [pool, liquidity_mint, crate::pda::INSURANCE_FUND_SEED],
+        ),
+        account(
+            docs = ["Liquidity tokens mint"],
+            name = "liquidity_mint",
+        ),
+        account(
+            docs = ["Contract's authority. PDA."],
+            name = "program_authority",
+            pda_seeds = [crate::pda::AUTHORITY_SEED],
+        ),
+        program(
+            docs = ["SPL Token program"],
+            name = "token_program",
+        ),
+    )]
+    SocializeLoss,
+
+    // 55
+    /// Propose e-mode category change which can't be applied right away via SetEmodeCategory i.e.
+    /// raises time locked LTVs. Category LTVs are locked for the same time as Reserve's ones.
+    /// Only one proposal per Pool can be pending, new one replaces it. Category ID 0 resets pending
+    /// proposal.
+    ///
+    #[doc = ix_docs::propose_emode_category!()]
+    #[accounts(
+        account(
+            docs = ["Pool account to configure e-mode category in."],
+            name = "pool",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Pools authority configured in `curator` account."],
+            name = "curator_pools_authority",
+            flags(signer),
+        ),
+        account(
+            docs = ["Curator account."],
+            name = "curator",
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Global config account"],
+            name = "texture_config",
+            checks(owner = "self"),
+            addr = crate::TEXTURE_CONFIG_ID,
+        ),
+    )]
+    ProposeEmodeCategory {
+        /// Category ID in range 1..=MAX_EMODE_CATEGORIES. 0 - reset pending proposal.
+        category_id: u8,
+        category: EmodeCategory,
+    },
+
+    // 56
+    /// Apply pending e-mode category proposal once its time lock expired.
+    ///
+    #[doc = ix_docs::apply_emode_category_proposal!()]
+    #[accounts(
+        account(
+            docs = ["Pool account to configure e-mode category in."],
+            name = "pool",
+            flags(writable),
+            checks(owner = "self"),
+        ),
+        account(
+            docs = ["Pools authority configured in `curator` account."],
+            name = "curator_pools_authority",
+            flags(signer),
+        ),
+        account(
+            docs = ["Curator account."],
+            name = "curator",
+            checks(owner = "self"),
+        ),
+    )]
+    ApplyEmodeCategoryProposal,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
@@ -429,6 +429,21 @@ pub struct AlterReserve {
     pub params: ReserveConfig,
     pub mode: u8,
     pub flash_loans_enabled: u8,
+    /// What to do with liquidity supply surplus found by `SyncReserve`. SURPLUS_TO_LPS, etc.
+    pub surplus_destination: u8,
+    /// 1 - positions with isolated collateral can borrow from this Reserve.
+    pub isolation_borrowable: u8,
+    /// Max value (in whole units of quote currency) of debt backed by this Reserve when it is of
+    /// RESERVE_TYPE_ISOLATED_COLLATERAL type. 0 - no limit.
+    pub isolated_debt_ceiling: u64,
+    /// Annual interest rate (in basis points) of fixed-rate loans originated from this Reserve.
+    /// 0 - fixed-rate borrowing is disabled.
+    pub fixed_rate_bps: u32,
+    /// Maximum term (in seconds) of fixed-rate loan.
+    pub fixed_max_term_sec: u32,
+    /// Time (in seconds) after maturity of fixed-rate loan till it can be liquidated regardless
+    /// of Position health.
+    pub fixed_grace_period_sec: u32,
 }
 impl AlterReserve {
     #[track_caller]
@@ -444,6 +459,12 @@ impl AlterReserve {
             params,
             mode,
             flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable,
+            isolated_debt_ceiling,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
         } = self;
         #[cfg(not(feature = "program-id-manually"))]
         let program_id = SUPER_LENDY_ID;
@@ -483,6 +504,12 @@ impl AlterReserve {
             params,
             mode,
             flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable,
+            isolated_debt_ceiling,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
         };
         solana_program::instruction::Instruction::new_with_borsh(
             program_id,
@@ -500,7 +527,8 @@ pub struct RefreshReserve {
     pub reserve: solana_program::pubkey::Pubkey,
     ///Price feed account to get market price for liquidity currency.
     pub market_price_feed: solana_program::pubkey::Pubkey,
-    ///Interest Rate Model account.
+    ///Interest Rate Model account. Curve account for Reserve with external IRM.
+    ///Super-lendy program id for Reserve with built-in IRM.
     pub irm: solana_program::pubkey::Pubkey,
 }
 impl RefreshReserve {
@@ -955,7 +983,7 @@ pub struct LockCollateral {
     pub position: solana_program::pubkey::Pubkey,
     ///User's SPL token wallet which holds LP tokens to be locked as collateral
     pub source_lp_wallet: solana_program::pubkey::Pubkey,
-    ///Position owner and also authority for source_lp_wallet
+    ///Position owner or its delegate. Also authority for source_lp_wallet
     pub owner: solana_program::pubkey::Pubkey,
     ///Reserve account which is the source of LP tokens being deposited. Refreshed.
     pub reserve: solana_program::pubkey::Pubkey,
@@ -1041,7 +1069,7 @@ pub struct UnlockCollateral {
     pub position: solana_program::pubkey::Pubkey,
     ///User's SPL token wallet which will receive unlocked LP tokens
     pub destination_lp_wallet: solana_program::pubkey::Pubkey,
-    ///Position owner
+    ///Position owner or its delegate
     pub owner: solana_program::pubkey::Pubkey,
     ///Reserve account which is the source of LP tokens being deposited. Refreshed.
     pub reserve: solana_program::pubkey::Pubkey,
@@ -1137,7 +1165,7 @@ pub struct Borrow {
     pub destination_liquidity_wallet: solana_program::pubkey::Pubkey,
     ///SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority
     pub curator_fee_receiver: solana_program::pubkey::Pubkey,
-    ///Position owner who borrow
+    ///Position owner or its delegate who borrow
     pub borrower: solana_program::pubkey::Pubkey,
     ///Reserve account which is the source of LP tokens being deposited. Refreshed.
     pub reserve: solana_program::pubkey::Pubkey,
@@ -1392,6 +1420,10 @@ pub struct WriteOffBadDebt {
     pub curator_pools_authority: solana_program::pubkey::Pubkey,
     ///Curator account.
     pub curator: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program
+    pub token_program: solana_program::pubkey::Pubkey,
     /// principal (borrowed as bad debt) token amount to write off
     pub amount: u64,
 }
@@ -1406,10 +1438,36 @@ impl WriteOffBadDebt {
             position,
             curator_pools_authority,
             curator,
+            liquidity_mint,
+            token_program,
             amount,
         } = self;
         #[cfg(not(feature = "program-id-manually"))]
         let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (insurance_fund, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                pool.as_ref(),
+                #[allow(clippy::useless_asref)]
+                liquidity_mint.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::INSURANCE_FUND_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
         #[allow(unused_mut)]
         let mut accounts = vec![];
         accounts
@@ -1430,6 +1488,38 @@ impl WriteOffBadDebt {
             .extend([
                 solana_program::instruction::AccountMeta::new_readonly(curator, false),
             ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(insurance_fund, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
         let ix = SuperLendyInstruction::WriteOffBadDebt {
             amount,
         };
@@ -1533,10 +1623,7 @@ impl Liquidate {
             ]);
         accounts
             .extend([
-                solana_program::instruction::AccountMeta::new_readonly(
-                    collateral_reserve,
-                    false,
-                ),
+                solana_program::instruction::AccountMeta::new(collateral_reserve, false),
             ]);
         accounts
             .extend([
@@ -1558,6 +1645,13 @@ impl Liquidate {
                     false,
                 ),
             ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
         accounts
             .extend([
                 solana_program::instruction::AccountMeta::new_readonly(
@@ -2299,6 +2393,7 @@ pub struct FlashRepay {
     ///SPL Token program
     pub token_program: solana_program::pubkey::Pubkey,
     /// Amount of liquidity to flash repay. Must be the same as in paired FlashBorrow IX.
+    /// Reserve's flash loan fee is charged on top of that amount.
     pub amount: u64,
 }
 impl FlashRepay {
@@ -2365,6 +2460,13 @@ impl FlashRepay {
                     false,
                 ),
             ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
         let ix = SuperLendyInstruction::FlashRepay {
             amount,
         };
@@ -2759,985 +2861,2325 @@ impl SetLpMetadata {
         )
     }
 }
-/// [SuperLendyInstruction::CreateTextureConfig] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreateTextureConfigAccountIndexes {
-    pub texture_config: usize,
-    pub owner: usize,
+///[SuperLendyInstruction::SyncReserve] Builder struct
+pub struct SyncReserve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Reserve account to sync.
+    pub reserve: solana_program::pubkey::Pubkey,
 }
-impl CreateTextureConfigAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const TEXTURE_CONFIG: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            texture_config: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+impl SyncReserve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, reserve } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::SyncReserve {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for CreateTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[SuperLendyInstruction::SetEmodeCategory] Builder struct
+pub struct SetEmodeCategory {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Pool account to configure e-mode category in.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: solana_program::pubkey::Pubkey,
+    ///Curator account.
+    pub curator: solana_program::pubkey::Pubkey,
+    /// Category ID in range 1..=MAX_EMODE_CATEGORIES
+    pub category_id: u8,
+    pub category: EmodeCategory,
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl SetEmodeCategory {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            pool,
+            curator_pools_authority,
+            curator,
+            category_id,
+            category,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(pool, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    curator_pools_authority,
+                    true,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::SetEmodeCategory {
+            category_id,
+            category,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for CreateTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::SetPositionEmode] Builder struct
+pub struct SetPositionEmode {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Pool the position belongs to.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Owner of the position
+    pub owner: solana_program::pubkey::Pubkey,
+    /// E-mode category ID configured in the Pool. 0 - opt out of e-mode.
+    pub category_id: u8,
 }
-impl TryFrom<Vec<u8>> for CreateTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl SetPositionEmode {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            pool,
+            owner,
+            category_id,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = SuperLendyInstruction::SetPositionEmode {
+            category_id,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::AlterTextureConfig] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterTextureConfigAccountIndexes {
-    pub texture_config: usize,
-    pub owner: usize,
+///[SuperLendyInstruction::ClaimCuratorLiquidationFees] Builder struct
+pub struct ClaimCuratorLiquidationFees {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Reserve account to claim liquidation fees from.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Pool.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Curator account.
+    pub curator: solana_program::pubkey::Pubkey,
+    ///SPL token wallet to receive claimed LP tokens. Must be ATA from curator.fees_authority.
+    pub fee_receiver: solana_program::pubkey::Pubkey,
 }
-impl AlterTextureConfigAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const TEXTURE_CONFIG: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            texture_config: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for AlterTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for AlterTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for AlterTextureConfigAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl ClaimCuratorLiquidationFees {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            reserve,
+            pool,
+            curator,
+            fee_receiver,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(fee_receiver, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::ClaimCuratorLiquidationFees {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::CreateCurator] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreateCuratorAccountIndexes {
-    pub curator: usize,
-    pub texture_config: usize,
-    pub global_config_owner: usize,
-}
-impl CreateCuratorAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const CURATOR: usize = 0usize;
-    pub const TEXTURE_CONFIG: usize = 1usize;
-    pub const GLOBAL_CONFIG_OWNER: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            curator: iter.next().unwrap(),
-            texture_config: iter.next().unwrap(),
-            global_config_owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            global_config_owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
+///[SuperLendyInstruction::ClaimTextureLiquidationFees] Builder struct
+pub struct ClaimTextureLiquidationFees {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Reserve account to claim liquidation fees from.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///SPL token wallet to receive claimed LP tokens. Must be ATA from [TextureConfig.fees_authority]
+    pub fee_receiver: solana_program::pubkey::Pubkey,
 }
-impl<'a> TryFrom<&'a [u8]> for CreateCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl ClaimTextureLiquidationFees {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            reserve,
+            fee_receiver,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(fee_receiver, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::ClaimTextureLiquidationFees {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[SuperLendyInstruction::LiquidateAndRedeem] Builder struct
+pub struct LiquidateAndRedeem {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///SPL token wallet to get repayment liquidity from.
+    pub repayment_source_wallet: solana_program::pubkey::Pubkey,
+    ///SPL token wallet to receive LP tokens when redeem is not possible.
+    pub destination_lp_wallet: solana_program::pubkey::Pubkey,
+    ///SPL token wallet to receive collateral Reserve's liquidity.
+    pub destination_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
+    pub principal_reserve: solana_program::pubkey::Pubkey,
+    ///Reserve account to take collateral from. Refreshed.
+    pub collateral_reserve: solana_program::pubkey::Pubkey,
+    ///Borrower Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Liquidator's authority which controls `repayment_source_wallet`
+    pub liquidator: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in principal Reserve.
+    pub principal_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in collateral Reserve.
+    pub collateral_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage principal tokens
+    pub principal_token_program: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage collateral Reserve's liquidity
+    pub collateral_liquidity_token_program: solana_program::pubkey::Pubkey,
+    /// liquidity amount to repay
+    pub liquidity_amount: u64,
 }
-impl<const N: usize> TryFrom<[u8; N]> for CreateCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for CreateCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-/// [SuperLendyInstruction::AlterCurator] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterCuratorAccountIndexes {
-    pub curator: usize,
-    pub owner: usize,
-}
-impl AlterCuratorAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const CURATOR: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            curator: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for AlterCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for AlterCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for AlterCuratorAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl LiquidateAndRedeem {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            repayment_source_wallet,
+            destination_lp_wallet,
+            destination_liquidity_wallet,
+            principal_reserve,
+            collateral_reserve,
+            position,
+            liquidator,
+            principal_reserve_liquidity_mint,
+            collateral_reserve_liquidity_mint,
+            principal_token_program,
+            collateral_liquidity_token_program,
+            liquidity_amount,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (principal_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                principal_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (collateral_reserve_lp_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (collateral_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (collateral_reserve_lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    repayment_source_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    destination_lp_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    destination_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(principal_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    principal_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(collateral_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_lp_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_lp_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(liquidator, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    principal_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    collateral_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    principal_token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    collateral_liquidity_token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::LiquidateAndRedeem {
+            liquidity_amount,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::CreatePool] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreatePoolAccountIndexes {
-    pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
+///[SuperLendyInstruction::RepayWithCollateral] Builder struct
+pub struct RepayWithCollateral {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Borrower's Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Reserve account the Position borrowed from and locked collateral in. Refreshed.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Position owner or its delegate
+    pub owner: solana_program::pubkey::Pubkey,
+    /// Amount of liquidity to repay. u64::MAX - repay as much as collateral allows.
+    pub amount: u64,
 }
-impl CreatePoolAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const POOL: usize = 0usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
-    pub const CURATOR: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for CreatePoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for CreatePoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for CreatePoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-/// [SuperLendyInstruction::AlterPool] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterPoolAccountIndexes {
-    pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-}
-impl AlterPoolAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const POOL: usize = 0usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
-    pub const CURATOR: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for AlterPoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterPoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for AlterPoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for AlterPoolAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl RepayWithCollateral {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            reserve,
+            owner,
+            amount,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(lp_mint, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::RepayWithCollateral {
+            amount,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::CreateReserve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreateReserveAccountIndexes {
-    pub reserve: usize,
-    pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub liquidity_mint: usize,
-    pub liquidity_supply: usize,
-    pub lp_mint: usize,
-    pub collateral_supply: usize,
-    pub market_price_feed: usize,
-    pub program_authority: usize,
-    pub lp_token_program: usize,
-    pub liquidity_token_program: usize,
-    pub system_program: usize,
+///[SuperLendyInstruction::RepayWithCollateralSwap] Builder struct
+pub struct RepayWithCollateralSwap {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Borrower's Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Reserve account to take collateral from. Refreshed.
+    pub collateral_reserve: solana_program::pubkey::Pubkey,
+    ///Owner's SPL token wallet to receive redeemed collateral liquidity. Swap input.
+    pub collateral_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Reserve account to repay principal tokens to. Refreshed.
+    pub principal_reserve: solana_program::pubkey::Pubkey,
+    ///Owner's SPL token wallet to receive principal liquidity. Swap output.
+    pub principal_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Position owner
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in collateral Reserve.
+    pub collateral_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in principal Reserve.
+    pub principal_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage collateral Reserve's liquidity
+    pub collateral_liquidity_token_program: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage principal tokens
+    pub principal_token_program: solana_program::pubkey::Pubkey,
+    ///Swap program. Must be the one set in TextureConfig.swap_program
+    pub swap_program: solana_program::pubkey::Pubkey,
+    /// Amount of collateral LP tokens to unlock and redeem for the swap.
+    pub lp_amount: u64,
+    /// Minimum amount of principal liquidity the swap must produce.
+    pub min_repay_amount: u64,
+    /// Instruction data passed to the swap program as is.
+    pub swap_data: Vec<u8>,
 }
-impl CreateReserveAccountIndexes {
-    pub const COUNT: usize = 13usize;
-    pub const RESERVE: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 2usize;
-    pub const CURATOR: usize = 3usize;
-    pub const LIQUIDITY_MINT: usize = 4usize;
-    pub const LIQUIDITY_SUPPLY: usize = 5usize;
-    pub const LP_MINT: usize = 6usize;
-    pub const COLLATERAL_SUPPLY: usize = 7usize;
-    pub const MARKET_PRICE_FEED: usize = 8usize;
-    pub const PROGRAM_AUTHORITY: usize = 9usize;
-    pub const LP_TOKEN_PROGRAM: usize = 10usize;
-    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 11usize;
-    pub const SYSTEM_PROGRAM: usize = 12usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            reserve: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            liquidity_supply: iter.next().unwrap(),
-            lp_mint: iter.next().unwrap(),
-            collateral_supply: iter.next().unwrap(),
-            market_price_feed: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            lp_token_program: iter.next().unwrap(),
-            liquidity_token_program: iter.next().unwrap(),
-            system_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            collateral_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            market_price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            system_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for CreateReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for CreateReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl RepayWithCollateralSwap {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            collateral_reserve,
+            collateral_liquidity_wallet,
+            principal_reserve,
+            principal_liquidity_wallet,
+            owner,
+            collateral_reserve_liquidity_mint,
+            principal_reserve_liquidity_mint,
+            collateral_liquidity_token_program,
+            principal_token_program,
+            swap_program,
+            lp_amount,
+            min_repay_amount,
+            swap_data,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (collateral_reserve_lp_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (collateral_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (collateral_reserve_lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                collateral_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (principal_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                principal_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(collateral_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_lp_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_reserve_lp_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    collateral_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(principal_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    principal_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    principal_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    collateral_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    principal_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    collateral_liquidity_token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    principal_token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    swap_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::RepayWithCollateralSwap {
+            lp_amount,
+            min_repay_amount,
+            swap_data,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl TryFrom<Vec<u8>> for CreateReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::SwapCollateral] Builder struct
+pub struct SwapCollateral {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Borrower's Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Reserve account to take collateral from. Refreshed.
+    pub source_reserve: solana_program::pubkey::Pubkey,
+    ///Owner's SPL token wallet to receive redeemed source liquidity. Swap input.
+    pub source_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Reserve account to lock new collateral in. Refreshed.
+    pub target_reserve: solana_program::pubkey::Pubkey,
+    ///Owner's SPL token wallet to receive target liquidity. Swap output.
+    pub target_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Position owner
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in source Reserve.
+    pub source_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint in target Reserve.
+    pub target_reserve_liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage source Reserve's liquidity
+    pub source_liquidity_token_program: solana_program::pubkey::Pubkey,
+    ///SPL Token program used to manage target Reserve's liquidity
+    pub target_liquidity_token_program: solana_program::pubkey::Pubkey,
+    ///Swap program. Must be the one set in TextureConfig.swap_program
+    pub swap_program: solana_program::pubkey::Pubkey,
+    /// Amount of source collateral LP tokens to unlock and redeem for the swap.
+    pub lp_amount: u64,
+    /// Minimum amount of target liquidity the swap must produce.
+    pub min_target_amount: u64,
+    /// Instruction data passed to the swap program as is.
+    pub swap_data: Vec<u8>,
 }
-/// [SuperLendyInstruction::AlterReserve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterReserveAccountIndexes {
-    pub reserve: usize,
-    pub pool: usize,
-    pub market_price_feed: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub texture_config: usize,
-}
-impl AlterReserveAccountIndexes {
-    pub const COUNT: usize = 6usize;
-    pub const RESERVE: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const MARKET_PRICE_FEED: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
-    pub const TEXTURE_CONFIG: usize = 5usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            reserve: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            market_price_feed: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            texture_config: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            market_price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+impl SwapCollateral {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            source_reserve,
+            source_liquidity_wallet,
+            target_reserve,
+            target_liquidity_wallet,
+            owner,
+            source_reserve_liquidity_mint,
+            target_reserve_liquidity_mint,
+            source_liquidity_token_program,
+            target_liquidity_token_program,
+            swap_program,
+            lp_amount,
+            min_target_amount,
+            swap_data,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (source_reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                source_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (source_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                source_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (source_reserve_lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                source_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (target_reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                target_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (target_reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                target_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (target_reserve_lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                target_reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(source_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_reserve_lp_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(target_reserve, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    target_reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    target_reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    target_reserve_lp_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    target_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    source_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    target_reserve_liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    source_liquidity_token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    target_liquidity_token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    swap_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::SwapCollateral {
+            lp_amount,
+            min_target_amount,
+            swap_data,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for AlterReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[SuperLendyInstruction::DepositAndLockCollateral] Builder struct
+pub struct DepositAndLockCollateral {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to lock collateral in.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Reserve account to deposit to. Must be refreshed beforehand.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Source SPL Token wallet to transfer liquidity from.
+    pub source_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Position owner or its delegate. Also authority for source_liquidity_wallet
+    pub owner: solana_program::pubkey::Pubkey,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: solana_program::pubkey::Pubkey,
+    /// Amount of liquidity to deposit. When u64::MAX is passed - all liquidity from provided
+    /// wallet will be deposited.
+    pub amount: u64,
+    /// Arbitrary bytes stored along with that Collateral. Same as in LockCollateral.
+    pub memo: [u8; COLLATERAL_MEMO_LEN],
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl DepositAndLockCollateral {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            reserve,
+            source_liquidity_wallet,
+            liquidity_mint,
+            owner,
+            liquidity_token_program,
+            amount,
+            memo,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(lp_mint, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::DepositAndLockCollateral {
+            amount,
+            memo,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for AlterReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::UnlockAndWithdrawLiquidity] Builder struct
+pub struct UnlockAndWithdrawLiquidity {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to unlock collateral from
+    pub position: solana_program::pubkey::Pubkey,
+    ///Reserve account to withdraw from. Must be refreshed beforehand.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///User's SPL Token wallet to receive the liquidity.
+    pub destination_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Position owner or its delegate
+    pub owner: solana_program::pubkey::Pubkey,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: solana_program::pubkey::Pubkey,
+    /// Amount of LP tokens user wants to unlock and redeem.
+    /// When u64::MAX is passed - maximum possible amount of LPs from user's position will be unlocked.
+    pub lp_amount: u64,
 }
-impl TryFrom<Vec<u8>> for AlterReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl UnlockAndWithdrawLiquidity {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            reserve,
+            destination_liquidity_wallet,
+            liquidity_mint,
+            owner,
+            liquidity_token_program,
+            lp_amount,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_collateral_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::COLLATERAL_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (lp_mint, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LP_TOKEN_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_collateral_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(lp_mint, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    destination_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    spl_token::ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::UnlockAndWithdrawLiquidity {
+            lp_amount,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::RefreshReserve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct RefreshReserveAccountIndexes {
-    pub reserve: usize,
-    pub market_price_feed: usize,
-    pub irm: usize,
-    pub texture_config: usize,
+///[SuperLendyInstruction::CreatePositionPda] Builder struct
+pub struct CreatePositionPda {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to create. PDA of [pool, owner, POSITION_SEED, index].
+    pub position: solana_program::pubkey::Pubkey,
+    ///Pool the position will belong to.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Owner of the position. Pays for position account.
+    pub owner: solana_program::pubkey::Pubkey,
+    /// Position type: POSITION_TYPE_CLASSIC or POSITION_TYPE_TRADING
+    pub position_type: u8,
+    /// Index of the Position among owner's Positions in the Pool.
+    pub index: u8,
 }
-impl RefreshReserveAccountIndexes {
-    pub const COUNT: usize = 4usize;
-    pub const RESERVE: usize = 0usize;
-    pub const MARKET_PRICE_FEED: usize = 1usize;
-    pub const IRM: usize = 2usize;
-    pub const TEXTURE_CONFIG: usize = 3usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            reserve: iter.next().unwrap(),
-            market_price_feed: iter.next().unwrap(),
-            irm: iter.next().unwrap(),
-            texture_config: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            market_price_feed: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            irm: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+impl CreatePositionPda {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            pool,
+            owner,
+            position_type,
+            index,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::CreatePositionPda {
+            position_type,
+            index,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for RefreshReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+///[SuperLendyInstruction::ResizePosition] Builder struct
+pub struct ResizePosition {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to resize.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Owner of the position. Pays for increased account size.
+    pub owner: solana_program::pubkey::Pubkey,
+    /// Number of collateral records in addition to MAX_DEPOSITS
+    pub extra_deposits: u8,
+    /// Number of borrow records in addition to MAX_BORROWS
+    pub extra_borrows: u8,
+    /// Number of reward records in addition to MAX_REWARDS
+    pub extra_rewards: u8,
+    /// Number of fixed-rate loan records
+    pub extra_fixed_loans: u8,
+}
+impl ResizePosition {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            owner,
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::ResizePosition {
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RefreshReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+///[SuperLendyInstruction::SetPositionDelegate] Builder struct
+pub struct SetPositionDelegate {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to set delegate for.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Owner of the position
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Delegate's authority
+    pub delegate: solana_program::pubkey::Pubkey,
+    /// Bit set of DELEGATE_PERMISSION_* flags. 0 - remove the delegate.
+    pub permissions: u8,
+    /// Market value (in cents of quote currency) the delegate can borrow. Each delegate's
+    /// borrow consumes it. Owner's own borrows don't. Max is MAX_DELEGATE_BORROW_ALLOWANCE.
+    pub borrow_allowance: u64,
+}
+impl SetPositionDelegate {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            owner,
+            delegate,
+            permissions,
+            borrow_allowance,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(delegate, false),
+            ]);
+        let ix = SuperLendyInstruction::SetPositionDelegate {
+            permissions,
+            borrow_allowance,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for RefreshReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::TransferPositionOwnership] Builder struct
+pub struct TransferPositionOwnership {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to transfer.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Current owner of the position
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Proposed owner of the position
+    pub new_owner: solana_program::pubkey::Pubkey,
 }
-impl TryFrom<Vec<u8>> for RefreshReserveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl TransferPositionOwnership {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            owner,
+            new_owner,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(new_owner, false),
+            ]);
+        let ix = SuperLendyInstruction::TransferPositionOwnership {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::DepositLiquidity] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct DepositLiquidityAccountIndexes {
-    pub authority: usize,
-    pub source_liquidity_wallet: usize,
-    pub destination_lp_wallet: usize,
-    pub reserve: usize,
-    pub liquidity_supply: usize,
-    pub liquidity_mint: usize,
-    pub lp_mint: usize,
-    pub program_authority: usize,
-    pub lp_token_program: usize,
-    pub liquidity_token_program: usize,
+///[SuperLendyInstruction::AcceptPositionOwnership] Builder struct
+pub struct AcceptPositionOwnership {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Position account to accept. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///New owner of the position
+    pub new_owner: solana_program::pubkey::Pubkey,
 }
-impl DepositLiquidityAccountIndexes {
-    pub const COUNT: usize = 10usize;
-    pub const AUTHORITY: usize = 0usize;
-    pub const SOURCE_LIQUIDITY_WALLET: usize = 1usize;
-    pub const DESTINATION_LP_WALLET: usize = 2usize;
-    pub const RESERVE: usize = 3usize;
-    pub const LIQUIDITY_SUPPLY: usize = 4usize;
-    pub const LIQUIDITY_MINT: usize = 5usize;
-    pub const LP_MINT: usize = 6usize;
-    pub const PROGRAM_AUTHORITY: usize = 7usize;
-    pub const LP_TOKEN_PROGRAM: usize = 8usize;
-    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 9usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            authority: iter.next().unwrap(),
-            source_liquidity_wallet: iter.next().unwrap(),
-            destination_lp_wallet: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            liquidity_supply: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            lp_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            lp_token_program: iter.next().unwrap(),
-            liquidity_token_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_liquidity_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_lp_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for DepositLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DepositLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl AcceptPositionOwnership {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, position, new_owner } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(new_owner, true),
+            ]);
+        let ix = SuperLendyInstruction::AcceptPositionOwnership {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for DepositLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::BorrowFixed] Builder struct
+pub struct BorrowFixed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Borrowers Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///User's SPL token wallet which will receive borrowed liquidity tokens
+    pub destination_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority
+    pub curator_fee_receiver: solana_program::pubkey::Pubkey,
+    ///Position owner or its delegate who borrow
+    pub borrower: solana_program::pubkey::Pubkey,
+    ///Reserve account to borrow from. Refreshed.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Pool borrow happens in.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Curator of the pool.
+    pub curator: solana_program::pubkey::Pubkey,
+    ///SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority
+    pub texture_fee_receiver: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program - either classic or 2022
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Amount of liquidity to borrow.
+    /// u64::MAX - uses 100% of user's borrowing power taking in to account Reserve's liquidity
+    /// limitations.
+    pub amount: u64,
+    /// Minimum amount of liquidity to receive, if borrowing at 100% of borrowing power
+    pub slippage_limit: u64,
+    /// Loan term in seconds. Must not exceed Reserve's `fixed_max_term_sec`.
+    pub term_sec: u32,
+    /// Max fixed rate (in basis points) borrower agrees to. Protects from rate change by
+    /// Curator.
+    pub max_rate_bps: u32,
+    /// Arbitrary bytes stored along with the loan.
+    pub memo: [u8; BORROW_MEMO_LEN],
 }
-impl TryFrom<Vec<u8>> for DepositLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl BorrowFixed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            destination_liquidity_wallet,
+            curator_fee_receiver,
+            borrower,
+            reserve,
+            pool,
+            curator,
+            texture_fee_receiver,
+            liquidity_mint,
+            token_program,
+            amount,
+            slippage_limit,
+            term_sec,
+            max_rate_bps,
+            memo,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    destination_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    curator_fee_receiver,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(borrower, true),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    texture_fee_receiver,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::BorrowFixed {
+            amount,
+            slippage_limit,
+            term_sec,
+            max_rate_bps,
+            memo,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::WithdrawLiquidity] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct WithdrawLiquidityAccountIndexes {
-    pub authority: usize,
-    pub source_lp_wallet: usize,
-    pub destination_liquidity_wallet: usize,
-    pub reserve: usize,
-    pub liquidity_supply: usize,
-    pub liquidity_mint: usize,
-    pub lp_mint: usize,
-    pub program_authority: usize,
-    pub lp_token_program: usize,
-    pub liquidity_token_program: usize,
+///[SuperLendyInstruction::RepayFixed] Builder struct
+pub struct RepayFixed {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Borrowers Position account. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///User's SPL token wallet with liquidity tokens to be used as repayment
+    pub source_liquidity_wallet: solana_program::pubkey::Pubkey,
+    ///Authority to transfer funds from `source_liquidity_wallet`
+    pub user_authority: solana_program::pubkey::Pubkey,
+    ///Reserve account the loan was taken from. Refreshed.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program - either classic or 2022
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Amount of principal token to repay. Set to u64::MAX to repay all borrowed amount.
+    pub amount: u64,
 }
-impl WithdrawLiquidityAccountIndexes {
-    pub const COUNT: usize = 10usize;
-    pub const AUTHORITY: usize = 0usize;
-    pub const SOURCE_LP_WALLET: usize = 1usize;
-    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
-    pub const RESERVE: usize = 3usize;
-    pub const LIQUIDITY_SUPPLY: usize = 4usize;
-    pub const LIQUIDITY_MINT: usize = 5usize;
-    pub const LP_MINT: usize = 6usize;
-    pub const PROGRAM_AUTHORITY: usize = 7usize;
-    pub const LP_TOKEN_PROGRAM: usize = 8usize;
-    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 9usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            authority: iter.next().unwrap(),
-            source_lp_wallet: iter.next().unwrap(),
-            destination_liquidity_wallet: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            liquidity_supply: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            lp_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            lp_token_program: iter.next().unwrap(),
-            liquidity_token_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_lp_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_liquidity_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+impl RepayFixed {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            position,
+            source_liquidity_wallet,
+            user_authority,
+            reserve,
+            liquidity_mint,
+            token_program,
+            amount,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    source_liquidity_wallet,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    user_authority,
+                    true,
+                ),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::RepayFixed {
+            amount,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for WithdrawLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[SuperLendyInstruction::InitInsuranceFund] Builder struct
+pub struct InitInsuranceFund {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///Pool to init insurance fund for.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Authority who can manage pool.
+    pub curator_pools_authority: solana_program::pubkey::Pubkey,
+    ///Curator account.
+    pub curator: solana_program::pubkey::Pubkey,
+    ///SPL Token program
+    pub token_program: solana_program::pubkey::Pubkey,
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WithdrawLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl InitInsuranceFund {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            liquidity_mint,
+            pool,
+            curator_pools_authority,
+            curator,
+            token_program,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (insurance_fund, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                pool.as_ref(),
+                #[allow(clippy::useless_asref)]
+                liquidity_mint.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::INSURANCE_FUND_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(insurance_fund, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    curator_pools_authority,
+                    true,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::InitInsuranceFund {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for WithdrawLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::ClaimInsuranceFees] Builder struct
+pub struct ClaimInsuranceFees {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Reserve account to claim insurance fees from. Refreshed.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Pool the Reserve belongs to.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program
+    pub token_program: solana_program::pubkey::Pubkey,
 }
-impl TryFrom<Vec<u8>> for WithdrawLiquidityAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-/// [SuperLendyInstruction::CreatePosition] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreatePositionAccountIndexes {
-    pub position: usize,
-    pub pool: usize,
-    pub owner: usize,
-}
-impl CreatePositionAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const POSITION: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const OWNER: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            position: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for CreatePositionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePositionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl ClaimInsuranceFees {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            reserve,
+            pool,
+            liquidity_mint,
+            token_program,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (insurance_fund, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                pool.as_ref(),
+                #[allow(clippy::useless_asref)]
+                liquidity_mint.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::INSURANCE_FUND_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(insurance_fund, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::ClaimInsuranceFees {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for CreatePositionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[SuperLendyInstruction::SocializeLoss] Builder struct
+pub struct SocializeLoss {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Pool to which bad debt position belongs
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Reserve account to write off bad debt in. Refreshed.
+    pub reserve: solana_program::pubkey::Pubkey,
+    ///Position without collateral. Refreshed.
+    pub position: solana_program::pubkey::Pubkey,
+    ///Liquidity tokens mint
+    pub liquidity_mint: solana_program::pubkey::Pubkey,
+    ///SPL Token program
+    pub token_program: solana_program::pubkey::Pubkey,
 }
-impl TryFrom<Vec<u8>> for CreatePositionAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl SocializeLoss {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            pool,
+            reserve,
+            position,
+            liquidity_mint,
+            token_program,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        let (reserve_liquidity_supply, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                reserve.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::LIQUIDITY_SUPPLY_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (insurance_fund, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                #[allow(clippy::useless_asref)]
+                pool.as_ref(),
+                #[allow(clippy::useless_asref)]
+                liquidity_mint.as_ref(),
+                #[allow(clippy::useless_asref)]
+                crate::pda::INSURANCE_FUND_SEED.as_ref(),
+            ],
+            &program_id,
+        );
+        let (program_authority, _) = solana_program::pubkey::Pubkey::find_program_address(
+            &[#[allow(clippy::useless_asref)] crate::pda::AUTHORITY_SEED.as_ref()],
+            &program_id,
+        );
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(pool, false),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(reserve, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(position, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(
+                    reserve_liquidity_supply,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(insurance_fund, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    liquidity_mint,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_authority,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    token_program,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::SocializeLoss {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [SuperLendyInstruction::ClosePosition] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct ClosePositionAccountIndexes {
-    pub position: usize,
-    pub owner: usize,
+///[SuperLendyInstruction::ProposeEmodeCategory] Builder struct
+pub struct ProposeEmodeCategory {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Pool account to configure e-mode category in.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: solana_program::pubkey::Pubkey,
+    ///Curator account.
+    pub curator: solana_program::pubkey::Pubkey,
+    /// Category ID in range 1..=MAX_EMODE_CATEGORIES. 0 - reset pending proposal.
+    pub category_id: u8,
+    pub category: EmodeCategory,
 }
-impl ClosePositionAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const POSITION: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            position: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
+impl ProposeEmodeCategory {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            pool,
+            curator_pools_authority,
+            curator,
+            category_id,
+            category,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(pool, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    curator_pools_authority,
+                    true,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    crate::TEXTURE_CONFIG_ID,
+                    false,
+                ),
+            ]);
+        let ix = SuperLendyInstruction::ProposeEmodeCategory {
+            category_id,
+            category,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[SuperLendyInstruction::ApplyEmodeCategoryProposal] Builder struct
+pub struct ApplyEmodeCategoryProposal {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Pool account to configure e-mode category in.
+    pub pool: solana_program::pubkey::Pubkey,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: solana_program::pubkey::Pubkey,
+    ///Curator account.
+    pub curator: solana_program::pubkey::Pubkey,
+}
+impl ApplyEmodeCategoryProposal {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            pool,
+            curator_pools_authority,
+            curator,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = SUPER_LENDY_ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(pool, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    curator_pools_authority,
+                    true,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curator, false),
+            ]);
+        let ix = SuperLendyInstruction::ApplyEmodeCategoryProposal {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+/// [SuperLendyInstruction::CreateTextureConfig] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateTextureConfigAccountIndexes {
+    pub texture_config: usize,
+    pub owner: usize,
+}
+impl CreateTextureConfigAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            texture_config: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
         indexes: impl IntoIterator<Item = &'a u8>,
     ) -> Result<Self, usize> {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
+            texture_config: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -3748,117 +5190,106 @@ impl ClosePositionAccountIndexes {
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ClosePositionAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for CreateTextureConfigAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClosePositionAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateTextureConfigAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ClosePositionAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for CreateTextureConfigAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ClosePositionAccountIndexes {
+impl TryFrom<Vec<u8>> for CreateTextureConfigAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::RefreshPosition] instruction account indexes helper
+/// [SuperLendyInstruction::AlterTextureConfig] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct RefreshPositionAccountIndexes {
-    pub position: usize,
-    pub deposits: Vec<usize>,
-    pub borrows: Vec<usize>,
+pub struct AlterTextureConfigAccountIndexes {
+    pub texture_config: usize,
+    pub owner: usize,
 }
-impl RefreshPositionAccountIndexes {
-    pub const POSITION: usize = 0usize;
-    pub fn new_direct_order(deposits_size: usize, borrows_size: usize) -> Self {
+impl AlterTextureConfigAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            position: iter.next().unwrap(),
-            deposits: {
-                let mut out = vec![];
-                for _ in 0..deposits_size {
-                    out.push(iter.next().unwrap());
-                }
-                out
-            },
-            borrows: {
-                let mut out = vec![];
-                for _ in 0..borrows_size {
-                    out.push(iter.next().unwrap());
-                }
-                out
-            },
+            texture_config: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
         indexes: impl IntoIterator<Item = &'a u8>,
-        deposits_size: usize,
-        borrows_size: usize,
     ) -> Result<Self, usize> {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
+            texture_config: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            deposits: {
-                let mut out = vec![];
-                for _ in 0..deposits_size {
-                    idx += 1;
-                    out.push(iter.next().ok_or(idx - 1)?);
-                }
-                out
-            },
-            borrows: {
-                let mut out = vec![];
-                for _ in 0..borrows_size {
-                    idx += 1;
-                    out.push(iter.next().ok_or(idx - 1)?);
-                }
-                out
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-/// [SuperLendyInstruction::LockCollateral] instruction account indexes helper
+impl<'a> TryFrom<&'a [u8]> for AlterTextureConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterTextureConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterTextureConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterTextureConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::CreateCurator] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct LockCollateralAccountIndexes {
-    pub position: usize,
-    pub source_lp_wallet: usize,
-    pub reserve_collateral_supply: usize,
-    pub owner: usize,
-    pub reserve: usize,
-    pub lp_token_program: usize,
+pub struct CreateCuratorAccountIndexes {
+    pub curator: usize,
+    pub texture_config: usize,
+    pub global_config_owner: usize,
 }
-impl LockCollateralAccountIndexes {
-    pub const COUNT: usize = 6usize;
-    pub const POSITION: usize = 0usize;
-    pub const SOURCE_LP_WALLET: usize = 1usize;
-    pub const RESERVE_COLLATERAL_SUPPLY: usize = 2usize;
-    pub const OWNER: usize = 3usize;
-    pub const RESERVE: usize = 4usize;
-    pub const LP_TOKEN_PROGRAM: usize = 5usize;
+impl CreateCuratorAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURATOR: usize = 0usize;
+    pub const TEXTURE_CONFIG: usize = 1usize;
+    pub const GLOBAL_CONFIG_OWNER: usize = 2usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            position: iter.next().unwrap(),
-            source_lp_wallet: iter.next().unwrap(),
-            reserve_collateral_supply: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            lp_token_program: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            global_config_owner: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -3867,87 +5298,60 @@ impl LockCollateralAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_lp_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve_collateral_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reserve: {
+            texture_config: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            lp_token_program: {
+            global_config_owner: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for LockCollateralAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for CreateCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for LockCollateralAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for LockCollateralAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for CreateCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for LockCollateralAccountIndexes {
+impl TryFrom<Vec<u8>> for CreateCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::UnlockCollateral] instruction account indexes helper
+/// [SuperLendyInstruction::AlterCurator] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct UnlockCollateralAccountIndexes {
-    pub position: usize,
-    pub reserve_collateral_supply: usize,
-    pub destination_lp_wallet: usize,
+pub struct AlterCuratorAccountIndexes {
+    pub curator: usize,
     pub owner: usize,
-    pub reserve: usize,
-    pub program_authority: usize,
-    pub lp_token_program: usize,
 }
-impl UnlockCollateralAccountIndexes {
-    pub const COUNT: usize = 7usize;
-    pub const POSITION: usize = 0usize;
-    pub const RESERVE_COLLATERAL_SUPPLY: usize = 1usize;
-    pub const DESTINATION_LP_WALLET: usize = 2usize;
-    pub const OWNER: usize = 3usize;
-    pub const RESERVE: usize = 4usize;
-    pub const PROGRAM_AUTHORITY: usize = 5usize;
-    pub const LP_TOKEN_PROGRAM: usize = 6usize;
+impl AlterCuratorAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURATOR: usize = 0usize;
+    pub const OWNER: usize = 1usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            position: iter.next().unwrap(),
-            reserve_collateral_supply: iter.next().unwrap(),
-            destination_lp_wallet: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
             owner: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            lp_token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -3956,15 +5360,7 @@ impl UnlockCollateralAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve_collateral_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_lp_wallet: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -3972,93 +5368,51 @@ impl UnlockCollateralAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            lp_token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for UnlockCollateralAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for AlterCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UnlockCollateralAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for UnlockCollateralAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for AlterCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for UnlockCollateralAccountIndexes {
+impl TryFrom<Vec<u8>> for AlterCuratorAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::Borrow] instruction account indexes helper
+/// [SuperLendyInstruction::CreatePool] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct BorrowAccountIndexes {
-    pub position: usize,
-    pub reserve_liquidity_supply: usize,
-    pub destination_liquidity_wallet: usize,
-    pub curator_fee_receiver: usize,
-    pub borrower: usize,
-    pub reserve: usize,
+pub struct CreatePoolAccountIndexes {
     pub pool: usize,
+    pub curator_pools_authority: usize,
     pub curator: usize,
-    pub texture_fee_receiver: usize,
-    pub texture_config: usize,
-    pub liquidity_mint: usize,
-    pub program_authority: usize,
-    pub token_program: usize,
 }
-impl BorrowAccountIndexes {
-    pub const COUNT: usize = 13usize;
-    pub const POSITION: usize = 0usize;
-    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
-    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
-    pub const CURATOR_FEE_RECEIVER: usize = 3usize;
-    pub const BORROWER: usize = 4usize;
-    pub const RESERVE: usize = 5usize;
-    pub const POOL: usize = 6usize;
-    pub const CURATOR: usize = 7usize;
-    pub const TEXTURE_FEE_RECEIVER: usize = 8usize;
-    pub const TEXTURE_CONFIG: usize = 9usize;
-    pub const LIQUIDITY_MINT: usize = 10usize;
-    pub const PROGRAM_AUTHORITY: usize = 11usize;
-    pub const TOKEN_PROGRAM: usize = 12usize;
+impl CreatePoolAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POOL: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            position: iter.next().unwrap(),
-            reserve_liquidity_supply: iter.next().unwrap(),
-            destination_liquidity_wallet: iter.next().unwrap(),
-            curator_fee_receiver: iter.next().unwrap(),
-            borrower: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
             pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
             curator: iter.next().unwrap(),
-            texture_fee_receiver: iter.next().unwrap(),
-            texture_config: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4067,115 +5421,63 @@ impl BorrowAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve_liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_liquidity_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_fee_receiver: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            borrower: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
             pool: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            texture_fee_receiver: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            texture_config: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
+            curator_pools_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for BorrowAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for CreatePoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for BorrowAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for BorrowAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for CreatePoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for BorrowAccountIndexes {
+impl TryFrom<Vec<u8>> for CreatePoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::Repay] instruction account indexes helper
+/// [SuperLendyInstruction::AlterPool] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct RepayAccountIndexes {
-    pub position: usize,
-    pub source_liquidity_wallet: usize,
-    pub reserve_liquidity_supply: usize,
-    pub user_authority: usize,
-    pub reserve: usize,
-    pub liquidity_mint: usize,
-    pub token_program: usize,
+pub struct AlterPoolAccountIndexes {
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
 }
-impl RepayAccountIndexes {
-    pub const COUNT: usize = 7usize;
-    pub const POSITION: usize = 0usize;
-    pub const SOURCE_LIQUIDITY_WALLET: usize = 1usize;
-    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 2usize;
-    pub const USER_AUTHORITY: usize = 3usize;
-    pub const RESERVE: usize = 4usize;
-    pub const LIQUIDITY_MINT: usize = 5usize;
-    pub const TOKEN_PROGRAM: usize = 6usize;
+impl AlterPoolAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POOL: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            position: iter.next().unwrap(),
-            source_liquidity_wallet: iter.next().unwrap(),
-            reserve_liquidity_supply: iter.next().unwrap(),
-            user_authority: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4184,185 +5486,93 @@ impl RepayAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            source_liquidity_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve_liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            user_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve: {
+            pool: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidity_mint: {
+            curator_pools_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for RepayAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for AlterPoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RepayAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterPoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for RepayAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for AlterPoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for RepayAccountIndexes {
+impl TryFrom<Vec<u8>> for AlterPoolAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::WriteOffBadDebt] instruction account indexes helper
+/// [SuperLendyInstruction::CreateReserve] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct WriteOffBadDebtAccountIndexes {
-    pub pool: usize,
+pub struct CreateReserveAccountIndexes {
     pub reserve: usize,
-    pub position: usize,
+    pub pool: usize,
     pub curator_pools_authority: usize,
     pub curator: usize,
+    pub liquidity_mint: usize,
+    pub liquidity_supply: usize,
+    pub lp_mint: usize,
+    pub collateral_supply: usize,
+    pub market_price_feed: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub liquidity_token_program: usize,
+    pub system_program: usize,
 }
-impl WriteOffBadDebtAccountIndexes {
-    pub const COUNT: usize = 5usize;
-    pub const POOL: usize = 0usize;
-    pub const RESERVE: usize = 1usize;
-    pub const POSITION: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            pool: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            position: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            position: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for WriteOffBadDebtAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WriteOffBadDebtAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
-}
-impl<const N: usize> TryFrom<[u8; N]> for WriteOffBadDebtAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-impl TryFrom<Vec<u8>> for WriteOffBadDebtAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
-}
-/// [SuperLendyInstruction::Liquidate] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct LiquidateAccountIndexes {
-    pub repayment_source_wallet: usize,
-    pub destination_lp_wallet: usize,
-    pub principal_reserve: usize,
-    pub principal_reserve_liquidity_supply: usize,
-    pub collateral_reserve: usize,
-    pub collateral_reserve_lp_supply: usize,
-    pub position: usize,
-    pub liquidator: usize,
-    pub principal_reserve_liquidity_mint: usize,
-    pub program_authority: usize,
-    pub principal_token_program: usize,
-    pub collateral_token_program: usize,
-}
-impl LiquidateAccountIndexes {
-    pub const COUNT: usize = 12usize;
-    pub const REPAYMENT_SOURCE_WALLET: usize = 0usize;
-    pub const DESTINATION_LP_WALLET: usize = 1usize;
-    pub const PRINCIPAL_RESERVE: usize = 2usize;
-    pub const PRINCIPAL_RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
-    pub const COLLATERAL_RESERVE: usize = 4usize;
-    pub const COLLATERAL_RESERVE_LP_SUPPLY: usize = 5usize;
-    pub const POSITION: usize = 6usize;
-    pub const LIQUIDATOR: usize = 7usize;
-    pub const PRINCIPAL_RESERVE_LIQUIDITY_MINT: usize = 8usize;
+impl CreateReserveAccountIndexes {
+    pub const COUNT: usize = 13usize;
+    pub const RESERVE: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 2usize;
+    pub const CURATOR: usize = 3usize;
+    pub const LIQUIDITY_MINT: usize = 4usize;
+    pub const LIQUIDITY_SUPPLY: usize = 5usize;
+    pub const LP_MINT: usize = 6usize;
+    pub const COLLATERAL_SUPPLY: usize = 7usize;
+    pub const MARKET_PRICE_FEED: usize = 8usize;
     pub const PROGRAM_AUTHORITY: usize = 9usize;
-    pub const PRINCIPAL_TOKEN_PROGRAM: usize = 10usize;
-    pub const COLLATERAL_TOKEN_PROGRAM: usize = 11usize;
+    pub const LP_TOKEN_PROGRAM: usize = 10usize;
+    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 11usize;
+    pub const SYSTEM_PROGRAM: usize = 12usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            repayment_source_wallet: iter.next().unwrap(),
-            destination_lp_wallet: iter.next().unwrap(),
-            principal_reserve: iter.next().unwrap(),
-            principal_reserve_liquidity_supply: iter.next().unwrap(),
-            collateral_reserve: iter.next().unwrap(),
-            collateral_reserve_lp_supply: iter.next().unwrap(),
-            position: iter.next().unwrap(),
-            liquidator: iter.next().unwrap(),
-            principal_reserve_liquidity_mint: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            liquidity_supply: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            collateral_supply: iter.next().unwrap(),
+            market_price_feed: iter.next().unwrap(),
             program_authority: iter.next().unwrap(),
-            principal_token_program: iter.next().unwrap(),
-            collateral_token_program: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            liquidity_token_program: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4371,39 +5581,39 @@ impl LiquidateAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            repayment_source_wallet: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            destination_lp_wallet: {
+            pool: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            principal_reserve: {
+            curator_pools_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            principal_reserve_liquidity_supply: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            collateral_reserve: {
+            liquidity_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            collateral_reserve_lp_supply: {
+            liquidity_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            position: {
+            lp_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidator: {
+            collateral_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            principal_reserve_liquidity_mint: {
+            market_price_feed: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -4411,74 +5621,72 @@ impl LiquidateAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            principal_token_program: {
+            lp_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            collateral_token_program: {
+            liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for LiquidateAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for CreateReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for LiquidateAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for LiquidateAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for CreateReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for LiquidateAccountIndexes {
+impl TryFrom<Vec<u8>> for CreateReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::ClaimCuratorPerformanceFees] instruction account indexes helper
+/// [SuperLendyInstruction::AlterReserve] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct ClaimCuratorPerformanceFeesAccountIndexes {
+pub struct AlterReserveAccountIndexes {
     pub reserve: usize,
-    pub reserve_liquidity_supply: usize,
     pub pool: usize,
+    pub market_price_feed: usize,
+    pub curator_pools_authority: usize,
     pub curator: usize,
-    pub fee_receiver: usize,
-    pub liquidity_mint: usize,
-    pub program_authority: usize,
-    pub token_program: usize,
+    pub texture_config: usize,
 }
-impl ClaimCuratorPerformanceFeesAccountIndexes {
-    pub const COUNT: usize = 8usize;
+impl AlterReserveAccountIndexes {
+    pub const COUNT: usize = 6usize;
     pub const RESERVE: usize = 0usize;
-    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
-    pub const POOL: usize = 2usize;
-    pub const CURATOR: usize = 3usize;
-    pub const FEE_RECEIVER: usize = 4usize;
-    pub const LIQUIDITY_MINT: usize = 5usize;
-    pub const PROGRAM_AUTHORITY: usize = 6usize;
-    pub const TOKEN_PROGRAM: usize = 7usize;
+    pub const POOL: usize = 1usize;
+    pub const MARKET_PRICE_FEED: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const TEXTURE_CONFIG: usize = 5usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
             reserve: iter.next().unwrap(),
-            reserve_liquidity_supply: iter.next().unwrap(),
             pool: iter.next().unwrap(),
+            market_price_feed: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
             curator: iter.next().unwrap(),
-            fee_receiver: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4491,92 +5699,74 @@ impl ClaimCuratorPerformanceFeesAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reserve_liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
             pool: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            fee_receiver: {
+            market_price_feed: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidity_mint: {
+            curator_pools_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            program_authority: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            texture_config: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ClaimCuratorPerformanceFeesAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for AlterReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]>
-for ClaimCuratorPerformanceFeesAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ClaimCuratorPerformanceFeesAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for AlterReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ClaimCuratorPerformanceFeesAccountIndexes {
+impl TryFrom<Vec<u8>> for AlterReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::ClaimTexturePerformanceFees] instruction account indexes helper
+/// [SuperLendyInstruction::RefreshReserve] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct ClaimTexturePerformanceFeesAccountIndexes {
+pub struct RefreshReserveAccountIndexes {
     pub reserve: usize,
-    pub reserve_liquidity_supply: usize,
-    pub fee_receiver: usize,
+    pub market_price_feed: usize,
+    pub irm: usize,
     pub texture_config: usize,
-    pub liquidity_mint: usize,
-    pub program_authority: usize,
-    pub token_program: usize,
 }
-impl ClaimTexturePerformanceFeesAccountIndexes {
-    pub const COUNT: usize = 7usize;
+impl RefreshReserveAccountIndexes {
+    pub const COUNT: usize = 4usize;
     pub const RESERVE: usize = 0usize;
-    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
-    pub const FEE_RECEIVER: usize = 2usize;
+    pub const MARKET_PRICE_FEED: usize = 1usize;
+    pub const IRM: usize = 2usize;
     pub const TEXTURE_CONFIG: usize = 3usize;
-    pub const LIQUIDITY_MINT: usize = 4usize;
-    pub const PROGRAM_AUTHORITY: usize = 5usize;
-    pub const TOKEN_PROGRAM: usize = 6usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
             reserve: iter.next().unwrap(),
-            reserve_liquidity_supply: iter.next().unwrap(),
-            fee_receiver: iter.next().unwrap(),
+            market_price_feed: iter.next().unwrap(),
+            irm: iter.next().unwrap(),
             texture_config: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4589,11 +5779,11 @@ impl ClaimTexturePerformanceFeesAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reserve_liquidity_supply: {
+            market_price_feed: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            fee_receiver: {
+            irm: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -4601,79 +5791,72 @@ impl ClaimTexturePerformanceFeesAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            token_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ClaimTexturePerformanceFeesAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for RefreshReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]>
-for ClaimTexturePerformanceFeesAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RefreshReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ClaimTexturePerformanceFeesAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for RefreshReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ClaimTexturePerformanceFeesAccountIndexes {
+impl TryFrom<Vec<u8>> for RefreshReserveAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::InitRewardSupply] instruction account indexes helper
+/// [SuperLendyInstruction::DepositLiquidity] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct InitRewardSupplyAccountIndexes {
-    pub reward_supply: usize,
-    pub reward_mint: usize,
-    pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub reward_authority: usize,
-    pub token_program: usize,
-    pub system_program: usize,
+pub struct DepositLiquidityAccountIndexes {
+    pub authority: usize,
+    pub source_liquidity_wallet: usize,
+    pub destination_lp_wallet: usize,
+    pub reserve: usize,
+    pub liquidity_supply: usize,
+    pub liquidity_mint: usize,
+    pub lp_mint: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub liquidity_token_program: usize,
 }
-impl InitRewardSupplyAccountIndexes {
-    pub const COUNT: usize = 8usize;
-    pub const REWARD_SUPPLY: usize = 0usize;
-    pub const REWARD_MINT: usize = 1usize;
-    pub const POOL: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
-    pub const REWARD_AUTHORITY: usize = 5usize;
-    pub const TOKEN_PROGRAM: usize = 6usize;
-    pub const SYSTEM_PROGRAM: usize = 7usize;
+impl DepositLiquidityAccountIndexes {
+    pub const COUNT: usize = 10usize;
+    pub const AUTHORITY: usize = 0usize;
+    pub const SOURCE_LIQUIDITY_WALLET: usize = 1usize;
+    pub const DESTINATION_LP_WALLET: usize = 2usize;
+    pub const RESERVE: usize = 3usize;
+    pub const LIQUIDITY_SUPPLY: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
+    pub const LP_MINT: usize = 6usize;
+    pub const PROGRAM_AUTHORITY: usize = 7usize;
+    pub const LP_TOKEN_PROGRAM: usize = 8usize;
+    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 9usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            reward_supply: iter.next().unwrap(),
-            reward_mint: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            reward_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
-            system_program: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+            source_liquidity_wallet: iter.next().unwrap(),
+            destination_lp_wallet: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            liquidity_supply: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            liquidity_token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4682,262 +5865,205 @@ impl InitRewardSupplyAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            reward_supply: {
+            authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reward_mint: {
+            source_liquidity_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            pool: {
+            destination_lp_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator_pools_authority: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
+            liquidity_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reward_authority: {
+            liquidity_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            lp_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            system_program: {
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for InitRewardSupplyAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for DepositLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for InitRewardSupplyAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DepositLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for InitRewardSupplyAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for DepositLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for InitRewardSupplyAccountIndexes {
+impl TryFrom<Vec<u8>> for DepositLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::SetRewardRules] instruction account indexes helper
+/// [SuperLendyInstruction::WithdrawLiquidity] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct SetRewardRulesAccountIndexes {
+pub struct WithdrawLiquidityAccountIndexes {
+    pub authority: usize,
+    pub source_lp_wallet: usize,
+    pub destination_liquidity_wallet: usize,
     pub reserve: usize,
-    pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub reward_mints: Vec<usize>,
+    pub liquidity_supply: usize,
+    pub liquidity_mint: usize,
+    pub lp_mint: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub liquidity_token_program: usize,
 }
-impl SetRewardRulesAccountIndexes {
-    pub const RESERVE: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 2usize;
-    pub const CURATOR: usize = 3usize;
-    pub fn new_direct_order(reward_mints_size: usize) -> Self {
+impl WithdrawLiquidityAccountIndexes {
+    pub const COUNT: usize = 10usize;
+    pub const AUTHORITY: usize = 0usize;
+    pub const SOURCE_LP_WALLET: usize = 1usize;
+    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
+    pub const RESERVE: usize = 3usize;
+    pub const LIQUIDITY_SUPPLY: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
+    pub const LP_MINT: usize = 6usize;
+    pub const PROGRAM_AUTHORITY: usize = 7usize;
+    pub const LP_TOKEN_PROGRAM: usize = 8usize;
+    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 9usize;
+    pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
+            authority: iter.next().unwrap(),
+            source_lp_wallet: iter.next().unwrap(),
+            destination_liquidity_wallet: iter.next().unwrap(),
             reserve: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            reward_mints: {
-                let mut out = vec![];
-                for _ in 0..reward_mints_size {
-                    out.push(iter.next().unwrap());
-                }
-                out
-            },
+            liquidity_supply: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            liquidity_token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
         indexes: impl IntoIterator<Item = &'a u8>,
-        reward_mints_size: usize,
     ) -> Result<Self, usize> {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            pool: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
+            authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
+            source_lp_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reward_mints: {
-                let mut out = vec![];
-                for _ in 0..reward_mints_size {
-                    idx += 1;
-                    out.push(iter.next().ok_or(idx - 1)?);
-                }
-                out
-            },
-        })
-    }
-}
-/// [SuperLendyInstruction::ClaimReward] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct ClaimRewardAccountIndexes {
-    pub position: usize,
-    pub rewards_supply: usize,
-    pub destination_wallet: usize,
-    pub position_owner: usize,
-    pub pool: usize,
-    pub reward_mint: usize,
-    pub reward_authority: usize,
-    pub token_program: usize,
-}
-impl ClaimRewardAccountIndexes {
-    pub const COUNT: usize = 8usize;
-    pub const POSITION: usize = 0usize;
-    pub const REWARDS_SUPPLY: usize = 1usize;
-    pub const DESTINATION_WALLET: usize = 2usize;
-    pub const POSITION_OWNER: usize = 3usize;
-    pub const POOL: usize = 4usize;
-    pub const REWARD_MINT: usize = 5usize;
-    pub const REWARD_AUTHORITY: usize = 6usize;
-    pub const TOKEN_PROGRAM: usize = 7usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            position: iter.next().unwrap(),
-            rewards_supply: iter.next().unwrap(),
-            destination_wallet: iter.next().unwrap(),
-            position_owner: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            reward_mint: iter.next().unwrap(),
-            reward_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            position: {
+            destination_liquidity_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            rewards_supply: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            destination_wallet: {
+            liquidity_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            position_owner: {
+            liquidity_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            pool: {
+            lp_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reward_mint: {
+            program_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reward_authority: {
+            lp_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            liquidity_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ClaimRewardAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for WithdrawLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClaimRewardAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WithdrawLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ClaimRewardAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for WithdrawLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ClaimRewardAccountIndexes {
+impl TryFrom<Vec<u8>> for WithdrawLiquidityAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::WithdrawReward] instruction account indexes helper
+/// [SuperLendyInstruction::CreatePosition] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct WithdrawRewardAccountIndexes {
-    pub rewards_supply: usize,
-    pub destination_wallet: usize,
+pub struct CreatePositionAccountIndexes {
+    pub position: usize,
     pub pool: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub reward_mint: usize,
-    pub reward_authority: usize,
-    pub token_program: usize,
+    pub owner: usize,
 }
-impl WithdrawRewardAccountIndexes {
-    pub const COUNT: usize = 8usize;
-    pub const REWARDS_SUPPLY: usize = 0usize;
-    pub const DESTINATION_WALLET: usize = 1usize;
-    pub const POOL: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
-    pub const REWARD_MINT: usize = 5usize;
-    pub const REWARD_AUTHORITY: usize = 6usize;
-    pub const TOKEN_PROGRAM: usize = 7usize;
+impl CreatePositionAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POSITION: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const OWNER: usize = 2usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            rewards_supply: iter.next().unwrap(),
-            destination_wallet: iter.next().unwrap(),
+            position: iter.next().unwrap(),
             pool: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            reward_mint: iter.next().unwrap(),
-            reward_authority: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -4946,11 +6072,7 @@ impl WithdrawRewardAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            rewards_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_wallet: {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -4958,83 +6080,52 @@ impl WithdrawRewardAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reward_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            reward_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            token_program: {
+            owner: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for WithdrawRewardAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for CreatePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WithdrawRewardAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for WithdrawRewardAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for CreatePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for WithdrawRewardAccountIndexes {
+impl TryFrom<Vec<u8>> for CreatePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::FlashBorrow] instruction account indexes helper
+/// [SuperLendyInstruction::ClosePosition] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct FlashBorrowAccountIndexes {
-    pub reserve: usize,
-    pub liquidity_supply: usize,
-    pub destination_wallet: usize,
-    pub liquidity_mint: usize,
-    pub program_authority: usize,
-    pub sysvar_instructions: usize,
-    pub token_program: usize,
+pub struct ClosePositionAccountIndexes {
+    pub position: usize,
+    pub owner: usize,
 }
-impl FlashBorrowAccountIndexes {
-    pub const COUNT: usize = 7usize;
-    pub const RESERVE: usize = 0usize;
-    pub const LIQUIDITY_SUPPLY: usize = 1usize;
-    pub const DESTINATION_WALLET: usize = 2usize;
-    pub const LIQUIDITY_MINT: usize = 3usize;
-    pub const PROGRAM_AUTHORITY: usize = 4usize;
-    pub const SYSVAR_INSTRUCTIONS: usize = 5usize;
-    pub const TOKEN_PROGRAM: usize = 6usize;
+impl ClosePositionAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const POSITION: usize = 0usize;
+    pub const OWNER: usize = 1usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            reserve: iter.next().unwrap(),
-            liquidity_supply: iter.next().unwrap(),
-            destination_wallet: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            program_authority: iter.next().unwrap(),
-            sysvar_instructions: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5043,181 +6134,217 @@ impl FlashBorrowAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            reserve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_supply: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            destination_wallet: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            liquidity_mint: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            program_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            sysvar_instructions: {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            owner: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for FlashBorrowAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for ClosePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for FlashBorrowAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClosePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for FlashBorrowAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for ClosePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for FlashBorrowAccountIndexes {
+impl TryFrom<Vec<u8>> for ClosePositionAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::FlashRepay] instruction account indexes helper
+/// [SuperLendyInstruction::RefreshPosition] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct FlashRepayAccountIndexes {
-    pub source_wallet: usize,
-    pub reserve: usize,
-    pub liquidity_supply: usize,
-    pub liquidity_mint: usize,
-    pub user_transfer_authority: usize,
-    pub sysvar_instructions: usize,
-    pub token_program: usize,
+pub struct RefreshPositionAccountIndexes {
+    pub position: usize,
+    pub deposits: Vec<usize>,
+    pub borrows: Vec<usize>,
 }
-impl FlashRepayAccountIndexes {
-    pub const COUNT: usize = 7usize;
-    pub const SOURCE_WALLET: usize = 0usize;
-    pub const RESERVE: usize = 1usize;
-    pub const LIQUIDITY_SUPPLY: usize = 2usize;
-    pub const LIQUIDITY_MINT: usize = 3usize;
-    pub const USER_TRANSFER_AUTHORITY: usize = 4usize;
-    pub const SYSVAR_INSTRUCTIONS: usize = 5usize;
-    pub const TOKEN_PROGRAM: usize = 6usize;
-    pub fn new_direct_order() -> Self {
+impl RefreshPositionAccountIndexes {
+    pub const POSITION: usize = 0usize;
+    pub fn new_direct_order(deposits_size: usize, borrows_size: usize) -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            source_wallet: iter.next().unwrap(),
-            reserve: iter.next().unwrap(),
-            liquidity_supply: iter.next().unwrap(),
-            liquidity_mint: iter.next().unwrap(),
-            user_transfer_authority: iter.next().unwrap(),
-            sysvar_instructions: iter.next().unwrap(),
-            token_program: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            deposits: {
+                let mut out = vec![];
+                for _ in 0..deposits_size {
+                    out.push(iter.next().unwrap());
+                }
+                out
+            },
+            borrows: {
+                let mut out = vec![];
+                for _ in 0..borrows_size {
+                    out.push(iter.next().unwrap());
+                }
+                out
+            },
         }
     }
     pub fn try_from_indexes<'a>(
         indexes: impl IntoIterator<Item = &'a u8>,
+        deposits_size: usize,
+        borrows_size: usize,
     ) -> Result<Self, usize> {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            source_wallet: {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            reserve: {
+            deposits: {
+                let mut out = vec![];
+                for _ in 0..deposits_size {
+                    idx += 1;
+                    out.push(iter.next().ok_or(idx - 1)?);
+                }
+                out
+            },
+            borrows: {
+                let mut out = vec![];
+                for _ in 0..borrows_size {
+                    idx += 1;
+                    out.push(iter.next().ok_or(idx - 1)?);
+                }
+                out
+            },
+        })
+    }
+}
+/// [SuperLendyInstruction::LockCollateral] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct LockCollateralAccountIndexes {
+    pub position: usize,
+    pub source_lp_wallet: usize,
+    pub reserve_collateral_supply: usize,
+    pub owner: usize,
+    pub reserve: usize,
+    pub lp_token_program: usize,
+}
+impl LockCollateralAccountIndexes {
+    pub const COUNT: usize = 6usize;
+    pub const POSITION: usize = 0usize;
+    pub const SOURCE_LP_WALLET: usize = 1usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 2usize;
+    pub const OWNER: usize = 3usize;
+    pub const RESERVE: usize = 4usize;
+    pub const LP_TOKEN_PROGRAM: usize = 5usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            source_lp_wallet: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidity_supply: {
+            source_lp_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            liquidity_mint: {
+            reserve_collateral_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            user_transfer_authority: {
+            owner: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            sysvar_instructions: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            token_program: {
+            lp_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for FlashRepayAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for LockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for FlashRepayAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for LockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for FlashRepayAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for LockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for FlashRepayAccountIndexes {
+impl TryFrom<Vec<u8>> for LockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::ProposeConfig] instruction account indexes helper
+/// [SuperLendyInstruction::UnlockCollateral] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct ProposeConfigAccountIndexes {
+pub struct UnlockCollateralAccountIndexes {
+    pub position: usize,
+    pub reserve_collateral_supply: usize,
+    pub destination_lp_wallet: usize,
+    pub owner: usize,
     pub reserve: usize,
-    pub pool: usize,
-    pub market_price_feed: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub texture_config: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
 }
-impl ProposeConfigAccountIndexes {
-    pub const COUNT: usize = 6usize;
-    pub const RESERVE: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const MARKET_PRICE_FEED: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
-    pub const TEXTURE_CONFIG: usize = 5usize;
+impl UnlockCollateralAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 1usize;
+    pub const DESTINATION_LP_WALLET: usize = 2usize;
+    pub const OWNER: usize = 3usize;
+    pub const RESERVE: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const LP_TOKEN_PROGRAM: usize = 6usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
+            position: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            destination_lp_wallet: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
             reserve: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
-            market_price_feed: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5226,81 +6353,109 @@ impl ProposeConfigAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            reserve: {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            pool: {
+            reserve_collateral_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            market_price_feed: {
+            destination_lp_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator_pools_authority: {
+            owner: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            texture_config: {
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ProposeConfigAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for UnlockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeConfigAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UnlockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ProposeConfigAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for UnlockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ProposeConfigAccountIndexes {
+impl TryFrom<Vec<u8>> for UnlockCollateralAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::ApplyConfigProposal] instruction account indexes helper
+/// [SuperLendyInstruction::Borrow] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct ApplyConfigProposalAccountIndexes {
+pub struct BorrowAccountIndexes {
+    pub position: usize,
+    pub reserve_liquidity_supply: usize,
+    pub destination_liquidity_wallet: usize,
+    pub curator_fee_receiver: usize,
+    pub borrower: usize,
     pub reserve: usize,
     pub pool: usize,
-    pub market_price_feed: usize,
-    pub curator_pools_authority: usize,
     pub curator: usize,
+    pub texture_fee_receiver: usize,
+    pub texture_config: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
 }
-impl ApplyConfigProposalAccountIndexes {
-    pub const COUNT: usize = 5usize;
-    pub const RESERVE: usize = 0usize;
-    pub const POOL: usize = 1usize;
-    pub const MARKET_PRICE_FEED: usize = 2usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
-    pub const CURATOR: usize = 4usize;
+impl BorrowAccountIndexes {
+    pub const COUNT: usize = 13usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
+    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
+    pub const CURATOR_FEE_RECEIVER: usize = 3usize;
+    pub const BORROWER: usize = 4usize;
+    pub const RESERVE: usize = 5usize;
+    pub const POOL: usize = 6usize;
+    pub const CURATOR: usize = 7usize;
+    pub const TEXTURE_FEE_RECEIVER: usize = 8usize;
+    pub const TEXTURE_CONFIG: usize = 9usize;
+    pub const LIQUIDITY_MINT: usize = 10usize;
+    pub const PROGRAM_AUTHORITY: usize = 11usize;
+    pub const TOKEN_PROGRAM: usize = 12usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
+            position: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            destination_liquidity_wallet: iter.next().unwrap(),
+            curator_fee_receiver: iter.next().unwrap(),
+            borrower: iter.next().unwrap(),
             reserve: iter.next().unwrap(),
             pool: iter.next().unwrap(),
-            market_price_feed: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
             curator: iter.next().unwrap(),
+            texture_fee_receiver: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5309,6 +6464,26 @@ impl ApplyConfigProposalAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            borrower: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
             reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
@@ -5317,66 +6492,87 @@ impl ApplyConfigProposalAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            market_price_feed: {
+            curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator_pools_authority: {
+            texture_fee_receiver: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for ApplyConfigProposalAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for BorrowAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ApplyConfigProposalAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for BorrowAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for ApplyConfigProposalAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for BorrowAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for ApplyConfigProposalAccountIndexes {
+impl TryFrom<Vec<u8>> for BorrowAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::DeleteReserve] instruction account indexes helper
+/// [SuperLendyInstruction::Repay] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct DeleteReserveAccountIndexes {
+pub struct RepayAccountIndexes {
+    pub position: usize,
+    pub source_liquidity_wallet: usize,
+    pub reserve_liquidity_supply: usize,
+    pub user_authority: usize,
     pub reserve: usize,
-    pub curator_pools_authority: usize,
-    pub curator: usize,
-    pub pool: usize,
+    pub liquidity_mint: usize,
+    pub token_program: usize,
 }
-impl DeleteReserveAccountIndexes {
-    pub const COUNT: usize = 4usize;
-    pub const RESERVE: usize = 0usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
-    pub const CURATOR: usize = 2usize;
-    pub const POOL: usize = 3usize;
+impl RepayAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const POSITION: usize = 0usize;
+    pub const SOURCE_LIQUIDITY_WALLET: usize = 1usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 2usize;
+    pub const USER_AUTHORITY: usize = 3usize;
+    pub const RESERVE: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
+            position: iter.next().unwrap(),
+            source_liquidity_wallet: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            user_authority: iter.next().unwrap(),
             reserve: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
-            curator: iter.next().unwrap(),
-            pool: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5385,67 +6581,100 @@ impl DeleteReserveAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            reserve: {
+            position: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator_pools_authority: {
+            source_liquidity_wallet: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            curator: {
+            reserve_liquidity_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            pool: {
+            user_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for DeleteReserveAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for RepayAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteReserveAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RepayAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for DeleteReserveAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for RepayAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for DeleteReserveAccountIndexes {
+impl TryFrom<Vec<u8>> for RepayAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::TransferTextureConfigOwnership] instruction account indexes helper
+/// [SuperLendyInstruction::WriteOffBadDebt] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct TransferTextureConfigOwnershipAccountIndexes {
-    pub texture_config: usize,
-    pub owner: usize,
-    pub new_owner: usize,
+pub struct WriteOffBadDebtAccountIndexes {
+    pub pool: usize,
+    pub reserve: usize,
+    pub position: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub reserve_liquidity_supply: usize,
+    pub insurance_fund: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
 }
-impl TransferTextureConfigOwnershipAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const TEXTURE_CONFIG: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub const NEW_OWNER: usize = 2usize;
+impl WriteOffBadDebtAccountIndexes {
+    pub const COUNT: usize = 10usize;
+    pub const POOL: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const POSITION: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 5usize;
+    pub const INSURANCE_FUND: usize = 6usize;
+    pub const LIQUIDITY_MINT: usize = 7usize;
+    pub const PROGRAM_AUTHORITY: usize = 8usize;
+    pub const TOKEN_PROGRAM: usize = 9usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            texture_config: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-            new_owner: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            insurance_fund: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5454,58 +6683,121 @@ impl TransferTextureConfigOwnershipAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            texture_config: {
+            pool: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            owner: {
+            reserve: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            new_owner: {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            insurance_fund: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for TransferTextureConfigOwnershipAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for WriteOffBadDebtAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]>
-for TransferTextureConfigOwnershipAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WriteOffBadDebtAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for TransferTextureConfigOwnershipAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for WriteOffBadDebtAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for TransferTextureConfigOwnershipAccountIndexes {
+impl TryFrom<Vec<u8>> for WriteOffBadDebtAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::Version] instruction account indexes helper
+/// [SuperLendyInstruction::Liquidate] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct VersionAccountIndexes {
-    pub system_program: usize,
+pub struct LiquidateAccountIndexes {
+    pub repayment_source_wallet: usize,
+    pub destination_lp_wallet: usize,
+    pub principal_reserve: usize,
+    pub principal_reserve_liquidity_supply: usize,
+    pub collateral_reserve: usize,
+    pub collateral_reserve_lp_supply: usize,
+    pub position: usize,
+    pub liquidator: usize,
+    pub principal_reserve_liquidity_mint: usize,
+    pub texture_config: usize,
+    pub program_authority: usize,
+    pub principal_token_program: usize,
+    pub collateral_token_program: usize,
 }
-impl VersionAccountIndexes {
-    pub const COUNT: usize = 1usize;
-    pub const SYSTEM_PROGRAM: usize = 0usize;
+impl LiquidateAccountIndexes {
+    pub const COUNT: usize = 13usize;
+    pub const REPAYMENT_SOURCE_WALLET: usize = 0usize;
+    pub const DESTINATION_LP_WALLET: usize = 1usize;
+    pub const PRINCIPAL_RESERVE: usize = 2usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const COLLATERAL_RESERVE: usize = 4usize;
+    pub const COLLATERAL_RESERVE_LP_SUPPLY: usize = 5usize;
+    pub const POSITION: usize = 6usize;
+    pub const LIQUIDATOR: usize = 7usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_MINT: usize = 8usize;
+    pub const TEXTURE_CONFIG: usize = 9usize;
+    pub const PROGRAM_AUTHORITY: usize = 10usize;
+    pub const PRINCIPAL_TOKEN_PROGRAM: usize = 11usize;
+    pub const COLLATERAL_TOKEN_PROGRAM: usize = 12usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
-            system_program: iter.next().unwrap(),
+            repayment_source_wallet: iter.next().unwrap(),
+            destination_lp_wallet: iter.next().unwrap(),
+            principal_reserve: iter.next().unwrap(),
+            principal_reserve_liquidity_supply: iter.next().unwrap(),
+            collateral_reserve: iter.next().unwrap(),
+            collateral_reserve_lp_supply: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            liquidator: iter.next().unwrap(),
+            principal_reserve_liquidity_mint: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            principal_token_program: iter.next().unwrap(),
+            collateral_token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5514,76 +6806,118 @@ impl VersionAccountIndexes {
         let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
         let mut idx = 0_usize;
         Ok(Self {
-            system_program: {
+            repayment_source_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_lp_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_lp_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for VersionAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for LiquidateAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for VersionAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for LiquidateAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for VersionAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for LiquidateAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for VersionAccountIndexes {
+impl TryFrom<Vec<u8>> for LiquidateAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-/// [SuperLendyInstruction::SetLpMetadata] instruction account indexes helper
+/// [SuperLendyInstruction::ClaimCuratorPerformanceFees] instruction account indexes helper
 #[derive(Debug, PartialEq)]
-pub struct SetLpMetadataAccountIndexes {
+pub struct ClaimCuratorPerformanceFeesAccountIndexes {
     pub reserve: usize,
-    pub lp_mint: usize,
+    pub reserve_liquidity_supply: usize,
     pub pool: usize,
-    pub metadata_account: usize,
-    pub curator_pools_authority: usize,
     pub curator: usize,
+    pub fee_receiver: usize,
+    pub liquidity_mint: usize,
     pub program_authority: usize,
-    pub mpl_token_metadata_program: usize,
-    pub system_program: usize,
-    pub sysvar_rent: usize,
+    pub token_program: usize,
 }
-impl SetLpMetadataAccountIndexes {
-    pub const COUNT: usize = 10usize;
+impl ClaimCuratorPerformanceFeesAccountIndexes {
+    pub const COUNT: usize = 8usize;
     pub const RESERVE: usize = 0usize;
-    pub const LP_MINT: usize = 1usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
     pub const POOL: usize = 2usize;
-    pub const METADATA_ACCOUNT: usize = 3usize;
-    pub const CURATOR_POOLS_AUTHORITY: usize = 4usize;
-    pub const CURATOR: usize = 5usize;
+    pub const CURATOR: usize = 3usize;
+    pub const FEE_RECEIVER: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
     pub const PROGRAM_AUTHORITY: usize = 6usize;
-    pub const MPL_TOKEN_METADATA_PROGRAM: usize = 7usize;
-    pub const SYSTEM_PROGRAM: usize = 8usize;
-    pub const SYSVAR_RENT: usize = 9usize;
+    pub const TOKEN_PROGRAM: usize = 7usize;
     pub fn new_direct_order() -> Self {
         let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
         Self {
             reserve: iter.next().unwrap(),
-            lp_mint: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
             pool: iter.next().unwrap(),
-            metadata_account: iter.next().unwrap(),
-            curator_pools_authority: iter.next().unwrap(),
             curator: iter.next().unwrap(),
+            fee_receiver: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
             program_authority: iter.next().unwrap(),
-            mpl_token_metadata_program: iter.next().unwrap(),
-            system_program: iter.next().unwrap(),
-            sysvar_rent: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
     }
     pub fn try_from_indexes<'a>(
@@ -5596,7 +6930,7 @@ impl SetLpMetadataAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            lp_mint: {
+            reserve_liquidity_supply: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
@@ -5604,3996 +6938,10440 @@ impl SetLpMetadataAccountIndexes {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            metadata_account: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            curator_pools_authority: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
             curator: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            program_authority: {
+            fee_receiver: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            mpl_token_metadata_program: {
+            liquidity_mint: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            system_program: {
+            program_authority: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
-            sysvar_rent: {
+            token_program: {
                 idx += 1;
                 iter.next().ok_or(idx - 1)?
             },
         })
     }
 }
-impl<'a> TryFrom<&'a [u8]> for SetLpMetadataAccountIndexes {
+impl<'a> TryFrom<&'a [u8]> for ClaimCuratorPerformanceFeesAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetLpMetadataAccountIndexes {
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for ClaimCuratorPerformanceFeesAccountIndexes {
     type Error = usize;
     fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(indexes)
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for SetLpMetadataAccountIndexes {
+impl<const N: usize> TryFrom<[u8; N]> for ClaimCuratorPerformanceFeesAccountIndexes {
     type Error = usize;
     fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-impl TryFrom<Vec<u8>> for SetLpMetadataAccountIndexes {
+impl TryFrom<Vec<u8>> for ClaimCuratorPerformanceFeesAccountIndexes {
     type Error = usize;
     fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
         Self::try_from_indexes(&indexes)
     }
 }
-///[SuperLendyInstruction::CreateTextureConfig] instruction account infos helper
-#[derive(Debug)]
-pub struct CreateTextureConfigAccounts<'a, 'i> {
-    ///Global config account to create. With uninitialized data.
-    ///Ownership must be already assigned to SuperLendy.
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Config owner. Will fund Config account.
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+/// [SuperLendyInstruction::ClaimTexturePerformanceFees] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClaimTexturePerformanceFeesAccountIndexes {
+    pub reserve: usize,
+    pub reserve_liquidity_supply: usize,
+    pub fee_receiver: usize,
+    pub texture_config: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
 }
-impl<'a, 'i> CreateTextureConfigAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !texture_config.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(texture_config), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*texture_config.key).into(),
-            );
-        }
-        if !texture_config.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*texture_config.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        if !rent.is_exempt(texture_config.lamports(), texture_config.data_len()) {
-            solana_program::msg!(
-                concat!(stringify!(texture_config), " is not rent exempt")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*texture_config.key).into(),
-            );
-        }
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
+impl ClaimTexturePerformanceFeesAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const RESERVE: usize = 0usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
+    pub const FEE_RECEIVER: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 3usize;
+    pub const LIQUIDITY_MINT: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            fee_receiver: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
-        Ok(Self { texture_config, owner })
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
     }
 }
-///[SuperLendyInstruction::AlterTextureConfig] instruction account infos helper
-#[derive(Debug)]
-pub struct AlterTextureConfigAccounts<'a, 'i> {
-    ///Global config account to change.
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config owner
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-}
-impl<'a, 'i> AlterTextureConfigAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !texture_config.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(texture_config), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*texture_config.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { texture_config, owner })
+impl<'a> TryFrom<&'a [u8]> for ClaimTexturePerformanceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::CreateCurator] instruction account infos helper
-#[derive(Debug)]
-pub struct CreateCuratorAccounts<'a, 'i> {
-    ///Curator account to create.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global Texture config account.
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config owner
-    pub global_config_owner: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for ClaimTexturePerformanceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> CreateCuratorAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let global_config_owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !curator.is_writable {
-            solana_program::msg!(concat!(stringify!(curator), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        if !curator.is_signer {
-            return Err(texture_common::error::MissingSignature(*curator.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        if !global_config_owner.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(global_config_owner), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*global_config_owner.key).into(),
-            );
-        }
-        if !global_config_owner.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*global_config_owner.key).into(),
-            );
-        }
-        Ok(Self {
-            curator,
-            texture_config,
-            global_config_owner,
-        })
+impl<const N: usize> TryFrom<[u8; N]> for ClaimTexturePerformanceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
     }
 }
-///[SuperLendyInstruction::AlterCurator] instruction account infos helper
-#[derive(Debug)]
-pub struct AlterCuratorAccounts<'a, 'i> {
-    ///Curator account to change.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Owner of the Curator account.
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+impl TryFrom<Vec<u8>> for ClaimTexturePerformanceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> AlterCuratorAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !curator.is_writable {
-            solana_program::msg!(concat!(stringify!(curator), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { curator, owner })
-    }
-}
-///[SuperLendyInstruction::CreatePool] instruction account infos helper
-#[derive(Debug)]
-pub struct CreatePoolAccounts<'a, 'i> {
-    ///Pool account to create. With uninitialized data.
-    ///Ownership must be already assigned to SuperLendy.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pools authority configured in `curator` account. Will fund Pool account.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+/// [SuperLendyInstruction::InitRewardSupply] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct InitRewardSupplyAccountIndexes {
+    pub reward_supply: usize,
+    pub reward_mint: usize,
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub reward_authority: usize,
+    pub token_program: usize,
+    pub system_program: usize,
 }
-impl<'a, 'i> CreatePoolAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !pool.is_writable {
-            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*pool.key).into());
-        }
-        if !pool.is_signer {
-            return Err(texture_common::error::MissingSignature(*pool.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !rent.is_exempt(pool.lamports(), pool.data_len()) {
-            solana_program::msg!(concat!(stringify!(pool), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*pool.key).into());
-        }
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl InitRewardSupplyAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const REWARD_SUPPLY: usize = 0usize;
+    pub const REWARD_MINT: usize = 1usize;
+    pub const POOL: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const REWARD_AUTHORITY: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub const SYSTEM_PROGRAM: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reward_supply: iter.next().unwrap(),
+            reward_mint: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            reward_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            pool,
-            curator_pools_authority,
-            curator,
+            reward_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::AlterPool] instruction account infos helper
-#[derive(Debug)]
-pub struct AlterPoolAccounts<'a, 'i> {
-    ///Pool account to alter
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pools authority configured in `curator` account. Will fund Pool account.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for InitRewardSupplyAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> AlterPoolAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !pool.is_writable {
-            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*pool.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        Ok(Self {
-            pool,
-            curator_pools_authority,
-            curator,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for InitRewardSupplyAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::CreateReserve] instruction account infos helper
-#[derive(Debug)]
-pub struct CreateReserveAccounts<'a, 'i> {
-    ///Reserve account to create. With uninitialized data.
-    ///Ownership must be already assigned to SuperLendy.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for created Reserve.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can add new reserves in to a pool.
-    ///Will fund Reserve account.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity mint of the Reserve
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity supply SPL Token wallet. Not initialized. PDA.
-    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity provider tokens mint of the Reserve. Not initialized. PDA.
-    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Collateral supply SPL Token wallet. Not initialized. PDA.
-    pub collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price feed account to get market price for liquidity currency.
-    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program - classic one
-    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program to manage liquidity tokens. Either classic or 2022
-    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///System Program.
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for InitRewardSupplyAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> CreateReserveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let lp_mint = texture_common::utils::next_account_info(iter)?;
-        let collateral_supply = texture_common::utils::next_account_info(iter)?;
-        let market_price_feed = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let lp_token_program = texture_common::utils::next_account_info(iter)?;
-        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
-        let system_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        if !reserve.is_signer {
-            return Err(texture_common::error::MissingSignature(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !rent.is_exempt(reserve.lamports(), reserve.data_len()) {
-            solana_program::msg!(concat!(stringify!(reserve), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        if !liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            liquidity_supply.owner,
-            &solana_program::system_program::ID,
-            concat!(stringify!(liquidity_supply), " owner"),
-        )?;
-        if !lp_mint.is_writable {
-            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_mint.owner,
-            &solana_program::system_program::ID,
-            concat!(stringify!(lp_mint), " owner"),
-        )?;
-        if !collateral_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(collateral_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*collateral_supply.key).into(),
-            );
+impl TryFrom<Vec<u8>> for InitRewardSupplyAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SetRewardRules] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetRewardRulesAccountIndexes {
+    pub reserve: usize,
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub reward_mints: Vec<usize>,
+}
+impl SetRewardRulesAccountIndexes {
+    pub const RESERVE: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 2usize;
+    pub const CURATOR: usize = 3usize;
+    pub fn new_direct_order(reward_mints_size: usize) -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            reward_mints: {
+                let mut out = vec![];
+                for _ in 0..reward_mints_size {
+                    out.push(iter.next().unwrap());
+                }
+                out
+            },
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            collateral_supply.owner,
-            &solana_program::system_program::ID,
-            concat!(stringify!(collateral_supply), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            market_price_feed.owner,
-            &price_proxy::ID,
-            concat!(stringify!(market_price_feed), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_token_program.key,
-            &spl_token::ID,
-            stringify!(lp_token_program),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+        reward_mints_size: usize,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            pool,
-            curator_pools_authority,
-            curator,
-            liquidity_mint,
-            liquidity_supply,
-            lp_mint,
-            collateral_supply,
-            market_price_feed,
-            program_authority,
-            lp_token_program,
-            liquidity_token_program,
-            system_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_mints: {
+                let mut out = vec![];
+                for _ in 0..reward_mints_size {
+                    idx += 1;
+                    out.push(iter.next().ok_or(idx - 1)?);
+                }
+                out
+            },
         })
     }
 }
-///[SuperLendyInstruction::AlterReserve] instruction account infos helper
-#[derive(Debug)]
-pub struct AlterReserveAccounts<'a, 'i> {
-    ///Reserve change.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for created Reserve.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price feed account to get market price for liquidity currency.
-    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config account
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+/// [SuperLendyInstruction::ClaimReward] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClaimRewardAccountIndexes {
+    pub position: usize,
+    pub rewards_supply: usize,
+    pub destination_wallet: usize,
+    pub position_owner: usize,
+    pub pool: usize,
+    pub reward_mint: usize,
+    pub reward_authority: usize,
+    pub token_program: usize,
 }
-impl<'a, 'i> AlterReserveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let market_price_feed = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            market_price_feed.owner,
-            &price_proxy::ID,
-            concat!(stringify!(market_price_feed), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl ClaimRewardAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const POSITION: usize = 0usize;
+    pub const REWARDS_SUPPLY: usize = 1usize;
+    pub const DESTINATION_WALLET: usize = 2usize;
+    pub const POSITION_OWNER: usize = 3usize;
+    pub const POOL: usize = 4usize;
+    pub const REWARD_MINT: usize = 5usize;
+    pub const REWARD_AUTHORITY: usize = 6usize;
+    pub const TOKEN_PROGRAM: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            rewards_supply: iter.next().unwrap(),
+            destination_wallet: iter.next().unwrap(),
+            position_owner: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            reward_mint: iter.next().unwrap(),
+            reward_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            pool,
-            market_price_feed,
-            curator_pools_authority,
-            curator,
-            texture_config,
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            rewards_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            position_owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::RefreshReserve] instruction account infos helper
-#[derive(Debug)]
-pub struct RefreshReserveAccounts<'a, 'i> {
-    ///Reserve account to refresh.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price feed account to get market price for liquidity currency.
-    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Interest Rate Model account.
-    pub irm: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config account
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for ClaimRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> RefreshReserveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let market_price_feed = texture_common::utils::next_account_info(iter)?;
-        let irm = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            market_price_feed.owner,
-            &price_proxy::ID,
-            concat!(stringify!(market_price_feed), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            irm.owner,
-            &curvy::ID,
-            concat!(stringify!(irm), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        Ok(Self {
-            reserve,
-            market_price_feed,
-            irm,
-            texture_config,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClaimRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::DepositLiquidity] instruction account infos helper
-#[derive(Debug)]
-pub struct DepositLiquidityAccounts<'a, 'i> {
-    ///Owner of the source_liquidity_wallet
-    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Source SPL Token wallet to transfer liquidity from.
-    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token wallet to receive LP tokens minted during deposit.
-    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account to deposit to. Must be refreshed beforehand.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.
-    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///LP tokens mint. PDA.
-    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program - either classic or 2022
-    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for ClaimRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> DepositLiquidityAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let authority = texture_common::utils::next_account_info(iter)?;
-        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
-        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let lp_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let lp_token_program = texture_common::utils::next_account_info(iter)?;
-        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !authority.is_signer {
-            return Err(texture_common::error::MissingSignature(*authority.key).into());
-        }
-        if !source_liquidity_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_liquidity_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
-                    .into(),
-            );
-        }
-        if !destination_lp_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_lp_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
-            );
-        }
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
-            );
-        }
-        if !lp_mint.is_writable {
-            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+impl TryFrom<Vec<u8>> for ClaimRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::WithdrawReward] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct WithdrawRewardAccountIndexes {
+    pub rewards_supply: usize,
+    pub destination_wallet: usize,
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub reward_mint: usize,
+    pub reward_authority: usize,
+    pub token_program: usize,
+}
+impl WithdrawRewardAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const REWARDS_SUPPLY: usize = 0usize;
+    pub const DESTINATION_WALLET: usize = 1usize;
+    pub const POOL: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const REWARD_MINT: usize = 5usize;
+    pub const REWARD_AUTHORITY: usize = 6usize;
+    pub const TOKEN_PROGRAM: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            rewards_supply: iter.next().unwrap(),
+            destination_wallet: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            reward_mint: iter.next().unwrap(),
+            reward_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_token_program.key,
-            &spl_token::ID,
-            stringify!(lp_token_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            authority,
-            source_liquidity_wallet,
-            destination_lp_wallet,
-            reserve,
-            liquidity_supply,
-            liquidity_mint,
-            lp_mint,
-            program_authority,
-            lp_token_program,
-            liquidity_token_program,
+            rewards_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reward_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::WithdrawLiquidity] instruction account infos helper
-#[derive(Debug)]
-pub struct WithdrawLiquidityAccounts<'a, 'i> {
-    ///Owner of the source_lp_wallet
-    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Source SPL Token wallet to transfer LP tokens from.
-    pub source_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token wallet to receive liquidity.
-    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account to withdraw from. Must be refreshed beforehand.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token wallet controlled by contract which will give the liquidity. PDA.
-    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///LP tokens mint. PDA.
-    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program - either classic or 2022
-    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for WithdrawRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> WithdrawLiquidityAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let authority = texture_common::utils::next_account_info(iter)?;
-        let source_lp_wallet = texture_common::utils::next_account_info(iter)?;
-        let destination_liquidity_wallet = texture_common::utils::next_account_info(
-            iter,
-        )?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let lp_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let lp_token_program = texture_common::utils::next_account_info(iter)?;
-        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !authority.is_signer {
-            return Err(texture_common::error::MissingSignature(*authority.key).into());
-        }
-        if !source_lp_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_lp_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_lp_wallet.key).into(),
-            );
-        }
-        if !destination_liquidity_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_liquidity_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
-                    .into(),
-            );
-        }
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
-            );
-        }
-        if !lp_mint.is_writable {
-            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for WithdrawRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for WithdrawRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for WithdrawRewardAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::FlashBorrow] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct FlashBorrowAccountIndexes {
+    pub reserve: usize,
+    pub liquidity_supply: usize,
+    pub destination_wallet: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub sysvar_instructions: usize,
+    pub token_program: usize,
+}
+impl FlashBorrowAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const RESERVE: usize = 0usize;
+    pub const LIQUIDITY_SUPPLY: usize = 1usize;
+    pub const DESTINATION_WALLET: usize = 2usize;
+    pub const LIQUIDITY_MINT: usize = 3usize;
+    pub const PROGRAM_AUTHORITY: usize = 4usize;
+    pub const SYSVAR_INSTRUCTIONS: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            liquidity_supply: iter.next().unwrap(),
+            destination_wallet: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            sysvar_instructions: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_token_program.key,
-            &spl_token::ID,
-            stringify!(lp_token_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            authority,
-            source_lp_wallet,
-            destination_liquidity_wallet,
-            reserve,
-            liquidity_supply,
-            liquidity_mint,
-            lp_mint,
-            program_authority,
-            lp_token_program,
-            liquidity_token_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            sysvar_instructions: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::CreatePosition] instruction account infos helper
-#[derive(Debug)]
-pub struct CreatePositionAccounts<'a, 'i> {
-    ///Position account to initialize. Allocated and owned by SuperLendy. Not initialized yet.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool the position will belong to.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Owner of the position
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for FlashBorrowAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> CreatePositionAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        if !position.is_signer {
-            return Err(texture_common::error::MissingSignature(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !rent.is_exempt(position.lamports(), position.data_len()) {
-            solana_program::msg!(concat!(stringify!(position), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { position, pool, owner })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for FlashBorrowAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::ClosePosition] instruction account infos helper
-#[derive(Debug)]
-pub struct ClosePositionAccounts<'a, 'i> {
-    ///Position account to close.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Owner of the position
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for FlashBorrowAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> ClosePositionAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { position, owner })
+impl TryFrom<Vec<u8>> for FlashBorrowAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
     }
 }
-///[SuperLendyInstruction::RefreshPosition] instruction account infos helper
-#[derive(Debug)]
-pub struct RefreshPositionAccounts<'a, 'i> {
-    ///Position account.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Collateral deposit reserve accounts - refreshed,
-    ///all in same order as listed in Position.deposits
-    pub deposits: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
-    ///Liquidity borrow reserve accounts - refreshed,
-    ///all in same order as listed in Position.borrows
-    pub borrows: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
+/// [SuperLendyInstruction::FlashRepay] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct FlashRepayAccountIndexes {
+    pub source_wallet: usize,
+    pub reserve: usize,
+    pub liquidity_supply: usize,
+    pub liquidity_mint: usize,
+    pub user_transfer_authority: usize,
+    pub sysvar_instructions: usize,
+    pub token_program: usize,
+    pub texture_config: usize,
 }
-impl<'a, 'i> RefreshPositionAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        deposit_count: usize,
-        borrow_count: usize,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let deposits = {
-            let mut out = vec![];
-            for _ in 0..deposit_count {
-                out.push(texture_common::utils::next_account_info(iter)?);
-            }
-            out
-        };
-        let borrows = {
-            let mut out = vec![];
-            for _ in 0..borrow_count {
-                out.push(texture_common::utils::next_account_info(iter)?);
-            }
-            out
-        };
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        for acc in &deposits {
-            #[allow(clippy::needless_borrow)]
-            texture_common::utils::verify_key(
-                acc.owner,
-                &__self_program_id__,
-                concat!(stringify!(acc), " owner"),
-            )?;
-        }
-        for acc in &borrows {
-            #[allow(clippy::needless_borrow)]
-            texture_common::utils::verify_key(
-                acc.owner,
-                &__self_program_id__,
-                concat!(stringify!(acc), " owner"),
-            )?;
+impl FlashRepayAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const SOURCE_WALLET: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const LIQUIDITY_SUPPLY: usize = 2usize;
+    pub const LIQUIDITY_MINT: usize = 3usize;
+    pub const USER_TRANSFER_AUTHORITY: usize = 4usize;
+    pub const SYSVAR_INSTRUCTIONS: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub const TEXTURE_CONFIG: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            source_wallet: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            liquidity_supply: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            user_transfer_authority: iter.next().unwrap(),
+            sysvar_instructions: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            position,
-            deposits,
-            borrows,
+            source_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            user_transfer_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            sysvar_instructions: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::LockCollateral] instruction account infos helper
-#[derive(Debug)]
-pub struct LockCollateralAccounts<'a, 'i> {
-    ///Position account to lock collateral in.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///User's SPL token wallet which holds LP tokens to be locked as collateral
-    pub source_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet to hold locked LP tokens. PDA.
-    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Position owner and also authority for source_lp_wallet
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for FlashRepayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> LockCollateralAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let source_lp_wallet = texture_common::utils::next_account_info(iter)?;
-        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let lp_token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !source_lp_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_lp_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_lp_wallet.key).into(),
-            );
-        }
-        if !reserve_collateral_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_collateral_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
-                    .into(),
-            );
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_token_program.key,
-            &spl_token::ID,
-            stringify!(lp_token_program),
-        )?;
-        Ok(Self {
-            position,
-            source_lp_wallet,
-            reserve_collateral_supply,
-            owner,
-            reserve,
-            lp_token_program,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for FlashRepayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::UnlockCollateral] instruction account infos helper
-#[derive(Debug)]
-pub struct UnlockCollateralAccounts<'a, 'i> {
-    ///Position account to unlock collateral from
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
-    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///User's SPL token wallet which will receive unlocked LP tokens
-    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Position owner
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for FlashRepayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> UnlockCollateralAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
-        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let lp_token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !reserve_collateral_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_collateral_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
-                    .into(),
-            );
-        }
-        if !destination_lp_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_lp_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
-            );
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
+impl TryFrom<Vec<u8>> for FlashRepayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ProposeConfig] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ProposeConfigAccountIndexes {
+    pub reserve: usize,
+    pub pool: usize,
+    pub market_price_feed: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub texture_config: usize,
+}
+impl ProposeConfigAccountIndexes {
+    pub const COUNT: usize = 6usize;
+    pub const RESERVE: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const MARKET_PRICE_FEED: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const TEXTURE_CONFIG: usize = 5usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            market_price_feed: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            lp_token_program.key,
-            &spl_token::ID,
-            stringify!(lp_token_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            position,
-            reserve_collateral_supply,
-            destination_lp_wallet,
-            owner,
-            reserve,
-            program_authority,
-            lp_token_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            market_price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::Borrow] instruction account infos helper
-#[derive(Debug)]
-pub struct BorrowAccounts<'a, 'i> {
-    ///Borrowers Position account. Refreshed.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds liquidity. PDA.
-    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///User's SPL token wallet which will receive borrowed liquidity tokens
-    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority
-    pub curator_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Position owner who borrow
-    pub borrower: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool borrow happens in.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator of the pool.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority
-    pub texture_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config account
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint.
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program - either classic or 2022
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for ProposeConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> BorrowAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let destination_liquidity_wallet = texture_common::utils::next_account_info(
-            iter,
-        )?;
-        let curator_fee_receiver = texture_common::utils::next_account_info(iter)?;
-        let borrower = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let texture_fee_receiver = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !reserve_liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
-                    .into(),
-            );
-        }
-        if !destination_liquidity_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_liquidity_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
-                    .into(),
-            );
-        }
-        if !curator_fee_receiver.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_fee_receiver), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_fee_receiver.key).into(),
-            );
-        }
-        if !borrower.is_signer {
-            return Err(texture_common::error::MissingSignature(*borrower.key).into());
-        }
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ProposeConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ProposeConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ApplyConfigProposal] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ApplyConfigProposalAccountIndexes {
+    pub reserve: usize,
+    pub pool: usize,
+    pub market_price_feed: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+}
+impl ApplyConfigProposalAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const RESERVE: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const MARKET_PRICE_FEED: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            market_price_feed: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        if !texture_fee_receiver.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(texture_fee_receiver), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*texture_fee_receiver.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            position,
-            reserve_liquidity_supply,
-            destination_liquidity_wallet,
-            curator_fee_receiver,
-            borrower,
-            reserve,
-            pool,
-            curator,
-            texture_fee_receiver,
-            texture_config,
-            liquidity_mint,
-            program_authority,
-            token_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            market_price_feed: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::Repay] instruction account infos helper
-#[derive(Debug)]
-pub struct RepayAccounts<'a, 'i> {
-    ///Borrowers Position account. Refreshed.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///User's SPL token wallet with liquidity tokens to be used as repayment
-    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet to return liquidity to. PDA.
-    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority to transfer funds from `source_liquidity_wallet`
-    pub user_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint.
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program - either classic or 2022
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for ApplyConfigProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> RepayAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
-        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let user_authority = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !source_liquidity_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(source_liquidity_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
-                    .into(),
-            );
-        }
-        if !reserve_liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
-                    .into(),
-            );
-        }
-        if !user_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*user_authority.key).into(),
-            );
-        }
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ApplyConfigProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ApplyConfigProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ApplyConfigProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::DeleteReserve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeleteReserveAccountIndexes {
+    pub reserve: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub pool: usize,
+}
+impl DeleteReserveAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const RESERVE: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
+    pub const POOL: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            position,
-            source_liquidity_wallet,
-            reserve_liquidity_supply,
-            user_authority,
-            reserve,
-            liquidity_mint,
-            token_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::WriteOffBadDebt] instruction account infos helper
-#[derive(Debug)]
-pub struct WriteOffBadDebtAccounts<'a, 'i> {
-    ///Pool to which bad debt position belongs
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account to write off bad debt in. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Unhealthy Position account. Refreshed.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can write-off bad debt from the reserve.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for DeleteReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> WriteOffBadDebtAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeleteReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeleteReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::TransferTextureConfigOwnership] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct TransferTextureConfigOwnershipAccountIndexes {
+    pub texture_config: usize,
+    pub owner: usize,
+    pub new_owner: usize,
+}
+impl TransferTextureConfigOwnershipAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const TEXTURE_CONFIG: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const NEW_OWNER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            texture_config: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            new_owner: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            pool,
-            reserve,
-            position,
-            curator_pools_authority,
-            curator,
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::Liquidate] instruction account infos helper
-#[derive(Debug)]
-pub struct LiquidateAccounts<'a, 'i> {
-    ///SPL token wallet to get repayment liquidity from.
-    pub repayment_source_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token wallet to receive LP tokens (released collateral of the liquidated Position)
-    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
-    pub principal_reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet to return principal liquidity to. PDA.
-    pub principal_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
-        'i,
-    >,
-    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
-    pub collateral_reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
-    pub collateral_reserve_lp_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Borrower Position account. Refreshed.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidator's authority which controls `repayment_source_wallet`
-    pub liquidator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint in principal Reserve.
-    pub principal_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
-        'i,
-    >,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program used to manage principal tokens
-    pub principal_token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token
-    pub collateral_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for TransferTextureConfigOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> LiquidateAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let repayment_source_wallet = texture_common::utils::next_account_info(iter)?;
-        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
-        let principal_reserve = texture_common::utils::next_account_info(iter)?;
-        let principal_reserve_liquidity_supply = texture_common::utils::next_account_info(
-            iter,
-        )?;
-        let collateral_reserve = texture_common::utils::next_account_info(iter)?;
-        let collateral_reserve_lp_supply = texture_common::utils::next_account_info(
-            iter,
-        )?;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let liquidator = texture_common::utils::next_account_info(iter)?;
-        let principal_reserve_liquidity_mint = texture_common::utils::next_account_info(
-            iter,
-        )?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let principal_token_program = texture_common::utils::next_account_info(iter)?;
-        let collateral_token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !repayment_source_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(repayment_source_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*repayment_source_wallet.key)
-                    .into(),
-            );
-        }
-        if !destination_lp_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_lp_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
-            );
-        }
-        if !principal_reserve.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(principal_reserve), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*principal_reserve.key).into(),
-            );
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for TransferTextureConfigOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for TransferTextureConfigOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for TransferTextureConfigOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::Version] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct VersionAccountIndexes {
+    pub system_program: usize,
+}
+impl VersionAccountIndexes {
+    pub const COUNT: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 0usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            system_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            principal_reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(principal_reserve), " owner"),
-        )?;
-        if !principal_reserve_liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(principal_reserve_liquidity_supply),
-                " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(
-                        *principal_reserve_liquidity_supply.key,
-                    )
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            collateral_reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(collateral_reserve), " owner"),
-        )?;
-        if !collateral_reserve_lp_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(collateral_reserve_lp_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*collateral_reserve_lp_supply.key)
-                    .into(),
-            );
-        }
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !liquidator.is_signer {
-            return Err(texture_common::error::MissingSignature(*liquidator.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            collateral_token_program.key,
-            &spl_token::ID,
-            stringify!(collateral_token_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            repayment_source_wallet,
-            destination_lp_wallet,
-            principal_reserve,
-            principal_reserve_liquidity_supply,
-            collateral_reserve,
-            collateral_reserve_lp_supply,
-            position,
-            liquidator,
-            principal_reserve_liquidity_mint,
-            program_authority,
-            principal_token_program,
-            collateral_token_program,
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::ClaimCuratorPerformanceFees] instruction account infos helper
-#[derive(Debug)]
-pub struct ClaimCuratorPerformanceFeesAccounts<'a, 'i> {
-    ///Reserve account to claim performance fees from. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
-    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token wallet to receive claimed fees. Must be ATA from curator.fees_authority.
-    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
-}
-impl<'a, 'i> ClaimCuratorPerformanceFeesAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let fee_receiver = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !reserve_liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        if !fee_receiver.is_writable {
-            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
-        }
-        Ok(Self {
-            reserve,
-            reserve_liquidity_supply,
-            pool,
-            curator,
-            fee_receiver,
-            liquidity_mint,
-            program_authority,
-            token_program,
-        })
+impl<'a> TryFrom<&'a [u8]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::ClaimTexturePerformanceFees] instruction account infos helper
-#[derive(Debug)]
-pub struct ClaimTexturePerformanceFeesAccounts<'a, 'i> {
-    ///Reserve account to claim performance fees from. Refreshed.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
-    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token wallet to receive claimed fees. Must be ATA from [TextureConfig.fees_authority]
-    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config account
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> ClaimTexturePerformanceFeesAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let fee_receiver = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !reserve_liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(reserve_liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
-                    .into(),
-            );
-        }
-        if !fee_receiver.is_writable {
-            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        Ok(Self {
-            reserve,
-            reserve_liquidity_supply,
-            fee_receiver,
-            texture_config,
-            liquidity_mint,
-            program_authority,
-            token_program,
-        })
+impl<const N: usize> TryFrom<[u8; N]> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
     }
 }
-///[SuperLendyInstruction::InitRewardSupply] instruction account infos helper
-#[derive(Debug)]
-pub struct InitRewardSupplyAccounts<'a, 'i> {
-    ///Reward supply account to initialize. Uninitialized. PDA
-    pub reward_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reward token mint.
-    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool to init reward supply for.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can manage pool.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's reward authority. PDA.
-    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///System Program.
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl TryFrom<Vec<u8>> for VersionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> InitRewardSupplyAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reward_supply = texture_common::utils::next_account_info(iter)?;
-        let reward_mint = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let reward_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        let system_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reward_supply.is_writable {
-            solana_program::msg!(concat!(stringify!(reward_supply), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reward_supply.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reward_supply.owner,
-            &solana_program::system_program::ID,
-            concat!(stringify!(reward_supply), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+/// [SuperLendyInstruction::SetLpMetadata] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetLpMetadataAccountIndexes {
+    pub reserve: usize,
+    pub lp_mint: usize,
+    pub pool: usize,
+    pub metadata_account: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub program_authority: usize,
+    pub mpl_token_metadata_program: usize,
+    pub system_program: usize,
+    pub sysvar_rent: usize,
+}
+impl SetLpMetadataAccountIndexes {
+    pub const COUNT: usize = 10usize;
+    pub const RESERVE: usize = 0usize;
+    pub const LP_MINT: usize = 1usize;
+    pub const POOL: usize = 2usize;
+    pub const METADATA_ACCOUNT: usize = 3usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 4usize;
+    pub const CURATOR: usize = 5usize;
+    pub const PROGRAM_AUTHORITY: usize = 6usize;
+    pub const MPL_TOKEN_METADATA_PROGRAM: usize = 7usize;
+    pub const SYSTEM_PROGRAM: usize = 8usize;
+    pub const SYSVAR_RENT: usize = 9usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            metadata_account: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            mpl_token_metadata_program: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+            sysvar_rent: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reward_supply,
-            reward_mint,
-            pool,
-            curator_pools_authority,
-            curator,
-            reward_authority,
-            token_program,
-            system_program,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            metadata_account: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            mpl_token_metadata_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            sysvar_rent: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::SetRewardRules] instruction account infos helper
-#[derive(Debug)]
-pub struct SetRewardRulesAccounts<'a, 'i> {
-    ///Reserve account to set reward rules for
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for created Reserve.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reward mint accounts - all in order as in `rules`
-    pub reward_mints: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
+impl<'a> TryFrom<&'a [u8]> for SetLpMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> SetRewardRulesAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        mints_count: usize,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let reward_mints = {
-            let mut out = vec![];
-            for _ in 0..mints_count {
-                out.push(texture_common::utils::next_account_info(iter)?);
-            }
-            out
-        };
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetLpMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetLpMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetLpMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SyncReserve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SyncReserveAccountIndexes {
+    pub reserve: usize,
+    pub reserve_liquidity_supply: usize,
+}
+impl SyncReserveAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const RESERVE: usize = 0usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            pool,
-            curator_pools_authority,
-            curator,
-            reward_mints,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::ClaimReward] instruction account infos helper
-#[derive(Debug)]
-pub struct ClaimRewardAccounts<'a, 'i> {
-    ///Position account to claim rewords for. Refreshed.
-    pub position: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds reward tokens. PDA.
-    pub rewards_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///User's SPL token wallet which will receive reward tokens
-    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Position owner
-    pub position_owner: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool, position belongs to.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reward token mint to claim. Determines which reward will be claimed from the Position.
-    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's reward authority. PDA.
-    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for SyncReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> ClaimRewardAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let position = texture_common::utils::next_account_info(iter)?;
-        let rewards_supply = texture_common::utils::next_account_info(iter)?;
-        let destination_wallet = texture_common::utils::next_account_info(iter)?;
-        let position_owner = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let reward_mint = texture_common::utils::next_account_info(iter)?;
-        let reward_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !position.is_writable {
-            solana_program::msg!(concat!(stringify!(position), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*position.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            position.owner,
-            &__self_program_id__,
-            concat!(stringify!(position), " owner"),
-        )?;
-        if !rewards_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(rewards_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*rewards_supply.key).into(),
-            );
-        }
-        if !destination_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
-            );
-        }
-        if !position_owner.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*position_owner.key).into(),
-            );
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SyncReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SyncReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SyncReserveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SetEmodeCategory] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetEmodeCategoryAccountIndexes {
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub texture_config: usize,
+}
+impl SetEmodeCategoryAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const POOL: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            position,
-            rewards_supply,
-            destination_wallet,
-            position_owner,
-            pool,
-            reward_mint,
-            reward_authority,
-            token_program,
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::WithdrawReward] instruction account infos helper
-#[derive(Debug)]
-pub struct WithdrawRewardAccounts<'a, 'i> {
-    ///Contract managed SPL token wallet which holds reward tokens. PDA.
-    pub rewards_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token account of reward_mint to receive reward tokens.
-    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool to withdraw rewards from.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reward token mint to withdraw.
-    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's reward authority. PDA.
-    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for SetEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> WithdrawRewardAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let rewards_supply = texture_common::utils::next_account_info(iter)?;
-        let destination_wallet = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let reward_mint = texture_common::utils::next_account_info(iter)?;
-        let reward_authority = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !rewards_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(rewards_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*rewards_supply.key).into(),
-            );
-        }
-        if !destination_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        Ok(Self {
-            rewards_supply,
-            destination_wallet,
-            pool,
-            curator_pools_authority,
-            curator,
-            reward_mint,
-            reward_authority,
-            token_program,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::FlashBorrow] instruction account infos helper
-#[derive(Debug)]
-pub struct FlashBorrowAccounts<'a, 'i> {
-    ///Reserve account to flash-borrow from.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.
-    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL token account to receive flash-borrowed tokens.
-    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Sysvar instructions account
-    pub sysvar_instructions: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for SetEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> FlashBorrowAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let destination_wallet = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let sysvar_instructions = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
-            );
-        }
-        if !destination_wallet.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(destination_wallet), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
-            );
+impl TryFrom<Vec<u8>> for SetEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SetPositionEmode] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetPositionEmodeAccountIndexes {
+    pub position: usize,
+    pub pool: usize,
+    pub owner: usize,
+}
+impl SetPositionEmodeAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POSITION: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const OWNER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            liquidity_supply,
-            destination_wallet,
-            liquidity_mint,
-            program_authority,
-            sysvar_instructions,
-            token_program,
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::FlashRepay] instruction account infos helper
-#[derive(Debug)]
-pub struct FlashRepayAccounts<'a, 'i> {
-    ///SPL token account to transfer tokens for repayment from.
-    pub source_wallet: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Reserve account to flash-repay to.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.
-    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Liquidity tokens mint
-    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority to transfer funds from source_wallet.
-    pub user_transfer_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Sysvar instructions account
-    pub sysvar_instructions: &'a solana_program::account_info::AccountInfo<'i>,
-    ///SPL Token program
-    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for SetPositionEmodeAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> FlashRepayAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let source_wallet = texture_common::utils::next_account_info(iter)?;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
-        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
-        let user_transfer_authority = texture_common::utils::next_account_info(iter)?;
-        let sysvar_instructions = texture_common::utils::next_account_info(iter)?;
-        let token_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !source_wallet.is_writable {
-            solana_program::msg!(concat!(stringify!(source_wallet), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*source_wallet.key).into());
-        }
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !liquidity_supply.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(liquidity_supply), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
-            );
-        }
-        if !user_transfer_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(user_transfer_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*user_transfer_authority.key)
-                    .into(),
-            );
-        }
-        if !user_transfer_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*user_transfer_authority.key)
-                    .into(),
-            );
-        }
-        Ok(Self {
-            source_wallet,
-            reserve,
-            liquidity_supply,
-            liquidity_mint,
-            user_transfer_authority,
-            sysvar_instructions,
-            token_program,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetPositionEmodeAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::ProposeConfig] instruction account infos helper
-#[derive(Debug)]
-pub struct ProposeConfigAccounts<'a, 'i> {
-    ///Reserve to propose new config for
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for created Reserve.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price feed account to get market price for liquidity currency.
-    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Global config account
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for SetPositionEmodeAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> ProposeConfigAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let market_price_feed = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            market_price_feed.owner,
-            &price_proxy::ID,
-            concat!(stringify!(market_price_feed), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl TryFrom<Vec<u8>> for SetPositionEmodeAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ClaimCuratorLiquidationFees] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClaimCuratorLiquidationFeesAccountIndexes {
+    pub reserve: usize,
+    pub reserve_collateral_supply: usize,
+    pub pool: usize,
+    pub curator: usize,
+    pub fee_receiver: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
+}
+impl ClaimCuratorLiquidationFeesAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const RESERVE: usize = 0usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 1usize;
+    pub const POOL: usize = 2usize;
+    pub const CURATOR: usize = 3usize;
+    pub const FEE_RECEIVER: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            fee_receiver: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            pool,
-            market_price_feed,
-            curator_pools_authority,
-            curator,
-            texture_config,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::ApplyConfigProposal] instruction account infos helper
-#[derive(Debug)]
-pub struct ApplyConfigProposalAccounts<'a, 'i> {
-    ///Reserve to apply config proposal for
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for created Reserve.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Price feed account to get market price for liquidity currency.
-    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for ClaimCuratorLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> ApplyConfigProposalAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let market_price_feed = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            market_price_feed.owner,
-            &price_proxy::ID,
-            concat!(stringify!(market_price_feed), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        Ok(Self {
-            reserve,
-            pool,
-            market_price_feed,
-            curator_pools_authority,
-            curator,
-        })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for ClaimCuratorLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::DeleteReserve] instruction account infos helper
-#[derive(Debug)]
-pub struct DeleteReserveAccounts<'a, 'i> {
-    ///Reserve to delete.
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves. He will reserve freed rent.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - Reserve belongs to.
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for ClaimCuratorLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> DeleteReserveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        if !reserve.is_writable {
-            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        Ok(Self {
-            reserve,
-            curator_pools_authority,
-            curator,
-            pool,
-        })
+impl TryFrom<Vec<u8>> for ClaimCuratorLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
     }
 }
-///[SuperLendyInstruction::TransferTextureConfigOwnership] instruction account infos helper
-#[derive(Debug)]
-pub struct TransferTextureConfigOwnershipAccounts<'a, 'i> {
-    ///Global config account.
-    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Current global config owner
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-    ///New global config owner
-    pub new_owner: &'a solana_program::account_info::AccountInfo<'i>,
+/// [SuperLendyInstruction::ClaimTextureLiquidationFees] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClaimTextureLiquidationFeesAccountIndexes {
+    pub reserve: usize,
+    pub reserve_collateral_supply: usize,
+    pub fee_receiver: usize,
+    pub texture_config: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
 }
-impl<'a, 'i> TransferTextureConfigOwnershipAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let texture_config = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        let new_owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        if !texture_config.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(texture_config), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*texture_config.key).into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.owner,
-            &__self_program_id__,
-            concat!(stringify!(texture_config), " owner"),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            texture_config.key,
-            &crate::TEXTURE_CONFIG_ID,
-            stringify!(texture_config),
-        )?;
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        if !new_owner.is_writable {
-            solana_program::msg!(concat!(stringify!(new_owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*new_owner.key).into());
-        }
-        if !new_owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*new_owner.key).into());
+impl ClaimTextureLiquidationFeesAccountIndexes {
+    pub const COUNT: usize = 6usize;
+    pub const RESERVE: usize = 0usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 1usize;
+    pub const FEE_RECEIVER: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 3usize;
+    pub const PROGRAM_AUTHORITY: usize = 4usize;
+    pub const TOKEN_PROGRAM: usize = 5usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            fee_receiver: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
         }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            texture_config,
-            owner,
-            new_owner,
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
 }
-///[SuperLendyInstruction::Version] instruction account infos helper
-#[derive(Debug)]
-pub struct VersionAccounts<'a, 'i> {
-    ///System Program.
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+impl<'a> TryFrom<&'a [u8]> for ClaimTextureLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
 }
-impl<'a, 'i> VersionAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let system_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
-        )?;
-        Ok(Self { system_program })
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for ClaimTextureLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
     }
 }
-///[SuperLendyInstruction::SetLpMetadata] instruction account infos helper
-#[derive(Debug)]
-pub struct SetLpMetadataAccounts<'a, 'i> {
-    ///Reserve to set LP metadata for
-    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///LP tokens mint. PDA.
-    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Pool - parent for Reserve
-    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Metadata account. PDA.
-    pub metadata_account: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Authority who can configure reserves.
-    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curator account.
-    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Contract's authority. PDA.
-    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Metaplex token metadata Program.
-    pub mpl_token_metadata_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///System Program.
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Sysvar rent account
-    pub sysvar_rent: &'a solana_program::account_info::AccountInfo<'i>,
+impl<const N: usize> TryFrom<[u8; N]> for ClaimTextureLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
 }
-impl<'a, 'i> SetLpMetadataAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let reserve = texture_common::utils::next_account_info(iter)?;
-        let lp_mint = texture_common::utils::next_account_info(iter)?;
-        let pool = texture_common::utils::next_account_info(iter)?;
-        let metadata_account = texture_common::utils::next_account_info(iter)?;
-        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
-        let curator = texture_common::utils::next_account_info(iter)?;
-        let program_authority = texture_common::utils::next_account_info(iter)?;
-        let mpl_token_metadata_program = texture_common::utils::next_account_info(iter)?;
-        let system_program = texture_common::utils::next_account_info(iter)?;
-        let sysvar_rent = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &SUPER_LENDY_ID,
-            "self_program_id",
-        )?;
-        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
-            .expect("rent");
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            reserve.owner,
-            &__self_program_id__,
-            concat!(stringify!(reserve), " owner"),
-        )?;
-        if !lp_mint.is_writable {
-            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            pool.owner,
-            &__self_program_id__,
-            concat!(stringify!(pool), " owner"),
-        )?;
-        if !metadata_account.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(metadata_account), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*metadata_account.key).into(),
-            );
-        }
-        if !curator_pools_authority.is_writable {
-            solana_program::msg!(
-                concat!(stringify!(curator_pools_authority), " is not writable")
-            );
-            return Err(
-                texture_common::error::InvalidAccount(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        if !curator_pools_authority.is_signer {
-            return Err(
-                texture_common::error::MissingSignature(*curator_pools_authority.key)
-                    .into(),
-            );
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curator.owner,
-            &__self_program_id__,
-            concat!(stringify!(curator), " owner"),
-        )?;
-        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
-            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
-            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+impl TryFrom<Vec<u8>> for ClaimTextureLiquidationFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::LiquidateAndRedeem] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct LiquidateAndRedeemAccountIndexes {
+    pub repayment_source_wallet: usize,
+    pub destination_lp_wallet: usize,
+    pub destination_liquidity_wallet: usize,
+    pub principal_reserve: usize,
+    pub principal_reserve_liquidity_supply: usize,
+    pub collateral_reserve: usize,
+    pub collateral_reserve_lp_supply: usize,
+    pub collateral_reserve_liquidity_supply: usize,
+    pub collateral_reserve_lp_mint: usize,
+    pub position: usize,
+    pub liquidator: usize,
+    pub principal_reserve_liquidity_mint: usize,
+    pub collateral_reserve_liquidity_mint: usize,
+    pub texture_config: usize,
+    pub program_authority: usize,
+    pub principal_token_program: usize,
+    pub collateral_token_program: usize,
+    pub collateral_liquidity_token_program: usize,
+}
+impl LiquidateAndRedeemAccountIndexes {
+    pub const COUNT: usize = 18usize;
+    pub const REPAYMENT_SOURCE_WALLET: usize = 0usize;
+    pub const DESTINATION_LP_WALLET: usize = 1usize;
+    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
+    pub const PRINCIPAL_RESERVE: usize = 3usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_SUPPLY: usize = 4usize;
+    pub const COLLATERAL_RESERVE: usize = 5usize;
+    pub const COLLATERAL_RESERVE_LP_SUPPLY: usize = 6usize;
+    pub const COLLATERAL_RESERVE_LIQUIDITY_SUPPLY: usize = 7usize;
+    pub const COLLATERAL_RESERVE_LP_MINT: usize = 8usize;
+    pub const POSITION: usize = 9usize;
+    pub const LIQUIDATOR: usize = 10usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_MINT: usize = 11usize;
+    pub const COLLATERAL_RESERVE_LIQUIDITY_MINT: usize = 12usize;
+    pub const TEXTURE_CONFIG: usize = 13usize;
+    pub const PROGRAM_AUTHORITY: usize = 14usize;
+    pub const PRINCIPAL_TOKEN_PROGRAM: usize = 15usize;
+    pub const COLLATERAL_TOKEN_PROGRAM: usize = 16usize;
+    pub const COLLATERAL_LIQUIDITY_TOKEN_PROGRAM: usize = 17usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            repayment_source_wallet: iter.next().unwrap(),
+            destination_lp_wallet: iter.next().unwrap(),
+            destination_liquidity_wallet: iter.next().unwrap(),
+            principal_reserve: iter.next().unwrap(),
+            principal_reserve_liquidity_supply: iter.next().unwrap(),
+            collateral_reserve: iter.next().unwrap(),
+            collateral_reserve_lp_supply: iter.next().unwrap(),
+            collateral_reserve_liquidity_supply: iter.next().unwrap(),
+            collateral_reserve_lp_mint: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            liquidator: iter.next().unwrap(),
+            principal_reserve_liquidity_mint: iter.next().unwrap(),
+            collateral_reserve_liquidity_mint: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            principal_token_program: iter.next().unwrap(),
+            collateral_token_program: iter.next().unwrap(),
+            collateral_liquidity_token_program: iter.next().unwrap(),
         }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            mpl_token_metadata_program.key,
-            &mpl_token_metadata::ID,
-            stringify!(mpl_token_metadata_program),
-        )?;
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
-        )?;
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
         Ok(Self {
-            reserve,
-            lp_mint,
-            pool,
-            metadata_account,
-            curator_pools_authority,
-            curator,
-            program_authority,
-            mpl_token_metadata_program,
-            system_program,
-            sysvar_rent,
+            repayment_source_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_lp_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_lp_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
         })
     }
-}
-pub(crate) mod ix_docs {
-    macro_rules! create_texture_config {
+}
+impl<'a> TryFrom<&'a [u8]> for LiquidateAndRedeemAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for LiquidateAndRedeemAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for LiquidateAndRedeemAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for LiquidateAndRedeemAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::RepayWithCollateral] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct RepayWithCollateralAccountIndexes {
+    pub position: usize,
+    pub reserve: usize,
+    pub reserve_collateral_supply: usize,
+    pub lp_mint: usize,
+    pub owner: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+}
+impl RepayWithCollateralAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 2usize;
+    pub const LP_MINT: usize = 3usize;
+    pub const OWNER: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const LP_TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for RepayWithCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RepayWithCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for RepayWithCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for RepayWithCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::RepayWithCollateralSwap] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct RepayWithCollateralSwapAccountIndexes {
+    pub position: usize,
+    pub collateral_reserve: usize,
+    pub collateral_reserve_lp_supply: usize,
+    pub collateral_reserve_liquidity_supply: usize,
+    pub collateral_reserve_lp_mint: usize,
+    pub collateral_liquidity_wallet: usize,
+    pub principal_reserve: usize,
+    pub principal_reserve_liquidity_supply: usize,
+    pub principal_liquidity_wallet: usize,
+    pub owner: usize,
+    pub collateral_reserve_liquidity_mint: usize,
+    pub principal_reserve_liquidity_mint: usize,
+    pub texture_config: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub collateral_liquidity_token_program: usize,
+    pub principal_token_program: usize,
+    pub swap_program: usize,
+}
+impl RepayWithCollateralSwapAccountIndexes {
+    pub const COUNT: usize = 18usize;
+    pub const POSITION: usize = 0usize;
+    pub const COLLATERAL_RESERVE: usize = 1usize;
+    pub const COLLATERAL_RESERVE_LP_SUPPLY: usize = 2usize;
+    pub const COLLATERAL_RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const COLLATERAL_RESERVE_LP_MINT: usize = 4usize;
+    pub const COLLATERAL_LIQUIDITY_WALLET: usize = 5usize;
+    pub const PRINCIPAL_RESERVE: usize = 6usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_SUPPLY: usize = 7usize;
+    pub const PRINCIPAL_LIQUIDITY_WALLET: usize = 8usize;
+    pub const OWNER: usize = 9usize;
+    pub const COLLATERAL_RESERVE_LIQUIDITY_MINT: usize = 10usize;
+    pub const PRINCIPAL_RESERVE_LIQUIDITY_MINT: usize = 11usize;
+    pub const TEXTURE_CONFIG: usize = 12usize;
+    pub const PROGRAM_AUTHORITY: usize = 13usize;
+    pub const LP_TOKEN_PROGRAM: usize = 14usize;
+    pub const COLLATERAL_LIQUIDITY_TOKEN_PROGRAM: usize = 15usize;
+    pub const PRINCIPAL_TOKEN_PROGRAM: usize = 16usize;
+    pub const SWAP_PROGRAM: usize = 17usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            collateral_reserve: iter.next().unwrap(),
+            collateral_reserve_lp_supply: iter.next().unwrap(),
+            collateral_reserve_liquidity_supply: iter.next().unwrap(),
+            collateral_reserve_lp_mint: iter.next().unwrap(),
+            collateral_liquidity_wallet: iter.next().unwrap(),
+            principal_reserve: iter.next().unwrap(),
+            principal_reserve_liquidity_supply: iter.next().unwrap(),
+            principal_liquidity_wallet: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            collateral_reserve_liquidity_mint: iter.next().unwrap(),
+            principal_reserve_liquidity_mint: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            collateral_liquidity_token_program: iter.next().unwrap(),
+            principal_token_program: iter.next().unwrap(),
+            swap_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_lp_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            collateral_liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            principal_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            swap_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for RepayWithCollateralSwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RepayWithCollateralSwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for RepayWithCollateralSwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for RepayWithCollateralSwapAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SwapCollateral] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SwapCollateralAccountIndexes {
+    pub position: usize,
+    pub source_reserve: usize,
+    pub source_reserve_collateral_supply: usize,
+    pub source_reserve_liquidity_supply: usize,
+    pub source_reserve_lp_mint: usize,
+    pub source_liquidity_wallet: usize,
+    pub target_reserve: usize,
+    pub target_reserve_collateral_supply: usize,
+    pub target_reserve_liquidity_supply: usize,
+    pub target_reserve_lp_mint: usize,
+    pub target_liquidity_wallet: usize,
+    pub owner: usize,
+    pub source_reserve_liquidity_mint: usize,
+    pub target_reserve_liquidity_mint: usize,
+    pub texture_config: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub source_liquidity_token_program: usize,
+    pub target_liquidity_token_program: usize,
+    pub swap_program: usize,
+}
+impl SwapCollateralAccountIndexes {
+    pub const COUNT: usize = 20usize;
+    pub const POSITION: usize = 0usize;
+    pub const SOURCE_RESERVE: usize = 1usize;
+    pub const SOURCE_RESERVE_COLLATERAL_SUPPLY: usize = 2usize;
+    pub const SOURCE_RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const SOURCE_RESERVE_LP_MINT: usize = 4usize;
+    pub const SOURCE_LIQUIDITY_WALLET: usize = 5usize;
+    pub const TARGET_RESERVE: usize = 6usize;
+    pub const TARGET_RESERVE_COLLATERAL_SUPPLY: usize = 7usize;
+    pub const TARGET_RESERVE_LIQUIDITY_SUPPLY: usize = 8usize;
+    pub const TARGET_RESERVE_LP_MINT: usize = 9usize;
+    pub const TARGET_LIQUIDITY_WALLET: usize = 10usize;
+    pub const OWNER: usize = 11usize;
+    pub const SOURCE_RESERVE_LIQUIDITY_MINT: usize = 12usize;
+    pub const TARGET_RESERVE_LIQUIDITY_MINT: usize = 13usize;
+    pub const TEXTURE_CONFIG: usize = 14usize;
+    pub const PROGRAM_AUTHORITY: usize = 15usize;
+    pub const LP_TOKEN_PROGRAM: usize = 16usize;
+    pub const SOURCE_LIQUIDITY_TOKEN_PROGRAM: usize = 17usize;
+    pub const TARGET_LIQUIDITY_TOKEN_PROGRAM: usize = 18usize;
+    pub const SWAP_PROGRAM: usize = 19usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            source_reserve: iter.next().unwrap(),
+            source_reserve_collateral_supply: iter.next().unwrap(),
+            source_reserve_liquidity_supply: iter.next().unwrap(),
+            source_reserve_lp_mint: iter.next().unwrap(),
+            source_liquidity_wallet: iter.next().unwrap(),
+            target_reserve: iter.next().unwrap(),
+            target_reserve_collateral_supply: iter.next().unwrap(),
+            target_reserve_liquidity_supply: iter.next().unwrap(),
+            target_reserve_lp_mint: iter.next().unwrap(),
+            target_liquidity_wallet: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            source_reserve_liquidity_mint: iter.next().unwrap(),
+            target_reserve_liquidity_mint: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            source_liquidity_token_program: iter.next().unwrap(),
+            target_liquidity_token_program: iter.next().unwrap(),
+            swap_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_reserve_lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_reserve_lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_reserve_liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            target_liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            swap_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SwapCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SwapCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SwapCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SwapCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::DepositAndLockCollateral] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DepositAndLockCollateralAccountIndexes {
+    pub position: usize,
+    pub reserve: usize,
+    pub source_liquidity_wallet: usize,
+    pub reserve_liquidity_supply: usize,
+    pub reserve_collateral_supply: usize,
+    pub lp_mint: usize,
+    pub liquidity_mint: usize,
+    pub owner: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub liquidity_token_program: usize,
+}
+impl DepositAndLockCollateralAccountIndexes {
+    pub const COUNT: usize = 11usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const SOURCE_LIQUIDITY_WALLET: usize = 2usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 4usize;
+    pub const LP_MINT: usize = 5usize;
+    pub const LIQUIDITY_MINT: usize = 6usize;
+    pub const OWNER: usize = 7usize;
+    pub const PROGRAM_AUTHORITY: usize = 8usize;
+    pub const LP_TOKEN_PROGRAM: usize = 9usize;
+    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 10usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            source_liquidity_wallet: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            liquidity_token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DepositAndLockCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for DepositAndLockCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DepositAndLockCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DepositAndLockCollateralAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::UnlockAndWithdrawLiquidity] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct UnlockAndWithdrawLiquidityAccountIndexes {
+    pub position: usize,
+    pub reserve: usize,
+    pub reserve_collateral_supply: usize,
+    pub reserve_liquidity_supply: usize,
+    pub lp_mint: usize,
+    pub destination_liquidity_wallet: usize,
+    pub liquidity_mint: usize,
+    pub owner: usize,
+    pub program_authority: usize,
+    pub lp_token_program: usize,
+    pub liquidity_token_program: usize,
+}
+impl UnlockAndWithdrawLiquidityAccountIndexes {
+    pub const COUNT: usize = 11usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const RESERVE_COLLATERAL_SUPPLY: usize = 2usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const LP_MINT: usize = 4usize;
+    pub const DESTINATION_LIQUIDITY_WALLET: usize = 5usize;
+    pub const LIQUIDITY_MINT: usize = 6usize;
+    pub const OWNER: usize = 7usize;
+    pub const PROGRAM_AUTHORITY: usize = 8usize;
+    pub const LP_TOKEN_PROGRAM: usize = 9usize;
+    pub const LIQUIDITY_TOKEN_PROGRAM: usize = 10usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            reserve_collateral_supply: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            lp_mint: iter.next().unwrap(),
+            destination_liquidity_wallet: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            lp_token_program: iter.next().unwrap(),
+            liquidity_token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_collateral_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            lp_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for UnlockAndWithdrawLiquidityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for UnlockAndWithdrawLiquidityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for UnlockAndWithdrawLiquidityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for UnlockAndWithdrawLiquidityAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::CreatePositionPda] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreatePositionPdaAccountIndexes {
+    pub position: usize,
+    pub pool: usize,
+    pub owner: usize,
+    pub system_program: usize,
+}
+impl CreatePositionPdaAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const POSITION: usize = 0usize;
+    pub const POOL: usize = 1usize;
+    pub const OWNER: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreatePositionPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreatePositionPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreatePositionPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreatePositionPdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ResizePosition] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ResizePositionAccountIndexes {
+    pub position: usize,
+    pub owner: usize,
+    pub system_program: usize,
+}
+impl ResizePositionAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POSITION: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ResizePositionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ResizePositionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ResizePositionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ResizePositionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SetPositionDelegate] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetPositionDelegateAccountIndexes {
+    pub position: usize,
+    pub owner: usize,
+    pub delegate: usize,
+}
+impl SetPositionDelegateAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POSITION: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const DELEGATE: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            delegate: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            delegate: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetPositionDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetPositionDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetPositionDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetPositionDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::TransferPositionOwnership] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct TransferPositionOwnershipAccountIndexes {
+    pub position: usize,
+    pub owner: usize,
+    pub new_owner: usize,
+}
+impl TransferPositionOwnershipAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POSITION: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const NEW_OWNER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            new_owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for TransferPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]>
+for TransferPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for TransferPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for TransferPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::AcceptPositionOwnership] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AcceptPositionOwnershipAccountIndexes {
+    pub position: usize,
+    pub new_owner: usize,
+}
+impl AcceptPositionOwnershipAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const POSITION: usize = 0usize;
+    pub const NEW_OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            new_owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AcceptPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AcceptPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AcceptPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AcceptPositionOwnershipAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::BorrowFixed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct BorrowFixedAccountIndexes {
+    pub position: usize,
+    pub reserve_liquidity_supply: usize,
+    pub destination_liquidity_wallet: usize,
+    pub curator_fee_receiver: usize,
+    pub borrower: usize,
+    pub reserve: usize,
+    pub pool: usize,
+    pub curator: usize,
+    pub texture_fee_receiver: usize,
+    pub texture_config: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
+}
+impl BorrowFixedAccountIndexes {
+    pub const COUNT: usize = 13usize;
+    pub const POSITION: usize = 0usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
+    pub const DESTINATION_LIQUIDITY_WALLET: usize = 2usize;
+    pub const CURATOR_FEE_RECEIVER: usize = 3usize;
+    pub const BORROWER: usize = 4usize;
+    pub const RESERVE: usize = 5usize;
+    pub const POOL: usize = 6usize;
+    pub const CURATOR: usize = 7usize;
+    pub const TEXTURE_FEE_RECEIVER: usize = 8usize;
+    pub const TEXTURE_CONFIG: usize = 9usize;
+    pub const LIQUIDITY_MINT: usize = 10usize;
+    pub const PROGRAM_AUTHORITY: usize = 11usize;
+    pub const TOKEN_PROGRAM: usize = 12usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            destination_liquidity_wallet: iter.next().unwrap(),
+            curator_fee_receiver: iter.next().unwrap(),
+            borrower: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            texture_fee_receiver: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            destination_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            borrower: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_fee_receiver: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for BorrowFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for BorrowFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for BorrowFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for BorrowFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::RepayFixed] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct RepayFixedAccountIndexes {
+    pub position: usize,
+    pub source_liquidity_wallet: usize,
+    pub reserve_liquidity_supply: usize,
+    pub user_authority: usize,
+    pub reserve: usize,
+    pub liquidity_mint: usize,
+    pub token_program: usize,
+}
+impl RepayFixedAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const POSITION: usize = 0usize;
+    pub const SOURCE_LIQUIDITY_WALLET: usize = 1usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 2usize;
+    pub const USER_AUTHORITY: usize = 3usize;
+    pub const RESERVE: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            position: iter.next().unwrap(),
+            source_liquidity_wallet: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            user_authority: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            source_liquidity_wallet: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            user_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for RepayFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RepayFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for RepayFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for RepayFixedAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::InitInsuranceFund] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct InitInsuranceFundAccountIndexes {
+    pub insurance_fund: usize,
+    pub liquidity_mint: usize,
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+}
+impl InitInsuranceFundAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const INSURANCE_FUND: usize = 0usize;
+    pub const LIQUIDITY_MINT: usize = 1usize;
+    pub const POOL: usize = 2usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 3usize;
+    pub const CURATOR: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub const SYSTEM_PROGRAM: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            insurance_fund: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            insurance_fund: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for InitInsuranceFundAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for InitInsuranceFundAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for InitInsuranceFundAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for InitInsuranceFundAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ClaimInsuranceFees] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClaimInsuranceFeesAccountIndexes {
+    pub reserve: usize,
+    pub reserve_liquidity_supply: usize,
+    pub insurance_fund: usize,
+    pub pool: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
+}
+impl ClaimInsuranceFeesAccountIndexes {
+    pub const COUNT: usize = 7usize;
+    pub const RESERVE: usize = 0usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 1usize;
+    pub const INSURANCE_FUND: usize = 2usize;
+    pub const POOL: usize = 3usize;
+    pub const LIQUIDITY_MINT: usize = 4usize;
+    pub const PROGRAM_AUTHORITY: usize = 5usize;
+    pub const TOKEN_PROGRAM: usize = 6usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            reserve: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            insurance_fund: iter.next().unwrap(),
+            pool: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            insurance_fund: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ClaimInsuranceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClaimInsuranceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ClaimInsuranceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ClaimInsuranceFeesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::SocializeLoss] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SocializeLossAccountIndexes {
+    pub pool: usize,
+    pub reserve: usize,
+    pub position: usize,
+    pub reserve_liquidity_supply: usize,
+    pub insurance_fund: usize,
+    pub liquidity_mint: usize,
+    pub program_authority: usize,
+    pub token_program: usize,
+}
+impl SocializeLossAccountIndexes {
+    pub const COUNT: usize = 8usize;
+    pub const POOL: usize = 0usize;
+    pub const RESERVE: usize = 1usize;
+    pub const POSITION: usize = 2usize;
+    pub const RESERVE_LIQUIDITY_SUPPLY: usize = 3usize;
+    pub const INSURANCE_FUND: usize = 4usize;
+    pub const LIQUIDITY_MINT: usize = 5usize;
+    pub const PROGRAM_AUTHORITY: usize = 6usize;
+    pub const TOKEN_PROGRAM: usize = 7usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            pool: iter.next().unwrap(),
+            reserve: iter.next().unwrap(),
+            position: iter.next().unwrap(),
+            reserve_liquidity_supply: iter.next().unwrap(),
+            insurance_fund: iter.next().unwrap(),
+            liquidity_mint: iter.next().unwrap(),
+            program_authority: iter.next().unwrap(),
+            token_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            position: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            reserve_liquidity_supply: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            insurance_fund: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            liquidity_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            token_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SocializeLossAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SocializeLossAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SocializeLossAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SocializeLossAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ProposeEmodeCategory] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ProposeEmodeCategoryAccountIndexes {
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+    pub texture_config: usize,
+}
+impl ProposeEmodeCategoryAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const POOL: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
+    pub const TEXTURE_CONFIG: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+            texture_config: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            texture_config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ProposeEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ProposeEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ProposeEmodeCategoryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [SuperLendyInstruction::ApplyEmodeCategoryProposal] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ApplyEmodeCategoryProposalAccountIndexes {
+    pub pool: usize,
+    pub curator_pools_authority: usize,
+    pub curator: usize,
+}
+impl ApplyEmodeCategoryProposalAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const POOL: usize = 0usize;
+    pub const CURATOR_POOLS_AUTHORITY: usize = 1usize;
+    pub const CURATOR: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            pool: iter.next().unwrap(),
+            curator_pools_authority: iter.next().unwrap(),
+            curator: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            pool: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator_pools_authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curator: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ApplyEmodeCategoryProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ApplyEmodeCategoryProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ApplyEmodeCategoryProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ApplyEmodeCategoryProposalAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+///[SuperLendyInstruction::CreateTextureConfig] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateTextureConfigAccounts<'a, 'i> {
+    ///Global config account to create. With uninitialized data.
+    ///Ownership must be already assigned to SuperLendy.
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Config owner. Will fund Config account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateTextureConfigAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !texture_config.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(texture_config), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_config.key).into(),
+            );
+        }
+        if !texture_config.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*texture_config.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        if !rent.is_exempt(texture_config.lamports(), texture_config.data_len()) {
+            solana_program::msg!(
+                concat!(stringify!(texture_config), " is not rent exempt")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_config.key).into(),
+            );
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { texture_config, owner })
+    }
+}
+///[SuperLendyInstruction::AlterTextureConfig] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterTextureConfigAccounts<'a, 'i> {
+    ///Global config account to change.
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config owner
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterTextureConfigAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !texture_config.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(texture_config), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_config.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { texture_config, owner })
+    }
+}
+///[SuperLendyInstruction::CreateCurator] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateCuratorAccounts<'a, 'i> {
+    ///Curator account to create.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global Texture config account.
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config owner
+    pub global_config_owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateCuratorAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let global_config_owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !curator.is_writable {
+            solana_program::msg!(concat!(stringify!(curator), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !curator.is_signer {
+            return Err(texture_common::error::MissingSignature(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        if !global_config_owner.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(global_config_owner), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*global_config_owner.key).into(),
+            );
+        }
+        if !global_config_owner.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*global_config_owner.key).into(),
+            );
+        }
+        Ok(Self {
+            curator,
+            texture_config,
+            global_config_owner,
+        })
+    }
+}
+///[SuperLendyInstruction::AlterCurator] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterCuratorAccounts<'a, 'i> {
+    ///Curator account to change.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the Curator account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterCuratorAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !curator.is_writable {
+            solana_program::msg!(concat!(stringify!(curator), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curator, owner })
+    }
+}
+///[SuperLendyInstruction::CreatePool] instruction account infos helper
+#[derive(Debug)]
+pub struct CreatePoolAccounts<'a, 'i> {
+    ///Pool account to create. With uninitialized data.
+    ///Ownership must be already assigned to SuperLendy.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pools authority configured in `curator` account. Will fund Pool account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreatePoolAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !pool.is_writable {
+            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        if !pool.is_signer {
+            return Err(texture_common::error::MissingSignature(*pool.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !rent.is_exempt(pool.lamports(), pool.data_len()) {
+            solana_program::msg!(concat!(stringify!(pool), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        Ok(Self {
+            pool,
+            curator_pools_authority,
+            curator,
+        })
+    }
+}
+///[SuperLendyInstruction::AlterPool] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterPoolAccounts<'a, 'i> {
+    ///Pool account to alter
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pools authority configured in `curator` account. Will fund Pool account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterPoolAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !pool.is_writable {
+            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        Ok(Self {
+            pool,
+            curator_pools_authority,
+            curator,
+        })
+    }
+}
+///[SuperLendyInstruction::CreateReserve] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateReserveAccounts<'a, 'i> {
+    ///Reserve account to create. With uninitialized data.
+    ///Ownership must be already assigned to SuperLendy.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for created Reserve.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can add new reserves in to a pool.
+    ///Will fund Reserve account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity mint of the Reserve
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity supply SPL Token wallet. Not initialized. PDA.
+    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity provider tokens mint of the Reserve. Not initialized. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Collateral supply SPL Token wallet. Not initialized. PDA.
+    pub collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price feed account to get market price for liquidity currency.
+    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - classic one
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program to manage liquidity tokens. Either classic or 2022
+    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateReserveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let market_price_feed = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        if !reserve.is_signer {
+            return Err(texture_common::error::MissingSignature(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !rent.is_exempt(reserve.lamports(), reserve.data_len()) {
+            solana_program::msg!(concat!(stringify!(reserve), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            liquidity_supply.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(liquidity_supply), " owner"),
+        )?;
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_mint.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(lp_mint), " owner"),
+        )?;
+        if !collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_supply.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_supply.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(collateral_supply), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            market_price_feed.owner,
+            &price_proxy::ID,
+            concat!(stringify!(market_price_feed), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            reserve,
+            pool,
+            curator_pools_authority,
+            curator,
+            liquidity_mint,
+            liquidity_supply,
+            lp_mint,
+            collateral_supply,
+            market_price_feed,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+            system_program,
+        })
+    }
+}
+///[SuperLendyInstruction::AlterReserve] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterReserveAccounts<'a, 'i> {
+    ///Reserve change.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for created Reserve.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price feed account to get market price for liquidity currency.
+    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterReserveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let market_price_feed = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            market_price_feed.owner,
+            &price_proxy::ID,
+            concat!(stringify!(market_price_feed), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            reserve,
+            pool,
+            market_price_feed,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::RefreshReserve] instruction account infos helper
+#[derive(Debug)]
+pub struct RefreshReserveAccounts<'a, 'i> {
+    ///Reserve account to refresh.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price feed account to get market price for liquidity currency.
+    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Interest Rate Model account. Curve account for Reserve with external IRM.
+    ///Super-lendy program id for Reserve with built-in IRM.
+    pub irm: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RefreshReserveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let market_price_feed = texture_common::utils::next_account_info(iter)?;
+        let irm = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            market_price_feed.owner,
+            &price_proxy::ID,
+            concat!(stringify!(market_price_feed), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            reserve,
+            market_price_feed,
+            irm,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::DepositLiquidity] instruction account infos helper
+#[derive(Debug)]
+pub struct DepositLiquidityAccounts<'a, 'i> {
+    ///Owner of the source_liquidity_wallet
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Source SPL Token wallet to transfer liquidity from.
+    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token wallet to receive LP tokens minted during deposit.
+    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to deposit to. Must be refreshed beforehand.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.
+    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DepositLiquidityAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        if !source_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !destination_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
+            );
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
+            );
+        }
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            authority,
+            source_liquidity_wallet,
+            destination_lp_wallet,
+            reserve,
+            liquidity_supply,
+            liquidity_mint,
+            lp_mint,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::WithdrawLiquidity] instruction account infos helper
+#[derive(Debug)]
+pub struct WithdrawLiquidityAccounts<'a, 'i> {
+    ///Owner of the source_lp_wallet
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Source SPL Token wallet to transfer LP tokens from.
+    pub source_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token wallet to receive liquidity.
+    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to withdraw from. Must be refreshed beforehand.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token wallet controlled by contract which will give the liquidity. PDA.
+    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> WithdrawLiquidityAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        let source_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let destination_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        if !source_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_lp_wallet.key).into(),
+            );
+        }
+        if !destination_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
+            );
+        }
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            authority,
+            source_lp_wallet,
+            destination_liquidity_wallet,
+            reserve,
+            liquidity_supply,
+            liquidity_mint,
+            lp_mint,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::CreatePosition] instruction account infos helper
+#[derive(Debug)]
+pub struct CreatePositionAccounts<'a, 'i> {
+    ///Position account to initialize. Allocated and owned by SuperLendy. Not initialized yet.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool the position will belong to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreatePositionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        if !position.is_signer {
+            return Err(texture_common::error::MissingSignature(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !rent.is_exempt(position.lamports(), position.data_len()) {
+            solana_program::msg!(concat!(stringify!(position), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { position, pool, owner })
+    }
+}
+///[SuperLendyInstruction::ClosePosition] instruction account infos helper
+#[derive(Debug)]
+pub struct ClosePositionAccounts<'a, 'i> {
+    ///Position account to close.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClosePositionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { position, owner })
+    }
+}
+///[SuperLendyInstruction::RefreshPosition] instruction account infos helper
+#[derive(Debug)]
+pub struct RefreshPositionAccounts<'a, 'i> {
+    ///Position account.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Collateral deposit reserve accounts - refreshed,
+    ///all in same order as listed in Position.deposits
+    pub deposits: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
+    ///Liquidity borrow reserve accounts - refreshed,
+    ///all in same order as listed in Position.borrows
+    pub borrows: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
+}
+impl<'a, 'i> RefreshPositionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        deposit_count: usize,
+        borrow_count: usize,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let deposits = {
+            let mut out = vec![];
+            for _ in 0..deposit_count {
+                out.push(texture_common::utils::next_account_info(iter)?);
+            }
+            out
+        };
+        let borrows = {
+            let mut out = vec![];
+            for _ in 0..borrow_count {
+                out.push(texture_common::utils::next_account_info(iter)?);
+            }
+            out
+        };
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        for acc in &deposits {
+            #[allow(clippy::needless_borrow)]
+            texture_common::utils::verify_key(
+                acc.owner,
+                &__self_program_id__,
+                concat!(stringify!(acc), " owner"),
+            )?;
+        }
+        for acc in &borrows {
+            #[allow(clippy::needless_borrow)]
+            texture_common::utils::verify_key(
+                acc.owner,
+                &__self_program_id__,
+                concat!(stringify!(acc), " owner"),
+            )?;
+        }
+        Ok(Self {
+            position,
+            deposits,
+            borrows,
+        })
+    }
+}
+///[SuperLendyInstruction::LockCollateral] instruction account infos helper
+#[derive(Debug)]
+pub struct LockCollateralAccounts<'a, 'i> {
+    ///Position account to lock collateral in.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet which holds LP tokens to be locked as collateral
+    pub source_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to hold locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate. Also authority for source_lp_wallet
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> LockCollateralAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let source_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !source_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_lp_wallet.key).into(),
+            );
+        }
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            source_lp_wallet,
+            reserve_collateral_supply,
+            owner,
+            reserve,
+            lp_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::UnlockCollateral] instruction account infos helper
+#[derive(Debug)]
+pub struct UnlockCollateralAccounts<'a, 'i> {
+    ///Position account to unlock collateral from
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet which will receive unlocked LP tokens
+    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> UnlockCollateralAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !destination_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
+            );
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            reserve_collateral_supply,
+            destination_lp_wallet,
+            owner,
+            reserve,
+            program_authority,
+            lp_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::Borrow] instruction account infos helper
+#[derive(Debug)]
+pub struct BorrowAccounts<'a, 'i> {
+    ///Borrowers Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet which will receive borrowed liquidity tokens
+    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority
+    pub curator_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate who borrow
+    pub borrower: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool borrow happens in.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator of the pool.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority
+    pub texture_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> BorrowAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let curator_fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let borrower = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !destination_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !curator_fee_receiver.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_fee_receiver), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_fee_receiver.key).into(),
+            );
+        }
+        if !borrower.is_signer {
+            return Err(texture_common::error::MissingSignature(*borrower.key).into());
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !texture_fee_receiver.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(texture_fee_receiver), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_fee_receiver.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            position,
+            reserve_liquidity_supply,
+            destination_liquidity_wallet,
+            curator_fee_receiver,
+            borrower,
+            reserve,
+            pool,
+            curator,
+            texture_fee_receiver,
+            texture_config,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::Repay] instruction account infos helper
+#[derive(Debug)]
+pub struct RepayAccounts<'a, 'i> {
+    ///Borrowers Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet with liquidity tokens to be used as repayment
+    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to return liquidity to. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority to transfer funds from `source_liquidity_wallet`
+    pub user_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account which is the source of LP tokens being deposited. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RepayAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let user_authority = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !source_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !user_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*user_authority.key).into(),
+            );
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        Ok(Self {
+            position,
+            source_liquidity_wallet,
+            reserve_liquidity_supply,
+            user_authority,
+            reserve,
+            liquidity_mint,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::WriteOffBadDebt] instruction account infos helper
+#[derive(Debug)]
+pub struct WriteOffBadDebtAccounts<'a, 'i> {
+    ///Pool to which bad debt position belongs
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to write off bad debt in. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Unhealthy Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can write-off bad debt from the reserve.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool's insurance fund for Reserve's liquidity mint. PDA.
+    pub insurance_fund: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> WriteOffBadDebtAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let insurance_fund = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !insurance_fund.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(insurance_fund), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*insurance_fund.key).into(),
+            );
+        }
+        Ok(Self {
+            pool,
+            reserve,
+            position,
+            curator_pools_authority,
+            curator,
+            reserve_liquidity_supply,
+            insurance_fund,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::Liquidate] instruction account infos helper
+#[derive(Debug)]
+pub struct LiquidateAccounts<'a, 'i> {
+    ///SPL token wallet to get repayment liquidity from.
+    pub repayment_source_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive LP tokens (released collateral of the liquidated Position)
+    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
+    pub principal_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to return principal liquidity to. PDA.
+    pub principal_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
+    pub collateral_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub collateral_reserve_lp_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Borrower Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidator's authority which controls `repayment_source_wallet`
+    pub liquidator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint in principal Reserve.
+    pub principal_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage principal tokens
+    pub principal_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token
+    pub collateral_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> LiquidateAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let repayment_source_wallet = texture_common::utils::next_account_info(iter)?;
+        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve = texture_common::utils::next_account_info(iter)?;
+        let collateral_reserve_lp_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let liquidator = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let principal_token_program = texture_common::utils::next_account_info(iter)?;
+        let collateral_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !repayment_source_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(repayment_source_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*repayment_source_wallet.key)
+                    .into(),
+            );
+        }
+        if !destination_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
+            );
+        }
+        if !principal_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*principal_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            principal_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(principal_reserve), " owner"),
+        )?;
+        if !principal_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve_liquidity_supply),
+                " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *principal_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !collateral_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(collateral_reserve), " owner"),
+        )?;
+        if !collateral_reserve_lp_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_lp_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve_lp_supply.key)
+                    .into(),
+            );
+        }
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !liquidator.is_signer {
+            return Err(texture_common::error::MissingSignature(*liquidator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_token_program.key,
+            &spl_token::ID,
+            stringify!(collateral_token_program),
+        )?;
+        Ok(Self {
+            repayment_source_wallet,
+            destination_lp_wallet,
+            principal_reserve,
+            principal_reserve_liquidity_supply,
+            collateral_reserve,
+            collateral_reserve_lp_supply,
+            position,
+            liquidator,
+            principal_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            principal_token_program,
+            collateral_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ClaimCuratorPerformanceFees] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimCuratorPerformanceFeesAccounts<'a, 'i> {
+    ///Reserve account to claim performance fees from. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive claimed fees. Must be ATA from curator.fees_authority.
+    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimCuratorPerformanceFeesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !fee_receiver.is_writable {
+            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
+        }
+        Ok(Self {
+            reserve,
+            reserve_liquidity_supply,
+            pool,
+            curator,
+            fee_receiver,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ClaimTexturePerformanceFees] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimTexturePerformanceFeesAccounts<'a, 'i> {
+    ///Reserve account to claim performance fees from. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive claimed fees. Must be ATA from [TextureConfig.fees_authority]
+    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimTexturePerformanceFeesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !fee_receiver.is_writable {
+            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            reserve,
+            reserve_liquidity_supply,
+            fee_receiver,
+            texture_config,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::InitRewardSupply] instruction account infos helper
+#[derive(Debug)]
+pub struct InitRewardSupplyAccounts<'a, 'i> {
+    ///Reward supply account to initialize. Uninitialized. PDA
+    pub reward_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reward token mint.
+    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool to init reward supply for.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can manage pool.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's reward authority. PDA.
+    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> InitRewardSupplyAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reward_supply = texture_common::utils::next_account_info(iter)?;
+        let reward_mint = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let reward_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reward_supply.is_writable {
+            solana_program::msg!(concat!(stringify!(reward_supply), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reward_supply.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reward_supply.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(reward_supply), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            reward_supply,
+            reward_mint,
+            pool,
+            curator_pools_authority,
+            curator,
+            reward_authority,
+            token_program,
+            system_program,
+        })
+    }
+}
+///[SuperLendyInstruction::SetRewardRules] instruction account infos helper
+#[derive(Debug)]
+pub struct SetRewardRulesAccounts<'a, 'i> {
+    ///Reserve account to set reward rules for
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for created Reserve.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reward mint accounts - all in order as in `rules`
+    pub reward_mints: Vec<&'a solana_program::account_info::AccountInfo<'i>>,
+}
+impl<'a, 'i> SetRewardRulesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        mints_count: usize,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let reward_mints = {
+            let mut out = vec![];
+            for _ in 0..mints_count {
+                out.push(texture_common::utils::next_account_info(iter)?);
+            }
+            out
+        };
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        Ok(Self {
+            reserve,
+            pool,
+            curator_pools_authority,
+            curator,
+            reward_mints,
+        })
+    }
+}
+///[SuperLendyInstruction::ClaimReward] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimRewardAccounts<'a, 'i> {
+    ///Position account to claim rewords for. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds reward tokens. PDA.
+    pub rewards_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet which will receive reward tokens
+    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner
+    pub position_owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool, position belongs to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reward token mint to claim. Determines which reward will be claimed from the Position.
+    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's reward authority. PDA.
+    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimRewardAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let rewards_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_wallet = texture_common::utils::next_account_info(iter)?;
+        let position_owner = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let reward_mint = texture_common::utils::next_account_info(iter)?;
+        let reward_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !rewards_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(rewards_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*rewards_supply.key).into(),
+            );
+        }
+        if !destination_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
+            );
+        }
+        if !position_owner.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*position_owner.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        Ok(Self {
+            position,
+            rewards_supply,
+            destination_wallet,
+            position_owner,
+            pool,
+            reward_mint,
+            reward_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::WithdrawReward] instruction account infos helper
+#[derive(Debug)]
+pub struct WithdrawRewardAccounts<'a, 'i> {
+    ///Contract managed SPL token wallet which holds reward tokens. PDA.
+    pub rewards_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token account of reward_mint to receive reward tokens.
+    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool to withdraw rewards from.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reward token mint to withdraw.
+    pub reward_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's reward authority. PDA.
+    pub reward_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> WithdrawRewardAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let rewards_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_wallet = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let reward_mint = texture_common::utils::next_account_info(iter)?;
+        let reward_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !rewards_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(rewards_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*rewards_supply.key).into(),
+            );
+        }
+        if !destination_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        Ok(Self {
+            rewards_supply,
+            destination_wallet,
+            pool,
+            curator_pools_authority,
+            curator,
+            reward_mint,
+            reward_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::FlashBorrow] instruction account infos helper
+#[derive(Debug)]
+pub struct FlashBorrowAccounts<'a, 'i> {
+    ///Reserve account to flash-borrow from.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.
+    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token account to receive flash-borrowed tokens.
+    pub destination_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Sysvar instructions account
+    pub sysvar_instructions: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> FlashBorrowAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_wallet = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let sysvar_instructions = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
+            );
+        }
+        if !destination_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_wallet.key).into(),
+            );
+        }
+        Ok(Self {
+            reserve,
+            liquidity_supply,
+            destination_wallet,
+            liquidity_mint,
+            program_authority,
+            sysvar_instructions,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::FlashRepay] instruction account infos helper
+#[derive(Debug)]
+pub struct FlashRepayAccounts<'a, 'i> {
+    ///SPL token account to transfer tokens for repayment from.
+    pub source_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to flash-repay to.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.
+    pub liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority to transfer funds from source_wallet.
+    pub user_transfer_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Sysvar instructions account
+    pub sysvar_instructions: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> FlashRepayAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let source_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let user_transfer_authority = texture_common::utils::next_account_info(iter)?;
+        let sysvar_instructions = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !source_wallet.is_writable {
+            solana_program::msg!(concat!(stringify!(source_wallet), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*source_wallet.key).into());
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*liquidity_supply.key).into(),
+            );
+        }
+        if !user_transfer_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(user_transfer_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*user_transfer_authority.key)
+                    .into(),
+            );
+        }
+        if !user_transfer_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*user_transfer_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            source_wallet,
+            reserve,
+            liquidity_supply,
+            liquidity_mint,
+            user_transfer_authority,
+            sysvar_instructions,
+            token_program,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::ProposeConfig] instruction account infos helper
+#[derive(Debug)]
+pub struct ProposeConfigAccounts<'a, 'i> {
+    ///Reserve to propose new config for
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for created Reserve.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price feed account to get market price for liquidity currency.
+    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ProposeConfigAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let market_price_feed = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            market_price_feed.owner,
+            &price_proxy::ID,
+            concat!(stringify!(market_price_feed), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            reserve,
+            pool,
+            market_price_feed,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::ApplyConfigProposal] instruction account infos helper
+#[derive(Debug)]
+pub struct ApplyConfigProposalAccounts<'a, 'i> {
+    ///Reserve to apply config proposal for
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for created Reserve.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Price feed account to get market price for liquidity currency.
+    pub market_price_feed: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ApplyConfigProposalAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let market_price_feed = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            market_price_feed.owner,
+            &price_proxy::ID,
+            concat!(stringify!(market_price_feed), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        Ok(Self {
+            reserve,
+            pool,
+            market_price_feed,
+            curator_pools_authority,
+            curator,
+        })
+    }
+}
+///[SuperLendyInstruction::DeleteReserve] instruction account infos helper
+#[derive(Debug)]
+pub struct DeleteReserveAccounts<'a, 'i> {
+    ///Reserve to delete.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves. He will reserve freed rent.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - Reserve belongs to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DeleteReserveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        Ok(Self {
+            reserve,
+            curator_pools_authority,
+            curator,
+            pool,
+        })
+    }
+}
+///[SuperLendyInstruction::TransferTextureConfigOwnership] instruction account infos helper
+#[derive(Debug)]
+pub struct TransferTextureConfigOwnershipAccounts<'a, 'i> {
+    ///Global config account.
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Current global config owner
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///New global config owner
+    pub new_owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> TransferTextureConfigOwnershipAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let new_owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !texture_config.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(texture_config), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_config.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !new_owner.is_writable {
+            solana_program::msg!(concat!(stringify!(new_owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*new_owner.key).into());
+        }
+        if !new_owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*new_owner.key).into());
+        }
+        Ok(Self {
+            texture_config,
+            owner,
+            new_owner,
+        })
+    }
+}
+///[SuperLendyInstruction::Version] instruction account infos helper
+#[derive(Debug)]
+pub struct VersionAccounts<'a, 'i> {
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> VersionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self { system_program })
+    }
+}
+///[SuperLendyInstruction::SetLpMetadata] instruction account infos helper
+#[derive(Debug)]
+pub struct SetLpMetadataAccounts<'a, 'i> {
+    ///Reserve to set LP metadata for
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool - parent for Reserve
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Metadata account. PDA.
+    pub metadata_account: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can configure reserves.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Metaplex token metadata Program.
+    pub mpl_token_metadata_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Sysvar rent account
+    pub sysvar_rent: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetLpMetadataAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let metadata_account = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let mpl_token_metadata_program = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        let sysvar_rent = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !metadata_account.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(metadata_account), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*metadata_account.key).into(),
+            );
+        }
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            mpl_token_metadata_program.key,
+            &mpl_token_metadata::ID,
+            stringify!(mpl_token_metadata_program),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            reserve,
+            lp_mint,
+            pool,
+            metadata_account,
+            curator_pools_authority,
+            curator,
+            program_authority,
+            mpl_token_metadata_program,
+            system_program,
+            sysvar_rent,
+        })
+    }
+}
+///[SuperLendyInstruction::SyncReserve] instruction account infos helper
+#[derive(Debug)]
+pub struct SyncReserveAccounts<'a, 'i> {
+    ///Reserve account to sync.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SyncReserveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        Ok(Self {
+            reserve,
+            reserve_liquidity_supply,
+        })
+    }
+}
+///[SuperLendyInstruction::SetEmodeCategory] instruction account infos helper
+#[derive(Debug)]
+pub struct SetEmodeCategoryAccounts<'a, 'i> {
+    ///Pool account to configure e-mode category in.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetEmodeCategoryAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !pool.is_writable {
+            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            pool,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::SetPositionEmode] instruction account infos helper
+#[derive(Debug)]
+pub struct SetPositionEmodeAccounts<'a, 'i> {
+    ///Position account.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool the position belongs to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetPositionEmodeAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { position, pool, owner })
+    }
+}
+///[SuperLendyInstruction::ClaimCuratorLiquidationFees] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimCuratorLiquidationFeesAccounts<'a, 'i> {
+    ///Reserve account to claim liquidation fees from.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive claimed LP tokens. Must be ATA from curator.fees_authority.
+    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage LP tokens - always classic SPL Token
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimCuratorLiquidationFeesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !fee_receiver.is_writable {
+            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            token_program.key,
+            &spl_token::ID,
+            stringify!(token_program),
+        )?;
+        Ok(Self {
+            reserve,
+            reserve_collateral_supply,
+            pool,
+            curator,
+            fee_receiver,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ClaimTextureLiquidationFees] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimTextureLiquidationFeesAccounts<'a, 'i> {
+    ///Reserve account to claim liquidation fees from.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive claimed LP tokens. Must be ATA from [TextureConfig.fees_authority]
+    pub fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage LP tokens - always classic SPL Token
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimTextureLiquidationFeesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !fee_receiver.is_writable {
+            solana_program::msg!(concat!(stringify!(fee_receiver), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*fee_receiver.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            token_program.key,
+            &spl_token::ID,
+            stringify!(token_program),
+        )?;
+        Ok(Self {
+            reserve,
+            reserve_collateral_supply,
+            fee_receiver,
+            texture_config,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::LiquidateAndRedeem] instruction account infos helper
+#[derive(Debug)]
+pub struct LiquidateAndRedeemAccounts<'a, 'i> {
+    ///SPL token wallet to get repayment liquidity from.
+    pub repayment_source_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive LP tokens when redeem is not possible.
+    pub destination_lp_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet to receive collateral Reserve's liquidity.
+    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.
+    pub principal_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to return principal liquidity to. PDA.
+    pub principal_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Reserve account to take collateral from. Refreshed.
+    pub collateral_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub collateral_reserve_lp_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with collateral Reserve's liquidity. PDA.
+    pub collateral_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///LP tokens mint of collateral Reserve. PDA.
+    pub collateral_reserve_lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Borrower Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidator's authority which controls `repayment_source_wallet`
+    pub liquidator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint in principal Reserve.
+    pub principal_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Liquidity tokens mint in collateral Reserve.
+    pub collateral_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage principal tokens
+    pub principal_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token
+    pub collateral_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral Reserve's liquidity
+    pub collateral_liquidity_token_program: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+}
+impl<'a, 'i> LiquidateAndRedeemAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let repayment_source_wallet = texture_common::utils::next_account_info(iter)?;
+        let destination_lp_wallet = texture_common::utils::next_account_info(iter)?;
+        let destination_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let principal_reserve = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve = texture_common::utils::next_account_info(iter)?;
+        let collateral_reserve_lp_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve_lp_mint = texture_common::utils::next_account_info(iter)?;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let liquidator = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let principal_token_program = texture_common::utils::next_account_info(iter)?;
+        let collateral_token_program = texture_common::utils::next_account_info(iter)?;
+        let collateral_liquidity_token_program = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !repayment_source_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(repayment_source_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*repayment_source_wallet.key)
+                    .into(),
+            );
+        }
+        if !destination_lp_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_lp_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_lp_wallet.key).into(),
+            );
+        }
+        if !destination_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !principal_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*principal_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            principal_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(principal_reserve), " owner"),
+        )?;
+        if !principal_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve_liquidity_supply),
+                " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *principal_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !collateral_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(collateral_reserve), " owner"),
+        )?;
+        if !collateral_reserve_lp_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_lp_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve_lp_supply.key)
+                    .into(),
+            );
+        }
+        if !collateral_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_liquidity_supply),
+                " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *collateral_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !collateral_reserve_lp_mint.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_lp_mint), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve_lp_mint.key)
+                    .into(),
+            );
+        }
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !liquidator.is_signer {
+            return Err(texture_common::error::MissingSignature(*liquidator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_token_program.key,
+            &spl_token::ID,
+            stringify!(collateral_token_program),
+        )?;
+        Ok(Self {
+            repayment_source_wallet,
+            destination_lp_wallet,
+            destination_liquidity_wallet,
+            principal_reserve,
+            principal_reserve_liquidity_supply,
+            collateral_reserve,
+            collateral_reserve_lp_supply,
+            collateral_reserve_liquidity_supply,
+            collateral_reserve_lp_mint,
+            position,
+            liquidator,
+            principal_reserve_liquidity_mint,
+            collateral_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            principal_token_program,
+            collateral_token_program,
+            collateral_liquidity_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::RepayWithCollateral] instruction account infos helper
+#[derive(Debug)]
+pub struct RepayWithCollateralAccounts<'a, 'i> {
+    ///Borrower's Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account the Position borrowed from and locked collateral in. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint of the Reserve. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RepayWithCollateralAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            reserve,
+            reserve_collateral_supply,
+            lp_mint,
+            owner,
+            program_authority,
+            lp_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::RepayWithCollateralSwap] instruction account infos helper
+#[derive(Debug)]
+pub struct RepayWithCollateralSwapAccounts<'a, 'i> {
+    ///Borrower's Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to take collateral from. Refreshed.
+    pub collateral_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub collateral_reserve_lp_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with collateral Reserve's liquidity. PDA.
+    pub collateral_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///LP tokens mint of collateral Reserve. PDA.
+    pub collateral_reserve_lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner's SPL token wallet to receive redeemed collateral liquidity. Swap input.
+    pub collateral_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to repay principal tokens to. Refreshed.
+    pub principal_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to return principal liquidity to. PDA.
+    pub principal_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Owner's SPL token wallet to receive principal liquidity. Swap output.
+    pub principal_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint in collateral Reserve.
+    pub collateral_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Liquidity tokens mint in principal Reserve.
+    pub principal_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral Reserve's liquidity
+    pub collateral_liquidity_token_program: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///SPL Token program used to manage principal tokens
+    pub principal_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Swap program. Must be the one set in TextureConfig.swap_program
+    pub swap_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RepayWithCollateralSwapAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let collateral_reserve = texture_common::utils::next_account_info(iter)?;
+        let collateral_reserve_lp_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let collateral_reserve_lp_mint = texture_common::utils::next_account_info(iter)?;
+        let collateral_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let principal_reserve = texture_common::utils::next_account_info(iter)?;
+        let principal_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let principal_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let collateral_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let principal_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let collateral_liquidity_token_program = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let principal_token_program = texture_common::utils::next_account_info(iter)?;
+        let swap_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !collateral_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            collateral_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(collateral_reserve), " owner"),
+        )?;
+        if !collateral_reserve_lp_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_lp_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve_lp_supply.key)
+                    .into(),
+            );
+        }
+        if !collateral_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_liquidity_supply),
+                " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *collateral_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !collateral_reserve_lp_mint.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_reserve_lp_mint), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_reserve_lp_mint.key)
+                    .into(),
+            );
+        }
+        if !collateral_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(collateral_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*collateral_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !principal_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*principal_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            principal_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(principal_reserve), " owner"),
+        )?;
+        if !principal_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_reserve_liquidity_supply),
+                " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *principal_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !principal_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(principal_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*principal_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            collateral_reserve,
+            collateral_reserve_lp_supply,
+            collateral_reserve_liquidity_supply,
+            collateral_reserve_lp_mint,
+            collateral_liquidity_wallet,
+            principal_reserve,
+            principal_reserve_liquidity_supply,
+            principal_liquidity_wallet,
+            owner,
+            collateral_reserve_liquidity_mint,
+            principal_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            lp_token_program,
+            collateral_liquidity_token_program,
+            principal_token_program,
+            swap_program,
+        })
+    }
+}
+///[SuperLendyInstruction::SwapCollateral] instruction account infos helper
+#[derive(Debug)]
+pub struct SwapCollateralAccounts<'a, 'i> {
+    ///Borrower's Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to take collateral from. Refreshed.
+    pub source_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds source Reserve's locked LP tokens. PDA.
+    pub source_reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Contract managed SPL token wallet with source Reserve's liquidity. PDA.
+    pub source_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///LP tokens mint of source Reserve. PDA.
+    pub source_reserve_lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner's SPL token wallet to receive redeemed source liquidity. Swap input.
+    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to lock new collateral in. Refreshed.
+    pub target_reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds target Reserve's locked LP tokens. PDA.
+    pub target_reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Contract managed SPL token wallet with target Reserve's liquidity. PDA.
+    pub target_reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///LP tokens mint of target Reserve. PDA.
+    pub target_reserve_lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner's SPL token wallet to receive target liquidity. Swap output.
+    pub target_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint in source Reserve.
+    pub source_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint in target Reserve.
+    pub target_reserve_liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program used to manage source Reserve's liquidity
+    pub source_liquidity_token_program: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///SPL Token program used to manage target Reserve's liquidity
+    pub target_liquidity_token_program: &'a solana_program::account_info::AccountInfo<
+        'i,
+    >,
+    ///Swap program. Must be the one set in TextureConfig.swap_program
+    pub swap_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SwapCollateralAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let source_reserve = texture_common::utils::next_account_info(iter)?;
+        let source_reserve_collateral_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let source_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let source_reserve_lp_mint = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let target_reserve = texture_common::utils::next_account_info(iter)?;
+        let target_reserve_collateral_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let target_reserve_liquidity_supply = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let target_reserve_lp_mint = texture_common::utils::next_account_info(iter)?;
+        let target_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let source_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let target_reserve_liquidity_mint = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_token_program = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let target_liquidity_token_program = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let swap_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !source_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            source_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(source_reserve), " owner"),
+        )?;
+        if !source_reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *source_reserve_collateral_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !source_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *source_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !source_reserve_lp_mint.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_reserve_lp_mint), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_reserve_lp_mint.key)
+                    .into(),
+            );
+        }
+        if !source_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !target_reserve.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(target_reserve), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*target_reserve.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            target_reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(target_reserve), " owner"),
+        )?;
+        if !target_reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(target_reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *target_reserve_collateral_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !target_reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(target_reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(
+                        *target_reserve_liquidity_supply.key,
+                    )
+                    .into(),
+            );
+        }
+        if !target_reserve_lp_mint.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(target_reserve_lp_mint), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*target_reserve_lp_mint.key)
+                    .into(),
+            );
+        }
+        if !target_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(target_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*target_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            source_reserve,
+            source_reserve_collateral_supply,
+            source_reserve_liquidity_supply,
+            source_reserve_lp_mint,
+            source_liquidity_wallet,
+            target_reserve,
+            target_reserve_collateral_supply,
+            target_reserve_liquidity_supply,
+            target_reserve_lp_mint,
+            target_liquidity_wallet,
+            owner,
+            source_reserve_liquidity_mint,
+            target_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            lp_token_program,
+            source_liquidity_token_program,
+            target_liquidity_token_program,
+            swap_program,
+        })
+    }
+}
+///[SuperLendyInstruction::DepositAndLockCollateral] instruction account infos helper
+#[derive(Debug)]
+pub struct DepositAndLockCollateralAccounts<'a, 'i> {
+    ///Position account to lock collateral in.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to deposit to. Must be refreshed beforehand.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Source SPL Token wallet to transfer liquidity from.
+    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to hold locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate. Also authority for source_liquidity_wallet
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DepositAndLockCollateralAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !source_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            reserve,
+            source_liquidity_wallet,
+            reserve_liquidity_supply,
+            reserve_collateral_supply,
+            lp_mint,
+            liquidity_mint,
+            owner,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::UnlockAndWithdrawLiquidity] instruction account infos helper
+#[derive(Debug)]
+pub struct UnlockAndWithdrawLiquidityAccounts<'a, 'i> {
+    ///Position account to unlock collateral from
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to withdraw from. Must be refreshed beforehand.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds locked LP tokens. PDA.
+    pub reserve_collateral_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Source SPL Token wallet controlled by contract which holds the liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///LP tokens mint. PDA.
+    pub lp_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL Token wallet to receive the liquidity.
+    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub lp_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub liquidity_token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> UnlockAndWithdrawLiquidityAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_collateral_supply = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let lp_mint = texture_common::utils::next_account_info(iter)?;
+        let destination_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let lp_token_program = texture_common::utils::next_account_info(iter)?;
+        let liquidity_token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_collateral_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_collateral_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_collateral_supply.key)
+                    .into(),
+            );
+        }
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !lp_mint.is_writable {
+            solana_program::msg!(concat!(stringify!(lp_mint), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*lp_mint.key).into());
+        }
+        if !destination_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            lp_token_program.key,
+            &spl_token::ID,
+            stringify!(lp_token_program),
+        )?;
+        Ok(Self {
+            position,
+            reserve,
+            reserve_collateral_supply,
+            reserve_liquidity_supply,
+            lp_mint,
+            destination_liquidity_wallet,
+            liquidity_mint,
+            owner,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::CreatePositionPda] instruction account infos helper
+#[derive(Debug)]
+pub struct CreatePositionPdaAccounts<'a, 'i> {
+    ///Position account to create. PDA of [pool, owner, POSITION_SEED, index].
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool the position will belong to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position. Pays for position account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreatePositionPdaAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(position), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            position,
+            pool,
+            owner,
+            system_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ResizePosition] instruction account infos helper
+#[derive(Debug)]
+pub struct ResizePositionAccounts<'a, 'i> {
+    ///Position account to resize.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position. Pays for increased account size.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ResizePositionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            position,
+            owner,
+            system_program,
+        })
+    }
+}
+///[SuperLendyInstruction::SetPositionDelegate] instruction account infos helper
+#[derive(Debug)]
+pub struct SetPositionDelegateAccounts<'a, 'i> {
+    ///Position account to set delegate for.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Owner of the position
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Delegate's authority
+    pub delegate: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetPositionDelegateAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let delegate = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self {
+            position,
+            owner,
+            delegate,
+        })
+    }
+}
+///[SuperLendyInstruction::TransferPositionOwnership] instruction account infos helper
+#[derive(Debug)]
+pub struct TransferPositionOwnershipAccounts<'a, 'i> {
+    ///Position account to transfer.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Current owner of the position
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Proposed owner of the position
+    pub new_owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> TransferPositionOwnershipAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let new_owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self {
+            position,
+            owner,
+            new_owner,
+        })
+    }
+}
+///[SuperLendyInstruction::AcceptPositionOwnership] instruction account infos helper
+#[derive(Debug)]
+pub struct AcceptPositionOwnershipAccounts<'a, 'i> {
+    ///Position account to accept. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///New owner of the position
+    pub new_owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AcceptPositionOwnershipAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let new_owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !new_owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*new_owner.key).into());
+        }
+        Ok(Self {
+            position,
+            new_owner,
+        })
+    }
+}
+///[SuperLendyInstruction::BorrowFixed] instruction account infos helper
+#[derive(Debug)]
+pub struct BorrowFixedAccounts<'a, 'i> {
+    ///Borrowers Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet which holds liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet which will receive borrowed liquidity tokens
+    pub destination_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority
+    pub curator_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position owner or its delegate who borrow
+    pub borrower: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to borrow from. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool borrow happens in.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator of the pool.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority
+    pub texture_fee_receiver: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> BorrowFixedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let destination_liquidity_wallet = texture_common::utils::next_account_info(
+            iter,
+        )?;
+        let curator_fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let borrower = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_fee_receiver = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !destination_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(destination_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*destination_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !curator_fee_receiver.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_fee_receiver), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_fee_receiver.key).into(),
+            );
+        }
+        if !borrower.is_signer {
+            return Err(texture_common::error::MissingSignature(*borrower.key).into());
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        if !texture_fee_receiver.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(texture_fee_receiver), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*texture_fee_receiver.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            position,
+            reserve_liquidity_supply,
+            destination_liquidity_wallet,
+            curator_fee_receiver,
+            borrower,
+            reserve,
+            pool,
+            curator,
+            texture_fee_receiver,
+            texture_config,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::RepayFixed] instruction account infos helper
+#[derive(Debug)]
+pub struct RepayFixedAccounts<'a, 'i> {
+    ///Borrowers Position account. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///User's SPL token wallet with liquidity tokens to be used as repayment
+    pub source_liquidity_wallet: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet to return liquidity to. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority to transfer funds from `source_liquidity_wallet`
+    pub user_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account the loan was taken from. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program - either classic or 2022
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RepayFixedAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let source_liquidity_wallet = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let user_authority = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !source_liquidity_wallet.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(source_liquidity_wallet), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*source_liquidity_wallet.key)
+                    .into(),
+            );
+        }
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !user_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*user_authority.key).into(),
+            );
+        }
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        Ok(Self {
+            position,
+            source_liquidity_wallet,
+            reserve_liquidity_supply,
+            user_authority,
+            reserve,
+            liquidity_mint,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::InitInsuranceFund] instruction account infos helper
+#[derive(Debug)]
+pub struct InitInsuranceFundAccounts<'a, 'i> {
+    ///Insurance fund account to initialize. Uninitialized. PDA
+    pub insurance_fund: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint.
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool to init insurance fund for.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority who can manage pool.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System Program.
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> InitInsuranceFundAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let insurance_fund = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        let rent = <solana_program::rent::Rent as solana_program::sysvar::Sysvar>::get()
+            .expect("rent");
+        if !insurance_fund.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(insurance_fund), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*insurance_fund.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            insurance_fund.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(insurance_fund), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curator_pools_authority), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        if !rent.is_exempt(curator.lamports(), curator.data_len()) {
+            solana_program::msg!(concat!(stringify!(curator), " is not rent exempt"));
+            return Err(texture_common::error::InvalidAccount(*curator.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            insurance_fund,
+            liquidity_mint,
+            pool,
+            curator_pools_authority,
+            curator,
+            program_authority,
+            token_program,
+            system_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ClaimInsuranceFees] instruction account infos helper
+#[derive(Debug)]
+pub struct ClaimInsuranceFeesAccounts<'a, 'i> {
+    ///Reserve account to claim insurance fees from. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool's insurance fund for Reserve's liquidity mint. PDA.
+    pub insurance_fund: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool the Reserve belongs to.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClaimInsuranceFeesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let insurance_fund = texture_common::utils::next_account_info(iter)?;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !insurance_fund.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(insurance_fund), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*insurance_fund.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        Ok(Self {
+            reserve,
+            reserve_liquidity_supply,
+            insurance_fund,
+            pool,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::SocializeLoss] instruction account infos helper
+#[derive(Debug)]
+pub struct SocializeLossAccounts<'a, 'i> {
+    ///Pool to which bad debt position belongs
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Reserve account to write off bad debt in. Refreshed.
+    pub reserve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Position without collateral. Refreshed.
+    pub position: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract managed SPL token wallet with Reserve's liquidity. PDA.
+    pub reserve_liquidity_supply: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pool's insurance fund for Reserve's liquidity mint. PDA.
+    pub insurance_fund: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Liquidity tokens mint
+    pub liquidity_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Contract's authority. PDA.
+    pub program_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token program
+    pub token_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SocializeLossAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let reserve = texture_common::utils::next_account_info(iter)?;
+        let position = texture_common::utils::next_account_info(iter)?;
+        let reserve_liquidity_supply = texture_common::utils::next_account_info(iter)?;
+        let insurance_fund = texture_common::utils::next_account_info(iter)?;
+        let liquidity_mint = texture_common::utils::next_account_info(iter)?;
+        let program_authority = texture_common::utils::next_account_info(iter)?;
+        let token_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !reserve.is_writable {
+            solana_program::msg!(concat!(stringify!(reserve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*reserve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            reserve.owner,
+            &__self_program_id__,
+            concat!(stringify!(reserve), " owner"),
+        )?;
+        if !position.is_writable {
+            solana_program::msg!(concat!(stringify!(position), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*position.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            position.owner,
+            &__self_program_id__,
+            concat!(stringify!(position), " owner"),
+        )?;
+        if !reserve_liquidity_supply.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(reserve_liquidity_supply), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*reserve_liquidity_supply.key)
+                    .into(),
+            );
+        }
+        if !insurance_fund.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(insurance_fund), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*insurance_fund.key).into(),
+            );
+        }
+        Ok(Self {
+            pool,
+            reserve,
+            position,
+            reserve_liquidity_supply,
+            insurance_fund,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        })
+    }
+}
+///[SuperLendyInstruction::ProposeEmodeCategory] instruction account infos helper
+#[derive(Debug)]
+pub struct ProposeEmodeCategoryAccounts<'a, 'i> {
+    ///Pool account to configure e-mode category in.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Global config account
+    pub texture_config: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ProposeEmodeCategoryAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        let texture_config = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !pool.is_writable {
+            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.owner,
+            &__self_program_id__,
+            concat!(stringify!(texture_config), " owner"),
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            texture_config.key,
+            &crate::TEXTURE_CONFIG_ID,
+            stringify!(texture_config),
+        )?;
+        Ok(Self {
+            pool,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        })
+    }
+}
+///[SuperLendyInstruction::ApplyEmodeCategoryProposal] instruction account infos helper
+#[derive(Debug)]
+pub struct ApplyEmodeCategoryProposalAccounts<'a, 'i> {
+    ///Pool account to configure e-mode category in.
+    pub pool: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pools authority configured in `curator` account.
+    pub curator_pools_authority: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curator account.
+    pub curator: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ApplyEmodeCategoryProposalAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let pool = texture_common::utils::next_account_info(iter)?;
+        let curator_pools_authority = texture_common::utils::next_account_info(iter)?;
+        let curator = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &SUPER_LENDY_ID,
+            "self_program_id",
+        )?;
+        if !pool.is_writable {
+            solana_program::msg!(concat!(stringify!(pool), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pool.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pool.owner,
+            &__self_program_id__,
+            concat!(stringify!(pool), " owner"),
+        )?;
+        if !curator_pools_authority.is_signer {
+            return Err(
+                texture_common::error::MissingSignature(*curator_pools_authority.key)
+                    .into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curator.owner,
+            &__self_program_id__,
+            concat!(stringify!(curator), " owner"),
+        )?;
+        Ok(Self {
+            pool,
+            curator_pools_authority,
+            curator,
+        })
+    }
+}
+pub(crate) mod ix_docs {
+    macro_rules! create_texture_config {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ",
+            "Global config account to create. With uninitialized data.", "\n",
+            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Config owner. Will fund Config account.", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [CreateTextureConfig]", " ",
+            "(method [into_instruction][CreateTextureConfig::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateTextureConfigAccounts]",
+            " ", "(method [from_iter][CreateTextureConfigAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreateTextureConfigAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_texture_config;
+    macro_rules! alter_texture_config {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Global config account to change.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Global config owner", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterTextureConfig]", " ",
+            "(method [into_instruction][AlterTextureConfig::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AlterTextureConfigAccounts]",
+            " ", "(method [from_iter][AlterTextureConfigAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [AlterTextureConfigAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_texture_config;
+    macro_rules! create_curator {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "Curator account to create.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global Texture config account.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ", "Global config owner",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateCurator]", " ",
+            "(method [into_instruction][CreateCurator::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [CreateCuratorAccounts]",
+            " ", "(method [from_iter][CreateCuratorAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [CreateCuratorAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_curator;
+    macro_rules! alter_curator {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curator account to change.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Owner of the Curator account.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterCurator]", " ",
+            "(method [into_instruction][AlterCurator::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AlterCuratorAccounts]",
+            " ", "(method [from_iter][AlterCuratorAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [AlterCuratorAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_curator;
+    macro_rules! create_pool {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ",
+            "Pool account to create. With uninitialized data.", "\n",
+            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Pools authority configured in `curator` account. Will fund Pool account.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator account.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreatePool]", " ",
+            "(method [into_instruction][CreatePool::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [CreatePoolAccounts]",
+            " ", "(method [from_iter][CreatePoolAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [CreatePoolAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_pool;
+    macro_rules! alter_pool {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Pool account to alter", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Pools authority configured in `curator` account. Will fund Pool account.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator account.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterPool]", " ",
+            "(method [into_instruction][AlterPool::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AlterPoolAccounts]",
+            " ", "(method [from_iter][AlterPoolAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [AlterPoolAccountIndexes].", "\n",
+            }
+        };
+    }
+    pub(crate) use alter_pool;
+    macro_rules! create_reserve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ",
+            "Reserve account to create. With uninitialized data.", "\n",
+            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can add new reserves in to a pool.", "\n",
+            "Will fund Reserve account.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity mint of the Reserve", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Liquidity supply SPL Token wallet. Not initialized. PDA.", "\n", " ", "\n",
+            "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Liquidity provider tokens mint of the Reserve. Not initialized. PDA.", "\n",
+            " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Collateral supply SPL Token wallet. Not initialized. PDA.", "\n", " ", "\n",
+            "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Price feed account to get market price for liquidity currency.", "\n", " ",
+            "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "10",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program - classic one", "\n",
+            " ", "\n", "<b><i>", "11", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program to manage liquidity tokens. Either classic or 2022", "\n",
+            " ", "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System Program.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateReserve]", " ",
+            "(method [into_instruction][CreateReserve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [CreateReserveAccounts]",
+            " ", "(method [from_iter][CreateReserveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [CreateReserveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_reserve;
+    macro_rules! alter_reserve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Reserve change.", "\n", " ", "\n", "<b><i>", "1",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Pool - parent for created Reserve.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Price feed account to get market price for liquidity currency.", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterReserve]", " ",
+            "(method [into_instruction][AlterReserve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AlterReserveAccounts]",
+            " ", "(method [from_iter][AlterReserveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [AlterReserveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_reserve;
+    macro_rules! refresh_reserve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Reserve account to refresh.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Price feed account to get market price for liquidity currency.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Interest Rate Model account. Curve account for Reserve with external IRM.",
+            "\n", "Super-lendy program id for Reserve with built-in IRM.", "\n", " ", "\n",
+            "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [RefreshReserve]", " ",
+            "(method [into_instruction][RefreshReserve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [RefreshReserveAccounts]",
+            " ", "(method [from_iter][RefreshReserveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [RefreshReserveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use refresh_reserve;
+    macro_rules! deposit_liquidity {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[signer\\]", "</b> ", "Owner of the source_liquidity_wallet", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Source SPL Token wallet to transfer liquidity from.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL Token wallet to receive LP tokens minted during deposit.", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to deposit to. Must be refreshed beforehand.", "\n", " ",
+            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>",
+            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n",
+            " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [DepositLiquidity]", " ",
+            "(method [into_instruction][DepositLiquidity::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [DepositLiquidityAccounts]",
+            " ", "(method [from_iter][DepositLiquidityAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [DepositLiquidityAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use deposit_liquidity;
+    macro_rules! withdraw_liquidity {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[signer\\]", "</b> ", "Owner of the source_lp_wallet", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Source SPL Token wallet to transfer LP tokens from.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL Token wallet to receive liquidity.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to withdraw from. Must be refreshed beforehand.", "\n", " ",
+            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL Token wallet controlled by contract which will give the liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>",
+            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n",
+            " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [WithdrawLiquidity]", " ",
+            "(method [into_instruction][WithdrawLiquidity::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [WithdrawLiquidityAccounts]",
+            " ", "(method [from_iter][WithdrawLiquidityAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [WithdrawLiquidityAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use withdraw_liquidity;
+    macro_rules! create_position {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ",
+            "Position account to initialize. Allocated and owned by SuperLendy. Not initialized yet.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool the position will belong to.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ", "Owner of the position",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreatePosition]", " ",
+            "(method [into_instruction][CreatePosition::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreatePositionAccounts]",
+            " ", "(method [from_iter][CreatePositionAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [CreatePositionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_position;
+    macro_rules! close_position {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account to close.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Owner of the position", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClosePosition]", " ",
+            "(method [into_instruction][ClosePosition::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ClosePositionAccounts]",
+            " ", "(method [from_iter][ClosePositionAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [ClosePositionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use close_position;
+    macro_rules! refresh_position {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account.", "\n", " ", "\n", "<b><i>",
+            "1", " .. ", "1", " + ", "`deposit_count`", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Collateral deposit reserve accounts - refreshed,", "\n",
+            "all in same order as listed in Position.deposits", "\n", " ", "\n",
+            "<b><i>", "1", " + ", "`deposit_count`", " .. ", "1", " + ",
+            "`deposit_count`", " + ", "`borrow_count`", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Liquidity borrow reserve accounts - refreshed,", "\n",
+            "all in same order as listed in Position.borrows", "\n", "\n", " ## Usage",
+            "\n", " ", "For create instruction use builder struct [RefreshPosition]",
+            " ", "(method [into_instruction][RefreshPosition::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [RefreshPositionAccounts]",
+            " ", "(method [from_iter][RefreshPositionAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [RefreshPositionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use refresh_position;
+    macro_rules! lock_collateral {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account to lock collateral in.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet which holds LP tokens to be locked as collateral",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to hold locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate. Also authority for source_lp_wallet", "\n", " ", "\n",
+            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [LockCollateral]", " ",
+            "(method [into_instruction][LockCollateral::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [LockCollateralAccounts]",
+            " ", "(method [from_iter][LockCollateralAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [LockCollateralAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use lock_collateral;
+    macro_rules! unlock_collateral {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account to unlock collateral from",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet which will receive unlocked LP tokens", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]",
+            "</b> ",
+            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [UnlockCollateral]", " ",
+            "(method [into_instruction][UnlockCollateral::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [UnlockCollateralAccounts]",
+            " ", "(method [from_iter][UnlockCollateralAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [UnlockCollateralAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use unlock_collateral;
+    macro_rules! borrow {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds liquidity. PDA.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet which will receive borrowed liquidity tokens", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate who borrow", "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool borrow happens in.", "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Curator of the pool.", "\n", " ", "\n", "<b><i>", "8",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority",
+            "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", " ", "\n", "<b><i>", "10", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Liquidity tokens mint.", "\n", " ", "\n", "<b><i>", "11",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ",
+            "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [Borrow]", " ",
+            "(method [into_instruction][Borrow::into_instruction]).", " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [BorrowAccounts]", " ",
+            "(method [from_iter][BorrowAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [BorrowAccountIndexes].", "\n", }
+        };
+    }
+    pub(crate) use borrow;
+    macro_rules! repay {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet with liquidity tokens to be used as repayment",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to return liquidity to. PDA.", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Authority to transfer funds from `source_liquidity_wallet`", "\n", " ",
+            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint.", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
+            " ## Usage", "\n", " ", "For create instruction use builder struct [Repay]",
+            " ", "(method [into_instruction][Repay::into_instruction]).", " ", "\n\n",
+            " ", "For parse accounts infos from processor use struct [RepayAccounts]",
+            " ", "(method [from_iter][RepayAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [RepayAccountIndexes].", "\n", }
+        };
+    }
+    pub(crate) use repay;
+    macro_rules! write_off_bad_debt {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Pool to which bad debt position belongs", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to write off bad debt in. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Unhealthy Position account. Refreshed.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can write-off bad debt from the reserve.", "\n", " ", "\n",
+            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n",
+            " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pool's insurance fund for Reserve's liquidity mint. PDA.", "\n", " ", "\n",
+            "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint",
+            "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "9",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [WriteOffBadDebt]", " ",
+            "(method [into_instruction][WriteOffBadDebt::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [WriteOffBadDebtAccounts]",
+            " ", "(method [from_iter][WriteOffBadDebtAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [WriteOffBadDebtAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use write_off_bad_debt;
+    macro_rules! liquidate {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "SPL token wallet to get repayment liquidity from.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive LP tokens (released collateral of the liquidated Position)",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to return principal liquidity to. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Borrower Position account. Refreshed.", "\n", " ", "\n", "<b><i>", "7",
+            "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Liquidator's authority which controls `repayment_source_wallet`", "\n", " ",
+            "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint in principal Reserve.", "\n", " ", "\n", "<b><i>",
+            "9", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", " ",
+            "\n", "<b><i>", "10", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "11",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage principal tokens", "\n", " ", "\n",
+            "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [Liquidate]", " ",
+            "(method [into_instruction][Liquidate::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [LiquidateAccounts]",
+            " ", "(method [from_iter][LiquidateAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [LiquidateAccountIndexes].", "\n",
+            }
+        };
+    }
+    pub(crate) use liquidate;
+    macro_rules! claim_curator_performance_fees {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "Reserve account to claim performance fees from. Refreshed.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Pool.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator account.", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive claimed fees. Must be ATA from curator.fees_authority.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>",
+            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimCuratorPerformanceFees]",
+            " ",
+            "(method [into_instruction][ClaimCuratorPerformanceFees::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClaimCuratorPerformanceFeesAccounts]",
+            " ", "(method [from_iter][ClaimCuratorPerformanceFeesAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [ClaimCuratorPerformanceFeesAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use claim_curator_performance_fees;
+    macro_rules! claim_texture_performance_fees {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ",
-            "Global config account to create. With uninitialized data.", "\n",
-            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Config owner. Will fund Config account.", "\n", "\n", " ## Usage", "\n",
-            " ", "For create instruction use builder struct [CreateTextureConfig]", " ",
-            "(method [into_instruction][CreateTextureConfig::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [CreateTextureConfigAccounts]",
-            " ", "(method [from_iter][CreateTextureConfigAccounts::from_iter]).", " ",
-            "\n\n", " ",
-            "For work with account indexes use struct [CreateTextureConfigAccountIndexes].",
+            "\\[writable\\]", "</b> ",
+            "Reserve account to claim performance fees from. Refreshed.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive claimed fees. Must be ATA from [TextureConfig.fees_authority]",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ",
+            "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimTexturePerformanceFees]",
+            " ",
+            "(method [into_instruction][ClaimTexturePerformanceFees::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClaimTexturePerformanceFeesAccounts]",
+            " ", "(method [from_iter][ClaimTexturePerformanceFeesAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [ClaimTexturePerformanceFeesAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_texture_config;
-    macro_rules! alter_texture_config {
+    pub(crate) use claim_texture_performance_fees;
+    macro_rules! init_reward_supply {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Global config account to change.", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Global config owner", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [AlterTextureConfig]", " ",
-            "(method [into_instruction][AlterTextureConfig::into_instruction]).", " ",
+            "\\[writable\\]", "</b> ",
+            "Reward supply account to initialize. Uninitialized. PDA", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ", "Reward token mint.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool to init reward supply for.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can manage pool.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's reward authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", " ", "\n",
+            "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ", "System Program.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [InitRewardSupply]", " ",
+            "(method [into_instruction][InitRewardSupply::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [AlterTextureConfigAccounts]",
-            " ", "(method [from_iter][AlterTextureConfigAccounts::from_iter]).", " ",
+            "For parse accounts infos from processor use struct [InitRewardSupplyAccounts]",
+            " ", "(method [from_iter][InitRewardSupplyAccounts::from_iter]).", " ",
             "\n\n", " ",
-            "For work with account indexes use struct [AlterTextureConfigAccountIndexes].",
+            "For work with account indexes use struct [InitRewardSupplyAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use alter_texture_config;
-    macro_rules! create_curator {
+    pub(crate) use init_reward_supply;
+    macro_rules! set_reward_rules {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ", "Curator account to create.", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Global Texture config account.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ", "Global config owner",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [CreateCurator]", " ",
-            "(method [into_instruction][CreateCurator::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [CreateCuratorAccounts]",
-            " ", "(method [from_iter][CreateCuratorAccounts::from_iter]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ", "Reserve account to set reward rules for", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "4", " .. ", "4", " + ", "`mints_count`", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Reward mint accounts - all in order as in `rules`", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetRewardRules]", " ",
+            "(method [into_instruction][SetRewardRules::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetRewardRulesAccounts]",
+            " ", "(method [from_iter][SetRewardRulesAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [CreateCuratorAccountIndexes].",
+            "For work with account indexes use struct [SetRewardRulesAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_curator;
-    macro_rules! alter_curator {
+    pub(crate) use set_reward_rules;
+    macro_rules! claim_reward {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Curator account to change.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Owner of the Curator account.", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [AlterCurator]", " ",
-            "(method [into_instruction][AlterCurator::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [AlterCuratorAccounts]",
-            " ", "(method [from_iter][AlterCuratorAccounts::from_iter]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ",
+            "Position account to claim rewords for. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds reward tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet which will receive reward tokens", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Position owner",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool, position belongs to.", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Reward token mint to claim. Determines which reward will be claimed from the Position.",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's reward authority. PDA.", "\n", " ", "\n", "<b><i>", "7",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimReward]", " ",
+            "(method [into_instruction][ClaimReward::into_instruction]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [AlterCuratorAccountIndexes].",
+            "For parse accounts infos from processor use struct [ClaimRewardAccounts]",
+            " ", "(method [from_iter][ClaimRewardAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [ClaimRewardAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use alter_curator;
-    macro_rules! create_pool {
+    pub(crate) use claim_reward;
+    macro_rules! withdraw_reward {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ",
-            "Pool account to create. With uninitialized data.", "\n",
-            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Pools authority configured in `curator` account. Will fund Pool account.",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Curator account.", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [CreatePool]", " ",
-            "(method [into_instruction][CreatePool::into_instruction]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds reward tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token account of reward_mint to receive reward tokens.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool to withdraw rewards from.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Reward token mint to withdraw.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's reward authority. PDA.",
+            "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [WithdrawReward]", " ",
+            "(method [into_instruction][WithdrawReward::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [WithdrawRewardAccounts]",
+            " ", "(method [from_iter][WithdrawRewardAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [CreatePoolAccounts]",
-            " ", "(method [from_iter][CreatePoolAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [CreatePoolAccountIndexes].",
+            "For work with account indexes use struct [WithdrawRewardAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_pool;
-    macro_rules! alter_pool {
+    pub(crate) use withdraw_reward;
+    macro_rules! flash_borrow {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Pool account to alter", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Pools authority configured in `curator` account. Will fund Pool account.",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Curator account.", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [AlterPool]", " ",
-            "(method [into_instruction][AlterPool::into_instruction]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ", "Reserve account to flash-borrow from.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token account to receive flash-borrowed tokens.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Sysvar instructions account", "\n", " ",
+            "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [FlashBorrow]", " ",
+            "(method [into_instruction][FlashBorrow::into_instruction]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [AlterPoolAccounts]",
-            " ", "(method [from_iter][AlterPoolAccounts::from_iter]).", " ", "\n\n", " ",
-            "For work with account indexes use struct [AlterPoolAccountIndexes].", "\n",
-            }
+            "For parse accounts infos from processor use struct [FlashBorrowAccounts]",
+            " ", "(method [from_iter][FlashBorrowAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [FlashBorrowAccountIndexes].",
+            "\n", }
         };
     }
-    pub(crate) use alter_pool;
-    macro_rules! create_reserve {
-        () => {
-            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ",
-            "Reserve account to create. With uninitialized data.", "\n",
-            "Ownership must be already assigned to SuperLendy.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can add new reserves in to a pool.", "\n",
-            "Will fund Reserve account.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity mint of the Reserve", "\n", " ", "\n", "<b><i>", "5",
-            "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Liquidity supply SPL Token wallet. Not initialized. PDA.", "\n", " ", "\n",
-            "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Liquidity provider tokens mint of the Reserve. Not initialized. PDA.", "\n",
-            " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Collateral supply SPL Token wallet. Not initialized. PDA.", "\n", " ", "\n",
-            "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Price feed account to get market price for liquidity currency.", "\n", " ",
-            "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "10",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program - classic one", "\n",
-            " ", "\n", "<b><i>", "11", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program to manage liquidity tokens. Either classic or 2022", "\n",
-            " ", "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "System Program.", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [CreateReserve]", " ",
-            "(method [into_instruction][CreateReserve::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [CreateReserveAccounts]",
-            " ", "(method [from_iter][CreateReserveAccounts::from_iter]).", " ", "\n\n",
+    pub(crate) use flash_borrow;
+    macro_rules! flash_repay {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "SPL token account to transfer tokens for repayment from.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to flash-repay to.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ",
+            "Authority to transfer funds from source_wallet.", "\n", " ", "\n", "<b><i>",
+            "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Sysvar instructions account", "\n",
+            " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program", "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Global config account", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [FlashRepay]", " ",
+            "(method [into_instruction][FlashRepay::into_instruction]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [CreateReserveAccountIndexes].",
+            "For parse accounts infos from processor use struct [FlashRepayAccounts]",
+            " ", "(method [from_iter][FlashRepayAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [FlashRepayAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_reserve;
-    macro_rules! alter_reserve {
+    pub(crate) use flash_repay;
+    macro_rules! propose_config {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve change.", "\n", " ", "\n", "<b><i>", "1",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Pool - parent for created Reserve.",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "\\[writable\\]", "</b> ", "Reserve to propose new config for", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
             "Price feed account to get market price for liquidity currency.", "\n", " ",
             "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
             "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
             "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
             "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account",
             "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [AlterReserve]", " ",
-            "(method [into_instruction][AlterReserve::into_instruction]).", " ", "\n\n",
+            "For create instruction use builder struct [ProposeConfig]", " ",
+            "(method [into_instruction][ProposeConfig::into_instruction]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [AlterReserveAccounts]",
-            " ", "(method [from_iter][AlterReserveAccounts::from_iter]).", " ", "\n\n",
+            "For parse accounts infos from processor use struct [ProposeConfigAccounts]",
+            " ", "(method [from_iter][ProposeConfigAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [AlterReserveAccountIndexes].",
+            "For work with account indexes use struct [ProposeConfigAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use alter_reserve;
-    macro_rules! refresh_reserve {
+    pub(crate) use propose_config;
+    macro_rules! apply_config_proposal {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve account to refresh.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "\\[writable\\]", "</b> ", "Reserve to apply config proposal for", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
             "Price feed account to get market price for liquidity currency.", "\n", " ",
-            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Interest Rate Model account.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", "\n",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", "\n",
             " ## Usage", "\n", " ",
-            "For create instruction use builder struct [RefreshReserve]", " ",
-            "(method [into_instruction][RefreshReserve::into_instruction]).", " ",
+            "For create instruction use builder struct [ApplyConfigProposal]", " ",
+            "(method [into_instruction][ApplyConfigProposal::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [RefreshReserveAccounts]",
-            " ", "(method [from_iter][RefreshReserveAccounts::from_iter]).", " ", "\n\n",
+            "For parse accounts infos from processor use struct [ApplyConfigProposalAccounts]",
+            " ", "(method [from_iter][ApplyConfigProposalAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ApplyConfigProposalAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use apply_config_proposal;
+    macro_rules! delete_reserve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Reserve to delete.", "\n", " ", "\n", "<b><i>",
+            "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can configure reserves. He will reserve freed rent.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator account.", "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Pool - Reserve belongs to.", "\n", "\n", " ## Usage",
+            "\n", " ", "For create instruction use builder struct [DeleteReserve]", " ",
+            "(method [into_instruction][DeleteReserve::into_instruction]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [RefreshReserveAccountIndexes].",
+            "For parse accounts infos from processor use struct [DeleteReserveAccounts]",
+            " ", "(method [from_iter][DeleteReserveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [DeleteReserveAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use refresh_reserve;
-    macro_rules! deposit_liquidity {
+    pub(crate) use delete_reserve;
+    macro_rules! transfer_texture_config_ownership {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[signer\\]", "</b> ", "Owner of the source_liquidity_wallet", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Source SPL Token wallet to transfer liquidity from.", "\n", " ", "\n",
-            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL Token wallet to receive LP tokens minted during deposit.", "\n", " ",
-            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account to deposit to. Must be refreshed beforehand.", "\n", " ",
-            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>",
-            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n",
-            " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [DepositLiquidity]", " ",
-            "(method [into_instruction][DepositLiquidity::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [DepositLiquidityAccounts]",
-            " ", "(method [from_iter][DepositLiquidityAccounts::from_iter]).", " ",
-            "\n\n", " ",
-            "For work with account indexes use struct [DepositLiquidityAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Global config account.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Current global config owner", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "New global config owner", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [TransferTextureConfigOwnership]",
+            " ",
+            "(method [into_instruction][TransferTextureConfigOwnership::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [TransferTextureConfigOwnershipAccounts]",
+            " ",
+            "(method [from_iter][TransferTextureConfigOwnershipAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [TransferTextureConfigOwnershipAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use deposit_liquidity;
-    macro_rules! withdraw_liquidity {
+    pub(crate) use transfer_texture_config_ownership;
+    macro_rules! version {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[signer\\]", "</b> ", "Owner of the source_lp_wallet", "\n", " ", "\n",
+            "\\[\\]", "</b> ", "System Program.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [Version]", " ",
+            "(method [into_instruction][Version::into_instruction]).", " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [VersionAccounts]", " ",
+            "(method [from_iter][VersionAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [VersionAccountIndexes].", "\n", }
+        };
+    }
+    pub(crate) use version;
+    macro_rules! set_lp_metadata {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Reserve to set LP metadata for", "\n", " ", "\n",
             "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Source SPL Token wallet to transfer LP tokens from.", "\n", " ", "\n",
-            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL Token wallet to receive liquidity.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account to withdraw from. Must be refreshed beforehand.", "\n", " ",
-            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL Token wallet controlled by contract which will give the liquidity. PDA.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>",
-            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n",
+            "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Pool - parent for Reserve", "\n", " ", "\n", "<b><i>",
+            "3", "</i></b>. <b>", "\\[writable\\]", "</b> ", "Metadata account. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable, signer\\]",
+            "</b> ", "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>",
+            "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "7",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Metaplex token metadata Program.", "\n",
             " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [WithdrawLiquidity]", " ",
-            "(method [into_instruction][WithdrawLiquidity::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [WithdrawLiquidityAccounts]",
-            " ", "(method [from_iter][WithdrawLiquidityAccounts::from_iter]).", " ",
-            "\n\n", " ",
-            "For work with account indexes use struct [WithdrawLiquidityAccountIndexes].",
+            "System Program.", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Sysvar rent account", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetLpMetadata]", " ",
+            "(method [into_instruction][SetLpMetadata::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetLpMetadataAccounts]",
+            " ", "(method [from_iter][SetLpMetadataAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetLpMetadataAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use withdraw_liquidity;
-    macro_rules! create_position {
+    pub(crate) use set_lp_metadata;
+    macro_rules! sync_reserve {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ",
-            "Position account to initialize. Allocated and owned by SuperLendy. Not initialized yet.",
-            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool the position will belong to.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ", "Owner of the position",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [CreatePosition]", " ",
-            "(method [into_instruction][CreatePosition::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [CreatePositionAccounts]",
-            " ", "(method [from_iter][CreatePositionAccounts::from_iter]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ", "Reserve account to sync.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SyncReserve]", " ",
+            "(method [into_instruction][SyncReserve::into_instruction]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [CreatePositionAccountIndexes].",
+            "For parse accounts infos from processor use struct [SyncReserveAccounts]",
+            " ", "(method [from_iter][SyncReserveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [SyncReserveAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_position;
-    macro_rules! close_position {
+    pub(crate) use sync_reserve;
+    macro_rules! set_emode_category {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Position account to close.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Owner of the position", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ClosePosition]", " ",
-            "(method [into_instruction][ClosePosition::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [ClosePositionAccounts]",
-            " ", "(method [from_iter][ClosePositionAccounts::from_iter]).", " ", "\n\n",
-            " ",
-            "For work with account indexes use struct [ClosePositionAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Pool account to configure e-mode category in.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Pools authority configured in `curator` account.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetEmodeCategory]", " ",
+            "(method [into_instruction][SetEmodeCategory::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetEmodeCategoryAccounts]",
+            " ", "(method [from_iter][SetEmodeCategoryAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [SetEmodeCategoryAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use close_position;
-    macro_rules! refresh_position {
+    pub(crate) use set_emode_category;
+    macro_rules! set_position_emode {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
             "\\[writable\\]", "</b> ", "Position account.", "\n", " ", "\n", "<b><i>",
-            "1", " .. ", "1", " + ", "`deposit_count`", "</i></b>. <b>", "\\[\\]",
-            "</b> ", "Collateral deposit reserve accounts - refreshed,", "\n",
-            "all in same order as listed in Position.deposits", "\n", " ", "\n",
-            "<b><i>", "1", " + ", "`deposit_count`", " .. ", "1", " + ",
-            "`deposit_count`", " + ", "`borrow_count`", "</i></b>. <b>", "\\[\\]",
-            "</b> ", "Liquidity borrow reserve accounts - refreshed,", "\n",
-            "all in same order as listed in Position.borrows", "\n", "\n", " ## Usage",
-            "\n", " ", "For create instruction use builder struct [RefreshPosition]",
-            " ", "(method [into_instruction][RefreshPosition::into_instruction]).", " ",
+            "1", "</i></b>. <b>", "\\[\\]", "</b> ", "Pool the position belongs to.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Owner of the position", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetPositionEmode]", " ",
+            "(method [into_instruction][SetPositionEmode::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [RefreshPositionAccounts]",
-            " ", "(method [from_iter][RefreshPositionAccounts::from_iter]).", " ",
+            "For parse accounts infos from processor use struct [SetPositionEmodeAccounts]",
+            " ", "(method [from_iter][SetPositionEmodeAccounts::from_iter]).", " ",
             "\n\n", " ",
-            "For work with account indexes use struct [RefreshPositionAccountIndexes].",
+            "For work with account indexes use struct [SetPositionEmodeAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use refresh_position;
-    macro_rules! lock_collateral {
+    pub(crate) use set_position_emode;
+    macro_rules! claim_curator_liquidation_fees {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Position account to lock collateral in.", "\n",
-            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "User's SPL token wallet which holds LP tokens to be locked as collateral",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet to hold locked LP tokens. PDA.", "\n",
-            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Position owner and also authority for source_lp_wallet", "\n", " ", "\n",
-            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
+            "\\[writable\\]", "</b> ", "Reserve account to claim liquidation fees from.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Pool.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator account.", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive claimed LP tokens. Must be ATA from curator.fees_authority.",
             "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [LockCollateral]", " ",
-            "(method [into_instruction][LockCollateral::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [LockCollateralAccounts]",
-            " ", "(method [from_iter][LockCollateralAccounts::from_iter]).", " ", "\n\n",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage LP tokens - always classic SPL Token",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimCuratorLiquidationFees]",
             " ",
-            "For work with account indexes use struct [LockCollateralAccountIndexes].",
+            "(method [into_instruction][ClaimCuratorLiquidationFees::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClaimCuratorLiquidationFeesAccounts]",
+            " ", "(method [from_iter][ClaimCuratorLiquidationFeesAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [ClaimCuratorLiquidationFeesAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use lock_collateral;
-    macro_rules! unlock_collateral {
+    pub(crate) use claim_curator_liquidation_fees;
+    macro_rules! claim_texture_liquidation_fees {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Position account to unlock collateral from",
+            "\\[writable\\]", "</b> ", "Reserve account to claim liquidation fees from.",
             "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
             "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
             " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "User's SPL token wallet which will receive unlocked LP tokens", "\n", " ",
-            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Position owner", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]",
-            "</b> ",
-            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [UnlockCollateral]", " ",
-            "(method [into_instruction][UnlockCollateral::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [UnlockCollateralAccounts]",
-            " ", "(method [from_iter][UnlockCollateralAccounts::from_iter]).", " ",
-            "\n\n", " ",
-            "For work with account indexes use struct [UnlockCollateralAccountIndexes].",
+            "SPL token wallet to receive claimed LP tokens. Must be ATA from [TextureConfig.fees_authority]",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>",
+            "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage LP tokens - always classic SPL Token",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimTextureLiquidationFees]",
+            " ",
+            "(method [into_instruction][ClaimTextureLiquidationFees::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClaimTextureLiquidationFeesAccounts]",
+            " ", "(method [from_iter][ClaimTextureLiquidationFeesAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [ClaimTextureLiquidationFeesAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use unlock_collateral;
-    macro_rules! borrow {
+    pub(crate) use claim_texture_liquidation_fees;
+    macro_rules! liquidate_and_redeem {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
-            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds liquidity. PDA.", "\n", " ",
-            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "User's SPL token wallet which will receive borrowed liquidity tokens", "\n",
-            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority",
-            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Position owner who borrow", "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>",
             "\\[writable\\]", "</b> ",
-            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
-            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool borrow happens in.", "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Curator of the pool.", "\n", " ", "\n", "<b><i>", "8",
+            "SPL token wallet to get repayment liquidity from.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive LP tokens when redeem is not possible.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet to receive collateral Reserve's liquidity.", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to return principal liquidity to. PDA.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to take collateral from. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with collateral Reserve's liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint of collateral Reserve. PDA.", "\n", " ", "\n", "<b><i>", "9",
             "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority",
-            "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Global config account", "\n", " ", "\n", "<b><i>", "10", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Liquidity tokens mint.", "\n", " ", "\n", "<b><i>", "11",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ",
-            "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
-            " ", "For create instruction use builder struct [Borrow]", " ",
-            "(method [into_instruction][Borrow::into_instruction]).", " ", "\n\n", " ",
-            "For parse accounts infos from processor use struct [BorrowAccounts]", " ",
-            "(method [from_iter][BorrowAccounts::from_iter]).", " ", "\n\n", " ",
-            "For work with account indexes use struct [BorrowAccountIndexes].", "\n", }
+            "Borrower Position account. Refreshed.", "\n", " ", "\n", "<b><i>", "10",
+            "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Liquidator's authority which controls `repayment_source_wallet`", "\n", " ",
+            "\n", "<b><i>", "11", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint in principal Reserve.", "\n", " ", "\n", "<b><i>",
+            "12", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint in collateral Reserve.", "\n", " ", "\n", "<b><i>",
+            "13", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", " ",
+            "\n", "<b><i>", "14", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "15",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage principal tokens", "\n", " ", "\n",
+            "<b><i>", "16", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token",
+            "\n", " ", "\n", "<b><i>", "17", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage collateral Reserve's liquidity", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [LiquidateAndRedeem]", " ",
+            "(method [into_instruction][LiquidateAndRedeem::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [LiquidateAndRedeemAccounts]",
+            " ", "(method [from_iter][LiquidateAndRedeemAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [LiquidateAndRedeemAccountIndexes].",
+            "\n", }
         };
     }
-    pub(crate) use borrow;
-    macro_rules! repay {
+    pub(crate) use liquidate_and_redeem;
+    macro_rules! repay_with_collateral {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
+            "\\[writable\\]", "</b> ", "Borrower's Position account. Refreshed.", "\n",
             " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "User's SPL token wallet with liquidity tokens to be used as repayment",
+            "Reserve account the Position borrowed from and locked collateral in. Refreshed.",
             "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet to return liquidity to. PDA.", "\n", " ",
-            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Authority to transfer funds from `source_liquidity_wallet`", "\n", " ",
-            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account which is the source of LP tokens being deposited. Refreshed.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint.", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "SPL Token program - either classic or 2022", "\n", "\n",
-            " ## Usage", "\n", " ", "For create instruction use builder struct [Repay]",
-            " ", "(method [into_instruction][Repay::into_instruction]).", " ", "\n\n",
-            " ", "For parse accounts infos from processor use struct [RepayAccounts]",
-            " ", "(method [from_iter][RepayAccounts::from_iter]).", " ", "\n\n", " ",
-            "For work with account indexes use struct [RepayAccountIndexes].", "\n", }
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint of the Reserve. PDA.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[signer\\]", "</b> ", "Position owner or its delegate", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [RepayWithCollateral]", " ",
+            "(method [into_instruction][RepayWithCollateral::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [RepayWithCollateralAccounts]",
+            " ", "(method [from_iter][RepayWithCollateralAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [RepayWithCollateralAccountIndexes].",
+            "\n", }
         };
     }
-    pub(crate) use repay;
-    macro_rules! write_off_bad_debt {
+    pub(crate) use repay_with_collateral;
+    macro_rules! repay_with_collateral_swap {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Pool to which bad debt position belongs", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account to write off bad debt in. Refreshed.", "\n", " ", "\n",
+            "\\[writable\\]", "</b> ", "Borrower's Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to take collateral from. Refreshed.", "\n", " ", "\n",
             "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Unhealthy Position account. Refreshed.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can write-off bad debt from the reserve.", "\n", " ", "\n",
-            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with collateral Reserve's liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint of collateral Reserve. PDA.", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Owner's SPL token wallet to receive redeemed collateral liquidity. Swap input.",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to repay principal tokens to. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "7", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to return principal liquidity to. PDA.",
+            "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Owner's SPL token wallet to receive principal liquidity. Swap output.",
+            "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner", "\n", " ", "\n", "<b><i>", "10", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Liquidity tokens mint in collateral Reserve.", "\n", " ", "\n",
+            "<b><i>", "11", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint in principal Reserve.", "\n", " ", "\n", "<b><i>",
+            "12", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", " ",
+            "\n", "<b><i>", "13", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "14",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token",
+            "\n", " ", "\n", "<b><i>", "15", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage collateral Reserve's liquidity", "\n", " ",
+            "\n", "<b><i>", "16", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage principal tokens", "\n", " ", "\n",
+            "<b><i>", "17", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Swap program. Must be the one set in TextureConfig.swap_program", "\n",
             "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [WriteOffBadDebt]", " ",
-            "(method [into_instruction][WriteOffBadDebt::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [WriteOffBadDebtAccounts]",
-            " ", "(method [from_iter][WriteOffBadDebtAccounts::from_iter]).", " ",
-            "\n\n", " ",
-            "For work with account indexes use struct [WriteOffBadDebtAccountIndexes].",
+            "For create instruction use builder struct [RepayWithCollateralSwap]", " ",
+            "(method [into_instruction][RepayWithCollateralSwap::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [RepayWithCollateralSwapAccounts]",
+            " ", "(method [from_iter][RepayWithCollateralSwapAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [RepayWithCollateralSwapAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use write_off_bad_debt;
-    macro_rules! liquidate {
+    pub(crate) use repay_with_collateral_swap;
+    macro_rules! swap_collateral {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "SPL token wallet to get repayment liquidity from.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token wallet to receive LP tokens (released collateral of the liquidated Position)",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.",
+            "\\[writable\\]", "</b> ", "Borrower's Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to take collateral from. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds source Reserve's locked LP tokens. PDA.",
             "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet to return principal liquidity to. PDA.",
-            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Reserve account to repay principal tokens owed by unhealthy Position. Refreshed.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
-            " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Borrower Position account. Refreshed.", "\n", " ", "\n", "<b><i>", "7",
-            "</i></b>. <b>", "\\[signer\\]", "</b> ",
-            "Liquidator's authority which controls `repayment_source_wallet`", "\n", " ",
-            "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint in principal Reserve.", "\n", " ", "\n", "<b><i>",
-            "9", "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n",
-            " ", "\n", "<b><i>", "10", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program used to manage principal tokens", "\n", " ", "\n",
-            "<b><i>", "11", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract managed SPL token wallet with source Reserve's liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint of source Reserve. PDA.", "\n", " ", "\n", "<b><i>", "5",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Owner's SPL token wallet to receive redeemed source liquidity. Swap input.",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to lock new collateral in. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "7", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds target Reserve's locked LP tokens. PDA.",
+            "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with target Reserve's liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint of target Reserve. PDA.", "\n", " ", "\n", "<b><i>", "10",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Owner's SPL token wallet to receive target liquidity. Swap output.", "\n",
+            " ", "\n", "<b><i>", "11", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner", "\n", " ", "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Liquidity tokens mint in source Reserve.", "\n", " ", "\n",
+            "<b><i>", "13", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Liquidity tokens mint in target Reserve.", "\n", " ", "\n", "<b><i>", "14",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account", "\n", " ", "\n",
+            "<b><i>", "15", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "16",
+            "</i></b>. <b>", "\\[\\]", "</b> ",
             "SPL Token program used to manage collateral tokens (LPs) - always classic SPL Token",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [Liquidate]", " ",
-            "(method [into_instruction][Liquidate::into_instruction]).", " ", "\n\n",
+            "\n", " ", "\n", "<b><i>", "17", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage source Reserve's liquidity", "\n", " ",
+            "\n", "<b><i>", "18", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program used to manage target Reserve's liquidity", "\n", " ",
+            "\n", "<b><i>", "19", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Swap program. Must be the one set in TextureConfig.swap_program", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SwapCollateral]", " ",
+            "(method [into_instruction][SwapCollateral::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SwapCollateralAccounts]",
+            " ", "(method [from_iter][SwapCollateralAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [LiquidateAccounts]",
-            " ", "(method [from_iter][LiquidateAccounts::from_iter]).", " ", "\n\n", " ",
-            "For work with account indexes use struct [LiquidateAccountIndexes].", "\n",
-            }
+            "For work with account indexes use struct [SwapCollateralAccountIndexes].",
+            "\n", }
         };
     }
-    pub(crate) use liquidate;
-    macro_rules! claim_curator_performance_fees {
+    pub(crate) use swap_collateral;
+    macro_rules! deposit_and_lock_collateral {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "Reserve account to claim performance fees from. Refreshed.", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
-            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Pool.", "\n",
-            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Curator account.", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "SPL token wallet to receive claimed fees. Must be ATA from curator.fees_authority.",
-            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>",
-            "7", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ClaimCuratorPerformanceFees]",
+            "\\[writable\\]", "</b> ", "Position account to lock collateral in.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to deposit to. Must be refreshed beforehand.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Source SPL Token wallet to transfer liquidity from.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Destination SPL Token wallet controlled by contract which will receive the liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to hold locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "7",
+            "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate. Also authority for source_liquidity_wallet", "\n", " ",
+            "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "9",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", " ", "\n",
+            "<b><i>", "10", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [DepositAndLockCollateral]",
             " ",
-            "(method [into_instruction][ClaimCuratorPerformanceFees::into_instruction]).",
+            "(method [into_instruction][DepositAndLockCollateral::into_instruction]).",
             " ", "\n\n", " ",
-            "For parse accounts infos from processor use struct [ClaimCuratorPerformanceFeesAccounts]",
-            " ", "(method [from_iter][ClaimCuratorPerformanceFeesAccounts::from_iter]).",
+            "For parse accounts infos from processor use struct [DepositAndLockCollateralAccounts]",
+            " ", "(method [from_iter][DepositAndLockCollateralAccounts::from_iter]).",
             " ", "\n\n", " ",
-            "For work with account indexes use struct [ClaimCuratorPerformanceFeesAccountIndexes].",
+            "For work with account indexes use struct [DepositAndLockCollateralAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use claim_curator_performance_fees;
-    macro_rules! claim_texture_performance_fees {
+    pub(crate) use deposit_and_lock_collateral;
+    macro_rules! unlock_and_withdraw_liquidity {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account to unlock collateral from",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to withdraw from. Must be refreshed beforehand.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds locked LP tokens. PDA.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Source SPL Token wallet controlled by contract which holds the liquidity. PDA.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>",
             "\\[writable\\]", "</b> ",
-            "Reserve account to claim performance fees from. Refreshed.", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
-            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token wallet to receive claimed fees. Must be ATA from [TextureConfig.fees_authority]",
-            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Global config account", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "5",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ",
-            "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ClaimTexturePerformanceFees]",
+            "User's SPL Token wallet to receive the liquidity.", "\n", " ", "\n",
+            "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint",
+            "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate", "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "9",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", " ", "\n",
+            "<b><i>", "10", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
             " ",
-            "(method [into_instruction][ClaimTexturePerformanceFees::into_instruction]).",
+            "For create instruction use builder struct [UnlockAndWithdrawLiquidity]",
+            " ",
+            "(method [into_instruction][UnlockAndWithdrawLiquidity::into_instruction]).",
             " ", "\n\n", " ",
-            "For parse accounts infos from processor use struct [ClaimTexturePerformanceFeesAccounts]",
-            " ", "(method [from_iter][ClaimTexturePerformanceFeesAccounts::from_iter]).",
+            "For parse accounts infos from processor use struct [UnlockAndWithdrawLiquidityAccounts]",
+            " ", "(method [from_iter][UnlockAndWithdrawLiquidityAccounts::from_iter]).",
             " ", "\n\n", " ",
-            "For work with account indexes use struct [ClaimTexturePerformanceFeesAccountIndexes].",
+            "For work with account indexes use struct [UnlockAndWithdrawLiquidityAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use claim_texture_performance_fees;
-    macro_rules! init_reward_supply {
+    pub(crate) use unlock_and_withdraw_liquidity;
+    macro_rules! create_position_pda {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
             "\\[writable\\]", "</b> ",
-            "Reward supply account to initialize. Uninitialized. PDA", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ", "Reward token mint.",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool to init reward supply for.", "\n", " ", "\n", "<b><i>", "3",
+            "Position account to create. PDA of [pool, owner, POSITION_SEED, index].",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool the position will belong to.", "\n", " ", "\n", "<b><i>", "2",
             "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can manage pool.", "\n", " ", "\n", "<b><i>", "4",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's reward authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", " ", "\n",
-            "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ", "System Program.", "\n",
+            "Owner of the position. Pays for position account.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ", "System Program.", "\n",
             "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [InitRewardSupply]", " ",
-            "(method [into_instruction][InitRewardSupply::into_instruction]).", " ",
+            "For create instruction use builder struct [CreatePositionPda]", " ",
+            "(method [into_instruction][CreatePositionPda::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [InitRewardSupplyAccounts]",
-            " ", "(method [from_iter][InitRewardSupplyAccounts::from_iter]).", " ",
+            "For parse accounts infos from processor use struct [CreatePositionPdaAccounts]",
+            " ", "(method [from_iter][CreatePositionPdaAccounts::from_iter]).", " ",
             "\n\n", " ",
-            "For work with account indexes use struct [InitRewardSupplyAccountIndexes].",
+            "For work with account indexes use struct [CreatePositionPdaAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use init_reward_supply;
-    macro_rules! set_reward_rules {
+    pub(crate) use create_position_pda;
+    macro_rules! resize_position {
         () => {
-            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve account to set reward rules for", "\n",
-            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "4", " .. ", "4", " + ", "`mints_count`", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Reward mint accounts - all in order as in `rules`", "\n",
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Position account to resize.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Owner of the position. Pays for increased account size.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "System Program.", "\n",
             "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [SetRewardRules]", " ",
-            "(method [into_instruction][SetRewardRules::into_instruction]).", " ",
+            "For create instruction use builder struct [ResizePosition]", " ",
+            "(method [into_instruction][ResizePosition::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [SetRewardRulesAccounts]",
-            " ", "(method [from_iter][SetRewardRulesAccounts::from_iter]).", " ", "\n\n",
+            "For parse accounts infos from processor use struct [ResizePositionAccounts]",
+            " ", "(method [from_iter][ResizePositionAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [SetRewardRulesAccountIndexes].",
+            "For work with account indexes use struct [ResizePositionAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use set_reward_rules;
-    macro_rules! claim_reward {
+    pub(crate) use resize_position;
+    macro_rules! set_position_delegate {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "Position account to claim rewords for. Refreshed.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds reward tokens. PDA.", "\n",
-            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "User's SPL token wallet which will receive reward tokens", "\n", " ", "\n",
-            "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Position owner",
-            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool, position belongs to.", "\n", " ", "\n", "<b><i>", "5",
-            "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Reward token mint to claim. Determines which reward will be claimed from the Position.",
-            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's reward authority. PDA.", "\n", " ", "\n", "<b><i>", "7",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ClaimReward]", " ",
-            "(method [into_instruction][ClaimReward::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [ClaimRewardAccounts]",
-            " ", "(method [from_iter][ClaimRewardAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [ClaimRewardAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Position account to set delegate for.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Owner of the position", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Delegate's authority", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [SetPositionDelegate]", " ",
+            "(method [into_instruction][SetPositionDelegate::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetPositionDelegateAccounts]",
+            " ", "(method [from_iter][SetPositionDelegateAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [SetPositionDelegateAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use claim_reward;
-    macro_rules! withdraw_reward {
+    pub(crate) use set_position_delegate;
+    macro_rules! transfer_position_ownership {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds reward tokens. PDA.", "\n",
-            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token account of reward_mint to receive reward tokens.", "\n", " ",
-            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool to withdraw rewards from.", "\n", " ", "\n", "<b><i>", "3",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Reward token mint to withdraw.", "\n", " ", "\n", "<b><i>", "6",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's reward authority. PDA.",
-            "\n", " ", "\n", "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [WithdrawReward]", " ",
-            "(method [into_instruction][WithdrawReward::into_instruction]).", " ",
-            "\n\n", " ",
-            "For parse accounts infos from processor use struct [WithdrawRewardAccounts]",
-            " ", "(method [from_iter][WithdrawRewardAccounts::from_iter]).", " ", "\n\n",
-            " ",
-            "For work with account indexes use struct [WithdrawRewardAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Position account to transfer.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Current owner of the position", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Proposed owner of the position", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [TransferPositionOwnership]", " ",
+            "(method [into_instruction][TransferPositionOwnership::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [TransferPositionOwnershipAccounts]",
+            " ", "(method [from_iter][TransferPositionOwnershipAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [TransferPositionOwnershipAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use withdraw_reward;
-    macro_rules! flash_borrow {
+    pub(crate) use transfer_position_ownership;
+    macro_rules! accept_position_ownership {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve account to flash-borrow from.", "\n",
-            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.",
-            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "SPL token account to receive flash-borrowed tokens.", "\n", " ", "\n",
-            "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint",
-            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "5",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Sysvar instructions account", "\n", " ",
-            "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [FlashBorrow]", " ",
-            "(method [into_instruction][FlashBorrow::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [FlashBorrowAccounts]",
-            " ", "(method [from_iter][FlashBorrowAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [FlashBorrowAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Position account to accept. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "New owner of the position", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AcceptPositionOwnership]", " ",
+            "(method [into_instruction][AcceptPositionOwnership::into_instruction]).",
+            " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [AcceptPositionOwnershipAccounts]",
+            " ", "(method [from_iter][AcceptPositionOwnershipAccounts::from_iter]).",
+            " ", "\n\n", " ",
+            "For work with account indexes use struct [AcceptPositionOwnershipAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use flash_borrow;
-    macro_rules! flash_repay {
+    pub(crate) use accept_position_ownership;
+    macro_rules! borrow_fixed {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ",
-            "SPL token account to transfer tokens for repayment from.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Reserve account to flash-repay to.", "\n", " ", "\n", "<b><i>", "2",
+            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet which holds liquidity. PDA.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet which will receive borrowed liquidity tokens", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "SPL token wallet which will receive loan origination fee. ATA from curator.fee_authority",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Position owner or its delegate who borrow", "\n", " ", "\n", "<b><i>", "5",
             "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "Contract managed SPL token wallet which holds Reserve's liquidity tokens. PDA.",
-            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Liquidity tokens mint", "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ",
-            "Authority to transfer funds from source_wallet.", "\n", " ", "\n", "<b><i>",
-            "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Sysvar instructions account", "\n",
-            " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "SPL Token program", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [FlashRepay]", " ",
-            "(method [into_instruction][FlashRepay::into_instruction]).", " ", "\n\n",
+            "Reserve account to borrow from. Refreshed.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Pool borrow happens in.", "\n", " ",
+            "\n", "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Curator of the pool.", "\n", " ", "\n", "<b><i>", "8", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "SPL token wallet which will receive loan origination fee. Must be ATA from GlobalConfig.fees_authority",
+            "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", " ", "\n", "<b><i>", "10", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Liquidity tokens mint.", "\n", " ", "\n", "<b><i>", "11",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Contract's authority. PDA.", "\n", " ",
+            "\n", "<b><i>", "12", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [BorrowFixed]", " ",
+            "(method [into_instruction][BorrowFixed::into_instruction]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [FlashRepayAccounts]",
-            " ", "(method [from_iter][FlashRepayAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [FlashRepayAccountIndexes].",
+            "For parse accounts infos from processor use struct [BorrowFixedAccounts]",
+            " ", "(method [from_iter][BorrowFixedAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [BorrowFixedAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use flash_repay;
-    macro_rules! propose_config {
+    pub(crate) use borrow_fixed;
+    macro_rules! repay_fixed {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve to propose new config for", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Price feed account to get market price for liquidity currency.", "\n", " ",
-            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Global config account",
-            "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ProposeConfig]", " ",
-            "(method [into_instruction][ProposeConfig::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [ProposeConfigAccounts]",
-            " ", "(method [from_iter][ProposeConfigAccounts::from_iter]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ", "Borrowers Position account. Refreshed.", "\n",
+            " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "User's SPL token wallet with liquidity tokens to be used as repayment",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet to return liquidity to. PDA.", "\n", " ",
+            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Authority to transfer funds from `source_liquidity_wallet`", "\n", " ",
+            "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account the loan was taken from. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint.",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token program - either classic or 2022", "\n", "\n", " ## Usage", "\n",
+            " ", "For create instruction use builder struct [RepayFixed]", " ",
+            "(method [into_instruction][RepayFixed::into_instruction]).", " ", "\n\n",
             " ",
-            "For work with account indexes use struct [ProposeConfigAccountIndexes].",
+            "For parse accounts infos from processor use struct [RepayFixedAccounts]",
+            " ", "(method [from_iter][RepayFixedAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [RepayFixedAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use propose_config;
-    macro_rules! apply_config_proposal {
+    pub(crate) use repay_fixed;
+    macro_rules! init_insurance_fund {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve to apply config proposal for", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Pool - parent for created Reserve.", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Price feed account to get market price for liquidity currency.", "\n", " ",
-            "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>", "4",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", "\n",
-            " ## Usage", "\n", " ",
-            "For create instruction use builder struct [ApplyConfigProposal]", " ",
-            "(method [into_instruction][ApplyConfigProposal::into_instruction]).", " ",
+            "\\[writable\\]", "</b> ",
+            "Insurance fund account to initialize. Uninitialized. PDA", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool to init insurance fund for.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Authority who can manage pool.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", " ", "\n",
+            "<b><i>", "7", "</i></b>. <b>", "\\[\\]", "</b> ", "System Program.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [InitInsuranceFund]", " ",
+            "(method [into_instruction][InitInsuranceFund::into_instruction]).", " ",
             "\n\n", " ",
-            "For parse accounts infos from processor use struct [ApplyConfigProposalAccounts]",
-            " ", "(method [from_iter][ApplyConfigProposalAccounts::from_iter]).", " ",
+            "For parse accounts infos from processor use struct [InitInsuranceFundAccounts]",
+            " ", "(method [from_iter][InitInsuranceFundAccounts::from_iter]).", " ",
             "\n\n", " ",
-            "For work with account indexes use struct [ApplyConfigProposalAccountIndexes].",
+            "For work with account indexes use struct [InitInsuranceFundAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use apply_config_proposal;
-    macro_rules! delete_reserve {
+    pub(crate) use init_insurance_fund;
+    macro_rules! claim_insurance_fees {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Reserve to delete.", "\n", " ", "\n", "<b><i>",
-            "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Authority who can configure reserves. He will reserve freed rent.", "\n",
-            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Curator account.", "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Pool - Reserve belongs to.", "\n", "\n", " ## Usage",
-            "\n", " ", "For create instruction use builder struct [DeleteReserve]", " ",
-            "(method [into_instruction][DeleteReserve::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [DeleteReserveAccounts]",
-            " ", "(method [from_iter][DeleteReserveAccounts::from_iter]).", " ", "\n\n",
-            " ",
-            "For work with account indexes use struct [DeleteReserveAccountIndexes].",
+            "\\[writable\\]", "</b> ",
+            "Reserve account to claim insurance fees from. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pool's insurance fund for Reserve's liquidity mint. PDA.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Pool the Reserve belongs to.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "6",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClaimInsuranceFees]", " ",
+            "(method [into_instruction][ClaimInsuranceFees::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClaimInsuranceFeesAccounts]",
+            " ", "(method [from_iter][ClaimInsuranceFeesAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ClaimInsuranceFeesAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use delete_reserve;
-    macro_rules! transfer_texture_config_ownership {
+    pub(crate) use claim_insurance_fees;
+    macro_rules! socialize_loss {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Global config account.", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Current global config owner", "\n", " ", "\n", "<b><i>", "2",
-            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "New global config owner", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [TransferTextureConfigOwnership]",
+            "\\[\\]", "</b> ", "Pool to which bad debt position belongs", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Reserve account to write off bad debt in. Refreshed.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Position without collateral. Refreshed.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Contract managed SPL token wallet with Reserve's liquidity. PDA.", "\n",
+            " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pool's insurance fund for Reserve's liquidity mint. PDA.", "\n", " ", "\n",
+            "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Liquidity tokens mint",
+            "\n", " ", "\n", "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "7",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SocializeLoss]", " ",
+            "(method [into_instruction][SocializeLoss::into_instruction]).", " ", "\n\n",
             " ",
-            "(method [into_instruction][TransferTextureConfigOwnership::into_instruction]).",
-            " ", "\n\n", " ",
-            "For parse accounts infos from processor use struct [TransferTextureConfigOwnershipAccounts]",
+            "For parse accounts infos from processor use struct [SocializeLossAccounts]",
+            " ", "(method [from_iter][SocializeLossAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "(method [from_iter][TransferTextureConfigOwnershipAccounts::from_iter]).",
-            " ", "\n\n", " ",
-            "For work with account indexes use struct [TransferTextureConfigOwnershipAccountIndexes].",
+            "For work with account indexes use struct [SocializeLossAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use transfer_texture_config_ownership;
-    macro_rules! version {
+    pub(crate) use socialize_loss;
+    macro_rules! propose_emode_category {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "System Program.", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [Version]", " ",
-            "(method [into_instruction][Version::into_instruction]).", " ", "\n\n", " ",
-            "For parse accounts infos from processor use struct [VersionAccounts]", " ",
-            "(method [from_iter][VersionAccounts::from_iter]).", " ", "\n\n", " ",
-            "For work with account indexes use struct [VersionAccountIndexes].", "\n", }
+            "\\[writable\\]", "</b> ", "Pool account to configure e-mode category in.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Pools authority configured in `curator` account.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "Global config account", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ProposeEmodeCategory]", " ",
+            "(method [into_instruction][ProposeEmodeCategory::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [ProposeEmodeCategoryAccounts]",
+            " ", "(method [from_iter][ProposeEmodeCategoryAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ProposeEmodeCategoryAccountIndexes].",
+            "\n", }
         };
     }
-    pub(crate) use version;
-    macro_rules! set_lp_metadata {
+    pub(crate) use propose_emode_category;
+    macro_rules! apply_emode_category_proposal {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Reserve to set LP metadata for", "\n", " ", "\n",
-            "<b><i>", "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
-            "LP tokens mint. PDA.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>",
-            "\\[\\]", "</b> ", "Pool - parent for Reserve", "\n", " ", "\n", "<b><i>",
-            "3", "</i></b>. <b>", "\\[writable\\]", "</b> ", "Metadata account. PDA.",
-            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable, signer\\]",
-            "</b> ", "Authority who can configure reserves.", "\n", " ", "\n", "<b><i>",
-            "5", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n", " ", "\n",
-            "<b><i>", "6", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "Contract's authority. PDA.", "\n", " ", "\n", "<b><i>", "7",
-            "</i></b>. <b>", "\\[\\]", "</b> ", "Metaplex token metadata Program.", "\n",
-            " ", "\n", "<b><i>", "8", "</i></b>. <b>", "\\[\\]", "</b> ",
-            "System Program.", "\n", " ", "\n", "<b><i>", "9", "</i></b>. <b>", "\\[\\]",
-            "</b> ", "Sysvar rent account", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [SetLpMetadata]", " ",
-            "(method [into_instruction][SetLpMetadata::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [SetLpMetadataAccounts]",
-            " ", "(method [from_iter][SetLpMetadataAccounts::from_iter]).", " ", "\n\n",
-            " ",
-            "For work with account indexes use struct [SetLpMetadataAccountIndexes].",
+            "\\[writable\\]", "</b> ", "Pool account to configure e-mode category in.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Pools authority configured in `curator` account.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Curator account.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ApplyEmodeCategoryProposal]", " ",
+            "(method [into_instruction][ApplyEmodeCategoryProposal::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [ApplyEmodeCategoryProposalAccounts]",
+            " ", "(method [from_iter][ApplyEmodeCategoryProposalAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ApplyEmodeCategoryProposalAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use set_lp_metadata;
+    pub(crate) use apply_emode_category_proposal;
 }
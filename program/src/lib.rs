@@ -1,4 +1,5 @@
 pub mod error;
+pub mod event;
 pub mod instruction;
 #[cfg(feature = "with-processor")]
 pub mod processor;
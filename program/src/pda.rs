@@ -12,6 +12,8 @@ pub const AUTHORITY_SEED: &[u8] = b"AUTHORITY";
 
 pub const REWARD_SUPPLY_SEED: &[u8] = b"REWARD_SUPPLY";
 
+pub const INSURANCE_FUND_SEED: &[u8] = b"INSURANCE_FUND";
+
 /// LP token mints are unique for each Reserve
 pub fn find_lp_token_mint(reserve: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[reserve.as_ref(), LP_TOKEN_SEED], &SUPER_LENDY_ID)
@@ -28,6 +30,15 @@ pub fn find_collateral_supply(reserve: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[reserve.as_ref(), COLLATERAL_SUPPLY_SEED], &SUPER_LENDY_ID)
 }
 
+/// Position created by CreatePositionPda. Owner may have many Positions in the Pool distinguished
+/// by `index`. Thus all user's Positions can be found without scanning program accounts.
+pub fn find_position(pool: &Pubkey, owner: &Pubkey, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[pool.as_ref(), owner.as_ref(), POSITION_SEED, &[index]],
+        &SUPER_LENDY_ID,
+    )
+}
+
 // Program authority is the owner of SPL Token wallets used to fund offers.
 pub fn find_program_authority() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUTHORITY_SEED], &SUPER_LENDY_ID)
@@ -42,6 +53,15 @@ pub fn find_reward_supply(pool: &Pubkey, reward_mint: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+/// Insurance fund - is the wallet where Pool accumulates insurance fees of all its Reserves with
+/// the same liquidity mint. Funds are used to absorb bad debt before it is socialized on LPs.
+pub fn find_insurance_fund(pool: &Pubkey, liquidity_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[pool.as_ref(), liquidity_mint.as_ref(), INSURANCE_FUND_SEED],
+        &SUPER_LENDY_ID,
+    )
+}
+
 /// Program (contract) authority used in rewards operations (e.g. to transfer rewards to users).
 pub fn find_rewards_program_authority(pool: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[pool.as_ref(), AUTHORITY_SEED], &SUPER_LENDY_ID)
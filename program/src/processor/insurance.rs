@@ -0,0 +1,231 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::msg;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, PodStateWithExtensions};
+use spl_token_2022::pod::PodMint;
+use spl_token_2022::state::Account;
+use texture_common::account::PodAccount;
+use texture_common::remote::system::SystemProgram;
+use texture_common::remote::token::SplToken;
+use texture_common::utils::verify_key;
+
+use crate::error::SuperLendyError;
+use crate::error::SuperLendyError::OperationCanNotBePerformed;
+use crate::event::SuperLendyEvent;
+use crate::instruction::{ClaimInsuranceFeesAccounts, InitInsuranceFundAccounts};
+use crate::pda;
+use crate::pda::{find_insurance_fund, find_liquidity_supply, find_program_authority};
+use crate::processor::{seedvec, verify_curator, verify_token_program, SeedVec};
+use crate::state::reserve::Reserve;
+use crate::LendyResult;
+
+impl<'a, 'b> crate::processor::Processor<'a, 'b> {
+    #[inline(never)]
+    pub fn init_insurance_fund(&self) -> LendyResult<()> {
+        msg!("init_insurance_fund ix");
+
+        let InitInsuranceFundAccounts {
+            insurance_fund,
+            liquidity_mint,
+            pool,
+            curator_pools_authority,
+            curator,
+            program_authority,
+            token_program,
+            system_program,
+        } = InitInsuranceFundAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_token_program(token_program)?;
+
+        if liquidity_mint.owner != token_program.key {
+            msg!(
+                "liquidity mint owner {} doesn't match token program {}",
+                liquidity_mint.owner,
+                token_program.key
+            );
+            return Err(OperationCanNotBePerformed);
+        }
+
+        verify_curator(pool, curator, curator_pools_authority)?;
+
+        let (expected_insurance_fund, insurance_fund_bump) =
+            find_insurance_fund(pool.key, liquidity_mint.key);
+
+        verify_key(
+            insurance_fund.key,
+            &expected_insurance_fund,
+            "insurance_fund",
+        )?;
+
+        let (expected_authority, _authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let rent = Rent::get().expect("No Rent");
+        let system_program = SystemProgram::new(system_program);
+        let create_account =
+            |account_info: &AccountInfo<'b>, owner: &Pubkey, length, seeds: SeedVec<'_>, bump| {
+                let init_lamports = rent.minimum_balance(length);
+
+                let nonce = [bump];
+                let mut seeds: SeedVec<'_> = seeds;
+                seeds.push(&nonce);
+
+                system_program
+                    .create_account(
+                        curator_pools_authority,
+                        account_info,
+                        length as u64,
+                        init_lamports,
+                        owner,
+                    )
+                    .signed(&[&seeds])
+                    .map_err(SuperLendyError::from)
+            };
+
+        let insurance_fund_data_len = if liquidity_mint.owner == &spl_token_2022::id() {
+            let mint_data = liquidity_mint.data.borrow();
+            let state = PodStateWithExtensions::<PodMint>::unpack(&mint_data).map_err(|_| {
+                msg!("unpack Token2022 mint failed");
+                OperationCanNotBePerformed
+            })?;
+
+            let mint_extensions = state.get_extension_types().map_err(|_| {
+                msg!("get_extension_types for Token2022 failed");
+                OperationCanNotBePerformed
+            })?;
+
+            let required_extensions =
+                ExtensionType::get_required_init_account_extensions(&mint_extensions);
+
+            ExtensionType::try_calculate_account_len::<Account>(&required_extensions).map_err(
+                |_| {
+                    msg!("try_calculate_account_len failed for Token2022 account");
+                    OperationCanNotBePerformed
+                },
+            )?
+        } else {
+            spl_token::state::Account::LEN
+        };
+
+        let pool_key_bytes = pool.key.to_bytes();
+        let mint_key_bytes = liquidity_mint.key.to_bytes();
+        let seeds = seedvec![&pool_key_bytes, &mint_key_bytes, pda::INSURANCE_FUND_SEED];
+        create_account(
+            insurance_fund,
+            token_program.key,
+            insurance_fund_data_len,
+            seeds,
+            insurance_fund_bump,
+        )?;
+
+        let spl_token = SplToken::new(token_program);
+        spl_token
+            .init_account3(insurance_fund, liquidity_mint, program_authority)?
+            .call()?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn claim_insurance_fees(&self) -> LendyResult<()> {
+        msg!("claim_insurance_fees ix");
+
+        let ClaimInsuranceFeesAccounts {
+            reserve,
+            reserve_liquidity_supply,
+            insurance_fund,
+            pool,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        } = ClaimInsuranceFeesAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_token_program(token_program)?;
+
+        let (expected_liquidity_supply, _liquidity_supply_bump) =
+            find_liquidity_supply(reserve.key);
+        verify_key(
+            reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "liquidity supply",
+        )?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        verify_key(pool.key, &unpacked_reserve.pool, "pool vs. reserve.pool")?;
+        verify_key(
+            liquidity_mint.key,
+            &unpacked_reserve.liquidity.mint,
+            "liquidity mint",
+        )?;
+
+        let (expected_insurance_fund, _insurance_fund_bump) =
+            find_insurance_fund(pool.key, liquidity_mint.key);
+        verify_key(
+            insurance_fund.key,
+            &expected_insurance_fund,
+            "insurance_fund",
+        )?;
+
+        if insurance_fund.owner != token_program.key {
+            msg!("Insurance fund is not initialized");
+            return Err(OperationCanNotBePerformed);
+        }
+
+        let clock = Clock::get().expect("no clock");
+
+        // Reserve must be fresh to ensure that all fees are accrued.
+        if unpacked_reserve.is_stale(&clock)? {
+            msg!("update reserve and try again");
+            return Err(SuperLendyError::StaleReserve);
+        }
+
+        let fee_amount = unpacked_reserve.liquidity.claim_insurance_fee()?;
+
+        if fee_amount == 0 {
+            msg!("fee amount is zero");
+            return Err(OperationCanNotBePerformed);
+        }
+
+        unpacked_reserve.mark_stale();
+
+        let spl_token = SplToken::new(token_program);
+
+        spl_token
+            .transfer(
+                reserve_liquidity_supply,
+                Some(liquidity_mint),
+                insurance_fund,
+                program_authority,
+                fee_amount,
+                Some(unpacked_reserve.liquidity.mint_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        SuperLendyEvent::InsuranceFunded {
+            pool: *pool.key,
+            reserve: *reserve.key,
+            insurance_fund: *insurance_fund.key,
+            amount: fee_amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
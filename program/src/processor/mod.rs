@@ -1,10 +1,13 @@
 use borsh::BorshDeserialize;
+use bytemuck::Zeroable;
 use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use spl_token_2022::extension::StateWithExtensions;
 use texture_common::account::PodAccount;
 use texture_common::error;
@@ -14,15 +17,19 @@ use tinyvec::ArrayVec;
 use crate::error::SuperLendyError;
 use crate::error::SuperLendyError::{InvalidKey, OperationCanNotBePerformed};
 use crate::instruction::{
-    AlterPoolAccounts, AlterTextureConfigAccounts, CreatePoolAccounts, CreateTextureConfigAccounts,
-    SuperLendyInstruction, TransferTextureConfigOwnershipAccounts,
+    AlterPoolAccounts, AlterTextureConfigAccounts, ApplyEmodeCategoryProposalAccounts,
+    CreatePoolAccounts, CreateTextureConfigAccounts, ProposeEmodeCategoryAccounts,
+    SetEmodeCategoryAccounts, SuperLendyInstruction, TransferTextureConfigOwnershipAccounts,
 };
 use crate::state::curator::Curator;
-use crate::state::pool::{Pool, PoolParams};
+use crate::state::pool::{
+    EmodeCategory, EmodeCategoryProposal, Pool, PoolParams, MAX_EMODE_CATEGORIES,
+};
 use crate::state::texture_cfg::{TextureConfig, TextureConfigParams};
 use crate::{LendyResult, SUPER_LENDY_ID};
 
 mod curator;
+mod insurance;
 mod position;
 mod reserve;
 mod rewards;
@@ -84,7 +91,23 @@ impl<'a, 'b> Processor<'a, 'b> {
                 params,
                 mode,
                 flash_loans_enabled,
-            } => self.alter_reserve(params, mode, flash_loans_enabled),
+                surplus_destination,
+                isolation_borrowable,
+                isolated_debt_ceiling,
+                fixed_rate_bps,
+                fixed_max_term_sec,
+                fixed_grace_period_sec,
+            } => self.alter_reserve(
+                params,
+                mode,
+                flash_loans_enabled,
+                surplus_destination,
+                isolation_borrowable,
+                isolated_debt_ceiling,
+                fixed_rate_bps,
+                fixed_max_term_sec,
+                fixed_grace_period_sec,
+            ),
             SuperLendyInstruction::RefreshReserve => self.refresh_reserve(),
             SuperLendyInstruction::DeleteReserve => self.delete_reserve(),
             SuperLendyInstruction::DepositLiquidity { amount } => self.deposit_liquidity(amount),
@@ -140,6 +163,81 @@ impl<'a, 'b> Processor<'a, 'b> {
             }
             SuperLendyInstruction::Version { no_error } => self.version(no_error),
             SuperLendyInstruction::SetLpMetadata { metadata } => self.set_lp_metadata(metadata),
+            SuperLendyInstruction::SyncReserve => self.sync_reserve(),
+            SuperLendyInstruction::SetEmodeCategory {
+                category_id,
+                category,
+            } => self.set_emode_category(category_id, category),
+            SuperLendyInstruction::ProposeEmodeCategory {
+                category_id,
+                category,
+            } => self.propose_emode_category(category_id, category),
+            SuperLendyInstruction::ApplyEmodeCategoryProposal => {
+                self.apply_emode_category_proposal()
+            }
+            SuperLendyInstruction::SetPositionEmode { category_id } => {
+                self.set_position_emode(category_id)
+            }
+            SuperLendyInstruction::ClaimCuratorLiquidationFees => {
+                self.claim_curator_liquidation_fees()
+            }
+            SuperLendyInstruction::ClaimTextureLiquidationFees => {
+                self.claim_texture_liquidation_fees()
+            }
+            SuperLendyInstruction::LiquidateAndRedeem { liquidity_amount } => {
+                self.liquidate_and_redeem(liquidity_amount)
+            }
+            SuperLendyInstruction::RepayWithCollateral { amount } => {
+                self.repay_with_collateral(amount)
+            }
+            SuperLendyInstruction::RepayWithCollateralSwap {
+                lp_amount,
+                min_repay_amount,
+                swap_data,
+            } => self.repay_with_collateral_swap(lp_amount, min_repay_amount, swap_data),
+            SuperLendyInstruction::SwapCollateral {
+                lp_amount,
+                min_target_amount,
+                swap_data,
+            } => self.swap_collateral(lp_amount, min_target_amount, swap_data),
+            SuperLendyInstruction::DepositAndLockCollateral { amount, memo } => {
+                self.deposit_and_lock_collateral(amount, memo)
+            }
+            SuperLendyInstruction::UnlockAndWithdrawLiquidity { lp_amount } => {
+                self.unlock_and_withdraw_liquidity(lp_amount)
+            }
+            SuperLendyInstruction::CreatePositionPda {
+                position_type,
+                index,
+            } => self.create_position_pda(position_type, index),
+            SuperLendyInstruction::ResizePosition {
+                extra_deposits,
+                extra_borrows,
+                extra_rewards,
+                extra_fixed_loans,
+            } => self.resize_position(
+                extra_deposits,
+                extra_borrows,
+                extra_rewards,
+                extra_fixed_loans,
+            ),
+            SuperLendyInstruction::SetPositionDelegate {
+                permissions,
+                borrow_allowance,
+            } => self.set_position_delegate(permissions, borrow_allowance),
+            SuperLendyInstruction::TransferPositionOwnership => self.transfer_position_ownership(),
+            SuperLendyInstruction::AcceptPositionOwnership => self.accept_position_ownership(),
+            SuperLendyInstruction::BorrowFixed {
+                amount,
+                slippage_limit,
+                term_sec,
+                max_rate_bps,
+                memo,
+            } => self.borrow_fixed(amount, slippage_limit, term_sec, max_rate_bps, memo),
+            SuperLendyInstruction::RepayFixed { amount } => self.repay_fixed(amount),
+            SuperLendyInstruction::InitInsuranceFund => self.init_insurance_fund(),
+            SuperLendyInstruction::ClaimInsuranceFees => self.claim_insurance_fees(),
+            SuperLendyInstruction::SocializeLoss => self.socialize_loss(),
             _ => {
                 msg!("Unrecognized IX");
                 Err(SuperLendyError::Unimplemented)
@@ -191,6 +289,9 @@ impl<'a, 'b> Processor<'a, 'b> {
         unpacked_cfg.performance_fee_rate_bps = params.performance_fee_rate_bps;
         unpacked_cfg.borrow_fee_rate_bps = params.borrow_fee_rate_bps;
         unpacked_cfg.reserve_timelock = params.reserve_timelock;
+        unpacked_cfg.flash_loan_fee_share_bps = params.flash_loan_fee_share_bps;
+        unpacked_cfg.liquidation_fee_share_bps = params.liquidation_fee_share_bps;
+        unpacked_cfg.swap_program = params.swap_program;
 
         Ok(())
     }
@@ -224,6 +325,8 @@ impl<'a, 'b> Processor<'a, 'b> {
     pub(super) fn create_pool(&self, params: PoolParams) -> LendyResult<()> {
         msg!("create_pool ix: {:?}", params);
 
+        params.validate()?;
+
         let CreatePoolAccounts {
             pool,
             curator_pools_authority,
@@ -252,6 +355,8 @@ impl<'a, 'b> Processor<'a, 'b> {
     pub(super) fn alter_pool(&self, params: PoolParams) -> LendyResult<()> {
         msg!("alter_pool ix: {:?}", params);
 
+        params.validate()?;
+
         let AlterPoolAccounts {
             pool,
             curator_pools_authority,
@@ -276,6 +381,162 @@ impl<'a, 'b> Processor<'a, 'b> {
         unpacked_pool.name = params.name;
         unpacked_pool.market_price_currency_symbol = params.market_price_currency_symbol;
         unpacked_pool.visible = params.visible;
+        unpacked_pool.auto_write_off_bad_debt = params.auto_write_off_bad_debt;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub(super) fn set_emode_category(
+        &self,
+        category_id: u8,
+        category: EmodeCategory,
+    ) -> LendyResult<()> {
+        msg!("set_emode_category ix: {} {:?}", category_id, category);
+
+        let SetEmodeCategoryAccounts {
+            pool,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        } = SetEmodeCategoryAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        // E-mode categories can be changed by `pools_authority` from Curator account
+        let curator_data = curator.data.borrow();
+        let unpacked_curator = Curator::try_from_bytes(curator_data.as_ref())?;
+
+        verify_key(
+            curator_pools_authority.key,
+            &unpacked_curator.pools_authority,
+            "curator_pools_authority",
+        )?;
+
+        let mut pool_data = pool.data.borrow_mut();
+        let unpacked_pool = Pool::try_from_bytes_mut(pool_data.as_mut())?;
+
+        verify_key(curator.key, &unpacked_pool.curator, "curator")?;
+
+        if category_id == 0 || category_id as usize > MAX_EMODE_CATEGORIES {
+            msg!("category_id must be in range [1, {}]", MAX_EMODE_CATEGORIES);
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        category.validate()?;
+
+        // Category LTVs apply to all its Reserves. Thus they are time locked the same way as
+        // Reserves' LTVs. Only changes which disable the category or don't raise locked LTVs are
+        // applied right away.
+        let texture_config_data = texture_config.data.borrow();
+        let unpacked_texture_config = TextureConfig::try_from_bytes(texture_config_data.as_ref())?;
+        if !unpacked_pool.emode_categories[category_id as usize - 1]
+            .can_be_applied_now(&category, &unpacked_texture_config.reserve_timelock)
+        {
+            msg!(
+                "e-mode category {} change is time locked. Use ProposeEmodeCategory IX instead.",
+                category_id
+            );
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        unpacked_pool.emode_categories[category_id as usize - 1] = category;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub(super) fn propose_emode_category(
+        &self,
+        category_id: u8,
+        category: EmodeCategory,
+    ) -> LendyResult<()> {
+        msg!("propose_emode_category ix: {} {:?}", category_id, category);
+
+        let ProposeEmodeCategoryAccounts {
+            pool,
+            curator_pools_authority,
+            curator,
+            texture_config,
+        } = ProposeEmodeCategoryAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_curator(pool, curator, curator_pools_authority)?;
+
+        let mut pool_data = pool.data.borrow_mut();
+        let unpacked_pool = Pool::try_from_bytes_mut(pool_data.as_mut())?;
+
+        if category_id == 0 {
+            // Means that pending proposal must be reset
+            unpacked_pool.emode_category_proposal = EmodeCategoryProposal::zeroed();
+            return Ok(());
+        }
+
+        if category_id as usize > MAX_EMODE_CATEGORIES {
+            msg!("category_id must be in range [1, {}]", MAX_EMODE_CATEGORIES);
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        category.validate()?;
+
+        let texture_config_data = texture_config.data.borrow();
+        let unpacked_texture_config = TextureConfig::try_from_bytes(texture_config_data.as_ref())?;
+        let current_category = unpacked_pool.emode_categories[category_id as usize - 1];
+        if current_category.can_be_applied_now(&category, &unpacked_texture_config.reserve_timelock)
+        {
+            msg!("supplied category can be applied right away. Use SetEmodeCategory IX instead.");
+            return Err(OperationCanNotBePerformed);
+        }
+
+        let clock = Clock::get().expect("no clock");
+
+        let max_time_lock =
+            current_category.max_time_lock(&category, &unpacked_texture_config.reserve_timelock);
+
+        unpacked_pool.emode_category_proposal = EmodeCategoryProposal {
+            can_be_applied_at: clock.unix_timestamp + max_time_lock,
+            category_id,
+            _padding: Zeroable::zeroed(),
+            category,
+        };
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub(super) fn apply_emode_category_proposal(&self) -> LendyResult<()> {
+        msg!("apply_emode_category_proposal ix");
+
+        let ApplyEmodeCategoryProposalAccounts {
+            pool,
+            curator_pools_authority,
+            curator,
+        } = ApplyEmodeCategoryProposalAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
+
+        verify_curator(pool, curator, curator_pools_authority)?;
+
+        let mut pool_data = pool.data.borrow_mut();
+        let unpacked_pool = Pool::try_from_bytes_mut(pool_data.as_mut())?;
+        let proposal = unpacked_pool.emode_category_proposal;
+
+        if proposal.category_id == 0 {
+            msg!("no e-mode category proposal to apply");
+            return Err(OperationCanNotBePerformed);
+        }
+
+        let clock = Clock::get().expect("no clock");
+
+        if proposal.can_be_applied_at > clock.unix_timestamp {
+            msg!(
+                "e-mode category proposal can be applied at {}. Wait for {} sec and try again.",
+                proposal.can_be_applied_at,
+                proposal.can_be_applied_at - clock.unix_timestamp
+            );
+            return Err(OperationCanNotBePerformed);
+        }
+
+        unpacked_pool.emode_categories[proposal.category_id as usize - 1] = proposal.category;
+        unpacked_pool.emode_category_proposal = EmodeCategoryProposal::zeroed();
 
         Ok(())
     }
@@ -422,3 +683,47 @@ pub fn spl_token_mint(spl_token_account: &AccountInfo<'_>) -> LendyResult<Pubkey
         }))
     }
 }
+
+/// Get token amount of either Token or Token2022 account.
+pub fn spl_token_amount(spl_token_account: &AccountInfo<'_>) -> LendyResult<u64> {
+    if spl_token_account.owner == &spl_token::id() {
+        let acc_data = spl_token_account.data.borrow();
+        let unpacked_wallet = spl_token::state::Account::unpack(&acc_data)
+            .map_err(|err| SuperLendyError::AccountUnpackError(*spl_token_account.key, err))?;
+        Ok(unpacked_wallet.amount)
+    } else if spl_token_account.owner == &spl_token_2022::id() {
+        let acc_data = spl_token_account.data.borrow();
+        let unpacked_wallet =
+            StateWithExtensions::<spl_token_2022::state::Account>::unpack(&acc_data)
+                .map_err(|err| SuperLendyError::AccountUnpackError(*spl_token_account.key, err))?;
+        Ok(unpacked_wallet.base.amount)
+    } else {
+        Err(InvalidKey(error::InvalidKey {
+            key_type: "Token program",
+            actual: *spl_token_account.owner,
+            expected: spl_token::id(),
+        }))
+    }
+}
+
+/// Get owner of either Token or Token2022 account.
+pub fn spl_token_owner(spl_token_account: &AccountInfo<'_>) -> LendyResult<Pubkey> {
+    if spl_token_account.owner == &spl_token::id() {
+        let acc_data = spl_token_account.data.borrow();
+        let unpacked_wallet = spl_token::state::Account::unpack(&acc_data)
+            .map_err(|err| SuperLendyError::AccountUnpackError(*spl_token_account.key, err))?;
+        Ok(unpacked_wallet.owner)
+    } else if spl_token_account.owner == &spl_token_2022::id() {
+        let acc_data = spl_token_account.data.borrow();
+        let unpacked_wallet =
+            StateWithExtensions::<spl_token_2022::state::Account>::unpack(&acc_data)
+                .map_err(|err| SuperLendyError::AccountUnpackError(*spl_token_account.key, err))?;
+        Ok(unpacked_wallet.base.owner)
+    } else {
+        Err(InvalidKey(error::InvalidKey {
+            key_type: "Token program",
+            actual: *spl_token_account.owner,
+            expected: spl_token::id(),
+        }))
+    }
+}
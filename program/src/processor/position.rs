@@ -1,35 +1,56 @@
+use bytemuck::Zeroable;
+use solana_program::account_info::AccountInfo;
 use solana_program::clock::Clock;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::msg;
+use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
 use solana_program::sysvar::Sysvar;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use std::collections::HashSet;
 use texture_common::account::PodAccount;
 use texture_common::error;
-use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, Decimal, MathError};
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal, MathError};
 use texture_common::remote::token::SplToken;
+use texture_common::remote::RemoteError;
 use texture_common::utils::verify_key;
 
 use crate::error::SuperLendyError;
 use crate::error::SuperLendyError::InvalidAmount;
+use crate::event::SuperLendyEvent;
 use crate::instruction::{
-    BorrowAccounts, ClosePositionAccounts, CreatePositionAccounts, LiquidateAccounts,
-    LockCollateralAccounts, RefreshPositionAccounts, RepayAccounts, UnlockCollateralAccounts,
-    WriteOffBadDebtAccounts,
+    AcceptPositionOwnershipAccounts, BorrowAccounts, ClosePositionAccounts, CreatePositionAccounts,
+    CreatePositionPdaAccounts, DepositAndLockCollateralAccounts, LiquidateAccounts,
+    LiquidateAndRedeemAccounts, LockCollateralAccounts, RefreshPositionAccounts, RepayAccounts,
+    RepayWithCollateralAccounts, RepayWithCollateralSwapAccounts, ResizePositionAccounts,
+    SetPositionDelegateAccounts, SetPositionEmodeAccounts, SocializeLossAccounts,
+    SwapCollateralAccounts, TransferPositionOwnershipAccounts, UnlockAndWithdrawLiquidityAccounts,
+    UnlockCollateralAccounts, WriteOffBadDebtAccounts,
 };
 use crate::pda::{
-    find_collateral_supply, find_liquidity_supply, find_lp_token_mint, find_program_authority,
+    find_collateral_supply, find_insurance_fund, find_liquidity_supply, find_lp_token_mint,
+    find_position, find_program_authority,
+};
+use crate::processor::{
+    seedvec, spl_token_amount, spl_token_mint, spl_token_owner, verify_curator,
+    verify_token_program, Processor,
 };
-use crate::processor::{spl_token_mint, verify_curator, verify_token_program, Processor};
 use crate::state::curator::Curator;
-use crate::state::pool::Pool;
+use crate::state::pool::{EmodeCategory, Pool};
 use crate::state::position::{
-    InitPositionParams, Position, BORROW_MEMO_LEN, COLLATERAL_MEMO_LEN, POSITION_TYPE_CLASSIC,
-    POSITION_TYPE_LONG_SHORT, POSITION_TYPE_LST_LEVERAGE,
+    max_withdraw_value, InitPositionParams, Position, PositionMut, PositionRef, RewardsMut,
+    BORROW_MEMO_LEN, COLLATERAL_MEMO_LEN, DELEGATE_PERMISSIONS_ALL, DELEGATE_PERMISSION_BORROW,
+    DELEGATE_PERMISSION_LOCK, DELEGATE_PERMISSION_REPAY, DELEGATE_PERMISSION_UNLOCK,
+    POSITION_TYPE_CLASSIC, POSITION_TYPE_LONG_SHORT, POSITION_TYPE_LST_LEVERAGE,
 };
 use crate::state::reserve::{
-    CalculateBorrowResult, CalculateLiquidationResult, CalculateRepayResult, Reserve,
-    RESERVE_MODE_BORROW_DISABLED, RESERVE_MODE_RETAIN_LIQUIDITY, RESERVE_TYPE_NOT_A_COLLATERAL,
+    CalculateBorrowResult, CalculateLiquidationResult, CalculateRepayResult,
+    CalculateRepayWithCollateralResult, Reserve, RESERVE_MODE_BORROW_DISABLED,
+    RESERVE_MODE_RETAIN_LIQUIDITY, RESERVE_TYPE_ISOLATED_COLLATERAL, RESERVE_TYPE_NOT_A_COLLATERAL,
     RESERVE_TYPE_PROTECTED_COLLATERAL,
 };
 use crate::state::reserve::{REWARD_FOR_BORROW, REWARD_FOR_LIQUIDITY};
@@ -79,6 +100,93 @@ impl<'a, 'b> Processor<'a, 'b> {
         Ok(())
     }
 
+    #[inline(never)]
+    pub fn create_position_pda(&self, position_type: u8, index: u8) -> LendyResult<()> {
+        msg!("create_position_pda ix: {} {}", position_type, index);
+
+        let CreatePositionPdaAccounts {
+            position,
+            pool,
+            owner,
+            system_program,
+        } = CreatePositionPdaAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let (expected_position, position_bump) = find_position(pool.key, owner.key, index);
+        verify_key(position.key, &expected_position, "position")?;
+
+        // Unpack the pool just to make sure its really a pool and not some other type SuperLendy account.
+        let pool_data = pool.data.borrow();
+        let unpacked_pool = Pool::try_from_bytes(pool_data.as_ref())?;
+
+        if position_type != POSITION_TYPE_CLASSIC
+            && position_type != POSITION_TYPE_LONG_SHORT
+            && position_type != POSITION_TYPE_LST_LEVERAGE
+        {
+            msg!("Invalid position_type {}", position_type);
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        msg!(
+            "Init position {}  for pool {}  position_type {}  index {}",
+            position.key,
+            String::from_utf8_lossy(&unpacked_pool.name),
+            position_type,
+            index
+        );
+
+        let rent = Rent::get().expect("No Rent");
+        let pool_key_bytes = pool.key.to_bytes();
+        let owner_key_bytes = owner.key.to_bytes();
+        let index_bytes = [index];
+        let bump_bytes = [position_bump];
+        let seeds = seedvec![
+            &pool_key_bytes,
+            &owner_key_bytes,
+            pda::POSITION_SEED,
+            &index_bytes,
+            &bump_bytes
+        ];
+
+        // Position address is predictable thus anyone can pre-fund it with some lamports which
+        // would make CreateAccount fail. Top up, allocate and assign instead.
+        let required_lamports = rent
+            .minimum_balance(Position::SIZE)
+            .saturating_sub(position.lamports());
+
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(owner.key, position.key, required_lamports),
+                &[owner.clone(), position.clone(), system_program.clone()],
+            )
+            .map_err(|err| SuperLendyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(position.key, Position::SIZE as u64),
+            &[position.clone(), system_program.clone()],
+            &[&seeds[..]],
+        )
+        .map_err(|err| SuperLendyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+
+        invoke_signed(
+            &system_instruction::assign(position.key, self.program_id),
+            &[position.clone(), system_program.clone()],
+            &[&seeds[..]],
+        )
+        .map_err(|err| SuperLendyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+
+        let position_params = InitPositionParams {
+            position_type,
+            pool: *pool.key,
+            owner: *owner.key,
+        };
+
+        let mut position_data = position.data.borrow_mut();
+        Position::init_bytes(position_data.as_mut(), position_params)?;
+
+        Ok(())
+    }
+
     #[inline(never)]
     pub fn close_position(&self) -> LendyResult<()> {
         msg!("close_position ix");
@@ -87,7 +195,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             ClosePositionAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
         let position_data = position.data.borrow();
-        let unpacked_position = Position::try_from_bytes(position_data.as_ref())?;
+        let unpacked_position = Position::unpack(position_data.as_ref())?;
 
         verify_key(owner.key, &unpacked_position.owner, "position owner")?;
 
@@ -106,6 +214,294 @@ impl<'a, 'b> Processor<'a, 'b> {
         Ok(())
     }
 
+    #[inline(never)]
+    pub fn resize_position(
+        &self,
+        extra_deposits: u8,
+        extra_borrows: u8,
+        extra_rewards: u8,
+        extra_fixed_loans: u8,
+    ) -> LendyResult<()> {
+        msg!(
+            "resize_position ix: extra_deposits {} extra_borrows {} extra_rewards {} extra_fixed_loans {}",
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans
+        );
+
+        let ResizePositionAccounts {
+            position,
+            owner,
+            system_program,
+        } = ResizePositionAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        // Extension parts are shifted when any of them changes its size. Thus copy records out and
+        // write them back after reallocation.
+        let (collateral, borrows, rewards, fixed_loans) = {
+            let position_data = position.data.borrow();
+            let unpacked_position = Position::unpack(position_data.as_ref())?;
+
+            verify_key(owner.key, &unpacked_position.owner, "position owner")?;
+
+            let collateral_in_use = unpacked_position
+                .collateral_ext
+                .iter()
+                .skip(extra_deposits as usize)
+                .any(|collateral| collateral.deposited_amount != 0);
+            let borrows_in_use = unpacked_position
+                .borrows_ext
+                .iter()
+                .skip(extra_borrows as usize)
+                .any(|borrow| borrow.borrowed_amount().unwrap_or(Decimal::ZERO) != Decimal::ZERO);
+            let rewards_in_use = unpacked_position
+                .rewards_ext
+                .iter()
+                .skip(extra_rewards as usize)
+                .any(|reward| !reward.is_vacant());
+            let fixed_loans_in_use = unpacked_position
+                .fixed_loans
+                .iter()
+                .skip(extra_fixed_loans as usize)
+                .any(|loan| loan.is_active());
+
+            if collateral_in_use || borrows_in_use || rewards_in_use || fixed_loans_in_use {
+                msg!("Extension records being removed are still in use");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            (
+                unpacked_position.collateral_ext.to_vec(),
+                unpacked_position.borrows_ext.to_vec(),
+                unpacked_position.rewards_ext.to_vec(),
+                unpacked_position.fixed_loans.to_vec(),
+            )
+        };
+
+        let new_size = Position::account_size(
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        );
+        if new_size > position.data_len() + MAX_PERMITTED_DATA_INCREASE {
+            msg!(
+                "Position can grow by {} bytes at most per one instruction. Current size {}  requested {}",
+                MAX_PERMITTED_DATA_INCREASE,
+                position.data_len(),
+                new_size
+            );
+            return Err(SuperLendyError::InvalidRealloc);
+        }
+
+        let rent = Rent::get().expect("No Rent");
+        let required_lamports = rent.minimum_balance(new_size);
+        let current_lamports = position.lamports();
+
+        if required_lamports > current_lamports {
+            invoke(
+                &system_instruction::transfer(
+                    owner.key,
+                    position.key,
+                    required_lamports - current_lamports,
+                ),
+                &[owner.clone(), position.clone(), system_program.clone()],
+            )
+            .map_err(|err| SuperLendyError::SystemProgram(RemoteError::Unrecognized(err)))?;
+        }
+
+        position.realloc(new_size, false).map_err(|err| {
+            msg!("Position realloc failed: {}", err);
+            SuperLendyError::InvalidRealloc
+        })?;
+
+        {
+            let mut position_data = position.data.borrow_mut();
+
+            let header = Position::try_from_bytes_mut(&mut position_data[..Position::SIZE])?;
+            header.extra_deposits = extra_deposits;
+            header.extra_borrows = extra_borrows;
+            header.extra_rewards = extra_rewards;
+            header.extra_fixed_loans = extra_fixed_loans;
+
+            let unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+            for (index, record) in unpacked_position.collateral_ext.iter_mut().enumerate() {
+                *record = collateral
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(Zeroable::zeroed);
+            }
+            for (index, record) in unpacked_position.borrows_ext.iter_mut().enumerate() {
+                *record = borrows.get(index).copied().unwrap_or_else(Zeroable::zeroed);
+            }
+            for (index, record) in unpacked_position.rewards_ext.iter_mut().enumerate() {
+                *record = rewards.get(index).copied().unwrap_or_else(Zeroable::zeroed);
+            }
+            for (index, record) in unpacked_position.fixed_loans.iter_mut().enumerate() {
+                *record = fixed_loans
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(Zeroable::zeroed);
+            }
+        }
+
+        // Rent exempt balance released by shrinking goes back to the owner
+        if current_lamports > required_lamports {
+            crate::processor::transfer_lamports(
+                position,
+                owner,
+                current_lamports - required_lamports,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn set_position_delegate(&self, permissions: u8, borrow_allowance: u64) -> LendyResult<()> {
+        msg!(
+            "set_position_delegate ix: permissions {} borrow_allowance {}",
+            permissions,
+            borrow_allowance
+        );
+
+        let SetPositionDelegateAccounts {
+            position,
+            owner,
+            delegate,
+        } = SetPositionDelegateAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        if permissions & !DELEGATE_PERMISSIONS_ALL != 0 {
+            msg!("unknown delegate permissions {:#b}", permissions);
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        let mut position_data = position.data.borrow_mut();
+        let mut unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+
+        verify_key(owner.key, &unpacked_position.owner, "position owner")?;
+
+        if delegate.key == owner.key || *delegate.key == Pubkey::default() {
+            msg!("delegate {} is not allowed", delegate.key);
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        unpacked_position.set_delegate(*delegate.key, permissions, borrow_allowance)?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn transfer_position_ownership(&self) -> LendyResult<()> {
+        msg!("transfer_position_ownership ix");
+
+        let TransferPositionOwnershipAccounts {
+            position,
+            owner,
+            new_owner,
+        } = TransferPositionOwnershipAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+
+        verify_key(owner.key, &unpacked_position.owner, "position owner")?;
+
+        if new_owner.key == owner.key {
+            msg!(
+                "cancel pending transfer to {}",
+                unpacked_position.pending_owner
+            );
+            unpacked_position.pending_owner = Pubkey::default();
+        } else {
+            msg!("propose new owner {}", new_owner.key);
+            unpacked_position.pending_owner = *new_owner.key;
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn accept_position_ownership(&self) -> LendyResult<()> {
+        msg!("accept_position_ownership ix");
+
+        let AcceptPositionOwnershipAccounts {
+            position,
+            new_owner,
+        } = AcceptPositionOwnershipAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+
+        if unpacked_position.pending_owner == Pubkey::default() {
+            msg!("Position has no pending ownership transfer");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        verify_key(
+            new_owner.key,
+            &unpacked_position.pending_owner,
+            "position pending owner",
+        )?;
+
+        // Rewards accrued till now should be recorded in the Position. Then they are claimed by
+        // the new owner as reward records are not bound to the owner.
+        let clock = Clock::get().expect("no clock");
+        if unpacked_position.is_stale(&clock)? {
+            msg!("Position is stale and must be refreshed");
+            return Err(SuperLendyError::StalePosition);
+        }
+
+        unpacked_position.owner = *new_owner.key;
+        unpacked_position.pending_owner = Pubkey::default();
+        // Delegates were authorized by previous owner
+        unpacked_position.delegates = Zeroable::zeroed();
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn set_position_emode(&self, category_id: u8) -> LendyResult<()> {
+        msg!("set_position_emode ix: {}", category_id);
+
+        let SetPositionEmodeAccounts {
+            position,
+            pool,
+            owner,
+        } = SetPositionEmodeAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+
+        verify_key(owner.key, &unpacked_position.owner, "position owner")?;
+        verify_key(pool.key, &unpacked_position.pool, "pool vs. position.pool")?;
+
+        let pool_data = pool.data.borrow();
+        let unpacked_pool = Pool::try_from_bytes(pool_data.as_ref())?;
+
+        if category_id != 0 && unpacked_pool.emode_category(category_id).is_none() {
+            msg!(
+                "e-mode category {} is not configured in the Pool",
+                category_id
+            );
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        // Switching category changes Position's LTVs. Doing that with active borrowings may
+        // instantly make the position unhealthy.
+        if unpacked_position.have_any_borrowings() {
+            msg!("Position e-mode can be changed only when there are no borrowings");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        unpacked_position.emode_category = category_id;
+        unpacked_position.mark_stale();
+
+        Ok(())
+    }
+
     #[inline(never)]
     pub fn refresh_position(&self, deposit_count: usize, borrow_count: usize) -> LendyResult<()> {
         msg!("refresh_position ix");
@@ -125,6 +521,9 @@ impl<'a, 'b> Processor<'a, 'b> {
             self.program_id,
         )?;
 
+        let emode_category =
+            next_emode_category(&mut account_info_iter, position_info, self.program_id)?;
+
         if account_info_iter.peek().is_some() {
             msg!("Too many position deposit or borrow reserves provided");
             return Err(SuperLendyError::OperationCanNotBePerformed);
@@ -132,19 +531,40 @@ impl<'a, 'b> Processor<'a, 'b> {
         drop(account_info_iter);
 
         let mut position_data = position_info.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
-        let mut rewards = position.rewards;
+        let PositionMut {
+            header: position,
+            collateral_ext,
+            borrows_ext,
+            rewards_ext,
+            fixed_loans,
+        } = Position::unpack_mut(position_data.as_mut())?;
+        let mut rewards = RewardsMut {
+            rewards: &mut position.rewards.rewards,
+            extra: rewards_ext,
+        };
+
+        // E-mode LTVs are applied only when all deposits and borrows belong to position's category.
+        let mut emode_applies = emode_category.is_some();
 
         let mut deposited_value = Decimal::ZERO;
         let mut borrowed_value = Decimal::ZERO;
+        let mut weighted_borrowed_value = Decimal::ZERO;
         let mut allowed_borrow_value = Decimal::ZERO;
         let mut partly_unhealthy_borrow_value = Decimal::ZERO;
         let mut fully_unhealthy_borrow_value = Decimal::ZERO;
 
         let mut touched_rewards_records = HashSet::new();
+        // Shared by all not yet migrated reserves
+        let mut migrated_reserve = None;
 
         let mut deposit_infos_iter = deposit_infos.into_iter();
-        for (index, collateral) in position.collateral.iter_mut().enumerate() {
+        // Extension records follow ones stored in the Position. Reserves are expected in that order.
+        for (index, collateral) in position
+            .collateral
+            .iter_mut()
+            .chain(collateral_ext.iter_mut())
+            .enumerate()
+        {
             if collateral.deposited_amount == 0 {
                 // Means that this is unused collateral record. Should be ignored.
                 continue;
@@ -166,7 +586,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             }
 
             let reserve_data = deposit_reserve_info.data.borrow();
-            let deposit_reserve = Reserve::try_from_bytes(&reserve_data)?;
+            let deposit_reserve = Reserve::unpack(&reserve_data, &mut migrated_reserve)?;
             if deposit_reserve.is_stale(&clock)? {
                 msg!(
                     "Deposit reserve provided for collateral {} is stale and must be refreshed",
@@ -175,6 +595,8 @@ impl<'a, 'b> Processor<'a, 'b> {
                 return Err(SuperLendyError::OperationCanNotBePerformed);
             }
 
+            emode_applies &= deposit_reserve.config.emode_category == position.emode_category;
+
             let market_value = deposit_reserve
                 .lp_exchange_rate()?
                 .decimal_lp_to_liquidity(Decimal::from_lamports(
@@ -232,7 +654,18 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
 
         let mut borrow_infos_iter = borrow_infos.into_iter();
-        for (index, borrowed_liquidity) in position.borrows.iter_mut().enumerate() {
+        // Fixed-rate loans go after all other borrow records. They carry their own terms.
+        for (index, (borrowed_liquidity, fixed_terms)) in position
+            .borrows
+            .iter_mut()
+            .chain(borrows_ext.iter_mut())
+            .map(|borrowed_liquidity| (borrowed_liquidity, None))
+            .chain(fixed_loans.iter_mut().map(|loan| {
+                let terms = *loan;
+                (&mut loan.liquidity, Some(terms))
+            }))
+            .enumerate()
+        {
             if borrowed_liquidity.borrowed_amount()? == Decimal::ZERO {
                 // Means that this is unused liquidity record. Should be ignored.
                 continue;
@@ -254,7 +687,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             }
 
             let reserve_data = borrow_reserve_info.data.borrow();
-            let borrow_reserve = Reserve::try_from_bytes(&reserve_data)?;
+            let borrow_reserve = Reserve::unpack(&reserve_data, &mut migrated_reserve)?;
             if borrow_reserve.is_stale(&clock)? {
                 msg!(
                     "Borrow reserve provided for liquidity {} is stale and must be refreshed",
@@ -263,8 +696,14 @@ impl<'a, 'b> Processor<'a, 'b> {
                 return Err(SuperLendyError::OperationCanNotBePerformed);
             }
 
-            borrowed_liquidity
-                .accrue_interest(borrow_reserve.liquidity.cumulative_borrow_rate()?)?;
+            emode_applies &= borrow_reserve.config.emode_category == position.emode_category;
+
+            let cumulative_borrow_rate = if let Some(terms) = fixed_terms {
+                terms.cumulative_borrow_rate(borrow_reserve.last_update.timestamp)?
+            } else {
+                borrow_reserve.liquidity.cumulative_borrow_rate()?
+            };
+            borrowed_liquidity.accrue_interest(cumulative_borrow_rate)?;
 
             let market_value = borrowed_liquidity
                 .borrowed_amount()?
@@ -274,6 +713,10 @@ impl<'a, 'b> Processor<'a, 'b> {
 
             borrowed_value = borrowed_value.checked_add(market_value)?;
 
+            // Risky borrowings consume more of the borrowing power than their market value
+            weighted_borrowed_value = weighted_borrowed_value
+                .checked_add(market_value.checked_mul(borrow_reserve.config.borrow_weight()?)?)?;
+
             // Accrue rewards
             let rewards_records = rewards.accrue_rewards(
                 REWARD_FOR_BORROW,
@@ -293,15 +736,27 @@ impl<'a, 'b> Processor<'a, 'b> {
             }
         }
 
+        if let Some(category) = emode_category.filter(|_| emode_applies) {
+            allowed_borrow_value = deposited_value.checked_mul(Decimal::from_basis_points(
+                category.max_borrow_ltv_bps as u32,
+            )?)?;
+            partly_unhealthy_borrow_value = deposited_value.checked_mul(
+                Decimal::from_basis_points(category.partly_unhealthy_ltv_bps as u32)?,
+            )?;
+            fully_unhealthy_borrow_value = deposited_value.checked_mul(
+                Decimal::from_basis_points(category.fully_unhealthy_ltv_bps as u32)?,
+            )?;
+        }
+
+        rewards.set_accrued_slot(clock.slot, touched_rewards_records);
+
         position.set_deposited_value(deposited_value)?;
         position.set_borrowed_value(borrowed_value)?;
+        position.set_weighted_borrowed_value(weighted_borrowed_value)?;
         position.set_allowed_borrow_value(allowed_borrow_value)?;
         position.set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)?;
         position.set_fully_unhealthy_borrow_value(fully_unhealthy_borrow_value)?;
 
-        rewards.set_accrued_slot(clock.slot, touched_rewards_records);
-        position.rewards = rewards;
-
         position
             .last_update
             .update(clock.slot, clock.unix_timestamp);
@@ -333,10 +788,11 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
 
         let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
 
         let reserve_data = reserve.data.borrow();
-        let unpacked_reserve = Reserve::try_from_bytes(&reserve_data)?;
+        let mut migrated_reserve = None;
+        let unpacked_reserve = Reserve::unpack(&reserve_data, &mut migrated_reserve)?;
 
         // Check that position and reserve belongs to the same pool
         if position.pool != unpacked_reserve.pool {
@@ -360,7 +816,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(SuperLendyError::StalePosition);
         }
 
-        verify_key(owner.key, &position.owner, "position owner")?;
+        position.verify_authority(owner.key, DELEGATE_PERMISSION_LOCK)?;
 
         let expected_collateral_supply = find_collateral_supply(reserve.key);
         verify_key(
@@ -374,6 +830,13 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
+        position.verify_emode_reserve(unpacked_reserve.config.emode_category)?;
+
+        position.apply_collateral_isolation(
+            *reserve.key,
+            unpacked_reserve.reserve_type == RESERVE_TYPE_ISOLATED_COLLATERAL,
+        )?;
+
         let collateral = position.find_or_add_collateral(*reserve.key)?;
 
         let lp_amount_to_lock = if amount == MAX_AMOUNT {
@@ -450,10 +913,11 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
 
         let reserve_data = reserve.data.borrow();
-        let unpacked_reserve = Reserve::try_from_bytes(&reserve_data)?;
+        let mut migrated_reserve = None;
+        let unpacked_reserve = Reserve::unpack(&reserve_data, &mut migrated_reserve)?;
 
         if unpacked_reserve.mode == RESERVE_MODE_RETAIN_LIQUIDITY {
             msg!("reserve do not allow unlocking collateral");
@@ -483,7 +947,16 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(SuperLendyError::StalePosition);
         }
 
-        verify_key(owner.key, &position.owner, "position owner")?;
+        if position
+            .verify_authority(owner.key, DELEGATE_PERMISSION_UNLOCK)?
+            .is_some()
+        {
+            verify_key(
+                &spl_token_owner(destination_lp_wallet)?,
+                &position.owner,
+                "destination_lp_wallet owner",
+            )?;
+        }
 
         let expected_collateral_supply = find_collateral_supply(reserve.key);
         verify_key(
@@ -492,73 +965,12 @@ impl<'a, 'b> Processor<'a, 'b> {
             "reserve collateral supply",
         )?;
 
-        let (collateral, collateral_index) = position.find_collateral(*reserve.key)?;
-        if collateral.deposited_amount == 0 {
-            msg!("Collateral deposited amount is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-
-        if position.deposited_value()? == Decimal::ZERO {
-            msg!("Position deposited value is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-
-        let withdraw_amount = if !position.have_any_borrowings() {
-            msg!(
-                "position doesn't have borrowings. deposited_amount {}",
-                collateral.deposited_amount
-            );
-            collateral.deposited_amount.min(amount)
-        } else {
-            // Determine how much collateral (in value terms) we can let go
-            let max_withdraw_value = position.max_withdraw_value(Decimal::from_basis_points(
-                unpacked_reserve.config.max_borrow_ltv_bps as u32,
-            )?)?;
-
-            if max_withdraw_value == Decimal::ZERO {
-                msg!("Maximum withdraw value is zero");
-                return Err(SuperLendyError::OperationCanNotBePerformed);
-            }
-
-            let withdraw_amount = if amount == MAX_AMOUNT {
-                // user want's to unlock maximum it can. Calculate how much collateral we can unlock
-                // based on max allowed withdraw_value.
-                // Example: collateral is 1 SOL, market_price = 100$,
-                // max_withdraw_value = 90$ (because there is some borrowings).
-                // Then max collateral contract allow to unlock is 90/100 = 0.9 SOL
-                // Also need to take into account decimals.
-                let max_unlockable_collateral = max_withdraw_value
-                    .checked_div(unpacked_reserve.liquidity.market_price()?)?
-                    .to_lamports_floor(unpacked_reserve.liquidity.mint_decimals)?;
-
-                msg!("full unlock. position's max_withdraw_value {}  max_unlockable_collateral {}  deposited_amount {}", max_withdraw_value, max_unlockable_collateral, collateral.deposited_amount);
-
-                max_unlockable_collateral.min(collateral.deposited_amount)
-            } else {
-                let withdraw_amount = amount.min(collateral.deposited_amount);
-                let withdraw_pct =
-                    Decimal::from_i128_with_scale(withdraw_amount as i128, 0)?.checked_div(
-                        Decimal::from_i128_with_scale(collateral.deposited_amount as i128, 0)?,
-                    )?; // Simple math here, thus from_i128_with_scale
-                let withdraw_value = collateral.market_value()?.checked_mul(withdraw_pct)?;
-                if withdraw_value > max_withdraw_value {
-                    msg!(
-                        "Withdraw value {} cannot exceed maximum withdraw value {}",
-                        withdraw_value,
-                        max_withdraw_value
-                    );
-                    return Err(SuperLendyError::OperationCanNotBePerformed);
-                }
-                msg!("exact unlock. withdraw_amount {}  withdraw_pct {}  withdraw_value {}  max_withdraw_value {}", withdraw_amount, withdraw_pct, withdraw_value, max_withdraw_value);
-                withdraw_amount
-            };
-
-            if withdraw_amount == 0 {
-                msg!("Withdraw amount is too small to transfer");
-                return Err(SuperLendyError::OperationCanNotBePerformed);
-            }
-            withdraw_amount
-        };
+        let (withdraw_amount, collateral_index) = unlock_amount(
+            position.as_position_ref(),
+            unpacked_reserve,
+            *reserve.key,
+            amount,
+        )?;
 
         position.withdraw(withdraw_amount, collateral_index)?;
         position.mark_stale();
@@ -585,57 +997,59 @@ impl<'a, 'b> Processor<'a, 'b> {
     }
 
     #[inline(never)]
-    pub fn borrow(
+    pub fn deposit_and_lock_collateral(
         &self,
         amount: u64,
-        slippage_limit: u64,
-        memo: [u8; BORROW_MEMO_LEN],
+        memo: [u8; COLLATERAL_MEMO_LEN],
     ) -> LendyResult<()> {
-        msg!("borrow ix: {}", amount);
+        msg!("deposit_and_lock_collateral ix: {}", amount);
 
         if amount == 0 {
-            msg!("Amount to borrow cannot be zero");
+            msg!("Amount to deposit cannot be zero");
             return Err(InvalidAmount);
         }
 
-        let BorrowAccounts {
+        let DepositAndLockCollateralAccounts {
             position,
-            reserve_liquidity_supply,
-            destination_liquidity_wallet,
-            curator_fee_receiver,
-            borrower,
             reserve,
-            pool,
-            curator,
-            texture_fee_receiver,
-            texture_config,
+            source_liquidity_wallet,
+            reserve_liquidity_supply,
+            reserve_collateral_supply,
+            lp_mint,
             liquidity_mint,
+            owner,
             program_authority,
-            token_program,
-        } = BorrowAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+            lp_token_program,
+            liquidity_token_program,
+        } = DepositAndLockCollateralAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
 
-        verify_token_program(token_program)?;
+        verify_token_program(liquidity_token_program)?;
 
-        if destination_liquidity_wallet.key == reserve_liquidity_supply.key
-            || destination_liquidity_wallet.key == curator_fee_receiver.key
-            || destination_liquidity_wallet.key == texture_fee_receiver.key
-        {
-            msg!("destination_liquidity_wallet must be external to the Super Lendy");
+        if source_liquidity_wallet.key == reserve_liquidity_supply.key {
+            msg!("Source liquidity wallet can not be same as reserve's liquidity supply");
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
-        let pool_data = pool.data.borrow();
-        let unpacked_pool = Pool::try_from_bytes(&pool_data)?;
-
-        let curator_data = curator.data.borrow();
-        let unpacked_curator = Curator::try_from_bytes(&curator_data)?;
+        let (expected_liquidity_supply, _) = find_liquidity_supply(reserve.key);
+        verify_key(
+            reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "reserve liquidity supply",
+        )?;
 
+        let (expected_collateral_supply, _) = find_collateral_supply(reserve.key);
         verify_key(
-            &unpacked_pool.curator,
-            curator.key,
-            "pool.curator vs. curator",
+            reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "reserve collateral supply",
         )?;
 
+        let (expected_lp_mint, _) = find_lp_token_mint(reserve.key);
+        verify_key(lp_mint.key, &expected_lp_mint, "LP mint")?;
+
         let (expected_authority, authority_bump) = find_program_authority();
         verify_key(
             program_authority.key,
@@ -643,58 +1057,23 @@ impl<'a, 'b> Processor<'a, 'b> {
             "program authority",
         )?;
 
-        let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
-
-        let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
-
+        let liquidity_user_wallet_mint = spl_token_mint(source_liquidity_wallet)?;
         verify_key(
+            &liquidity_user_wallet_mint,
             liquidity_mint.key,
-            &unpacked_reserve.liquidity.mint,
-            "liquidity mint",
+            "liquidity_wallet.mint",
         )?;
 
-        if unpacked_reserve.reserve_type == RESERVE_TYPE_PROTECTED_COLLATERAL
-            || unpacked_reserve.mode == RESERVE_MODE_BORROW_DISABLED
-            || unpacked_reserve.mode == RESERVE_MODE_RETAIN_LIQUIDITY
-        {
-            msg!("reserve do not allow borrowing");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-
-        let texture_config_data = texture_config.data.borrow();
-        let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
 
-        // Curator's fee receiver should the ATA from curator.fee_authority
-        let expected_curator_fee_receiver = get_associated_token_address_with_program_id(
-            &unpacked_curator.fees_authority,
-            &unpacked_reserve.liquidity.mint,
-            token_program.key,
-        );
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
-            curator_fee_receiver.key,
-            &expected_curator_fee_receiver,
-            "curator_fee_receiver",
-        )?;
-
-        let expected_texture_fee_receiver = get_associated_token_address_with_program_id(
-            &unpacked_texture_config.fees_authority,
+            liquidity_mint.key,
             &unpacked_reserve.liquidity.mint,
-            token_program.key,
-        );
-
-        verify_key(
-            &unpacked_reserve.pool,
-            pool.key,
-            "pool from reserve doesn't match pool provided",
-        )?;
-
-        verify_key(
-            texture_fee_receiver.key,
-            &expected_texture_fee_receiver,
-            "texture_fee_receiver",
+            "liquidity mint",
         )?;
 
         // Check that position and reserve belongs to the same pool
@@ -703,222 +1082,457 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
-        //Reserve checks
         let clock = Clock::get().expect("no clock");
         if unpacked_reserve.is_stale(&clock)? {
-            msg!("Reserve {} is stale and must be refreshed prior to borrow. Last updated on {}. Current slot {}", reserve.key, unpacked_reserve.last_update.slot, clock.slot);
+            msg!(
+                "Reserve {} is stale and must be refreshed prior to deposit",
+                reserve.key
+            );
             return Err(SuperLendyError::StaleReserve);
         }
 
-        let expected_liquidity_supply = find_liquidity_supply(reserve.key);
-        verify_key(
-            reserve_liquidity_supply.key,
-            &expected_liquidity_supply.0,
-            "reserve liquidity supply",
-        )?;
-
-        // Position checks
-        verify_key(borrower.key, &position.owner, "position owner")?;
-
+        // Position needs to be refreshed to accrue rewards. Because after increase of the locked
+        // collateral rewards base will change.
         if position.is_stale(&clock)? {
-            msg!("Position is stale and must be refreshed prior to borrow");
+            msg!("Position is stale and must be refreshed");
             return Err(SuperLendyError::StalePosition);
         }
 
-        if !position.have_any_deposits() {
-            msg!("Position has no deposits to borrow against");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+        position.verify_authority(owner.key, DELEGATE_PERMISSION_LOCK)?;
 
-        if position.deposited_value()? == Decimal::ZERO {
-            msg!("Position deposits have zero value");
+        if unpacked_reserve.reserve_type == RESERVE_TYPE_NOT_A_COLLATERAL {
+            msg!("Reserve do not allow collateral lock. This Reserve for borrowing only.");
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
-        let remaining_borrow_value = position.remaining_borrow_value()?;
-        if remaining_borrow_value == Decimal::ZERO {
-            msg!("Remaining borrow value is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+        position.verify_emode_reserve(unpacked_reserve.config.emode_category)?;
 
-        let borrow_value = if amount == MAX_AMOUNT {
-            let max_borrow_value = unpacked_reserve.max_borrow_value()?;
-            msg!(
-                "reserve's max_borrow_value {}   user's remaining_borrow_value {} ",
-                max_borrow_value,
-                remaining_borrow_value
-            );
-            remaining_borrow_value.min(max_borrow_value)
+        position.apply_collateral_isolation(
+            *reserve.key,
+            unpacked_reserve.reserve_type == RESERVE_TYPE_ISOLATED_COLLATERAL,
+        )?;
+
+        let amount = if amount == MAX_AMOUNT {
+            // deposit all tokens from user's wallet
+            spl_token_amount(source_liquidity_wallet)?
         } else {
-            remaining_borrow_value
+            amount
         };
 
-        let CalculateBorrowResult {
-            borrow_amount,
-            receive_amount,
-            curator_borrow_fee,
-            texture_borrow_fee,
-        } = unpacked_reserve.calculate_borrow(
-            amount,
-            borrow_value,
-            unpacked_texture_config.borrow_fee_rate_bps,
-        )?;
-
-        if receive_amount == 0 {
-            msg!("Borrow amount is too small to receive liquidity after fees");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+        let lp_amount = unpacked_reserve.deposit_liquidity(amount)?;
+        unpacked_reserve.mark_stale();
 
-        if amount == MAX_AMOUNT && receive_amount < slippage_limit {
-            msg!("Received liquidity would be smaller than the desired slippage limit");
+        if lp_amount == 0 {
+            msg!("deposit is too small and results in zero LP tokens to lock");
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
-        let borrowed_lamports = receive_amount
-            .checked_add(curator_borrow_fee)
-            .ok_or(SuperLendyError::MathError(MathError(format!(
-                "borrow(): checked_add {} + {}",
-                receive_amount, curator_borrow_fee
-            ))))?
-            .checked_add(texture_borrow_fee)
-            .ok_or(SuperLendyError::MathError(MathError(format!(
-                "borrow(): checked_add {} + {} + {}",
-                receive_amount, curator_borrow_fee, texture_borrow_fee
-            ))))?;
-
-        msg!("borrow_amount {}  receive_amount {}  curator_borrow_fee {}  texture_borrow_fee {}  borrowed_lamports {}",
-            borrow_amount, receive_amount, curator_borrow_fee, texture_borrow_fee, borrowed_lamports);
-
-        unpacked_reserve
-            .liquidity
-            .borrow(borrow_amount, borrowed_lamports)?;
-        unpacked_reserve.mark_stale();
-
-        if unpacked_reserve.liquidity.utilization_rate()?
-            > Decimal::from_basis_points(unpacked_reserve.config.max_borrow_utilization_bps as u32)?
-        {
-            msg!(
-                "Borrow results in utilization rate {} which is greater then threshold {}",
-                unpacked_reserve.liquidity.utilization_rate()?,
-                Decimal::from_basis_points(
-                    unpacked_reserve.config.max_borrow_utilization_bps as u32
-                )?
-            );
-            return Err(SuperLendyError::ResourceExhausted);
-        }
+        msg!("amount {}  lp_amount {}", amount, lp_amount);
 
-        let borrowed_liquidity = position.find_or_add_borrowed_liquidity(
-            *reserve.key,
-            unpacked_reserve.liquidity.cumulative_borrow_rate()?,
+        let collateral = position.find_or_add_collateral(*reserve.key)?;
+        collateral.deposit(
+            lp_amount,
+            unpacked_reserve.lp_market_price()?,
+            unpacked_reserve.liquidity.mint_decimals,
         )?;
-
-        borrowed_liquidity.borrow(borrow_amount, unpacked_reserve.liquidity.market_price()?)?;
-        borrowed_liquidity.memo = memo;
+        collateral.memo = memo;
 
         position.mark_stale();
 
-        let spl_token = SplToken::new(token_program);
-
-        if curator_borrow_fee > 0 {
-            spl_token
-                .transfer(
-                    reserve_liquidity_supply,
-                    Some(liquidity_mint),
-                    curator_fee_receiver,
-                    program_authority,
-                    curator_borrow_fee,
-                    Some(unpacked_reserve.liquidity.mint_decimals),
-                )?
-                .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
-
-            msg!("curator's borrow fee {}", curator_borrow_fee);
-        }
-
-        if texture_borrow_fee > 0 {
-            spl_token
-                .transfer(
-                    reserve_liquidity_supply,
-                    Some(liquidity_mint),
-                    texture_fee_receiver,
-                    program_authority,
-                    texture_borrow_fee,
-                    Some(unpacked_reserve.liquidity.mint_decimals),
-                )?
-                .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
-
-            msg!("texture's borrow fee {}", texture_borrow_fee);
-        }
-
-        if receive_amount == 0 {
-            msg!("borrow results in 0 transfer to the user");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-
-        spl_token
+        SplToken::new(liquidity_token_program)
             .transfer(
-                reserve_liquidity_supply,
+                source_liquidity_wallet,
                 Some(liquidity_mint),
-                destination_liquidity_wallet,
-                program_authority,
-                receive_amount,
+                reserve_liquidity_supply,
+                owner,
+                amount,
                 Some(unpacked_reserve.liquidity.mint_decimals),
             )?
+            .call()?;
+
+        SplToken::new(lp_token_program)
+            .mint_to(
+                lp_mint,
+                program_authority,
+                reserve_collateral_supply,
+                lp_amount,
+            )?
             .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
 
         Ok(())
     }
 
     #[inline(never)]
-    pub fn repay(&self, amount: u64) -> LendyResult<()> {
-        msg!("repay ix: {}", amount);
+    pub fn unlock_and_withdraw_liquidity(&self, lp_amount: u64) -> LendyResult<()> {
+        msg!("unlock_and_withdraw_liquidity ix: {}", lp_amount);
 
-        if amount == 0 {
-            msg!("Amount to repay cannot be zero");
+        if lp_amount == 0 {
+            msg!("LP amount to unlock cannot be zero");
             return Err(InvalidAmount);
         }
 
-        let RepayAccounts {
+        let UnlockAndWithdrawLiquidityAccounts {
             position,
-            source_liquidity_wallet,
-            reserve_liquidity_supply,
-            user_authority,
             reserve,
+            reserve_collateral_supply,
+            reserve_liquidity_supply,
+            lp_mint,
+            destination_liquidity_wallet,
             liquidity_mint,
-            token_program,
-        } = RepayAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
-
-        verify_token_program(token_program)?;
+            owner,
+            program_authority,
+            lp_token_program,
+            liquidity_token_program,
+        } = UnlockAndWithdrawLiquidityAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
 
-        let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
+        verify_token_program(liquidity_token_program)?;
 
-        let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        if destination_liquidity_wallet.key == reserve_liquidity_supply.key {
+            msg!("Destination liquidity wallet can not be same as reserve's liquidity supply");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
 
+        let (expected_liquidity_supply, _) = find_liquidity_supply(reserve.key);
         verify_key(
-            liquidity_mint.key,
-            &unpacked_reserve.liquidity.mint,
-            "liquidity mint",
+            reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "reserve liquidity supply",
         )?;
 
-        // Check that source_liquidity_wallet is external to the contract. Practically it is not necessary
-        // as user_authority ensures that it is something external. But just to validate input and give
-        // caller better clue about what is wrong in accounts...
-        if source_liquidity_wallet.key == reserve_liquidity_supply.key {
-            msg!("source_liquidity_wallet can not be reserve_liquidity_supply");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+        let (expected_collateral_supply, _) = find_collateral_supply(reserve.key);
+        verify_key(
+            reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "reserve collateral supply",
+        )?;
 
-        // Check that position and reserve belongs to the same pool
-        if position.pool != unpacked_reserve.pool {
-            msg!("Position and reserve belongs to different pools");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+        let (expected_lp_mint, _) = find_lp_token_mint(reserve.key);
+        verify_key(lp_mint.key, &expected_lp_mint, "LP mint")?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let liquidity_user_wallet_mint = spl_token_mint(destination_liquidity_wallet)?;
+        verify_key(
+            &liquidity_user_wallet_mint,
+            liquidity_mint.key,
+            "liquidity_wallet.mint",
+        )?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        if unpacked_reserve.mode == RESERVE_MODE_RETAIN_LIQUIDITY {
+            msg!("reserve do not allow unlocking collateral");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        verify_key(
+            liquidity_mint.key,
+            &unpacked_reserve.liquidity.mint,
+            "liquidity mint",
+        )?;
+
+        // Check that position and reserve belongs to the same pool
+        if position.pool != unpacked_reserve.pool {
+            msg!("Position and reserve belongs to different pools");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let clock = Clock::get().expect("no clock");
+        if unpacked_reserve.is_stale(&clock)? {
+            msg!(
+                "Reserve {} is stale and must be refreshed prior to unlock collateral",
+                reserve.key
+            );
+            return Err(SuperLendyError::StaleReserve);
+        }
+
+        // Position needs to be refreshed to accrue rewards and to update collateral.market_value
+        if position.is_stale(&clock)? {
+            msg!("Position is stale and must be refreshed");
+            return Err(SuperLendyError::StalePosition);
+        }
+
+        if position
+            .verify_authority(owner.key, DELEGATE_PERMISSION_UNLOCK)?
+            .is_some()
+        {
+            verify_key(
+                &spl_token_owner(destination_liquidity_wallet)?,
+                &position.owner,
+                "destination_liquidity_wallet owner",
+            )?;
+        }
+
+        let (withdraw_amount, collateral_index) = unlock_amount(
+            position.as_position_ref(),
+            unpacked_reserve,
+            *reserve.key,
+            lp_amount,
+        )?;
+
+        let max_withdraw_lp_amount = unpacked_reserve.max_withdraw_lp_amount()?;
+        let withdraw_amount = if lp_amount == MAX_AMOUNT {
+            // Also respect withdraw utilization limit of the Reserve
+            withdraw_amount.min(max_withdraw_lp_amount)
+        } else {
+            withdraw_amount
+        };
+
+        let liquidity_amount = unpacked_reserve.withdraw_liquidity(withdraw_amount)?;
+
+        let utilization_after_withdraw = unpacked_reserve.liquidity.utilization_rate()?;
+        let max_withdraw_utilization = Decimal::from_basis_points(
+            unpacked_reserve.config.max_withdraw_utilization_bps as u32,
+        )?;
+        if utilization_after_withdraw > max_withdraw_utilization {
+            msg!("withdraw operation results in utilization {} which is higher than threshold {}. Max allowed withdraw LP amount {}",
+                utilization_after_withdraw, max_withdraw_utilization, max_withdraw_lp_amount);
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        unpacked_reserve.mark_stale();
+
+        if withdraw_amount == 0 || liquidity_amount == 0 {
+            msg!("Withdraw amount is too small to transfer");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        position.withdraw(withdraw_amount, collateral_index)?;
+        position.mark_stale();
+
+        msg!(
+            "withdraw_amount {}  liquidity_amount {}",
+            withdraw_amount,
+            liquidity_amount
+        );
+
+        SplToken::new(lp_token_program)
+            .burn(
+                reserve_collateral_supply,
+                lp_mint,
+                program_authority,
+                withdraw_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        SplToken::new(liquidity_token_program)
+            .transfer(
+                reserve_liquidity_supply,
+                Some(liquidity_mint),
+                destination_liquidity_wallet,
+                program_authority,
+                liquidity_amount,
+                Some(unpacked_reserve.liquidity.mint_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn borrow(
+        &self,
+        amount: u64,
+        slippage_limit: u64,
+        memo: [u8; BORROW_MEMO_LEN],
+    ) -> LendyResult<()> {
+        msg!("borrow ix: {}", amount);
+
+        self.borrow_liquidity(amount, slippage_limit, memo, None)
+    }
+
+    #[inline(never)]
+    pub fn borrow_fixed(
+        &self,
+        amount: u64,
+        slippage_limit: u64,
+        term_sec: u32,
+        max_rate_bps: u32,
+        memo: [u8; BORROW_MEMO_LEN],
+    ) -> LendyResult<()> {
+        msg!(
+            "borrow_fixed ix: {}  term_sec {}  max_rate_bps {}",
+            amount,
+            term_sec,
+            max_rate_bps
+        );
+
+        self.borrow_liquidity(amount, slippage_limit, memo, Some((term_sec, max_rate_bps)))
+    }
+
+    /// Common part of Borrow and BorrowFixed which have the same accounts. `fixed_terms` (term in
+    /// seconds and max rate in basis points) are set for fixed-rate loan.
+    #[inline(never)]
+    fn borrow_liquidity(
+        &self,
+        amount: u64,
+        slippage_limit: u64,
+        memo: [u8; BORROW_MEMO_LEN],
+        fixed_terms: Option<(u32, u32)>,
+    ) -> LendyResult<()> {
+        if amount == 0 {
+            msg!("Amount to borrow cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let BorrowAccounts {
+            position,
+            reserve_liquidity_supply,
+            destination_liquidity_wallet,
+            curator_fee_receiver,
+            borrower,
+            reserve,
+            pool,
+            curator,
+            texture_fee_receiver,
+            texture_config,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        } = BorrowAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        verify_token_program(token_program)?;
+
+        if destination_liquidity_wallet.key == reserve_liquidity_supply.key
+            || destination_liquidity_wallet.key == curator_fee_receiver.key
+            || destination_liquidity_wallet.key == texture_fee_receiver.key
+        {
+            msg!("destination_liquidity_wallet must be external to the Super Lendy");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let pool_data = pool.data.borrow();
+        let unpacked_pool = Pool::try_from_bytes(&pool_data)?;
+
+        let curator_data = curator.data.borrow();
+        let unpacked_curator = Curator::try_from_bytes(&curator_data)?;
+
+        verify_key(
+            &unpacked_pool.curator,
+            curator.key,
+            "pool.curator vs. curator",
+        )?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let isolated_collateral_reserve =
+            next_isolated_collateral_reserve(&mut account_info_iter, &position, self.program_id)?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        verify_key(
+            liquidity_mint.key,
+            &unpacked_reserve.liquidity.mint,
+            "liquidity mint",
+        )?;
+
+        if unpacked_reserve.reserve_type == RESERVE_TYPE_PROTECTED_COLLATERAL
+            || unpacked_reserve.mode == RESERVE_MODE_BORROW_DISABLED
+            || unpacked_reserve.mode == RESERVE_MODE_RETAIN_LIQUIDITY
+        {
+            msg!("reserve do not allow borrowing");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        if position.is_isolated() && unpacked_reserve.isolation_borrowable != 1 {
+            msg!("Position with isolated collateral can not borrow from that reserve");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        position.verify_emode_reserve(unpacked_reserve.config.emode_category)?;
+
+        if let Some((term_sec, max_rate_bps)) = fixed_terms {
+            if unpacked_reserve.fixed_rate_bps == 0 {
+                msg!("reserve do not offer fixed-rate loans");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            if term_sec == 0 || term_sec > unpacked_reserve.fixed_max_term_sec {
+                msg!(
+                    "Loan term {} sec must be positive and not greater than {} sec",
+                    term_sec,
+                    unpacked_reserve.fixed_max_term_sec
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            if unpacked_reserve.fixed_rate_bps > max_rate_bps {
+                msg!(
+                    "Reserve's fixed rate {} bps is greater than max rate {} bps",
+                    unpacked_reserve.fixed_rate_bps,
+                    max_rate_bps
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+        }
+
+        let texture_config_data = texture_config.data.borrow();
+        let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+
+        // Curator's fee receiver should the ATA from curator.fee_authority
+        let expected_curator_fee_receiver = get_associated_token_address_with_program_id(
+            &unpacked_curator.fees_authority,
+            &unpacked_reserve.liquidity.mint,
+            token_program.key,
+        );
+
+        verify_key(
+            curator_fee_receiver.key,
+            &expected_curator_fee_receiver,
+            "curator_fee_receiver",
+        )?;
+
+        let expected_texture_fee_receiver = get_associated_token_address_with_program_id(
+            &unpacked_texture_config.fees_authority,
+            &unpacked_reserve.liquidity.mint,
+            token_program.key,
+        );
+
+        verify_key(
+            &unpacked_reserve.pool,
+            pool.key,
+            "pool from reserve doesn't match pool provided",
+        )?;
+
+        verify_key(
+            texture_fee_receiver.key,
+            &expected_texture_fee_receiver,
+            "texture_fee_receiver",
+        )?;
+
+        // Check that position and reserve belongs to the same pool
+        if position.pool != unpacked_reserve.pool {
+            msg!("Position and reserve belongs to different pools");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
 
         //Reserve checks
         let clock = Clock::get().expect("no clock");
         if unpacked_reserve.is_stale(&clock)? {
-            msg!("Reserve is stale and must be refreshed prior to repay");
+            msg!("Reserve {} is stale and must be refreshed prior to borrow. Last updated on {}. Current slot {}", reserve.key, unpacked_reserve.last_update.slot, clock.slot);
             return Err(SuperLendyError::StaleReserve);
         }
 
@@ -929,336 +1543,2171 @@ impl<'a, 'b> Processor<'a, 'b> {
             "reserve liquidity supply",
         )?;
 
-        if position.is_stale(&clock)? {
-            msg!("Position is stale and must be refreshed prior to repay");
-            return Err(SuperLendyError::StalePosition);
-        }
+        // Position checks
+        let delegate = position.verify_authority(borrower.key, DELEGATE_PERMISSION_BORROW)?;
+        if delegate.is_some() {
+            verify_key(
+                &spl_token_owner(destination_liquidity_wallet)?,
+                &position.owner,
+                "destination_liquidity_wallet owner",
+            )?;
+        }
+
+        if position.is_stale(&clock)? {
+            msg!("Position is stale and must be refreshed prior to borrow");
+            return Err(SuperLendyError::StalePosition);
+        }
+
+        if !position.have_any_deposits() {
+            msg!("Position has no deposits to borrow against");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        if position.deposited_value()? == Decimal::ZERO {
+            msg!("Position deposits have zero value");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        // Remaining borrowing power is risk-adjusted. Convert it to plain value of this Reserve's
+        // liquidity.
+        let remaining_borrow_value = position
+            .remaining_borrow_value()?
+            .checked_div(unpacked_reserve.config.borrow_weight()?)?;
+        if remaining_borrow_value == Decimal::ZERO {
+            msg!("Remaining borrow value is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        // Delegate can borrow only within its own allowance
+        let remaining_borrow_value = if let Some(delegate) = delegate {
+            if delegate.borrow_allowance_cents() == 0 {
+                msg!("Delegate's borrow allowance is exhausted");
+                return Err(SuperLendyError::BorrowTooLarge);
+            }
+            remaining_borrow_value.min(delegate.borrow_allowance()?)
+        } else {
+            remaining_borrow_value
+        };
+
+        let borrow_value = if amount == MAX_AMOUNT {
+            let max_borrow_value = unpacked_reserve.max_borrow_value()?;
+            msg!(
+                "reserve's max_borrow_value {}   user's remaining_borrow_value {} ",
+                max_borrow_value,
+                remaining_borrow_value
+            );
+            remaining_borrow_value.min(max_borrow_value)
+        } else {
+            remaining_borrow_value
+        };
+
+        let CalculateBorrowResult {
+            borrow_amount,
+            receive_amount,
+            curator_borrow_fee,
+            texture_borrow_fee,
+        } = unpacked_reserve.calculate_borrow(
+            amount,
+            borrow_value,
+            unpacked_texture_config.borrow_fee_rate_bps,
+        )?;
+
+        if receive_amount == 0 {
+            msg!("Borrow amount is too small to receive liquidity after fees");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        if amount == MAX_AMOUNT && receive_amount < slippage_limit {
+            msg!("Received liquidity would be smaller than the desired slippage limit");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let borrowed_lamports = receive_amount
+            .checked_add(curator_borrow_fee)
+            .ok_or(SuperLendyError::MathError(MathError(format!(
+                "borrow(): checked_add {} + {}",
+                receive_amount, curator_borrow_fee
+            ))))?
+            .checked_add(texture_borrow_fee)
+            .ok_or(SuperLendyError::MathError(MathError(format!(
+                "borrow(): checked_add {} + {} + {}",
+                receive_amount, curator_borrow_fee, texture_borrow_fee
+            ))))?;
+
+        msg!("borrow_amount {}  receive_amount {}  curator_borrow_fee {}  texture_borrow_fee {}  borrowed_lamports {}",
+            borrow_amount, receive_amount, curator_borrow_fee, texture_borrow_fee, borrowed_lamports);
+
+        let market_price = unpacked_reserve.liquidity.market_price()?;
+
+        if let Some(delegate) = delegate {
+            position.consume_borrow_allowance(
+                &delegate.delegate,
+                borrow_amount.checked_mul(market_price)?,
+            )?;
+        }
+
+        if let Some((term_sec, _max_rate_bps)) = fixed_terms {
+            // Loan accrues along with the Reserve thus it originates at Reserve's last update
+            let origination_timestamp = unpacked_reserve.last_update.timestamp;
+            let loan = position.add_fixed_loan(
+                *reserve.key,
+                unpacked_reserve.fixed_rate_bps,
+                origination_timestamp,
+                origination_timestamp.saturating_add(term_sec as i64),
+            )?;
+
+            loan.liquidity.borrow(borrow_amount, market_price)?;
+            loan.liquidity.memo = memo;
+
+            let interest_per_year = loan.interest_per_year(borrow_amount)?;
+            unpacked_reserve.liquidity.borrow_fixed(
+                borrow_amount,
+                borrowed_lamports,
+                interest_per_year,
+            )?;
+        } else {
+            unpacked_reserve
+                .liquidity
+                .borrow(borrow_amount, borrowed_lamports)?;
+
+            let borrowed_liquidity = position.find_or_add_borrowed_liquidity(
+                *reserve.key,
+                unpacked_reserve.liquidity.cumulative_borrow_rate()?,
+            )?;
+
+            borrowed_liquidity.borrow(borrow_amount, market_price)?;
+            borrowed_liquidity.memo = memo;
+        }
+        unpacked_reserve.mark_stale();
+
+        if unpacked_reserve.liquidity.utilization_rate()?
+            > Decimal::from_basis_points(unpacked_reserve.config.max_borrow_utilization_bps as u32)?
+        {
+            msg!(
+                "Borrow results in utilization rate {} which is greater then threshold {}",
+                unpacked_reserve.liquidity.utilization_rate()?,
+                Decimal::from_basis_points(
+                    unpacked_reserve.config.max_borrow_utilization_bps as u32
+                )?
+            );
+            return Err(SuperLendyError::ResourceExhausted);
+        }
+
+        position.mark_stale();
+
+        if let Some(isolated_collateral_reserve) = isolated_collateral_reserve {
+            let mut isolated_reserve_data = isolated_collateral_reserve.data.borrow_mut();
+            let isolated_reserve = Reserve::unpack_mut(isolated_reserve_data.as_mut())?;
+            isolated_reserve.increase_isolated_debt(borrow_amount.checked_mul(market_price)?)?;
+        }
+
+        let spl_token = SplToken::new(token_program);
+
+        if curator_borrow_fee > 0 {
+            spl_token
+                .transfer(
+                    reserve_liquidity_supply,
+                    Some(liquidity_mint),
+                    curator_fee_receiver,
+                    program_authority,
+                    curator_borrow_fee,
+                    Some(unpacked_reserve.liquidity.mint_decimals),
+                )?
+                .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+            msg!("curator's borrow fee {}", curator_borrow_fee);
+        }
+
+        if texture_borrow_fee > 0 {
+            spl_token
+                .transfer(
+                    reserve_liquidity_supply,
+                    Some(liquidity_mint),
+                    texture_fee_receiver,
+                    program_authority,
+                    texture_borrow_fee,
+                    Some(unpacked_reserve.liquidity.mint_decimals),
+                )?
+                .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+            msg!("texture's borrow fee {}", texture_borrow_fee);
+        }
+
+        if receive_amount == 0 {
+            msg!("borrow results in 0 transfer to the user");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        spl_token
+            .transfer(
+                reserve_liquidity_supply,
+                Some(liquidity_mint),
+                destination_liquidity_wallet,
+                program_authority,
+                receive_amount,
+                Some(unpacked_reserve.liquidity.mint_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn repay(&self, amount: u64) -> LendyResult<()> {
+        msg!("repay ix: {}", amount);
+
+        self.repay_liquidity(amount, false)
+    }
+
+    #[inline(never)]
+    pub fn repay_fixed(&self, amount: u64) -> LendyResult<()> {
+        msg!("repay_fixed ix: {}", amount);
+
+        self.repay_liquidity(amount, true)
+    }
+
+    /// Common part of Repay and RepayFixed which have the same accounts. `fixed` - repay
+    /// fixed-rate loan instead of the variable rate borrow.
+    #[inline(never)]
+    fn repay_liquidity(&self, amount: u64, fixed: bool) -> LendyResult<()> {
+        if amount == 0 {
+            msg!("Amount to repay cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let RepayAccounts {
+            position,
+            source_liquidity_wallet,
+            reserve_liquidity_supply,
+            user_authority,
+            reserve,
+            liquidity_mint,
+            token_program,
+        } = RepayAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        verify_token_program(token_program)?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let isolated_collateral_reserve =
+            next_isolated_collateral_reserve(&mut account_info_iter, &position, self.program_id)?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        verify_key(
+            liquidity_mint.key,
+            &unpacked_reserve.liquidity.mint,
+            "liquidity mint",
+        )?;
+
+        // Check that source_liquidity_wallet is external to the contract. Practically it is not necessary
+        // as user_authority ensures that it is something external. But just to validate input and give
+        // caller better clue about what is wrong in accounts...
+        if source_liquidity_wallet.key == reserve_liquidity_supply.key {
+            msg!("source_liquidity_wallet can not be reserve_liquidity_supply");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        // Check that position and reserve belongs to the same pool
+        if position.pool != unpacked_reserve.pool {
+            msg!("Position and reserve belongs to different pools");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        //Reserve checks
+        let clock = Clock::get().expect("no clock");
+        if unpacked_reserve.is_stale(&clock)? {
+            msg!("Reserve is stale and must be refreshed prior to repay");
+            return Err(SuperLendyError::StaleReserve);
+        }
+
+        let expected_liquidity_supply = find_liquidity_supply(reserve.key);
+        verify_key(
+            reserve_liquidity_supply.key,
+            &expected_liquidity_supply.0,
+            "reserve liquidity supply",
+        )?;
+
+        if position.is_stale(&clock)? {
+            msg!("Position is stale and must be refreshed prior to repay");
+            return Err(SuperLendyError::StalePosition);
+        }
+
+        if !position.have_any_borrowings() {
+            msg!("Position has no borrowings");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (borrowed_liquidity, liquidity_index, fixed_loan) = if fixed {
+            let (loan, loan_index) = position.find_fixed_loan(*reserve.key)?;
+            (&loan.liquidity, loan_index, Some(*loan))
+        } else {
+            let (borrowed_liquidity, liquidity_index) =
+                position.find_borrowed_liquidity(*reserve.key)?;
+            (borrowed_liquidity, liquidity_index, None)
+        };
+
+        if borrowed_liquidity.borrowed_amount()? == Decimal::ZERO {
+            msg!("Liquidity borrowed amount is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let CalculateRepayResult {
+            settle_amount, /* this value used in internal contract math to account repay */
+            repay_amount, /* this is approximated (ceiled) amount will be transferred from Borrower to Reserve supply */
+        } = unpacked_reserve.calculate_repay(amount, borrowed_liquidity.borrowed_amount()?)?;
+
+        if repay_amount == 0 {
+            msg!("Repay amount is too small to transfer liquidity");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        msg!(
+            "settle_amount {}  repay_amount {}",
+            settle_amount,
+            repay_amount
+        );
+
+        if let Some(loan) = fixed_loan {
+            unpacked_reserve.liquidity.repay_fixed(
+                repay_amount,
+                settle_amount,
+                loan.interest_per_year(settle_amount)?,
+            )?;
+            position.repay_fixed_loan(settle_amount, liquidity_index)?;
+        } else {
+            unpacked_reserve
+                .liquidity
+                .repay(repay_amount, settle_amount)?;
+            position.repay(settle_amount, liquidity_index)?;
+        }
+        unpacked_reserve.mark_stale();
+        position.mark_stale();
+
+        if let Some(isolated_collateral_reserve) = isolated_collateral_reserve {
+            let mut isolated_reserve_data = isolated_collateral_reserve.data.borrow_mut();
+            let isolated_reserve = Reserve::unpack_mut(isolated_reserve_data.as_mut())?;
+            isolated_reserve.decrease_isolated_debt(
+                settle_amount.checked_mul(unpacked_reserve.liquidity.market_price()?)?,
+            )?;
+        }
+
+        let spl_token = SplToken::new(token_program);
+
+        spl_token
+            .transfer(
+                source_liquidity_wallet,
+                Some(liquidity_mint),
+                reserve_liquidity_supply,
+                user_authority,
+                repay_amount,
+                Some(unpacked_reserve.liquidity.mint_decimals),
+            )?
+            .call()?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn liquidate(&self, liquidity_amount: u64) -> LendyResult<()> {
+        msg!("liquidate ix: {}", liquidity_amount);
+
+        let accounts = LiquidateAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let (liquidator_amount, authority_bump) = liquidate_position(liquidity_amount, &accounts)?;
+
+        let collateral_spl_token = SplToken::new(accounts.collateral_token_program);
+        collateral_spl_token
+            .transfer(
+                accounts.collateral_reserve_lp_supply,
+                None,
+                accounts.destination_lp_wallet,
+                accounts.program_authority,
+                liquidator_amount,
+                None,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn liquidate_and_redeem(&self, liquidity_amount: u64) -> LendyResult<()> {
+        msg!("liquidate_and_redeem ix: {}", liquidity_amount);
+
+        let LiquidateAndRedeemAccounts {
+            repayment_source_wallet,
+            destination_lp_wallet,
+            destination_liquidity_wallet,
+            principal_reserve,
+            principal_reserve_liquidity_supply,
+            collateral_reserve,
+            collateral_reserve_lp_supply,
+            collateral_reserve_liquidity_supply,
+            collateral_reserve_lp_mint,
+            position,
+            liquidator,
+            principal_reserve_liquidity_mint,
+            collateral_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            principal_token_program,
+            collateral_token_program,
+            collateral_liquidity_token_program,
+        } = LiquidateAndRedeemAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        verify_token_program(collateral_liquidity_token_program)?;
+
+        let (expected_liquidity_supply, _) = find_liquidity_supply(collateral_reserve.key);
+        verify_key(
+            collateral_reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "collateral_reserve_liquidity_supply",
+        )?;
+
+        let (expected_lp_mint, _) = find_lp_token_mint(collateral_reserve.key);
+        verify_key(
+            collateral_reserve_lp_mint.key,
+            &expected_lp_mint,
+            "collateral_reserve_lp_mint",
+        )?;
+
+        if destination_liquidity_wallet.key == collateral_reserve_liquidity_supply.key {
+            msg!("destination_liquidity_wallet can not be collateral_reserve_liquidity_supply");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let liquidity_user_wallet_mint = spl_token_mint(destination_liquidity_wallet)?;
+        verify_key(
+            &liquidity_user_wallet_mint,
+            collateral_reserve_liquidity_mint.key,
+            "destination_liquidity_wallet.mint",
+        )?;
+
+        let (liquidator_amount, authority_bump) = liquidate_position(
+            liquidity_amount,
+            &LiquidateAccounts {
+                repayment_source_wallet,
+                destination_lp_wallet,
+                principal_reserve,
+                principal_reserve_liquidity_supply,
+                collateral_reserve,
+                collateral_reserve_lp_supply,
+                position,
+                liquidator,
+                principal_reserve_liquidity_mint,
+                texture_config,
+                program_authority,
+                principal_token_program,
+                collateral_token_program,
+            },
+        )?;
+
+        let mut collateral_reserve_data = collateral_reserve.data.borrow_mut();
+        let unpacked_collateral_reserve = Reserve::unpack_mut(collateral_reserve_data.as_mut())?;
+
+        verify_key(
+            collateral_reserve_liquidity_mint.key,
+            &unpacked_collateral_reserve.liquidity.mint,
+            "collateral reserve liquidity mint",
+        )?;
+
+        let collateral_spl_token = SplToken::new(collateral_token_program);
+
+        if unpacked_collateral_reserve
+            .redeemable_liquidity(liquidator_amount)?
+            .is_none()
+        {
+            msg!(
+                "Collateral Reserve can not redeem {} LP tokens. Transferring LPs instead.",
+                liquidator_amount
+            );
+
+            collateral_spl_token
+                .transfer(
+                    collateral_reserve_lp_supply,
+                    None,
+                    destination_lp_wallet,
+                    program_authority,
+                    liquidator_amount,
+                    None,
+                )?
+                .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+            return Ok(());
+        }
+
+        let liquidity_amount = unpacked_collateral_reserve.withdraw_liquidity(liquidator_amount)?;
+        unpacked_collateral_reserve.mark_stale();
+
+        msg!(
+            "redeem lp_amount {}  liquidity_amount {}",
+            liquidator_amount,
+            liquidity_amount
+        );
+
+        collateral_spl_token
+            .burn(
+                collateral_reserve_lp_supply,
+                collateral_reserve_lp_mint,
+                program_authority,
+                liquidator_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        let liquidity_spl_token = SplToken::new(collateral_liquidity_token_program);
+        liquidity_spl_token
+            .transfer(
+                collateral_reserve_liquidity_supply,
+                Some(collateral_reserve_liquidity_mint),
+                destination_liquidity_wallet,
+                program_authority,
+                liquidity_amount,
+                Some(unpacked_collateral_reserve.liquidity.mint_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn repay_with_collateral(&self, amount: u64) -> LendyResult<()> {
+        msg!("repay_with_collateral ix: {}", amount);
+
+        if amount == 0 {
+            msg!("Amount to repay cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let RepayWithCollateralAccounts {
+            position,
+            reserve,
+            reserve_collateral_supply,
+            lp_mint,
+            owner,
+            program_authority,
+            lp_token_program,
+        } = RepayWithCollateralAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let expected_collateral_supply = find_collateral_supply(reserve.key);
+        verify_key(
+            reserve_collateral_supply.key,
+            &expected_collateral_supply.0,
+            "reserve collateral supply",
+        )?;
+
+        let (expected_lp_mint, _) = find_lp_token_mint(reserve.key);
+        verify_key(lp_mint.key, &expected_lp_mint, "lp mint")?;
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        // Repaying with collateral burns it thus delegate needs collateral permission as well
+        position.verify_authority(
+            owner.key,
+            DELEGATE_PERMISSION_REPAY | DELEGATE_PERMISSION_UNLOCK,
+        )?;
+
+        // Check that position and reserve belongs to the same pool
+        if position.pool != unpacked_reserve.pool {
+            msg!("Position and reserve belongs to different pools");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let clock = Clock::get().expect("no clock");
+        if unpacked_reserve.is_stale(&clock)? {
+            msg!("Reserve is stale and must be refreshed prior to repay");
+            return Err(SuperLendyError::StaleReserve);
+        }
+
+        // Position needs to be refreshed to accrue rewards as both collateral and borrowing bases
+        // will change.
+        if position.is_stale(&clock)? {
+            msg!("Position is stale and must be refreshed prior to repay");
+            return Err(SuperLendyError::StalePosition);
+        }
+
+        let (borrowed_liquidity, liquidity_index) =
+            position.find_borrowed_liquidity(*reserve.key)?;
+
+        if borrowed_liquidity.borrowed_amount()? == Decimal::ZERO {
+            msg!("Liquidity borrowed amount is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (collateral, collateral_index) = position.find_collateral(*reserve.key)?;
+        if collateral.deposited_amount == 0 {
+            msg!("Collateral deposited amount is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let CalculateRepayWithCollateralResult {
+            settle_amount,
+            repay_amount,
+            lp_amount,
+        } = unpacked_reserve.calculate_repay_with_collateral(
+            amount,
+            borrowed_liquidity.borrowed_amount()?,
+            collateral.deposited_amount,
+        )?;
+
+        if repay_amount == 0 || lp_amount == 0 {
+            msg!("Repay amount is too small");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        msg!(
+            "settle_amount {}  repay_amount {}  lp_amount {}",
+            settle_amount,
+            repay_amount,
+            lp_amount
+        );
+
+        unpacked_reserve.repay_with_collateral(lp_amount, settle_amount)?;
+        unpacked_reserve.mark_stale();
+
+        position.repay(settle_amount, liquidity_index)?;
+        position.withdraw(lp_amount, collateral_index)?;
+        position.mark_stale();
+
+        // Isolated Position can hold collateral only from its isolated collateral Reserve. Thus
+        // the debt is tracked by the same Reserve.
+        if position.isolated_collateral == *reserve.key {
+            unpacked_reserve.decrease_isolated_debt(
+                settle_amount.checked_mul(unpacked_reserve.liquidity.market_price()?)?,
+            )?;
+        }
+
+        SplToken::new(lp_token_program)
+            .burn(
+                reserve_collateral_supply,
+                lp_mint,
+                program_authority,
+                lp_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn repay_with_collateral_swap(
+        &self,
+        lp_amount: u64,
+        min_repay_amount: u64,
+        swap_data: Vec<u8>,
+    ) -> LendyResult<()> {
+        msg!(
+            "repay_with_collateral_swap ix: {} {}",
+            lp_amount,
+            min_repay_amount
+        );
+
+        if lp_amount == 0 {
+            msg!("Collateral amount cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let RepayWithCollateralSwapAccounts {
+            position,
+            collateral_reserve,
+            collateral_reserve_lp_supply,
+            collateral_reserve_liquidity_supply,
+            collateral_reserve_lp_mint,
+            collateral_liquidity_wallet,
+            principal_reserve,
+            principal_reserve_liquidity_supply,
+            principal_liquidity_wallet,
+            owner,
+            collateral_reserve_liquidity_mint,
+            principal_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            lp_token_program,
+            collateral_liquidity_token_program,
+            principal_token_program,
+            swap_program,
+        } = RepayWithCollateralSwapAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        verify_token_program(collateral_liquidity_token_program)?;
+        verify_token_program(principal_token_program)?;
+
+        {
+            let texture_config_data = texture_config.data.borrow();
+            let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+
+            if unpacked_texture_config.swap_program == Pubkey::default() {
+                msg!("Swap program is not set in Texture config");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            verify_key(
+                swap_program.key,
+                &unpacked_texture_config.swap_program,
+                "swap program",
+            )?;
+        }
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        if collateral_reserve.key == principal_reserve.key {
+            msg!("Same Reserve debt must be repaid with RepayWithCollateral");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (expected_collateral_supply, _) = find_collateral_supply(collateral_reserve.key);
+        verify_key(
+            collateral_reserve_lp_supply.key,
+            &expected_collateral_supply,
+            "collateral_reserve_lp_supply",
+        )?;
+
+        let (expected_liquidity_supply, _) = find_liquidity_supply(collateral_reserve.key);
+        verify_key(
+            collateral_reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "collateral_reserve_liquidity_supply",
+        )?;
+
+        let (expected_lp_mint, _) = find_lp_token_mint(collateral_reserve.key);
+        verify_key(
+            collateral_reserve_lp_mint.key,
+            &expected_lp_mint,
+            "collateral_reserve_lp_mint",
+        )?;
+
+        let (expected_liquidity_supply, _) = find_liquidity_supply(principal_reserve.key);
+        verify_key(
+            principal_reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "principal_reserve_liquidity_supply",
+        )?;
+
+        let collateral_wallet_mint = spl_token_mint(collateral_liquidity_wallet)?;
+        verify_key(
+            &collateral_wallet_mint,
+            collateral_reserve_liquidity_mint.key,
+            "collateral_liquidity_wallet.mint",
+        )?;
+
+        let principal_wallet_mint = spl_token_mint(principal_liquidity_wallet)?;
+        verify_key(
+            &principal_wallet_mint,
+            principal_reserve_liquidity_mint.key,
+            "principal_liquidity_wallet.mint",
+        )?;
+
+        let clock = Clock::get().expect("no clock");
+
+        // Unlock and redeem collateral. Account data borrows are released before the swap CPI.
+        let (liquidity_amount, withdraw_value, withdraw_collateral_ltv, collateral_decimals) = {
+            let mut position_data = position.data.borrow_mut();
+            let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+            let mut collateral_reserve_data = collateral_reserve.data.borrow_mut();
+            let unpacked_collateral_reserve =
+                Reserve::unpack_mut(collateral_reserve_data.as_mut())?;
+
+            let principal_reserve_data = principal_reserve.data.borrow();
+            let mut migrated_reserve = None;
+            let unpacked_principal_reserve =
+                Reserve::unpack(&principal_reserve_data, &mut migrated_reserve)?;
+
+            verify_key(owner.key, &position.owner, "position owner")?;
+
+            verify_key(
+                collateral_reserve_liquidity_mint.key,
+                &unpacked_collateral_reserve.liquidity.mint,
+                "collateral reserve liquidity mint",
+            )?;
+            verify_key(
+                principal_reserve_liquidity_mint.key,
+                &unpacked_principal_reserve.liquidity.mint,
+                "principal reserve liquidity mint",
+            )?;
+
+            if position.pool != unpacked_collateral_reserve.pool
+                || position.pool != unpacked_principal_reserve.pool
+            {
+                msg!("Position and reserves belongs to different pools");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            if unpacked_collateral_reserve.is_stale(&clock)? {
+                msg!("Collateral reserve is stale and must be refreshed");
+                return Err(SuperLendyError::StaleReserve);
+            }
+
+            if unpacked_principal_reserve.is_stale(&clock)? {
+                msg!("Principal reserve is stale and must be refreshed");
+                return Err(SuperLendyError::StaleReserve);
+            }
+
+            if position.is_stale(&clock)? {
+                msg!("Position is stale and must be refreshed");
+                return Err(SuperLendyError::StalePosition);
+            }
+
+            let (borrowed_liquidity, _) =
+                position.find_borrowed_liquidity(*principal_reserve.key)?;
+            if borrowed_liquidity.borrowed_amount()? == Decimal::ZERO {
+                msg!("Liquidity borrowed amount is zero");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let (collateral, collateral_index) =
+                position.find_collateral(*collateral_reserve.key)?;
+            if lp_amount > collateral.deposited_amount {
+                msg!(
+                    "Collateral amount {} exceeds deposited amount {}",
+                    lp_amount,
+                    collateral.deposited_amount
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let withdraw_pct = Decimal::from_i128_with_scale(lp_amount as i128, 0)?.checked_div(
+                Decimal::from_i128_with_scale(collateral.deposited_amount as i128, 0)?,
+            )?; // Simple math here, thus from_i128_with_scale
+            let withdraw_value = collateral.market_value()?.checked_mul(withdraw_pct)?;
+
+            let withdraw_collateral_ltv =
+                position.withdraw_collateral_ltv(Decimal::from_basis_points(
+                    unpacked_collateral_reserve.config.max_borrow_ltv_bps as u32,
+                )?)?;
+
+            if unpacked_collateral_reserve
+                .redeemable_liquidity(lp_amount)?
+                .is_none()
+            {
+                msg!("Collateral Reserve can not redeem {} LP tokens", lp_amount);
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let liquidity_amount = unpacked_collateral_reserve.withdraw_liquidity(lp_amount)?;
+
+            let utilization_after_withdraw =
+                unpacked_collateral_reserve.liquidity.utilization_rate()?;
+            let max_withdraw_utilization = Decimal::from_basis_points(
+                unpacked_collateral_reserve
+                    .config
+                    .max_withdraw_utilization_bps as u32,
+            )?;
+            if utilization_after_withdraw > max_withdraw_utilization {
+                msg!(
+                    "collateral redeem results in utilization {} which is higher than threshold {}",
+                    utilization_after_withdraw,
+                    max_withdraw_utilization
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            unpacked_collateral_reserve.mark_stale();
+
+            position.withdraw(lp_amount, collateral_index)?;
+            position.mark_stale();
+
+            (
+                liquidity_amount,
+                withdraw_value,
+                withdraw_collateral_ltv,
+                unpacked_collateral_reserve.liquidity.mint_decimals,
+            )
+        };
+
+        msg!(
+            "redeem lp_amount {}  liquidity_amount {}  withdraw_value {}",
+            lp_amount,
+            liquidity_amount,
+            withdraw_value
+        );
+
+        SplToken::new(lp_token_program)
+            .burn(
+                collateral_reserve_lp_supply,
+                collateral_reserve_lp_mint,
+                program_authority,
+                lp_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        SplToken::new(collateral_liquidity_token_program)
+            .transfer(
+                collateral_reserve_liquidity_supply,
+                Some(collateral_reserve_liquidity_mint),
+                collateral_liquidity_wallet,
+                program_authority,
+                liquidity_amount,
+                Some(collateral_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        let principal_balance_before = spl_token_amount(principal_liquidity_wallet)?;
+
+        // All accounts after the listed ones belong to the swap program.
+        let swap_accounts: Vec<AccountInfo<'b>> = account_info_iter.cloned().collect();
+        let swap_ix = Instruction {
+            program_id: *swap_program.key,
+            accounts: swap_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: *account.key,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: swap_data,
+        };
+        let mut swap_account_infos = swap_accounts;
+        swap_account_infos.push(swap_program.clone());
+
+        invoke(&swap_ix, &swap_account_infos).map_err(SuperLendyError::SwapError)?;
+
+        let received_amount =
+            spl_token_amount(principal_liquidity_wallet)?.saturating_sub(principal_balance_before);
+
+        if received_amount == 0 || received_amount < min_repay_amount {
+            msg!(
+                "Swap gave {} principal tokens while at least {} expected",
+                received_amount,
+                min_repay_amount
+            );
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let mut principal_reserve_data = principal_reserve.data.borrow_mut();
+        let unpacked_principal_reserve = Reserve::unpack_mut(principal_reserve_data.as_mut())?;
+
+        let (borrowed_liquidity, liquidity_index) =
+            position.find_borrowed_liquidity(*principal_reserve.key)?;
+
+        let CalculateRepayResult {
+            settle_amount,
+            repay_amount,
+        } = unpacked_principal_reserve
+            .calculate_repay(received_amount, borrowed_liquidity.borrowed_amount()?)?;
+
+        if repay_amount == 0 {
+            msg!("Repay amount is too small to transfer liquidity");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        msg!(
+            "received_amount {}  settle_amount {}  repay_amount {}",
+            received_amount,
+            settle_amount,
+            repay_amount
+        );
+
+        unpacked_principal_reserve
+            .liquidity
+            .repay(repay_amount, settle_amount)?;
+        unpacked_principal_reserve.mark_stale();
+
+        position.repay(settle_amount, liquidity_index)?;
+
+        let repay_value =
+            settle_amount.checked_mul(unpacked_principal_reserve.liquidity.market_price()?)?;
+
+        // Isolated Position can hold collateral only from its isolated collateral Reserve.
+        if position.isolated_collateral == *collateral_reserve.key {
+            let mut collateral_reserve_data = collateral_reserve.data.borrow_mut();
+            let unpacked_collateral_reserve =
+                Reserve::unpack_mut(collateral_reserve_data.as_mut())?;
+            unpacked_collateral_reserve.decrease_isolated_debt(repay_value)?;
+        }
+
+        // Position values are not recalculated till next refresh. Thus check resulting borrowing
+        // power based on values the Position was refreshed with.
+        if position.have_any_borrowings() {
+            let weighted_borrowed_value = position
+                .weighted_borrowed_value()?
+                .checked_sub(
+                    repay_value.checked_mul(unpacked_principal_reserve.config.borrow_weight()?)?,
+                )?
+                .max(Decimal::ZERO);
+
+            let max_withdraw_value = max_withdraw_value(
+                position.allowed_borrow_value()?,
+                weighted_borrowed_value,
+                withdraw_collateral_ltv,
+            )?;
+
+            if withdraw_value > max_withdraw_value {
+                msg!(
+                    "Withdraw value {} exceeds maximum withdraw value {} after repay",
+                    withdraw_value,
+                    max_withdraw_value
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+        }
+
+        SplToken::new(principal_token_program)
+            .transfer(
+                principal_liquidity_wallet,
+                Some(principal_reserve_liquidity_mint),
+                principal_reserve_liquidity_supply,
+                owner,
+                repay_amount,
+                Some(unpacked_principal_reserve.liquidity.mint_decimals),
+            )?
+            .call()?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn swap_collateral(
+        &self,
+        lp_amount: u64,
+        min_target_amount: u64,
+        swap_data: Vec<u8>,
+    ) -> LendyResult<()> {
+        msg!("swap_collateral ix: {} {}", lp_amount, min_target_amount);
+
+        if lp_amount == 0 {
+            msg!("Collateral amount cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let SwapCollateralAccounts {
+            position,
+            source_reserve,
+            source_reserve_collateral_supply,
+            source_reserve_liquidity_supply,
+            source_reserve_lp_mint,
+            source_liquidity_wallet,
+            target_reserve,
+            target_reserve_collateral_supply,
+            target_reserve_liquidity_supply,
+            target_reserve_lp_mint,
+            target_liquidity_wallet,
+            owner,
+            source_reserve_liquidity_mint,
+            target_reserve_liquidity_mint,
+            texture_config,
+            program_authority,
+            lp_token_program,
+            source_liquidity_token_program,
+            target_liquidity_token_program,
+            swap_program,
+        } = SwapCollateralAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        verify_token_program(source_liquidity_token_program)?;
+        verify_token_program(target_liquidity_token_program)?;
+
+        {
+            let texture_config_data = texture_config.data.borrow();
+            let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+
+            if unpacked_texture_config.swap_program == Pubkey::default() {
+                msg!("Swap program is not set in Texture config");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            verify_key(
+                swap_program.key,
+                &unpacked_texture_config.swap_program,
+                "swap program",
+            )?;
+        }
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        if source_reserve.key == target_reserve.key {
+            msg!("Source and target reserves must be different");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (expected_collateral_supply, _) = find_collateral_supply(source_reserve.key);
+        verify_key(
+            source_reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "source_reserve_collateral_supply",
+        )?;
+
+        let (expected_liquidity_supply, _) = find_liquidity_supply(source_reserve.key);
+        verify_key(
+            source_reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "source_reserve_liquidity_supply",
+        )?;
+
+        let (expected_lp_mint, _) = find_lp_token_mint(source_reserve.key);
+        verify_key(
+            source_reserve_lp_mint.key,
+            &expected_lp_mint,
+            "source_reserve_lp_mint",
+        )?;
+
+        let (expected_collateral_supply, _) = find_collateral_supply(target_reserve.key);
+        verify_key(
+            target_reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "target_reserve_collateral_supply",
+        )?;
+
+        let (expected_liquidity_supply, _) = find_liquidity_supply(target_reserve.key);
+        verify_key(
+            target_reserve_liquidity_supply.key,
+            &expected_liquidity_supply,
+            "target_reserve_liquidity_supply",
+        )?;
+
+        let (expected_lp_mint, _) = find_lp_token_mint(target_reserve.key);
+        verify_key(
+            target_reserve_lp_mint.key,
+            &expected_lp_mint,
+            "target_reserve_lp_mint",
+        )?;
+
+        let source_wallet_mint = spl_token_mint(source_liquidity_wallet)?;
+        verify_key(
+            &source_wallet_mint,
+            source_reserve_liquidity_mint.key,
+            "source_liquidity_wallet.mint",
+        )?;
+
+        let target_wallet_mint = spl_token_mint(target_liquidity_wallet)?;
+        verify_key(
+            &target_wallet_mint,
+            target_reserve_liquidity_mint.key,
+            "target_liquidity_wallet.mint",
+        )?;
+
+        let clock = Clock::get().expect("no clock");
+
+        // Unlock and redeem source collateral. Account data borrows are released before the swap CPI.
+        let (liquidity_amount, withdraw_value, withdraw_collateral_ltv, source_decimals) = {
+            let mut position_data = position.data.borrow_mut();
+            let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+            let mut source_reserve_data = source_reserve.data.borrow_mut();
+            let unpacked_source_reserve = Reserve::unpack_mut(source_reserve_data.as_mut())?;
+
+            let target_reserve_data = target_reserve.data.borrow();
+            let mut migrated_reserve = None;
+            let unpacked_target_reserve =
+                Reserve::unpack(&target_reserve_data, &mut migrated_reserve)?;
+
+            verify_key(owner.key, &position.owner, "position owner")?;
+
+            verify_key(
+                source_reserve_liquidity_mint.key,
+                &unpacked_source_reserve.liquidity.mint,
+                "source reserve liquidity mint",
+            )?;
+            verify_key(
+                target_reserve_liquidity_mint.key,
+                &unpacked_target_reserve.liquidity.mint,
+                "target reserve liquidity mint",
+            )?;
+
+            if position.pool != unpacked_source_reserve.pool
+                || position.pool != unpacked_target_reserve.pool
+            {
+                msg!("Position and reserves belongs to different pools");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            if unpacked_source_reserve.is_stale(&clock)? {
+                msg!("Source reserve is stale and must be refreshed");
+                return Err(SuperLendyError::StaleReserve);
+            }
+
+            if unpacked_target_reserve.is_stale(&clock)? {
+                msg!("Target reserve is stale and must be refreshed");
+                return Err(SuperLendyError::StaleReserve);
+            }
+
+            // Position needs to be refreshed to accrue rewards and to have actual collateral values.
+            if position.is_stale(&clock)? {
+                msg!("Position is stale and must be refreshed");
+                return Err(SuperLendyError::StalePosition);
+            }
+
+            if unpacked_target_reserve.reserve_type == RESERVE_TYPE_NOT_A_COLLATERAL {
+                msg!(
+                    "Target Reserve do not allow collateral lock. This Reserve for borrowing only."
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let (collateral, collateral_index) = position.find_collateral(*source_reserve.key)?;
+            if lp_amount > collateral.deposited_amount {
+                msg!(
+                    "Collateral amount {} exceeds deposited amount {}",
+                    lp_amount,
+                    collateral.deposited_amount
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let withdraw_pct = Decimal::from_i128_with_scale(lp_amount as i128, 0)?.checked_div(
+                Decimal::from_i128_with_scale(collateral.deposited_amount as i128, 0)?,
+            )?; // Simple math here, thus from_i128_with_scale
+            let withdraw_value = collateral.market_value()?.checked_mul(withdraw_pct)?;
+
+            let withdraw_collateral_ltv =
+                position.withdraw_collateral_ltv(Decimal::from_basis_points(
+                    unpacked_source_reserve.config.max_borrow_ltv_bps as u32,
+                )?)?;
+
+            if unpacked_source_reserve
+                .redeemable_liquidity(lp_amount)?
+                .is_none()
+            {
+                msg!("Source Reserve can not redeem {} LP tokens", lp_amount);
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            let liquidity_amount = unpacked_source_reserve.withdraw_liquidity(lp_amount)?;
+
+            let utilization_after_withdraw =
+                unpacked_source_reserve.liquidity.utilization_rate()?;
+            let max_withdraw_utilization = Decimal::from_basis_points(
+                unpacked_source_reserve.config.max_withdraw_utilization_bps as u32,
+            )?;
+            if utilization_after_withdraw > max_withdraw_utilization {
+                msg!(
+                    "collateral swap results in utilization {} which is higher than threshold {}",
+                    utilization_after_withdraw,
+                    max_withdraw_utilization
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+
+            unpacked_source_reserve.mark_stale();
+
+            position.withdraw(lp_amount, collateral_index)?;
+            position.mark_stale();
+
+            (
+                liquidity_amount,
+                withdraw_value,
+                withdraw_collateral_ltv,
+                unpacked_source_reserve.liquidity.mint_decimals,
+            )
+        };
+
+        msg!(
+            "redeem lp_amount {}  liquidity_amount {}  withdraw_value {}",
+            lp_amount,
+            liquidity_amount,
+            withdraw_value
+        );
+
+        SplToken::new(lp_token_program)
+            .burn(
+                source_reserve_collateral_supply,
+                source_reserve_lp_mint,
+                program_authority,
+                lp_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        SplToken::new(source_liquidity_token_program)
+            .transfer(
+                source_reserve_liquidity_supply,
+                Some(source_reserve_liquidity_mint),
+                source_liquidity_wallet,
+                program_authority,
+                liquidity_amount,
+                Some(source_decimals),
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        let target_balance_before = spl_token_amount(target_liquidity_wallet)?;
+
+        // All accounts after the listed ones belong to the swap program.
+        let swap_accounts: Vec<AccountInfo<'b>> = account_info_iter.cloned().collect();
+        let swap_ix = Instruction {
+            program_id: *swap_program.key,
+            accounts: swap_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: *account.key,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: swap_data,
+        };
+        let mut swap_account_infos = swap_accounts;
+        swap_account_infos.push(swap_program.clone());
+
+        invoke(&swap_ix, &swap_account_infos).map_err(SuperLendyError::SwapError)?;
+
+        let received_amount =
+            spl_token_amount(target_liquidity_wallet)?.saturating_sub(target_balance_before);
+
+        if received_amount == 0 || received_amount < min_target_amount {
+            msg!(
+                "Swap gave {} target tokens while at least {} expected",
+                received_amount,
+                min_target_amount
+            );
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let mut position_data = position.data.borrow_mut();
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+        let mut target_reserve_data = target_reserve.data.borrow_mut();
+        let unpacked_target_reserve = Reserve::unpack_mut(target_reserve_data.as_mut())?;
+
+        position.verify_emode_reserve(unpacked_target_reserve.config.emode_category)?;
+
+        position.apply_collateral_isolation(
+            *target_reserve.key,
+            unpacked_target_reserve.reserve_type == RESERVE_TYPE_ISOLATED_COLLATERAL,
+        )?;
+
+        let target_lp_amount = unpacked_target_reserve.deposit_liquidity(received_amount)?;
+        unpacked_target_reserve.mark_stale();
+
+        if target_lp_amount == 0 {
+            msg!("Swapped amount is too small and results in zero LP tokens to lock");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let target_lp_market_price = unpacked_target_reserve.lp_market_price()?;
+        let target_decimals = unpacked_target_reserve.liquidity.mint_decimals;
+
+        let collateral = position.find_or_add_collateral(*target_reserve.key)?;
+        collateral.deposit(target_lp_amount, target_lp_market_price, target_decimals)?;
+        position.mark_stale();
+
+        let deposit_value = Decimal::from_lamports(target_lp_amount, target_decimals)?
+            .checked_mul(target_lp_market_price)?;
+
+        msg!(
+            "received_amount {}  target_lp_amount {}  deposit_value {}",
+            received_amount,
+            target_lp_amount,
+            deposit_value
+        );
+
+        // Position values are not recalculated till next refresh. Thus check resulting borrowing
+        // power based on values the Position was refreshed with. New collateral is accounted with
+        // target Reserve's own LTV. Target is in Position's e-mode category (if any) thus category
+        // LTV keeps applying after refresh and Reserve's LTV is the conservative estimate.
+        if position.have_any_borrowings() {
+            let allowed_borrow_value =
+                position
+                    .allowed_borrow_value()?
+                    .checked_add(deposit_value.checked_mul(Decimal::from_basis_points(
+                        unpacked_target_reserve.config.max_borrow_ltv_bps as u32,
+                    )?)?)?;
+
+            let max_withdraw_value = max_withdraw_value(
+                allowed_borrow_value,
+                position.weighted_borrowed_value()?,
+                withdraw_collateral_ltv,
+            )?;
+
+            if withdraw_value > max_withdraw_value {
+                msg!(
+                    "Withdraw value {} exceeds maximum withdraw value {} after collateral swap",
+                    withdraw_value,
+                    max_withdraw_value
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+        }
+
+        SplToken::new(target_liquidity_token_program)
+            .transfer(
+                target_liquidity_wallet,
+                Some(target_reserve_liquidity_mint),
+                target_reserve_liquidity_supply,
+                owner,
+                received_amount,
+                Some(target_decimals),
+            )?
+            .call()?;
+
+        SplToken::new(lp_token_program)
+            .mint_to(
+                target_reserve_lp_mint,
+                program_authority,
+                target_reserve_collateral_supply,
+                target_lp_amount,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn write_off_bad_debt(&self, amount: u64) -> LendyResult<()> {
+        msg!("write_off_bad_debt ix");
+
+        if amount == 0 {
+            msg!("Amount to write off cannot be zero");
+            return Err(InvalidAmount);
+        }
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let WriteOffBadDebtAccounts {
+            pool,
+            reserve,
+            position,
+            curator_pools_authority,
+            curator,
+            reserve_liquidity_supply,
+            insurance_fund,
+            liquidity_mint,
+            program_authority,
+            token_program,
+        } = WriteOffBadDebtAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        verify_curator(pool, curator, curator_pools_authority)?;
+
+        write_off_position_debt(
+            amount,
+            &SocializeLossAccounts {
+                pool,
+                reserve,
+                position,
+                reserve_liquidity_supply,
+                insurance_fund,
+                liquidity_mint,
+                program_authority,
+                token_program,
+            },
+            &mut account_info_iter,
+            self.program_id,
+        )
+    }
+
+    #[inline(never)]
+    pub fn socialize_loss(&self) -> LendyResult<()> {
+        msg!("socialize_loss ix");
+
+        let mut account_info_iter = self.accounts.iter();
+
+        let accounts = SocializeLossAccounts::from_iter(&mut account_info_iter, self.program_id)?;
+
+        let pool_data = accounts.pool.data.borrow();
+        let unpacked_pool = Pool::try_from_bytes(pool_data.as_ref())?;
+
+        if !unpacked_pool.auto_write_off_bad_debt_enabled() {
+            msg!("Pool doesn't allow permissionless write-off of bad debt");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        // Unlike WriteOffBadDebt zero deposited value is not enough here as it may be caused by
+        // zero price of the collateral. All collateral must be seized.
+        {
+            let position_data = accounts.position.data.borrow();
+            let unpacked_position = Position::unpack(position_data.as_ref())?;
+            if unpacked_position.have_any_deposits() {
+                msg!("Position still has collateral. Liquidate it first.");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+        }
+
+        write_off_position_debt(
+            MAX_AMOUNT,
+            &accounts,
+            &mut account_info_iter,
+            self.program_id,
+        )
+    }
+}
+
+/// Common part of WriteOffBadDebt and SocializeLoss. Writes off up to `amount` of debt (variable
+/// and fixed-rate) of the Position without collateral. Loss is covered from Pool's insurance fund
+/// first and only the rest is socialized on LPs.
+fn write_off_position_debt<'a, 'b, I>(
+    amount: u64,
+    accounts: &SocializeLossAccounts<'a, 'b>,
+    account_info_iter: &mut I,
+    program_id: &Pubkey,
+) -> LendyResult<()>
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    let SocializeLossAccounts {
+        pool,
+        reserve,
+        position,
+        reserve_liquidity_supply,
+        insurance_fund,
+        liquidity_mint,
+        program_authority,
+        token_program,
+    } = *accounts;
+
+    verify_token_program(token_program)?;
+
+    // Insurance fund is the token account of the mint's token program. Other token program would
+    // let the caller skip the fund and put the whole loss on LPs.
+    if liquidity_mint.owner != token_program.key {
+        msg!(
+            "liquidity mint owner {} doesn't match token program {}",
+            liquidity_mint.owner,
+            token_program.key
+        );
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
+
+    let mut position_data = position.data.borrow_mut();
+    let mut unpacked_position = Position::unpack_mut(position_data.as_mut())?;
+
+    let isolated_collateral_reserve =
+        next_isolated_collateral_reserve(account_info_iter, &unpacked_position, program_id)?;
+
+    let mut reserve_data = reserve.data.borrow_mut();
+    let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+    if unpacked_position.pool != unpacked_reserve.pool || unpacked_position.pool != *pool.key {
+        msg!("Position, reserve and provided pool do not match");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
+
+    verify_key(
+        liquidity_mint.key,
+        &unpacked_reserve.liquidity.mint,
+        "liquidity mint",
+    )?;
+
+    let (expected_liquidity_supply, _liquidity_supply_bump) = find_liquidity_supply(reserve.key);
+    verify_key(
+        reserve_liquidity_supply.key,
+        &expected_liquidity_supply,
+        "liquidity supply",
+    )?;
+
+    let (expected_insurance_fund, _insurance_fund_bump) =
+        find_insurance_fund(pool.key, liquidity_mint.key);
+    verify_key(
+        insurance_fund.key,
+        &expected_insurance_fund,
+        "insurance_fund",
+    )?;
+
+    let (expected_authority, authority_bump) = find_program_authority();
+    verify_key(
+        program_authority.key,
+        &expected_authority,
+        "program authority",
+    )?;
+
+    let clock = Clock::get().expect("no clock");
+    if unpacked_position.is_stale(&clock)? {
+        msg!("Position is stale and must be refreshed");
+        return Err(SuperLendyError::StalePosition);
+    }
 
-        if !position.have_any_borrowings() {
-            msg!("Position has no borrowings");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    if unpacked_reserve.is_stale(&clock)? {
+        msg!("Reserve is stale and must be refreshed");
+        return Err(SuperLendyError::StaleReserve);
+    }
 
-        let (borrowed_liquidity, liquidity_index) =
-            position.find_borrowed_liquidity(*reserve.key)?;
+    if unpacked_position.deposited_value()? != Decimal::ZERO {
+        msg!("Position has {} deposited value. Liquidate it first and then try to write off bad debt again.", unpacked_position.deposited_value()?);
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        if borrowed_liquidity.borrowed_amount()? == Decimal::ZERO {
-            msg!("Liquidity borrowed amount is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    let decimals = unpacked_reserve.liquidity.mint_decimals;
+
+    // Variable-rate debt is written off first and then fixed-rate loan from the same Reserve
+    let mut written_off_amount = 0;
+    if unpacked_position
+        .find_borrowed_liquidity(*reserve.key)
+        .is_ok()
+    {
+        let borrowed_liquidity = unpacked_position.find_borrowed_liquidity_mut(*reserve.key)?;
+        let borrowed_amount = borrowed_liquidity.borrowed_amount()?;
+        borrowed_liquidity.write_off_bad_debt(amount, decimals)?;
+        written_off_amount = borrowed_amount
+            .checked_sub(borrowed_liquidity.borrowed_amount()?)?
+            .to_lamports_ceil(decimals)?;
 
-        let CalculateRepayResult {
-            settle_amount, /* this value used in internal contract math to account repay */
-            repay_amount, /* this is approximated (ceiled) amount will be transferred from Borrower to Reserve supply */
-        } = unpacked_reserve.calculate_repay(amount, borrowed_liquidity.borrowed_amount()?)?;
+        unpacked_reserve
+            .liquidity
+            .write_off_bad_debt(written_off_amount)?;
+    }
 
-        if repay_amount == 0 {
-            msg!("Repay amount is too small to transfer liquidity");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
+    let fixed_amount = if amount == MAX_AMOUNT {
+        MAX_AMOUNT
+    } else {
+        amount.saturating_sub(written_off_amount)
+    };
+
+    if fixed_amount > 0 {
+        if let Ok((_loan, loan_index)) = unpacked_position.find_fixed_loan(*reserve.key) {
+            let loan = &mut unpacked_position.fixed_loans[loan_index];
+            let borrowed_amount = loan.liquidity.borrowed_amount()?;
+            loan.liquidity.write_off_bad_debt(fixed_amount, decimals)?;
+            let settle_amount = borrowed_amount.checked_sub(loan.liquidity.borrowed_amount()?)?;
+
+            unpacked_reserve
+                .liquidity
+                .write_off_fixed_bad_debt(settle_amount, loan.interest_per_year(settle_amount)?)?;
+
+            let fixed_written_off_amount = settle_amount.to_lamports_ceil(decimals)?;
+            written_off_amount = written_off_amount
+                .checked_add(fixed_written_off_amount)
+                .ok_or(SuperLendyError::MathError(MathError(format!(
+                    "write_off_position_debt(): checked_add {} + {}",
+                    written_off_amount, fixed_written_off_amount
+                ))))?;
         }
+    }
 
+    if written_off_amount == 0 {
         msg!(
-            "settle_amount {}  repay_amount {}",
-            settle_amount,
-            repay_amount
+            "Position has no debt in Reserve {} to write off",
+            reserve.key
         );
+        return Err(SuperLendyError::BorrowedLiquidityNotFound);
+    }
 
-        unpacked_reserve
-            .liquidity
-            .repay(repay_amount, settle_amount)?;
-        unpacked_reserve.mark_stale();
+    unpacked_position.mark_stale();
 
-        position.repay(settle_amount, liquidity_index)?;
-        position.mark_stale();
+    // Insurance fund takes the loss first. Only the rest is socialized on LPs.
+    let covered_amount = if insurance_fund.owner == token_program.key {
+        written_off_amount.min(spl_token_amount(insurance_fund)?)
+    } else if insurance_fund.data_is_empty() {
+        msg!("Insurance fund is not initialized. Whole loss is socialized on LPs");
+        0
+    } else {
+        msg!(
+            "insurance fund owner {} doesn't match token program {}",
+            insurance_fund.owner,
+            token_program.key
+        );
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    };
 
-        let spl_token = SplToken::new(token_program);
+    if covered_amount > 0 {
+        unpacked_reserve.liquidity.deposit(covered_amount)?;
 
+        let spl_token = SplToken::new(token_program);
         spl_token
             .transfer(
-                source_liquidity_wallet,
+                insurance_fund,
                 Some(liquidity_mint),
                 reserve_liquidity_supply,
-                user_authority,
-                repay_amount,
-                Some(unpacked_reserve.liquidity.mint_decimals),
+                program_authority,
+                covered_amount,
+                Some(decimals),
             )?
-            .call()?;
-
-        Ok(())
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
     }
 
-    #[inline(never)]
-    pub fn liquidate(&self, liquidity_amount: u64) -> LendyResult<()> {
-        msg!("liquidate ix: {}", liquidity_amount);
-
-        if liquidity_amount == 0 {
-            msg!("Amount to liquidate cannot be zero");
-            return Err(InvalidAmount);
-        }
+    unpacked_reserve.mark_stale();
 
-        let LiquidateAccounts {
-            repayment_source_wallet,
-            destination_lp_wallet,
-            principal_reserve,
-            principal_reserve_liquidity_supply,
-            collateral_reserve,
-            collateral_reserve_lp_supply,
-            position,
-            liquidator,
-            principal_reserve_liquidity_mint,
-            program_authority,
-            principal_token_program,
-            collateral_token_program,
-        } = LiquidateAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+    if let Some(isolated_collateral_reserve) = isolated_collateral_reserve {
+        let mut isolated_reserve_data = isolated_collateral_reserve.data.borrow_mut();
+        let isolated_reserve = Reserve::unpack_mut(isolated_reserve_data.as_mut())?;
+        isolated_reserve.decrease_isolated_debt(
+            Decimal::from_lamports(written_off_amount, decimals)?
+                .checked_mul(unpacked_reserve.liquidity.market_price()?)?,
+        )?;
+    }
 
-        verify_token_program(principal_token_program)?;
+    msg!(
+        "written off {} covered by insurance fund {}",
+        written_off_amount,
+        covered_amount
+    );
+
+    SuperLendyEvent::BadDebtWrittenOff {
+        pool: *pool.key,
+        reserve: *reserve.key,
+        position: *position.key,
+        amount: written_off_amount,
+        covered_amount,
+        socialized_amount: written_off_amount - covered_amount,
+    }
+    .emit();
 
-        let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
+    Ok(())
+}
 
-        let mut principal_reserve_data = principal_reserve.data.borrow_mut();
-        let unpacked_principal_reserve =
-            Reserve::try_from_bytes_mut(principal_reserve_data.as_mut())?;
+/// Common part of Liquidate and LiquidateAndRedeem. Repays Position's debt from liquidator's wallet
+/// and releases collateral LP tokens which stay in collateral supply. Returns LP amount to be passed
+/// to liquidator and program authority bump.
+fn liquidate_position(
+    liquidity_amount: u64,
+    accounts: &LiquidateAccounts<'_, '_>,
+) -> LendyResult<(u64, u8)> {
+    if liquidity_amount == 0 {
+        msg!("Amount to liquidate cannot be zero");
+        return Err(InvalidAmount);
+    }
 
+    let LiquidateAccounts {
+        repayment_source_wallet,
+        destination_lp_wallet,
+        principal_reserve,
+        principal_reserve_liquidity_supply,
+        collateral_reserve,
+        collateral_reserve_lp_supply,
+        position,
+        liquidator,
+        principal_reserve_liquidity_mint,
+        texture_config,
+        program_authority,
+        principal_token_program,
+        collateral_token_program: _,
+    } = *accounts;
+
+    verify_token_program(principal_token_program)?;
+
+    let mut position_data = position.data.borrow_mut();
+    let mut position = Position::unpack_mut(position_data.as_mut())?;
+
+    let mut principal_reserve_data = principal_reserve.data.borrow_mut();
+    let unpacked_principal_reserve = Reserve::unpack_mut(principal_reserve_data.as_mut())?;
+
+    verify_key(
+        principal_reserve_liquidity_mint.key,
+        &unpacked_principal_reserve.liquidity.mint,
+        "principal reserve liquidity mint",
+    )?;
+
+    // Check lp_wallet.mint & liquidity_wallet.mint
+    {
+        let lp_user_wallet_mint = spl_token_mint(destination_lp_wallet)?;
+        let (lp_mint, _lp_mint_bump) = find_lp_token_mint(collateral_reserve.key);
+        verify_key(&lp_user_wallet_mint, &lp_mint, "lp_wallet.mint")?;
+
+        let liquidity_user_wallet_mint = spl_token_mint(repayment_source_wallet)?;
         verify_key(
+            &liquidity_user_wallet_mint,
             principal_reserve_liquidity_mint.key,
-            &unpacked_principal_reserve.liquidity.mint,
-            "principal reserve liquidity mint",
+            "liquidity_wallet.mint",
         )?;
+    }
 
-        // Check lp_wallet.mint & liquidity_wallet.mint
-        {
-            let lp_user_wallet_mint = spl_token_mint(destination_lp_wallet)?;
-            let (lp_mint, _lp_mint_bump) = find_lp_token_mint(collateral_reserve.key);
-            verify_key(&lp_user_wallet_mint, &lp_mint, "lp_wallet.mint")?;
-
-            let liquidity_user_wallet_mint = spl_token_mint(repayment_source_wallet)?;
-            verify_key(
-                &liquidity_user_wallet_mint,
-                principal_reserve_liquidity_mint.key,
-                "liquidity_wallet.mint",
-            )?;
-        }
-
-        let collateral_reserve_data = collateral_reserve.data.borrow();
-        let unpacked_collateral_reserve =
-            Reserve::try_from_bytes(collateral_reserve_data.as_ref())?;
-
-        // Check that repayment_source_wallet and destination_lp_wallet are external to the contract
-        // as it supposed to be.
-        if repayment_source_wallet.key == principal_reserve_liquidity_supply.key
-            || repayment_source_wallet.key == collateral_reserve_lp_supply.key
-        {
-            msg!("repayment_source_wallet should not be neither principal_reserve_liquidity_supply nor collateral_reserve_lp_supply");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-
-        if destination_lp_wallet.key == principal_reserve_liquidity_supply.key
-            || repayment_source_wallet.key == collateral_reserve_lp_supply.key
-        {
-            msg!("destination_lp_wallet should not be neither principal_reserve_liquidity_supply nor collateral_reserve_lp_supply");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    let mut collateral_reserve_data = collateral_reserve.data.borrow_mut();
+    let unpacked_collateral_reserve = Reserve::unpack_mut(collateral_reserve_data.as_mut())?;
 
-        let (expected_authority, authority_bump) = find_program_authority();
-        verify_key(
-            program_authority.key,
-            &expected_authority,
-            "program authority",
-        )?;
+    // Check that repayment_source_wallet and destination_lp_wallet are external to the contract
+    // as it supposed to be.
+    if repayment_source_wallet.key == principal_reserve_liquidity_supply.key
+        || repayment_source_wallet.key == collateral_reserve_lp_supply.key
+    {
+        msg!("repayment_source_wallet should not be neither principal_reserve_liquidity_supply nor collateral_reserve_lp_supply");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        // Check that position and both reserve belongs to the same pool
-        if position.pool != unpacked_principal_reserve.pool {
-            msg!("Position and principal reserve belongs to different pools");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
-        if position.pool != unpacked_collateral_reserve.pool {
-            msg!("Position and collateral reserve belongs to different pools");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    if destination_lp_wallet.key == principal_reserve_liquidity_supply.key
+        || repayment_source_wallet.key == collateral_reserve_lp_supply.key
+    {
+        msg!("destination_lp_wallet should not be neither principal_reserve_liquidity_supply nor collateral_reserve_lp_supply");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        //Reserves checks
-        let clock = Clock::get().expect("no clock");
-        if unpacked_principal_reserve.is_stale(&clock)? {
-            msg!("Principal Reserve is stale and must be refreshed");
-            return Err(SuperLendyError::StaleReserve);
-        }
+    let (expected_authority, authority_bump) = find_program_authority();
+    verify_key(
+        program_authority.key,
+        &expected_authority,
+        "program authority",
+    )?;
+
+    // Check that position and both reserve belongs to the same pool
+    if position.pool != unpacked_principal_reserve.pool {
+        msg!("Position and principal reserve belongs to different pools");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
+    if position.pool != unpacked_collateral_reserve.pool {
+        msg!("Position and collateral reserve belongs to different pools");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        if unpacked_collateral_reserve.is_stale(&clock)? {
-            msg!("Collateral Reserve is stale and must be refreshed");
-            return Err(SuperLendyError::StaleReserve);
-        }
+    //Reserves checks
+    let clock = Clock::get().expect("no clock");
+    if unpacked_principal_reserve.is_stale(&clock)? {
+        msg!("Principal Reserve is stale and must be refreshed");
+        return Err(SuperLendyError::StaleReserve);
+    }
 
-        let expected_liquidity_supply = find_liquidity_supply(principal_reserve.key);
-        verify_key(
-            principal_reserve_liquidity_supply.key,
-            &expected_liquidity_supply.0,
-            "principal_reserve_liquidity_supply",
-        )?;
+    if unpacked_collateral_reserve.is_stale(&clock)? {
+        msg!("Collateral Reserve is stale and must be refreshed");
+        return Err(SuperLendyError::StaleReserve);
+    }
 
-        let expected_lp_supply = find_collateral_supply(collateral_reserve.key);
-        verify_key(
-            collateral_reserve_lp_supply.key,
-            &expected_lp_supply.0,
-            "collateral_reserve_lp_supply",
-        )?;
+    let expected_liquidity_supply = find_liquidity_supply(principal_reserve.key);
+    verify_key(
+        principal_reserve_liquidity_supply.key,
+        &expected_liquidity_supply.0,
+        "principal_reserve_liquidity_supply",
+    )?;
+
+    let expected_lp_supply = find_collateral_supply(collateral_reserve.key);
+    verify_key(
+        collateral_reserve_lp_supply.key,
+        &expected_lp_supply.0,
+        "collateral_reserve_lp_supply",
+    )?;
+
+    if position.is_stale(&clock)? {
+        msg!("Position is stale and must be refreshed");
+        return Err(SuperLendyError::StalePosition);
+    }
 
-        if position.is_stale(&clock)? {
-            msg!("Position is stale and must be refreshed");
-            return Err(SuperLendyError::StalePosition);
-        }
+    if position.deposited_value()? == Decimal::ZERO {
+        msg!("Position has no deposited value");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        if position.deposited_value()? == Decimal::ZERO {
-            msg!("Position has no deposited value");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    if position.borrowed_value()? == Decimal::ZERO {
+        msg!("Position has no borrowed value");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        if position.borrowed_value()? == Decimal::ZERO {
-            msg!("Position has no borrowed value");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    // Fixed-rate loan which is not repaid after maturity and grace period can be liquidated
+    // regardless of Position health.
+    let overdue = position
+        .find_fixed_loan(*principal_reserve.key)
+        .map(|(loan, _)| {
+            loan.is_overdue(
+                clock.unix_timestamp,
+                unpacked_principal_reserve.fixed_grace_period_sec,
+            )
+        })
+        .unwrap_or(false);
 
-        if position.borrowed_value()? < position.partly_unhealthy_borrow_value()? {
-            msg!("Position is healthy and cannot be liquidated");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    if !overdue && position.weighted_borrowed_value()? < position.partly_unhealthy_borrow_value()? {
+        msg!("Position is healthy and cannot be liquidated");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        let (borrowed_liquidity, borrowed_liquidity_index) =
-            position.find_borrowed_liquidity(*principal_reserve.key)?;
+    let variable_borrow_exists = position
+        .find_borrowed_liquidity(*principal_reserve.key)
+        .map(|(borrowed_liquidity, _)| {
+            borrowed_liquidity
+                .borrowed_amount()
+                .unwrap_or(Decimal::ZERO)
+                != Decimal::ZERO
+        })
+        .unwrap_or(false);
+
+    // Unhealthy Position's fixed-rate loan is liquidated after variable rate borrow from the same
+    // Reserve.
+    let fixed_loan = if overdue || !variable_borrow_exists {
+        let (loan, loan_index) = position.find_fixed_loan(*principal_reserve.key)?;
+        Some((*loan, loan_index))
+    } else {
+        None
+    };
+
+    let (borrowed_liquidity, borrowed_liquidity_index) =
+        if let Some((loan, loan_index)) = &fixed_loan {
+            (&loan.liquidity, *loan_index)
+        } else {
+            position.find_borrowed_liquidity(*principal_reserve.key)?
+        };
 
-        if borrowed_liquidity.market_value()? == Decimal::ZERO {
-            msg!("Position's borrowed value is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    if borrowed_liquidity.market_value()? == Decimal::ZERO {
+        msg!("Position's borrowed value is zero");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        let (collateral, collateral_index) = position.find_collateral(*collateral_reserve.key)?;
+    let (collateral, collateral_index) = position.find_collateral(*collateral_reserve.key)?;
 
-        // When collateral market value is zero it means that Reserve have bad debt. It's up to Curator
-        // to decide when to write off bad debt via WriteOffBadDebt
-        if collateral.market_value()? == Decimal::ZERO {
-            msg!("Position's deposit value is zero");
-            return Err(SuperLendyError::OperationCanNotBePerformed);
-        }
+    // When collateral market value is zero it means that Reserve have bad debt. It's up to Curator
+    // to decide when to write off bad debt via WriteOffBadDebt
+    if collateral.market_value()? == Decimal::ZERO {
+        msg!("Position's deposit value is zero");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        let CalculateLiquidationResult {
-            settle_amount,
-            repay_amount,
-            withdraw_amount,
-        } = unpacked_collateral_reserve.calculate_liquidation(
+    let CalculateLiquidationResult {
+        settle_amount,
+        repay_amount,
+        withdraw_amount,
+        bonus_amount,
+    } = if let Some((loan, _)) = fixed_loan.filter(|_| overdue) {
+        unpacked_collateral_reserve.calculate_overdue_liquidation(
             liquidity_amount,
-            position,
+            &position,
+            &loan,
+            collateral,
+            unpacked_principal_reserve.liquidity.mint_decimals,
+        )?
+    } else {
+        unpacked_collateral_reserve.calculate_liquidation(
+            liquidity_amount,
+            &position,
             borrowed_liquidity,
             collateral,
             unpacked_principal_reserve.liquidity.mint_decimals,
-        )?;
+        )?
+    };
+
+    msg!(
+        "settle_amount {}  repay_amount {}  withdraw_amount {}",
+        settle_amount,
+        repay_amount,
+        withdraw_amount
+    );
+
+    if repay_amount == 0 {
+        msg!("Liquidation is too small to transfer liquidity");
+        return Err(SuperLendyError::LiquidationTooSmall);
+    }
+    if withdraw_amount == 0 {
+        msg!("Liquidation is too small to receive collateral");
+        return Err(SuperLendyError::LiquidationTooSmall);
+    }
 
-        msg!(
-            "settle_amount {}  repay_amount {}  withdraw_amount {}",
-            settle_amount,
+    // Curator and Texture take their shares of liquidation bonus. These LP tokens stay in
+    // collateral supply till claimed.
+    let texture_config_data = texture_config.data.borrow();
+    let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+    let (curator_liquidation_fee, texture_liquidation_fee) = unpacked_collateral_reserve
+        .config
+        .fees
+        .calculate_liquidation_fees(
+            bonus_amount,
+            unpacked_texture_config.liquidation_fee_share_bps,
+        )?;
+    unpacked_collateral_reserve
+        .accrue_liquidation_fees(curator_liquidation_fee, texture_liquidation_fee)?;
+
+    let liquidator_amount = withdraw_amount
+        .checked_sub(curator_liquidation_fee)
+        .ok_or(SuperLendyError::MathError(MathError(format!(
+            "liquidate(): checked_sub {} - {}",
+            withdraw_amount, curator_liquidation_fee
+        ))))?
+        .checked_sub(texture_liquidation_fee)
+        .ok_or(SuperLendyError::MathError(MathError(format!(
+            "liquidate(): checked_sub {} - {} - {}",
+            withdraw_amount, curator_liquidation_fee, texture_liquidation_fee
+        ))))?;
+
+    msg!(
+        "curator_liquidation_fee {}  texture_liquidation_fee {}  liquidator_amount {}",
+        curator_liquidation_fee,
+        texture_liquidation_fee,
+        liquidator_amount
+    );
+
+    if let Some((loan, loan_index)) = fixed_loan {
+        unpacked_principal_reserve.liquidity.repay_fixed(
             repay_amount,
-            withdraw_amount
-        );
-
-        if repay_amount == 0 {
-            msg!("Liquidation is too small to transfer liquidity");
-            return Err(SuperLendyError::LiquidationTooSmall);
-        }
-        if withdraw_amount == 0 {
-            msg!("Liquidation is too small to receive collateral");
-            return Err(SuperLendyError::LiquidationTooSmall);
-        }
-
+            settle_amount,
+            loan.interest_per_year(settle_amount)?,
+        )?;
+        position.repay_fixed_loan(settle_amount, loan_index)?;
+    } else {
         unpacked_principal_reserve
             .liquidity
             .repay(repay_amount, settle_amount)?;
-        unpacked_principal_reserve.mark_stale();
-
         position.repay(settle_amount, borrowed_liquidity_index)?;
-        position.withdraw(withdraw_amount, collateral_index)?;
-        position.mark_stale();
-
-        let principal_spl_token = SplToken::new(principal_token_program);
-
-        principal_spl_token
-            .transfer(
-                repayment_source_wallet,
-                Some(principal_reserve_liquidity_mint),
-                principal_reserve_liquidity_supply,
-                liquidator,
-                repay_amount,
-                Some(unpacked_principal_reserve.liquidity.mint_decimals),
-            )?
-            .call()?;
+    }
+    unpacked_principal_reserve.mark_stale();
 
-        let collateral_spl_token = SplToken::new(collateral_token_program);
-        collateral_spl_token
-            .transfer(
-                collateral_reserve_lp_supply,
-                None,
-                destination_lp_wallet,
-                program_authority,
-                withdraw_amount,
-                None,
-            )?
-            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+    position.withdraw(withdraw_amount, collateral_index)?;
+    position.mark_stale();
 
-        Ok(())
+    if position.isolated_collateral == *collateral_reserve.key {
+        unpacked_collateral_reserve.decrease_isolated_debt(
+            settle_amount.checked_mul(unpacked_principal_reserve.liquidity.market_price()?)?,
+        )?;
     }
 
-    #[inline(never)]
-    pub fn write_off_bad_debt(&self, amount: u64) -> LendyResult<()> {
-        msg!("write_off_bad_debt ix");
+    let principal_spl_token = SplToken::new(principal_token_program);
 
-        if amount == 0 {
-            msg!("Amount to write off cannot be zero");
-            return Err(InvalidAmount);
-        }
+    principal_spl_token
+        .transfer(
+            repayment_source_wallet,
+            Some(principal_reserve_liquidity_mint),
+            principal_reserve_liquidity_supply,
+            liquidator,
+            repay_amount,
+            Some(unpacked_principal_reserve.liquidity.mint_decimals),
+        )?
+        .call()?;
 
-        let WriteOffBadDebtAccounts {
-            pool,
-            reserve,
-            position,
-            curator_pools_authority,
-            curator,
-        } = WriteOffBadDebtAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+    Ok((liquidator_amount, authority_bump))
+}
 
-        let mut position_data = position.data.borrow_mut();
-        let unpacked_position = Position::try_from_bytes_mut(position_data.as_mut())?;
+/// Amount of LP tokens which can be unlocked from `position` when user asks for `amount`.
+/// u64::MAX - maximum amount allowed by Position's borrowing power. Returns the amount along with
+/// index of the collateral record.
+fn unlock_amount(
+    position: PositionRef,
+    unpacked_reserve: &Reserve,
+    reserve_key: Pubkey,
+    amount: u64,
+) -> LendyResult<(u64, usize)> {
+    let (collateral, collateral_index) = position.find_collateral(reserve_key)?;
+    if collateral.deposited_amount == 0 {
+        msg!("Collateral deposited amount is zero");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+    if position.deposited_value()? == Decimal::ZERO {
+        msg!("Position deposited value is zero");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
+    }
 
-        if unpacked_position.pool != unpacked_reserve.pool || unpacked_position.pool != *pool.key {
-            msg!("Position, reserve and provided pool do not match");
+    let withdraw_amount = if !position.have_any_borrowings() {
+        msg!(
+            "position doesn't have borrowings. deposited_amount {}",
+            collateral.deposited_amount
+        );
+        collateral.deposited_amount.min(amount)
+    } else {
+        // Determine how much collateral (in value terms) we can let go
+        let max_withdraw_value = position.max_withdraw_value(position.withdraw_collateral_ltv(
+            Decimal::from_basis_points(unpacked_reserve.config.max_borrow_ltv_bps as u32)?,
+        )?)?;
+
+        if max_withdraw_value == Decimal::ZERO {
+            msg!("Maximum withdraw value is zero");
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
 
-        verify_curator(pool, curator, curator_pools_authority)?;
+        let withdraw_amount = if amount == MAX_AMOUNT {
+            // user want's to unlock maximum it can. Calculate how much collateral we can unlock
+            // based on max allowed withdraw_value.
+            // Example: collateral is 1 SOL, market_price = 100$,
+            // max_withdraw_value = 90$ (because there is some borrowings).
+            // Then max collateral contract allow to unlock is 90/100 = 0.9 SOL
+            // Also need to take into account decimals.
+            let max_unlockable_collateral = max_withdraw_value
+                .checked_div(unpacked_reserve.liquidity.market_price()?)?
+                .to_lamports_floor(unpacked_reserve.liquidity.mint_decimals)?;
 
-        let clock = Clock::get().expect("no clock");
-        if unpacked_position.is_stale(&clock)? {
-            msg!("Position is stale and must be refreshed");
-            return Err(SuperLendyError::StalePosition);
-        }
+            msg!("full unlock. position's max_withdraw_value {}  max_unlockable_collateral {}  deposited_amount {}", max_withdraw_value, max_unlockable_collateral, collateral.deposited_amount);
 
-        if unpacked_reserve.is_stale(&clock)? {
-            msg!("Reserve is stale and must be refreshed");
-            return Err(SuperLendyError::StaleReserve);
-        }
+            max_unlockable_collateral.min(collateral.deposited_amount)
+        } else {
+            let withdraw_amount = amount.min(collateral.deposited_amount);
+            let withdraw_pct =
+                Decimal::from_i128_with_scale(withdraw_amount as i128, 0)?.checked_div(
+                    Decimal::from_i128_with_scale(collateral.deposited_amount as i128, 0)?,
+                )?; // Simple math here, thus from_i128_with_scale
+            let withdraw_value = collateral.market_value()?.checked_mul(withdraw_pct)?;
+            if withdraw_value > max_withdraw_value {
+                msg!(
+                    "Withdraw value {} cannot exceed maximum withdraw value {}",
+                    withdraw_value,
+                    max_withdraw_value
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+            msg!("exact unlock. withdraw_amount {}  withdraw_pct {}  withdraw_value {}  max_withdraw_value {}", withdraw_amount, withdraw_pct, withdraw_value, max_withdraw_value);
+            withdraw_amount
+        };
 
-        if unpacked_position.deposited_value()? != Decimal::ZERO {
-            msg!("Position has {} deposited value. Liquidate it first and then try to write off bad debt again.", unpacked_position.deposited_value()?);
+        if withdraw_amount == 0 {
+            msg!("Withdraw amount is too small to transfer");
             return Err(SuperLendyError::OperationCanNotBePerformed);
         }
+        withdraw_amount
+    };
 
-        unpacked_position
-            .find_borrowed_liquidity_mut(*reserve.key)?
-            .write_off_bad_debt(amount, unpacked_reserve.liquidity.mint_decimals)?;
-        unpacked_position.mark_stale();
+    Ok((withdraw_amount, collateral_index))
+}
 
-        unpacked_reserve.liquidity.write_off_bad_debt(amount)?;
-        unpacked_reserve.mark_stale();
+/// Position with isolated collateral must pass its isolated collateral Reserve as the last account
+/// of Borrow, Repay and WriteOffBadDebt. Returns that Reserve for isolated Position and None otherwise.
+fn next_isolated_collateral_reserve<'a, 'b, I>(
+    iter: &mut I,
+    position: &Position,
+    program_id: &Pubkey,
+) -> LendyResult<Option<&'a AccountInfo<'b>>>
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    if !position.is_isolated() {
+        return Ok(None);
+    }
 
-        Ok(())
+    let isolated_collateral_reserve = iter.next().ok_or(SuperLendyError::NotEnoughAccountKeys(
+        error::NotEnoughAccountKeys,
+    ))?;
+
+    verify_key(
+        isolated_collateral_reserve.key,
+        &position.isolated_collateral,
+        "isolated collateral reserve",
+    )?;
+    verify_key(
+        isolated_collateral_reserve.owner,
+        program_id,
+        "isolated collateral reserve owner",
+    )?;
+
+    if !isolated_collateral_reserve.is_writable {
+        msg!("Isolated collateral reserve must be writable");
+        return Err(SuperLendyError::OperationCanNotBePerformed);
     }
+
+    Ok(Some(isolated_collateral_reserve))
+}
+
+/// Takes Pool account passed after position reserves when position is opted in to e-mode and
+/// returns position's e-mode category. None when position is not in e-mode or category was
+/// disabled by the curator - in that case Reserve's LTVs are used.
+fn next_emode_category<'a, 'b, I>(
+    iter: &mut I,
+    position_info: &AccountInfo<'b>,
+    program_id: &Pubkey,
+) -> LendyResult<Option<EmodeCategory>>
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    let position_data = position_info.data.borrow();
+    let position = Position::unpack(&position_data)?;
+
+    if position.emode_category == 0 {
+        return Ok(None);
+    }
+
+    let pool_info = iter.next().ok_or(SuperLendyError::NotEnoughAccountKeys(
+        error::NotEnoughAccountKeys,
+    ))?;
+
+    verify_key(pool_info.key, &position.pool, "pool vs. position.pool")?;
+    verify_key(pool_info.owner, program_id, "pool owner")?;
+
+    let pool_data = pool_info.data.borrow();
+    let pool = Pool::try_from_bytes(&pool_data)?;
+
+    Ok(pool.emode_category(position.emode_category).copied())
 }
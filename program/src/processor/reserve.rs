@@ -23,7 +23,7 @@ use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, PodStateWithExtensions};
 use spl_token_2022::pod::PodMint;
 use spl_token_2022::state::Account;
-use texture_common::math::Decimal;
+use texture_common::math::{Decimal, MathError};
 use texture_common::remote::system::SystemProgram;
 use texture_common::remote::token::SplToken;
 use texture_common::utils::verify_key;
@@ -31,26 +31,29 @@ use texture_common::utils::verify_key;
 use crate::error::SuperLendyError;
 use crate::error::SuperLendyError::OperationCanNotBePerformed;
 use crate::instruction::{
-    AlterReserveAccounts, ApplyConfigProposalAccounts, ClaimCuratorPerformanceFeesAccounts,
+    AlterReserveAccounts, ApplyConfigProposalAccounts, ClaimCuratorLiquidationFeesAccounts,
+    ClaimCuratorPerformanceFeesAccounts, ClaimTextureLiquidationFeesAccounts,
     ClaimTexturePerformanceFeesAccounts, CreateReserveAccounts, DeleteReserveAccounts,
     DepositLiquidityAccounts, FlashBorrowAccounts, FlashRepayAccounts, LpTokenMetadata,
     ProposeConfigAccounts, RefreshReserveAccounts, SetLpMetadataAccounts, SuperLendyInstruction,
-    WithdrawLiquidityAccounts,
+    SyncReserveAccounts, WithdrawLiquidityAccounts,
 };
 use crate::pda::{
     find_collateral_supply, find_liquidity_supply, find_lp_token_mint, find_metadata,
     find_program_authority,
 };
 use crate::processor::{
-    mint_decimals, seedvec, spl_token_mint, verify_curator, verify_token_program, Processor,
-    SeedVec,
+    mint_decimals, seedvec, spl_token_amount, spl_token_mint, verify_curator, verify_token_program,
+    Processor, SeedVec,
 };
 use crate::state::curator::Curator;
 use crate::state::last_update::LastUpdate;
 use crate::state::pool::Pool;
 use crate::state::reserve::{
-    ConfigFields, ConfigProposal, Reserve, ReserveCollateral, ReserveConfig, ReserveLiquidity,
-    ReserveParams, MAX_CONFIG_PROPOSALS, RESERVE_MODE_NORMAL, RESERVE_MODE_RETAIN_LIQUIDITY,
+    ConfigFields, ConfigProposal, InterestRateModel, Reserve, ReserveCollateral, ReserveConfig,
+    ReserveLiquidity, ReserveParams, MAX_CONFIG_PROPOSALS, RESERVE_MODE_NORMAL,
+    RESERVE_MODE_RETAIN_LIQUIDITY, RESERVE_TYPE_ISOLATED_COLLATERAL, SURPLUS_TO_CURATOR,
+    SURPLUS_TO_LPS,
 };
 use crate::state::texture_cfg::TextureConfig;
 use crate::{pda, LendyResult, MAX_AMOUNT};
@@ -256,10 +259,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             mode: RESERVE_MODE_NORMAL,
             pool: *pool.key,
             liquidity,
-            collateral: ReserveCollateral {
-                lp_total_supply: 0,
-                _padding: 0,
-            },
+            collateral: ReserveCollateral::new(),
             config,
             flash_loans_enabled: 0, // Flash loans disabled by default
         };
@@ -274,11 +274,18 @@ impl<'a, 'b> Processor<'a, 'b> {
     }
 
     #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
     pub fn alter_reserve(
         &self,
         proposed_config: ReserveConfig,
         mode: u8,
         flash_loans_enabled: u8,
+        surplus_destination: u8,
+        isolation_borrowable: u8,
+        isolated_debt_ceiling: u64,
+        fixed_rate_bps: u32,
+        fixed_max_term_sec: u32,
+        fixed_grace_period_sec: u32,
     ) -> LendyResult<()> {
         msg!("alter_reserve ix: {:?}", proposed_config);
 
@@ -300,13 +307,33 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         let texture_config_data = texture_config.data.borrow();
         let unpacked_texture_config = TextureConfig::try_from_bytes(texture_config_data.as_ref())?;
 
         proposed_config.validate()?;
 
+        if surplus_destination != SURPLUS_TO_LPS && surplus_destination != SURPLUS_TO_CURATOR {
+            msg!("Unknown surplus destination {}", surplus_destination);
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if isolation_borrowable > 1
+            || (isolation_borrowable == 1
+                && unpacked_reserve.reserve_type == RESERVE_TYPE_ISOLATED_COLLATERAL)
+        {
+            msg!("isolation_borrowable must be 0 or 1 and can not be set for isolated collateral Reserve");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        verify_emode_category(pool, proposed_config.emode_category)?;
+
+        if fixed_rate_bps != 0 && fixed_max_term_sec == 0 {
+            msg!("fixed_max_term_sec can't be zero when fixed-rate borrowing is enabled");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
         if !unpacked_reserve
             .config
             .can_be_applied_now(&proposed_config, &unpacked_texture_config.reserve_timelock)
@@ -315,9 +342,15 @@ impl<'a, 'b> Processor<'a, 'b> {
             return Err(OperationCanNotBePerformed);
         }
 
-        unpacked_reserve.config = proposed_config;
+        unpacked_reserve.set_config(proposed_config)?;
         unpacked_reserve.mode = mode;
         unpacked_reserve.flash_loans_enabled = flash_loans_enabled;
+        unpacked_reserve.surplus_destination = surplus_destination;
+        unpacked_reserve.isolation_borrowable = isolation_borrowable;
+        unpacked_reserve.isolated_debt_ceiling = isolated_debt_ceiling;
+        unpacked_reserve.fixed_rate_bps = fixed_rate_bps;
+        unpacked_reserve.fixed_max_term_sec = fixed_max_term_sec;
+        unpacked_reserve.fixed_grace_period_sec = fixed_grace_period_sec;
 
         Ok(())
     }
@@ -336,7 +369,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         verify_curator(pool, curator, curator_pools_authority)?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
         verify_key(pool.key, &unpacked_reserve.pool, "pool vs. reserve.pool")?;
 
         if unpacked_reserve.liquidity.total_liquidity()? != Decimal::ZERO {
@@ -369,7 +402,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         } = RefreshReserveAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
             market_price_feed.key,
@@ -377,10 +410,16 @@ impl<'a, 'b> Processor<'a, 'b> {
             "market price feed",
         )?;
 
-        verify_key(irm.key, &unpacked_reserve.config.irm, "irm")?;
+        verify_key(irm.key, &unpacked_reserve.config.irm_account(), "irm")?;
 
         let irm_data = irm.data.borrow();
-        let unpacked_irm = Curve::try_from_bytes(irm_data.as_ref())?;
+        let unpacked_irm = match unpacked_reserve.config.interest_rate_model()? {
+            InterestRateModel::Curve(_) => {
+                verify_key(irm.owner, &curvy::ID, "irm owner")?;
+                Some(Curve::try_from_bytes(irm_data.as_ref())?)
+            }
+            InterestRateModel::Kinked(_) | InterestRateModel::Adaptive(_) => None,
+        };
 
         let price_feed_data = market_price_feed.data.borrow();
         let unpacked_price_feed = PriceFeed::try_from_bytes(&price_feed_data)?;
@@ -438,10 +477,11 @@ impl<'a, 'b> Processor<'a, 'b> {
             .set_market_price(unpacked_price_feed.try_price()?)?;
 
         unpacked_reserve.accrue_interest(
-            clock.slot,
+            clock.unix_timestamp,
             unpacked_texture_config.performance_fee_rate_bps,
             unpacked_irm,
         )?;
+        unpacked_reserve.take_rate_snapshot(clock.unix_timestamp)?;
         unpacked_reserve
             .last_update
             .update(clock.slot, clock.unix_timestamp);
@@ -514,7 +554,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
             liquidity_mint.key,
@@ -639,7 +679,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         if unpacked_reserve.mode == RESERVE_MODE_RETAIN_LIQUIDITY {
             msg!("reserve do not allow withdrawing");
@@ -783,7 +823,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
             liquidity_mint.key,
@@ -879,7 +919,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         let unpacked_cfg = TextureConfig::try_from_bytes(cfg_data.as_ref())?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         // Provided `fee_receiver` must be ATA from Texture's fees_authority
         let expected_fees_receiver = get_associated_token_address_with_program_id(
@@ -926,6 +966,200 @@ impl<'a, 'b> Processor<'a, 'b> {
         Ok(())
     }
 
+    #[inline(never)]
+    pub fn claim_curator_liquidation_fees(&self) -> LendyResult<()> {
+        msg!("claim_curator_liquidation_fees ix");
+
+        let ClaimCuratorLiquidationFeesAccounts {
+            reserve,
+            reserve_collateral_supply,
+            pool,
+            curator,
+            fee_receiver,
+            program_authority,
+            token_program,
+        } = ClaimCuratorLiquidationFeesAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
+
+        if fee_receiver.key == reserve_collateral_supply.key {
+            msg!("Fee receiver wallet can not be same as reserve's collateral supply");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (expected_collateral_supply, _collateral_supply_bump) =
+            find_collateral_supply(reserve.key);
+        verify_key(
+            reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "collateral supply",
+        )?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let pool_data = pool.data.borrow();
+        let unpacked_pool = Pool::try_from_bytes(&pool_data)?;
+
+        let curator_data = curator.data.borrow();
+        let unpacked_curator = Curator::try_from_bytes(&curator_data)?;
+
+        verify_key(
+            &unpacked_pool.curator,
+            curator.key,
+            "pool.curator vs. curator",
+        )?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        verify_key(pool.key, &unpacked_reserve.pool, "pool vs. reserve.pool")?;
+
+        // Curator's fee receiver should be the LP ATA from curator.fee_authority
+        let (lp_mint, _lp_mint_bump) = find_lp_token_mint(reserve.key);
+        let expected_curator_fee_receiver = get_associated_token_address_with_program_id(
+            &unpacked_curator.fees_authority,
+            &lp_mint,
+            token_program.key,
+        );
+
+        verify_key(
+            fee_receiver.key,
+            &expected_curator_fee_receiver,
+            "fee_receiver",
+        )?;
+
+        let fee_amount = unpacked_reserve.claim_curator_liquidation_fee();
+
+        if fee_amount == 0 {
+            msg!("fee amount is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let spl_token = SplToken::new(token_program);
+
+        spl_token
+            .transfer(
+                reserve_collateral_supply,
+                None,
+                fee_receiver,
+                program_authority,
+                fee_amount,
+                None,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn claim_texture_liquidation_fees(&self) -> LendyResult<()> {
+        msg!("claim_texture_liquidation_fees ix");
+
+        let ClaimTextureLiquidationFeesAccounts {
+            reserve,
+            reserve_collateral_supply,
+            fee_receiver,
+            texture_config,
+            program_authority,
+            token_program,
+        } = ClaimTextureLiquidationFeesAccounts::from_iter(
+            &mut self.accounts.iter(),
+            self.program_id,
+        )?;
+
+        if fee_receiver.key == reserve_collateral_supply.key {
+            msg!("Fee receiver wallet can not be same as reserve's collateral supply");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let (expected_collateral_supply, _collateral_supply_bump) =
+            find_collateral_supply(reserve.key);
+        verify_key(
+            reserve_collateral_supply.key,
+            &expected_collateral_supply,
+            "collateral supply",
+        )?;
+
+        let (expected_authority, authority_bump) = find_program_authority();
+        verify_key(
+            program_authority.key,
+            &expected_authority,
+            "program authority",
+        )?;
+
+        let cfg_data = texture_config.data.borrow();
+        let unpacked_cfg = TextureConfig::try_from_bytes(cfg_data.as_ref())?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        // Provided `fee_receiver` must be LP ATA from Texture's fees_authority
+        let (lp_mint, _lp_mint_bump) = find_lp_token_mint(reserve.key);
+        let expected_fees_receiver = get_associated_token_address_with_program_id(
+            &unpacked_cfg.fees_authority,
+            &lp_mint,
+            token_program.key,
+        );
+
+        verify_key(fee_receiver.key, &expected_fees_receiver, "fee_receiver")?;
+
+        let fee_amount = unpacked_reserve.claim_texture_liquidation_fee();
+
+        if fee_amount == 0 {
+            msg!("fee amount is zero");
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let spl_token = SplToken::new(token_program);
+
+        spl_token
+            .transfer(
+                reserve_collateral_supply,
+                None,
+                fee_receiver,
+                program_authority,
+                fee_amount,
+                None,
+            )?
+            .signed(&[&[pda::AUTHORITY_SEED, &[authority_bump]]])?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn sync_reserve(&self) -> LendyResult<()> {
+        msg!("sync_reserve ix");
+
+        let SyncReserveAccounts {
+            reserve,
+            reserve_liquidity_supply,
+        } = SyncReserveAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+
+        let supply_amount = spl_token_amount(reserve_liquidity_supply)?;
+
+        let mut reserve_data = reserve.data.borrow_mut();
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
+
+        let surplus_amount = unpacked_reserve.sync_supply(supply_amount)?;
+
+        unpacked_reserve.mark_stale();
+
+        msg!(
+            "supply balance {}, surplus {} accounted with destination {}",
+            supply_amount,
+            surplus_amount,
+            unpacked_reserve.surplus_destination
+        );
+
+        Ok(())
+    }
+
     #[inline(never)]
     pub fn flash_borrow(&self, amount: u64) -> LendyResult<()> {
         msg!("flash_borrow ix");
@@ -974,7 +1208,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
             liquidity_mint.key,
@@ -1136,6 +1370,7 @@ impl<'a, 'b> Processor<'a, 'b> {
             liquidity_supply,
             liquidity_mint,
             user_transfer_authority,
+            texture_config,
             sysvar_instructions,
             token_program,
         } = FlashRepayAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
@@ -1167,7 +1402,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         verify_key(
             liquidity_mint.key,
@@ -1287,9 +1522,35 @@ impl<'a, 'b> Processor<'a, 'b> {
             Decimal::from_lamports(amount, unpacked_reserve.liquidity.mint_decimals)?,
         )?;
 
+        let texture_config_data = texture_config.data.borrow();
+        let unpacked_texture_config = TextureConfig::try_from_bytes(&texture_config_data)?;
+
+        let fee_amount = unpacked_reserve
+            .config
+            .fees
+            .calculate_flash_loan_fee(amount, unpacked_reserve.liquidity.mint_decimals)?;
+
+        unpacked_reserve.liquidity.accrue_flash_loan_fee(
+            fee_amount,
+            unpacked_reserve
+                .config
+                .fees
+                .curator_performance_fee_rate_bps,
+            unpacked_texture_config.flash_loan_fee_share_bps,
+        )?;
+
+        let repay_amount = amount
+            .checked_add(fee_amount)
+            .ok_or(SuperLendyError::MathError(MathError(format!(
+                "flash_repay(): checked_add {} + {}",
+                amount, fee_amount
+            ))))?;
+
+        msg!("flash repay amount {} fee {}", amount, fee_amount);
+
         // We do not mark Reserve as stale after FlashRepay because Flash operation as a whole do not
-        // change anything in the Reserve. This is virtual operation. No money came and no gone. Even
-        // Solana slot wasn't changed.
+        // change borrowed amount in the Reserve. The only change is the fee added to available
+        // liquidity which doesn't require any recalculations. Even Solana slot wasn't changed.
 
         let spl_token = SplToken::new(token_program);
 
@@ -1299,7 +1560,7 @@ impl<'a, 'b> Processor<'a, 'b> {
                 Some(liquidity_mint),
                 liquidity_supply,
                 user_transfer_authority,
-                amount,
+                repay_amount,
                 Some(unpacked_reserve.liquidity.mint_decimals),
             )?
             .call()?;
@@ -1329,7 +1590,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         let texture_config_data = texture_config.data.borrow();
         let unpacked_texture_config = TextureConfig::try_from_bytes(texture_config_data.as_ref())?;
@@ -1353,6 +1614,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         simulated_config.apply_proposal(proposal)?;
 
         simulated_config.validate()?;
+        verify_emode_category(pool, simulated_config.emode_category)?;
 
         if unpacked_reserve
             .config
@@ -1405,7 +1667,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
         let proposal = unpacked_reserve.proposed_configs.0[index as usize];
 
         // Ensure that market_price_feed checked above same as in proposed config - but only when we are
@@ -1446,8 +1708,9 @@ impl<'a, 'b> Processor<'a, 'b> {
         let mut config = unpacked_reserve.config;
         config.apply_proposal(proposal)?;
         config.validate()?;
+        verify_emode_category(pool, config.emode_category)?;
 
-        unpacked_reserve.config = config;
+        unpacked_reserve.set_config(config)?;
 
         // Proposal is cleared to prevent subsequent applications. Can be applied only once.
         unpacked_reserve.proposed_configs.0[index as usize] = ConfigProposal::zeroed();
@@ -1464,7 +1727,7 @@ impl<'a, 'b> Processor<'a, 'b> {
         curator_pools_authority: &AccountInfo<'b>,
     ) -> LendyResult<()> {
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         self.check_price_feed(market_price_feed, pool)?;
 
@@ -1493,7 +1756,8 @@ impl<'a, 'b> Processor<'a, 'b> {
         } = SetLpMetadataAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
 
         let reserve_data = reserve.data.borrow();
-        let unpacked_reserve = Reserve::try_from_bytes(reserve_data.as_ref())?;
+        let mut migrated_reserve = None;
+        let unpacked_reserve = Reserve::unpack(reserve_data.as_ref(), &mut migrated_reserve)?;
 
         verify_curator(pool, curator, curator_pools_authority)?;
 
@@ -1548,6 +1812,25 @@ impl<'a, 'b> Processor<'a, 'b> {
     }
 }
 
+/// Checks that Reserve can be put in to e-mode category `category_id` of the `pool`.
+fn verify_emode_category(pool: &AccountInfo, category_id: u8) -> LendyResult<()> {
+    if category_id == 0 {
+        return Ok(());
+    }
+
+    let pool_data = pool.data.borrow();
+    let unpacked_pool = Pool::try_from_bytes(&pool_data)?;
+    if unpacked_pool.emode_category(category_id).is_none() {
+        msg!(
+            "e-mode category {} is not configured in the Pool",
+            category_id
+        );
+        return Err(SuperLendyError::InvalidConfig);
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn create_metadata<'b>(
     metadata_program: &AccountInfo<'b>,
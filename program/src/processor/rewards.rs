@@ -151,7 +151,7 @@ impl<'a, 'b> crate::processor::Processor<'a, 'b> {
         )?;
 
         let mut reserve_data = reserve.data.borrow_mut();
-        let unpacked_reserve = Reserve::try_from_bytes_mut(reserve_data.as_mut())?;
+        let unpacked_reserve = Reserve::unpack_mut(reserve_data.as_mut())?;
 
         crate::processor::verify_curator(pool, curator, curator_pools_authority)?;
 
@@ -228,7 +228,7 @@ impl<'a, 'b> crate::processor::Processor<'a, 'b> {
         )?;
 
         let mut position_data = position.data.borrow_mut();
-        let position = Position::try_from_bytes_mut(position_data.as_mut())?;
+        let mut position = Position::unpack_mut(position_data.as_mut())?;
 
         verify_key(&position.pool, pool.key, "position.pool vs. pool")?;
 
@@ -251,7 +251,8 @@ impl<'a, 'b> crate::processor::Processor<'a, 'b> {
             return Err(SuperLendyError::StalePosition);
         }
 
-        let reward_record = position.rewards.find_reward(reward_mint.key);
+        let mut rewards = position.rewards_mut();
+        let reward_record = rewards.find_reward(reward_mint.key);
 
         if reward_record.is_none() {
             msg!("No rewards found for mint {}", reward_mint.key);
@@ -1,6 +1,6 @@
 //! State types
 
-use solana_program::clock::{DEFAULT_TICKS_PER_SECOND, DEFAULT_TICKS_PER_SLOT, SECONDS_PER_DAY};
+use solana_program::clock::SECONDS_PER_DAY;
 
 pub mod curator;
 pub mod last_update;
@@ -9,8 +9,9 @@ pub mod position;
 pub mod reserve;
 pub mod texture_cfg;
 
-pub const SLOTS_PER_YEAR: u64 =
-    DEFAULT_TICKS_PER_SECOND / DEFAULT_TICKS_PER_SLOT * SECONDS_PER_DAY * 365;
+/// Interest is compounded per second of Solana time. Slot duration drifts so it can not be used
+/// to measure time.
+pub const SECONDS_PER_YEAR: u64 = SECONDS_PER_DAY * 365;
 
 /// Scale of precision
 pub const SCALE: u32 = 18;
@@ -22,6 +23,14 @@ pub const HALF_WAD: u64 = 500_000_000_000_000_000;
 pub const PERCENT_SCALER: u64 = 10_000_000_000_000_000;
 pub const INITIAL_COLLATERAL_RATIO: u64 = 1;
 const INITIAL_COLLATERAL_RATE: u64 = INITIAL_COLLATERAL_RATIO * WAD;
+/// Virtual liquidity (lamports) every Reserve is considered to hold on top of the real one when LP
+/// exchange rate is calculated (see [`reserve::ReserveCollateral::virtual_offsets`]). Together with [`VIRTUAL_LP_AMOUNT`] it keeps the rate from being
+/// skewed while LP supply is (close to) zero: to make next depositor lose anything on rounding
+/// the first depositor must donate (via interest or flash loan fees) `VIRTUAL_LIQUIDITY_AMOUNT`
+/// times more and most of that donation goes to virtual LPs.
+pub const VIRTUAL_LIQUIDITY_AMOUNT: u64 = 1_000;
+/// Virtual LP tokens (lamports) matching [`VIRTUAL_LIQUIDITY_AMOUNT`] at initial exchange rate.
+pub const VIRTUAL_LP_AMOUNT: u64 = VIRTUAL_LIQUIDITY_AMOUNT * INITIAL_COLLATERAL_RATIO;
 
 pub const TEXTURE_CONFIG_DISCRIMINATOR: &[u8; 8] = b"TXT__CFG";
 
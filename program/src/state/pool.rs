@@ -1,14 +1,26 @@
+use crate::error::SuperLendyError;
+use crate::state::texture_cfg::ReserveTimelock;
 use crate::state::POOL_DISCRIMINATOR;
+use crate::LendyResult;
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
+use solana_program::clock::UnixTimestamp;
+use solana_program::msg;
 use solana_program::pubkey::Pubkey;
 use texture_common::account::{PodAccount, PodAccountError};
 
 pub const POOL_NAME_MAX_LEN: usize = 128;
 pub const CURRENCY_SYMBOL_MAX_LEN: usize = 16;
+pub const EMODE_CATEGORY_NAME_MAX_LEN: usize = 16;
+
+/// Max number of e-mode categories in the Pool. Category IDs are 1..=MAX_EMODE_CATEGORIES.
+/// ID 0 means "no category".
+pub const MAX_EMODE_CATEGORIES: usize = 8;
 
 static_assertions::const_assert_eq!(Pool::SIZE, std::mem::size_of::<Pool>());
 static_assertions::const_assert_eq!(0, std::mem::size_of::<Pool>() % 8);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<EmodeCategory>() % 8);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<EmodeCategoryProposal>() % 8);
 
 /// This is multi-currency lend\borrow pool. This is grouping entity for Reserves.
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -22,8 +34,12 @@ pub struct Pool {
     /// When Pool is not visible - it is not shown in UI.
     pub visible: u8,
 
+    /// When set to 1 anyone can write off bad debt of Positions which lost all their collateral
+    /// via SocializeLoss IX. When 0 - only Curator can do that via WriteOffBadDebt IX.
+    pub auto_write_off_bad_debt: u8,
+
     /// Vacant to store mode/status flags
-    pub _flags: [u8; 6],
+    pub _flags: [u8; 5],
 
     /// Address of Curator account this pool belongs to. `pools_authority` from the Curator account has rights
     /// to add and configure new Reserves in the Pool.
@@ -36,7 +52,131 @@ pub struct Pool {
     /// to express market prices and values.
     pub market_price_currency_symbol: [u8; CURRENCY_SYMBOL_MAX_LEN],
 
-    pub _padding: [u8; 30 * 8],
+    /// Efficiency mode categories. Category with ID N is stored at index N-1.
+    pub emode_categories: [EmodeCategory; MAX_EMODE_CATEGORIES],
+
+    /// Pending change of one of e-mode categories. Applied via ApplyEmodeCategoryProposal IX when
+    /// time lock expires.
+    pub emode_category_proposal: EmodeCategoryProposal,
+
+    pub _padding: [u8; 8],
+}
+
+/// Efficiency mode (e-mode) category groups correlated assets (e.g. SOL and SOL LSTs). Position
+/// opted in to the category gets category's LTVs instead of Reserve's ones when all its deposits
+/// and borrows are from Reserves of that category.
+/// Category with zero `max_borrow_ltv_bps` is not configured.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[repr(C)]
+pub struct EmodeCategory {
+    /// Human-readable category name e.g. "SOL-correlated"
+    pub name: [u8; EMODE_CATEGORY_NAME_MAX_LEN],
+    /// LTV till which Position can borrow. Replaces Reserve's `max_borrow_ltv_bps`.
+    pub max_borrow_ltv_bps: u16,
+    /// LTV at which partial liquidation starts. Replaces Reserve's `partly_unhealthy_ltv_bps`.
+    pub partly_unhealthy_ltv_bps: u16,
+    /// LTV at which full liquidation is allowed. Replaces Reserve's `fully_unhealthy_ltv_bps`.
+    pub fully_unhealthy_ltv_bps: u16,
+    pub _padding: [u8; 2],
+}
+
+impl EmodeCategory {
+    pub fn is_configured(&self) -> bool {
+        self.max_borrow_ltv_bps != 0
+    }
+
+    /// Validate category LTVs. Zeroed category (i.e. disabled one) is valid.
+    pub fn validate(&self) -> LendyResult<()> {
+        if *self == Self::zeroed() {
+            return Ok(());
+        }
+
+        if self.partly_unhealthy_ltv_bps < 1_000 || self.partly_unhealthy_ltv_bps > 10_000 {
+            msg!("partly_unhealthy_ltv must be in range [10, 100] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.fully_unhealthy_ltv_bps <= self.partly_unhealthy_ltv_bps
+            || self.fully_unhealthy_ltv_bps > 10_000
+        {
+            msg!("fully_unhealthy_ltv must be in range (partly_unhealthy_ltv, 100]");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.max_borrow_ltv_bps < 500 || self.max_borrow_ltv_bps >= self.partly_unhealthy_ltv_bps
+        {
+            msg!(
+                "max_borrow_ltv_bps must be in range [5, partly_unhealthy_ltv) % i.e. [5, {}) %",
+                self.partly_unhealthy_ltv_bps / 100
+            );
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `proposed` category can replace this one without time lock i.e. it disables
+    /// the category, doesn't raise LTVs or raises only those LTVs which changes are not time locked.
+    pub fn can_be_applied_now(
+        &self,
+        proposed: &EmodeCategory,
+        reserve_timelock: &ReserveTimelock,
+    ) -> bool {
+        if *proposed == Self::zeroed() {
+            return true;
+        }
+
+        if proposed.max_borrow_ltv_bps > self.max_borrow_ltv_bps
+            && reserve_timelock.max_borrow_ltv_lock_sec != 0
+        {
+            return false;
+        }
+
+        if (proposed.partly_unhealthy_ltv_bps > self.partly_unhealthy_ltv_bps
+            || proposed.fully_unhealthy_ltv_bps > self.fully_unhealthy_ltv_bps)
+            && reserve_timelock.unhealthy_ltv_lock_sec != 0
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns time lock for replacing this category with `proposed` one - max lock among
+    /// changed LTVs. The same locks as for Reserve's LTVs are used.
+    pub fn max_time_lock(
+        &self,
+        proposed: &EmodeCategory,
+        reserve_timelock: &ReserveTimelock,
+    ) -> UnixTimestamp {
+        let mut max_time_lock: u32 = 0;
+
+        if proposed.max_borrow_ltv_bps != self.max_borrow_ltv_bps {
+            max_time_lock = max_time_lock.max(reserve_timelock.max_borrow_ltv_lock_sec);
+        }
+
+        if proposed.partly_unhealthy_ltv_bps != self.partly_unhealthy_ltv_bps
+            || proposed.fully_unhealthy_ltv_bps != self.fully_unhealthy_ltv_bps
+        {
+            max_time_lock = max_time_lock.max(reserve_timelock.unhealthy_ltv_lock_sec);
+        }
+
+        max_time_lock as UnixTimestamp
+    }
+}
+
+/// Proposed change of e-mode category. Zeroed proposal is unused. Only one proposal per Pool can be
+/// pending. New proposal replaces the previous one.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[repr(C)]
+pub struct EmodeCategoryProposal {
+    /// Solana time this proposed change can be applied to the Pool.
+    pub can_be_applied_at: UnixTimestamp,
+    /// ID of the category to change. 0 indicates unused proposal.
+    pub category_id: u8,
+    pub _padding: [u8; 7],
+    /// New category value
+    pub category: EmodeCategory,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
@@ -48,6 +188,19 @@ pub struct PoolParams {
     pub market_price_currency_symbol: [u8; CURRENCY_SYMBOL_MAX_LEN],
 
     pub visible: u8, // 0 or 1
+
+    pub auto_write_off_bad_debt: u8, // 0 or 1
+}
+
+impl PoolParams {
+    pub fn validate(&self) -> LendyResult<()> {
+        if self.auto_write_off_bad_debt > 1 {
+            msg!("auto_write_off_bad_debt must be 0 or 1");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        Ok(())
+    }
 }
 
 impl PodAccount for Pool {
@@ -77,10 +230,13 @@ impl PodAccount for Pool {
             discriminator,
             version,
             visible,
+            auto_write_off_bad_debt,
             _flags,
             curator,
             name,
             market_price_currency_symbol,
+            emode_categories,
+            emode_category_proposal,
             _padding,
         } = self;
 
@@ -90,9 +246,32 @@ impl PodAccount for Pool {
         *market_price_currency_symbol = params.market_price_currency_symbol;
         *curator = curator_key;
         *visible = 0;
+        *auto_write_off_bad_debt = params.auto_write_off_bad_debt;
+        *emode_categories = Zeroable::zeroed();
+        *emode_category_proposal = Zeroable::zeroed();
         *_padding = Zeroable::zeroed();
         *_flags = Zeroable::zeroed();
 
         Ok(())
     }
 }
+
+impl Pool {
+    pub fn auto_write_off_bad_debt_enabled(&self) -> bool {
+        self.auto_write_off_bad_debt == 1
+    }
+
+    /// Returns configured e-mode category by its ID. None for ID 0 and not configured categories.
+    pub fn emode_category(&self, category_id: u8) -> Option<&EmodeCategory> {
+        if category_id == 0 || category_id as usize > MAX_EMODE_CATEGORIES {
+            return None;
+        }
+
+        let category = &self.emode_categories[category_id as usize - 1];
+        if category.is_configured() {
+            Some(category)
+        } else {
+            None
+        }
+    }
+}
@@ -1,28 +1,56 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::ops::{Deref, DerefMut};
 
 use bytemuck::{Pod, Zeroable};
-use solana_program::clock::{Clock, Slot};
+use solana_program::clock::{Clock, Slot, UnixTimestamp};
 use solana_program::{msg, pubkey::Pubkey};
 use texture_common::account::{PodAccount, PodAccountError};
 use texture_common::math::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal, MathError, MathResult,
 };
+use texture_common::utils::verify_key;
 
-use crate::error::SuperLendyError;
+use crate::error::{SerializeError, SuperLendyError};
 use crate::state::last_update::LastUpdate;
 use crate::state::reserve::{RewardRule, RewardRules};
-use crate::state::POSITION_DISCRIMINATOR;
+use crate::state::{POSITION_DISCRIMINATOR, SECONDS_PER_YEAR};
 use crate::{LendyResult, MAX_AMOUNT};
 
 static_assertions::const_assert_eq!(Position::SIZE, std::mem::size_of::<Position>());
 static_assertions::const_assert_eq!(0, std::mem::size_of::<Position>() % 8);
+// Extension records follow the Position right away, thus keep them aligned as the Position itself.
+static_assertions::const_assert_eq!(0, std::mem::size_of::<Position>() % 16);
 static_assertions::const_assert_eq!(0, std::mem::size_of::<DepositedCollateral>() % 16);
 static_assertions::const_assert_eq!(0, std::mem::size_of::<BorrowedLiquidity>() % 16);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<Reward>() % 16);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<FixedLoan>() % 16);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<PositionDelegate>() % 8);
 
 pub const MAX_DEPOSITS: usize = 10;
 pub const MAX_BORROWS: usize = 10;
 pub const MAX_REWARDS: usize = 10;
+pub const MAX_DELEGATES: usize = 4;
+
+/// Delegate can repay Position's debt with its collateral (RepayWithCollateral). As that burns
+/// collateral DELEGATE_PERMISSION_UNLOCK is required too. Plain Repay is permissionless thus
+/// doesn't need that.
+pub const DELEGATE_PERMISSION_REPAY: u8 = 1 << 0;
+/// Delegate can lock collateral (from its own wallets) to the Position.
+pub const DELEGATE_PERMISSION_LOCK: u8 = 1 << 1;
+/// Delegate can borrow against the Position to owner's wallets within its borrow allowance.
+pub const DELEGATE_PERMISSION_BORROW: u8 = 1 << 2;
+/// Delegate can unlock collateral to wallets owned by Position owner only.
+pub const DELEGATE_PERMISSION_UNLOCK: u8 = 1 << 3;
+pub const DELEGATE_PERMISSIONS_ALL: u8 = DELEGATE_PERMISSION_REPAY
+    | DELEGATE_PERMISSION_LOCK
+    | DELEGATE_PERMISSION_BORROW
+    | DELEGATE_PERMISSION_UNLOCK;
+
+/// Delegate's borrow allowance is measured in cents of quote currency
+pub const DELEGATE_BORROW_ALLOWANCE_DECIMALS: u8 = 2;
+/// Borrow allowance is stored in 7 bytes. See PositionDelegate.
+pub const MAX_DELEGATE_BORROW_ALLOWANCE: u64 = (1 << 56) - 1;
 
 /// Classic Borrow/Lend position
 pub const POSITION_TYPE_CLASSIC: u8 = 0;
@@ -42,8 +70,13 @@ pub struct Position {
     /// positions are processed same way. This field makes difference in UI interpretation.
     pub position_type: u8,
 
+    /// E-mode category (see Pool.emode_categories) position opted in to. 0 - e-mode is off.
+    /// Category LTVs are applied only when all position's deposits and borrows are from Reserves
+    /// of that category.
+    pub emode_category: u8,
+
     /// Vacant to store mode/status flags
-    pub _flags: [u8; 6],
+    pub _flags: [u8; 5],
 
     /// Last update to collateral, liquidity, or their market values
     pub last_update: LastUpdate,
@@ -69,8 +102,32 @@ pub struct Position {
     pub partly_unhealthy_borrow_value: i128,
     /// Very dangerous borrow value at which position can be liquidated at once.
     pub fully_unhealthy_borrow_value: i128,
-
-    pub _padding: [u8; 256],
+    /// Market value of borrows multiplied by borrow weights of their Reserves. This value (not
+    /// `borrowed_value`) is compared against allowed and unhealthy borrow values.
+    pub weighted_borrowed_value: i128,
+
+    /// Reserve of RESERVE_TYPE_ISOLATED_COLLATERAL type which collateral this position holds.
+    /// Such position can not hold any other collateral. Zero key - position is not isolated.
+    pub isolated_collateral: Pubkey,
+
+    /// Number of collateral records stored in the extension in addition to `collateral`.
+    pub extra_deposits: u8,
+    /// Number of borrow records stored in the extension in addition to `borrows`.
+    pub extra_borrows: u8,
+    /// Number of reward records stored in the extension in addition to `rewards`.
+    pub extra_rewards: u8,
+    /// Number of fixed-rate loan records stored in the extension. Position itself has no room for
+    /// them thus fixed-rate borrowing requires ResizePosition first.
+    pub extra_fixed_loans: u8,
+
+    pub _padding: [u8; 12],
+
+    /// New owner proposed by TransferPositionOwnership. Becomes the owner once accepts ownership.
+    /// Zero key - no transfer in progress.
+    pub pending_owner: Pubkey,
+
+    /// Operators authorized by the owner to manage the Position. See DELEGATE_PERMISSION_*.
+    pub delegates: [PositionDelegate; MAX_DELEGATES],
 }
 
 impl PodAccount for Position {
@@ -97,6 +154,7 @@ impl PodAccount for Position {
             discriminator,
             version,
             position_type,
+            emode_category,
             _flags,
             last_update,
             pool,
@@ -109,12 +167,21 @@ impl PodAccount for Position {
             allowed_borrow_value,
             partly_unhealthy_borrow_value,
             fully_unhealthy_borrow_value,
+            weighted_borrowed_value,
+            isolated_collateral,
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
             _padding,
+            pending_owner,
+            delegates,
         } = self;
 
         *discriminator = *POSITION_DISCRIMINATOR;
         *version = Self::VERSION;
         *position_type = params.position_type;
+        *emode_category = 0;
         *last_update = LastUpdate::new(0, 0);
         *owner = params.owner;
         *pool = params.pool;
@@ -125,8 +192,16 @@ impl PodAccount for Position {
         *allowed_borrow_value = Decimal::ZERO.into_bits().unwrap();
         *partly_unhealthy_borrow_value = Decimal::ZERO.into_bits().unwrap();
         *fully_unhealthy_borrow_value = Decimal::ZERO.into_bits().unwrap();
+        *weighted_borrowed_value = Decimal::ZERO.into_bits().unwrap();
         *rewards = Zeroable::zeroed();
+        *isolated_collateral = Pubkey::default();
+        *extra_deposits = 0;
+        *extra_borrows = 0;
+        *extra_rewards = 0;
+        *extra_fixed_loans = 0;
         *_padding = Zeroable::zeroed();
+        *pending_owner = Pubkey::default();
+        *delegates = Zeroable::zeroed();
         *_flags = Zeroable::zeroed();
 
         Ok(())
@@ -145,6 +220,7 @@ impl Position {
             discriminator: *POSITION_DISCRIMINATOR,
             version: 0,
             position_type: POSITION_TYPE_CLASSIC,
+            emode_category: 0,
             _flags: Zeroable::zeroed(),
             last_update: LastUpdate {
                 slot: 0,
@@ -162,7 +238,15 @@ impl Position {
             allowed_borrow_value: Decimal::ZERO.into_bits().unwrap(),
             partly_unhealthy_borrow_value: Decimal::ZERO.into_bits().unwrap(),
             fully_unhealthy_borrow_value: Decimal::ZERO.into_bits().unwrap(),
+            weighted_borrowed_value: Decimal::ZERO.into_bits().unwrap(),
+            isolated_collateral: Pubkey::default(),
+            extra_deposits: 0,
+            extra_borrows: 0,
+            extra_rewards: 0,
+            extra_fixed_loans: 0,
             _padding: Zeroable::zeroed(),
+            pending_owner: Pubkey::default(),
+            delegates: Zeroable::zeroed(),
         }
     }
 
@@ -212,63 +296,72 @@ impl Position {
         Ok(())
     }
 
+    pub fn weighted_borrowed_value(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.weighted_borrowed_value).map_err(From::from)
+    }
+
+    pub fn set_weighted_borrowed_value(&mut self, value: Decimal) -> LendyResult<()> {
+        self.weighted_borrowed_value = value.into_bits()?;
+        Ok(())
+    }
+
     /// Calculate the current ratio of borrowed value to deposited value
     pub fn ltv(&self) -> LendyResult<Decimal> {
         Ok(ltv(self.borrowed_value()?, self.deposited_value()?)?)
     }
 
-    /// Repay liquidity
-    pub fn repay(&mut self, settle_amount: Decimal, borrowing_index: usize) -> LendyResult<()> {
-        if borrowing_index >= MAX_BORROWS {
-            msg!(
-                "liquidity_index {} beyond limit {}",
-                borrowing_index,
-                MAX_BORROWS
-            );
-            return Err(SuperLendyError::Internal(String::from(
-                "liquidity_index to big during repay",
-            )));
+    /// Calculate the current ratio of risk-adjusted (weighted) borrowed value to deposited value
+    pub fn weighted_ltv(&self) -> LendyResult<Decimal> {
+        Ok(ltv(
+            self.weighted_borrowed_value()?,
+            self.deposited_value()?,
+        )?)
+    }
+
+    /// Calculate the maximum collateral value that can be withdrawn
+    pub fn max_withdraw_value(&self, withdraw_collateral_ltv: Decimal) -> LendyResult<Decimal> {
+        Ok(max_withdraw_value(
+            self.allowed_borrow_value()?,
+            self.weighted_borrowed_value()?,
+            withdraw_collateral_ltv,
+        )?)
+    }
+
+    /// LTV to be used in `max_withdraw_value` for collateral with `reserve_ltv`. Position in e-mode
+    /// may have its allowed borrow value calculated with category LTV which is higher than
+    /// Reserve's one. Thus the biggest of the two is used to stay on the safe side.
+    pub fn withdraw_collateral_ltv(&self, reserve_ltv: Decimal) -> LendyResult<Decimal> {
+        if self.emode_category == 0 || self.deposited_value()? == Decimal::ZERO {
+            return Ok(reserve_ltv);
         }
-        let borrowed_liquidity = &mut self.borrows[borrowing_index];
 
-        borrowed_liquidity.repay(settle_amount)?;
+        let position_ltv = self
+            .allowed_borrow_value()?
+            .checked_div(self.deposited_value()?)?;
 
-        Ok(())
+        Ok(reserve_ltv.max(position_ltv))
     }
 
-    /// Withdraw collateral
-    pub fn withdraw(&mut self, withdraw_amount: u64, collateral_index: usize) -> LendyResult<()> {
-        if collateral_index >= MAX_DEPOSITS {
+    /// Position in e-mode can lock collateral and borrow only in Reserves of its e-mode category.
+    /// Otherwise category LTVs would silently stop applying to the whole Position.
+    pub fn verify_emode_reserve(&self, reserve_emode_category: u8) -> LendyResult<()> {
+        if self.emode_category != 0 && self.emode_category != reserve_emode_category {
             msg!(
-                "collateral_index {} beyond limit {}",
-                collateral_index,
-                MAX_DEPOSITS
+                "Position is in e-mode category {} while Reserve is in category {}",
+                self.emode_category,
+                reserve_emode_category
             );
-            return Err(SuperLendyError::Internal(String::from(
-                "liquidity_index to big during repay",
-            )));
+            return Err(SuperLendyError::OperationCanNotBePerformed);
         }
-        let collateral = &mut self.collateral[collateral_index];
-
-        collateral.withdraw(withdraw_amount)?;
 
         Ok(())
     }
 
-    /// Calculate the maximum collateral value that can be withdrawn
-    pub fn max_withdraw_value(&self, withdraw_collateral_ltv: Decimal) -> LendyResult<Decimal> {
-        Ok(max_withdraw_value(
-            self.allowed_borrow_value()?,
-            self.borrowed_value()?,
-            withdraw_collateral_ltv,
-        )?)
-    }
-
-    /// Calculate the maximum liquidity value that can be borrowed
+    /// Calculate the maximum liquidity value (risk-adjusted) that can be borrowed
     pub fn remaining_borrow_value(&self) -> LendyResult<Decimal> {
         Ok(remaining_borrow_value(
             self.allowed_borrow_value()?,
-            self.borrowed_value()?,
+            self.weighted_borrowed_value()?,
         )?)
     }
 
@@ -294,15 +387,521 @@ impl Position {
             .map_err(From::from)
     }
 
+    pub fn is_isolated(&self) -> bool {
+        self.isolated_collateral != Pubkey::default()
+    }
+
+    pub fn is_stale(&self, clock: &Clock) -> LendyResult<bool> {
+        // Position is stale if it was updated longer then 1 slot ago i.e. to be not stale (by time)
+        // it should be updated in current slot.
+        self.last_update.is_stale_by_slot(clock.slot, 1)
+    }
+
+    pub fn mark_stale(&mut self) {
+        self.last_update.mark_stale();
+    }
+
+    /// Checks that `authority` is either the owner or a delegate having all of `permissions`.
+    /// Returns delegate's record when `authority` is not the owner.
+    pub fn verify_authority(
+        &self,
+        authority: &Pubkey,
+        permissions: u8,
+    ) -> LendyResult<Option<PositionDelegate>> {
+        if *authority == self.owner {
+            return Ok(None);
+        }
+
+        match self.find_delegate(authority) {
+            Some(delegate) if delegate.permissions & permissions == permissions => {
+                Ok(Some(*delegate))
+            }
+            Some(_) => {
+                msg!(
+                    "delegate {} is not permitted to perform the operation",
+                    authority
+                );
+                Err(SuperLendyError::OperationCanNotBePerformed)
+            }
+            None => {
+                verify_key(authority, &self.owner, "position owner or delegate")?;
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn find_delegate(&self, delegate: &Pubkey) -> Option<&PositionDelegate> {
+        self.delegates
+            .iter()
+            .find(|record| record.is_active() && record.delegate == *delegate)
+    }
+
+    /// Adds, updates or (when `permissions` is 0) removes delegate's record.
+    pub fn set_delegate(
+        &mut self,
+        delegate: Pubkey,
+        permissions: u8,
+        borrow_allowance: u64,
+    ) -> LendyResult<()> {
+        if borrow_allowance > MAX_DELEGATE_BORROW_ALLOWANCE {
+            msg!(
+                "borrow allowance {} exceeds max {}",
+                borrow_allowance,
+                MAX_DELEGATE_BORROW_ALLOWANCE
+            );
+            return Err(SuperLendyError::InvalidAmount);
+        }
+
+        if let Some(record) = self
+            .delegates
+            .iter_mut()
+            .find(|record| record.is_active() && record.delegate == delegate)
+        {
+            if permissions == 0 {
+                *record = Zeroable::zeroed();
+            } else {
+                record.permissions = permissions;
+                record.set_borrow_allowance_cents(borrow_allowance);
+            }
+            return Ok(());
+        }
+
+        if permissions == 0 {
+            msg!("delegate {} not found", delegate);
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        if let Some(record) = self.delegates.iter_mut().find(|record| !record.is_active()) {
+            *record = PositionDelegate {
+                delegate,
+                borrow_allowance: Zeroable::zeroed(),
+                permissions,
+            };
+            record.set_borrow_allowance_cents(borrow_allowance);
+            Ok(())
+        } else {
+            msg!("max delegates limit {} reached", MAX_DELEGATES);
+            Err(SuperLendyError::ResourceExhausted)
+        }
+    }
+
+    /// Consumes `borrow_value` of `delegate`'s borrow allowance. Consumed value is rounded up to
+    /// whole cents thus delegate can never borrow more than allowed.
+    pub fn consume_borrow_allowance(
+        &mut self,
+        delegate: &Pubkey,
+        borrow_value: Decimal,
+    ) -> LendyResult<()> {
+        let record = self
+            .delegates
+            .iter_mut()
+            .find(|record| record.is_active() && record.delegate == *delegate)
+            .ok_or_else(|| {
+                msg!("delegate {} not found", delegate);
+                SuperLendyError::OperationCanNotBePerformed
+            })?;
+
+        let borrow_allowance = record.borrow_allowance_cents();
+        let consumed_allowance =
+            borrow_value.to_lamports_ceil(DELEGATE_BORROW_ALLOWANCE_DECIMALS)?;
+        if consumed_allowance > borrow_allowance {
+            msg!(
+                "Borrow value {} exceeds delegate's borrow allowance {}",
+                borrow_value,
+                record.borrow_allowance()?
+            );
+            return Err(SuperLendyError::BorrowTooLarge);
+        }
+
+        record.set_borrow_allowance_cents(borrow_allowance - consumed_allowance);
+
+        Ok(())
+    }
+
+    /// Total number of collateral records the position can hold
+    pub fn max_deposits(&self) -> usize {
+        MAX_DEPOSITS + self.extra_deposits as usize
+    }
+
+    /// Total number of borrow records the position can hold
+    pub fn max_borrows(&self) -> usize {
+        MAX_BORROWS + self.extra_borrows as usize
+    }
+
+    /// Total number of reward records the position can hold
+    pub fn max_rewards(&self) -> usize {
+        MAX_REWARDS + self.extra_rewards as usize
+    }
+
+    /// Size of Position account data with given number of extension records.
+    pub fn account_size(
+        extra_deposits: u8,
+        extra_borrows: u8,
+        extra_rewards: u8,
+        extra_fixed_loans: u8,
+    ) -> usize {
+        Self::SIZE
+            + extra_deposits as usize * std::mem::size_of::<DepositedCollateral>()
+            + extra_borrows as usize * std::mem::size_of::<BorrowedLiquidity>()
+            + extra_rewards as usize * std::mem::size_of::<Reward>()
+            + extra_fixed_loans as usize * std::mem::size_of::<FixedLoan>()
+    }
+
+    /// Unpacks Position account data: the Position itself along with its extension records.
+    pub fn unpack(data: &[u8]) -> LendyResult<PositionRef<'_>> {
+        if data.len() < Self::SIZE {
+            return Err(SerializeError::NotEnoughData.into());
+        }
+
+        let (header_data, extension_data) = data.split_at(Self::SIZE);
+        let header = Self::try_from_bytes(header_data)?;
+
+        let (collateral_len, borrows_len, rewards_len) =
+            header.extension_lens(extension_data.len())?;
+        let (collateral_data, extension_data) = extension_data.split_at(collateral_len);
+        let (borrows_data, extension_data) = extension_data.split_at(borrows_len);
+        let (rewards_data, fixed_loans_data) = extension_data.split_at(rewards_len);
+
+        Ok(PositionRef {
+            header,
+            collateral_ext: bytemuck::try_cast_slice(collateral_data)
+                .map_err(SerializeError::from)?,
+            borrows_ext: bytemuck::try_cast_slice(borrows_data).map_err(SerializeError::from)?,
+            rewards_ext: bytemuck::try_cast_slice(rewards_data).map_err(SerializeError::from)?,
+            fixed_loans: bytemuck::try_cast_slice(fixed_loans_data)
+                .map_err(SerializeError::from)?,
+        })
+    }
+
+    /// Unpacks Position account data for modification.
+    pub fn unpack_mut(data: &mut [u8]) -> LendyResult<PositionMut<'_>> {
+        if data.len() < Self::SIZE {
+            return Err(SerializeError::NotEnoughData.into());
+        }
+
+        let (header_data, extension_data) = data.split_at_mut(Self::SIZE);
+        let header = Self::try_from_bytes_mut(header_data)?;
+
+        let (collateral_len, borrows_len, rewards_len) =
+            header.extension_lens(extension_data.len())?;
+        let (collateral_data, extension_data) = extension_data.split_at_mut(collateral_len);
+        let (borrows_data, extension_data) = extension_data.split_at_mut(borrows_len);
+        let (rewards_data, fixed_loans_data) = extension_data.split_at_mut(rewards_len);
+
+        Ok(PositionMut {
+            header,
+            collateral_ext: bytemuck::try_cast_slice_mut(collateral_data)
+                .map_err(SerializeError::from)?,
+            borrows_ext: bytemuck::try_cast_slice_mut(borrows_data)
+                .map_err(SerializeError::from)?,
+            rewards_ext: bytemuck::try_cast_slice_mut(rewards_data)
+                .map_err(SerializeError::from)?,
+            fixed_loans: bytemuck::try_cast_slice_mut(fixed_loans_data)
+                .map_err(SerializeError::from)?,
+        })
+    }
+
+    /// Checks that extension data has expected length and returns lengths of collateral, borrows
+    /// and rewards parts of it.
+    fn extension_lens(&self, extension_len: usize) -> LendyResult<(usize, usize, usize)> {
+        let expected_len = Self::account_size(
+            self.extra_deposits,
+            self.extra_borrows,
+            self.extra_rewards,
+            self.extra_fixed_loans,
+        ) - Self::SIZE;
+
+        match extension_len.cmp(&expected_len) {
+            Ordering::Less => return Err(SerializeError::NotEnoughData.into()),
+            Ordering::Greater => return Err(SerializeError::TooMuchSpace.into()),
+            Ordering::Equal => {}
+        }
+
+        Ok((
+            self.extra_deposits as usize * std::mem::size_of::<DepositedCollateral>(),
+            self.extra_borrows as usize * std::mem::size_of::<BorrowedLiquidity>(),
+            self.extra_rewards as usize * std::mem::size_of::<Reward>(),
+        ))
+    }
+}
+
+/// Position along with its extension records. Collateral, borrow and reward records are
+/// addressed by logical index: records stored in the `Position` go first and extension records
+/// follow them. Thus record with index MAX_DEPOSITS is the first collateral record of the extension.
+/// RefreshPosition expects reserves in that order too. Fixed-rate loans are stored in the extension
+/// only and reserves of active loans go after reserves of all other borrow records.
+#[derive(Clone, Copy)]
+pub struct PositionRef<'a> {
+    pub header: &'a Position,
+    pub collateral_ext: &'a [DepositedCollateral],
+    pub borrows_ext: &'a [BorrowedLiquidity],
+    pub rewards_ext: &'a [Reward],
+    pub fixed_loans: &'a [FixedLoan],
+}
+
+impl Deref for PositionRef<'_> {
+    type Target = Position;
+
+    fn deref(&self) -> &Self::Target {
+        self.header
+    }
+}
+
+impl<'a> From<&'a Position> for PositionRef<'a> {
+    fn from(header: &'a Position) -> Self {
+        PositionRef {
+            header,
+            collateral_ext: &[],
+            borrows_ext: &[],
+            rewards_ext: &[],
+            fixed_loans: &[],
+        }
+    }
+}
+
+impl<'a> PositionRef<'a> {
+    /// All collateral records in logical order
+    pub fn collateral(&self) -> impl Iterator<Item = &'a DepositedCollateral> {
+        self.header
+            .collateral
+            .iter()
+            .chain(self.collateral_ext.iter())
+    }
+
+    /// All borrow records in logical order
+    pub fn borrows(&self) -> impl Iterator<Item = &'a BorrowedLiquidity> {
+        self.header.borrows.iter().chain(self.borrows_ext.iter())
+    }
+
+    /// All reward records in logical order
+    pub fn rewards(&self) -> impl Iterator<Item = &'a Reward> {
+        self.header
+            .rewards
+            .rewards
+            .iter()
+            .chain(self.rewards_ext.iter())
+    }
+
+    /// Find collateral by deposit reserve
+    pub fn find_collateral(
+        &self,
+        deposit_reserve: Pubkey,
+    ) -> LendyResult<(&'a DepositedCollateral, /* collateral index */ usize)> {
+        self.collateral()
+            .enumerate()
+            .find(|(_index, collateral)| collateral.deposit_reserve == deposit_reserve)
+            .map(|(index, collateral)| (collateral, index))
+            .ok_or(SuperLendyError::DepositedCollateralNotFound)
+    }
+
+    /// Find borrowed liquidity record by borrow reserve
+    pub fn find_borrowed_liquidity(
+        &self,
+        borrow_reserve: Pubkey,
+    ) -> LendyResult<(&'a BorrowedLiquidity, usize)> {
+        self.borrows()
+            .enumerate()
+            .find(|(_index, liquidity)| liquidity.borrow_reserve == borrow_reserve)
+            .map(|(index, liquidity)| (liquidity, index))
+            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)
+    }
+
+    /// Find active fixed-rate loan by borrow reserve
+    pub fn find_fixed_loan(&self, borrow_reserve: Pubkey) -> LendyResult<(&'a FixedLoan, usize)> {
+        self.fixed_loans
+            .iter()
+            .enumerate()
+            .find(|(_index, loan)| {
+                loan.is_active() && loan.liquidity.borrow_reserve == borrow_reserve
+            })
+            .map(|(index, loan)| (loan, index))
+            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)
+    }
+
+    pub fn have_any_borrowings(&self) -> bool {
+        for borrowing in self.borrows() {
+            if borrowing.borrowed_amount().unwrap_or(Decimal::ZERO) != Decimal::ZERO {
+                return true;
+            }
+        }
+        self.fixed_loans.iter().any(FixedLoan::is_active)
+    }
+
+    pub fn have_any_deposits(&self) -> bool {
+        for deposit in self.collateral() {
+            if deposit.deposited_amount != 0 {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Checks is position can be safely closed.
+    /// Returns None - when position can be safely closed.
+    /// Returns Some(reason why it can NOT be closed) - when  position can NOT be safely closed.
+    pub fn closable(&self) -> Option</* reason why it can NOT be closed */ String> {
+        for (idx, deposited_collateral) in self.collateral().enumerate() {
+            if deposited_collateral.deposited_amount > 0 {
+                return Some(format!(
+                    "locked collateral exists. idx {} amount {}",
+                    idx, deposited_collateral.deposited_amount
+                ));
+            }
+        }
+
+        for (idx, borrow) in self.borrows().enumerate() {
+            if borrow.borrowed_amount().expect("borrowed amount") > Decimal::ZERO {
+                return Some(format!(
+                    "borrow exists. idx {} amount {}",
+                    idx,
+                    borrow.borrowed_amount().expect("borrowed amount")
+                ));
+            }
+        }
+
+        for (idx, loan) in self.fixed_loans.iter().enumerate() {
+            if loan.is_active() {
+                return Some(format!(
+                    "fixed-rate loan exists. idx {} amount {}",
+                    idx,
+                    loan.liquidity.borrowed_amount().expect("borrowed amount")
+                ));
+            }
+        }
+
+        for (idx, reward) in self.rewards().enumerate() {
+            if reward.accrued_amount > 0 {
+                return Some(format!(
+                    "unclaimed reward exists. idx {} amount {}",
+                    idx, reward.accrued_amount
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/// Mutable counterpart of the [PositionRef].
+pub struct PositionMut<'a> {
+    pub header: &'a mut Position,
+    pub collateral_ext: &'a mut [DepositedCollateral],
+    pub borrows_ext: &'a mut [BorrowedLiquidity],
+    pub rewards_ext: &'a mut [Reward],
+    pub fixed_loans: &'a mut [FixedLoan],
+}
+
+impl Deref for PositionMut<'_> {
+    type Target = Position;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.header
+    }
+}
+
+impl DerefMut for PositionMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut *self.header
+    }
+}
+
+impl<'a> From<&'a mut Position> for PositionMut<'a> {
+    fn from(header: &'a mut Position) -> Self {
+        PositionMut {
+            header,
+            collateral_ext: &mut [],
+            borrows_ext: &mut [],
+            rewards_ext: &mut [],
+            fixed_loans: &mut [],
+        }
+    }
+}
+
+impl PositionMut<'_> {
+    pub fn as_position_ref(&self) -> PositionRef<'_> {
+        PositionRef {
+            header: &*self.header,
+            collateral_ext: &*self.collateral_ext,
+            borrows_ext: &*self.borrows_ext,
+            rewards_ext: &*self.rewards_ext,
+            fixed_loans: &*self.fixed_loans,
+        }
+    }
+
+    /// All collateral records in logical order
+    pub fn collateral_mut(&mut self) -> impl Iterator<Item = &mut DepositedCollateral> {
+        self.header
+            .collateral
+            .iter_mut()
+            .chain(self.collateral_ext.iter_mut())
+    }
+
+    /// All borrow records in logical order
+    pub fn borrows_mut(&mut self) -> impl Iterator<Item = &mut BorrowedLiquidity> {
+        self.header
+            .borrows
+            .iter_mut()
+            .chain(self.borrows_ext.iter_mut())
+    }
+
+    /// All reward records in logical order
+    pub fn rewards_mut(&mut self) -> RewardsMut<'_> {
+        RewardsMut {
+            rewards: &mut self.header.rewards.rewards,
+            extra: &mut *self.rewards_ext,
+        }
+    }
+
+    /// Repay liquidity
+    pub fn repay(&mut self, settle_amount: Decimal, borrowing_index: usize) -> LendyResult<()> {
+        if borrowing_index >= self.max_borrows() {
+            msg!(
+                "liquidity_index {} beyond limit {}",
+                borrowing_index,
+                self.max_borrows()
+            );
+            return Err(SuperLendyError::Internal(String::from(
+                "liquidity_index to big during repay",
+            )));
+        }
+        let borrowed_liquidity = self
+            .borrows_mut()
+            .nth(borrowing_index)
+            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)?;
+
+        borrowed_liquidity.repay(settle_amount)?;
+
+        Ok(())
+    }
+
+    /// Withdraw collateral
+    pub fn withdraw(&mut self, withdraw_amount: u64, collateral_index: usize) -> LendyResult<()> {
+        if collateral_index >= self.max_deposits() {
+            msg!(
+                "collateral_index {} beyond limit {}",
+                collateral_index,
+                self.max_deposits()
+            );
+            return Err(SuperLendyError::Internal(String::from(
+                "liquidity_index to big during repay",
+            )));
+        }
+        let collateral = self
+            .collateral_mut()
+            .nth(collateral_index)
+            .ok_or(SuperLendyError::DepositedCollateralNotFound)?;
+
+        collateral.withdraw(withdraw_amount)?;
+
+        Ok(())
+    }
+
     /// Find collateral by deposit reserve
     pub fn find_collateral(
         &self,
         deposit_reserve: Pubkey,
     ) -> LendyResult<(&DepositedCollateral, /* collateral index */ usize)> {
-        let collateral_index = self
-            ._find_collateral_index(deposit_reserve)
-            .ok_or(SuperLendyError::DepositedCollateralNotFound)?;
-        Ok((&self.collateral[collateral_index], collateral_index))
+        self.as_position_ref().find_collateral(deposit_reserve)
     }
 
     /// Find or add collateral by deposit reserve
@@ -310,12 +909,22 @@ impl Position {
         &mut self,
         deposit_reserve: Pubkey,
     ) -> LendyResult<&mut DepositedCollateral> {
-        if let Some(collateral_index) = self._find_collateral_index(deposit_reserve) {
-            return Ok(&mut self.collateral[collateral_index]);
+        let existing_index = self
+            .find_collateral(deposit_reserve)
+            .ok()
+            .map(|(_collateral, index)| index);
+
+        let max_deposits = self.max_deposits();
+        let mut collateral = self.collateral_mut();
+
+        if let Some(collateral_index) = existing_index {
+            return collateral
+                .nth(collateral_index)
+                .ok_or(SuperLendyError::DepositedCollateralNotFound);
         }
 
         // Already initialized deposit is not found. Thus initialize new record.
-        for deposit in self.collateral.iter_mut() {
+        for deposit in collateral {
             if deposit.deposited_amount == 0 {
                 // i.e. its never been initialized or fully withdrawn
                 let new_deposit = DepositedCollateral::new(deposit_reserve);
@@ -324,15 +933,47 @@ impl Position {
             }
         }
 
-        msg!("max limit {} for deposits reached", MAX_DEPOSITS);
+        msg!(
+            "max limit {} for deposits reached. Use ResizePosition to extend it",
+            max_deposits
+        );
 
         Err(SuperLendyError::ResourceExhausted)
     }
 
-    fn _find_collateral_index(&self, deposit_reserve: Pubkey) -> Option<usize> {
-        self.collateral
-            .iter()
-            .position(|collateral| collateral.deposit_reserve == deposit_reserve)
+    /// Checks that collateral from `deposit_reserve` can be locked in the position with respect to
+    /// isolation rules. `isolated` - is `deposit_reserve` of RESERVE_TYPE_ISOLATED_COLLATERAL type.
+    /// Position locking isolated collateral becomes isolated. Position which has no deposits and
+    /// no borrowings left is released from isolation.
+    pub fn apply_collateral_isolation(
+        &mut self,
+        deposit_reserve: Pubkey,
+        isolated: bool,
+    ) -> LendyResult<()> {
+        if self.is_isolated() && !self.have_any_deposits() && !self.have_any_borrowings() {
+            self.isolated_collateral = Pubkey::default();
+        }
+
+        if self.is_isolated() {
+            if self.isolated_collateral != deposit_reserve {
+                msg!(
+                    "Position is isolated by collateral from {} and can not hold other collateral",
+                    self.isolated_collateral
+                );
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+            return Ok(());
+        }
+
+        if isolated {
+            if self.have_any_deposits() || self.have_any_borrowings() {
+                msg!("Isolated collateral can only be locked in position without deposits and borrowings");
+                return Err(SuperLendyError::OperationCanNotBePerformed);
+            }
+            self.isolated_collateral = deposit_reserve;
+        }
+
+        Ok(())
     }
 
     /// Find borrowed liquidity record by borrow reserve
@@ -340,20 +981,18 @@ impl Position {
         &self,
         borrow_reserve: Pubkey,
     ) -> LendyResult<(&BorrowedLiquidity, usize)> {
-        let liquidity_index = self
-            ._find_borrowed_liquidity(borrow_reserve)
-            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)?;
-        Ok((&self.borrows[liquidity_index], liquidity_index))
+        self.as_position_ref()
+            .find_borrowed_liquidity(borrow_reserve)
     }
 
     pub fn find_borrowed_liquidity_mut(
         &mut self,
         borrow_reserve: Pubkey,
     ) -> LendyResult<&mut BorrowedLiquidity> {
-        let liquidity_index = self
-            ._find_borrowed_liquidity(borrow_reserve)
-            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)?;
-        Ok(&mut self.borrows[liquidity_index])
+        let (_liquidity, liquidity_index) = self.find_borrowed_liquidity(borrow_reserve)?;
+        self.borrows_mut()
+            .nth(liquidity_index)
+            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)
     }
 
     /// Find or add liquidity by borrow reserve
@@ -362,14 +1001,24 @@ impl Position {
         borrow_reserve: Pubkey,
         cumulative_borrow_rate: Decimal,
     ) -> LendyResult<&mut BorrowedLiquidity> {
+        let existing_index = self
+            .find_borrowed_liquidity(borrow_reserve)
+            .ok()
+            .map(|(_liquidity, index)| index);
+
+        let max_borrows = self.max_borrows();
+        let mut borrows = self.borrows_mut();
+
         // Try to find existing record by borrow_reserve
-        if let Some(liquidity_index) = self._find_borrowed_liquidity(borrow_reserve) {
-            return Ok(&mut self.borrows[liquidity_index]);
+        if let Some(liquidity_index) = existing_index {
+            return borrows
+                .nth(liquidity_index)
+                .ok_or(SuperLendyError::BorrowedLiquidityNotFound);
         }
 
         // No record found by borrow_reserve. Therefore, try to find first record with no borrowed amount.
         // This could be uninitialized record or currently not used.
-        for borrowing in self.borrows.iter_mut() {
+        for borrowing in borrows {
             if borrowing.borrowed_amount()? == Decimal::ZERO {
                 let borrowed_liquidity =
                     BorrowedLiquidity::new(borrow_reserve, cumulative_borrow_rate);
@@ -378,78 +1027,120 @@ impl Position {
             }
         }
 
-        msg!("max limit {} for borrows reached", MAX_BORROWS);
+        msg!(
+            "max limit {} for borrows reached. Use ResizePosition to extend it",
+            max_borrows
+        );
 
         Err(SuperLendyError::ResourceExhausted)
     }
 
-    pub fn have_any_borrowings(&self) -> bool {
-        for borrowing in self.borrows.iter() {
-            if borrowing.borrowed_amount().unwrap_or(Decimal::ZERO) != Decimal::ZERO {
-                return true;
-            }
-        }
-        false
+    /// Find active fixed-rate loan by borrow reserve
+    pub fn find_fixed_loan(&self, borrow_reserve: Pubkey) -> LendyResult<(&FixedLoan, usize)> {
+        self.as_position_ref().find_fixed_loan(borrow_reserve)
     }
 
-    pub fn have_any_deposits(&self) -> bool {
-        for deposit in self.collateral.iter() {
-            if deposit.deposited_amount != 0 {
-                return true;
+    /// Start new fixed-rate loan from `borrow_reserve`. Position can have only one active loan
+    /// from the Reserve.
+    pub fn add_fixed_loan(
+        &mut self,
+        borrow_reserve: Pubkey,
+        rate_bps: u32,
+        origination_timestamp: UnixTimestamp,
+        maturity_timestamp: UnixTimestamp,
+    ) -> LendyResult<&mut FixedLoan> {
+        if self.find_fixed_loan(borrow_reserve).is_ok() {
+            msg!(
+                "Position already has fixed-rate loan from reserve {}. Repay it first",
+                borrow_reserve
+            );
+            return Err(SuperLendyError::OperationCanNotBePerformed);
+        }
+
+        let max_fixed_loans = self.extra_fixed_loans;
+        for loan in self.fixed_loans.iter_mut() {
+            if !loan.is_active() {
+                *loan = FixedLoan::new(
+                    borrow_reserve,
+                    rate_bps,
+                    origination_timestamp,
+                    maturity_timestamp,
+                );
+                return Ok(loan);
             }
         }
-        false
+
+        msg!(
+            "max limit {} for fixed-rate loans reached. Use ResizePosition to extend it",
+            max_fixed_loans
+        );
+
+        Err(SuperLendyError::ResourceExhausted)
     }
 
-    fn _find_borrowed_liquidity(&self, borrow_reserve: Pubkey) -> Option<usize> {
-        self.borrows
-            .iter()
-            .position(|liquidity| liquidity.borrow_reserve == borrow_reserve)
+    /// Repay fixed-rate loan
+    pub fn repay_fixed_loan(
+        &mut self,
+        settle_amount: Decimal,
+        loan_index: usize,
+    ) -> LendyResult<()> {
+        self.fixed_loans
+            .get_mut(loan_index)
+            .ok_or(SuperLendyError::BorrowedLiquidityNotFound)?
+            .liquidity
+            .repay(settle_amount)
     }
 
-    pub fn is_stale(&self, clock: &Clock) -> LendyResult<bool> {
-        // Position is stale if it was updated longer then 1 slot ago i.e. to be not stale (by time)
-        // it should be updated in current slot.
-        self.last_update.is_stale_by_slot(clock.slot, 1)
+    pub fn have_any_borrowings(&self) -> bool {
+        self.as_position_ref().have_any_borrowings()
     }
 
-    pub fn mark_stale(&mut self) {
-        self.last_update.mark_stale();
+    pub fn have_any_deposits(&self) -> bool {
+        self.as_position_ref().have_any_deposits()
     }
 
-    /// Checks is position can be safely closed.
-    /// Returns None - when position can be safely closed.
-    /// Returns Some(reason why it can NOT be closed) - when  position can NOT be safely closed.
+    /// Checks is position can be safely closed. See [PositionRef::closable].
     pub fn closable(&self) -> Option</* reason why it can NOT be closed */ String> {
-        for (idx, deposited_collateral) in self.collateral.iter().enumerate() {
-            if deposited_collateral.deposited_amount > 0 {
-                return Some(format!(
-                    "locked collateral exists. idx {} amount {}",
-                    idx, deposited_collateral.deposited_amount
-                ));
-            }
-        }
+        self.as_position_ref().closable()
+    }
+}
 
-        for (idx, borrow) in self.borrows.iter().enumerate() {
-            if borrow.borrowed_amount().expect("borrowed amount") > Decimal::ZERO {
-                return Some(format!(
-                    "borrow exists. idx {} amount {}",
-                    idx,
-                    borrow.borrowed_amount().expect("borrowed amount")
-                ));
-            }
-        }
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct PositionDelegate {
+    /// Operator authorized to act on behalf of the owner
+    pub delegate: Pubkey,
+    /// Market value (in cents of quote currency) the delegate can still borrow. Decreased by
+    /// delegate's borrows only thus owner's own borrows don't consume it. Little-endian 56 bit
+    /// number - that keeps the record 40 bytes long.
+    pub borrow_allowance: [u8; 7],
+    /// Bit set of DELEGATE_PERMISSION_* flags. 0 - vacant record.
+    pub permissions: u8,
+}
 
-        for (idx, reward) in self.rewards.rewards.iter().enumerate() {
-            if reward.accrued_amount > 0 {
-                return Some(format!(
-                    "unclaimed reward exists. idx {} amount {}",
-                    idx, reward.accrued_amount
-                ));
-            }
-        }
+impl PositionDelegate {
+    pub fn is_active(&self) -> bool {
+        self.permissions != 0
+    }
 
-        None
+    pub fn borrow_allowance_cents(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes[..7].copy_from_slice(&self.borrow_allowance);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// `cents` must not exceed MAX_DELEGATE_BORROW_ALLOWANCE
+    fn set_borrow_allowance_cents(&mut self, cents: u64) {
+        self.borrow_allowance
+            .copy_from_slice(&cents.to_le_bytes()[..7]);
+    }
+
+    pub fn borrow_allowance(&self) -> LendyResult<Decimal> {
+        Decimal::from_lamports(
+            self.borrow_allowance_cents(),
+            DELEGATE_BORROW_ALLOWANCE_DECIMALS,
+        )
+        .map_err(From::from)
     }
 }
 
@@ -721,6 +1412,84 @@ impl BorrowedLiquidity {
     }
 }
 
+/// Fixed-rate loan with maturity. Unlike [BorrowedLiquidity] it doesn't follow Reserve's
+/// `cumulative_borrow_rate` - interest accrues linearly with the rate locked at origination. Loan's
+/// own cumulative rate (1 + rate * years since origination) is kept in `liquidity`. Once maturity
+/// and Reserve's grace period pass the loan can be liquidated regardless of Position health.
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct FixedLoan {
+    /// Borrowed liquidity plus interest. Loan with zero borrowed amount is vacant.
+    pub liquidity: BorrowedLiquidity,
+    /// Annual interest rate (in basis points) locked at origination
+    pub rate_bps: u32,
+    pub _padding0: u32,
+    /// Solana time interest accrues from
+    pub origination_timestamp: UnixTimestamp,
+    /// Solana time the loan must be repaid by
+    pub maturity_timestamp: UnixTimestamp,
+    pub _padding: [u8; 8],
+}
+
+impl FixedLoan {
+    pub fn new(
+        borrow_reserve: Pubkey,
+        rate_bps: u32,
+        origination_timestamp: UnixTimestamp,
+        maturity_timestamp: UnixTimestamp,
+    ) -> Self {
+        Self {
+            liquidity: BorrowedLiquidity::new(borrow_reserve, Decimal::ONE),
+            rate_bps,
+            _padding0: 0,
+            origination_timestamp,
+            maturity_timestamp,
+            _padding: Zeroable::zeroed(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.liquidity.borrowed_amount().unwrap_or(Decimal::ZERO) != Decimal::ZERO
+    }
+
+    pub fn rate(&self) -> LendyResult<Decimal> {
+        Decimal::from_basis_points(self.rate_bps).map_err(From::from)
+    }
+
+    /// Loan's cumulative borrow rate at `timestamp`
+    pub fn cumulative_borrow_rate(&self, timestamp: UnixTimestamp) -> LendyResult<Decimal> {
+        let seconds_elapsed = timestamp.saturating_sub(self.origination_timestamp).max(0);
+        let years_elapsed = Decimal::from_i128_with_scale(seconds_elapsed as i128, 0)?
+            .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0)?)?;
+
+        Ok(Decimal::ONE.checked_add(self.rate()?.checked_mul(years_elapsed)?)?)
+    }
+
+    /// Accrue interest till `timestamp`. This must be the time borrow Reserve accrued its interest
+    /// till (i.e. its `last_update.timestamp`) so that Reserve's fixed borrowed amount stays equal
+    /// to the sum of its loans.
+    pub fn accrue_interest(&mut self, timestamp: UnixTimestamp) -> LendyResult<()> {
+        self.liquidity
+            .accrue_interest(self.cumulative_borrow_rate(timestamp)?)
+    }
+
+    /// Annual interest the loan pays for `amount` of its current debt. Reserve's fixed interest per
+    /// year is changed by that value when loan is originated or repaid.
+    pub fn interest_per_year(&self, amount: Decimal) -> LendyResult<Decimal> {
+        Ok(amount
+            .checked_div(self.liquidity.cumulative_borrow_rate()?)?
+            .checked_mul(self.rate()?)?)
+    }
+
+    /// Loan is not repaid after maturity plus `grace_period_sec`
+    pub fn is_overdue(&self, timestamp: UnixTimestamp, grace_period_sec: u32) -> bool {
+        let liquidation_timestamp = self
+            .maturity_timestamp
+            .saturating_add(grace_period_sec as i64);
+        self.is_active() && timestamp >= liquidation_timestamp
+    }
+}
+
 /// Reward record tracks interaction of user's position with ALL Reward Rules with given
 /// reward_mint. Rewards from all such rules accumulated on one Rewards record.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Pod, Zeroable)]
@@ -847,6 +1616,51 @@ pub struct Rewards {
 }
 
 impl Rewards {
+    /// Accrues rewards for one particular `deposit` or `borrow` record. See
+    /// [RewardsMut::accrue_rewards].
+    pub fn accrue_rewards(
+        &mut self,
+        reason: u8, // reward reason: REWARD_FOR_BORROW or REWARD_FOR_LIQUIDITY
+        amount: Decimal,
+        reward_rules: &RewardRules,
+        current_slot: Slot,
+    ) -> LendyResult<HashSet<usize>> {
+        RewardsMut::from(self).accrue_rewards(reason, amount, reward_rules, current_slot)
+    }
+
+    pub fn find_reward(&mut self, reward_mint: &Pubkey) -> Option<(usize, &mut Reward)> {
+        self.rewards
+            .iter_mut()
+            .enumerate()
+            .find(|(_index, reward)| &reward.reward_mint == reward_mint)
+    }
+
+    pub fn set_accrued_slot(&mut self, current_slot: Slot, indexes: HashSet<usize>) {
+        RewardsMut::from(self).set_accrued_slot(current_slot, indexes)
+    }
+}
+
+/// Reward records of the Position: ones stored in the `Position` followed by ones from its
+/// extension. Record indexes are logical i.e. counted through both parts.
+pub struct RewardsMut<'a> {
+    pub rewards: &'a mut [Reward],
+    pub extra: &'a mut [Reward],
+}
+
+impl<'a> From<&'a mut Rewards> for RewardsMut<'a> {
+    fn from(rewards: &'a mut Rewards) -> Self {
+        RewardsMut {
+            rewards: &mut rewards.rewards,
+            extra: &mut [],
+        }
+    }
+}
+
+impl RewardsMut<'_> {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Reward> {
+        self.rewards.iter_mut().chain(self.extra.iter_mut())
+    }
+
     /// Accrues rewards for one particular `deposit` or `borrow` record.
     /// `reason` - encodes rewarded situation. REWARD_FOR_BORROW or REWARD_FOR_LIQUIDITY
     /// `amount` - locked collateral or borrowed amount to use as base for rewards accrual. WAD.
@@ -880,7 +1694,7 @@ impl Rewards {
                     match self.find_unused_reward() {
                         None => {
                             msg!(
-                                "warning: no free space for rewards for rule {} reward_mint {}",
+                                "warning: no free space for rewards for rule {} reward_mint {}. Use ResizePosition to extend it",
                                 String::from_utf8_lossy(&rule.name),
                                 rule.reward_mint
                             );
@@ -904,8 +1718,7 @@ impl Rewards {
     }
 
     pub fn find_reward(&mut self, reward_mint: &Pubkey) -> Option<(usize, &mut Reward)> {
-        self.rewards
-            .iter_mut()
+        self.iter_mut()
             .enumerate()
             .find(|(_index, reward)| &reward.reward_mint == reward_mint)
     }
@@ -914,12 +1727,14 @@ impl Rewards {
     // 1. Default initialized with default (zero) pubkey.
     // 2. With non default reward_mint AND zero accrued amount (i.e. fully claimed)
     pub fn find_unused_reward(&mut self) -> Option<&mut Reward> {
-        self.rewards.iter_mut().find(|reward| reward.is_vacant())
+        self.iter_mut().find(|reward| reward.is_vacant())
     }
 
     pub fn set_accrued_slot(&mut self, current_slot: Slot, indexes: HashSet<usize>) {
-        for idx in indexes {
-            self.rewards[idx].accrued_slot = current_slot;
+        for (idx, reward) in self.iter_mut().enumerate() {
+            if indexes.contains(&idx) {
+                reward.accrued_slot = current_slot;
+            }
         }
     }
 }
@@ -1392,6 +2207,7 @@ mod tests {
             discriminator: Zeroable::zeroed(),
             version: 0,
             position_type: 0,
+            emode_category: 0,
             _flags: Zeroable::zeroed(),
             last_update: Zeroable::zeroed(),
             pool: Default::default(),
@@ -1419,7 +2235,18 @@ mod tests {
                 .unwrap()
                 .into_bits()
                 .unwrap(),
+            weighted_borrowed_value: Decimal::from_i128_with_scale(100, 9)
+                .unwrap()
+                .into_bits()
+                .unwrap(),
+            isolated_collateral: Pubkey::default(),
+            extra_deposits: 0,
+            extra_borrows: 0,
+            extra_rewards: 0,
+            extra_fixed_loans: 0,
             _padding: Zeroable::zeroed(),
+            pending_owner: Pubkey::default(),
+            delegates: Zeroable::zeroed(),
         };
 
         position.collateral[0] = collateral;
@@ -1435,4 +2262,323 @@ mod tests {
         // Withdrawing 1125 will bring position's LTV to 80%
         assert_eq!(max_withdraw, Decimal::from_lamports(1125, 9).unwrap());
     }
+
+    #[test]
+    fn collateral_isolation() {
+        let normal_reserve = Pubkey::new_unique();
+        let isolated_reserve = Pubkey::new_unique();
+        let other_isolated_reserve = Pubkey::new_unique();
+
+        let mut position = Position::new(
+            Default::default(),
+            Default::default(),
+            Zeroable::zeroed(),
+            Zeroable::zeroed(),
+        );
+
+        // Isolated collateral can not be added to position with other collateral
+        position.collateral[0] = DepositedCollateral::new(normal_reserve);
+        position.collateral[0].deposited_amount = 100;
+        assert!(PositionMut::from(&mut position)
+            .apply_collateral_isolation(isolated_reserve, true)
+            .is_err());
+        assert!(!position.is_isolated());
+
+        position.collateral[0] = Zeroable::zeroed();
+        PositionMut::from(&mut position)
+            .apply_collateral_isolation(isolated_reserve, true)
+            .unwrap();
+        assert_eq!(position.isolated_collateral, isolated_reserve);
+
+        position.collateral[0] = DepositedCollateral::new(isolated_reserve);
+        position.collateral[0].deposited_amount = 100;
+
+        // Same isolated collateral can be added again
+        PositionMut::from(&mut position)
+            .apply_collateral_isolation(isolated_reserve, true)
+            .unwrap();
+
+        // Any other collateral is rejected
+        assert!(PositionMut::from(&mut position)
+            .apply_collateral_isolation(normal_reserve, false)
+            .is_err());
+        assert!(PositionMut::from(&mut position)
+            .apply_collateral_isolation(other_isolated_reserve, true)
+            .is_err());
+
+        // Collateral fully unlocked - isolation is released
+        position.collateral[0] = Zeroable::zeroed();
+        PositionMut::from(&mut position)
+            .apply_collateral_isolation(normal_reserve, false)
+            .unwrap();
+        assert!(!position.is_isolated());
+    }
+
+    #[test]
+    fn emode_withdraw_collateral_ltv() {
+        let mut position = Position::new(
+            Default::default(),
+            Default::default(),
+            Zeroable::zeroed(),
+            Zeroable::zeroed(),
+        );
+
+        let reserve_ltv = Decimal::from_basis_points(7_000).unwrap();
+
+        // Position's allowed borrow value calculated with e-mode LTV 90%
+        position
+            .set_deposited_value(Decimal::from_i128_with_scale(1000, 0).unwrap())
+            .unwrap();
+        position
+            .set_allowed_borrow_value(Decimal::from_i128_with_scale(900, 0).unwrap())
+            .unwrap();
+
+        // Not in e-mode - Reserve's LTV is used as is
+        assert_eq!(
+            position.withdraw_collateral_ltv(reserve_ltv).unwrap(),
+            reserve_ltv
+        );
+
+        position.emode_category = 1;
+        assert_eq!(
+            position.withdraw_collateral_ltv(reserve_ltv).unwrap(),
+            Decimal::from_basis_points(9_000).unwrap()
+        );
+
+        // Reserve's LTV is higher than position's one
+        assert_eq!(
+            position
+                .withdraw_collateral_ltv(Decimal::from_basis_points(9_500).unwrap())
+                .unwrap(),
+            Decimal::from_basis_points(9_500).unwrap()
+        );
+    }
+
+    #[test]
+    fn position_extension() {
+        let mut data = vec![0_u8; Position::account_size(1, 1, 1, 1)];
+        Position::init_bytes(
+            &mut data[..Position::SIZE],
+            InitPositionParams {
+                position_type: POSITION_TYPE_CLASSIC,
+                pool: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+            },
+        )
+        .unwrap();
+
+        // Extension records are not expected until they are counted in the Position
+        assert!(Position::unpack(&data).is_err());
+
+        let header = Position::try_from_bytes_mut(&mut data[..Position::SIZE]).unwrap();
+        header.extra_deposits = 1;
+        header.extra_borrows = 1;
+        header.extra_rewards = 1;
+        header.extra_fixed_loans = 1;
+
+        assert!(Position::unpack(&data[..data.len() - 1]).is_err());
+
+        let mut position = Position::unpack_mut(&mut data).unwrap();
+        assert_eq!(position.max_deposits(), MAX_DEPOSITS + 1);
+
+        for _ in 0..MAX_DEPOSITS {
+            position
+                .find_or_add_collateral(Pubkey::new_unique())
+                .unwrap()
+                .deposited_amount = 1;
+        }
+
+        // Next collateral record is taken from the extension
+        let extension_reserve = Pubkey::new_unique();
+        position
+            .find_or_add_collateral(extension_reserve)
+            .unwrap()
+            .deposited_amount = 100;
+        assert_eq!(
+            position.collateral_ext[0].deposit_reserve,
+            extension_reserve
+        );
+
+        let (collateral, collateral_index) = position.find_collateral(extension_reserve).unwrap();
+        assert_eq!(collateral.deposited_amount, 100);
+        assert_eq!(collateral_index, MAX_DEPOSITS);
+
+        position.withdraw(40, collateral_index).unwrap();
+        assert_eq!(position.collateral_ext[0].deposited_amount, 60);
+
+        assert!(matches!(
+            position.find_or_add_collateral(Pubkey::new_unique()),
+            Err(SuperLendyError::ResourceExhausted)
+        ));
+
+        let position = Position::unpack(&data).unwrap();
+        assert_eq!(position.collateral().count(), MAX_DEPOSITS + 1);
+        assert_eq!(position.borrows().count(), MAX_BORROWS + 1);
+        assert_eq!(position.rewards().count(), MAX_REWARDS + 1);
+        assert!(position.closable().is_some());
+    }
+
+    #[test]
+    fn position_delegates() {
+        let owner = Pubkey::new_unique();
+        let mut position = Position::new(
+            Pubkey::new_unique(),
+            owner,
+            Zeroable::zeroed(),
+            Zeroable::zeroed(),
+        );
+
+        let delegate = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        assert_eq!(
+            position
+                .verify_authority(&owner, DELEGATE_PERMISSIONS_ALL)
+                .unwrap(),
+            None
+        );
+        assert!(position
+            .verify_authority(&stranger, DELEGATE_PERMISSION_REPAY)
+            .is_err());
+
+        // Removing absent delegate
+        assert!(position.set_delegate(delegate, 0, 0).is_err());
+
+        position
+            .set_delegate(delegate, DELEGATE_PERMISSION_LOCK, 100)
+            .unwrap();
+        let record = position
+            .verify_authority(&delegate, DELEGATE_PERMISSION_LOCK)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.borrow_allowance_cents(), 100);
+        assert!(position
+            .verify_authority(
+                &delegate,
+                DELEGATE_PERMISSION_LOCK | DELEGATE_PERMISSION_UNLOCK
+            )
+            .is_err());
+
+        // Update existing record
+        position
+            .set_delegate(
+                delegate,
+                DELEGATE_PERMISSION_LOCK | DELEGATE_PERMISSION_UNLOCK,
+                200,
+            )
+            .unwrap();
+        assert_eq!(
+            position.delegates.iter().filter(|d| d.is_active()).count(),
+            1
+        );
+        assert!(position
+            .verify_authority(
+                &delegate,
+                DELEGATE_PERMISSION_LOCK | DELEGATE_PERMISSION_UNLOCK
+            )
+            .is_ok());
+
+        for _ in 1..MAX_DELEGATES {
+            position
+                .set_delegate(Pubkey::new_unique(), DELEGATE_PERMISSION_REPAY, 0)
+                .unwrap();
+        }
+        assert!(position
+            .set_delegate(stranger, DELEGATE_PERMISSION_REPAY, 0)
+            .is_err());
+
+        // Removed delegate vacates its record
+        position.set_delegate(delegate, 0, 0).unwrap();
+        assert!(position
+            .verify_authority(&delegate, DELEGATE_PERMISSION_LOCK)
+            .is_err());
+        position
+            .set_delegate(stranger, DELEGATE_PERMISSION_REPAY, 0)
+            .unwrap();
+    }
+
+    #[test]
+    fn delegate_borrow_allowance() {
+        let owner = Pubkey::new_unique();
+        let mut position = Position::new(
+            Pubkey::new_unique(),
+            owner,
+            Zeroable::zeroed(),
+            Zeroable::zeroed(),
+        );
+
+        let delegate = Pubkey::new_unique();
+        assert!(matches!(
+            position.set_delegate(
+                delegate,
+                DELEGATE_PERMISSION_BORROW,
+                MAX_DELEGATE_BORROW_ALLOWANCE + 1
+            ),
+            Err(SuperLendyError::InvalidAmount)
+        ));
+
+        position
+            .set_delegate(delegate, DELEGATE_PERMISSION_BORROW, 30_000)
+            .unwrap();
+        assert_eq!(
+            position
+                .find_delegate(&delegate)
+                .unwrap()
+                .borrow_allowance()
+                .unwrap(),
+            Decimal::from_i128_with_scale(300, 0).unwrap()
+        );
+
+        // Consumed 131.505 is rounded up to 131.51
+        position
+            .consume_borrow_allowance(&delegate, Decimal::from_i128_with_scale(131505, 3).unwrap())
+            .unwrap();
+        assert_eq!(
+            position
+                .find_delegate(&delegate)
+                .unwrap()
+                .borrow_allowance_cents(),
+            16_849
+        );
+
+        assert!(matches!(
+            position.consume_borrow_allowance(
+                &delegate,
+                Decimal::from_i128_with_scale(168_491, 3).unwrap()
+            ),
+            Err(SuperLendyError::BorrowTooLarge)
+        ));
+
+        position
+            .consume_borrow_allowance(&delegate, Decimal::from_i128_with_scale(16_849, 2).unwrap())
+            .unwrap();
+        assert_eq!(
+            position
+                .find_delegate(&delegate)
+                .unwrap()
+                .borrow_allowance_cents(),
+            0
+        );
+
+        // Owner is not a delegate thus has no allowance to consume
+        assert!(position
+            .consume_borrow_allowance(&owner, Decimal::ONE)
+            .is_err());
+
+        // Owner resets the allowance
+        position
+            .set_delegate(
+                delegate,
+                DELEGATE_PERMISSION_BORROW,
+                MAX_DELEGATE_BORROW_ALLOWANCE,
+            )
+            .unwrap();
+        assert_eq!(
+            position
+                .find_delegate(&delegate)
+                .unwrap()
+                .borrow_allowance_cents(),
+            MAX_DELEGATE_BORROW_ALLOWANCE
+        );
+    }
 }
@@ -16,11 +16,14 @@ use texture_common::math::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal, MathError, MathResult,
 };
 
-use crate::error::SuperLendyError;
+use crate::error::{SerializeError, SuperLendyError};
 use crate::state::last_update::LastUpdate;
-use crate::state::position::{BorrowedLiquidity, DepositedCollateral, Position};
+use crate::state::position::{BorrowedLiquidity, DepositedCollateral, FixedLoan, Position};
 use crate::state::texture_cfg::ReserveTimelock;
-use crate::state::{INITIAL_COLLATERAL_RATE, RESERVE_DISCRIMINATOR, SCALE, SLOTS_PER_YEAR};
+use crate::state::{
+    INITIAL_COLLATERAL_RATE, RESERVE_DISCRIMINATOR, SCALE, SECONDS_PER_YEAR,
+    VIRTUAL_LIQUIDITY_AMOUNT, VIRTUAL_LP_AMOUNT,
+};
 use crate::{LendyResult, MAX_AMOUNT};
 
 static_assertions::const_assert_eq!(Reserve::SIZE, std::mem::size_of::<Reserve>());
@@ -30,6 +33,9 @@ static_assertions::const_assert_eq!(0, std::mem::size_of::<ReserveCollateral>()
 static_assertions::const_assert_eq!(0, std::mem::size_of::<ReserveConfig>() % 16);
 static_assertions::const_assert_eq!(0, std::mem::size_of::<ReserveFeesConfig>() % 16);
 static_assertions::const_assert_eq!(0, std::mem::size_of::<RewardRules>() % 16);
+static_assertions::const_assert_eq!(32, std::mem::size_of::<KinkedIrm>());
+static_assertions::const_assert_eq!(32, std::mem::size_of::<AdaptiveIrm>());
+static_assertions::const_assert_eq!(12, std::mem::size_of::<RateSnapshot>());
 
 /// Important note about amounts representation in this contact.
 /// When some fn parameter or variable has _amount suffix and u64 type - this "lamports" representation.
@@ -70,6 +76,15 @@ pub const RESERVE_TYPE_PROTECTED_COLLATERAL: u8 = 1;
 ///    collateral.
 pub const RESERVE_TYPE_NOT_A_COLLATERAL: u8 = 2;
 
+/// Marks Reserve holding risky collateral which must not back borrows together with other
+/// collaterals. Position which locked collateral in such Reserve:
+/// 1. Can NOT hold any other collateral.
+/// 2. Can borrow only from Reserves with `isolation_borrowable` flag set.
+///
+/// Total debt backed by such collateral (across all positions) is limited by Reserve's
+/// `isolated_debt_ceiling`.
+pub const RESERVE_TYPE_ISOLATED_COLLATERAL: u8 = 3;
+
 // RESERVE MODES. Can be set during `AlterReserve` ix.
 
 /// Fully functional Reserve
@@ -100,6 +115,52 @@ pub const RESERVE_MODE_BORROW_DISABLED: u8 = 1;
 /// 3. Unlock LP tokens in this Reserve.
 pub const RESERVE_MODE_RETAIN_LIQUIDITY: u8 = 2;
 
+// SURPLUS DESTINATIONS. Can be set during `AlterReserve` ix. Define what `SyncReserve` ix does with
+// tokens found in liquidity supply on top of Reserve's `available_amount` (e.g. sent there directly).
+
+/// Surplus is added to Reserve's liquidity thus increasing LP exchange rate.
+pub const SURPLUS_TO_LPS: u8 = 0;
+
+/// Surplus is accrued as Curator's performance fee and can be claimed via
+/// `ClaimCuratorPerformanceFees` ix.
+pub const SURPLUS_TO_CURATOR: u8 = 1;
+
+// INTEREST RATE MODEL KINDS. Define how `ReserveConfig.irm` is interpreted. Changed via `AlterReserve`
+// or `ProposeConfig` ix and time locked the same way as IRM account.
+
+/// `irm` is the address of `curvy` Curve account which must be passed to `RefreshReserve`.
+pub const IRM_KIND_CURVE: u8 = 0;
+
+/// `irm` holds parameters of built-in two-slope model (see [KinkedIrm]). No external account is
+/// involved - super-lendy program id is passed to `RefreshReserve` in place of IRM account.
+pub const IRM_KIND_KINKED: u8 = 1;
+
+/// `irm` holds parameters of built-in adaptive model (see [AdaptiveIrm]). Borrow rate at target
+/// utilization drifts up while utilization is above the target and down while it is below. Current
+/// rate at target is kept in `Reserve.adaptive_rate_at_target`. As with kinked model super-lendy
+/// program id is passed to `RefreshReserve` in place of IRM account.
+pub const IRM_KIND_ADAPTIVE: u8 = 2;
+
+/// Size of Reserve's rate snapshots ring buffer - one week of daily snapshots
+pub const MAX_RATE_SNAPSHOTS: usize = 7;
+/// Length of the period (day) at most one rate snapshot is taken in
+pub const RATE_SNAPSHOT_INTERVAL_SEC: UnixTimestamp = 24 * 3600;
+
+/// Number of bytes ReserveConfig grew by in Reserve version 3
+const RESERVE_CONFIG_V3_GROWTH: usize = 16;
+/// Offset of `max_total_borrow` in ReserveConfig of Reserve version 2. It took the tail of fees.
+const RESERVE_CONFIG_V2_MAX_TOTAL_BORROW_OFFSET: usize =
+    std::mem::offset_of!(ReserveConfig, fees) + 8;
+
+/// Max rate at target of adaptive IRM - 10000%
+pub const ADAPTIVE_IRM_MAX_RATE_BPS: u32 = 1_000_000;
+/// Max yearly adjustment speed of adaptive IRM - 100.0
+pub const ADAPTIVE_IRM_MAX_SPEED_BPS: u32 = 1_000_000;
+/// Rate at target can change at most e^ADAPTIVE_IRM_MAX_DRIFT_EXPONENT times in one accrual. That
+/// exceeds ratio of max and min rates at target thus bounds are reached anyway but intermediate
+/// values can't overflow when Reserve wasn't refreshed for a long time.
+const ADAPTIVE_IRM_MAX_DRIFT_EXPONENT: i128 = 14;
+
 /// Reserve is a part on the Pool which manages all aspect of one currency (token) i.e. supply,
 /// LP tokens, interest, LP exchange rate, oracles and more.
 /// Reserves (token in it) can be used as principal currency only or both as principal and collateral.
@@ -117,8 +178,15 @@ pub struct Reserve {
     pub mode: u8,
     pub flash_loans_enabled: u8,
 
+    /// What to do with liquidity supply surplus. SURPLUS_TO_LPS, etc. See all constants above.
+    pub surplus_destination: u8,
+
+    /// 1 - positions with isolated collateral (see RESERVE_TYPE_ISOLATED_COLLATERAL) can borrow
+    /// from this Reserve.
+    pub isolation_borrowable: u8,
+
     /// Vacant to store mode/status flags
-    pub _flags: [u8; 4],
+    pub _flags: [u8; 2],
 
     /// Last slot when supply and rates updated
     pub last_update: LastUpdate,
@@ -135,8 +203,43 @@ pub struct Reserve {
     /// Configuration changes list (it is not a queue - just indexed list of proposed changes)
     pub proposed_configs: ProposedConfigs,
 
+    /// Market value (in quote currency) of all debt backed by this Reserve when it is of
+    /// RESERVE_TYPE_ISOLATED_COLLATERAL type. Increased on Borrow by value of borrowed amount and
+    /// decreased by value of repaid (liquidated, written off) amount. WAD.
+    pub isolated_debt: i128,
+    /// Maximum `isolated_debt` (in whole units of quote currency) which can be backed by this
+    /// Reserve. Borrow will fail when resulting isolated debt exceeds this value. 0 - no limit.
+    pub isolated_debt_ceiling: u64,
+
+    /// Curator's part of liquidation bonuses. Amount of this Reserve's LP tokens kept in collateral
+    /// supply which can be claimed by Curator via ClaimCuratorLiquidationFees.
+    pub curator_liquidation_fee: u64,
+    /// Texture's part of liquidation bonuses. Amount of this Reserve's LP tokens kept in collateral
+    /// supply which can be claimed via ClaimTextureLiquidationFees.
+    pub texture_liquidation_fee: u64,
+
+    /// Annual interest rate (in basis points) locked by fixed-rate loans originated from this
+    /// Reserve. Already originated loans keep their rate. 0 - fixed-rate borrowing is disabled.
+    pub fixed_rate_bps: u32,
+    /// Maximum term (in seconds) of fixed-rate loan.
+    pub fixed_max_term_sec: u32,
+    /// Time (in seconds) after maturity of fixed-rate loan during which it can still be repaid.
+    /// Once it passes the loan can be liquidated regardless of Position health.
+    pub fixed_grace_period_sec: u32,
+    /// Index in `rate_snapshots` the next snapshot will be written to
+    pub rate_snapshot_index: u8,
+    pub _padding1: [u8; 11],
+
+    /// Borrow rate at target utilization of adaptive IRM (see IRM_KIND_ADAPTIVE). Drifts on each
+    /// interest accrual. 0 - not initialized yet and `initial_rate_at_target_bps` is used. WAD.
+    pub adaptive_rate_at_target: i128,
+
+    /// Ring buffer of rates taken by RefreshReserve once per RATE_SNAPSHOT_INTERVAL_SEC. Use
+    /// [Reserve::rate_snapshots] to get them in chronological order.
+    pub rate_snapshots: [RateSnapshot; MAX_RATE_SNAPSHOTS],
+
     // For future use
-    pub _padding: [u8; 256],
+    pub _padding: [u8; 12],
 }
 
 impl PodAccount for Reserve {
@@ -144,7 +247,10 @@ impl PodAccount for Reserve {
 
     type Version = u8;
 
-    const VERSION: Self::Version = 1;
+    /// 1 - interest accrued per slot
+    /// 2 - interest accrued per second
+    /// 3 - ReserveConfig grew to hold max_total_borrow and borrow rate bounds
+    const VERSION: Self::Version = 3;
 
     type InitParams = (ReserveParams, LastUpdate);
 
@@ -165,6 +271,8 @@ impl PodAccount for Reserve {
             reserve_type,
             mode,
             flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable,
             _flags,
             last_update,
             pool,
@@ -173,6 +281,17 @@ impl PodAccount for Reserve {
             config,
             reward_rules,
             proposed_configs: pending_config,
+            isolated_debt,
+            isolated_debt_ceiling,
+            curator_liquidation_fee,
+            texture_liquidation_fee,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
+            rate_snapshot_index,
+            _padding1,
+            adaptive_rate_at_target,
+            rate_snapshots,
             _padding,
         } = self;
 
@@ -186,10 +305,23 @@ impl PodAccount for Reserve {
         *collateral = params.0.collateral;
         *config = params.0.config;
         *flash_loans_enabled = params.0.flash_loans_enabled;
+        *surplus_destination = SURPLUS_TO_LPS;
+        *isolation_borrowable = 0;
+        *isolated_debt = Decimal::ZERO.into_bits().unwrap();
+        *isolated_debt_ceiling = 0;
+        *curator_liquidation_fee = 0;
+        *texture_liquidation_fee = 0;
+        *fixed_rate_bps = 0;
+        *fixed_max_term_sec = 0;
+        *fixed_grace_period_sec = 0;
+        *adaptive_rate_at_target = Decimal::ZERO.into_bits().unwrap();
+        *rate_snapshot_index = 0;
+        *rate_snapshots = Zeroable::zeroed();
 
         *reward_rules = Zeroable::zeroed();
         *pending_config = Zeroable::zeroed();
         *_padding = Zeroable::zeroed();
+        *_padding1 = Zeroable::zeroed();
         *_flags = Zeroable::zeroed();
 
         Ok(())
@@ -221,6 +353,40 @@ impl Reserve {
         Ok(lp_amount)
     }
 
+    /// Record Curator's and Texture's parts of liquidation bonus. LP tokens stay in collateral supply
+    /// till claimed.
+    pub fn accrue_liquidation_fees(
+        &mut self,
+        curator_fee: u64,
+        texture_fee: u64,
+    ) -> LendyResult<()> {
+        self.curator_liquidation_fee = self
+            .curator_liquidation_fee
+            .checked_add(curator_fee)
+            .ok_or(MathError(format!(
+                "accrue_liquidation_fees(): checked_add {} + {}",
+                self.curator_liquidation_fee, curator_fee
+            )))?;
+        self.texture_liquidation_fee = self
+            .texture_liquidation_fee
+            .checked_add(texture_fee)
+            .ok_or(MathError(format!(
+                "accrue_liquidation_fees(): checked_add {} + {}",
+                self.texture_liquidation_fee, texture_fee
+            )))?;
+        Ok(())
+    }
+
+    /// Returns LP amount to be transferred to Curator and resets accrued value
+    pub fn claim_curator_liquidation_fee(&mut self) -> u64 {
+        std::mem::take(&mut self.curator_liquidation_fee)
+    }
+
+    /// Returns LP amount to be transferred to Texture and resets accrued value
+    pub fn claim_texture_liquidation_fee(&mut self) -> u64 {
+        std::mem::take(&mut self.texture_liquidation_fee)
+    }
+
     /// Record redeemed LPs and return amount of liquidity to withdraw
     pub fn withdraw_liquidity(&mut self, lp_amount: u64) -> LendyResult<u64> {
         let collateral_exchange_rate = self.lp_exchange_rate()?;
@@ -232,15 +398,192 @@ impl Reserve {
         Ok(liquidity_amount)
     }
 
-    /// Calculate the current borrow rate.
-    pub fn current_borrow_rate(&self, curve: &Curve) -> LendyResult<Decimal> {
-        Ok(current_borrow_rate(
-            &curve.y[..curve.y_count as usize],
-            curve.decimals,
-            curve.x_step,
-            Decimal::from_i128_with_scale(curve.x0 as i128, 0)?,
-            self.liquidity.utilization_rate()?,
-        )?)
+    /// Liquidity amount `lp_amount` LP tokens can be redeemed for right away. Returns None when
+    /// Reserve retains its liquidity or has not enough of it available. Unlike WithdrawLiquidity
+    /// `max_withdraw_utilization_bps` is not taken into account as that limit exists to keep
+    /// liquidity for liquidators. Non-liquidation callers must check it after the withdrawal.
+    pub fn redeemable_liquidity(&self, lp_amount: u64) -> LendyResult<Option<u64>> {
+        if self.mode == RESERVE_MODE_RETAIN_LIQUIDITY {
+            return Ok(None);
+        }
+
+        let liquidity_amount = self.lp_exchange_rate()?.lp_to_liquidity(lp_amount)?;
+        if liquidity_amount == 0 || liquidity_amount > self.liquidity.available_amount {
+            return Ok(None);
+        }
+
+        Ok(Some(liquidity_amount))
+    }
+
+    /// Record LPs burned to repay `settle_amount` of debt. Liquidity stays in the supply - it is
+    /// just owned by remaining LPs instead of the borrower. Thus no available liquidity needed.
+    pub fn repay_with_collateral(
+        &mut self,
+        lp_amount: u64,
+        settle_amount: Decimal,
+    ) -> LendyResult<()> {
+        self.collateral.burn(lp_amount)?;
+        self.liquidity.repay(0, settle_amount)?;
+
+        Ok(())
+    }
+
+    /// Reconcile Reserve's liquidity with actual balance of its liquidity supply wallet. Supply
+    /// is expected to hold `available_amount` less claimed performance fees. Surplus (e.g. tokens
+    /// transferred to the supply directly) is accounted according to `surplus_destination`.
+    /// Supply balance less then expected means broken accounting. Returns surplus amount.
+    pub fn sync_supply(&mut self, supply_amount: u64) -> LendyResult<u64> {
+        if self.liquidity.fee_claims_tracked == 0 {
+            // Reserve was created before claims were counted. All the difference between
+            // available amount and supply balance is considered as fees claimed so far.
+            self.liquidity.claimed_performance_fee = self
+                .liquidity
+                .available_amount
+                .saturating_sub(supply_amount);
+            self.liquidity.fee_claims_tracked = 1;
+        }
+
+        let expected_amount = self
+            .liquidity
+            .available_amount
+            .checked_sub(self.liquidity.claimed_performance_fee)
+            .ok_or(MathError(format!(
+                "sync_supply(): checked_sub {} - {}",
+                self.liquidity.available_amount, self.liquidity.claimed_performance_fee
+            )))?;
+        if supply_amount < expected_amount {
+            msg!(
+                "Liquidity supply balance {} is less then expected amount {}",
+                supply_amount,
+                expected_amount
+            );
+            return Err(SuperLendyError::SupplyDeficit(
+                supply_amount,
+                expected_amount,
+            ));
+        }
+
+        let surplus_amount = supply_amount - expected_amount;
+        if surplus_amount == 0 {
+            return Ok(0);
+        }
+
+        match self.surplus_destination {
+            SURPLUS_TO_LPS => self.liquidity.deposit(surplus_amount)?,
+            SURPLUS_TO_CURATOR => {
+                self.liquidity.deposit(surplus_amount)?;
+                self.liquidity.set_curator_performance_fee(
+                    self.liquidity.curator_performance_fee()?.checked_add(
+                        Decimal::from_lamports(surplus_amount, self.liquidity.mint_decimals)?,
+                    )?,
+                )?;
+            }
+            _ => {
+                msg!("Unknown surplus destination {}", self.surplus_destination);
+                return Err(SuperLendyError::InvalidAccountData);
+            }
+        }
+
+        Ok(surplus_amount)
+    }
+
+    /// Functions to read and set Decimal amounts which are i128 inside
+    pub fn isolated_debt(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.isolated_debt).map_err(From::from)
+    }
+
+    pub fn set_isolated_debt(&mut self, value: Decimal) -> LendyResult<()> {
+        self.isolated_debt = value.into_bits()?;
+        Ok(())
+    }
+
+    /// Current rate at target utilization of adaptive IRM. Model's initial rate is used till first
+    /// adaptation.
+    pub fn adaptive_rate_at_target(&self, adaptive_irm: &AdaptiveIrm) -> LendyResult<Decimal> {
+        let rate_at_target = Decimal::from_bits(self.adaptive_rate_at_target)?;
+        if rate_at_target == Decimal::ZERO {
+            Ok(Decimal::from_basis_points(
+                adaptive_irm.initial_rate_at_target_bps,
+            )?)
+        } else {
+            Ok(rate_at_target)
+        }
+    }
+
+    pub fn set_adaptive_rate_at_target(&mut self, value: Decimal) -> LendyResult<()> {
+        self.adaptive_rate_at_target = value.into_bits()?;
+        Ok(())
+    }
+
+    /// Sets new config. Adaptive IRM state is reset when interest rate model changes thus new model
+    /// starts from its initial rate.
+    pub fn set_config(&mut self, config: ReserveConfig) -> LendyResult<()> {
+        if config.irm_kind != self.config.irm_kind || config.irm != self.config.irm {
+            self.set_adaptive_rate_at_target(Decimal::ZERO)?;
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    /// Account new debt of `value` (in quote currency) backed by this isolated collateral Reserve.
+    pub fn increase_isolated_debt(&mut self, value: Decimal) -> LendyResult<()> {
+        let isolated_debt = self.isolated_debt()?.checked_add(value)?;
+
+        if self.isolated_debt_ceiling > 0
+            && isolated_debt > Decimal::from_i128_with_scale(self.isolated_debt_ceiling as i128, 0)?
+        {
+            msg!(
+                "Borrow results in isolated debt {} which is greater then ceiling {}",
+                isolated_debt,
+                self.isolated_debt_ceiling
+            );
+            return Err(SuperLendyError::ResourceExhausted);
+        }
+
+        self.set_isolated_debt(isolated_debt)
+    }
+
+    /// Release debt of `value` (in quote currency) backed by this isolated collateral Reserve.
+    /// Debt is accounted by market value thus repaid value can be greater than the one accounted
+    /// on Borrow (e.g. when price of borrowed token grows). Isolated debt never goes below zero.
+    pub fn decrease_isolated_debt(&mut self, value: Decimal) -> LendyResult<()> {
+        let isolated_debt = self.isolated_debt()?;
+        if value >= isolated_debt {
+            self.set_isolated_debt(Decimal::ZERO)
+        } else {
+            self.set_isolated_debt(isolated_debt.checked_sub(value)?)
+        }
+    }
+
+    /// Calculate the current borrow rate. `curve` is required when Reserve uses external IRM.
+    /// Rate given by IRM is clamped by Reserve's `min_borrow_rate_bps` and `max_borrow_rate_bps`.
+    pub fn current_borrow_rate(&self, curve: Option<&Curve>) -> LendyResult<Decimal> {
+        self.config.clamp_borrow_rate(self.irm_borrow_rate(curve)?)
+    }
+
+    /// Borrow rate given by Reserve's interest rate model as is
+    fn irm_borrow_rate(&self, curve: Option<&Curve>) -> LendyResult<Decimal> {
+        match self.config.interest_rate_model()? {
+            InterestRateModel::Curve(_) => {
+                let curve = curve.ok_or(SuperLendyError::Internal(
+                    "Curve is required to calculate borrow rate".to_string(),
+                ))?;
+                Ok(current_borrow_rate(
+                    &curve.y[..curve.y_count as usize],
+                    curve.decimals,
+                    curve.x_step,
+                    Decimal::from_i128_with_scale(curve.x0 as i128, 0)?,
+                    self.liquidity.utilization_rate()?,
+                )?)
+            }
+            InterestRateModel::Kinked(kinked_irm) => {
+                Ok(kinked_irm.borrow_rate(self.liquidity.utilization_rate()?)?)
+            }
+            InterestRateModel::Adaptive(adaptive_irm) => Ok(adaptive_irm.borrow_rate(
+                self.adaptive_rate_at_target(&adaptive_irm)?,
+                self.liquidity.utilization_rate()?,
+            )?),
+        }
     }
 
     /// Collateral exchange rate
@@ -264,6 +607,8 @@ impl Reserve {
                 self.liquidity.mint_decimals,
             )?,
             self.liquidity.market_price()?,
+            self.liquidity.mint_decimals,
+            self.collateral.virtual_offsets == 1,
         )?)
     }
 
@@ -274,7 +619,7 @@ impl Reserve {
                 self.liquidity.available_amount,
                 self.liquidity.mint_decimals,
             )?,
-            self.liquidity.borrowed_amount()?,
+            self.liquidity.total_borrowed_amount()?,
             Decimal::from_basis_points(self.config.max_withdraw_utilization_bps as u32)?,
         )?)
     }
@@ -289,43 +634,272 @@ impl Reserve {
     }
 
     /// Amount of liquidity can be borrowed from the Reserve
-    /// There are two limiting factors:
+    /// There are three limiting factors:
     /// 1. available liquidity
     /// 2. Reserve's max_borrow_utilization
+    /// 3. Reserve's max_total_borrow
     pub fn max_borrow_amount(&self) -> LendyResult<Decimal> {
-        self.liquidity
-            .max_borrow_amount(self.config.max_borrow_utilization_bps)
+        let max_borrow_amount = self
+            .liquidity
+            .max_borrow_amount(self.config.max_borrow_utilization_bps)?;
+
+        match self.remaining_borrow_capacity()? {
+            Some(remaining_capacity) => Ok(max_borrow_amount.min(remaining_capacity)),
+            None => Ok(max_borrow_amount),
+        }
     }
 
     /// Value of liquidity can be borrowed from the Reserve
     pub fn max_borrow_value(&self) -> LendyResult<Decimal> {
         Ok(self
-            .liquidity
-            .max_borrow_amount(self.config.max_borrow_utilization_bps)?
+            .max_borrow_amount()?
             .checked_mul(self.liquidity.market_price()?)?)
     }
 
+    /// Amount of liquidity which can be borrowed till `max_total_borrow` limit is reached.
+    /// Returns `None` when Reserve's borrows are not limited.
+    pub fn remaining_borrow_capacity(&self) -> LendyResult<Option<Decimal>> {
+        if self.config.max_total_borrow == 0 {
+            return Ok(None);
+        }
+
+        let max_total_borrow =
+            Decimal::from_lamports(self.config.max_total_borrow, self.liquidity.mint_decimals)?;
+        let borrowed_amount = self.liquidity.total_borrowed_amount()?;
+
+        if borrowed_amount >= max_total_borrow {
+            Ok(Some(Decimal::ZERO))
+        } else {
+            Ok(Some(max_total_borrow.checked_sub(borrowed_amount)?))
+        }
+    }
+
+    /// Migrates Reserve account data written by previous program version (see [Reserve::migrate])
+    /// and unpacks it. All instructions which modify Reserve unpack it this way.
+    pub fn unpack_mut(data: &mut [u8]) -> LendyResult<&mut Self> {
+        Self::migrate(data)?;
+        Ok(Self::try_from_bytes_mut(data)?)
+    }
+
+    /// Unpacks Reserve account data for reading. Data of current version is used in place. Data
+    /// written by previous program version is migrated into `migrated` buffer so instructions which
+    /// only read Reserve accept not yet migrated accounts too. The buffer is allocated once and may
+    /// be reused for several reserves as heap memory is never freed.
+    pub fn unpack<'a>(
+        data: &'a [u8],
+        migrated: &'a mut Option<Box<Self>>,
+    ) -> LendyResult<&'a Self> {
+        if data.len() != Self::SIZE
+            || !data.starts_with(RESERVE_DISCRIMINATOR)
+            || data[std::mem::offset_of!(Reserve, version)] == Self::VERSION
+        {
+            return Ok(Self::try_from_bytes(data)?);
+        }
+
+        let migrated = migrated.get_or_insert_with(bytemuck::allocation::zeroed_box);
+        let migrated_data = bytemuck::bytes_of_mut(migrated.as_mut());
+        migrated_data.copy_from_slice(data);
+        Self::migrate_from(data, migrated_data)?;
+
+        Ok(&**migrated)
+    }
+
+    /// Upgrades Reserve account data written by previous program version in place. Must be called
+    /// before the data is unpacked as current [`PodAccount::VERSION`] is expected there.
+    ///
+    /// Version 1 -> 2: interest accrual switched from slots to seconds. Layout is the same and
+    /// `last_update.timestamp` was always maintained, so interest for the period since last refresh
+    /// is just accrued by elapsed seconds.
+    ///
+    /// Version 2 -> 3: ReserveConfig grew by [RESERVE_CONFIG_V3_GROWTH] bytes. Reserve's config and
+    /// configs of all proposals are moved apart to give room for new (zeroed) fields. Subsequent
+    /// fields are shifted by taking space from the tail padding. `max_total_borrow` kept by version
+    /// 2 in the tail of fees is moved to its place in grown part of the config.
+    pub fn migrate(data: &mut [u8]) -> LendyResult<()> {
+        if data.len() != Self::SIZE || !data.starts_with(RESERVE_DISCRIMINATOR) {
+            // Let unpacking report the error
+            return Ok(());
+        }
+
+        if data[std::mem::offset_of!(Reserve, version)] == Self::VERSION {
+            return Ok(());
+        }
+
+        let old_data = data.to_vec();
+        Self::migrate_from(&old_data, data)
+    }
+
+    /// Writes migrated `old_data` to `data`. `data` must hold a copy of `old_data`.
+    fn migrate_from(old_data: &[u8], data: &mut [u8]) -> LendyResult<()> {
+        let version = old_data[std::mem::offset_of!(Reserve, version)];
+        match version {
+            1 | 2 => {
+                msg!("migrate reserve version {} -> {}", version, Self::VERSION);
+                Self::grow_configs(old_data, data);
+                data[std::mem::offset_of!(Reserve, version)] = Self::VERSION;
+                Ok(())
+            }
+            actual => Err(SerializeError::VersionMismatch {
+                expected: Self::VERSION,
+                actual,
+            }
+            .into()),
+        }
+    }
+
+    /// Inserts [RESERVE_CONFIG_V3_GROWTH] zero bytes after Reserve's config and after config of
+    /// each proposal. `old_data` must be in version 2 layout.
+    fn grow_configs(old_data: &[u8], data: &mut [u8]) {
+        let old_config_size = std::mem::size_of::<ReserveConfig>() - RESERVE_CONFIG_V3_GROWTH;
+        let old_proposal_size = std::mem::offset_of!(ConfigProposal, config) + old_config_size;
+
+        // (length, grows) of data segments starting from Reserve's config
+        let mut segments = vec![
+            (old_config_size, true),
+            (std::mem::size_of::<RewardRules>(), false),
+        ];
+        segments.extend([(old_proposal_size, true); MAX_CONFIG_PROPOSALS]);
+
+        let mut old_offset = std::mem::offset_of!(Reserve, config);
+        let mut new_offset = old_offset;
+        for (len, grows) in segments {
+            data[new_offset..new_offset + len]
+                .copy_from_slice(&old_data[old_offset..old_offset + len]);
+            old_offset += len;
+            new_offset += len;
+            if grows {
+                data[new_offset..new_offset + RESERVE_CONFIG_V3_GROWTH].fill(0);
+                new_offset += RESERVE_CONFIG_V3_GROWTH;
+            }
+        }
+
+        // Remaining fields. Dropped tail of old padding is unused.
+        let len = Self::SIZE - new_offset;
+        data[new_offset..].copy_from_slice(&old_data[old_offset..old_offset + len]);
+
+        let mut config_offsets = vec![std::mem::offset_of!(Reserve, config)];
+        config_offsets.extend((0..MAX_CONFIG_PROPOSALS).map(|index| {
+            std::mem::offset_of!(Reserve, proposed_configs)
+                + index * std::mem::size_of::<ConfigProposal>()
+                + std::mem::offset_of!(ConfigProposal, config)
+        }));
+        for config_offset in config_offsets {
+            let old = config_offset + RESERVE_CONFIG_V2_MAX_TOTAL_BORROW_OFFSET;
+            let new = config_offset + std::mem::offset_of!(ReserveConfig, max_total_borrow);
+            data.copy_within(old..old + 8, new);
+            data[old..old + 8].fill(0);
+        }
+    }
+
     /// Update borrow rate and accrue interest
     pub fn accrue_interest(
         &mut self,
-        current_slot: Slot,
+        current_timestamp: UnixTimestamp,
         texture_performance_fee_rate_bps: u16,
-        irm: &Curve,
+        irm: Option<&Curve>,
     ) -> LendyResult<()> {
-        let slots_elapsed = self.last_update.slots_elapsed(current_slot)?;
-        if slots_elapsed > 0 {
+        let seconds_elapsed = self.last_update.seconds_elapsed(current_timestamp)?;
+        if seconds_elapsed > 0 {
+            // Adaptive IRM drifts according to utilization during elapsed time. Rate moves
+            // gradually thus interest accrues at the average of the rates at start and end of the
+            // period.
+            let accrual_borrow_rate = if let InterestRateModel::Adaptive(adaptive_irm) =
+                self.config.interest_rate_model()?
+            {
+                let start_borrow_rate = self.current_borrow_rate(irm)?;
+                let rate_at_target = adaptive_irm.adapt_rate_at_target(
+                    self.adaptive_rate_at_target(&adaptive_irm)?,
+                    self.liquidity.utilization_rate()?,
+                    seconds_elapsed,
+                )?;
+                self.set_adaptive_rate_at_target(rate_at_target)?;
+
+                start_borrow_rate
+                    .checked_add(self.current_borrow_rate(irm)?)?
+                    .checked_div(Decimal::from_i128_with_scale(2, 0)?)?
+            } else {
+                self.current_borrow_rate(irm)?
+            };
+
             let current_borrow_rate = self.current_borrow_rate(irm)?;
-            self.liquidity.compound_interest(
-                current_borrow_rate,
+            let variable_interest = self.liquidity.compound_interest(
+                accrual_borrow_rate,
+                self.config.fees.curator_performance_fee_rate_bps,
+                texture_performance_fee_rate_bps,
+                seconds_elapsed,
+            )?;
+            let fixed_interest = self.liquidity.accrue_fixed_interest(
                 self.config.fees.curator_performance_fee_rate_bps,
                 texture_performance_fee_rate_bps,
-                slots_elapsed,
+                seconds_elapsed,
+            )?;
+            self.liquidity.accrue_insurance_fee(
+                variable_interest.checked_add(fixed_interest)?,
+                self.config.fees.insurance_fee_rate_bps,
             )?;
             self.liquidity.set_borrow_rate(current_borrow_rate).ok();
+
+            let current_supply_rate = self.liquidity.current_supply_rate(
+                current_borrow_rate,
+                self.config.fees.curator_performance_fee_rate_bps,
+                texture_performance_fee_rate_bps,
+                self.config.fees.insurance_fee_rate_bps,
+            )?;
+            self.liquidity.set_supply_rate(current_supply_rate).ok();
+        }
+        Ok(())
+    }
+
+    /// Saves current rates in to `rate_snapshots` ring buffer when no snapshot was taken during
+    /// current day (see RATE_SNAPSHOT_INTERVAL_SEC). Replaces the oldest snapshot when buffer is
+    /// full.
+    pub fn take_rate_snapshot(&mut self, current_timestamp: UnixTimestamp) -> LendyResult<()> {
+        let day = u16::try_from(current_timestamp / RATE_SNAPSHOT_INTERVAL_SEC).map_err(|_| {
+            SuperLendyError::MathError(MathError(format!(
+                "rate snapshot day of timestamp {} doesn't fit u16",
+                current_timestamp
+            )))
+        })?;
+        if let Some(latest) = self.latest_rate_snapshot() {
+            if day <= latest.day {
+                return Ok(());
+            }
         }
+
+        let snapshot = RateSnapshot {
+            day,
+            utilization_bps: snapshot_bps(self.liquidity.utilization_rate()?)?.min(u16::MAX as u32)
+                as u16,
+            borrow_rate_bps: snapshot_bps(self.liquidity.borrow_rate()?)?,
+            supply_rate_bps: snapshot_bps(self.liquidity.supply_rate()?)?,
+        };
+
+        let index = self.rate_snapshot_index as usize % MAX_RATE_SNAPSHOTS;
+        self.rate_snapshots[index] = snapshot;
+        self.rate_snapshot_index = ((index + 1) % MAX_RATE_SNAPSHOTS) as u8;
+
         Ok(())
     }
 
+    /// Most recent rate snapshot if any
+    pub fn latest_rate_snapshot(&self) -> Option<RateSnapshot> {
+        let index =
+            (self.rate_snapshot_index as usize + MAX_RATE_SNAPSHOTS - 1) % MAX_RATE_SNAPSHOTS;
+        let snapshot = self.rate_snapshots[index];
+        (snapshot.day != 0).then_some(snapshot)
+    }
+
+    /// Taken rate snapshots from the oldest to the most recent one
+    pub fn rate_snapshots(&self) -> Vec<RateSnapshot> {
+        (0..MAX_RATE_SNAPSHOTS)
+            .map(|offset| {
+                self.rate_snapshots
+                    [(self.rate_snapshot_index as usize + offset) % MAX_RATE_SNAPSHOTS]
+            })
+            .filter(|snapshot| snapshot.day != 0)
+            .collect()
+    }
+
     /// Borrow liquidity up to a maximum market value
     /// `amount_to_borrow` - amount in Lamports (or equivalent for other tokens) user wants to borrow
     /// `max_borrow_value` - limit in value (usually $) to borrow i.e. we ask the function "please,
@@ -338,13 +912,17 @@ impl Reserve {
         texture_borrow_fee_rate_bps: u16,
     ) -> LendyResult<CalculateBorrowResult> {
         if amount_to_borrow == MAX_AMOUNT {
-            let borrow_amount = max_borrow_value
+            let mut borrow_amount = max_borrow_value
                 .checked_div(self.liquidity.market_price()?)?
                 .min(Decimal::from_lamports(
                     self.liquidity.available_amount,
                     self.liquidity.mint_decimals,
                 )?);
 
+            if let Some(remaining_capacity) = self.remaining_borrow_capacity()? {
+                borrow_amount = borrow_amount.min(remaining_capacity);
+            }
+
             let (curator_borrow_fee, texture_borrow_fee) = self.config.fees.calculate_borrow_fees(
                 borrow_amount,
                 self.liquidity.mint_decimals,
@@ -399,6 +977,18 @@ impl Reserve {
                 return Err(SuperLendyError::BorrowTooLarge);
             }
 
+            if let Some(remaining_capacity) = self.remaining_borrow_capacity()? {
+                if borrow_amount_wad > remaining_capacity {
+                    msg!(
+                        "Borrow amount {} exceeds remaining capacity {} of max_total_borrow {}",
+                        borrow_amount_wad,
+                        remaining_capacity,
+                        self.config.max_total_borrow
+                    );
+                    return Err(SuperLendyError::ResourceExhausted);
+                }
+            }
+
             Ok(CalculateBorrowResult {
                 borrow_amount: borrow_amount_wad,
                 receive_amount,
@@ -428,6 +1018,34 @@ impl Reserve {
         })
     }
 
+    /// Repay liquidity up to the borrowed amount with LP tokens of the same Reserve. Repaid amount
+    /// is capped by liquidity `collateral_amount` LPs are worth. LP amount is rounded up thus the
+    /// Reserve never settles more debt than burned LPs are worth.
+    pub fn calculate_repay_with_collateral(
+        &self,
+        amount_to_repay: u64,
+        borrowed_amount: Decimal,
+        collateral_amount: u64,
+    ) -> LendyResult<CalculateRepayWithCollateralResult> {
+        let exchange_rate = self.lp_exchange_rate()?;
+        let max_repay_amount = exchange_rate.lp_to_liquidity(collateral_amount)?;
+
+        let CalculateRepayResult {
+            settle_amount,
+            repay_amount,
+        } = self.calculate_repay(amount_to_repay.min(max_repay_amount), borrowed_amount)?;
+
+        let lp_amount = exchange_rate
+            .decimal_liquidity_to_lp(Decimal::from_i128_with_scale(repay_amount as i128, 0)?)?
+            .to_lamports_ceil(0)?; // This is just a math with amounts in lamport from
+
+        Ok(CalculateRepayWithCollateralResult {
+            settle_amount,
+            repay_amount,
+            lp_amount,
+        })
+    }
+
     /// Liquidate some or all of an unhealthy position
     /// There are two liquidation levels:
     /// 1. When reserve.config.partly_unhealthy_ltv >= positions.LTV > reserve.config.fully_unhealthy_ltv - in this case we
@@ -442,7 +1060,8 @@ impl Reserve {
         collateral: &DepositedCollateral,
         principal_mint_decimals: u8,
     ) -> Result<CalculateLiquidationResult, SuperLendyError> {
-        let position_ltv = position.ltv()?;
+        // Borrow weights make the position's risk-adjusted LTV higher than the plain one
+        let position_ltv = position.weighted_ltv()?;
         let partly_unhealthy_ltv =
             Decimal::from_basis_points(self.config.partly_unhealthy_ltv_bps as u32)?;
         let fully_unhealthy_ltv =
@@ -456,17 +1075,58 @@ impl Reserve {
             ));
         }
 
-        let bonus_rate = Decimal::from_basis_points(self.config.liquidation_bonus_bps as u32)?
-            .checked_add(Decimal::ONE)?;
-
-        let reserve_max_liquidation_amount = borrowed_liquidity.borrowed_amount()?;
-
         let liquidation_close_factor = if position_ltv >= fully_unhealthy_ltv {
             Decimal::ONE // Allow to liquidate full borrowed amount at once
         } else {
             Decimal::from_basis_points(self.config.partial_liquidation_factor_bps as u32)?
         };
 
+        self.calculate_liquidation_amounts(
+            amount_to_liquidate,
+            position,
+            borrowed_liquidity,
+            collateral,
+            principal_mint_decimals,
+            liquidation_close_factor,
+        )
+    }
+
+    /// Same as `calculate_liquidation` but for fixed-rate loan which is not repaid after maturity
+    /// and grace period. Such loan can be liquidated in full regardless of Position health.
+    pub fn calculate_overdue_liquidation(
+        &self,
+        amount_to_liquidate: u64,
+        position: &Position,
+        loan: &FixedLoan,
+        collateral: &DepositedCollateral,
+        principal_mint_decimals: u8,
+    ) -> Result<CalculateLiquidationResult, SuperLendyError> {
+        self.calculate_liquidation_amounts(
+            amount_to_liquidate,
+            position,
+            &loan.liquidity,
+            collateral,
+            principal_mint_decimals,
+            Decimal::ONE,
+        )
+    }
+
+    fn calculate_liquidation_amounts(
+        &self,
+        amount_to_liquidate: u64,
+        position: &Position,
+        borrowed_liquidity: &BorrowedLiquidity,
+        collateral: &DepositedCollateral,
+        principal_mint_decimals: u8,
+        liquidation_close_factor: Decimal,
+    ) -> Result<CalculateLiquidationResult, SuperLendyError> {
+        let bonus = self
+            .config
+            .liquidation_bonus(position.weighted_ltv()?, position.ltv()?)?;
+        let bonus_rate = bonus.checked_add(Decimal::ONE)?;
+
+        let reserve_max_liquidation_amount = borrowed_liquidity.borrowed_amount()?;
+
         let position_max_liquidation_amount =
             position.max_liquidation_amount(borrowed_liquidity, liquidation_close_factor)?;
 
@@ -530,10 +1190,16 @@ impl Reserve {
             }
         }
 
+        let bonus_amount = Decimal::from_lamports(withdraw_amount, self.liquidity.mint_decimals)?
+            .checked_mul(bonus)?
+            .checked_div(bonus_rate)?
+            .to_lamports_floor(self.liquidity.mint_decimals)?;
+
         Ok(CalculateLiquidationResult {
             settle_amount,
             repay_amount,
             withdraw_amount,
+            bonus_amount,
         })
     }
 
@@ -590,6 +1256,17 @@ pub struct CalculateRepayResult {
     pub repay_amount: u64,
 }
 
+/// Calculate repay with collateral result
+#[derive(Debug)]
+pub struct CalculateRepayWithCollateralResult {
+    /// Amount of liquidity that is settled from the position.
+    pub settle_amount: Decimal,
+    /// Amount of liquidity repaid as u64
+    pub repay_amount: u64,
+    /// Amount of LP tokens to burn from position's collateral
+    pub lp_amount: u64,
+}
+
 /// Calculate liquidation result
 #[derive(Debug)]
 pub struct CalculateLiquidationResult {
@@ -600,6 +1277,8 @@ pub struct CalculateLiquidationResult {
     pub repay_amount: u64,
     /// Amount of collateral to withdraw in exchange for repay amount
     pub withdraw_amount: u64,
+    /// Part of `withdraw_amount` which is liquidation bonus
+    pub bonus_amount: u64,
 }
 
 /// Reserve liquidity
@@ -625,12 +1304,31 @@ pub struct ReserveLiquidity {
 
     /// Reserve liquidity available. Measured in smallest token unit (e.g. lamports).
     pub available_amount: u64,
-    pub _padding: u64,
+    /// Total amount of performance fees claimed from the reserve. Claimed fees leave liquidity
+    /// supply but stay in `available_amount`.
+    pub claimed_performance_fee: u64,
 
     /// Reserve liquidity mint decimals
     pub mint_decimals: u8,
+    /// 1 when `claimed_performance_fee` counts all claims since reserve creation. Reserves
+    /// created before the counter was introduced have 0 here till the first SyncReserve.
+    pub fee_claims_tracked: u8,
+
+    pub _padding1: [u8; 14],
 
-    pub _padding1: [u8; 15 + 32 * 2],
+    /// Liquidity borrowed via fixed-rate loans plus their interest. Not included in
+    /// `borrowed_amount` as it doesn't follow `cumulative_borrow_rate`. WAD.
+    pub fixed_borrowed_amount: i128,
+    /// Sum of annual interest of all fixed-rate loans i.e. `fixed_borrowed_amount` grows by that
+    /// value in a year. WAD.
+    pub fixed_interest_per_year: i128,
+
+    /// This is last time calculated yearly rate LPs earn (net of performance fees). As
+    /// `borrow_rate` it is not used by the contract and here for off-chain apps.
+    pub supply_rate: i128,
+
+    /// Insurance fee amount which is to be transferred to Pool's insurance fund. WAD
+    pub insurance_fee: i128,
 }
 
 impl ReserveLiquidity {
@@ -644,9 +1342,14 @@ impl ReserveLiquidity {
             cumulative_borrow_rate: Decimal::ONE.into_bits().unwrap(),
             market_price: Decimal::ZERO.into_bits().unwrap(),
             mint_decimals,
+            fee_claims_tracked: 1,
             _padding1: Zeroable::zeroed(),
-            _padding: 0,
+            claimed_performance_fee: 0,
             borrow_rate: Decimal::ZERO.into_bits().unwrap(),
+            fixed_borrowed_amount: Decimal::ZERO.into_bits().unwrap(),
+            fixed_interest_per_year: Decimal::ZERO.into_bits().unwrap(),
+            supply_rate: Decimal::ZERO.into_bits().unwrap(),
+            insurance_fee: Decimal::ZERO.into_bits().unwrap(),
         }
     }
 
@@ -696,6 +1399,15 @@ impl ReserveLiquidity {
         Ok(())
     }
 
+    pub fn insurance_fee(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.insurance_fee).map_err(From::from)
+    }
+
+    pub fn set_insurance_fee(&mut self, value: Decimal) -> LendyResult<()> {
+        self.insurance_fee = value.into_bits()?;
+        Ok(())
+    }
+
     pub fn borrow_rate(&self) -> LendyResult<Decimal> {
         Decimal::from_bits(self.borrow_rate).map_err(From::from)
     }
@@ -705,12 +1417,69 @@ impl ReserveLiquidity {
         Ok(())
     }
 
-    /// Calculate the total reserve supply including active loans
+    pub fn supply_rate(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.supply_rate).map_err(From::from)
+    }
+
+    pub fn set_supply_rate(&mut self, value: Decimal) -> LendyResult<()> {
+        self.supply_rate = value.into_bits()?;
+        Ok(())
+    }
+
+    /// Yearly rate LPs earn when variable rate borrowings pay `borrow_rate`. Interest of fixed-rate
+    /// loans is included too.
+    pub fn current_supply_rate(
+        &self,
+        borrow_rate: Decimal,
+        curator_performance_fee_rate_bps: u16,
+        texture_performance_fee_rate_bps: u16,
+        insurance_fee_rate_bps: u16,
+    ) -> LendyResult<Decimal> {
+        Ok(supply_rate(
+            self.borrowed_amount()?,
+            borrow_rate,
+            self.fixed_interest_per_year()?,
+            self.total_liquidity()?,
+            texture_performance_fee_rate_bps,
+            curator_performance_fee_rate_bps,
+            insurance_fee_rate_bps,
+        )?)
+    }
+
+    pub fn fixed_borrowed_amount(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.fixed_borrowed_amount).map_err(From::from)
+    }
+
+    pub fn set_fixed_borrowed_amount(&mut self, value: Decimal) -> LendyResult<()> {
+        self.fixed_borrowed_amount = value.into_bits()?;
+        Ok(())
+    }
+
+    pub fn fixed_interest_per_year(&self) -> LendyResult<Decimal> {
+        Decimal::from_bits(self.fixed_interest_per_year).map_err(From::from)
+    }
+
+    pub fn set_fixed_interest_per_year(&mut self, value: Decimal) -> LendyResult<()> {
+        self.fixed_interest_per_year = value.into_bits()?;
+        Ok(())
+    }
+
+    /// Liquidity borrowed with both variable and fixed rates
+    pub fn total_borrowed_amount(&self) -> LendyResult<Decimal> {
+        Ok(self
+            .borrowed_amount()?
+            .checked_add(self.fixed_borrowed_amount()?)?)
+    }
+
+    /// Calculate the total reserve supply including active loans. Insurance fee accrued but not
+    /// yet claimed belongs to Pool's insurance fund and thus is not the part of it.
     pub fn total_liquidity(&self) -> LendyResult<Decimal> {
         Ok(total_liquidity(
             Decimal::from_lamports(self.available_amount, self.mint_decimals)?,
-            self.borrowed_amount()?,
-        )?)
+            self.total_borrowed_amount()?,
+        )?
+        .checked_sub(self.insurance_fee()?)?
+        .max(Decimal::ZERO))
     }
 
     /// Add liquidity to available amount
@@ -766,14 +1535,78 @@ impl ReserveLiquidity {
         Ok(())
     }
 
-    /// Add repay amount to available liquidity and subtract settle amount from
-    /// total borrows
-    pub fn repay(&mut self, repay_amount: u64, settle_amount: Decimal) -> LendyResult<()> {
-        self.available_amount =
-            self.available_amount
-                .checked_add(repay_amount)
-                .ok_or(SuperLendyError::MathError(MathError(format!(
-                    "repay(): checked_add {} + {}",
+    /// Same as `borrow` but for fixed-rate loan.
+    /// `interest_per_year` - annual interest of the loan
+    pub fn borrow_fixed(
+        &mut self,
+        borrow_amount: Decimal,
+        borrowed_lamports: u64,
+        interest_per_year: Decimal,
+    ) -> LendyResult<()> {
+        if borrowed_lamports > self.available_amount {
+            msg!(
+                "Borrow amount {} cannot exceed available amount {}",
+                borrowed_lamports,
+                self.available_amount
+            );
+            return Err(SuperLendyError::InvalidAmount);
+        }
+
+        self.available_amount = self.available_amount.checked_sub(borrowed_lamports).ok_or(
+            SuperLendyError::MathError(MathError(format!(
+                "borrow_fixed(): checked_sub {} + {}",
+                self.available_amount, borrowed_lamports
+            ))),
+        )?;
+
+        self.set_fixed_borrowed_amount(self.fixed_borrowed_amount()?.checked_add(borrow_amount)?)?;
+        self.set_fixed_interest_per_year(
+            self.fixed_interest_per_year()?
+                .checked_add(interest_per_year)?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Same as `repay` but for fixed-rate loan.
+    /// `interest_per_year` - annual interest of the loan for settled amount
+    pub fn repay_fixed(
+        &mut self,
+        repay_amount: u64,
+        settle_amount: Decimal,
+        interest_per_year: Decimal,
+    ) -> LendyResult<()> {
+        self.available_amount =
+            self.available_amount
+                .checked_add(repay_amount)
+                .ok_or(SuperLendyError::MathError(MathError(format!(
+                    "repay_fixed(): checked_add {} + {}",
+                    self.available_amount, repay_amount
+                ))))?;
+
+        // Don't go negative due to rounding during last repay
+        self.set_fixed_borrowed_amount(
+            self.fixed_borrowed_amount()?
+                .checked_sub(settle_amount)?
+                .max(Decimal::ZERO),
+        )?;
+        self.set_fixed_interest_per_year(
+            self.fixed_interest_per_year()?
+                .checked_sub(interest_per_year)?
+                .max(Decimal::ZERO),
+        )?;
+
+        Ok(())
+    }
+
+    /// Add repay amount to available liquidity and subtract settle amount from
+    /// total borrows
+    pub fn repay(&mut self, repay_amount: u64, settle_amount: Decimal) -> LendyResult<()> {
+        self.available_amount =
+            self.available_amount
+                .checked_add(repay_amount)
+                .ok_or(SuperLendyError::MathError(MathError(format!(
+                    "repay(): checked_add {} + {}",
                     self.available_amount, repay_amount
                 ))))?;
 
@@ -792,8 +1625,11 @@ impl ReserveLiquidity {
     /// Calculate the liquidity utilization rate of the reserve
     pub fn utilization_rate(&self) -> LendyResult<Decimal> {
         Ok(liquidity_utilization_rate(
-            self.total_liquidity()?,
-            self.borrowed_amount()?,
+            total_liquidity(
+                Decimal::from_lamports(self.available_amount, self.mint_decimals)?,
+                self.total_borrowed_amount()?,
+            )?,
+            self.total_borrowed_amount()?,
         )?)
     }
 
@@ -805,7 +1641,7 @@ impl ReserveLiquidity {
         max_borrow_amount(
             self.available_amount,
             self.mint_decimals,
-            self.borrowed_amount()?,
+            self.total_borrowed_amount()?,
             max_borrow_utilization_bps,
         )
     }
@@ -822,6 +1658,13 @@ impl ReserveLiquidity {
             )?)?
             .max(Decimal::ZERO);
         self.set_curator_performance_fee(remaining_amount)?;
+        self.claimed_performance_fee = self
+            .claimed_performance_fee
+            .checked_add(claimable_amount)
+            .ok_or(MathError(format!(
+            "claim_curator_performance_fee(): checked_add {} + {}",
+            self.claimed_performance_fee, claimable_amount
+        )))?;
 
         Ok(claimable_amount)
     }
@@ -838,23 +1681,50 @@ impl ReserveLiquidity {
             )?)?
             .max(Decimal::ZERO);
         self.set_texture_performance_fee(remaining_amount)?;
+        self.claimed_performance_fee = self
+            .claimed_performance_fee
+            .checked_add(claimable_amount)
+            .ok_or(MathError(format!(
+            "claim_texture_performance_fee(): checked_add {} + {}",
+            self.claimed_performance_fee, claimable_amount
+        )))?;
+
+        Ok(claimable_amount)
+    }
+
+    /// Takes accrued insurance fee out of available liquidity. Only whole lamports are claimed
+    /// and no more than available liquidity. The rest stays accrued till next claim.
+    pub fn claim_insurance_fee(&mut self) -> LendyResult<u64> {
+        let claimable_amount = self
+            .insurance_fee()?
+            .to_lamports_floor(self.mint_decimals)?
+            .min(self.available_amount);
+        let remaining_amount = self
+            .insurance_fee()?
+            .checked_sub(Decimal::from_lamports(
+                claimable_amount,
+                self.mint_decimals,
+            )?)?
+            .max(Decimal::ZERO);
+        self.set_insurance_fee(remaining_amount)?;
+        self.withdraw(claimable_amount)?;
 
         Ok(claimable_amount)
     }
 
-    /// Compound current borrow rate over elapsed slots
+    /// Compound current borrow rate over elapsed seconds. Returns interest accrued.
     fn compound_interest(
         &mut self,
         current_borrow_rate: Decimal,
         curator_performance_fee_rate_bps: u16,
         texture_performance_fee_rate_bps: u16,
-        slots_elapsed: u64,
-    ) -> LendyResult<()> {
-        let slot_interest_rate = current_borrow_rate
-            .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0)?)?;
+        seconds_elapsed: u64,
+    ) -> LendyResult<Decimal> {
+        let second_interest_rate = current_borrow_rate
+            .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0)?)?;
         let compounded_interest_rate = Decimal::ONE
-            .checked_add(slot_interest_rate)?
-            .checked_pow(slots_elapsed)?;
+            .checked_add(second_interest_rate)?
+            .checked_pow(seconds_elapsed)?;
 
         // All decimals in that contract stored with 18 precision. And cumulative_borrow_rate_wads do so. Above calculated
         // `compounded_interest_rate` may have greater precision. We'll scale it to 18 to store same value in the sate as
@@ -877,32 +1747,133 @@ impl ReserveLiquidity {
         // the sum of position.borrowed_amount from all positions borrowed from the Reserve.
         // Just to repeat... reserve.borrowed_amount = liquidity_given_to_borrowers + interest.
 
-        // This is absolute increase of the reserve during elapsed slots. It is base for performance fee.
-        let interest_for_elapsed_slots =
+        // This is absolute increase of the reserve during elapsed time. It is base for performance fee.
+        let interest_for_elapsed_time =
             new_borrowed_amount_wads.checked_sub(self.borrowed_amount()?)?;
 
-        if interest_for_elapsed_slots != Decimal::ZERO && curator_performance_fee_rate_bps != 0 {
-            let fee_for_elapsed_slots = interest_for_elapsed_slots.checked_mul(
+        if interest_for_elapsed_time != Decimal::ZERO && curator_performance_fee_rate_bps != 0 {
+            let fee_for_elapsed_time = interest_for_elapsed_time.checked_mul(
                 Decimal::from_basis_points(curator_performance_fee_rate_bps as u32)?,
             )?;
             self.set_curator_performance_fee(
                 self.curator_performance_fee()?
-                    .checked_add(fee_for_elapsed_slots)?,
+                    .checked_add(fee_for_elapsed_time)?,
             )?;
         }
 
-        if interest_for_elapsed_slots != Decimal::ZERO && texture_performance_fee_rate_bps != 0 {
-            let fee_for_elapsed_slots = interest_for_elapsed_slots.checked_mul(
+        if interest_for_elapsed_time != Decimal::ZERO && texture_performance_fee_rate_bps != 0 {
+            let fee_for_elapsed_time = interest_for_elapsed_time.checked_mul(
                 Decimal::from_basis_points(texture_performance_fee_rate_bps as u32)?,
             )?;
             self.set_texture_performance_fee(
                 self.texture_performance_fee()?
-                    .checked_add(fee_for_elapsed_slots)?,
+                    .checked_add(fee_for_elapsed_time)?,
             )?;
         }
 
         self.set_borrowed_amount(new_borrowed_amount_wads)?;
 
+        Ok(interest_for_elapsed_time)
+    }
+
+    /// Accrue interest of fixed-rate loans over elapsed seconds. Their interest grows linearly
+    /// and is subject to the same performance fees as interest of variable rate borrowings.
+    /// Returns interest accrued.
+    fn accrue_fixed_interest(
+        &mut self,
+        curator_performance_fee_rate_bps: u16,
+        texture_performance_fee_rate_bps: u16,
+        seconds_elapsed: u64,
+    ) -> LendyResult<Decimal> {
+        let interest_for_elapsed_time = self
+            .fixed_interest_per_year()?
+            .checked_mul(Decimal::from_i128_with_scale(seconds_elapsed as i128, 0)?)?
+            .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0)?)?;
+
+        if interest_for_elapsed_time == Decimal::ZERO {
+            return Ok(Decimal::ZERO);
+        }
+
+        if curator_performance_fee_rate_bps != 0 {
+            let fee_for_elapsed_time = interest_for_elapsed_time.checked_mul(
+                Decimal::from_basis_points(curator_performance_fee_rate_bps as u32)?,
+            )?;
+            self.set_curator_performance_fee(
+                self.curator_performance_fee()?
+                    .checked_add(fee_for_elapsed_time)?,
+            )?;
+        }
+
+        if texture_performance_fee_rate_bps != 0 {
+            let fee_for_elapsed_time = interest_for_elapsed_time.checked_mul(
+                Decimal::from_basis_points(texture_performance_fee_rate_bps as u32)?,
+            )?;
+            self.set_texture_performance_fee(
+                self.texture_performance_fee()?
+                    .checked_add(fee_for_elapsed_time)?,
+            )?;
+        }
+
+        self.set_fixed_borrowed_amount(
+            self.fixed_borrowed_amount()?
+                .checked_add(interest_for_elapsed_time)?,
+        )?;
+
+        Ok(interest_for_elapsed_time)
+    }
+
+    /// Accrue part of the interest which goes to Pool's insurance fund
+    fn accrue_insurance_fee(
+        &mut self,
+        interest_for_elapsed_time: Decimal,
+        insurance_fee_rate_bps: u16,
+    ) -> LendyResult<()> {
+        if interest_for_elapsed_time == Decimal::ZERO || insurance_fee_rate_bps == 0 {
+            return Ok(());
+        }
+
+        let fee_for_elapsed_time = interest_for_elapsed_time
+            .checked_mul(Decimal::from_basis_points(insurance_fee_rate_bps as u32)?)?;
+        self.set_insurance_fee(self.insurance_fee()?.checked_add(fee_for_elapsed_time)?)?;
+
+        Ok(())
+    }
+
+    /// Add flash loan fee to available liquidity. Fee is a pool yield thus it is split the same
+    /// way as interest: Curator and Texture shares are accrued as performance fees and the rest
+    /// goes to LPs via increased total liquidity.
+    pub fn accrue_flash_loan_fee(
+        &mut self,
+        fee_amount: u64,
+        curator_performance_fee_rate_bps: u16,
+        texture_flash_loan_fee_share_bps: u16,
+    ) -> LendyResult<()> {
+        if fee_amount == 0 {
+            return Ok(());
+        }
+
+        self.deposit(fee_amount)?;
+
+        let fee = Decimal::from_lamports(fee_amount, self.mint_decimals)?;
+
+        if curator_performance_fee_rate_bps != 0 {
+            let curator_fee = fee.checked_mul(Decimal::from_basis_points(
+                curator_performance_fee_rate_bps as u32,
+            )?)?;
+            self.set_curator_performance_fee(
+                self.curator_performance_fee()?.checked_add(curator_fee)?,
+            )?;
+        }
+
+        if texture_flash_loan_fee_share_bps != 0 {
+            let texture_fee = fee.checked_mul(Decimal::from_basis_points(
+                texture_flash_loan_fee_share_bps as u32,
+            )?)?;
+            self.set_texture_performance_fee(
+                self.texture_performance_fee()?.checked_add(texture_fee)?,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -919,6 +1890,28 @@ impl ReserveLiquidity {
 
         Ok(())
     }
+
+    /// Same as `write_off_bad_debt` but for fixed-rate loan. Unlike `repay_fixed` no liquidity
+    /// comes back to the Reserve.
+    /// `interest_per_year` - annual interest of the loan for written off amount
+    pub fn write_off_fixed_bad_debt(
+        &mut self,
+        settle_amount: Decimal,
+        interest_per_year: Decimal,
+    ) -> LendyResult<()> {
+        self.set_fixed_borrowed_amount(
+            self.fixed_borrowed_amount()?
+                .checked_sub(settle_amount)?
+                .max(Decimal::ZERO),
+        )?;
+        self.set_fixed_interest_per_year(
+            self.fixed_interest_per_year()?
+                .checked_sub(interest_per_year)?
+                .max(Decimal::ZERO),
+        )?;
+
+        Ok(())
+    }
 }
 
 /// Reserve collateral
@@ -927,7 +1920,11 @@ impl ReserveLiquidity {
 pub struct ReserveCollateral {
     /// Reserve LP tokens supply, used for exchange rate
     pub lp_total_supply: u64,
-    pub _padding: u64,
+    /// 1 - virtual liquidity and LP amounts are added when LP exchange rate is calculated. Set for
+    /// Reserves created after virtual amounts were introduced. Exchange rate of older Reserves
+    /// stays as it was.
+    pub virtual_offsets: u8,
+    pub _padding: [u8; 7],
 }
 
 impl Default for ReserveCollateral {
@@ -941,7 +1938,8 @@ impl ReserveCollateral {
     pub fn new() -> Self {
         Self {
             lp_total_supply: 0,
-            _padding: 0,
+            virtual_offsets: 1,
+            _padding: [0; 7],
         }
     }
 
@@ -974,6 +1972,8 @@ impl ReserveCollateral {
         Ok(lp_exchange_rate(
             total_liquidity,
             Decimal::from_lamports(self.lp_total_supply, decimals)?,
+            decimals,
+            self.virtual_offsets == 1,
         )?)
     }
 }
@@ -1018,7 +2018,8 @@ impl From<LpExchangeRate> for Decimal {
 pub struct ReserveConfig {
     /// `Price proxy` price account for
     pub market_price_feed: Pubkey,
-    /// Interest rate model (IRM) account
+    /// Interest rate model (IRM). Depending on `irm_kind` this is either `curvy` Curve account
+    /// or inline [KinkedIrm] parameters. Use [ReserveConfig::interest_rate_model] to interpret.
     pub irm: Pubkey,
     /// Bonus a liquidator gets when repaying part of an unhealthy position,
     /// as a basis points - bps (0.01%)
@@ -1060,10 +2061,42 @@ pub struct ReserveConfig {
     /// The setting should be used to keep some available_liquidity in the Reserve to support
     /// liquidations.
     pub max_withdraw_utilization_bps: u16,
-    pub _padding: [u8; 6],
+
+    /// Risk weight (in basis points) of liquidity borrowed from this Reserve. Value of borrowing
+    /// is multiplied by that weight when Position's borrowing power and health are calculated.
+    /// Thus borrowing of volatile token consumes more of the borrowing power than borrowing of
+    /// stablecoin of the same value. 10_000 - borrowed value is taken as is.
+    /// 0 is treated as 10_000.
+    pub borrow_weight_bps: u16,
+
+    /// Liquidation bonus (in basis points) paid for positions at or beyond `fully_unhealthy_ltv`.
+    /// When set, `liquidation_bonus_bps` is the bonus paid at `partly_unhealthy_ltv` and actual bonus
+    /// grows linearly with position's LTV between the two. Bonus is additionally capped so that
+    /// liquidation never takes more collateral value than the position has.
+    /// 0 - dynamic bonus is off and flat `liquidation_bonus_bps` is applied.
+    pub max_liquidation_bonus_bps: u16,
+
+    /// Kind of interest rate model stored in `irm`. IRM_KIND_CURVE, etc. See all constants above.
+    pub irm_kind: u8,
+    /// E-mode category (see Pool.emode_categories) this Reserve belongs to. 0 - no category.
+    /// Category defines LTVs of the Reserve for e-mode positions thus changes are time locked
+    /// together with LTVs.
+    pub emode_category: u8,
 
     /// Program owner fees assessed, separate from gains due to interest accrual
     pub fees: ReserveFeesConfig,
+
+    /// Maximum total borrowed amount (including accrued interest) this Reserve could have. Borrow
+    /// operation will fail if resulting borrowed amount will be more than specified. Flash loans
+    /// are not limited by this setting as they are returned within the same transaction.
+    /// 0 - no limit.
+    pub max_total_borrow: u64,
+
+    /// Floor (in basis points) of the borrow rate. Rate given by IRM is never lower than that.
+    pub min_borrow_rate_bps: u32,
+    /// Cap (in basis points) of the borrow rate. Protects borrowers from absurd rates given by
+    /// misconfigured IRM. 0 - no cap.
+    pub max_borrow_rate_bps: u32,
 }
 
 impl ReserveConfig {
@@ -1106,6 +2139,16 @@ impl ReserveConfig {
             return Err(SuperLendyError::InvalidConfig);
         }
 
+        if self.fees.flash_loan_fee_bps > 500 {
+            msg!("flash_loan_fee_bps must be in range [0, 5] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.fees.curator_liquidation_fee_share_bps > 5000 {
+            msg!("curator_liquidation_fee_share_bps must be in range [0, 50] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
         if self.max_borrow_utilization_bps > 10_000 {
             msg!("max_borrow_utilization_bps must be in range [0, 100] %");
             return Err(SuperLendyError::InvalidConfig);
@@ -1126,9 +2169,82 @@ impl ReserveConfig {
             return Err(SuperLendyError::InvalidConfig);
         }
 
+        if self.borrow_weight_bps != 0 && self.borrow_weight_bps < 10_000 {
+            msg!("borrow_weight_bps must be 0 or not less than 10000 (i.e. 1.0)");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.max_liquidation_bonus_bps != 0
+            && (self.max_liquidation_bonus_bps < self.liquidation_bonus_bps
+                || self.max_liquidation_bonus_bps > 5_000)
+        {
+            msg!("max_liquidation_bonus_bps must be 0 or in range [liquidation_bonus, 50] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.max_borrow_rate_bps != 0 && self.max_borrow_rate_bps < self.min_borrow_rate_bps {
+            msg!("max_borrow_rate_bps must be 0 or not less than min_borrow_rate_bps");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.fees.insurance_fee_rate_bps > 3000 {
+            msg!("insurance_fee_rate_bps must be in range [0, 30] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        match self.interest_rate_model()? {
+            InterestRateModel::Curve(_) => {}
+            InterestRateModel::Kinked(kinked_irm) => kinked_irm.validate()?,
+            InterestRateModel::Adaptive(adaptive_irm) => adaptive_irm.validate()?,
+        }
+
         Ok(())
     }
 
+    /// Interprets `irm_kind` and `irm` fields
+    pub fn interest_rate_model(&self) -> LendyResult<InterestRateModel> {
+        match self.irm_kind {
+            IRM_KIND_CURVE => Ok(InterestRateModel::Curve(self.irm)),
+            IRM_KIND_KINKED => Ok(InterestRateModel::Kinked(bytemuck::cast(
+                self.irm.to_bytes(),
+            ))),
+            IRM_KIND_ADAPTIVE => Ok(InterestRateModel::Adaptive(bytemuck::cast(
+                self.irm.to_bytes(),
+            ))),
+            irm_kind => {
+                msg!("Unknown IRM kind {}", irm_kind);
+                Err(SuperLendyError::InvalidConfig)
+            }
+        }
+    }
+
+    /// Stores `irm` in `irm_kind` and `irm` fields
+    pub fn set_interest_rate_model(&mut self, irm: InterestRateModel) {
+        match irm {
+            InterestRateModel::Curve(curve) => {
+                self.irm_kind = IRM_KIND_CURVE;
+                self.irm = curve;
+            }
+            InterestRateModel::Kinked(kinked_irm) => {
+                self.irm_kind = IRM_KIND_KINKED;
+                self.irm = Pubkey::new_from_array(bytemuck::cast(kinked_irm));
+            }
+            InterestRateModel::Adaptive(adaptive_irm) => {
+                self.irm_kind = IRM_KIND_ADAPTIVE;
+                self.irm = Pubkey::new_from_array(bytemuck::cast(adaptive_irm));
+            }
+        }
+    }
+
+    /// Account to pass as `irm` in to `RefreshReserve` ix
+    pub fn irm_account(&self) -> Pubkey {
+        if self.irm_kind == IRM_KIND_CURVE {
+            self.irm
+        } else {
+            crate::SUPER_LENDY_ID
+        }
+    }
+
     pub fn can_be_applied_now(
         &self,
         proposed_config: &ReserveConfig,
@@ -1139,7 +2255,9 @@ impl ReserveConfig {
         {
             return false;
         }
-        if self.irm != proposed_config.irm && reserve_timelock.irm_lock_sec != 0 {
+        if (self.irm != proposed_config.irm || self.irm_kind != proposed_config.irm_kind)
+            && reserve_timelock.irm_lock_sec != 0
+        {
             return false;
         }
 
@@ -1201,6 +2319,38 @@ impl ReserveConfig {
             return false;
         }
 
+        if self.max_total_borrow != proposed_config.max_total_borrow
+            && reserve_timelock.max_total_borrow_lock_sec != 0
+        {
+            return false;
+        }
+
+        if self.borrow_weight_bps != proposed_config.borrow_weight_bps
+            && reserve_timelock.borrow_weight_lock_sec != 0
+        {
+            return false;
+        }
+
+        if self.max_liquidation_bonus_bps != proposed_config.max_liquidation_bonus_bps
+            && reserve_timelock.liquidation_bonus_lock_sec != 0
+        {
+            return false;
+        }
+
+        if (self.min_borrow_rate_bps != proposed_config.min_borrow_rate_bps
+            || self.max_borrow_rate_bps != proposed_config.max_borrow_rate_bps)
+            && reserve_timelock.irm_lock_sec != 0
+        {
+            return false;
+        }
+
+        if self.emode_category != proposed_config.emode_category
+            && (reserve_timelock.unhealthy_ltv_lock_sec != 0
+                || reserve_timelock.max_borrow_ltv_lock_sec != 0)
+        {
+            return false;
+        }
+
         true
     }
 
@@ -1226,10 +2376,11 @@ impl ReserveConfig {
 
         if change_map.contains(ConfigFields::IRM) {
             msg!(
-                "apply IRM. Old value {} new value {}",
-                self.irm,
-                proposal.config.irm
+                "apply IRM. Old value {:?} new value {:?}",
+                self.interest_rate_model()?,
+                proposal.config.interest_rate_model()?
             );
+            self.irm_kind = proposal.config.irm_kind;
             self.irm = proposal.config.irm;
         }
 
@@ -1334,75 +2485,460 @@ impl ReserveConfig {
                 proposal.config.fees.curator_performance_fee_rate_bps;
         }
 
-        Ok(())
-    }
-}
-
-/// Proposed (time locked) config changes works as follows:
-/// 1. Each Reserve has proposed_configs array - holds proposed changes.
-/// 2. Each proposed change is a new config which will be applied on to current config once brewed.
-///    Applied - means that changed values from proposed config will be copied in to Reserve's
-///    config. Rest of the Reserve's config stay unchanged.
-/// 3. Brew duration is determined based on largest brew time among all changed parameters.
-pub const MAX_CONFIG_PROPOSALS: usize = 4;
-
-bitflags! {
-    pub struct ConfigFields: u64 {
-        const MARKET_PRICE_FEED            = 0b0000000000000001;
-        const IRM                          = 0b0000000000000010;
-        const LIQUIDATION_BONUS            = 0b0000000000000100;
-        const PARTLY_UNHEALTHY_LTV         = 0b0000000000001000;
-        const FULLY_UNHEALTHY_LTV          = 0b0000000000010000;
-        const PARTIAL_LIQUIDATION_FACTOR   = 0b0000000000100000;
-        const MAX_TOTAL_LIQUIDITY          = 0b0000000001000000;
-        const MAX_BORROW_LTV               = 0b0000000010000000;
-        const MAX_BORROW_UTILIZATION       = 0b0000000100000000;
-        const PRICE_STALE_THRESHOLD        = 0b0000001000000000;
-        const MAX_WITHDRAW_UTILIZATION     = 0b0000010000000000;
-        const CURATOR_BORROW_FEE_RATE      = 0b0000100000000000;
-        const CURATOR_PERFORMANCE_FEE_RATE = 0b0001000000000000;
-    }
-}
-
-#[derive(Clone, Copy, Debug, Pod, Zeroable, BorshDeserialize, BorshSerialize, PartialEq)]
-#[repr(C)]
-pub struct ConfigProposal {
-    /// Solana time this proposed change can be applied to Reserve. 0 indicates unused entry.
-    pub can_be_applied_at: UnixTimestamp,
-    /// This is bitmap showing which parameters in `config` represent proposed change. There could
-    /// be just one field changed or several or all.
-    pub change_map: u64,
-    /// Used as storage for changed values. At maximum whole config can be changed via single request.
-    pub config: ReserveConfig,
-}
-
-impl ConfigProposal {
-    /// Returns max time lock value for that proposal - simply max lock among all changed fields.
-    pub fn max_time_lock(self, reserve_timelock: &ReserveTimelock) -> LendyResult<UnixTimestamp> {
-        let mut max_time_lock: u32 = 0;
+        if change_map.contains(ConfigFields::FLASH_LOAN_FEE_RATE) {
+            msg!(
+                "apply FLASH_LOAN_FEE_RATE. Old value {} new value {}",
+                self.fees.flash_loan_fee_bps,
+                proposal.config.fees.flash_loan_fee_bps
+            );
+            self.fees.flash_loan_fee_bps = proposal.config.fees.flash_loan_fee_bps;
+        }
 
-        let change_map = ConfigFields::from_bits(self.change_map).ok_or(
-            SuperLendyError::Internal("proposal change_map interpretation".to_string()),
-        )?;
+        if change_map.contains(ConfigFields::CURATOR_LIQUIDATION_FEE_SHARE) {
+            msg!(
+                "apply CURATOR_LIQUIDATION_FEE_SHARE. Old value {} new value {}",
+                self.fees.curator_liquidation_fee_share_bps,
+                proposal.config.fees.curator_liquidation_fee_share_bps
+            );
+            self.fees.curator_liquidation_fee_share_bps =
+                proposal.config.fees.curator_liquidation_fee_share_bps;
+        }
 
-        if change_map.contains(ConfigFields::MARKET_PRICE_FEED) {
-            max_time_lock = max(reserve_timelock.market_price_feed_lock_sec, max_time_lock);
+        if change_map.contains(ConfigFields::MAX_TOTAL_BORROW) {
+            msg!(
+                "apply MAX_TOTAL_BORROW. Old value {} new value {}",
+                self.max_total_borrow,
+                proposal.config.max_total_borrow
+            );
+            self.max_total_borrow = proposal.config.max_total_borrow;
         }
 
-        if change_map.contains(ConfigFields::IRM) {
-            max_time_lock = max(reserve_timelock.irm_lock_sec, max_time_lock);
+        if change_map.contains(ConfigFields::BORROW_WEIGHT) {
+            msg!(
+                "apply BORROW_WEIGHT. Old value {} new value {}",
+                self.borrow_weight_bps,
+                proposal.config.borrow_weight_bps
+            );
+            self.borrow_weight_bps = proposal.config.borrow_weight_bps;
         }
 
-        if change_map.contains(ConfigFields::LIQUIDATION_BONUS) {
-            max_time_lock = max(reserve_timelock.liquidation_bonus_lock_sec, max_time_lock);
+        if change_map.contains(ConfigFields::MAX_LIQUIDATION_BONUS) {
+            msg!(
+                "apply MAX_LIQUIDATION_BONUS. Old value {} new value {}",
+                self.max_liquidation_bonus_bps,
+                proposal.config.max_liquidation_bonus_bps
+            );
+            self.max_liquidation_bonus_bps = proposal.config.max_liquidation_bonus_bps;
         }
 
-        if change_map.contains(ConfigFields::PARTLY_UNHEALTHY_LTV) {
-            max_time_lock = max(reserve_timelock.unhealthy_ltv_lock_sec, max_time_lock);
+        if change_map.contains(ConfigFields::BORROW_RATE_BOUNDS) {
+            msg!(
+                "apply BORROW_RATE_BOUNDS. Old value [{}, {}] new value [{}, {}]",
+                self.min_borrow_rate_bps,
+                self.max_borrow_rate_bps,
+                proposal.config.min_borrow_rate_bps,
+                proposal.config.max_borrow_rate_bps
+            );
+            self.min_borrow_rate_bps = proposal.config.min_borrow_rate_bps;
+            self.max_borrow_rate_bps = proposal.config.max_borrow_rate_bps;
         }
 
-        if change_map.contains(ConfigFields::FULLY_UNHEALTHY_LTV) {
-            max_time_lock = max(reserve_timelock.unhealthy_ltv_lock_sec, max_time_lock);
+        if change_map.contains(ConfigFields::INSURANCE_FEE_RATE) {
+            msg!(
+                "apply INSURANCE_FEE_RATE. Old value {} new value {}",
+                self.fees.insurance_fee_rate_bps,
+                proposal.config.fees.insurance_fee_rate_bps
+            );
+            self.fees.insurance_fee_rate_bps = proposal.config.fees.insurance_fee_rate_bps;
+        }
+
+        if change_map.contains(ConfigFields::EMODE_CATEGORY) {
+            msg!(
+                "apply EMODE_CATEGORY. Old value {} new value {}",
+                self.emode_category,
+                proposal.config.emode_category
+            );
+            self.emode_category = proposal.config.emode_category;
+        }
+
+        Ok(())
+    }
+
+    /// Applies `min_borrow_rate_bps` and `max_borrow_rate_bps` to the rate given by IRM
+    pub fn clamp_borrow_rate(&self, borrow_rate: Decimal) -> LendyResult<Decimal> {
+        let mut borrow_rate =
+            borrow_rate.max(Decimal::from_basis_points(self.min_borrow_rate_bps)?);
+        if self.max_borrow_rate_bps != 0 {
+            borrow_rate = borrow_rate.min(Decimal::from_basis_points(self.max_borrow_rate_bps)?);
+        }
+        Ok(borrow_rate)
+    }
+
+    /// Multiplier applied to value of liquidity borrowed from the Reserve. See `borrow_weight_bps`.
+    pub fn borrow_weight(&self) -> LendyResult<Decimal> {
+        if self.borrow_weight_bps == 0 {
+            return Ok(Decimal::ONE);
+        }
+
+        Ok(Decimal::from_basis_points(self.borrow_weight_bps as u32)?)
+    }
+
+    /// Liquidation bonus rate for the position with given LTVs. `weighted_ltv` determines position's
+    /// place between `partly_unhealthy_ltv` and `fully_unhealthy_ltv`, while plain `ltv` is used
+    /// to cap the bonus so that `ltv * (1 + bonus)` does not exceed 1.
+    pub fn liquidation_bonus(&self, weighted_ltv: Decimal, ltv: Decimal) -> LendyResult<Decimal> {
+        let min_bonus = Decimal::from_basis_points(self.liquidation_bonus_bps as u32)?;
+
+        if self.max_liquidation_bonus_bps == 0 {
+            return Ok(min_bonus);
+        }
+
+        let max_bonus = Decimal::from_basis_points(self.max_liquidation_bonus_bps as u32)?;
+        let partly_unhealthy_ltv =
+            Decimal::from_basis_points(self.partly_unhealthy_ltv_bps as u32)?;
+        let fully_unhealthy_ltv = Decimal::from_basis_points(self.fully_unhealthy_ltv_bps as u32)?;
+
+        let bonus = if weighted_ltv <= partly_unhealthy_ltv {
+            min_bonus
+        } else if weighted_ltv >= fully_unhealthy_ltv {
+            max_bonus
+        } else {
+            // Linear interpolation between min and max bonuses
+            let progress = weighted_ltv
+                .checked_sub(partly_unhealthy_ltv)?
+                .checked_div(fully_unhealthy_ltv.checked_sub(partly_unhealthy_ltv)?)?;
+            min_bonus.checked_add(max_bonus.checked_sub(min_bonus)?.checked_mul(progress)?)?
+        };
+
+        // Liquidator can not get more than position's collateral is worth. Insolvent positions
+        // are liquidated without bonus.
+        if ltv >= Decimal::ONE {
+            return Ok(Decimal::ZERO);
+        }
+        let insolvency_bonus = Decimal::ONE.checked_div(ltv)?.checked_sub(Decimal::ONE)?;
+
+        Ok(bonus.min(insolvency_bonus))
+    }
+}
+
+/// Interest rate model of the Reserve. Stored in `ReserveConfig` as `irm_kind` and `irm` fields.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InterestRateModel {
+    /// External `curvy` Curve account
+    Curve(Pubkey),
+    /// Built-in two-slope model
+    Kinked(KinkedIrm),
+    /// Built-in model adapting to utilization
+    Adaptive(AdaptiveIrm),
+}
+
+/// Parameters of built-in two-slope interest rate model. Occupies the same 32 bytes as Curve
+/// account address in `ReserveConfig.irm`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[repr(C)]
+pub struct KinkedIrm {
+    /// Borrow rate (in basis points) at zero utilization
+    pub base_rate_bps: u32,
+    /// Borrow rate increase (in basis points) from zero to optimal utilization
+    pub slope1_bps: u32,
+    /// Utilization (in basis points) after which borrow rate grows by `slope2_bps`
+    pub optimal_utilization_bps: u32,
+    /// Borrow rate increase (in basis points) from optimal to 100% utilization
+    pub slope2_bps: u32,
+    pub _padding: [u8; 16],
+}
+
+impl KinkedIrm {
+    pub fn new(
+        base_rate_bps: u32,
+        slope1_bps: u32,
+        optimal_utilization_bps: u32,
+        slope2_bps: u32,
+    ) -> Self {
+        Self {
+            base_rate_bps,
+            slope1_bps,
+            optimal_utilization_bps,
+            slope2_bps,
+            _padding: Zeroable::zeroed(),
+        }
+    }
+
+    pub fn validate(&self) -> LendyResult<()> {
+        if self.optimal_utilization_bps == 0 || self.optimal_utilization_bps > 10_000 {
+            msg!("optimal_utilization_bps must be in range (0, 100] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        Ok(())
+    }
+
+    /// Borrow rate at given utilization
+    pub fn borrow_rate(&self, utilization_rate: Decimal) -> MathResult<Decimal> {
+        kinked_borrow_rate(
+            Decimal::from_basis_points(self.base_rate_bps)?,
+            Decimal::from_basis_points(self.slope1_bps)?,
+            Decimal::from_basis_points(self.optimal_utilization_bps)?,
+            Decimal::from_basis_points(self.slope2_bps)?,
+            utilization_rate,
+        )
+    }
+}
+
+impl Display for KinkedIrm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "kinked (base {} bps, slope1 {} bps, optimal utilization {} bps, slope2 {} bps)",
+            self.base_rate_bps, self.slope1_bps, self.optimal_utilization_bps, self.slope2_bps
+        )
+    }
+}
+
+/// Parameters of built-in adaptive interest rate model. Occupies the same 32 bytes as Curve account
+/// address in `ReserveConfig.irm`.
+///
+/// Borrow rate follows the curve going through `rate_at_target / steepness` at zero utilization,
+/// `rate_at_target` at target utilization and `rate_at_target * steepness` at 100% utilization.
+/// Rate at target itself grows exponentially with `adjustment_speed` (per year) multiplied by
+/// normalized distance of current utilization from the target. Thus when utilization stays above
+/// the target borrowing becomes more expensive till demand drops and vice versa.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[repr(C)]
+pub struct AdaptiveIrm {
+    /// Utilization (in basis points) the model steers Reserve to
+    pub target_utilization_bps: u32,
+    /// Borrow rate (in basis points) at target utilization to start from
+    pub initial_rate_at_target_bps: u32,
+    /// Lower bound of borrow rate (in basis points) at target utilization
+    pub min_rate_at_target_bps: u32,
+    /// Upper bound of borrow rate (in basis points) at target utilization
+    pub max_rate_at_target_bps: u32,
+    /// Yearly speed (in basis points) of rate at target drift when utilization is at 0% or 100%.
+    /// E.g. 500_000 - rate at target changes e^50 times per year.
+    pub adjustment_speed_bps: u32,
+    /// Ratio (in basis points) of borrow rate at 100% utilization to rate at target. Must be not
+    /// less than 10_000 (i.e. 1.0).
+    pub curve_steepness_bps: u32,
+    pub _padding: [u8; 8],
+}
+
+impl AdaptiveIrm {
+    pub fn new(
+        target_utilization_bps: u32,
+        initial_rate_at_target_bps: u32,
+        min_rate_at_target_bps: u32,
+        max_rate_at_target_bps: u32,
+        adjustment_speed_bps: u32,
+        curve_steepness_bps: u32,
+    ) -> Self {
+        Self {
+            target_utilization_bps,
+            initial_rate_at_target_bps,
+            min_rate_at_target_bps,
+            max_rate_at_target_bps,
+            adjustment_speed_bps,
+            curve_steepness_bps,
+            _padding: Zeroable::zeroed(),
+        }
+    }
+
+    pub fn validate(&self) -> LendyResult<()> {
+        if self.target_utilization_bps == 0 || self.target_utilization_bps >= 10_000 {
+            msg!("target_utilization_bps must be in range (0, 100) %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.min_rate_at_target_bps == 0
+            || self.min_rate_at_target_bps > self.initial_rate_at_target_bps
+            || self.initial_rate_at_target_bps > self.max_rate_at_target_bps
+            || self.max_rate_at_target_bps > ADAPTIVE_IRM_MAX_RATE_BPS
+        {
+            msg!(
+                "rates at target must satisfy 0 < min_rate <= initial_rate <= max_rate <= {}",
+                ADAPTIVE_IRM_MAX_RATE_BPS
+            );
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.adjustment_speed_bps > ADAPTIVE_IRM_MAX_SPEED_BPS {
+            msg!(
+                "adjustment_speed_bps must be not greater than {}",
+                ADAPTIVE_IRM_MAX_SPEED_BPS
+            );
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.curve_steepness_bps < 10_000 {
+            msg!("curve_steepness_bps must be not less than 10000 (i.e. 1.0)");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        Ok(())
+    }
+
+    /// Borrow rate at given utilization and current rate at target
+    pub fn borrow_rate(
+        &self,
+        rate_at_target: Decimal,
+        utilization_rate: Decimal,
+    ) -> MathResult<Decimal> {
+        adaptive_borrow_rate(
+            rate_at_target,
+            Decimal::from_basis_points(self.curve_steepness_bps)?,
+            self.utilization_error(utilization_rate)?,
+        )
+    }
+
+    /// Rate at target after `seconds_elapsed` with given utilization
+    pub fn adapt_rate_at_target(
+        &self,
+        rate_at_target: Decimal,
+        utilization_rate: Decimal,
+        seconds_elapsed: u64,
+    ) -> MathResult<Decimal> {
+        let adapted_rate_at_target = adapt_rate_at_target(
+            rate_at_target,
+            Decimal::from_basis_points(self.adjustment_speed_bps)?,
+            self.utilization_error(utilization_rate)?,
+            seconds_elapsed,
+        )?;
+
+        Ok(adapted_rate_at_target
+            .max(Decimal::from_basis_points(self.min_rate_at_target_bps)?)
+            .min(Decimal::from_basis_points(self.max_rate_at_target_bps)?))
+    }
+
+    fn utilization_error(&self, utilization_rate: Decimal) -> MathResult<Decimal> {
+        adaptive_utilization_error(
+            Decimal::from_basis_points(self.target_utilization_bps)?,
+            utilization_rate,
+        )
+    }
+}
+
+impl Display for AdaptiveIrm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "adaptive (target utilization {} bps, initial rate {} bps, min rate {} bps, max rate {} bps, speed {} bps, steepness {} bps)",
+            self.target_utilization_bps,
+            self.initial_rate_at_target_bps,
+            self.min_rate_at_target_bps,
+            self.max_rate_at_target_bps,
+            self.adjustment_speed_bps,
+            self.curve_steepness_bps
+        )
+    }
+}
+
+/// Reserve's rates on some day. Rates and utilization are in basis points. Rates not fitting u32
+/// are saturated. Kept compact so that a week of snapshots fits Reserve's padding.
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+#[repr(C)]
+pub struct RateSnapshot {
+    /// Day (Solana time divided by RATE_SNAPSHOT_INTERVAL_SEC) the snapshot was taken at.
+    /// 0 - empty entry.
+    pub day: u16,
+    /// Utilization never exceeds 100% thus fits u16.
+    pub utilization_bps: u16,
+    pub borrow_rate_bps: u32,
+    pub supply_rate_bps: u32,
+}
+
+impl RateSnapshot {
+    /// Solana time the day of the snapshot starts at
+    pub fn timestamp(&self) -> UnixTimestamp {
+        self.day as UnixTimestamp * RATE_SNAPSHOT_INTERVAL_SEC
+    }
+}
+
+impl Display for RateSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: borrow rate {} bps, supply rate {} bps, utilization {} bps",
+            self.timestamp(),
+            self.borrow_rate_bps,
+            self.supply_rate_bps,
+            self.utilization_bps,
+        )
+    }
+}
+
+/// Proposed (time locked) config changes works as follows:
+/// 1. Each Reserve has proposed_configs array - holds proposed changes.
+/// 2. Each proposed change is a new config which will be applied on to current config once brewed.
+///    Applied - means that changed values from proposed config will be copied in to Reserve's
+///    config. Rest of the Reserve's config stay unchanged.
+/// 3. Brew duration is determined based on largest brew time among all changed parameters.
+pub const MAX_CONFIG_PROPOSALS: usize = 4;
+
+bitflags! {
+    pub struct ConfigFields: u64 {
+        const MARKET_PRICE_FEED            = 0b0000000000000001;
+        const IRM                          = 0b0000000000000010;
+        const LIQUIDATION_BONUS            = 0b0000000000000100;
+        const PARTLY_UNHEALTHY_LTV         = 0b0000000000001000;
+        const FULLY_UNHEALTHY_LTV          = 0b0000000000010000;
+        const PARTIAL_LIQUIDATION_FACTOR   = 0b0000000000100000;
+        const MAX_TOTAL_LIQUIDITY          = 0b0000000001000000;
+        const MAX_BORROW_LTV               = 0b0000000010000000;
+        const MAX_BORROW_UTILIZATION       = 0b0000000100000000;
+        const PRICE_STALE_THRESHOLD        = 0b0000001000000000;
+        const MAX_WITHDRAW_UTILIZATION     = 0b0000010000000000;
+        const CURATOR_BORROW_FEE_RATE      = 0b0000100000000000;
+        const CURATOR_PERFORMANCE_FEE_RATE = 0b0001000000000000;
+        const FLASH_LOAN_FEE_RATE          = 0b0010000000000000;
+        const MAX_TOTAL_BORROW             = 0b0100000000000000;
+        const BORROW_WEIGHT                = 0b1000000000000000;
+        const MAX_LIQUIDATION_BONUS        = 0b10000000000000000;
+        const CURATOR_LIQUIDATION_FEE_SHARE = 0b100000000000000000;
+        const BORROW_RATE_BOUNDS           = 0b1000000000000000000;
+        const INSURANCE_FEE_RATE           = 0b10000000000000000000;
+        const EMODE_CATEGORY               = 0b100000000000000000000;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Pod, Zeroable, BorshDeserialize, BorshSerialize, PartialEq)]
+#[repr(C)]
+pub struct ConfigProposal {
+    /// Solana time this proposed change can be applied to Reserve. 0 indicates unused entry.
+    pub can_be_applied_at: UnixTimestamp,
+    /// This is bitmap showing which parameters in `config` represent proposed change. There could
+    /// be just one field changed or several or all.
+    pub change_map: u64,
+    /// Used as storage for changed values. At maximum whole config can be changed via single request.
+    pub config: ReserveConfig,
+}
+
+impl ConfigProposal {
+    /// Returns max time lock value for that proposal - simply max lock among all changed fields.
+    pub fn max_time_lock(self, reserve_timelock: &ReserveTimelock) -> LendyResult<UnixTimestamp> {
+        let mut max_time_lock: u32 = 0;
+
+        let change_map = ConfigFields::from_bits(self.change_map).ok_or(
+            SuperLendyError::Internal("proposal change_map interpretation".to_string()),
+        )?;
+
+        if change_map.contains(ConfigFields::MARKET_PRICE_FEED) {
+            max_time_lock = max(reserve_timelock.market_price_feed_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::IRM) {
+            max_time_lock = max(reserve_timelock.irm_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::LIQUIDATION_BONUS) {
+            max_time_lock = max(reserve_timelock.liquidation_bonus_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::PARTLY_UNHEALTHY_LTV) {
+            max_time_lock = max(reserve_timelock.unhealthy_ltv_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::FULLY_UNHEALTHY_LTV) {
+            max_time_lock = max(reserve_timelock.unhealthy_ltv_lock_sec, max_time_lock);
         }
 
         if change_map.contains(ConfigFields::PARTIAL_LIQUIDATION_FACTOR) {
@@ -1449,6 +2985,39 @@ impl ConfigProposal {
             max_time_lock = max(reserve_timelock.fees_lock_sec, max_time_lock);
         }
 
+        if change_map.contains(ConfigFields::FLASH_LOAN_FEE_RATE) {
+            max_time_lock = max(reserve_timelock.fees_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::CURATOR_LIQUIDATION_FEE_SHARE) {
+            max_time_lock = max(reserve_timelock.fees_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::MAX_TOTAL_BORROW) {
+            max_time_lock = max(reserve_timelock.max_total_borrow_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::BORROW_WEIGHT) {
+            max_time_lock = max(reserve_timelock.borrow_weight_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::MAX_LIQUIDATION_BONUS) {
+            max_time_lock = max(reserve_timelock.liquidation_bonus_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::BORROW_RATE_BOUNDS) {
+            max_time_lock = max(reserve_timelock.irm_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::INSURANCE_FEE_RATE) {
+            max_time_lock = max(reserve_timelock.fees_lock_sec, max_time_lock);
+        }
+
+        if change_map.contains(ConfigFields::EMODE_CATEGORY) {
+            max_time_lock = max(reserve_timelock.unhealthy_ltv_lock_sec, max_time_lock);
+            max_time_lock = max(reserve_timelock.max_borrow_ltv_lock_sec, max_time_lock);
+        }
+
         Ok(max_time_lock as UnixTimestamp)
     }
 }
@@ -1462,7 +3031,11 @@ impl Display for ConfigProposal {
         }
 
         if change_map.contains(ConfigFields::IRM) {
-            write!(f, " irm: {}", self.config.irm)?;
+            match self.config.interest_rate_model().map_err(|_| Error)? {
+                InterestRateModel::Curve(curve) => write!(f, " irm: {}", curve)?,
+                InterestRateModel::Kinked(kinked_irm) => write!(f, " irm: {}", kinked_irm)?,
+                InterestRateModel::Adaptive(adaptive_irm) => write!(f, " irm: {}", adaptive_irm)?,
+            }
         }
 
         if change_map.contains(ConfigFields::LIQUIDATION_BONUS) {
@@ -1549,6 +3122,58 @@ impl Display for ConfigProposal {
             )?;
         }
 
+        if change_map.contains(ConfigFields::FLASH_LOAN_FEE_RATE) {
+            write!(
+                f,
+                " flash_loan_fee_bps: {}",
+                self.config.fees.flash_loan_fee_bps
+            )?;
+        }
+
+        if change_map.contains(ConfigFields::CURATOR_LIQUIDATION_FEE_SHARE) {
+            write!(
+                f,
+                " curator_liquidation_fee_share_bps: {}",
+                self.config.fees.curator_liquidation_fee_share_bps
+            )?;
+        }
+
+        if change_map.contains(ConfigFields::MAX_TOTAL_BORROW) {
+            write!(f, " max_total_borrow: {}", self.config.max_total_borrow)?;
+        }
+
+        if change_map.contains(ConfigFields::BORROW_WEIGHT) {
+            write!(f, " borrow_weight_bps: {}", self.config.borrow_weight_bps)?;
+        }
+
+        if change_map.contains(ConfigFields::MAX_LIQUIDATION_BONUS) {
+            write!(
+                f,
+                " max_liquidation_bonus_bps: {}",
+                self.config.max_liquidation_bonus_bps
+            )?;
+        }
+
+        if change_map.contains(ConfigFields::BORROW_RATE_BOUNDS) {
+            write!(
+                f,
+                " min_borrow_rate_bps: {} max_borrow_rate_bps: {}",
+                self.config.min_borrow_rate_bps, self.config.max_borrow_rate_bps
+            )?;
+        }
+
+        if change_map.contains(ConfigFields::INSURANCE_FEE_RATE) {
+            write!(
+                f,
+                " insurance_fee_rate_bps: {}",
+                self.config.fees.insurance_fee_rate_bps
+            )?;
+        }
+
+        if change_map.contains(ConfigFields::EMODE_CATEGORY) {
+            write!(f, " emode_category: {}", self.config.emode_category)?;
+        }
+
         Ok(())
     }
 }
@@ -1715,8 +3340,18 @@ pub struct ReserveFeesConfig {
     pub curator_borrow_fee_rate_bps: u16,
     /// Part of pool yield which goes to pool Curator firm
     pub curator_performance_fee_rate_bps: u16,
+    /// Fee assessed on `FlashBorrow`, expressed as a basis points. Paid on top of borrowed amount
+    /// in paired `FlashRepay`. Treated as a pool yield i.e. split between LPs, Curator and Texture
+    /// the same way as interest.
+    pub flash_loan_fee_bps: u16,
+    /// Part of liquidation bonus which goes to pool Curator firm. Taken from collateral given to
+    /// liquidator.
+    pub curator_liquidation_fee_share_bps: u16,
+    /// Part of pool yield (in basis points) which goes to Pool's insurance fund. Insurance fund
+    /// absorbs bad debt before it is socialized on LPs. See WriteOffBadDebt.
+    pub insurance_fee_rate_bps: u16,
 
-    pub _padding: [u8; 12],
+    pub _padding: [u8; 6],
 }
 
 impl ReserveFeesConfig {
@@ -1738,6 +3373,38 @@ impl ReserveFeesConfig {
         )
     }
 
+    /// Calculate fee to be paid on top of `amount` in `FlashRepay`. Rounded up so that non-zero
+    /// fee rate always results in at least 1 lamport fee.
+    pub fn calculate_flash_loan_fee(&self, amount: u64, decimals: u8) -> LendyResult<u64> {
+        if amount == 0 || self.flash_loan_fee_bps == 0 {
+            return Ok(0);
+        }
+
+        let fee = Decimal::from_lamports(amount, decimals)?
+            .checked_mul(Decimal::from_basis_points(self.flash_loan_fee_bps as u32)?)?;
+
+        Ok(fee.to_lamports_ceil(decimals)?.max(1))
+    }
+
+    /// Split `bonus_amount` (LP tokens) given to liquidator in to Curator's and Texture's parts.
+    /// Rest of the bonus stays with the liquidator.
+    pub fn calculate_liquidation_fees(
+        &self,
+        bonus_amount: u64,
+        texture_liquidation_fee_share_bps: u16,
+    ) -> LendyResult<(/*curator fee*/ u64, /*texture fee */ u64)> {
+        let share = |bps: u16| -> LendyResult<u64> {
+            Ok(Decimal::from_lamports(bonus_amount, 0)?
+                .checked_mul(Decimal::from_basis_points(bps as u32)?)?
+                .to_lamports_floor(0)?)
+        };
+
+        Ok((
+            share(self.curator_liquidation_fee_share_bps)?,
+            share(texture_liquidation_fee_share_bps)?,
+        ))
+    }
+
     fn calculate_fees(
         &self,
         amount: Decimal,
@@ -1842,14 +3509,161 @@ pub fn current_borrow_rate(
     )
 }
 
-pub fn current_deposit_rate(
-    curve_y: &[u32],
-    curve_decimals: u8,
-    curve_x_step: u32,
-    curve_x0: Decimal,
+/// Two-slope (kinked) borrow rate. Grows linearly from `base_rate` to `base_rate + slope1` while
+/// utilization goes from 0 to `optimal_utilization` and then from `base_rate + slope1` to
+/// `base_rate + slope1 + slope2` while utilization goes to 100%.
+pub fn kinked_borrow_rate(
+    base_rate: Decimal,
+    slope1: Decimal,
+    optimal_utilization: Decimal,
+    slope2: Decimal,
     utilization_rate: Decimal,
-    texture_performance_fee_rate_bps: u16,
-    curator_performance_fee_rate_bps: u16,
+) -> MathResult<Decimal> {
+    if utilization_rate <= optimal_utilization {
+        let slope1_part = slope1
+            .checked_mul(utilization_rate)?
+            .checked_div(optimal_utilization)?;
+        Ok(base_rate.checked_add(slope1_part)?)
+    } else {
+        let slope2_part = slope2
+            .checked_mul(utilization_rate.checked_sub(optimal_utilization)?)?
+            .checked_div(Decimal::ONE.checked_sub(optimal_utilization)?)?;
+        Ok(base_rate.checked_add(slope1)?.checked_add(slope2_part)?)
+    }
+}
+
+/// Normalized distance of `utilization_rate` from `target_utilization`. Goes from -1 at zero
+/// utilization through 0 at the target to 1 at 100% utilization.
+pub fn adaptive_utilization_error(
+    target_utilization: Decimal,
+    utilization_rate: Decimal,
+) -> MathResult<Decimal> {
+    let distance = utilization_rate.checked_sub(target_utilization)?;
+    if utilization_rate > target_utilization {
+        Ok(distance.checked_div(Decimal::ONE.checked_sub(target_utilization)?)?)
+    } else {
+        Ok(distance.checked_div(target_utilization)?)
+    }
+}
+
+/// Adaptive borrow rate. Linear between `rate_at_target / curve_steepness` (error -1) and
+/// `rate_at_target` (error 0) and between `rate_at_target` and `rate_at_target * curve_steepness`
+/// (error 1).
+pub fn adaptive_borrow_rate(
+    rate_at_target: Decimal,
+    curve_steepness: Decimal,
+    utilization_error: Decimal,
+) -> MathResult<Decimal> {
+    let slope = if utilization_error > Decimal::ZERO {
+        curve_steepness.checked_sub(Decimal::ONE)?
+    } else {
+        Decimal::ONE.checked_sub(Decimal::ONE.checked_div(curve_steepness)?)?
+    };
+
+    Ok(rate_at_target
+        .checked_mul(Decimal::ONE.checked_add(slope.checked_mul(utilization_error)?)?)?)
+}
+
+/// Drifts `rate_at_target` for `seconds_elapsed` with yearly `adjustment_speed` scaled by
+/// `utilization_error`. Drift is compounded each second the same way interest is.
+pub fn adapt_rate_at_target(
+    rate_at_target: Decimal,
+    adjustment_speed: Decimal,
+    utilization_error: Decimal,
+    seconds_elapsed: u64,
+) -> MathResult<Decimal> {
+    let yearly_speed = adjustment_speed.checked_mul(utilization_error)?;
+    if yearly_speed == Decimal::ZERO {
+        return Ok(rate_at_target);
+    }
+
+    // Limit drift exponent (yearly_speed * seconds / SECONDS_PER_YEAR) by its max value
+    let yearly_speed_abs = yearly_speed.max(Decimal::ZERO.checked_sub(yearly_speed)?);
+    let max_seconds = Decimal::from_i128_with_scale(
+        ADAPTIVE_IRM_MAX_DRIFT_EXPONENT * SECONDS_PER_YEAR as i128,
+        0,
+    )?
+    .checked_div(yearly_speed_abs)?;
+    let seconds_elapsed =
+        if Decimal::from_i128_with_scale(seconds_elapsed as i128, 0)? > max_seconds {
+            max_seconds.floor()?
+        } else {
+            seconds_elapsed
+        };
+
+    let speed_per_second =
+        yearly_speed.checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0)?)?;
+    let drift = Decimal::ONE
+        .checked_add(speed_per_second)?
+        .checked_pow(seconds_elapsed)?;
+
+    // Scale to 18 digits precision as all stored decimals are
+    let drift = Decimal::from_bits(drift.into_bits()?)?;
+
+    Ok(rate_at_target.checked_mul(drift)?)
+}
+
+/// Yearly rate earned by LPs. Interest paid by variable rate and fixed-rate borrowings is spread
+/// over all Reserve's liquidity and reduced by performance and insurance fees.
+pub fn supply_rate(
+    variable_borrowed_amount: Decimal,
+    borrow_rate: Decimal,
+    fixed_interest_per_year: Decimal,
+    total_liquidity: Decimal,
+    texture_performance_fee_rate_bps: u16,
+    curator_performance_fee_rate_bps: u16,
+    insurance_fee_rate_bps: u16,
+) -> MathResult<Decimal> {
+    if total_liquidity == Decimal::ZERO {
+        return Ok(Decimal::ZERO);
+    }
+
+    let interest_per_year = variable_borrowed_amount
+        .checked_mul(borrow_rate)?
+        .checked_add(fixed_interest_per_year)?;
+
+    net_of_performance_fees(
+        interest_per_year.checked_div(total_liquidity)?,
+        texture_performance_fee_rate_bps,
+        curator_performance_fee_rate_bps,
+        insurance_fee_rate_bps,
+    )
+}
+
+fn net_of_performance_fees(
+    gross_rate: Decimal,
+    texture_performance_fee_rate_bps: u16,
+    curator_performance_fee_rate_bps: u16,
+    insurance_fee_rate_bps: u16,
+) -> MathResult<Decimal> {
+    let net_fees_rate = Decimal::ONE
+        .checked_sub(Decimal::from_basis_points(
+            texture_performance_fee_rate_bps as u32,
+        )?)?
+        .checked_sub(Decimal::from_basis_points(
+            curator_performance_fee_rate_bps as u32,
+        )?)?
+        .checked_sub(Decimal::from_basis_points(insurance_fee_rate_bps as u32)?)?;
+
+    gross_rate.checked_mul(net_fees_rate)
+}
+
+/// Converts rate to basis points saturating at u32::MAX
+fn snapshot_bps(rate: Decimal) -> MathResult<u32> {
+    let bps = rate
+        .checked_mul(Decimal::from_i128_with_scale(10_000, 0)?)?
+        .floor()?;
+    Ok(bps.min(u32::MAX as u64) as u32)
+}
+
+pub fn current_deposit_rate(
+    curve_y: &[u32],
+    curve_decimals: u8,
+    curve_x_step: u32,
+    curve_x0: Decimal,
+    utilization_rate: Decimal,
+    texture_performance_fee_rate_bps: u16,
+    curator_performance_fee_rate_bps: u16,
 ) -> MathResult<Decimal> {
     let current_borrow_rate = current_borrow_rate(
         curve_y,
@@ -1859,15 +3673,13 @@ pub fn current_deposit_rate(
         utilization_rate,
     )?;
     let deposit_interest_rate_gross = current_borrow_rate.checked_mul(utilization_rate)?;
-    let net_fees_rate = Decimal::ONE
-        .checked_sub(Decimal::from_basis_points(
-            texture_performance_fee_rate_bps as u32,
-        )?)?
-        .checked_sub(Decimal::from_basis_points(
-            curator_performance_fee_rate_bps as u32,
-        )?)?;
 
-    deposit_interest_rate_gross.checked_mul(net_fees_rate)
+    net_of_performance_fees(
+        deposit_interest_rate_gross,
+        texture_performance_fee_rate_bps,
+        curator_performance_fee_rate_bps,
+        0,
+    )
 }
 
 /// Calculates total liquidity for given Reserve. This is simpy a sum of:
@@ -1950,32 +3762,53 @@ pub fn max_borrow_amount(
     Ok(Decimal::from_lamports(available_amount, decimals)?.min(remaining_borrow_amount))
 }
 
-/// Calculates LP exchange rate.
+/// Calculates LP exchange rate. Virtual liquidity and LP amounts are added to real ones thus
+/// empty Reserve starts from initial exchange rate and nobody can skew it while LP supply is small.
 /// `total_liquidity` - total liquidity in given Reserve. WAD.
 /// `lp_total_supply` - total LP tokens minted by that Reserve. WAD.
+/// `decimals` - decimals of Reserve's liquidity (and LP) token.
+/// `virtual_offsets` - add virtual amounts. When not set initial rate is used for empty Reserve only.
 pub fn lp_exchange_rate(
     total_liquidity: Decimal,
     lp_total_supply: Decimal,
+    decimals: u8,
+    virtual_offsets: bool,
 ) -> MathResult<LpExchangeRate> {
-    let rate = if lp_total_supply == Decimal::ZERO || total_liquidity == Decimal::ZERO {
-        Decimal::from_i128_with_scale(INITIAL_COLLATERAL_RATE as i128, SCALE)?
-    } else {
-        lp_total_supply.checked_div(total_liquidity)?
-    };
+    if !virtual_offsets {
+        let rate = if lp_total_supply == Decimal::ZERO || total_liquidity == Decimal::ZERO {
+            Decimal::from_i128_with_scale(INITIAL_COLLATERAL_RATE as i128, SCALE)?
+        } else {
+            lp_total_supply.checked_div(total_liquidity)?
+        };
+
+        return Ok(LpExchangeRate(rate));
+    }
+
+    let total_liquidity =
+        total_liquidity.checked_add(Decimal::from_lamports(VIRTUAL_LIQUIDITY_AMOUNT, decimals)?)?;
+    let lp_total_supply =
+        lp_total_supply.checked_add(Decimal::from_lamports(VIRTUAL_LP_AMOUNT, decimals)?)?;
 
-    Ok(LpExchangeRate(rate))
+    Ok(LpExchangeRate(
+        lp_total_supply.checked_div(total_liquidity)?,
+    ))
 }
 
 /// Calculates LP price based on price of underling assets.
 /// `total_liquidity` - total liquidity from given Reserve. WAD
 /// `lp_total_supply` - total number of minted LP tokens for that Reserve. WAD.
 /// `liquidity_market_price` - market price of the liquidity token from the Reserve.
+/// `decimals` - decimals of Reserve's liquidity (and LP) token.
+/// `virtual_offsets` - add virtual amounts (see [lp_exchange_rate]).
 pub fn lp_market_price(
     total_liquidity: Decimal,
     lp_total_supply: Decimal,
     liquidity_market_price: Decimal,
+    decimals: u8,
+    virtual_offsets: bool,
 ) -> MathResult<Decimal> {
-    let exchange_rate = lp_exchange_rate(total_liquidity, lp_total_supply)?;
+    let exchange_rate =
+        lp_exchange_rate(total_liquidity, lp_total_supply, decimals, virtual_offsets)?;
     let liquidity_in_one_lp = exchange_rate.decimal_lp_to_liquidity(Decimal::ONE)?;
 
     liquidity_in_one_lp.checked_mul(liquidity_market_price)
@@ -1990,10 +3823,12 @@ mod tests {
     fn test_reserve(available_amount: u64) -> Reserve {
         Reserve {
             discriminator: *RESERVE_DISCRIMINATOR,
-            version: 1,
+            version: Reserve::VERSION,
             reserve_type: RESERVE_TYPE_NORMAL,
             mode: RESERVE_MODE_NORMAL,
             flash_loans_enabled: 0,
+            surplus_destination: SURPLUS_TO_LPS,
+            isolation_borrowable: 0,
             _flags: Zeroable::zeroed(),
             last_update: LastUpdate {
                 slot: 0,
@@ -2020,13 +3855,19 @@ mod tests {
                     .into_bits()
                     .unwrap(),
                 mint_decimals: 9,
+                fee_claims_tracked: 1,
                 _padding1: Zeroable::zeroed(),
-                _padding: 0,
+                claimed_performance_fee: 0,
                 borrow_rate: 0,
+                fixed_borrowed_amount: 0,
+                fixed_interest_per_year: 0,
+                supply_rate: 0,
+                insurance_fee: 0,
             },
             collateral: ReserveCollateral {
                 lp_total_supply: available_amount, // LP exchange rate = 1
-                _padding: 0,
+                virtual_offsets: 1,
+                _padding: [0; 7],
             },
             config: ReserveConfig {
                 market_price_feed: Default::default(),
@@ -2036,19 +3877,39 @@ mod tests {
                 partly_unhealthy_ltv_bps: 8500,
                 fully_unhealthy_ltv_bps: 9000,
                 partial_liquidation_factor_bps: 2000,
-                _padding: Zeroable::zeroed(),
                 fees: ReserveFeesConfig {
                     curator_borrow_fee_rate_bps: 300, // 3%
                     curator_performance_fee_rate_bps: 0,
+                    flash_loan_fee_bps: 0,
+                    curator_liquidation_fee_share_bps: 0,
+                    insurance_fee_rate_bps: 0,
                     _padding: Zeroable::zeroed(),
                 },
                 max_total_liquidity: u64::MAX,
                 max_borrow_utilization_bps: 8000,
                 price_stale_threshold_sec: 1,
                 max_withdraw_utilization_bps: 9500,
+                max_total_borrow: 0,
+                borrow_weight_bps: 10_000,
+                max_liquidation_bonus_bps: 0,
+                irm_kind: IRM_KIND_CURVE,
+                min_borrow_rate_bps: 0,
+                max_borrow_rate_bps: 0,
+                emode_category: 0,
             },
             reward_rules: Zeroable::zeroed(),
             proposed_configs: ProposedConfigs::zeroed(),
+            isolated_debt: 0,
+            isolated_debt_ceiling: 0,
+            curator_liquidation_fee: 0,
+            texture_liquidation_fee: 0,
+            fixed_rate_bps: 0,
+            fixed_max_term_sec: 0,
+            fixed_grace_period_sec: 0,
+            rate_snapshot_index: 0,
+            _padding1: Zeroable::zeroed(),
+            adaptive_rate_at_target: 0,
+            rate_snapshots: Zeroable::zeroed(),
             _padding: Zeroable::zeroed(),
         }
     }
@@ -2057,10 +3918,10 @@ mod tests {
     fn calc_lp_market_price() {
         let mut test_reserve = test_reserve(1000);
 
-        // Borrowed amount is 1000
+        // Borrowed amount is 2000
         test_reserve
             .liquidity
-            .set_borrowed_amount(Decimal::from_lamports(1000, 9).unwrap())
+            .set_borrowed_amount(Decimal::from_lamports(2000, 9).unwrap())
             .unwrap();
 
         test_reserve
@@ -2068,12 +3929,13 @@ mod tests {
             .set_market_price(Decimal::from_i128_with_scale(250, 0).unwrap())
             .unwrap();
 
-        // There are twice less LP tokens then liquidity tokens.
+        // Together with virtual amounts there are twice less LP tokens then liquidity tokens:
+        // (1000 + 1000 virtual) LPs vs. (3000 + 1000 virtual) liquidity.
         test_reserve.collateral.lp_total_supply = 1000;
 
         let total_liquidity = test_reserve.liquidity.total_liquidity().unwrap();
 
-        assert_eq!(total_liquidity, Decimal::from_lamports(2000, 9).unwrap());
+        assert_eq!(total_liquidity, Decimal::from_lamports(3000, 9).unwrap());
 
         let lp_market_price = test_reserve.lp_market_price().unwrap();
 
@@ -2085,6 +3947,181 @@ mod tests {
         );
     }
 
+    #[test]
+    fn legacy_reserve_lp_rate_unchanged() {
+        // Reserve created before virtual amounts were introduced
+        let mut reserve = test_reserve(1000);
+        reserve.collateral.virtual_offsets = 0;
+
+        reserve
+            .liquidity
+            .set_borrowed_amount(Decimal::from_lamports(1000, 9).unwrap())
+            .unwrap();
+        reserve
+            .liquidity
+            .set_market_price(Decimal::from_i128_with_scale(250, 0).unwrap())
+            .unwrap();
+        reserve.collateral.lp_total_supply = 1000;
+
+        // 1000 LPs vs. 2000 liquidity as before the upgrade
+        assert_eq!(
+            reserve.lp_exchange_rate().unwrap().0,
+            Decimal::from_lamports(1000, 9)
+                .unwrap()
+                .checked_div(Decimal::from_lamports(2000, 9).unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            reserve.lp_market_price().unwrap(),
+            Decimal::from_i128_with_scale(500, 0).unwrap()
+        );
+
+        let deposit_lp_amount = reserve.deposit_liquidity(2000).unwrap();
+        assert_eq!(deposit_lp_amount, 1000);
+
+        // Empty legacy Reserve uses initial rate
+        let mut empty_reserve = test_reserve(0);
+        empty_reserve.collateral.virtual_offsets = 0;
+        assert_eq!(empty_reserve.lp_exchange_rate().unwrap().0, Decimal::ONE);
+    }
+
+    #[test]
+    fn first_depositor_inflation() {
+        let mut reserve = test_reserve(0);
+
+        // Attacker is the first depositor and gets 1 LP for 1 lamport
+        let attacker_lp_amount = reserve.deposit_liquidity(1).unwrap();
+        assert_eq!(attacker_lp_amount, 1);
+
+        // Then he "donates" 1000 SOL to LP holders via flash loan fee to inflate LP price
+        let donation = 1_000_000_000_000;
+        reserve
+            .liquidity
+            .accrue_flash_loan_fee(donation, 0, 0)
+            .unwrap();
+
+        // Victim deposits 1 SOL and still gets LP tokens. Without virtual amounts it would get
+        // nothing as 1 LP would cost 1000 SOL.
+        let victim_lp_amount = reserve.deposit_liquidity(1_000_000_000).unwrap();
+        assert!(victim_lp_amount > 0);
+
+        // Most of the donation belongs to virtual LPs. Attacker can't get it back.
+        let attacker_liquidity = reserve.withdraw_liquidity(attacker_lp_amount).unwrap();
+        assert!(attacker_liquidity < donation / VIRTUAL_LIQUIDITY_AMOUNT);
+    }
+
+    #[test]
+    fn sync_supply() {
+        let mut reserve = test_reserve(1000);
+
+        // Nothing to sync
+        assert_eq!(reserve.sync_supply(1000).unwrap(), 0);
+        assert_eq!(reserve.liquidity.available_amount, 1000);
+
+        // Deficit
+        assert_matches!(
+            reserve.sync_supply(999),
+            Err(SuperLendyError::SupplyDeficit(999, 1000))
+        );
+
+        // Surplus goes to LPs
+        let lp_rate_before = reserve.lp_exchange_rate().unwrap();
+        assert_eq!(reserve.sync_supply(1100).unwrap(), 100);
+        assert_eq!(reserve.liquidity.available_amount, 1100);
+        assert_eq!(
+            reserve.liquidity.curator_performance_fee().unwrap(),
+            Decimal::ZERO
+        );
+        assert!(reserve.lp_exchange_rate().unwrap().0 < lp_rate_before.0);
+
+        // Surplus goes to Curator
+        reserve.surplus_destination = SURPLUS_TO_CURATOR;
+        assert_eq!(reserve.sync_supply(1150).unwrap(), 50);
+        assert_eq!(reserve.liquidity.available_amount, 1150);
+        assert_eq!(
+            reserve.liquidity.curator_performance_fee().unwrap(),
+            Decimal::from_lamports(50, 9).unwrap()
+        );
+
+        // Claimed fee leaves the supply but stays in available liquidity
+        let claimed = reserve.liquidity.claim_curator_performance_fee().unwrap();
+        assert_eq!(claimed, 50);
+        assert_eq!(reserve.liquidity.available_amount, 1150);
+        assert_eq!(reserve.liquidity.claimed_performance_fee, 50);
+        assert_eq!(reserve.sync_supply(1100).unwrap(), 0);
+        assert_matches!(
+            reserve.sync_supply(1099),
+            Err(SuperLendyError::SupplyDeficit(1099, 1100))
+        );
+    }
+
+    #[test]
+    fn sync_supply_legacy_reserve() {
+        let mut reserve = test_reserve(1000);
+        reserve.liquidity.fee_claims_tracked = 0;
+
+        // Fees claimed before the counter existed are reconciled once
+        assert_eq!(reserve.sync_supply(970).unwrap(), 0);
+        assert_eq!(reserve.liquidity.fee_claims_tracked, 1);
+        assert_eq!(reserve.liquidity.claimed_performance_fee, 30);
+        assert_eq!(reserve.liquidity.available_amount, 1000);
+
+        // Afterwards deficit is detected as usual
+        assert_matches!(
+            reserve.sync_supply(969),
+            Err(SuperLendyError::SupplyDeficit(969, 970))
+        );
+        assert_eq!(reserve.sync_supply(980).unwrap(), 10);
+    }
+
+    #[test]
+    fn isolated_debt_ceiling() {
+        let mut reserve = test_reserve(1000);
+        reserve.reserve_type = RESERVE_TYPE_ISOLATED_COLLATERAL;
+
+        // No limit
+        reserve
+            .increase_isolated_debt(Decimal::from_i128_with_scale(1_000_000, 0).unwrap())
+            .unwrap();
+        reserve
+            .decrease_isolated_debt(Decimal::from_i128_with_scale(1_000_000, 0).unwrap())
+            .unwrap();
+        assert_eq!(reserve.isolated_debt().unwrap(), Decimal::ZERO);
+
+        reserve.isolated_debt_ceiling = 100;
+
+        reserve
+            .increase_isolated_debt(Decimal::from_i128_with_scale(60, 0).unwrap())
+            .unwrap();
+        reserve
+            .increase_isolated_debt(Decimal::from_i128_with_scale(40, 0).unwrap())
+            .unwrap();
+
+        // Ceiling reached
+        assert_matches!(
+            reserve.increase_isolated_debt(Decimal::from_lamports(1, 9).unwrap()),
+            Err(SuperLendyError::ResourceExhausted)
+        );
+        assert_eq!(
+            reserve.isolated_debt().unwrap(),
+            Decimal::from_i128_with_scale(100, 0).unwrap()
+        );
+
+        reserve
+            .decrease_isolated_debt(Decimal::from_i128_with_scale(30, 0).unwrap())
+            .unwrap();
+        assert_eq!(
+            reserve.isolated_debt().unwrap(),
+            Decimal::from_i128_with_scale(70, 0).unwrap()
+        );
+
+        // Repaid value greater than accounted one
+        reserve
+            .decrease_isolated_debt(Decimal::from_i128_with_scale(71, 0).unwrap())
+            .unwrap();
+        assert_eq!(reserve.isolated_debt().unwrap(), Decimal::ZERO);
+    }
+
     #[test]
     fn compound_interest() {
         let mut test_reserve = test_reserve(1000);
@@ -2097,7 +4134,7 @@ mod tests {
         let current_borrow_rate = Decimal::from_basis_points(2000).expect("current_borrow_rate"); // 20%
         let curator_performance_fee_rate_bps = 0;
         let texture_performance_fee_rate_bps = 0;
-        let slots_elapsed = SLOTS_PER_YEAR;
+        let seconds_elapsed = SECONDS_PER_YEAR;
 
         test_reserve
             .liquidity
@@ -2105,11 +4142,11 @@ mod tests {
                 current_borrow_rate,
                 curator_performance_fee_rate_bps,
                 texture_performance_fee_rate_bps,
-                slots_elapsed,
+                seconds_elapsed,
             )
             .expect("interest");
 
-        // Because one Solana year is passed and borrow rate was 20% - borrowed amount in the
+        // Because one year is passed and borrow rate was 20% - borrowed amount in the
         // reserve must increase approx. by 22% due to interest compounding
         assert_eq!(
             test_reserve
@@ -2122,50 +4159,703 @@ mod tests {
         );
 
         assert_eq!(
-            test_reserve.liquidity.cumulative_borrow_rate().unwrap(),
-            Decimal::from_i128_with_scale(1221402757772865561, 18).unwrap()
+            test_reserve.liquidity.cumulative_borrow_rate().unwrap(),
+            Decimal::from_i128_with_scale(1221402757385561290, 18).unwrap()
+        );
+
+        // Same reserve with same rates BUT with curator and Texture performance fees turned on.
+        let mut test_reserve1 = crate::state::reserve::tests::test_reserve(1000);
+        // Borrowed amount is 1000
+        test_reserve1
+            .liquidity
+            .set_borrowed_amount(Decimal::from_lamports(1000, 9).unwrap())
+            .unwrap();
+
+        let current_borrow_rate = Decimal::from_basis_points(2000).expect("current_borrow_rate"); // 20%
+        let curator_performance_fee_rate_bps = 2000; // 20%
+        let texture_performance_fee_rate_bps = 3000; // 30%
+        let seconds_elapsed = SECONDS_PER_YEAR;
+
+        test_reserve1
+            .liquidity
+            .compound_interest(
+                current_borrow_rate,
+                curator_performance_fee_rate_bps,
+                texture_performance_fee_rate_bps,
+                seconds_elapsed,
+            )
+            .expect("interest");
+
+        // Interest for the period is ~21 USDC. All interest goes in to borrowed_amount
+        assert_eq!(
+            test_reserve1
+                .liquidity
+                .borrowed_amount()
+                .unwrap()
+                .to_lamports_round(9)
+                .unwrap(),
+            1221
+        );
+
+        // Cumulative borrow rate stays the same as in situation without perf. fees.
+        assert_eq!(
+            test_reserve.liquidity.cumulative_borrow_rate().unwrap(),
+            Decimal::from_i128_with_scale(1221402757385561290, 18).unwrap()
+        );
+    }
+
+    #[test]
+    fn kinked_irm() {
+        let kinked_irm = KinkedIrm::new(200, 400, 8000, 6000);
+        let rate = |utilization_bps| {
+            kinked_irm
+                .borrow_rate(Decimal::from_basis_points(utilization_bps).unwrap())
+                .unwrap()
+        };
+
+        assert_eq!(rate(0), Decimal::from_basis_points(200).unwrap());
+        assert_eq!(rate(4000), Decimal::from_basis_points(400).unwrap());
+        assert_eq!(rate(8000), Decimal::from_basis_points(600).unwrap());
+        assert_eq!(rate(9000), Decimal::from_basis_points(3600).unwrap());
+        assert_eq!(rate(10_000), Decimal::from_basis_points(6600).unwrap());
+
+        let mut reserve = test_reserve(1000);
+        let curve = Pubkey::new_unique();
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Curve(curve));
+        assert_eq!(reserve.config.irm_account(), curve);
+        // Curve IRM can't be evaluated without Curve account
+        assert!(reserve.current_borrow_rate(None).is_err());
+
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Kinked(kinked_irm));
+        assert_eq!(reserve.config.irm_kind, IRM_KIND_KINKED);
+        assert_eq!(reserve.config.irm_account(), crate::SUPER_LENDY_ID);
+        assert_eq!(
+            reserve.config.interest_rate_model().unwrap(),
+            InterestRateModel::Kinked(kinked_irm)
+        );
+        reserve.config.validate().unwrap();
+
+        reserve
+            .liquidity
+            .borrow(Decimal::from_lamports(500, 9).unwrap(), 500)
+            .unwrap();
+        assert_eq!(
+            reserve.current_borrow_rate(None).unwrap(),
+            kinked_irm
+                .borrow_rate(reserve.liquidity.utilization_rate().unwrap())
+                .unwrap()
+        );
+
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Kinked(KinkedIrm::new(200, 400, 0, 6000)));
+        assert_matches!(
+            reserve.config.validate(),
+            Err(SuperLendyError::InvalidConfig)
+        );
+
+        reserve.config.irm_kind = 3;
+        assert!(reserve.config.interest_rate_model().is_err());
+    }
+
+    #[test]
+    fn adaptive_irm() {
+        let adaptive_irm = AdaptiveIrm::new(8000, 400, 10, 20_000, 500_000, 40_000);
+        let bps = |value| Decimal::from_basis_points(value).unwrap();
+        let rate = |utilization_bps| {
+            adaptive_irm
+                .borrow_rate(bps(400), bps(utilization_bps))
+                .unwrap()
+        };
+
+        assert_eq!(rate(0), bps(100));
+        assert_eq!(rate(4000), bps(250));
+        assert_eq!(rate(8000), bps(400));
+        assert_eq!(rate(9000), bps(1000));
+        assert_eq!(rate(10_000), bps(1600));
+
+        // No drift at target utilization and no drift without time passed
+        let adapt = |utilization_bps, seconds_elapsed| {
+            adaptive_irm
+                .adapt_rate_at_target(bps(400), bps(utilization_bps), seconds_elapsed)
+                .unwrap()
+        };
+        assert_eq!(adapt(8000, SECONDS_PER_YEAR), bps(400));
+        assert_eq!(adapt(10_000, 0), bps(400));
+        // Rate at target grows above the target and declines below it
+        assert!(adapt(9000, 3600) > bps(400));
+        assert!(adapt(7000, 3600) < bps(400));
+        // ... but within configured bounds
+        assert_eq!(adapt(10_000, SECONDS_PER_YEAR), bps(20_000));
+        assert_eq!(adapt(0, SECONDS_PER_YEAR), bps(10));
+
+        let mut reserve = test_reserve(1000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Adaptive(adaptive_irm));
+        assert_eq!(reserve.config.irm_kind, IRM_KIND_ADAPTIVE);
+        assert_eq!(reserve.config.irm_account(), crate::SUPER_LENDY_ID);
+        reserve.config.validate().unwrap();
+        assert_eq!(
+            reserve.adaptive_rate_at_target(&adaptive_irm).unwrap(),
+            bps(400)
+        );
+
+        // Fully utilized Reserve makes rate at target grow on each accrual
+        reserve
+            .liquidity
+            .borrow(Decimal::from_lamports(1000, 9).unwrap(), 1000)
+            .unwrap();
+        reserve.accrue_interest(3600, 0, None).unwrap();
+        let rate_at_target = reserve.adaptive_rate_at_target(&adaptive_irm).unwrap();
+        assert_eq!(rate_at_target, adapt(10_000, 3600));
+        assert_eq!(
+            reserve.current_borrow_rate(None).unwrap(),
+            rate_at_target.checked_mul(bps(40_000)).unwrap()
+        );
+
+        // Changing the model resets adaptive state
+        let mut config = reserve.config;
+        config.set_interest_rate_model(InterestRateModel::Adaptive(AdaptiveIrm::new(
+            8000, 500, 10, 20_000, 500_000, 40_000,
+        )));
+        reserve.set_config(config).unwrap();
+        assert_eq!(reserve.adaptive_rate_at_target, 0);
+
+        for invalid in [
+            AdaptiveIrm::new(10_000, 400, 10, 20_000, 500_000, 40_000),
+            AdaptiveIrm::new(8000, 400, 0, 20_000, 500_000, 40_000),
+            AdaptiveIrm::new(8000, 400, 500, 20_000, 500_000, 40_000),
+            AdaptiveIrm::new(8000, 400, 10, 300, 500_000, 40_000),
+            AdaptiveIrm::new(8000, 400, 10, 20_000, 500_000, 9000),
+        ] {
+            reserve
+                .config
+                .set_interest_rate_model(InterestRateModel::Adaptive(invalid));
+            assert_matches!(
+                reserve.config.validate(),
+                Err(SuperLendyError::InvalidConfig)
+            );
+        }
+    }
+
+    #[test]
+    fn adaptive_irm_multi_day_accrual() {
+        let adaptive_irm = AdaptiveIrm::new(8000, 400, 10, 20_000, 500_000, 40_000);
+        let three_days = 3 * 24 * 3600;
+
+        let mut reserve = test_reserve(1_000_000_000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Adaptive(adaptive_irm));
+        reserve
+            .liquidity
+            .borrow(
+                Decimal::from_lamports(1_000_000_000, 9).unwrap(),
+                1_000_000_000,
+            )
+            .unwrap();
+
+        let start_rate = reserve.current_borrow_rate(None).unwrap();
+        let mut at_start_rate = reserve;
+        let mut at_end_rate = reserve;
+
+        // Fully utilized Reserve is not refreshed for three days
+        reserve.accrue_interest(three_days, 0, None).unwrap();
+        let end_rate = reserve.current_borrow_rate(None).unwrap();
+        assert!(end_rate > start_rate);
+        assert_eq!(reserve.liquidity.borrow_rate().unwrap(), end_rate);
+
+        let average_rate = start_rate
+            .checked_add(end_rate)
+            .unwrap()
+            .checked_div(Decimal::from_i128_with_scale(2, 0).unwrap())
+            .unwrap();
+        let mut expected = at_start_rate;
+        let curator_fee_bps = reserve.config.fees.curator_performance_fee_rate_bps;
+        expected
+            .liquidity
+            .compound_interest(average_rate, curator_fee_bps, 0, three_days as u64)
+            .unwrap();
+        assert_eq!(
+            reserve.liquidity.borrowed_amount().unwrap(),
+            expected.liquidity.borrowed_amount().unwrap()
+        );
+
+        // Neither the start rate nor the adapted one is applied to the whole period
+        at_start_rate
+            .liquidity
+            .compound_interest(start_rate, curator_fee_bps, 0, three_days as u64)
+            .unwrap();
+        at_end_rate
+            .liquidity
+            .compound_interest(end_rate, curator_fee_bps, 0, three_days as u64)
+            .unwrap();
+        assert!(
+            reserve.liquidity.borrowed_amount().unwrap()
+                > at_start_rate.liquidity.borrowed_amount().unwrap()
+        );
+        assert!(
+            reserve.liquidity.borrowed_amount().unwrap()
+                < at_end_rate.liquidity.borrowed_amount().unwrap()
+        );
+    }
+
+    #[test]
+    fn adaptive_irm_long_interval_accrual() {
+        // Rate at target of fully utilized Reserve reaches its 20% cap within first hours. Thus
+        // borrow rate moves from 2 * 10% to 2 * 20% and the whole year is accrued at 30%:
+        // e^0.3 = 1.3498588
+        let adaptive_irm = AdaptiveIrm::new(8000, 1000, 100, 2000, 500_000, 20_000);
+        let bps = |value| Decimal::from_basis_points(value).unwrap();
+
+        let mut reserve = test_reserve(1_000_000_000_000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Adaptive(adaptive_irm));
+        reserve
+            .liquidity
+            .borrow(
+                Decimal::from_lamports(1_000_000_000_000, 9).unwrap(),
+                1_000_000_000_000,
+            )
+            .unwrap();
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(2000));
+
+        reserve
+            .accrue_interest(SECONDS_PER_YEAR as i64, 0, None)
+            .unwrap();
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(4000));
+
+        let cumulative_borrow_rate = reserve.liquidity.cumulative_borrow_rate().unwrap();
+        assert!(cumulative_borrow_rate > Decimal::from_i128_with_scale(13_498_588, 7).unwrap());
+        assert!(cumulative_borrow_rate < Decimal::from_i128_with_scale(13_498_589, 7).unwrap());
+    }
+
+    /// Simulates a year of borrowers reacting to adaptive borrow rate. Borrow demand declines as the
+    /// rate grows: utilization = 84% - 0.5 * borrow_rate. Thus equilibrium is at target utilization
+    /// of 80% with borrow rate of 8%. Model starts far away from it (rate at target 4%, 50%
+    /// utilization) and must converge in a few months.
+    #[test]
+    fn adaptive_irm_convergence() {
+        let adaptive_irm = AdaptiveIrm::new(8000, 400, 10, 20_000, 500_000, 40_000);
+        let total_liquidity: u64 = 1_000_000_000_000;
+        let day = 24 * 3600;
+
+        let mut reserve = test_reserve(total_liquidity);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Adaptive(adaptive_irm));
+
+        let set_utilization = |reserve: &mut Reserve, utilization_bps: u64| {
+            let borrowed_amount = total_liquidity * utilization_bps / 10_000;
+            reserve.liquidity.available_amount = total_liquidity - borrowed_amount;
+            reserve.liquidity.borrowed_amount = Decimal::from_lamports(borrowed_amount, 9)
+                .unwrap()
+                .into_bits()
+                .unwrap();
+        };
+
+        set_utilization(&mut reserve, 5000);
+        let mut timestamp = 0;
+        for _ in 0..365 {
+            let borrow_rate_bps = reserve
+                .current_borrow_rate(None)
+                .unwrap()
+                .checked_mul(Decimal::from_i128_with_scale(10_000, 0).unwrap())
+                .unwrap()
+                .floor()
+                .unwrap();
+            let demand_bps = 8400_u64.saturating_sub(borrow_rate_bps / 2).min(10_000);
+            set_utilization(&mut reserve, demand_bps);
+
+            timestamp += day;
+            reserve.accrue_interest(timestamp, 0, None).unwrap();
+            reserve.last_update.update(0, timestamp);
+        }
+
+        let utilization = reserve.liquidity.utilization_rate().unwrap();
+        assert!(utilization > Decimal::from_basis_points(7950).unwrap());
+        assert!(utilization < Decimal::from_basis_points(8050).unwrap());
+
+        let rate_at_target = reserve.adaptive_rate_at_target(&adaptive_irm).unwrap();
+        assert!(rate_at_target > Decimal::from_basis_points(750).unwrap());
+        assert!(rate_at_target < Decimal::from_basis_points(850).unwrap());
+    }
+
+    #[test]
+    fn fixed_rate_loan() {
+        let mut reserve = test_reserve(1000);
+        let amount = Decimal::from_i128_with_scale(1000, 0).unwrap();
+
+        // 10% per year for one year
+        let mut loan = FixedLoan::new(Pubkey::new_unique(), 1000, 0, SECONDS_PER_YEAR as i64);
+        loan.liquidity
+            .borrow(amount, reserve.liquidity.market_price().unwrap())
+            .unwrap();
+        reserve
+            .liquidity
+            .borrow_fixed(amount, 1000, loan.interest_per_year(amount).unwrap())
+            .unwrap();
+
+        assert_eq!(reserve.liquidity.available_amount, 0);
+        assert_eq!(reserve.liquidity.total_borrowed_amount().unwrap(), amount);
+        assert_eq!(
+            reserve.liquidity.fixed_interest_per_year().unwrap(),
+            Decimal::from_i128_with_scale(100, 0).unwrap()
+        );
+
+        // Half a year later both the Reserve and the loan accrued the same linear interest
+        let half_year = SECONDS_PER_YEAR / 2;
+        reserve
+            .liquidity
+            .accrue_fixed_interest(0, 0, half_year)
+            .unwrap();
+        loan.accrue_interest(half_year as i64).unwrap();
+
+        let expected = Decimal::from_i128_with_scale(1050, 0).unwrap();
+        assert_eq!(reserve.liquidity.fixed_borrowed_amount().unwrap(), expected);
+        assert_eq!(loan.liquidity.borrowed_amount().unwrap(), expected);
+
+        assert!(!loan.is_overdue(half_year as i64, 0));
+        assert!(!loan.is_overdue(SECONDS_PER_YEAR as i64, 3600));
+        assert!(loan.is_overdue(SECONDS_PER_YEAR as i64 + 3600, 3600));
+
+        // Full repay releases all the interest the loan contributed
+        reserve
+            .liquidity
+            .repay_fixed(1050, expected, loan.interest_per_year(expected).unwrap())
+            .unwrap();
+        loan.liquidity.repay(expected).unwrap();
+
+        assert_eq!(reserve.liquidity.available_amount, 1050);
+        assert_eq!(
+            reserve.liquidity.fixed_borrowed_amount().unwrap(),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            reserve.liquidity.fixed_interest_per_year().unwrap(),
+            Decimal::ZERO
+        );
+        assert!(!loan.is_active());
+        assert!(!loan.is_overdue(SECONDS_PER_YEAR as i64 + 3600, 3600));
+    }
+
+    #[test]
+    fn migrate_from_slot_based_accrual() {
+        let mut reserve = test_reserve(1000);
+        reserve.version = 1;
+
+        let data = bytemuck::bytes_of_mut(&mut reserve);
+        Reserve::migrate(data).expect("migrate");
+        assert_eq!(reserve.version, Reserve::VERSION);
+
+        // Already migrated Reserve stays untouched
+        let data = bytemuck::bytes_of_mut(&mut reserve);
+        Reserve::migrate(data).expect("migrate");
+        assert_eq!(reserve.version, Reserve::VERSION);
+
+        reserve.version = Reserve::VERSION + 1;
+        let data = bytemuck::bytes_of_mut(&mut reserve);
+        assert_matches!(
+            Reserve::migrate(data),
+            Err(SuperLendyError::Serialize(
+                SerializeError::VersionMismatch { .. }
+            ))
+        );
+    }
+
+    #[test]
+    fn unpack_mut_migrates() {
+        let mut reserve = test_reserve(1000);
+        reserve.version = 1;
+
+        let mut data = bytemuck::bytes_of(&reserve).to_vec();
+        assert!(Reserve::try_from_bytes_mut(&mut data).is_err());
+
+        let unpacked = Reserve::unpack_mut(&mut data).expect("unpack_mut");
+        assert_eq!(unpacked.version, Reserve::VERSION);
+        assert_eq!(unpacked.liquidity.available_amount, 1000);
+    }
+
+    #[test]
+    fn unpack_migrates_copy() {
+        let mut reserve = test_reserve(1000);
+        reserve.version = 2;
+        let legacy_data = bytemuck::bytes_of(&reserve).to_vec();
+
+        reserve.version = Reserve::VERSION;
+        reserve.liquidity.available_amount = 2000;
+        let current_data = bytemuck::bytes_of(&reserve).to_vec();
+
+        let mut migrated = None;
+        let unpacked = Reserve::unpack(&legacy_data, &mut migrated).expect("unpack legacy");
+        assert_eq!(unpacked.version, Reserve::VERSION);
+        assert_eq!(unpacked.liquidity.available_amount, 1000);
+        // Account data itself is left as is
+        assert_eq!(legacy_data[std::mem::offset_of!(Reserve, version)], 2);
+
+        let unpacked = Reserve::unpack(&current_data, &mut migrated).expect("unpack current");
+        assert_eq!(unpacked.liquidity.available_amount, 2000);
+
+        let mut unknown_data = legacy_data.clone();
+        unknown_data[std::mem::offset_of!(Reserve, version)] = Reserve::VERSION + 1;
+        assert!(Reserve::unpack(&unknown_data, &mut migrated).is_err());
+    }
+
+    #[test]
+    fn migrate_config_growth() {
+        let mut reserve = test_reserve(1000);
+        reserve.config.max_total_borrow = 1000;
+        reserve.proposed_configs.0[MAX_CONFIG_PROPOSALS - 1].can_be_applied_at = 100;
+        reserve.proposed_configs.0[MAX_CONFIG_PROPOSALS - 1]
+            .config
+            .max_total_borrow = 2000;
+        reserve.isolated_debt_ceiling = 3000;
+        reserve.adaptive_rate_at_target = 4000;
+
+        // Version 2 layout is the same without bytes ReserveConfig grew by
+        let old_config_size = std::mem::size_of::<ReserveConfig>() - RESERVE_CONFIG_V3_GROWTH;
+        let mut grown_at = vec![std::mem::offset_of!(Reserve, config) + old_config_size];
+        for index in 0..MAX_CONFIG_PROPOSALS {
+            grown_at.push(
+                std::mem::offset_of!(Reserve, proposed_configs)
+                    + index * std::mem::size_of::<ConfigProposal>()
+                    + std::mem::offset_of!(ConfigProposal, config)
+                    + old_config_size,
+            );
+        }
+        let mut data = bytemuck::bytes_of(&reserve)
+            .iter()
+            .enumerate()
+            .filter(|(offset, _)| {
+                !grown_at
+                    .iter()
+                    .any(|start| (*start..start + RESERVE_CONFIG_V3_GROWTH).contains(offset))
+            })
+            .map(|(_, byte)| *byte)
+            .collect::<Vec<_>>();
+        data.resize(Reserve::SIZE, 0);
+        data[std::mem::offset_of!(Reserve, version)] = 2;
+
+        // ... and max_total_borrow in the tail of fees
+        let old_proposal_size = std::mem::size_of::<ConfigProposal>() - RESERVE_CONFIG_V3_GROWTH;
+        let config_offset = std::mem::offset_of!(Reserve, config);
+        let proposal_config_offset = std::mem::offset_of!(Reserve, proposed_configs)
+            - RESERVE_CONFIG_V3_GROWTH
+            + (MAX_CONFIG_PROPOSALS - 1) * old_proposal_size
+            + std::mem::offset_of!(ConfigProposal, config);
+        for (offset, max_total_borrow) in [(config_offset, 1000u64), (proposal_config_offset, 2000)]
+        {
+            let offset = offset + RESERVE_CONFIG_V2_MAX_TOTAL_BORROW_OFFSET;
+            data[offset..offset + 8].copy_from_slice(&max_total_borrow.to_le_bytes());
+        }
+
+        Reserve::migrate(&mut data).expect("migrate");
+        assert_eq!(data, bytemuck::bytes_of(&reserve));
+    }
+
+    #[test]
+    fn supply_rate_and_snapshots() {
+        let mut reserve = test_reserve(1000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Kinked(KinkedIrm::new(
+                200, 400, 8000, 6000,
+            )));
+        reserve
+            .liquidity
+            .borrow(Decimal::from_lamports(500, 9).unwrap(), 500)
+            .unwrap();
+        let bps = |value| Decimal::from_basis_points(value).unwrap();
+
+        // 4.5% paid by half of the liquidity. Texture takes 10% of that.
+        let borrow_rate = reserve.current_borrow_rate(None).unwrap();
+        assert_eq!(borrow_rate, bps(450));
+        assert_eq!(
+            reserve
+                .liquidity
+                .current_supply_rate(borrow_rate, 0, 0, 0)
+                .unwrap(),
+            bps(225)
+        );
+        assert_eq!(
+            reserve
+                .liquidity
+                .current_supply_rate(borrow_rate, 0, 1000, 0)
+                .unwrap(),
+            bps(225).checked_mul(bps(9000)).unwrap()
+        );
+
+        assert!(reserve.rate_snapshots().is_empty());
+        assert!(reserve.latest_rate_snapshot().is_none());
+
+        reserve.accrue_interest(3600, 0, None).unwrap();
+        assert_eq!(reserve.liquidity.borrow_rate().unwrap(), bps(450));
+        assert!(reserve.liquidity.supply_rate().unwrap() >= bps(225));
+
+        let day = 20_000;
+        let timestamp = day as i64 * RATE_SNAPSHOT_INTERVAL_SEC + 3600;
+        reserve.take_rate_snapshot(timestamp).unwrap();
+        let snapshot = reserve.latest_rate_snapshot().unwrap();
+        assert_eq!(snapshot.day, day);
+        assert_eq!(snapshot.timestamp(), timestamp - 3600);
+        assert_eq!(snapshot.borrow_rate_bps, 450);
+        assert_eq!(snapshot.supply_rate_bps, 225);
+        assert_eq!(snapshot.utilization_bps, 5000);
+
+        // Not more than one snapshot a day
+        reserve
+            .take_rate_snapshot(snapshot.timestamp() + RATE_SNAPSHOT_INTERVAL_SEC - 1)
+            .unwrap();
+        assert_eq!(reserve.rate_snapshots(), vec![snapshot]);
+
+        // Ring buffer keeps the most recent snapshots in chronological order
+        for index in 1..=MAX_RATE_SNAPSHOTS as i64 {
+            reserve
+                .take_rate_snapshot(timestamp + index * RATE_SNAPSHOT_INTERVAL_SEC)
+                .unwrap();
+        }
+        let snapshots = reserve.rate_snapshots();
+        assert_eq!(snapshots.len(), MAX_RATE_SNAPSHOTS);
+        for (index, snapshot) in snapshots.iter().enumerate() {
+            assert_eq!(snapshot.day, day + index as u16 + 1);
+        }
+        assert_eq!(reserve.latest_rate_snapshot(), snapshots.last().copied());
+
+        // Rates far above 655.35% (e.g. from adaptive IRM) are not saturated
+        reserve.liquidity.set_borrow_rate(bps(1_000_000)).unwrap();
+        reserve
+            .take_rate_snapshot(
+                timestamp + (MAX_RATE_SNAPSHOTS as i64 + 1) * RATE_SNAPSHOT_INTERVAL_SEC,
+            )
+            .unwrap();
+        assert_eq!(
+            reserve.latest_rate_snapshot().unwrap().borrow_rate_bps,
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn insurance_fee() {
+        let mut reserve = test_reserve(1000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Kinked(KinkedIrm::new(
+                200, 400, 8000, 6000,
+            )));
+        reserve.config.fees.insurance_fee_rate_bps = 1000;
+        reserve
+            .liquidity
+            .borrow(Decimal::from_lamports(500, 9).unwrap(), 500)
+            .unwrap();
+        let bps = |value| Decimal::from_basis_points(value).unwrap();
+
+        // Insurance fund takes 10% of the interest
+        reserve
+            .accrue_interest(SECONDS_PER_YEAR as UnixTimestamp, 0, None)
+            .unwrap();
+        let interest = reserve
+            .liquidity
+            .borrowed_amount()
+            .unwrap()
+            .checked_sub(Decimal::from_lamports(500, 9).unwrap())
+            .unwrap();
+        assert_eq!(interest.to_lamports_floor(9).unwrap(), 23);
+        assert_eq!(
+            reserve.liquidity.insurance_fee().unwrap(),
+            interest.checked_mul(bps(1000)).unwrap()
+        );
+
+        // ... and LPs earn less the same way as with performance fee
+        assert_eq!(
+            reserve
+                .liquidity
+                .current_supply_rate(bps(450), 0, 0, 1000)
+                .unwrap(),
+            reserve
+                .liquidity
+                .current_supply_rate(bps(450), 0, 1000, 0)
+                .unwrap()
+        );
+
+        // Accrued fee is not LPs' liquidity
+        let total_liquidity = reserve.liquidity.total_liquidity().unwrap();
+        assert_eq!(
+            total_liquidity,
+            Decimal::from_lamports(500 + 500, 9)
+                .unwrap()
+                .checked_add(interest)
+                .unwrap()
+                .checked_sub(reserve.liquidity.insurance_fee().unwrap())
+                .unwrap()
+        );
+
+        // Whole lamports are claimed out of available liquidity. LP exchange rate stays the same.
+        let available_amount = reserve.liquidity.available_amount;
+        assert_eq!(reserve.liquidity.claim_insurance_fee().unwrap(), 2);
+        assert_eq!(reserve.liquidity.available_amount, available_amount - 2);
+        assert_eq!(
+            reserve.liquidity.total_liquidity().unwrap(),
+            total_liquidity
         );
+        assert!(reserve.liquidity.insurance_fee().unwrap() < Decimal::from_lamports(1, 9).unwrap());
 
-        // Same reserve with same rates BUT with curator and Texture performance fees turned on.
-        let mut test_reserve1 = crate::state::reserve::tests::test_reserve(1000);
-        // Borrowed amount is 1000
-        test_reserve1
+        // Claim is limited by available liquidity
+        reserve
             .liquidity
-            .set_borrowed_amount(Decimal::from_lamports(1000, 9).unwrap())
+            .set_insurance_fee(Decimal::from_lamports(600, 9).unwrap())
             .unwrap();
+        assert_eq!(
+            reserve.liquidity.claim_insurance_fee().unwrap(),
+            available_amount - 2
+        );
+        assert_eq!(reserve.liquidity.available_amount, 0);
+        assert_eq!(
+            reserve.liquidity.insurance_fee().unwrap(),
+            Decimal::from_lamports(102, 9).unwrap()
+        );
+        assert_eq!(reserve.liquidity.claim_insurance_fee().unwrap(), 0);
+    }
 
-        let current_borrow_rate = Decimal::from_basis_points(2000).expect("current_borrow_rate"); // 20%
-        let curator_performance_fee_rate_bps = 2000; // 20%
-        let texture_performance_fee_rate_bps = 3000; // 30%
-        let slots_elapsed = SLOTS_PER_YEAR;
+    #[test]
+    fn borrow_rate_bounds() {
+        let mut reserve = test_reserve(1000);
+        reserve
+            .config
+            .set_interest_rate_model(InterestRateModel::Kinked(KinkedIrm::new(
+                200, 400, 8000, 6000,
+            )));
+        let bps = |value| Decimal::from_basis_points(value).unwrap();
 
-        test_reserve1
+        // No bounds - IRM rate as is
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(200));
+
+        reserve.config.min_borrow_rate_bps = 300;
+        reserve.config.validate().unwrap();
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(300));
+
+        reserve
             .liquidity
-            .compound_interest(
-                current_borrow_rate,
-                curator_performance_fee_rate_bps,
-                texture_performance_fee_rate_bps,
-                slots_elapsed,
-            )
-            .expect("interest");
+            .borrow(Decimal::from_lamports(1000, 9).unwrap(), 1000)
+            .unwrap();
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(6600));
 
-        // Interest for the period is ~21 USDC. All interest goes in to borrowed_amount
-        assert_eq!(
-            test_reserve1
-                .liquidity
-                .borrowed_amount()
-                .unwrap()
-                .to_lamports_round(9)
-                .unwrap(),
-            1221
-        );
+        reserve.config.max_borrow_rate_bps = 5000;
+        reserve.config.validate().unwrap();
+        assert_eq!(reserve.current_borrow_rate(None).unwrap(), bps(5000));
 
-        // Cumulative borrow rate stays the same as in situation without perf. fees.
-        assert_eq!(
-            test_reserve.liquidity.cumulative_borrow_rate().unwrap(),
-            Decimal::from_i128_with_scale(1221402757772865561, 18).unwrap()
+        reserve.config.max_borrow_rate_bps = 200;
+        assert_matches!(
+            reserve.config.validate(),
+            Err(SuperLendyError::InvalidConfig)
         );
     }
+
     #[test]
     fn calc_borrow() {
         let liquidity_available_amount = 1_000_000_000;
@@ -2209,6 +4899,55 @@ mod tests {
         assert_eq!(two_percents, 19047619);
     }
 
+    #[test]
+    fn calc_borrow_max_total_borrow() {
+        let liquidity_available_amount = 1_000_000_000;
+
+        let mut test_reserve = test_reserve(liquidity_available_amount);
+        test_reserve.config.fees.curator_borrow_fee_rate_bps = 0;
+        test_reserve.config.max_total_borrow = 500_000;
+        test_reserve
+            .liquidity
+            .set_borrowed_amount(Decimal::from_lamports(200_000, 9).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            test_reserve.remaining_borrow_capacity().unwrap(),
+            Some(Decimal::from_lamports(300_000, 9).unwrap())
+        );
+
+        let max_borrow_value = Decimal::from_i128_with_scale(100000, 0).unwrap();
+
+        // Borrow above the cap
+        assert_matches!(
+            test_reserve.calculate_borrow(300_001, max_borrow_value, 0),
+            Err(SuperLendyError::ResourceExhausted)
+        );
+
+        // Borrow exactly up to the cap
+        let borrow_result = test_reserve
+            .calculate_borrow(300_000, max_borrow_value, 0)
+            .unwrap();
+        assert_eq!(borrow_result.receive_amount, 300_000);
+
+        // Borrow max is limited by the cap
+        let borrow_result = test_reserve
+            .calculate_borrow(u64::MAX, max_borrow_value, 0)
+            .unwrap();
+        assert_eq!(
+            borrow_result.borrow_amount,
+            Decimal::from_lamports(300_000, 9).unwrap()
+        );
+        assert_eq!(
+            test_reserve.max_borrow_amount().unwrap(),
+            Decimal::from_lamports(300_000, 9).unwrap()
+        );
+
+        // No cap
+        test_reserve.config.max_total_borrow = 0;
+        assert_eq!(test_reserve.remaining_borrow_capacity().unwrap(), None);
+    }
+
     #[test]
     fn calc_repay() {
         let liquidity_available_amount = 1_000_000_000;
@@ -2235,6 +4974,73 @@ mod tests {
         assert_eq!(repay_result.repay_amount, 100000);
     }
 
+    #[test]
+    fn flash_loan_fee() {
+        let liquidity_available_amount = 1_000_000_000;
+
+        let mut test_reserve = test_reserve(liquidity_available_amount);
+        test_reserve.liquidity.curator_performance_fee = 0;
+        test_reserve.liquidity.texture_performance_fee = 0;
+
+        // Zero fee rate - no fee
+        assert_eq!(
+            test_reserve
+                .config
+                .fees
+                .calculate_flash_loan_fee(1_000_000, 9)
+                .unwrap(),
+            0
+        );
+
+        test_reserve.config.fees.flash_loan_fee_bps = 9; // 0.09%
+        test_reserve.config.fees.curator_performance_fee_rate_bps = 1000; // 10%
+
+        let fee = test_reserve
+            .config
+            .fees
+            .calculate_flash_loan_fee(1_000_000, 9)
+            .unwrap();
+        assert_eq!(fee, 900);
+
+        // Tiny amount still pays 1 lamport
+        assert_eq!(
+            test_reserve
+                .config
+                .fees
+                .calculate_flash_loan_fee(1, 9)
+                .unwrap(),
+            1
+        );
+
+        let lp_rate_before = test_reserve.lp_exchange_rate().unwrap();
+
+        // Texture takes 20% of the fee
+        test_reserve
+            .liquidity
+            .accrue_flash_loan_fee(fee, 1000, 2000)
+            .unwrap();
+
+        assert_eq!(
+            test_reserve.liquidity.available_amount,
+            liquidity_available_amount + fee
+        );
+        assert_eq!(
+            test_reserve.liquidity.curator_performance_fee().unwrap(),
+            Decimal::from_lamports(90, 9).unwrap()
+        );
+        assert_eq!(
+            test_reserve.liquidity.texture_performance_fee().unwrap(),
+            Decimal::from_lamports(180, 9).unwrap()
+        );
+
+        // LPs got their part
+        let lp_rate_after = test_reserve.lp_exchange_rate().unwrap();
+        assert!(
+            lp_rate_after.lp_to_liquidity(1_000_000_000).unwrap()
+                > lp_rate_before.lp_to_liquidity(1_000_000_000).unwrap()
+        );
+    }
+
     #[test]
     fn calc_max_withdraw_amount() {
         let liquidity_available_amount = 1_000_000_000; // 1 SOL
@@ -2337,6 +5143,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2428,6 +5237,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2528,6 +5340,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2629,6 +5444,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2718,6 +5536,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2816,6 +5637,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -2859,6 +5683,260 @@ mod tests {
 
     // User's position meets criteria for FULL liquidation. Moreover - there is already bad debt.
     // Liquidator asks for max possible amount.
+    #[test]
+    fn borrow_weight() {
+        let mut config = test_reserve(1_000_000_000).config;
+        config.fees.curator_borrow_fee_rate_bps = 100;
+
+        // 0 - weight is not set and borrowed value is taken as is
+        config.borrow_weight_bps = 0;
+        assert!(config.validate().is_ok());
+        assert_eq!(config.borrow_weight().unwrap(), Decimal::ONE);
+
+        // Weight can not decrease borrowed value
+        config.borrow_weight_bps = 9_000;
+        assert!(config.validate().is_err());
+
+        config.borrow_weight_bps = 15_000;
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.borrow_weight().unwrap(),
+            Decimal::from_basis_points(15_000).unwrap()
+        );
+    }
+
+    // User's LTV is 80% and partly_unhealthy_ltv 84% but borrowed liquidity has weight 1.1 thus
+    // risk-adjusted LTV is 88% and position can be liquidated.
+    #[test]
+    fn liquidation_with_borrow_weight() {
+        let principal_market_price = Decimal::from_i128_with_scale(80, 0).unwrap(); // Assume principal is SOL
+        let collateral_market_price = Decimal::from_i128_with_scale(1, 0).unwrap(); // Assume collateral is USDC
+        let reserve_borrowed_amount = Decimal::from_lamports(100_000_000_000, 9).unwrap();
+
+        let user_borrowed_amount = Decimal::from_lamports(1_000_000_000, 9).unwrap(); // 1 SOL
+        let user_borrowed_value = user_borrowed_amount
+            .checked_mul(principal_market_price)
+            .unwrap();
+        let user_weighted_borrowed_value = user_borrowed_value
+            .checked_mul(Decimal::from_basis_points(11_000).unwrap())
+            .unwrap();
+        let user_collateral_amount = Decimal::from_lamports(100_000_000, 6).unwrap(); // 100 USDC
+        let user_collateral_value = user_collateral_amount
+            .checked_mul(collateral_market_price)
+            .unwrap();
+
+        let mut reserve = test_reserve(1_000_000_000_000);
+        reserve
+            .liquidity
+            .set_borrowed_amount(reserve_borrowed_amount)
+            .unwrap();
+        reserve
+            .liquidity
+            .set_market_price(principal_market_price)
+            .unwrap();
+        reserve.config.partly_unhealthy_ltv_bps = 8400;
+        reserve.config.fully_unhealthy_ltv_bps = 9000;
+        reserve.config.partial_liquidation_factor_bps = 2000;
+        reserve.config.liquidation_bonus_bps = 10;
+
+        let deposits: [DepositedCollateral; MAX_DEPOSITS] = Zeroable::zeroed();
+        let borrows: [BorrowedLiquidity; MAX_BORROWS] = Zeroable::zeroed();
+
+        let mut position = Position::new(Default::default(), Default::default(), deposits, borrows);
+
+        position.set_deposited_value(user_collateral_value).unwrap();
+        position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_weighted_borrowed_value)
+            .unwrap();
+
+        let mut borrowed_liquidity = BorrowedLiquidity::new(Pubkey::new_unique(), Decimal::ONE);
+        borrowed_liquidity
+            .set_borrowed_amount(user_borrowed_amount)
+            .unwrap();
+        borrowed_liquidity
+            .set_market_value(user_borrowed_value)
+            .unwrap();
+
+        let mut collateral = DepositedCollateral::new(Pubkey::new_unique());
+        collateral.set_market_value(user_collateral_value).unwrap();
+        collateral.deposited_amount = user_collateral_amount.to_lamports_round(6).unwrap();
+
+        assert_eq!(
+            position.ltv().unwrap(),
+            Decimal::from_basis_points(8000).unwrap()
+        );
+        assert_eq!(
+            position.weighted_ltv().unwrap(),
+            Decimal::from_basis_points(8800).unwrap()
+        );
+
+        let calc_result = reserve
+            .calculate_liquidation(
+                100_000_000, // 0.1 SOL
+                &position,
+                &borrowed_liquidity,
+                &collateral,
+                reserve.liquidity.mint_decimals,
+            )
+            .unwrap();
+
+        // Weight affects only health check. Liquidation amounts are calculated from plain values.
+        assert_eq!(calc_result.repay_amount, 100_000_000);
+        assert_eq!(calc_result.withdraw_amount, 8_008_000);
+        // 0.1% bonus
+        assert_eq!(calc_result.bonus_amount, 8_000);
+    }
+
+    #[test]
+    fn liquidation_fees() {
+        let mut fees = test_reserve(1_000_000_000_000).config.fees;
+
+        fees.curator_liquidation_fee_share_bps = 0;
+        assert_eq!(
+            fees.calculate_liquidation_fees(149_850_149, 0).unwrap(),
+            (0, 0)
+        );
+
+        fees.curator_liquidation_fee_share_bps = 1000; // 10 %
+        assert_eq!(
+            fees.calculate_liquidation_fees(149_850_149, 2000).unwrap(),
+            (14_985_014, 29_970_029)
+        );
+        assert_eq!(fees.calculate_liquidation_fees(0, 2000).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn redeemable_liquidity() {
+        let mut reserve = test_reserve(1_000);
+
+        assert_eq!(reserve.redeemable_liquidity(1_000).unwrap(), Some(1_000));
+        assert_eq!(reserve.redeemable_liquidity(1_001).unwrap(), None);
+        assert_eq!(reserve.redeemable_liquidity(0).unwrap(), None);
+
+        reserve.mode = RESERVE_MODE_RETAIN_LIQUIDITY;
+        assert_eq!(reserve.redeemable_liquidity(500).unwrap(), None);
+    }
+
+    #[test]
+    fn repay_with_collateral() {
+        let mut reserve = test_reserve(1_000);
+        reserve
+            .liquidity
+            .set_borrowed_amount(Decimal::from_lamports(1_000, 9).unwrap())
+            .unwrap();
+        // LP exchange rate = (1_250 + VIRTUAL_LP_AMOUNT) / (2_000 + VIRTUAL_LIQUIDITY_AMOUNT) = 0.75
+        reserve.collateral.lp_total_supply = 1_250;
+        let borrowed = Decimal::from_lamports(300, 9).unwrap();
+
+        let result = reserve
+            .calculate_repay_with_collateral(100, borrowed, 1_000)
+            .unwrap();
+        assert_eq!(result.repay_amount, 100);
+        assert_eq!(result.lp_amount, 75);
+
+        // Rounding up LP amount
+        let result = reserve
+            .calculate_repay_with_collateral(101, borrowed, 1_000)
+            .unwrap();
+        assert_eq!(result.repay_amount, 101);
+        assert_eq!(result.lp_amount, 76);
+
+        // Capped by borrowed amount
+        let result = reserve
+            .calculate_repay_with_collateral(MAX_AMOUNT, borrowed, 1_000)
+            .unwrap();
+        assert_eq!(result.settle_amount, borrowed);
+        assert_eq!(result.repay_amount, 300);
+        assert_eq!(result.lp_amount, 225);
+
+        // Capped by collateral
+        let result = reserve
+            .calculate_repay_with_collateral(MAX_AMOUNT, borrowed, 100)
+            .unwrap();
+        assert_eq!(result.repay_amount, 133);
+        assert_eq!(result.lp_amount, 100);
+
+        let total_liquidity = reserve.liquidity.total_liquidity().unwrap();
+        reserve
+            .repay_with_collateral(result.lp_amount, result.settle_amount)
+            .unwrap();
+        assert_eq!(reserve.liquidity.available_amount, 1_000);
+        assert_eq!(reserve.collateral.lp_total_supply, 1_150);
+        assert_eq!(
+            reserve.liquidity.total_liquidity().unwrap(),
+            total_liquidity
+                .checked_sub(Decimal::from_lamports(133, 9).unwrap())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn dynamic_liquidation_bonus() {
+        let mut config = test_reserve(1_000_000_000_000).config;
+        config.partly_unhealthy_ltv_bps = 8000;
+        config.fully_unhealthy_ltv_bps = 9000;
+        config.liquidation_bonus_bps = 100; // 1 %
+        config.max_liquidation_bonus_bps = 0;
+
+        let bps = |bps: u32| Decimal::from_basis_points(bps).unwrap();
+
+        // Flat bonus when dynamic one is off
+        assert_eq!(
+            config.liquidation_bonus(bps(8500), bps(8500)).unwrap(),
+            bps(100)
+        );
+        assert_eq!(
+            config.liquidation_bonus(bps(9500), bps(9500)).unwrap(),
+            bps(100)
+        );
+
+        config.max_liquidation_bonus_bps = 50;
+        assert!(config.validate().is_err());
+        config.max_liquidation_bonus_bps = 5_001;
+        assert!(config.validate().is_err());
+        config.max_liquidation_bonus_bps = 1100; // 11 %
+        assert!(config.validate().is_ok());
+
+        // Min bonus at partly unhealthy LTV
+        assert_eq!(
+            config.liquidation_bonus(bps(8000), bps(8000)).unwrap(),
+            bps(100)
+        );
+        // Half way between partly and fully unhealthy LTVs
+        assert_eq!(
+            config.liquidation_bonus(bps(8500), bps(8500)).unwrap(),
+            bps(600)
+        );
+        // Max bonus at fully unhealthy LTV
+        assert_eq!(
+            config.liquidation_bonus(bps(9000), bps(9000)).unwrap(),
+            bps(1100)
+        );
+        // Weighted LTV drives interpolation while plain LTV is far from insolvency
+        assert_eq!(
+            config.liquidation_bonus(bps(8500), bps(7000)).unwrap(),
+            bps(600)
+        );
+
+        // Max bonus is capped by insolvency: 95% LTV leaves only ~5.26% of collateral value above debt
+        let insolvency_bonus = Decimal::ONE
+            .checked_div(bps(9500))
+            .unwrap()
+            .checked_sub(Decimal::ONE)
+            .unwrap();
+        assert_eq!(
+            config.liquidation_bonus(bps(9500), bps(9500)).unwrap(),
+            insolvency_bonus
+        );
+
+        // Insolvent position gives no bonus
+        assert_eq!(
+            config.liquidation_bonus(bps(10_500), bps(10_500)).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
     // Contract should allow as much of borrowed amount as mush collateral is there taking in to
     // account liquidation_bonus.
     #[test]
@@ -2914,6 +5992,9 @@ mod tests {
 
         position.set_deposited_value(user_collateral_value).unwrap();
         position.set_borrowed_value(user_borrowed_value).unwrap();
+        position
+            .set_weighted_borrowed_value(user_borrowed_value)
+            .unwrap();
         position
             .set_partly_unhealthy_borrow_value(partly_unhealthy_borrow_value)
             .unwrap();
@@ -39,7 +39,19 @@ pub struct TextureConfig {
 
     pub reserve_timelock: ReserveTimelock,
 
-    pub _padding: [u8; 32 * 8],
+    /// Part of (any) flash loan fee which will be paid to Texture. Rest of the fee is split
+    /// between Curator and LPs.
+    pub flash_loan_fee_share_bps: u16,
+
+    /// Part of (any) liquidation bonus which will be paid to Texture. Taken from the collateral
+    /// given to liquidator and accumulated (as LP tokens) on the collateral Reserve.
+    pub liquidation_fee_share_bps: u16,
+
+    /// Swap program RepayWithCollateralSwap is allowed to call. Default (zero) key means that
+    /// swap-based deleveraging is disabled.
+    pub swap_program: Pubkey,
+
+    pub _padding: [u8; 32 * 7 - 8],
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
@@ -55,6 +67,15 @@ pub struct TextureConfigParams {
     pub fees_authority: Pubkey,
 
     pub reserve_timelock: ReserveTimelock,
+
+    /// Part of (any) flash loan fee which will be paid to Texture
+    pub flash_loan_fee_share_bps: u16,
+
+    /// Part of (any) liquidation bonus which will be paid to Texture
+    pub liquidation_fee_share_bps: u16,
+
+    /// Swap program allowed for swap-based deleveraging. Default (zero) key disables it.
+    pub swap_program: Pubkey,
 }
 
 impl PodAccount for TextureConfig {
@@ -89,6 +110,9 @@ impl PodAccount for TextureConfig {
             owner,
             fees_authority,
             reserve_timelock,
+            flash_loan_fee_share_bps,
+            liquidation_fee_share_bps,
+            swap_program,
             _padding,
         } = self;
 
@@ -99,6 +123,9 @@ impl PodAccount for TextureConfig {
         *performance_fee_rate_bps = params.performance_fee_rate_bps;
         *borrow_fee_rate_bps = params.borrow_fee_rate_bps;
         *reserve_timelock = params.reserve_timelock;
+        *flash_loan_fee_share_bps = params.flash_loan_fee_share_bps;
+        *liquidation_fee_share_bps = params.liquidation_fee_share_bps;
+        *swap_program = params.swap_program;
         *_padding = Zeroable::zeroed();
         *_flags = Zeroable::zeroed();
 
@@ -118,6 +145,16 @@ impl TextureConfigParams {
             return Err(SuperLendyError::InvalidConfig);
         }
 
+        if self.flash_loan_fee_share_bps > 5000 {
+            msg!("Flash loan fee share must be in range [0, 50] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
+        if self.liquidation_fee_share_bps > 5000 {
+            msg!("Liquidation fee share must be in range [0, 50] %");
+            return Err(SuperLendyError::InvalidConfig);
+        }
+
         self.reserve_timelock.validate()?;
 
         Ok(())
@@ -142,7 +179,8 @@ pub struct ReserveTimelock {
     pub price_stale_threshold_lock_sec: u32,
     pub max_withdraw_utilization_lock_sec: u32,
     pub fees_lock_sec: u32,
-    pub _padding: u32,
+    pub max_total_borrow_lock_sec: u32,
+    pub borrow_weight_lock_sec: u32,
 }
 
 const SECONDS_IN_TWO_WEEKS: u32 = 604800 * 2;
@@ -159,6 +197,8 @@ impl ReserveTimelock {
             || self.price_stale_threshold_lock_sec > SECONDS_IN_TWO_WEEKS
             || self.max_withdraw_utilization_lock_sec > SECONDS_IN_TWO_WEEKS
             || self.fees_lock_sec > SECONDS_IN_TWO_WEEKS
+            || self.max_total_borrow_lock_sec > SECONDS_IN_TWO_WEEKS
+            || self.borrow_weight_lock_sec > SECONDS_IN_TWO_WEEKS
         {
             msg!(
                 "time lock can not be greater than two weeks: {} sec",
@@ -7,35 +7,38 @@ use price_proxy::state::utils::str_to_array;
 use solana_program::instruction::AccountMeta;
 use solana_program::native_token::LAMPORTS_PER_SOL;
 use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClientError, ProgramTestBanksClientExt, ProgramTestContext};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
 use spl_associated_token_account::get_associated_token_address;
 use texture_common::account::PodAccount;
-use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, Decimal};
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal};
 use tracing::info;
 
-use super_lendy::instruction::{RefreshReserve, SuperLendyInstruction};
+use super_lendy::instruction::{AlterReserve, RefreshReserve, SuperLendyInstruction};
 use super_lendy::pda::{find_liquidity_supply, find_lp_token_mint, find_program_authority};
-use super_lendy::state::pool::PoolParams;
+use super_lendy::state::pool::{EmodeCategory, PoolParams};
 use super_lendy::state::position::{Position, BORROW_MEMO_LEN};
 use super_lendy::state::reserve::{
-    FeeCalculation, Reserve, ReserveConfig, ReserveFeesConfig, RESERVE_TYPE_NORMAL,
+    FeeCalculation, Reserve, ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE,
+    RESERVE_TYPE_ISOLATED_COLLATERAL, RESERVE_TYPE_NORMAL,
 };
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfig, TextureConfigParams};
-use super_lendy::state::SLOTS_PER_YEAR;
+use super_lendy::state::{SECONDS_PER_YEAR, VIRTUAL_LIQUIDITY_AMOUNT, VIRTUAL_LP_AMOUNT};
 use super_lendy::{MAX_AMOUNT, SUPER_LENDY_ID, TEXTURE_CONFIG_ID};
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
     alter_reserve, alter_texture_config, borrow, create_pool, create_position, create_reserve,
-    deposit_liquidity, lock_collateral, refresh_position, refresh_position_ix,
+    deposit_liquidity, lock_collateral, refresh_position, refresh_position_ix, repay,
+    set_emode_category, set_position_emode,
 };
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
     create_associated_token_account, get_account, get_token_account, init_program_test,
-    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
-    LAMPORTS_PER_USDC,
+    init_token_accounts, lender_keypair, texture_config_keypair, warp_clock, warp_seconds, Runner,
+    LAMPORTS, LAMPORTS_PER_USDC,
 };
 
 pub mod utils;
@@ -706,10 +709,12 @@ async fn borrow_fees_success() {
         texture_fee_token_acc0.amount + texture_fee
     );
 
-    // 1 SOLANA YEAR LATER
+    let reserve_borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR).expect("warp_to_slot"); // solana_year = 63072000 slots
+    // 1 YEAR LATER
+
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -721,27 +726,39 @@ async fn borrow_fees_success() {
         .expect("get position");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
+        .checked_pow(SECONDS_PER_YEAR)
         .unwrap();
 
     let curator_performance_fee = reserve.liquidity.curator_performance_fee().unwrap();
     let texture_performance_fee = reserve.liquidity.texture_performance_fee().unwrap();
+    let interest = reserve
+        .liquidity
+        .borrowed_amount()
+        .unwrap()
+        .checked_sub(reserve_borrowed_amount)
+        .unwrap();
 
-    // CHECK PERFORMANCE FEES
+    // CHECK PERFORMANCE FEES. Curator takes 20% and Texture 40% of the interest.
     assert_eq!(
         curator_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(128858068, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(2000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
     assert_eq!(
         texture_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(257716136, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(4000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
 
     let exp_borrowed_amount = Decimal::from_i128_with_scale(131, 2) // borrowed_amount = receive_amount + fees
@@ -838,6 +855,9 @@ async fn borrow_lp_exchange_rate_success() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     alter_reserve(
@@ -860,6 +880,9 @@ async fn borrow_lp_exchange_rate_success() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     alter_reserve(
@@ -879,6 +902,9 @@ async fn borrow_lp_exchange_rate_success() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -892,7 +918,8 @@ async fn borrow_lp_exchange_rate_success() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
@@ -1100,11 +1127,10 @@ async fn borrow_lp_exchange_rate_success() {
     assert_eq!(curator_fee_token_acc1.amount, curator_fee_token_acc0.amount);
     assert_eq!(texture_fee_token_acc1.amount, texture_fee_token_acc0.amount);
 
-    // 1 SLOT LATER
+    // 1 SECOND LATER
 
-    let slot = 2_u64;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
+    info!("warp 1 second");
+    warp_seconds(&mut ctx, 1).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -1116,11 +1142,11 @@ async fn borrow_lp_exchange_rate_success() {
         .expect("get position");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
         .checked_pow(1)
@@ -1143,11 +1169,13 @@ async fn borrow_lp_exchange_rate_success() {
     assert_eq!(borrowed_amount.round_to_decimals(9), exp_borrowed_amount);
 
     let lp_exchange_rate0 = reserve.lp_exchange_rate().unwrap();
-    let lp_total_supply = Decimal::from_lamports(1_000 * LAMPORTS_PER_SOL, 9).unwrap(); // received when lender deposited 1_000 SOL
-    let total_liquidity = Decimal::from_lamports(999 * LAMPORTS_PER_SOL, 9) // cause borrowed 1 SOL
-        .unwrap()
-        .checked_add(borrowed_amount) // debt
-        .unwrap();
+    let lp_total_supply =
+        Decimal::from_lamports(1_000 * LAMPORTS_PER_SOL + VIRTUAL_LP_AMOUNT, 9).unwrap(); // received when lender deposited 1_000 SOL
+    let total_liquidity =
+        Decimal::from_lamports(999 * LAMPORTS_PER_SOL + VIRTUAL_LIQUIDITY_AMOUNT, 9) // cause borrowed 1 SOL
+            .unwrap()
+            .checked_add(borrowed_amount) // debt
+            .unwrap();
 
     // CHECK LP EXCHANGE RATE
     assert_eq!(
@@ -1155,11 +1183,10 @@ async fn borrow_lp_exchange_rate_success() {
         lp_total_supply.checked_div(total_liquidity).unwrap()
     );
 
-    // 1 SOLANA YEAR LATER
+    // 1 YEAR LATER
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR + slot)
-        .expect("warp_to_slot"); // solana_year = 63072000 slots
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -1171,14 +1198,14 @@ async fn borrow_lp_exchange_rate_success() {
         .expect("get position");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
+        .checked_pow(SECONDS_PER_YEAR)
         .unwrap();
     let exp_borrowed_amount = borrowed_amount // debt of past period
         .checked_mul(compounded_interest_rate)
@@ -1193,11 +1220,13 @@ async fn borrow_lp_exchange_rate_success() {
     );
 
     let lp_exchange_rate1 = reserve.lp_exchange_rate().unwrap();
-    let lp_total_supply = Decimal::from_lamports(1_000 * LAMPORTS_PER_SOL, 9).unwrap(); // received when lender deposited 1_000 SOL
-    let total_liquidity = Decimal::from_lamports(999 * LAMPORTS_PER_SOL, 9) // cause borrowed 1 SOL
-        .unwrap()
-        .checked_add(borrowed_amount) // debt
-        .unwrap();
+    let lp_total_supply =
+        Decimal::from_lamports(1_000 * LAMPORTS_PER_SOL + VIRTUAL_LP_AMOUNT, 9).unwrap(); // received when lender deposited 1_000 SOL
+    let total_liquidity =
+        Decimal::from_lamports(999 * LAMPORTS_PER_SOL + VIRTUAL_LIQUIDITY_AMOUNT, 9) // cause borrowed 1 SOL
+            .unwrap()
+            .checked_add(borrowed_amount) // debt
+            .unwrap();
 
     // CHECK LP EXCHANGE RATE
     assert_eq!(
@@ -1292,6 +1321,9 @@ async fn borrow_check_interest_success() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     alter_reserve(
@@ -1314,6 +1346,9 @@ async fn borrow_check_interest_success() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     alter_reserve(
@@ -1333,6 +1368,9 @@ async fn borrow_check_interest_success() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -1346,17 +1384,18 @@ async fn borrow_check_interest_success() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
         .await
         .expect("alter_texture_config");
 
-    // 1 SOLANA YEAR LATER
+    // 1 YEAR LATER
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR).expect("warp_to_slot"); // solana_year = 63072000 slots
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -1508,10 +1547,10 @@ async fn borrow_check_interest_success() {
     let reserve_borrowed_amount_wads = reserve.liquidity.borrowed_amount().unwrap();
     assert_eq!(position_borrowed_amount_wads, reserve_borrowed_amount_wads);
 
-    // 1 SOLANA YEAR LATER
+    // 1 YEAR LATER
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR * 2);
-    ctx.warp_to_slot(SLOTS_PER_YEAR * 2).expect("warp_to_slot"); // solana_year = 63072000 slots
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -1523,14 +1562,14 @@ async fn borrow_check_interest_success() {
         .expect("get position");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
+        .checked_pow(SECONDS_PER_YEAR)
         .unwrap();
     let exp_borrowed_amount = Decimal::ONE
         .checked_mul(compounded_interest_rate)
@@ -1544,10 +1583,9 @@ async fn borrow_check_interest_success() {
     assert_eq!(borrowed_amount.round_to_decimals(9), exp_borrowed_amount);
 }
 
-/// See test description in
-/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#fe0a4a8c29d54e939ad388c8bf8b856d
+/// Interest must be accrued by Solana time elapsed. Slots produced in that time do not matter.
 #[tokio::test]
-async fn borrow_incorrect_accounts() {
+async fn borrow_interest_apr_accuracy() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -1565,8 +1603,8 @@ async fn borrow_incorrect_accounts() {
 
     let pool_authority_keypair = Keypair::new();
     let pool_authority_pubkey = pool_authority_keypair.pubkey();
-    let pool1_keypair = Keypair::new();
-    let pool1_pubkey = pool1_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
 
     let curator_keypair = Keypair::new();
     let curator_pubkey = curator_keypair.pubkey();
@@ -1605,7 +1643,7 @@ async fn borrow_incorrect_accounts() {
         &admin_keypair,
         &borrower_keypair,
         &curator_keypair,
-        &pool1_keypair,
+        &pool_keypair,
         &reserve_sol1_keypair,
         &reserve_sol2_keypair,
         &reserve_usdc_keypair,
@@ -1617,6 +1655,87 @@ async fn borrow_incorrect_accounts() {
     )
     .await;
 
+    // ALTER reserve.borrow_fee & reserve.performance_fee to zero
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    // ALTER texture_config.borrow_fee & texture_config.performance_fee to zero
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
     // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
 
     let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
@@ -1627,6 +1746,7 @@ async fn borrow_incorrect_accounts() {
     let source_lender_liq_wallet_sol =
         get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
 
+    info!("deposit initial liquidity");
     deposit_liquidity(
         &mut ctx,
         reserve_sol1_pubkey,
@@ -1651,6 +1771,7 @@ async fn borrow_incorrect_accounts() {
         get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
     let deposit_usdc_amount = 1000 * LAMPORTS_PER_USDC;
 
+    info!("deposit {} into USDC reserve", deposit_usdc_amount);
     deposit_liquidity(
         &mut ctx,
         reserve_usdc_pubkey,
@@ -1668,6 +1789,7 @@ async fn borrow_incorrect_accounts() {
         .await
         .expect("refresh position");
 
+    info!("lock {} collateral lp", deposit_usdc_amount);
     lock_collateral(
         &mut ctx,
         reserve_usdc_pubkey,
@@ -1681,14 +1803,15 @@ async fn borrow_incorrect_accounts() {
     .await
     .expect("lock_collateral");
 
+    info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
         .await
-        .expect("refresh position");
+        .expect("refresh_position");
 
-    // BORROW BY USER NOT ASSOCIATED WITH POSITION
+    // BORROW 1 SOL AFTER LOCK DEPOSITED COLLATERAL
 
     let dest_borrower_liq_wallet_sol =
-        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
     let texture_fee_receiver =
         create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
             .await
@@ -1697,292 +1820,123 @@ async fn borrow_incorrect_accounts() {
         create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
             .await
             .expect("create curator fee receiver ata");
+    let amount = LAMPORTS_PER_SOL;
 
-    info!("borrow by user not associated wit position");
-    let result = borrow(
+    info!("borrow {} SOL after lock deposited collateral", amount);
+    borrow(
         &mut ctx,
         position_pubkey,
         reserve_sol1_pubkey,
         sol_price_feed,
         irm,
-        pool1_pubkey,
-        &lender_keypair,
+        pool_pubkey,
+        &borrower_keypair,
         curator_pubkey,
         curator_fee_receiver,
         texture_fee_receiver,
         dest_borrower_liq_wallet_sol,
-        LAMPORTS_PER_SOL,
+        amount,
         1,
     )
-    .await;
-
-    assert!(result.is_err());
+    .await
+    .expect("borrow");
 
-    // CREATE POOL2
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
 
-    let pool2_keypair = Keypair::new();
-    let reserve_sol2_keypair = Keypair::new();
-    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey();
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let borrowed_amount0 = reserve.liquidity.borrowed_amount().unwrap();
+    assert_eq!(
+        borrowed_amount0,
+        Decimal::from_lamports(1_000_000_000, 9).unwrap()
+    );
 
-    let pool_params = PoolParams {
-        name: [1; 128],
-        market_price_currency_symbol: str_to_array("USD"),
-        visible: 0,
-    };
+    // 1000 SLOTS LATER WITHOUT SOLANA TIME CHANGE
 
-    create_pool(
-        &mut ctx,
-        &pool2_keypair,
-        &pool_authority_keypair,
-        curator_keypair.pubkey(),
-        pool_params,
-    )
-    .await
-    .expect("create_pool");
+    info!("warp 1000 slots, 0 seconds");
+    warp_clock(&mut ctx, 1000, 0).await;
 
-    // CREATE RESERVE SOL2
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
 
-    let fees_config = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 100,
-        curator_performance_fee_rate_bps: 2000,
-        _padding: Zeroable::zeroed(),
-    };
-    let reserve_config = ReserveConfig {
-        market_price_feed: sol_price_feed,
-        irm,
-        liquidation_bonus_bps: 200,
-        max_borrow_ltv_bps: 6000,
-        partly_unhealthy_ltv_bps: 6500,
-        fully_unhealthy_ltv_bps: 7000,
-        partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
-        fees: fees_config,
-        max_total_liquidity: 10_000 * LAMPORTS_PER_SOL,
-        max_borrow_utilization_bps: 1000,
-        price_stale_threshold_sec: 1,
-        max_withdraw_utilization_bps: 9000,
-    };
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    info!("create reserve sol borrow enabled");
-    create_reserve(
-        &mut ctx,
-        &reserve_sol2_keypair,
-        pool2_keypair.pubkey(),
-        &pool_authority_keypair,
-        curator_keypair.pubkey(),
-        liquidity_sol_mint,
-        sol_price_feed,
-        reserve_config,
-        RESERVE_TYPE_NORMAL,
-    )
-    .await
-    .expect("create_reserve_sol1");
+    // CHECK NO INTEREST ACCRUED. Interest depends on elapsed time only.
+    assert_eq!(
+        reserve.liquidity.borrowed_amount().unwrap(),
+        borrowed_amount0
+    );
 
-    let dest_borrower_liq_wallet_sol =
-        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    // HALF A YEAR LATER WITH 400MS SLOTS
 
-    // BORROW WITH INCORRECT reserve_liquidity_supply
+    let half_year = SECONDS_PER_YEAR / 2;
+    info!("warp {} seconds with 400ms slots", half_year);
+    warp_clock(&mut ctx, half_year * 1000 / 400, half_year).await;
 
-    info!("borrow with incorrect reserve_liquidity_supply");
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
 
-    // choose reserve_liquidity_supply from other reserve
-    let reserve_liquidity_supply = find_liquidity_supply(&reserve_sol2_pubkey).0;
-    let program_authority = find_program_authority().0;
-    let accounts = vec![
-        AccountMeta::new(position_pubkey, false),
-        AccountMeta::new(reserve_liquidity_supply, false),
-        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
-        AccountMeta::new(curator_fee_receiver, false),
-        AccountMeta::new(borrower_pubkey, true),
-        AccountMeta::new(reserve_sol1_pubkey, false),
-        AccountMeta::new(pool1_pubkey, false),
-        AccountMeta::new(curator_pubkey, false),
-        AccountMeta::new(texture_fee_receiver, false),
-        AccountMeta::new(TEXTURE_CONFIG_ID, false),
-        AccountMeta::new(program_authority, false),
-        AccountMeta::new(spl_token::ID, false),
-    ];
+    // HALF A YEAR LATER WITH 450MS SLOTS
 
-    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
+    info!("warp {} seconds with 450ms slots", half_year);
+    warp_clock(&mut ctx, half_year * 1000 / 450, half_year).await;
 
-    ixs.push(
-        RefreshReserve {
-            reserve: reserve_sol1_pubkey,
-            market_price_feed: sol_price_feed,
-            irm,
-        }
-        .into_instruction(),
-    );
-    let ix = SuperLendyInstruction::Borrow {
-        amount: LAMPORTS_PER_SOL,
-        slippage_limit: 1,
-        memo: [0; BORROW_MEMO_LEN],
-    };
-    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
-        SUPER_LENDY_ID,
-        &ix,
-        accounts,
-    ));
-    let blockhash = ctx
-        .banks_client
-        .get_latest_blockhash()
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
         .await
-        .expect("get latest blockhash");
-    let tx = Transaction::new_signed_with_payer(
-        &ixs,
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &borrower_keypair],
-        blockhash,
-    );
-    let result = ctx.banks_client.process_transaction(tx).await;
-
-    assert!(result.is_err());
+        .expect("refresh_position");
 
-    // BORROW WITH INCORRECT POSITION
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let position_pool2_kp = Keypair::new();
-    create_position(
-        &mut ctx,
-        &position_pool2_kp,
-        pool2_keypair.pubkey(),
-        &borrower_keypair,
-    )
-    .await
-    .expect("create_position");
-
-    info!("borrow with incorrect position");
-
-    let reserve_liquidity_supply = find_liquidity_supply(&reserve_sol1_pubkey).0;
-    let program_authority = find_program_authority().0;
-    let accounts = vec![
-        AccountMeta::new(position_pool2_kp.pubkey(), false),
-        AccountMeta::new(reserve_liquidity_supply, false),
-        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
-        AccountMeta::new(curator_fee_receiver, false),
-        AccountMeta::new(borrower_pubkey, true),
-        AccountMeta::new(reserve_sol1_pubkey, false),
-        AccountMeta::new(pool1_pubkey, false),
-        AccountMeta::new(curator_pubkey, false),
-        AccountMeta::new(texture_fee_receiver, false),
-        AccountMeta::new(TEXTURE_CONFIG_ID, false),
-        AccountMeta::new(program_authority, false),
-        AccountMeta::new(spl_token::ID, false),
-    ];
-    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
-
-    ixs.push(
-        RefreshReserve {
-            reserve: reserve_sol1_pubkey,
-            market_price_feed: sol_price_feed,
-            irm,
-        }
-        .into_instruction(),
-    );
-    let ix = SuperLendyInstruction::Borrow {
-        amount: LAMPORTS_PER_SOL,
-        slippage_limit: 1,
-        memo: [0; BORROW_MEMO_LEN],
-    };
-    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
-        SUPER_LENDY_ID,
-        &ix,
-        accounts,
-    ));
-    let blockhash = ctx
-        .banks_client
-        .get_latest_blockhash()
-        .await
-        .expect("get latest blockhash");
-    let tx = Transaction::new_signed_with_payer(
-        &ixs,
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &borrower_keypair],
-        blockhash,
-    );
-    let result = ctx.banks_client.process_transaction(tx).await;
-
-    assert!(result.is_err());
-
-    // BORROW WITH INCORRECT destination_liquidity_wallet
-
-    info!("borrow with incorrect destination_liquidity_wallet");
-    let result = borrow(
-        &mut ctx,
-        position_pubkey,
-        reserve_sol1_pubkey,
-        sol_price_feed,
-        irm,
-        pool1_pubkey,
-        &borrower_keypair,
-        curator_pubkey,
-        curator_fee_receiver,
-        texture_fee_receiver,
-        reserve_liquidity_supply,
-        LAMPORTS_PER_SOL,
-        1,
-    )
-    .await;
-
-    assert!(result.is_err());
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+        .unwrap()
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
+        .unwrap();
+    let compounded_interest_rate = second_interest_rate
+        .checked_add(Decimal::ONE)
+        .unwrap()
+        .checked_pow(SECONDS_PER_YEAR)
+        .unwrap();
+    let exp_borrowed_amount = borrowed_amount0
+        .checked_mul(compounded_interest_rate)
+        .unwrap()
+        .round_to_decimals(9);
 
-    // BORROW WITH reserve_liquidity_supply = dest_borrower_liq_wallet_sol
+    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
 
-    info!("borrow with reserve_liquidity_supply = dest_borrower_liq_wallet_sol");
-    let program_authority = find_program_authority().0;
-    let accounts = vec![
-        AccountMeta::new(position_pubkey, false),
-        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
-        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
-        AccountMeta::new(curator_fee_receiver, false),
-        AccountMeta::new(borrower_pubkey, true),
-        AccountMeta::new(reserve_sol1_pubkey, false),
-        AccountMeta::new(pool1_pubkey, false),
-        AccountMeta::new(curator_pubkey, false),
-        AccountMeta::new(texture_fee_receiver, false),
-        AccountMeta::new(TEXTURE_CONFIG_ID, false),
-        AccountMeta::new(program_authority, false),
-        AccountMeta::new(spl_token::ID, false),
-    ];
-    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
+    // CHECK ONE YEAR OF INTEREST ACCRUED REGARDLESS OF SLOTS DURATION
+    info!("repayment = borrow + interest = {}", borrowed_amount);
+    assert_eq!(borrowed_amount.round_to_decimals(9), exp_borrowed_amount);
 
-    ixs.push(
-        RefreshReserve {
-            reserve: reserve_sol1_pubkey,
-            market_price_feed: sol_price_feed,
-            irm,
-        }
-        .into_instruction(),
-    );
-    let ix = SuperLendyInstruction::Borrow {
-        amount: LAMPORTS_PER_SOL,
-        slippage_limit: 1,
-        memo: [0; BORROW_MEMO_LEN],
-    };
-    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
-        SUPER_LENDY_ID,
-        &ix,
-        accounts,
-    ));
-    let blockhash = ctx
-        .banks_client
-        .get_latest_blockhash()
-        .await
-        .expect("get latest blockhash");
-    let tx = Transaction::new_signed_with_payer(
-        &ixs,
-        Some(&ctx.payer.pubkey()),
-        &[&ctx.payer, &borrower_keypair],
-        blockhash,
+    // 40% APR compounded every second gives e^0.4 - 1 = 49.18% APY
+    assert_eq!(
+        borrowed_amount
+            .checked_sub(borrowed_amount0)
+            .unwrap()
+            .round_to_decimals(4),
+        Decimal::from_i128_with_scale(4918, 4).unwrap()
     );
-    let result = ctx.banks_client.process_transaction(tx).await;
-
-    assert!(result.is_err());
 }
 
 /// See test description in
-/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#a4d002d950ac42f580f50c0cae89ae5f
+/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#fe0a4a8c29d54e939ad388c8bf8b856d
 #[tokio::test]
-async fn borrow_limits() {
+async fn borrow_incorrect_accounts() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -2000,8 +1954,8 @@ async fn borrow_limits() {
 
     let pool_authority_keypair = Keypair::new();
     let pool_authority_pubkey = pool_authority_keypair.pubkey();
-    let pool_keypair = Keypair::new();
-    let pool_pubkey = pool_keypair.pubkey();
+    let pool1_keypair = Keypair::new();
+    let pool1_pubkey = pool1_keypair.pubkey();
 
     let curator_keypair = Keypair::new();
     let curator_pubkey = curator_keypair.pubkey();
@@ -2029,6 +1983,7 @@ async fn borrow_limits() {
         Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
     let liquidity_usdc_mint =
         Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
     init_token_accounts(&mut runner, &liquidity_sol_mint);
     init_token_accounts(&mut runner, &liquidity_usdc_mint);
 
@@ -2039,7 +1994,7 @@ async fn borrow_limits() {
         &admin_keypair,
         &borrower_keypair,
         &curator_keypair,
-        &pool_keypair,
+        &pool1_keypair,
         &reserve_sol1_keypair,
         &reserve_sol2_keypair,
         &reserve_usdc_keypair,
@@ -2051,79 +2006,7 @@ async fn borrow_limits() {
     )
     .await;
 
-    // ALTER reserve_sol1.max_total_liquidity & reserve_usdc.max_utilization. Set fees to zero
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let mut params = reserve.config;
-    let max_total_liquidity = 100000;
-    params.max_total_liquidity = max_total_liquidity;
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
-    alter_reserve(
-        &mut ctx,
-        reserve_sol1_pubkey,
-        pool_pubkey,
-        &pool_authority_keypair,
-        curator_pubkey,
-        params,
-        0,
-    )
-    .await
-    .expect("alter_reserve");
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let mut params = reserve.config;
-    params.max_borrow_utilization_bps = 5000; // 50%
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
-    alter_reserve(
-        &mut ctx,
-        reserve_usdc_pubkey,
-        pool_pubkey,
-        &pool_authority_keypair,
-        curator_pubkey,
-        params,
-        0,
-    )
-    .await
-    .expect("alter_reserve");
-
-    let params = TextureConfigParams {
-        borrow_fee_rate_bps: 0,
-        performance_fee_rate_bps: 0,
-        fees_authority: texture_owner_keypair.pubkey(),
-        reserve_timelock: ReserveTimelock {
-            market_price_feed_lock_sec: 0,
-            irm_lock_sec: 0,
-            liquidation_bonus_lock_sec: 0,
-            unhealthy_ltv_lock_sec: 0,
-            partial_liquidation_factor_lock_sec: 0,
-            max_total_liquidity_lock_sec: 0,
-            max_borrow_ltv_lock_sec: 0,
-            max_borrow_utilization_lock_sec: 0,
-            price_stale_threshold_lock_sec: 0,
-            max_withdraw_utilization_lock_sec: 0,
-            fees_lock_sec: 0,
-            _padding: 0,
-        },
-    };
-    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
-        .await
-        .expect("alter_texture_config");
-
-    // DEPOSIT LIQUIDITY GREATER WHEN THRESHOLD
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
 
     let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
     let dest_lender_lp_wallet_sol =
@@ -2133,8 +2016,7 @@ async fn borrow_limits() {
     let source_lender_liq_wallet_sol =
         get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
 
-    info!("deposit liquidity greater when threshold");
-    let result = deposit_liquidity(
+    deposit_liquidity(
         &mut ctx,
         reserve_sol1_pubkey,
         sol_price_feed,
@@ -2142,32 +2024,1764 @@ async fn borrow_limits() {
         &lender_keypair,
         source_lender_liq_wallet_sol,
         dest_lender_lp_wallet_sol,
-        max_total_liquidity + 1,
+        1_000 * LAMPORTS_PER_SOL,
     )
-    .await;
+    .await
+    .expect("deposit_liquidity");
 
-    assert!(result.is_err());
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
 
-    // DEPOSIT MAX TOTAL LIQUIDITY
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 1000 * LAMPORTS_PER_USDC;
 
-    info!("deposit max_total_liquidity");
     deposit_liquidity(
         &mut ctx,
-        reserve_sol1_pubkey,
-        sol_price_feed,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
         irm,
-        &lender_keypair,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    // BORROW BY USER NOT ASSOCIATED WITH POSITION
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow by user not associated wit position");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool1_pubkey,
+        &lender_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // CREATE POOL2
+
+    let pool2_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey();
+
+    let pool_params = PoolParams {
+        name: [1; 128],
+        market_price_currency_symbol: str_to_array("USD"),
+        visible: 0,
+        auto_write_off_bad_debt: 0,
+    };
+
+    create_pool(
+        &mut ctx,
+        &pool2_keypair,
+        &pool_authority_keypair,
+        curator_keypair.pubkey(),
+        pool_params,
+    )
+    .await
+    .expect("create_pool");
+
+    // CREATE RESERVE SOL2
+
+    let fees_config = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 100,
+        curator_performance_fee_rate_bps: 2000,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    let reserve_config = ReserveConfig {
+        market_price_feed: sol_price_feed,
+        irm,
+        liquidation_bonus_bps: 200,
+        max_borrow_ltv_bps: 6000,
+        partly_unhealthy_ltv_bps: 6500,
+        fully_unhealthy_ltv_bps: 7000,
+        partial_liquidation_factor_bps: 2000,
+        fees: fees_config,
+        max_total_liquidity: 10_000 * LAMPORTS_PER_SOL,
+        max_borrow_utilization_bps: 1000,
+        price_stale_threshold_sec: 1,
+        max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
+    };
+
+    info!("create reserve sol borrow enabled");
+    create_reserve(
+        &mut ctx,
+        &reserve_sol2_keypair,
+        pool2_keypair.pubkey(),
+        &pool_authority_keypair,
+        curator_keypair.pubkey(),
+        liquidity_sol_mint,
+        sol_price_feed,
+        reserve_config,
+        RESERVE_TYPE_NORMAL,
+    )
+    .await
+    .expect("create_reserve_sol1");
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+
+    // BORROW WITH INCORRECT reserve_liquidity_supply
+
+    info!("borrow with incorrect reserve_liquidity_supply");
+
+    // choose reserve_liquidity_supply from other reserve
+    let reserve_liquidity_supply = find_liquidity_supply(&reserve_sol2_pubkey).0;
+    let program_authority = find_program_authority().0;
+    let accounts = vec![
+        AccountMeta::new(position_pubkey, false),
+        AccountMeta::new(reserve_liquidity_supply, false),
+        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
+        AccountMeta::new(curator_fee_receiver, false),
+        AccountMeta::new(borrower_pubkey, true),
+        AccountMeta::new(reserve_sol1_pubkey, false),
+        AccountMeta::new(pool1_pubkey, false),
+        AccountMeta::new(curator_pubkey, false),
+        AccountMeta::new(texture_fee_receiver, false),
+        AccountMeta::new(TEXTURE_CONFIG_ID, false),
+        AccountMeta::new(program_authority, false),
+        AccountMeta::new(spl_token::ID, false),
+    ];
+
+    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
+
+    ixs.push(
+        RefreshReserve {
+            reserve: reserve_sol1_pubkey,
+            market_price_feed: sol_price_feed,
+            irm,
+        }
+        .into_instruction(),
+    );
+    let ix = SuperLendyInstruction::Borrow {
+        amount: LAMPORTS_PER_SOL,
+        slippage_limit: 1,
+        memo: [0; BORROW_MEMO_LEN],
+    };
+    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
+        SUPER_LENDY_ID,
+        &ix,
+        accounts,
+    ));
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &borrower_keypair],
+        blockhash,
+    );
+    let result = ctx.banks_client.process_transaction(tx).await;
+
+    assert!(result.is_err());
+
+    // BORROW WITH INCORRECT POSITION
+
+    let position_pool2_kp = Keypair::new();
+    create_position(
+        &mut ctx,
+        &position_pool2_kp,
+        pool2_keypair.pubkey(),
+        &borrower_keypair,
+    )
+    .await
+    .expect("create_position");
+
+    info!("borrow with incorrect position");
+
+    let reserve_liquidity_supply = find_liquidity_supply(&reserve_sol1_pubkey).0;
+    let program_authority = find_program_authority().0;
+    let accounts = vec![
+        AccountMeta::new(position_pool2_kp.pubkey(), false),
+        AccountMeta::new(reserve_liquidity_supply, false),
+        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
+        AccountMeta::new(curator_fee_receiver, false),
+        AccountMeta::new(borrower_pubkey, true),
+        AccountMeta::new(reserve_sol1_pubkey, false),
+        AccountMeta::new(pool1_pubkey, false),
+        AccountMeta::new(curator_pubkey, false),
+        AccountMeta::new(texture_fee_receiver, false),
+        AccountMeta::new(TEXTURE_CONFIG_ID, false),
+        AccountMeta::new(program_authority, false),
+        AccountMeta::new(spl_token::ID, false),
+    ];
+    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
+
+    ixs.push(
+        RefreshReserve {
+            reserve: reserve_sol1_pubkey,
+            market_price_feed: sol_price_feed,
+            irm,
+        }
+        .into_instruction(),
+    );
+    let ix = SuperLendyInstruction::Borrow {
+        amount: LAMPORTS_PER_SOL,
+        slippage_limit: 1,
+        memo: [0; BORROW_MEMO_LEN],
+    };
+    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
+        SUPER_LENDY_ID,
+        &ix,
+        accounts,
+    ));
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &borrower_keypair],
+        blockhash,
+    );
+    let result = ctx.banks_client.process_transaction(tx).await;
+
+    assert!(result.is_err());
+
+    // BORROW WITH INCORRECT destination_liquidity_wallet
+
+    info!("borrow with incorrect destination_liquidity_wallet");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool1_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        reserve_liquidity_supply,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // BORROW WITH reserve_liquidity_supply = dest_borrower_liq_wallet_sol
+
+    info!("borrow with reserve_liquidity_supply = dest_borrower_liq_wallet_sol");
+    let program_authority = find_program_authority().0;
+    let accounts = vec![
+        AccountMeta::new(position_pubkey, false),
+        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
+        AccountMeta::new(dest_borrower_liq_wallet_sol, false),
+        AccountMeta::new(curator_fee_receiver, false),
+        AccountMeta::new(borrower_pubkey, true),
+        AccountMeta::new(reserve_sol1_pubkey, false),
+        AccountMeta::new(pool1_pubkey, false),
+        AccountMeta::new(curator_pubkey, false),
+        AccountMeta::new(texture_fee_receiver, false),
+        AccountMeta::new(TEXTURE_CONFIG_ID, false),
+        AccountMeta::new(program_authority, false),
+        AccountMeta::new(spl_token::ID, false),
+    ];
+    let (mut ixs, _) = refresh_position_ix(&mut ctx, position_pubkey).await;
+
+    ixs.push(
+        RefreshReserve {
+            reserve: reserve_sol1_pubkey,
+            market_price_feed: sol_price_feed,
+            irm,
+        }
+        .into_instruction(),
+    );
+    let ix = SuperLendyInstruction::Borrow {
+        amount: LAMPORTS_PER_SOL,
+        slippage_limit: 1,
+        memo: [0; BORROW_MEMO_LEN],
+    };
+    ixs.push(solana_program::instruction::Instruction::new_with_borsh(
+        SUPER_LENDY_ID,
+        &ix,
+        accounts,
+    ));
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &borrower_keypair],
+        blockhash,
+    );
+    let result = ctx.banks_client.process_transaction(tx).await;
+
+    assert!(result.is_err());
+}
+
+/// See test description in
+/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#a4d002d950ac42f580f50c0cae89ae5f
+#[tokio::test]
+async fn borrow_limits() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER reserve_sol1.max_total_liquidity & reserve_usdc.max_utilization. Set fees to zero
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    let max_total_liquidity = 100000;
+    params.max_total_liquidity = max_total_liquidity;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.max_borrow_utilization_bps = 5000; // 50%
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // DEPOSIT LIQUIDITY GREATER WHEN THRESHOLD
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit liquidity greater when threshold");
+    let result = deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        max_total_liquidity + 1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // DEPOSIT MAX TOTAL LIQUIDITY
+
+    info!("deposit max_total_liquidity");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        max_total_liquidity,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 1 LIQUIDITY
+
+    info!("deposit 1 liquidity");
+    let result = deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_amount = 1_000 * LAMPORTS_PER_USDC;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // BORROW 490 USDC TO SET UTILIZATION TO 0.49%
+
+    let dest_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow 490 usdc to set utilization to 49%");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        490 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // BORROW 510 USDC TO INCREASE max_utilization_bps
+
+    info!("borrow 510 usdc to increase max_utilization_bps");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        20 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err())
+}
+
+#[tokio::test]
+async fn borrow_max_total_borrow() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER reserve_usdc.max_total_borrow. Set fees to zero
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    let max_total_borrow = 300 * LAMPORTS_PER_USDC;
+    params.max_total_borrow = max_total_borrow;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_amount = 1_000 * LAMPORTS_PER_USDC;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    let dest_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    // BORROW 250 USDC WHICH IS BELOW max_total_borrow
+
+    info!("borrow 250 usdc");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        250 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // BORROW 60 USDC TO EXCEED max_total_borrow
+
+    info!("borrow 60 usdc to exceed max_total_borrow");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        60 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // BORROW MAX. RESULTING BORROWED AMOUNT MUST NOT EXCEED max_total_borrow
+
+    info!("borrow max amount");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        u64::MAX,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    assert!(
+        reserve.liquidity.borrowed_amount().unwrap()
+            <= Decimal::from_lamports(max_total_borrow, 6).unwrap()
+    );
+    assert_eq!(
+        reserve.remaining_borrow_capacity().unwrap(),
+        Some(Decimal::ZERO)
+    );
+}
+
+/// See test description in
+/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#5f16ce76d02a4d3f8e0188337fa171ee
+#[tokio::test]
+async fn borrow_reserve_type() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_borrower_pubkey = borrower_position_keypair.pubkey();
+    let lender_position_kp = Keypair::new();
+    let position_lender_pubkey = lender_position_kp.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey(); // RESERVE_TYPE_BORROW_ONLY
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey(); // RESERVE_TYPE_BORROW_DISABLED
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey(); // RESERVE_TYPE_NORMAL
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER reserve.borrow_fee & reserve.performance_fee to zero
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    // ALTER texture_config.borrow_fee & texture_config.performance_fee to zero
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL2 RESERVE & LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_sol2_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    create_position(
+        &mut ctx,
+        &lender_position_kp,
+        pool_keypair.pubkey(),
+        &lender_keypair,
+    )
+    .await
+    .expect("create_position");
+
+    refresh_position(&mut ctx, lender_position_kp.pubkey())
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        lender_position_kp.pubkey(),
+        &lender_keypair,
+        dest_lender_lp_wallet_sol,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // DEPOSIT USDC & LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 100_000_000;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, borrower_position_keypair.pubkey())
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        borrower_position_keypair.pubkey(),
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // TRY TO BORROW FROM SOL2 RESERVE WITH BORROW_DISABLE MODE
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver_sol =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver_sol =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("try to borrow from sol2 reserve");
+    let result = borrow(
+        &mut ctx,
+        position_borrower_pubkey,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver_sol,
+        texture_fee_receiver_sol,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // BORROW FROM USDC RESERVE WITH LOCKING COLLATERAL FROM SOL2 RESERVE
+
+    let dest_lender_liq_wallet_usdc =
+        get_associated_token_address(&lender_pubkey, &liquidity_usdc_mint);
+    let texture_fee_receiver_usdc =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver_usdc =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    refresh_position(&mut ctx, position_lender_pubkey)
+        .await
+        .expect("refresh_position");
+
+    info!("borrow from usdc reserve with locking collateral from sol2 reserve");
+    borrow(
+        &mut ctx,
+        position_lender_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &lender_keypair,
+        curator_pubkey,
+        curator_fee_receiver_usdc,
+        texture_fee_receiver_usdc,
+        dest_lender_liq_wallet_usdc,
+        10_000_000,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // DEPOSIT LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit liquidity to sol1 reserve");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
         source_lender_liq_wallet_sol,
         dest_lender_lp_wallet_sol,
-        max_total_liquidity,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // TRY TO LOCK COLLATERAL IN SOL1 RESERVE WITH BORROW_ONLY MODE
+
+    refresh_position(&mut ctx, position_lender_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("try to lock collateral in sol1 reserve with BORROW_ONLY mode");
+    let result = lock_collateral(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        position_lender_pubkey,
+        &lender_keypair,
+        dest_lender_lp_wallet_sol,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+
+    assert!(result.is_err());
+
+    // BORROW FROM SOL1 RESERVE
+
+    info!("borrow from sol1 reserve");
+    borrow(
+        &mut ctx,
+        position_borrower_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver_sol,
+        texture_fee_receiver_sol,
+        dest_borrower_liq_wallet_sol,
+        100_000_000,
+        1,
+    )
+    .await
+    .expect("borrow");
+}
+
+#[tokio::test]
+async fn borrow_irm() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "curve1-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // Set fees to zero
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_amount = 1_000 * LAMPORTS_PER_USDC;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // BORROW 10 USDC TO SET UTILIZATION TO 1%
+
+    let dest_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow 10 usdc to set utilization to 1%");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        10 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // 1 SECOND LATER
+
+    info!("warp 1 second");
+    warp_seconds(&mut ctx, 1).await;
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
+    assert_eq!(
+        reserve.liquidity.borrow_rate().unwrap(),
+        Decimal::from_i128_with_scale(21, 2).unwrap()
+    );
+
+    // BORROW MORE 90 USDC TO SET UTILIZATION TO 10%
+
+    info!("borrow more 90 usdc to set utilization to 10%");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        90 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // 1 SECOND LATER
+
+    info!("warp 1 second");
+    warp_seconds(&mut ctx, 1).await;
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve
+            .liquidity
+            .borrow_rate()
+            .unwrap()
+            .round_to_decimals(4),
+        Decimal::from_i128_with_scale(3000, 4).unwrap()
+    );
+
+    // CHECK INTEREST ACCRUED 1 SECOND
+
+    let second_interest_rate = Decimal::from_i128_with_scale(3, 1) // borrow_rate = 30%
+        .unwrap()
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
+        .unwrap();
+    let compounded_interest_rate = second_interest_rate
+        .checked_add(Decimal::ONE)
+        .unwrap()
+        .checked_pow(1)
+        .unwrap();
+    let exp_borrowed_amount = Decimal::from_i128_with_scale(90, 0) // borrowed_amount * interest_rate
+        .unwrap()
+        .checked_add(borrowed_amount)
+        .unwrap()
+        .checked_mul(compounded_interest_rate)
+        .unwrap()
+        .round_to_decimals(9);
+
+    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
+    assert_eq!(borrowed_amount.round_to_decimals(9), exp_borrowed_amount);
+
+    // BORROW MORE 150 USDC TO SET UTILIZATION TO 25%
+
+    info!("borrow more 150 usdc to set utilization to 25%");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        150 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // 1 SECOND LATER
+
+    info!("warp 1 second");
+    warp_seconds(&mut ctx, 1).await;
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve
+            .liquidity
+            .borrow_rate()
+            .unwrap()
+            .round_to_decimals(4),
+        Decimal::from_i128_with_scale(4000, 4).unwrap()
+    );
+
+    // CHECK INTEREST ACCRUED 1 SECOND
+
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40%
+        .unwrap()
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
+        .unwrap();
+    let compounded_interest_rate = second_interest_rate
+        .checked_add(Decimal::ONE)
+        .unwrap()
+        .checked_pow(1)
+        .unwrap();
+    let exp_borrowed_amount = Decimal::from_i128_with_scale(150, 0) // borrowed_amount * interest_rate
+        .unwrap()
+        .checked_add(borrowed_amount)
+        .unwrap()
+        .checked_mul(compounded_interest_rate)
+        .unwrap()
+        .round_to_decimals(8);
+
+    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
+    assert_eq!(borrowed_amount.round_to_decimals(8), exp_borrowed_amount);
+}
+
+// Reserve can only give 5 SOL (out of 10) because of max_borrow_utilization_bps = 50%
+// While deposited collateral allows greater borrow amount.
+#[tokio::test]
+async fn borrow_max_amount_limited_by_reserve() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
     )
-    .await
-    .expect("deposit_liquidity");
+    .await;
 
-    // DEPOSIT 1 LIQUIDITY
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
 
-    info!("deposit 1 liquidity");
-    let result = deposit_liquidity(
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit initial liquidity");
+    deposit_liquidity(
         &mut ctx,
         reserve_sol1_pubkey,
         sol_price_feed,
@@ -2175,13 +3789,12 @@ async fn borrow_limits() {
         &lender_keypair,
         source_lender_liq_wallet_sol,
         dest_lender_lp_wallet_sol,
-        1,
+        10 * LAMPORTS_PER_SOL,
     )
-    .await;
-
-    assert!(result.is_err());
+    .await
+    .expect("deposit_liquidity");
 
-    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+    // DEPOSIT 2000 USDC AND LOCK COLLATERAL
 
     let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
     let dest_borrower_lp_wallet_usdc =
@@ -2190,8 +3803,9 @@ async fn borrow_limits() {
             .expect("create lp ata");
     let source_borrower_liq_wallet_usdc =
         get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let deposit_amount = 1_000 * LAMPORTS_PER_USDC;
+    let deposit_usdc_amount = 2_000 * LAMPORTS_PER_USDC;
 
+    info!("deposit {} into USDC reserve", deposit_usdc_amount);
     deposit_liquidity(
         &mut ctx,
         reserve_usdc_pubkey,
@@ -2200,7 +3814,7 @@ async fn borrow_limits() {
         &borrower_keypair,
         source_borrower_liq_wallet_usdc,
         dest_borrower_lp_wallet_usdc,
-        deposit_amount,
+        deposit_usdc_amount,
     )
     .await
     .expect("deposit_liquidity");
@@ -2209,6 +3823,7 @@ async fn borrow_limits() {
         .await
         .expect("refresh position");
 
+    info!("lock {} collateral lp", deposit_usdc_amount);
     lock_collateral(
         &mut ctx,
         reserve_usdc_pubkey,
@@ -2217,70 +3832,237 @@ async fn borrow_limits() {
         position_pubkey,
         &borrower_keypair,
         dest_borrower_lp_wallet_usdc,
-        deposit_amount,
+        deposit_usdc_amount,
     )
     .await
     .expect("lock_collateral");
 
-    // BORROW 490 USDC TO SET UTILIZATION TO 0.49%
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
 
-    let dest_borrower_liq_wallet_usdc =
-        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+
+    // CHECK deposited_value = deposit_amount * lp_exchange_rate
+    assert_eq!(
+        position.deposited_value().unwrap(),
+        Decimal::from_i128_with_scale(2002, 0).unwrap()
+    );
+    // CHECK allowed_borrow_value = deposit_amount * lp_exchange_rate * %max_borrow_ltv
+    assert_eq!(
+        position.allowed_borrow_value().unwrap(),
+        Decimal::from_i128_with_scale(18018, 1).unwrap()
+    );
+
+    // BORROW maximum SOLs contract can give
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
     let texture_fee_receiver =
-        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
             .await
             .expect("create texture fee receiver ata");
     let curator_fee_receiver =
-        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
             .await
             .expect("create curator fee receiver ata");
 
-    info!("borrow 490 usdc to set utilization to 49%");
+    let borrower_sol_token_acc0 =
+        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
+            .await
+            .expect("get token acc");
+    let curator_fee_token_acc0 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
+        .await
+        .expect("get token acc");
+    let texture_fee_token_acc0 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
+        .await
+        .expect("get token acc");
+
+    info!("borrow MAX SOL after lock deposited collateral");
     borrow(
         &mut ctx,
         position_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_sol1_pubkey,
+        sol_price_feed,
         irm,
         pool_pubkey,
         &borrower_keypair,
         curator_pubkey,
         curator_fee_receiver,
         texture_fee_receiver,
-        dest_borrower_liq_wallet_usdc,
-        490 * LAMPORTS_PER_USDC,
+        dest_borrower_liq_wallet_sol,
+        u64::MAX,
         1,
     )
     .await
     .expect("borrow");
 
-    // BORROW 510 USDC TO INCREASE max_utilization_bps
+    let amount = LAMPORTS_PER_SOL;
 
-    info!("borrow 510 usdc to increase max_utilization_bps");
-    let result = borrow(
-        &mut ctx,
-        position_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
-        irm,
-        pool_pubkey,
-        &borrower_keypair,
-        curator_pubkey,
-        curator_fee_receiver,
-        texture_fee_receiver,
-        dest_borrower_liq_wallet_usdc,
-        20 * LAMPORTS_PER_USDC,
-        1,
-    )
-    .await;
+    let borrower_sol_token_acc1 =
+        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
+            .await
+            .expect("get token acc");
+    let curator_fee_token_acc1 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
+        .await
+        .expect("get token acc");
+    let texture_fee_token_acc1 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
+        .await
+        .expect("get token acc");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let texture_config_acc = get_account(&mut ctx.banks_client, texture_config_keypair.pubkey())
+        .await
+        .expect("get position");
+    let texture_config =
+        TextureConfig::try_from_bytes(&texture_config_acc.data).expect("cast reserve data");
+
+    // CHECK POSITION BORROWED VALUE
+    let borrowed = position.borrowed_value().unwrap();
+    assert_eq!(
+        borrowed,
+        // 500 - including 1%_curator_fee + 30%_texture_fee.
+        Decimal::from_i128_with_scale(500, 0).unwrap()
+    );
+    // CHECK BorrowedLiquidity amount
+    let borrowed_liquidity = position
+        .find_borrowed_liquidity(reserve_sol1_pubkey)
+        .expect("find_borrowed_liquidity")
+        .0;
+    let borrowed_amount = borrowed_liquidity.borrowed_amount().unwrap();
+
+    // User borrowed 5 SOLs - 3.81 for himself and rest to pay Texture and Curator fees. Solana time is not winded
+    // between Borrow and RefreshPosition. Thus no interest accrued yet.
+    assert_eq!(
+        borrowed_amount,
+        Decimal::from_lamports(5_000_000_000, 9).unwrap()
+    );
+
+    // CHECK BORROWER BALANCE
+    assert_eq!(
+        borrower_sol_token_acc1.amount,
+        borrower_sol_token_acc0.amount + 3816793893
+    );
+
+    // CHECK FEE TRANSFERS
+    let (curator_fee, texture_fee) = reserve
+        .config
+        .fees
+        .calculate_borrow_fees(
+            Decimal::from_lamports(amount, 9).unwrap(),
+            9,
+            texture_config.borrow_fee_rate_bps,
+            FeeCalculation::Exclusive,
+        )
+        .expect("calculate borrow fees");
+    assert_eq!(curator_fee, 10_000_000);
+    assert_eq!(texture_fee, 300_000_000);
+
+    // Curator borrow fee is 1% from borrowed amount i.e. from 3816793893
+    assert_eq!(
+        curator_fee_token_acc1.amount,
+        curator_fee_token_acc0.amount + 38167939
+    );
+    // Texture borrow fee is 30% i.e. 3816793893 * 0.3 = 1145038168
+    assert_eq!(
+        texture_fee_token_acc1.amount,
+        texture_fee_token_acc0.amount + 1145038168
+    );
+
+    let reserve_borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
+
+    // 1 YEAR LATER
+
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+        .unwrap()
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
+        .unwrap();
+    let compounded_interest_rate = second_interest_rate
+        .checked_add(Decimal::ONE)
+        .unwrap()
+        .checked_pow(SECONDS_PER_YEAR)
+        .unwrap();
+
+    let curator_performance_fee = reserve
+        .liquidity
+        .curator_performance_fee()
+        .unwrap()
+        .round_to_decimals(9);
+    let texture_performance_fee = reserve
+        .liquidity
+        .texture_performance_fee()
+        .unwrap()
+        .round_to_decimals(9);
+
+    let interest = reserve
+        .liquidity
+        .borrowed_amount()
+        .unwrap()
+        .checked_sub(reserve_borrowed_amount)
+        .unwrap();
+
+    // CHECK PERFORMANCE FEES. Curator takes 20% and Texture 40% of the interest.
+    assert_eq!(
+        curator_performance_fee,
+        interest
+            .checked_mul(Decimal::from_basis_points(2000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
+    );
+    assert_eq!(
+        texture_performance_fee,
+        interest
+            .checked_mul(Decimal::from_basis_points(4000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
+    );
 
-    assert!(result.is_err())
+    let exp_borrowed_amount = Decimal::from_i128_with_scale(500, 2) // borrowed_amount = receive_amount + fees
+        .unwrap()
+        .checked_mul(compounded_interest_rate)
+        .unwrap()
+        .round_to_decimals(6);
+    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
+
+    // CHECK REPAYMENT TO RESERVE
+    assert_eq!(borrowed_amount.round_to_decimals(6), exp_borrowed_amount);
 }
 
-/// See test description in
-/// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#5f16ce76d02a4d3f8e0188337fa171ee
+// This test operates on big amounts to test overflow conditions as well as u64:max as special value
+// for Borrow IX.
+// Reserve can give 5_000_000 SOL (out of 10_000_000) because of max_borrow_utilization_bps = 50%
+// While deposited collateral allows to borrow just 1_000_000 SOL.
 #[tokio::test]
-async fn borrow_reserve_type() {
+async fn borrow_max_amount_limited_by_position() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -2290,9 +4072,7 @@ async fn borrow_reserve_type() {
     let lender_keypair = lender_keypair();
     let lender_pubkey = lender_keypair.pubkey();
     let borrower_position_keypair = Keypair::new();
-    let position_borrower_pubkey = borrower_position_keypair.pubkey();
-    let lender_position_kp = Keypair::new();
-    let position_lender_pubkey = lender_position_kp.pubkey();
+    let position_pubkey = borrower_position_keypair.pubkey();
 
     let texture_owner_keypair = Keypair::new();
     let texture_owner_pubkey = texture_owner_keypair.pubkey();
@@ -2307,11 +4087,10 @@ async fn borrow_reserve_type() {
     let curator_pubkey = curator_keypair.pubkey();
 
     let reserve_sol1_keypair = Keypair::new();
-    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey(); // RESERVE_TYPE_BORROW_ONLY
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
     let reserve_sol2_keypair = Keypair::new();
-    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey(); // RESERVE_TYPE_BORROW_DISABLED
     let reserve_usdc_keypair = Keypair::new();
-    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey(); // RESERVE_TYPE_NORMAL
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
 
     runner.add_native_wallet(admin_pubkey, LAMPORTS);
     runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
@@ -2330,6 +4109,7 @@ async fn borrow_reserve_type() {
         Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
     let liquidity_usdc_mint =
         Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
     init_token_accounts(&mut runner, &liquidity_sol_mint);
     init_token_accounts(&mut runner, &liquidity_usdc_mint);
 
@@ -2352,80 +4132,9 @@ async fn borrow_reserve_type() {
     )
     .await;
 
-    // ALTER reserve.borrow_fee & reserve.performance_fee to zero
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let mut params = reserve.config;
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
-    alter_reserve(
-        &mut ctx,
-        reserve_sol1_pubkey,
-        pool_pubkey,
-        &pool_authority_keypair,
-        curator_pubkey,
-        params,
-        0,
-    )
-    .await
-    .expect("alter_reserve");
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let mut params = reserve.config;
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
-    alter_reserve(
-        &mut ctx,
-        reserve_usdc_pubkey,
-        pool_pubkey,
-        &pool_authority_keypair,
-        curator_pubkey,
-        params,
-        0,
-    )
-    .await
-    .expect("alter_reserve");
-
-    // ALTER texture_config.borrow_fee & texture_config.performance_fee to zero
-
-    let params = TextureConfigParams {
-        borrow_fee_rate_bps: 0,
-        performance_fee_rate_bps: 0,
-        fees_authority: texture_owner_keypair.pubkey(),
-        reserve_timelock: ReserveTimelock {
-            market_price_feed_lock_sec: 0,
-            irm_lock_sec: 0,
-            liquidation_bonus_lock_sec: 0,
-            unhealthy_ltv_lock_sec: 0,
-            partial_liquidation_factor_lock_sec: 0,
-            max_total_liquidity_lock_sec: 0,
-            max_borrow_ltv_lock_sec: 0,
-            max_borrow_utilization_lock_sec: 0,
-            price_stale_threshold_lock_sec: 0,
-            max_withdraw_utilization_lock_sec: 0,
-            fees_lock_sec: 0,
-            _padding: 0,
-        },
-    };
-    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
-        .await
-        .expect("alter_texture_config");
-
-    // DEPOSIT INITIAL LIQUIDITY TO SOL2 RESERVE & LOCK COLLATERAL
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
 
-    let lp_mint = find_lp_token_mint(&reserve_sol2_pubkey).0;
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
     let dest_lender_lp_wallet_sol =
         create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
             .await
@@ -2433,46 +4142,21 @@ async fn borrow_reserve_type() {
     let source_lender_liq_wallet_sol =
         get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
 
+    info!("deposit initial liquidity");
     deposit_liquidity(
         &mut ctx,
-        reserve_sol2_pubkey,
+        reserve_sol1_pubkey,
         sol_price_feed,
         irm,
         &lender_keypair,
         source_lender_liq_wallet_sol,
         dest_lender_lp_wallet_sol,
-        LAMPORTS_PER_SOL,
+        1_000_000 * LAMPORTS_PER_SOL, // 1M SOLs ! worth 100M $
     )
     .await
     .expect("deposit_liquidity");
 
-    create_position(
-        &mut ctx,
-        &lender_position_kp,
-        pool_keypair.pubkey(),
-        &lender_keypair,
-    )
-    .await
-    .expect("create_position");
-
-    refresh_position(&mut ctx, lender_position_kp.pubkey())
-        .await
-        .expect("refresh position");
-
-    lock_collateral(
-        &mut ctx,
-        reserve_sol2_pubkey,
-        sol_price_feed,
-        irm,
-        lender_position_kp.pubkey(),
-        &lender_keypair,
-        dest_lender_lp_wallet_sol,
-        LAMPORTS_PER_SOL,
-    )
-    .await
-    .expect("lock_collateral");
-
-    // DEPOSIT USDC & LOCK COLLATERAL
+    // DEPOSIT 100 USDC AND LOCK COLLATERAL
 
     let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
     let dest_borrower_lp_wallet_usdc =
@@ -2481,8 +4165,9 @@ async fn borrow_reserve_type() {
             .expect("create lp ata");
     let source_borrower_liq_wallet_usdc =
         get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let deposit_usdc_amount = 100_000_000;
+    let deposit_usdc_amount = 10_000_000 * LAMPORTS_PER_USDC; // 10M $
 
+    info!("deposit {} into USDC reserve", deposit_usdc_amount);
     deposit_liquidity(
         &mut ctx,
         reserve_usdc_pubkey,
@@ -2496,16 +4181,17 @@ async fn borrow_reserve_type() {
     .await
     .expect("deposit_liquidity");
 
-    refresh_position(&mut ctx, borrower_position_keypair.pubkey())
+    refresh_position(&mut ctx, position_pubkey)
         .await
         .expect("refresh position");
 
+    info!("lock {} collateral lp", deposit_usdc_amount);
     lock_collateral(
         &mut ctx,
         reserve_usdc_pubkey,
         usdc_price_feed,
         irm,
-        borrower_position_keypair.pubkey(),
+        position_pubkey,
         &borrower_keypair,
         dest_borrower_lp_wallet_usdc,
         deposit_usdc_amount,
@@ -2513,144 +4199,151 @@ async fn borrow_reserve_type() {
     .await
     .expect("lock_collateral");
 
-    // TRY TO BORROW FROM SOL2 RESERVE WITH BORROW_DISABLE MODE
-
-    let dest_borrower_liq_wallet_sol =
-        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
-    let texture_fee_receiver_sol =
-        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
-            .await
-            .expect("create texture fee receiver ata");
-    let curator_fee_receiver_sol =
-        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
-            .await
-            .expect("create curator fee receiver ata");
-
-    info!("try to borrow from sol2 reserve");
-    let result = borrow(
-        &mut ctx,
-        position_borrower_pubkey,
-        reserve_sol2_pubkey,
-        sol_price_feed,
-        irm,
-        pool_pubkey,
-        &borrower_keypair,
-        curator_pubkey,
-        curator_fee_receiver_sol,
-        texture_fee_receiver_sol,
-        dest_borrower_liq_wallet_sol,
-        LAMPORTS_PER_SOL,
-        1,
-    )
-    .await;
-
-    assert!(result.is_err());
-
-    // BORROW FROM USDC RESERVE WITH LOCKING COLLATERAL FROM SOL2 RESERVE
-
-    let dest_lender_liq_wallet_usdc =
-        get_associated_token_address(&lender_pubkey, &liquidity_usdc_mint);
-    let texture_fee_receiver_usdc =
-        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
-            .await
-            .expect("create texture fee receiver ata");
-    let curator_fee_receiver_usdc =
-        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
-            .await
-            .expect("create curator fee receiver ata");
-
-    refresh_position(&mut ctx, position_lender_pubkey)
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
         .await
-        .expect("refresh_position");
-
-    info!("borrow from usdc reserve with locking collateral from sol2 reserve");
-    borrow(
-        &mut ctx,
-        position_lender_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
-        irm,
-        pool_pubkey,
-        &lender_keypair,
-        curator_pubkey,
-        curator_fee_receiver_usdc,
-        texture_fee_receiver_usdc,
-        dest_lender_liq_wallet_usdc,
-        10_000_000,
-        1,
-    )
-    .await
-    .expect("borrow");
-
-    // DEPOSIT LIQUIDITY TO SOL1 RESERVE
-
-    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
-    let dest_lender_lp_wallet_sol =
-        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
-            .await
-            .expect("create lp ata");
-    let source_lender_liq_wallet_sol =
-        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
-
-    info!("deposit liquidity to sol1 reserve");
-    deposit_liquidity(
-        &mut ctx,
-        reserve_sol1_pubkey,
-        sol_price_feed,
-        irm,
-        &lender_keypair,
-        source_lender_liq_wallet_sol,
-        dest_lender_lp_wallet_sol,
-        LAMPORTS_PER_SOL,
-    )
-    .await
-    .expect("deposit_liquidity");
-
-    // TRY TO LOCK COLLATERAL IN SOL1 RESERVE WITH BORROW_ONLY MODE
+        .expect("refresh position");
 
-    refresh_position(&mut ctx, position_lender_pubkey)
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
         .await
-        .expect("refresh position");
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+
+    // CHECK deposited_value = deposit_amount * lp_exchange_rate
+    assert_eq!(
+        position.deposited_value().unwrap(),
+        Decimal::from_i128_with_scale(100100000, 1).unwrap()
+    );
+    // CHECK allowed_borrow_value = deposit_amount * lp_exchange_rate * %max_borrow_ltv
+    assert_eq!(
+        position.allowed_borrow_value().unwrap(),
+        Decimal::from_i128_with_scale(900900000, 2).unwrap()
+    );
 
-    info!("try to lock collateral in sol1 reserve with BORROW_ONLY mode");
-    let result = lock_collateral(
-        &mut ctx,
-        reserve_sol1_pubkey,
-        sol_price_feed,
-        irm,
-        position_lender_pubkey,
-        &lender_keypair,
-        dest_lender_lp_wallet_sol,
-        LAMPORTS_PER_SOL,
-    )
-    .await;
+    // BORROW MAX SOL contract can give
 
-    assert!(result.is_err());
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
 
-    // BORROW FROM SOL1 RESERVE
+    let borrower_sol_token_acc0 =
+        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
+            .await
+            .expect("get token acc");
+    let curator_fee_token_acc0 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
+        .await
+        .expect("get token acc");
+    let texture_fee_token_acc0 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
+        .await
+        .expect("get token acc");
 
-    info!("borrow from sol1 reserve");
+    info!("borrow MAX SOL after lock deposited collateral");
     borrow(
         &mut ctx,
-        position_borrower_pubkey,
+        position_pubkey,
         reserve_sol1_pubkey,
         sol_price_feed,
         irm,
         pool_pubkey,
         &borrower_keypair,
         curator_pubkey,
-        curator_fee_receiver_sol,
-        texture_fee_receiver_sol,
+        curator_fee_receiver,
+        texture_fee_receiver,
         dest_borrower_liq_wallet_sol,
-        100_000_000,
+        u64::MAX,
         1,
     )
     .await
     .expect("borrow");
+
+    let borrower_sol_token_acc1 =
+        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
+            .await
+            .expect("get token acc");
+    let curator_fee_token_acc1 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
+        .await
+        .expect("get token acc");
+    let texture_fee_token_acc1 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
+        .await
+        .expect("get token acc");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get position");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let texture_config_acc = get_account(&mut ctx.banks_client, texture_config_keypair.pubkey())
+        .await
+        .expect("get position");
+    let texture_config =
+        TextureConfig::try_from_bytes(&texture_config_acc.data).expect("cast reserve data");
+
+    // CHECK POSITION BORROWED VALUE
+    let borrowed = position.borrowed_value().unwrap();
+    assert_eq!(
+        borrowed,
+        // Position's allowed borrow value is 9_009_000 USD. We asked max borrow and it is also 9_009_000 in value!
+        Decimal::from_i128_with_scale(900900000, 2).unwrap()
+    );
+    // CHECK BorrowedLiquidity amount
+    let borrowed_liquidity = position
+        .find_borrowed_liquidity(reserve_sol1_pubkey)
+        .expect("find_borrowed_liquidity")
+        .0;
+    let borrowed_amount = borrowed_liquidity.borrowed_amount().unwrap();
+
+    assert_eq!(
+        borrowed_amount,
+        Decimal::from_lamports(90_090 * LAMPORTS_PER_SOL, 9).unwrap()
+    );
+
+    // CHECK BORROWER BALANCE
+    assert_eq!(
+        borrower_sol_token_acc1.amount,
+        borrower_sol_token_acc0.amount + 68_770_992_366_412 // 68_770 SOL
+    );
+
+    // CHECK FEE TRANSFERS
+    let (curator_fee, texture_fee) = reserve
+        .config
+        .fees
+        .calculate_borrow_fees(
+            Decimal::from_lamports(68_770_992_366_412, 9).unwrap(),
+            9,
+            texture_config.borrow_fee_rate_bps,
+            FeeCalculation::Exclusive,
+        )
+        .expect("calculate borrow fees");
+    assert_eq!(curator_fee, 687_709_923_664);
+    assert_eq!(texture_fee, 20_631_297_709_924);
+
+    assert_eq!(
+        curator_fee_token_acc1.amount,
+        curator_fee_token_acc0.amount + curator_fee
+    );
+    assert_eq!(
+        texture_fee_token_acc1.amount,
+        texture_fee_token_acc0.amount + texture_fee
+    );
 }
 
 #[tokio::test]
-async fn borrow_irm() {
+async fn borrow_with_borrow_weight() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -2687,16 +4380,16 @@ async fn borrow_irm() {
     runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
 
     // 1 SOL = 100 USD
-    add_price_feed_acc(&mut runner, "sol-usd").await;
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
     // 1 USDC = 1.001 USD
     let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
 
-    let irm = add_curve_acc(&mut runner, "curve1-acc").await;
-
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
     let liquidity_sol_mint =
         Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
     let liquidity_usdc_mint =
         Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
     init_token_accounts(&mut runner, &liquidity_sol_mint);
     init_token_accounts(&mut runner, &liquidity_usdc_mint);
 
@@ -2719,19 +4412,17 @@ async fn borrow_irm() {
     )
     .await;
 
-    // Set fees to zero
+    // SOL1 BORROWINGS HAVE 1.5 RISK WEIGHT
 
     let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
         .await
-        .expect("get position");
+        .expect("get reserve");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
     let mut params = reserve.config;
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
-    alter_reserve(
+
+    // Weight below 1.0 is not allowed
+    params.borrow_weight_bps = 9_000;
+    let result = alter_reserve(
         &mut ctx,
         reserve_sol1_pubkey,
         pool_pubkey,
@@ -2740,22 +4431,13 @@ async fn borrow_irm() {
         params,
         0,
     )
-    .await
-    .expect("alter_reserve");
+    .await;
+    assert!(result.is_err());
 
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let mut params = reserve.config;
-    params.fees = ReserveFeesConfig {
-        curator_borrow_fee_rate_bps: 0,
-        curator_performance_fee_rate_bps: 0,
-        _padding: Zeroable::zeroed(),
-    };
+    params.borrow_weight_bps = 15_000;
     alter_reserve(
         &mut ctx,
-        reserve_usdc_pubkey,
+        reserve_sol1_pubkey,
         pool_pubkey,
         &pool_authority_keypair,
         curator_pubkey,
@@ -2765,30 +4447,30 @@ async fn borrow_irm() {
     .await
     .expect("alter_reserve");
 
-    let params = TextureConfigParams {
-        borrow_fee_rate_bps: 0,
-        performance_fee_rate_bps: 0,
-        fees_authority: texture_owner_keypair.pubkey(),
-        reserve_timelock: ReserveTimelock {
-            market_price_feed_lock_sec: 0,
-            irm_lock_sec: 0,
-            liquidation_bonus_lock_sec: 0,
-            unhealthy_ltv_lock_sec: 0,
-            partial_liquidation_factor_lock_sec: 0,
-            max_total_liquidity_lock_sec: 0,
-            max_borrow_ltv_lock_sec: 0,
-            max_borrow_utilization_lock_sec: 0,
-            price_stale_threshold_lock_sec: 0,
-            max_withdraw_utilization_lock_sec: 0,
-            fees_lock_sec: 0,
-            _padding: 0,
-        },
-    };
-    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
-        .await
-        .expect("alter_texture_config");
+    // LENDER DEPOSITS 100 SOL
 
-    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        100 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // BORROWER DEPOSITS 1000 USDC AND LOCKS COLLATERAL
 
     let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
     let dest_borrower_lp_wallet_usdc =
@@ -2797,7 +4479,6 @@ async fn borrow_irm() {
             .expect("create lp ata");
     let source_borrower_liq_wallet_usdc =
         get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let deposit_amount = 1_000 * LAMPORTS_PER_USDC;
 
     deposit_liquidity(
         &mut ctx,
@@ -2807,7 +4488,7 @@ async fn borrow_irm() {
         &borrower_keypair,
         source_borrower_liq_wallet_usdc,
         dest_borrower_lp_wallet_usdc,
-        deposit_amount,
+        1000 * LAMPORTS_PER_USDC,
     )
     .await
     .expect("deposit_liquidity");
@@ -2824,203 +4505,93 @@ async fn borrow_irm() {
         position_pubkey,
         &borrower_keypair,
         dest_borrower_lp_wallet_usdc,
-        deposit_amount,
+        MAX_AMOUNT,
     )
     .await
     .expect("lock_collateral");
 
-    // BORROW 10 USDC TO SET UTILIZATION TO 1%
-
-    let dest_borrower_liq_wallet_usdc =
-        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let texture_fee_receiver =
-        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
-            .await
-            .expect("create texture fee receiver ata");
-    let curator_fee_receiver =
-        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
-            .await
-            .expect("create curator fee receiver ata");
-
-    info!("borrow 10 usdc to set utilization to 1%");
-    borrow(
-        &mut ctx,
-        position_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
-        irm,
-        pool_pubkey,
-        &borrower_keypair,
-        curator_pubkey,
-        curator_fee_receiver,
-        texture_fee_receiver,
-        dest_borrower_liq_wallet_usdc,
-        10 * LAMPORTS_PER_USDC,
-        1,
-    )
-    .await
-    .expect("borrow");
-
-    // 1 SLOT LATER
-
-    let mut slot = 2_u64;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
-
-    info!("refresh position");
-    refresh_position(&mut ctx, position_pubkey)
-        .await
-        .expect("refresh position");
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
-    assert_eq!(
-        reserve.liquidity.borrow_rate().unwrap(),
-        Decimal::from_i128_with_scale(21, 2).unwrap()
-    );
+    // BORROW 5 SOL. PLAIN VALUE WITH FEES (655 USD) IS BELOW ALLOWED BORROW VALUE (900.9 USD)
+    // BUT WEIGHTED ONE IS ABOVE.
 
-    // BORROW MORE 90 USDC TO SET UTILIZATION TO 10%
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
 
-    info!("borrow more 90 usdc to set utilization to 10%");
-    borrow(
+    let result = borrow(
         &mut ctx,
         position_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_sol1_pubkey,
+        sol_price_feed,
         irm,
         pool_pubkey,
         &borrower_keypair,
         curator_pubkey,
         curator_fee_receiver,
         texture_fee_receiver,
-        dest_borrower_liq_wallet_usdc,
-        90 * LAMPORTS_PER_USDC,
+        dest_borrower_liq_wallet_sol,
+        5 * LAMPORTS_PER_SOL,
         1,
     )
-    .await
-    .expect("borrow");
-
-    // 1 SLOT LATER
-
-    slot += 1;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
-
-    info!("refresh position");
-    refresh_position(&mut ctx, position_pubkey)
-        .await
-        .expect("refresh position");
-
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    assert_eq!(
-        reserve
-            .liquidity
-            .borrow_rate()
-            .unwrap()
-            .round_to_decimals(4),
-        Decimal::from_i128_with_scale(3000, 4).unwrap()
-    );
-
-    // CHECK INTEREST ACCRUED 1 SLOT
-
-    let slot_interest_rate = Decimal::from_i128_with_scale(3, 1) // borrow_rate = 30%
-        .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
-        .unwrap();
-    let compounded_interest_rate = slot_interest_rate
-        .checked_add(Decimal::ONE)
-        .unwrap()
-        .checked_pow(1)
-        .unwrap();
-    let exp_borrowed_amount = Decimal::from_i128_with_scale(90, 0) // borrowed_amount * interest_rate
-        .unwrap()
-        .checked_add(borrowed_amount)
-        .unwrap()
-        .checked_mul(compounded_interest_rate)
-        .unwrap()
-        .round_to_decimals(9);
-
-    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
-    assert_eq!(borrowed_amount.round_to_decimals(9), exp_borrowed_amount);
+    .await;
+    assert!(result.is_err());
 
-    // BORROW MORE 150 USDC TO SET UTILIZATION TO 25%
+    // BORROW 4 SOL
 
-    info!("borrow more 150 usdc to set utilization to 25%");
     borrow(
         &mut ctx,
         position_pubkey,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_sol1_pubkey,
+        sol_price_feed,
         irm,
         pool_pubkey,
         &borrower_keypair,
         curator_pubkey,
         curator_fee_receiver,
         texture_fee_receiver,
-        dest_borrower_liq_wallet_usdc,
-        150 * LAMPORTS_PER_USDC,
+        dest_borrower_liq_wallet_sol,
+        4 * LAMPORTS_PER_SOL,
         1,
     )
     .await
     .expect("borrow");
 
-    // 1 SLOT LATER
-
-    slot += 1;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
-
-    info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
         .await
         .expect("refresh position");
 
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
         .await
         .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+
+    // Borrowed value is kept as is. Weighted one is used to check position's health.
     assert_eq!(
-        reserve
-            .liquidity
-            .borrow_rate()
+        position.weighted_borrowed_value().unwrap(),
+        position
+            .borrowed_value()
+            .unwrap()
+            .checked_mul(Decimal::from_basis_points(15_000).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        position.remaining_borrow_value().unwrap(),
+        position
+            .allowed_borrow_value()
+            .unwrap()
+            .checked_sub(position.weighted_borrowed_value().unwrap())
             .unwrap()
-            .round_to_decimals(4),
-        Decimal::from_i128_with_scale(4000, 4).unwrap()
     );
-
-    // CHECK INTEREST ACCRUED 1 SLOT
-
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40%
-        .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
-        .unwrap();
-    let compounded_interest_rate = slot_interest_rate
-        .checked_add(Decimal::ONE)
-        .unwrap()
-        .checked_pow(1)
-        .unwrap();
-    let exp_borrowed_amount = Decimal::from_i128_with_scale(150, 0) // borrowed_amount * interest_rate
-        .unwrap()
-        .checked_add(borrowed_amount)
-        .unwrap()
-        .checked_mul(compounded_interest_rate)
-        .unwrap()
-        .round_to_decimals(8);
-
-    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
-    assert_eq!(borrowed_amount.round_to_decimals(8), exp_borrowed_amount);
 }
 
-// Reserve can only give 5 SOL (out of 10) because of max_borrow_utilization_bps = 50%
-// While deposited collateral allows greater borrow amount.
 #[tokio::test]
-async fn borrow_max_amount_limited_by_reserve() {
+async fn borrow_isolated_collateral() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -3045,10 +4616,11 @@ async fn borrow_max_amount_limited_by_reserve() {
     let curator_pubkey = curator_keypair.pubkey();
 
     let reserve_sol1_keypair = Keypair::new();
-    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
     let reserve_sol2_keypair = Keypair::new();
     let reserve_usdc_keypair = Keypair::new();
     let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+    let reserve_isolated_keypair = Keypair::new();
+    let reserve_isolated_pubkey = reserve_isolated_keypair.pubkey();
 
     runner.add_native_wallet(admin_pubkey, LAMPORTS);
     runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
@@ -3067,7 +4639,6 @@ async fn borrow_max_amount_limited_by_reserve() {
         Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
     let liquidity_usdc_mint =
         Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
-
     init_token_accounts(&mut runner, &liquidity_sol_mint);
     init_token_accounts(&mut runner, &liquidity_usdc_mint);
 
@@ -3090,51 +4661,98 @@ async fn borrow_max_amount_limited_by_reserve() {
     )
     .await;
 
-    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+    // CREATE ISOLATED COLLATERAL SOL RESERVE
 
-    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
-    let dest_lender_lp_wallet_sol =
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.market_price_feed = sol_price_feed;
+    params.max_borrow_ltv_bps = 5000;
+
+    create_reserve(
+        &mut ctx,
+        &reserve_isolated_keypair,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        liquidity_sol_mint,
+        sol_price_feed,
+        params,
+        RESERVE_TYPE_ISOLATED_COLLATERAL,
+    )
+    .await
+    .expect("create_reserve");
+
+    // Isolated collateral Reserve itself can not be isolation borrowable
+    let result = alter_isolation(
+        &mut ctx,
+        reserve_isolated_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        0,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // 100 USD debt ceiling
+    alter_isolation(
+        &mut ctx,
+        reserve_isolated_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        0,
+        100,
+    )
+    .await
+    .expect("alter_isolation");
+
+    // LENDER DEPOSITS 1000 USDC
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_lender_lp_wallet_usdc =
         create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
             .await
             .expect("create lp ata");
-    let source_lender_liq_wallet_sol =
-        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+    let source_lender_liq_wallet_usdc =
+        get_associated_token_address(&lender_pubkey, &liquidity_usdc_mint);
 
-    info!("deposit initial liquidity");
     deposit_liquidity(
         &mut ctx,
-        reserve_sol1_pubkey,
-        sol_price_feed,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
         irm,
         &lender_keypair,
-        source_lender_liq_wallet_sol,
-        dest_lender_lp_wallet_sol,
-        10 * LAMPORTS_PER_SOL,
+        source_lender_liq_wallet_usdc,
+        dest_lender_lp_wallet_usdc,
+        1_000 * LAMPORTS_PER_USDC,
     )
     .await
     .expect("deposit_liquidity");
 
-    // DEPOSIT 2000 USDC AND LOCK COLLATERAL
+    // BORROWER DEPOSITS 10 SOL TO ISOLATED RESERVE AND LOCKS COLLATERAL
 
-    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
-    let dest_borrower_lp_wallet_usdc =
+    let lp_mint = find_lp_token_mint(&reserve_isolated_pubkey).0;
+    let dest_borrower_lp_wallet_sol =
         create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
             .await
             .expect("create lp ata");
-    let source_borrower_liq_wallet_usdc =
-        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let deposit_usdc_amount = 2_000 * LAMPORTS_PER_USDC;
+    let source_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
 
-    info!("deposit {} into USDC reserve", deposit_usdc_amount);
     deposit_liquidity(
         &mut ctx,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_isolated_pubkey,
+        sol_price_feed,
         irm,
         &borrower_keypair,
-        source_borrower_liq_wallet_usdc,
-        dest_borrower_lp_wallet_usdc,
-        deposit_usdc_amount,
+        source_borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_sol,
+        10 * LAMPORTS_PER_SOL,
     )
     .await
     .expect("deposit_liquidity");
@@ -3143,231 +4761,181 @@ async fn borrow_max_amount_limited_by_reserve() {
         .await
         .expect("refresh position");
 
-    info!("lock {} collateral lp", deposit_usdc_amount);
     lock_collateral(
         &mut ctx,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_isolated_pubkey,
+        sol_price_feed,
         irm,
         position_pubkey,
         &borrower_keypair,
-        dest_borrower_lp_wallet_usdc,
-        deposit_usdc_amount,
+        dest_borrower_lp_wallet_sol,
+        MAX_AMOUNT,
     )
     .await
     .expect("lock_collateral");
 
-    info!("refresh position");
-    refresh_position(&mut ctx, position_pubkey)
-        .await
-        .expect("refresh position");
-
     let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
         .await
         .expect("get position");
     let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert_eq!(position.isolated_collateral, reserve_isolated_pubkey);
 
-    // CHECK deposited_value = deposit_amount * lp_exchange_rate
-    assert_eq!(
-        position.deposited_value().unwrap(),
-        Decimal::from_i128_with_scale(2002, 0).unwrap()
-    );
-    // CHECK allowed_borrow_value = deposit_amount * lp_exchange_rate * %max_borrow_ltv
-    assert_eq!(
-        position.allowed_borrow_value().unwrap(),
-        Decimal::from_i128_with_scale(18018, 1).unwrap()
-    );
+    // ISOLATED POSITION CAN NOT LOCK OTHER COLLATERAL
 
-    // BORROW maximum SOLs contract can give
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
 
-    let dest_borrower_liq_wallet_sol =
-        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        10 * LAMPORTS_PER_USDC,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let result = lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        MAX_AMOUNT,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // BORROW FROM RESERVE WHICH IS NOT ISOLATION BORROWABLE
+
+    let dest_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
     let texture_fee_receiver =
-        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_usdc_mint)
             .await
             .expect("create texture fee receiver ata");
     let curator_fee_receiver =
-        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_usdc_mint)
             .await
             .expect("create curator fee receiver ata");
 
-    let borrower_sol_token_acc0 =
-        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
-            .await
-            .expect("get token acc");
-    let curator_fee_token_acc0 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
-        .await
-        .expect("get token acc");
-    let texture_fee_token_acc0 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
-        .await
-        .expect("get token acc");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        50 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await;
+    assert!(result.is_err());
+
+    alter_isolation(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        0,
+    )
+    .await
+    .expect("alter_isolation");
 
-    info!("borrow MAX SOL after lock deposited collateral");
+    // BORROW 50 USDC WHICH IS BELOW DEBT CEILING
+
+    info!("borrow 50 usdc");
     borrow(
         &mut ctx,
         position_pubkey,
-        reserve_sol1_pubkey,
-        sol_price_feed,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
         irm,
         pool_pubkey,
         &borrower_keypair,
         curator_pubkey,
         curator_fee_receiver,
         texture_fee_receiver,
-        dest_borrower_liq_wallet_sol,
-        u64::MAX,
+        dest_borrower_liq_wallet_usdc,
+        50 * LAMPORTS_PER_USDC,
         1,
     )
     .await
     .expect("borrow");
 
-    let amount = LAMPORTS_PER_SOL;
-
-    let borrower_sol_token_acc1 =
-        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
-            .await
-            .expect("get token acc");
-    let curator_fee_token_acc1 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
-        .await
-        .expect("get token acc");
-    let texture_fee_token_acc1 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
-        .await
-        .expect("get token acc");
-
-    info!("refresh position");
-    refresh_position(&mut ctx, position_pubkey)
-        .await
-        .expect("refresh_position");
-
     let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
         .await
         .expect("get position");
     let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_isolated_pubkey)
         .await
-        .expect("get position");
+        .expect("get reserve");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let texture_config_acc = get_account(&mut ctx.banks_client, texture_config_keypair.pubkey())
-        .await
-        .expect("get position");
-    let texture_config =
-        TextureConfig::try_from_bytes(&texture_config_acc.data).expect("cast reserve data");
-
-    // CHECK POSITION BORROWED VALUE
-    let borrowed = position.borrowed_value().unwrap();
-    assert_eq!(
-        borrowed,
-        // 500 - including 1%_curator_fee + 30%_texture_fee.
-        Decimal::from_i128_with_scale(500, 0).unwrap()
-    );
-    // CHECK BorrowedLiquidity amount
-    let borrowed_liquidity = position
-        .find_borrowed_liquidity(reserve_sol1_pubkey)
-        .expect("find_borrowed_liquidity")
-        .0;
-    let borrowed_amount = borrowed_liquidity.borrowed_amount().unwrap();
-
-    // User borrowed 5 SOLs - 3.81 for himself and rest to pay Texture and Curator fees. Solana time is not winded
-    // between Borrow and RefreshPosition. Thus no interest accrued yet.
-    assert_eq!(
-        borrowed_amount,
-        Decimal::from_lamports(5_000_000_000, 9).unwrap()
-    );
-
-    // CHECK BORROWER BALANCE
     assert_eq!(
-        borrower_sol_token_acc1.amount,
-        borrower_sol_token_acc0.amount + 3816793893
+        reserve.isolated_debt().unwrap(),
+        position.borrows[0].market_value().unwrap()
     );
 
-    // CHECK FEE TRANSFERS
-    let (curator_fee, texture_fee) = reserve
-        .config
-        .fees
-        .calculate_borrow_fees(
-            Decimal::from_lamports(amount, 9).unwrap(),
-            9,
-            texture_config.borrow_fee_rate_bps,
-            FeeCalculation::Exclusive,
-        )
-        .expect("calculate borrow fees");
-    assert_eq!(curator_fee, 10_000_000);
-    assert_eq!(texture_fee, 300_000_000);
-
-    // Curator borrow fee is 1% from borrowed amount i.e. from 3816793893
-    assert_eq!(
-        curator_fee_token_acc1.amount,
-        curator_fee_token_acc0.amount + 38167939
-    );
-    // Texture borrow fee is 30% i.e. 3816793893 * 0.3 = 1145038168
-    assert_eq!(
-        texture_fee_token_acc1.amount,
-        texture_fee_token_acc0.amount + 1145038168
-    );
+    // BORROW 60 USDC TO EXCEED DEBT CEILING
 
-    // 1 SOLANA YEAR LATER
+    info!("borrow 60 usdc to exceed debt ceiling");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_usdc,
+        60 * LAMPORTS_PER_USDC,
+        1,
+    )
+    .await;
+    assert!(result.is_err());
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR).expect("warp_to_slot"); // solana_year = 63072000 slots
+    // REPAY ALL. ISOLATED DEBT RELEASED
 
-    info!("refresh position");
-    refresh_position(&mut ctx, position_pubkey)
-        .await
-        .expect("refresh_position");
+    repay(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("repay");
 
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_isolated_pubkey)
         .await
-        .expect("get position");
+        .expect("get reserve");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
-        .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
-        .unwrap();
-    let compounded_interest_rate = slot_interest_rate
-        .checked_add(Decimal::ONE)
-        .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
-        .unwrap();
-
-    let curator_performance_fee = reserve
-        .liquidity
-        .curator_performance_fee()
-        .unwrap()
-        .round_to_decimals(9);
-    let texture_performance_fee = reserve
-        .liquidity
-        .texture_performance_fee()
-        .unwrap()
-        .round_to_decimals(9);
-
-    // CHECK PERFORMANCE FEES
-    assert_eq!(
-        curator_performance_fee,
-        Decimal::from_i128_with_scale(491824686, 9).unwrap()
-    );
-    assert_eq!(
-        texture_performance_fee,
-        Decimal::from_i128_with_scale(983649373, 9).unwrap()
-    );
-
-    let exp_borrowed_amount = Decimal::from_i128_with_scale(500, 2) // borrowed_amount = receive_amount + fees
-        .unwrap()
-        .checked_mul(compounded_interest_rate)
-        .unwrap()
-        .round_to_decimals(6);
-    let borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
-
-    // CHECK REPAYMENT TO RESERVE
-    assert_eq!(borrowed_amount.round_to_decimals(6), exp_borrowed_amount);
+    assert_eq!(reserve.isolated_debt().unwrap(), Decimal::ZERO);
 }
 
-// This test operates on big amounts to test overflow conditions as well as u64:max as special value
-// for Borrow IX.
-// Reserve can give 5_000_000 SOL (out of 10_000_000) because of max_borrow_utilization_bps = 50%
-// While deposited collateral allows to borrow just 1_000_000 SOL.
 #[tokio::test]
-async fn borrow_max_amount_limited_by_position() {
+async fn borrow_emode() {
     let mut runner = init_program_test();
 
     let admin_keypair = admin_keypair();
@@ -3394,8 +4962,10 @@ async fn borrow_max_amount_limited_by_position() {
     let reserve_sol1_keypair = Keypair::new();
     let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
     let reserve_sol2_keypair = Keypair::new();
+    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey();
     let reserve_usdc_keypair = Keypair::new();
-    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+    let reserve_lst_keypair = Keypair::new();
+    let reserve_lst_pubkey = reserve_lst_keypair.pubkey();
 
     runner.add_native_wallet(admin_pubkey, LAMPORTS);
     runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
@@ -3405,8 +4975,7 @@ async fn borrow_max_amount_limited_by_position() {
 
     // 1 SOL = 100 USD
     let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
-    // 1 USDC = 1.001 USD
-    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+    let _usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
 
     let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
 
@@ -3414,30 +4983,120 @@ async fn borrow_max_amount_limited_by_position() {
         Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
     let liquidity_usdc_mint =
         Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
-
     init_token_accounts(&mut runner, &liquidity_sol_mint);
     init_token_accounts(&mut runner, &liquidity_usdc_mint);
 
     let mut ctx = runner.start_with_context().await;
 
-    setup_lendy_env(
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // CONFIGURE "SOL-correlated" E-MODE CATEGORY
+
+    let mut category = EmodeCategory {
+        name: str_to_array("SOL-correlated"),
+        max_borrow_ltv_bps: 9300,
+        partly_unhealthy_ltv_bps: 9300,
+        fully_unhealthy_ltv_bps: 9500,
+        _padding: Zeroable::zeroed(),
+    };
+
+    // max_borrow_ltv must be below partly_unhealthy_ltv
+    let result = set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await;
+    assert!(result.is_err());
+
+    category.max_borrow_ltv_bps = 9000;
+
+    // Category IDs start from 1
+    let result = set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        0,
+        category,
+    )
+    .await;
+    assert!(result.is_err());
+
+    set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await
+    .expect("set_emode_category");
+
+    // CREATE LST RESERVE WITH 60% MAX BORROW LTV
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    create_reserve(
+        &mut ctx,
+        &reserve_lst_keypair,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        liquidity_sol_mint,
+        sol_price_feed,
+        reserve.config,
+        RESERVE_TYPE_NORMAL,
+    )
+    .await
+    .expect("create_reserve");
+
+    // Category 2 is not configured
+    let result = alter_emode_category(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        2,
+    )
+    .await;
+    assert!(result.is_err());
+
+    alter_emode_category(
         &mut ctx,
-        &admin_keypair,
-        &borrower_keypair,
-        &curator_keypair,
-        &pool_keypair,
-        &reserve_sol1_keypair,
-        &reserve_sol2_keypair,
-        &reserve_usdc_keypair,
-        &texture_owner_keypair,
-        &texture_config_keypair,
+        reserve_sol1_pubkey,
+        pool_pubkey,
         &pool_authority_keypair,
-        &borrower_position_keypair,
-        irm,
+        curator_pubkey,
+        1,
     )
-    .await;
+    .await
+    .expect("alter_emode_category");
 
-    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+    // LENDER DEPOSITS 100 SOL TO SOL1 RESERVE
 
     let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
     let dest_lender_lp_wallet_sol =
@@ -3447,7 +5106,6 @@ async fn borrow_max_amount_limited_by_position() {
     let source_lender_liq_wallet_sol =
         get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
 
-    info!("deposit initial liquidity");
     deposit_liquidity(
         &mut ctx,
         reserve_sol1_pubkey,
@@ -3456,32 +5114,30 @@ async fn borrow_max_amount_limited_by_position() {
         &lender_keypair,
         source_lender_liq_wallet_sol,
         dest_lender_lp_wallet_sol,
-        1_000_000 * LAMPORTS_PER_SOL, // 1M SOLs ! worth 100M $
+        100 * LAMPORTS_PER_SOL,
     )
     .await
     .expect("deposit_liquidity");
 
-    // DEPOSIT 100 USDC AND LOCK COLLATERAL
+    // BORROWER DEPOSITS 10 SOL TO LST RESERVE AND LOCKS COLLATERAL
 
-    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
-    let dest_borrower_lp_wallet_usdc =
+    let lp_mint = find_lp_token_mint(&reserve_lst_pubkey).0;
+    let dest_borrower_lp_wallet_lst =
         create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
             .await
             .expect("create lp ata");
-    let source_borrower_liq_wallet_usdc =
-        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
-    let deposit_usdc_amount = 10_000_000 * LAMPORTS_PER_USDC; // 10M $
+    let source_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
 
-    info!("deposit {} into USDC reserve", deposit_usdc_amount);
     deposit_liquidity(
         &mut ctx,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_lst_pubkey,
+        sol_price_feed,
         irm,
         &borrower_keypair,
-        source_borrower_liq_wallet_usdc,
-        dest_borrower_lp_wallet_usdc,
-        deposit_usdc_amount,
+        source_borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_lst,
+        10 * LAMPORTS_PER_SOL,
     )
     .await
     .expect("deposit_liquidity");
@@ -3490,21 +5146,31 @@ async fn borrow_max_amount_limited_by_position() {
         .await
         .expect("refresh position");
 
-    info!("lock {} collateral lp", deposit_usdc_amount);
     lock_collateral(
         &mut ctx,
-        reserve_usdc_pubkey,
-        usdc_price_feed,
+        reserve_lst_pubkey,
+        sol_price_feed,
         irm,
         position_pubkey,
         &borrower_keypair,
-        dest_borrower_lp_wallet_usdc,
-        deposit_usdc_amount,
+        dest_borrower_lp_wallet_lst,
+        MAX_AMOUNT,
     )
     .await
     .expect("lock_collateral");
 
-    info!("refresh position");
+    // POSITION OPTS IN TO E-MODE
+
+    let result =
+        set_position_emode(&mut ctx, position_pubkey, pool_pubkey, &borrower_keypair, 2).await;
+    assert!(result.is_err());
+
+    set_position_emode(&mut ctx, position_pubkey, pool_pubkey, &borrower_keypair, 1)
+        .await
+        .expect("set_position_emode");
+
+    // LST RESERVE IS NOT IN THE CATEGORY YET - RESERVE'S LTVS ARE USED
+
     refresh_position(&mut ctx, position_pubkey)
         .await
         .expect("refresh position");
@@ -3513,19 +5179,99 @@ async fn borrow_max_amount_limited_by_position() {
         .await
         .expect("get position");
     let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
-
-    // CHECK deposited_value = deposit_amount * lp_exchange_rate
+    assert_eq!(position.emode_category, 1);
     assert_eq!(
-        position.deposited_value().unwrap(),
-        Decimal::from_i128_with_scale(100100000, 1).unwrap()
+        position.allowed_borrow_value().unwrap(),
+        position
+            .deposited_value()
+            .unwrap()
+            .checked_mul(Decimal::from_basis_points(6000).unwrap())
+            .unwrap()
     );
-    // CHECK allowed_borrow_value = deposit_amount * lp_exchange_rate * %max_borrow_ltv
+
+    // COLLATERAL FROM RESERVE OUTSIDE THE CATEGORY CAN NOT BE LOCKED
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_lst_pubkey,
+        sol_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_lst,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let result = lock_collateral(
+        &mut ctx,
+        reserve_lst_pubkey,
+        sol_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_lst,
+        MAX_AMOUNT,
+    )
+    .await;
+    assert!(result.is_err());
+
+    alter_emode_category(
+        &mut ctx,
+        reserve_lst_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+    )
+    .await
+    .expect("alter_emode_category");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_lst_pubkey,
+        sol_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_lst,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // ALL DEPOSITS ARE IN THE CATEGORY - CATEGORY LTVS ARE USED
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let deposited_value = position.deposited_value().unwrap();
     assert_eq!(
         position.allowed_borrow_value().unwrap(),
-        Decimal::from_i128_with_scale(900900000, 2).unwrap()
+        deposited_value
+            .checked_mul(Decimal::from_basis_points(9000).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        position.partly_unhealthy_borrow_value().unwrap(),
+        deposited_value
+            .checked_mul(Decimal::from_basis_points(9300).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        position.fully_unhealthy_borrow_value().unwrap(),
+        deposited_value
+            .checked_mul(Decimal::from_basis_points(9500).unwrap())
+            .unwrap()
     );
 
-    // BORROW MAX SOL contract can give
+    // BORROW 8 SOL WHICH IS ABOVE RESERVE'S LTV BUT BELOW CATEGORY'S ONE
 
     let dest_borrower_liq_wallet_sol =
         get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
@@ -3538,18 +5284,6 @@ async fn borrow_max_amount_limited_by_position() {
             .await
             .expect("create curator fee receiver ata");
 
-    let borrower_sol_token_acc0 =
-        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
-            .await
-            .expect("get token acc");
-    let curator_fee_token_acc0 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
-        .await
-        .expect("get token acc");
-    let texture_fee_token_acc0 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
-        .await
-        .expect("get token acc");
-
-    info!("borrow MAX SOL after lock deposited collateral");
     borrow(
         &mut ctx,
         position_pubkey,
@@ -3562,87 +5296,147 @@ async fn borrow_max_amount_limited_by_position() {
         curator_fee_receiver,
         texture_fee_receiver,
         dest_borrower_liq_wallet_sol,
-        u64::MAX,
+        8 * LAMPORTS_PER_SOL,
         1,
     )
     .await
     .expect("borrow");
 
-    let borrower_sol_token_acc1 =
-        get_token_account(&mut ctx.banks_client, dest_borrower_liq_wallet_sol)
+    // SOL2 RESERVE IS OUTSIDE THE CATEGORY - BORROWING FROM IT IS REJECTED
+
+    let lp_mint = find_lp_token_mint(&reserve_sol2_pubkey).0;
+    let dest_lender_lp_wallet_sol2 =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
             .await
-            .expect("get token acc");
-    let curator_fee_token_acc1 = get_token_account(&mut ctx.banks_client, curator_fee_receiver)
-        .await
-        .expect("get token acc");
-    let texture_fee_token_acc1 = get_token_account(&mut ctx.banks_client, texture_fee_receiver)
-        .await
-        .expect("get token acc");
+            .expect("create lp ata");
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol2,
+        10 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
 
-    info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
         .await
-        .expect("refresh_position");
+        .expect("refresh position");
 
-    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
-        .await
-        .expect("get position");
-    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
-    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
-        .await
-        .expect("get position");
-    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
-    let texture_config_acc = get_account(&mut ctx.banks_client, texture_config_keypair.pubkey())
-        .await
-        .expect("get position");
-    let texture_config =
-        TextureConfig::try_from_bytes(&texture_config_acc.data).expect("cast reserve data");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL / 10,
+        1,
+    )
+    .await;
+    assert!(result.is_err());
 
-    // CHECK POSITION BORROWED VALUE
-    let borrowed = position.borrowed_value().unwrap();
-    assert_eq!(
-        borrowed,
-        // Position's allowed borrow value is 9_009_000 USD. We asked max borrow and it is also 9_009_000 in value!
-        Decimal::from_i128_with_scale(900900000, 2).unwrap()
-    );
-    // CHECK BorrowedLiquidity amount
-    let borrowed_liquidity = position
-        .find_borrowed_liquidity(reserve_sol1_pubkey)
-        .expect("find_borrowed_liquidity")
-        .0;
-    let borrowed_amount = borrowed_liquidity.borrowed_amount().unwrap();
+    // E-MODE CAN NOT BE CHANGED WHILE THERE ARE BORROWINGS
 
-    assert_eq!(
-        borrowed_amount,
-        Decimal::from_lamports(90_090 * LAMPORTS_PER_SOL, 9).unwrap()
-    );
+    let result =
+        set_position_emode(&mut ctx, position_pubkey, pool_pubkey, &borrower_keypair, 0).await;
+    assert!(result.is_err());
+}
 
-    // CHECK BORROWER BALANCE
-    assert_eq!(
-        borrower_sol_token_acc1.amount,
-        borrower_sol_token_acc0.amount + 68_770_992_366_412 // 68_770 SOL
+async fn alter_emode_category(
+    ctx: &mut ProgramTestContext,
+    reserve: Pubkey,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    emode_category: u8,
+) -> Result<(), BanksClientError> {
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve)
+        .await
+        .expect("get reserve");
+    let unpacked_reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = unpacked_reserve.config;
+    params.emode_category = emode_category;
+
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[AlterReserve {
+            reserve,
+            pool,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            params,
+            mode: unpacked_reserve.mode,
+            flash_loans_enabled: unpacked_reserve.flash_loans_enabled,
+            surplus_destination: unpacked_reserve.surplus_destination,
+            isolation_borrowable: unpacked_reserve.isolation_borrowable,
+            isolated_debt_ceiling: unpacked_reserve.isolated_debt_ceiling,
+            fixed_rate_bps: unpacked_reserve.fixed_rate_bps,
+            fixed_max_term_sec: unpacked_reserve.fixed_max_term_sec,
+            fixed_grace_period_sec: unpacked_reserve.fixed_grace_period_sec,
+        }
+        .into_instruction()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, curator_pools_authority],
+        blockhash,
     );
+    ctx.banks_client.process_transaction(tx).await
+}
 
-    // CHECK FEE TRANSFERS
-    let (curator_fee, texture_fee) = reserve
-        .config
-        .fees
-        .calculate_borrow_fees(
-            Decimal::from_lamports(68_770_992_366_412, 9).unwrap(),
-            9,
-            texture_config.borrow_fee_rate_bps,
-            FeeCalculation::Exclusive,
-        )
-        .expect("calculate borrow fees");
-    assert_eq!(curator_fee, 687_709_923_664);
-    assert_eq!(texture_fee, 20_631_297_709_924);
+async fn alter_isolation(
+    ctx: &mut ProgramTestContext,
+    reserve: Pubkey,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    isolation_borrowable: u8,
+    isolated_debt_ceiling: u64,
+) -> Result<(), BanksClientError> {
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve)
+        .await
+        .expect("get reserve");
+    let unpacked_reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    assert_eq!(
-        curator_fee_token_acc1.amount,
-        curator_fee_token_acc0.amount + curator_fee
-    );
-    assert_eq!(
-        texture_fee_token_acc1.amount,
-        texture_fee_token_acc0.amount + texture_fee
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[AlterReserve {
+            reserve,
+            pool,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            params: unpacked_reserve.config,
+            mode: unpacked_reserve.mode,
+            flash_loans_enabled: unpacked_reserve.flash_loans_enabled,
+            surplus_destination: unpacked_reserve.surplus_destination,
+            isolation_borrowable,
+            isolated_debt_ceiling,
+            fixed_rate_bps: unpacked_reserve.fixed_rate_bps,
+            fixed_max_term_sec: unpacked_reserve.fixed_max_term_sec,
+            fixed_grace_period_sec: unpacked_reserve.fixed_grace_period_sec,
+        }
+        .into_instruction()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, curator_pools_authority],
+        blockhash,
     );
+    ctx.banks_client.process_transaction(tx).await
 }
@@ -8,24 +8,24 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use spl_associated_token_account::get_associated_token_address;
 use texture_common::account::PodAccount;
-use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, Decimal};
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal};
 use tracing::info;
 
-use super_lendy::pda::find_lp_token_mint;
+use super_lendy::pda::{find_liquidity_supply, find_lp_token_mint};
 use super_lendy::state::position::Position;
 use super_lendy::state::reserve::{FeeCalculation, Reserve};
 use super_lendy::state::texture_cfg::TextureConfig;
-use super_lendy::state::SLOTS_PER_YEAR;
+use super_lendy::state::SECONDS_PER_YEAR;
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
     borrow, claim_curator_performance_fees, claim_texture_performance_fees, deposit_liquidity,
-    lock_collateral, refresh_position,
+    lock_collateral, refresh_position, sync_reserve,
 };
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
     create_associated_token_account, get_account, get_token_account, init_program_test,
-    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
+    init_token_accounts, lender_keypair, texture_config_keypair, warp_seconds, Runner, LAMPORTS,
     LAMPORTS_PER_USDC,
 };
 
@@ -316,10 +316,12 @@ async fn claim_success() {
         texture_fee_token_acc0.amount + texture_fee
     );
 
-    // 1 SOLANA YEAR LATER
+    let reserve_borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR).expect("warp_to_slot"); // solana_year = 63072000 slots
+    // 1 YEAR LATER
+
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -332,27 +334,39 @@ async fn claim_success() {
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
     let lp_exchange_rate0 = reserve.lp_exchange_rate().unwrap();
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
+        .checked_pow(SECONDS_PER_YEAR)
         .unwrap();
 
     let curator_performance_fee = reserve.liquidity.curator_performance_fee().unwrap();
     let texture_performance_fee = reserve.liquidity.texture_performance_fee().unwrap();
+    let interest = reserve
+        .liquidity
+        .borrowed_amount()
+        .unwrap()
+        .checked_sub(reserve_borrowed_amount)
+        .unwrap();
 
-    // CHECK PERFORMANCE FEES
+    // CHECK PERFORMANCE FEES. Curator takes 20% and Texture 40% of the interest.
     assert_eq!(
         curator_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(128858068, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(2000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
     assert_eq!(
         texture_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(257716136, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(4000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
 
     let exp_borrowed_amount = Decimal::from_i128_with_scale(131, 2) // borrowed_amount = receive_amount + fees
@@ -457,6 +471,32 @@ async fn claim_success() {
     assert_eq!(borrowed_amount1, borrowed_amount0);
     assert_eq!(lp_exchange_rate1.0, lp_exchange_rate0.0);
     assert_eq!(total_liquidity1, total_liquidity0);
+
+    // CHECK claimed fees are counted and not reported as supply deficit
+    let supply_acc = get_token_account(
+        &mut ctx.banks_client,
+        find_liquidity_supply(&reserve_sol1_pubkey).0,
+    )
+    .await
+    .expect("get token acc");
+    assert_eq!(
+        supply_acc.amount,
+        reserve.liquidity.available_amount - reserve.liquidity.claimed_performance_fee
+    );
+
+    info!("sync reserve");
+    sync_reserve(&mut ctx, reserve_sol1_pubkey)
+        .await
+        .expect("sync_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        supply_acc.amount,
+        reserve.liquidity.available_amount - reserve.liquidity.claimed_performance_fee
+    );
 }
 
 /// See test description in
@@ -744,10 +784,12 @@ async fn claim_incorrect_fee_receiver() {
         texture_fee_token_acc0.amount + texture_fee
     );
 
-    // 1 SOLANA YEAR LATER
+    let reserve_borrowed_amount = reserve.liquidity.borrowed_amount().unwrap();
 
-    info!("wrap to slot {}", SLOTS_PER_YEAR);
-    ctx.warp_to_slot(SLOTS_PER_YEAR).expect("warp_to_slot"); // solana_year = 63072000 slots
+    // 1 YEAR LATER
+
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -759,27 +801,39 @@ async fn claim_incorrect_fee_receiver() {
         .expect("get position");
     let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
 
-    let slot_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
+    let second_interest_rate = Decimal::from_i128_with_scale(4, 1) // borrow_rate = 40% fixed
         .unwrap()
-        .checked_div(Decimal::from_i128_with_scale(SLOTS_PER_YEAR as i128, 0).unwrap())
+        .checked_div(Decimal::from_i128_with_scale(SECONDS_PER_YEAR as i128, 0).unwrap())
         .unwrap();
-    let compounded_interest_rate = slot_interest_rate
+    let compounded_interest_rate = second_interest_rate
         .checked_add(Decimal::ONE)
         .unwrap()
-        .checked_pow(SLOTS_PER_YEAR)
+        .checked_pow(SECONDS_PER_YEAR)
         .unwrap();
 
     let curator_performance_fee = reserve.liquidity.curator_performance_fee().unwrap();
     let texture_performance_fee = reserve.liquidity.texture_performance_fee().unwrap();
+    let interest = reserve
+        .liquidity
+        .borrowed_amount()
+        .unwrap()
+        .checked_sub(reserve_borrowed_amount)
+        .unwrap();
 
-    // CHECK PERFORMANCE FEES
+    // CHECK PERFORMANCE FEES. Curator takes 20% and Texture 40% of the interest.
     assert_eq!(
         curator_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(128858068, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(2000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
     assert_eq!(
         texture_performance_fee.round_to_decimals(9),
-        Decimal::from_i128_with_scale(257716136, 9).unwrap()
+        interest
+            .checked_mul(Decimal::from_basis_points(4000).unwrap())
+            .unwrap()
+            .round_to_decimals(9)
     );
 
     let exp_borrowed_amount = Decimal::from_i128_with_scale(131, 2) // borrowed_amount = receive_amount + fees
@@ -1,6 +1,7 @@
 #![cfg(feature = "test-bpf")]
 
 use bytemuck::Zeroable;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
@@ -46,6 +47,9 @@ async fn create_alter_success() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -141,6 +145,9 @@ async fn create_incorrect_admin() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -214,6 +221,9 @@ async fn alter_incorrect_owner() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -0,0 +1,269 @@
+#![cfg(feature = "test-bpf")]
+
+use std::str::FromStr;
+
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use texture_common::account::PodAccount;
+use tracing::info;
+
+use super_lendy::pda::{find_collateral_supply, find_lp_token_mint};
+use super_lendy::state::position::Position;
+use super_lendy::state::reserve::Reserve;
+use super_lendy::MAX_AMOUNT;
+
+use crate::utils::setup_super_lendy::setup_lendy_env;
+use crate::utils::superlendy_executor::{
+    borrow, deposit_and_lock_collateral, deposit_liquidity, unlock_and_withdraw_liquidity,
+};
+use crate::utils::{
+    add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
+    create_associated_token_account, get_account, get_token_account, init_program_test,
+    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
+    LAMPORTS_PER_USDC,
+};
+
+pub mod utils;
+
+#[tokio::test]
+async fn deposit_and_lock_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    let borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let collateral_supply_usdc = find_collateral_supply(&reserve_usdc_pubkey).0;
+
+    let borrower_liq_token_acc0 =
+        get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+            .await
+            .expect("get token acc");
+
+    info!("SOL1 reserve is not a collateral");
+    deposit_and_lock_collateral(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect_err("deposit_and_lock_collateral to NOT_A_COLLATERAL reserve");
+
+    // DEPOSIT AND LOCK 10_000 USDC
+
+    let deposit_usdc_amount = 10_000 * LAMPORTS_PER_USDC;
+    deposit_and_lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_liq_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_and_lock_collateral");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let collateral_supply = get_token_account(&mut ctx.banks_client, collateral_supply_usdc)
+        .await
+        .expect("get token acc");
+    let borrower_liq_token_acc1 =
+        get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+            .await
+            .expect("get token acc");
+
+    // LP exchange rate = 1 as USDC is not borrowed. LPs minted straight to collateral supply.
+    let collateral = position.find_collateral(reserve_usdc_pubkey).unwrap().0;
+    assert_eq!(collateral.deposited_amount, deposit_usdc_amount);
+    assert_eq!(reserve.collateral.lp_total_supply, deposit_usdc_amount);
+    assert_eq!(reserve.liquidity.available_amount, deposit_usdc_amount);
+    assert_eq!(collateral_supply.amount, deposit_usdc_amount);
+    assert_eq!(
+        borrower_liq_token_acc1.amount,
+        borrower_liq_token_acc0.amount - deposit_usdc_amount
+    );
+
+    // BORROW 10 SOL
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let lender_lp_wallet_sol = create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+        .await
+        .expect("create lp ata");
+    let lender_liq_wallet_sol = get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        lender_liq_wallet_sol,
+        lender_lp_wallet_sol,
+        100 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        10 * LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // UNLOCK AND WITHDRAW
+
+    info!("can not unlock whole collateral while there is debt");
+    unlock_and_withdraw_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_liq_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect_err("unlock_and_withdraw_liquidity of whole collateral");
+
+    info!("unlock as much as Position allows");
+    unlock_and_withdraw_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_liq_wallet_usdc,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("unlock_and_withdraw_liquidity");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let collateral_supply = get_token_account(&mut ctx.banks_client, collateral_supply_usdc)
+        .await
+        .expect("get token acc");
+    let borrower_liq_token_acc2 =
+        get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+            .await
+            .expect("get token acc");
+
+    // Part of collateral stays locked to back SOL debt. Unlocked LPs burned from collateral supply.
+    let collateral = position.find_collateral(reserve_usdc_pubkey).unwrap().0;
+    let unlocked_amount = deposit_usdc_amount - collateral.deposited_amount;
+    info!("unlocked_amount {}", unlocked_amount);
+    assert!(collateral.deposited_amount > 0);
+    assert!(unlocked_amount > 0);
+    assert_eq!(collateral_supply.amount, collateral.deposited_amount);
+    assert_eq!(
+        reserve.collateral.lp_total_supply,
+        collateral.deposited_amount
+    );
+    assert_eq!(
+        borrower_liq_token_acc2.amount,
+        borrower_liq_token_acc1.amount + unlocked_amount
+    );
+}
@@ -8,6 +8,7 @@ use solana_program::native_token::LAMPORTS_PER_SOL;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
 use spl_associated_token_account::{
     get_associated_token_address, get_associated_token_address_with_program_id,
 };
@@ -19,13 +20,15 @@ use super_lendy::pda::{find_liquidity_supply, find_lp_token_mint};
 use super_lendy::state::curator::CuratorParams;
 use super_lendy::state::pool::PoolParams;
 use super_lendy::state::position::Position;
-use super_lendy::state::reserve::{Reserve, ReserveConfig, ReserveFeesConfig, RESERVE_TYPE_NORMAL};
+use super_lendy::state::reserve::{
+    Reserve, ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE, RESERVE_TYPE_NORMAL,
+};
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
     borrow, create_curator, create_pool, create_reserve, create_texture_config, deposit_liquidity,
-    lock_collateral, refresh_position, withdraw_liquidity,
+    lock_collateral, refresh_position, sync_reserve, withdraw_liquidity,
 };
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
@@ -74,6 +77,9 @@ pub async fn deposit_success(liquidity_mint: Pubkey) {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -87,7 +93,8 @@ pub async fn deposit_success(liquidity_mint: Pubkey) {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -121,6 +128,7 @@ pub async fn deposit_success(liquidity_mint: Pubkey) {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -138,6 +146,9 @@ pub async fn deposit_success(liquidity_mint: Pubkey) {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -148,12 +159,18 @@ pub async fn deposit_success(liquidity_mint: Pubkey) {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
@@ -276,6 +293,9 @@ async fn deposit_incorrect_source() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -289,7 +309,8 @@ async fn deposit_incorrect_source() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -323,6 +344,7 @@ async fn deposit_incorrect_source() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -340,6 +362,9 @@ async fn deposit_incorrect_source() {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -350,12 +375,18 @@ async fn deposit_incorrect_source() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
@@ -441,6 +472,9 @@ async fn deposit_max_total_liquidity() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -454,7 +488,8 @@ async fn deposit_max_total_liquidity() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -488,6 +523,7 @@ async fn deposit_max_total_liquidity() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -505,6 +541,9 @@ async fn deposit_max_total_liquidity() {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let max_total_liquidity = 100_000_000;
@@ -516,12 +555,18 @@ async fn deposit_max_total_liquidity() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
@@ -589,6 +634,338 @@ async fn deposit_max_total_liquidity() {
     assert_eq!(reserve.liquidity.total_liquidity().unwrap(), Decimal::ZERO)
 }
 
+/// Classic first-depositor attack: attacker deposits 1 lamport into a fresh Reserve and then
+/// inflates LP price by donating to the Reserve. Direct token transfers to the supply are not
+/// accounted while flash loan fees are shared with virtual LPs so the next depositor loses nothing.
+#[tokio::test]
+async fn deposit_first_depositor_attack() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let owner_keypair = Keypair::new();
+    let owner_pubkey = owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+    let reserve_keypair = Keypair::new();
+    let reserve_pubkey = reserve_keypair.pubkey();
+    let attacker_keypair = borrow_keypair();
+    let attacker_pubkey = attacker_keypair.pubkey();
+    let victim_keypair = lender_keypair();
+    let victim_pubkey = victim_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(attacker_pubkey, LAMPORTS);
+    runner.add_native_wallet(victim_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    let liquidity_mint = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    init_token_accounts(&mut runner, &liquidity_mint);
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let mut ctx = runner.start_with_context().await;
+
+    // CREATE TEXTURE CONFIG
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 100,
+        performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: owner_pubkey,
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
+        .await
+        .expect("create_texture_config");
+
+    // CREATE CURATOR
+
+    let params = CuratorParams {
+        owner: owner_pubkey,
+        fees_authority: pool_authority_pubkey,
+        pools_authority: pool_authority_pubkey,
+        vaults_authority: pool_authority_pubkey,
+        name: [1; 128],
+        logo_url: [2; 128],
+        website_url: [3; 128],
+    };
+    create_curator(
+        &mut ctx,
+        &curator_keypair,
+        &admin_keypair,
+        &owner_keypair,
+        params,
+    )
+    .await
+    .expect("create_curator");
+
+    // CREATE POOL
+
+    let params = PoolParams {
+        name: [1; 128],
+        market_price_currency_symbol: str_to_array("USD"),
+        visible: 0,
+        auto_write_off_bad_debt: 0,
+    };
+
+    create_pool(
+        &mut ctx,
+        &pool_keypair,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+    )
+    .await
+    .expect("create_pool");
+
+    // CREATE RESERVE
+
+    let fees_config = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 22,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    let config = ReserveConfig {
+        market_price_feed: sol_price_feed,
+        irm,
+        liquidation_bonus_bps: 200,
+        max_borrow_ltv_bps: 6000,
+        partly_unhealthy_ltv_bps: 6500,
+        fully_unhealthy_ltv_bps: 7000,
+        partial_liquidation_factor_bps: 2000,
+        fees: fees_config,
+        max_total_liquidity: 1_000 * LAMPORTS_PER_SOL,
+        max_borrow_utilization_bps: 1000,
+        price_stale_threshold_sec: 10000000,
+        max_withdraw_utilization_bps: 10000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
+    };
+
+    create_reserve(
+        &mut ctx,
+        &reserve_keypair,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        liquidity_mint,
+        sol_price_feed,
+        config,
+        RESERVE_TYPE_NORMAL,
+    )
+    .await
+    .expect("create_reserve");
+
+    // CREATE LP TOKEN WALLETS
+
+    let lp_mint = find_lp_token_mint(&reserve_pubkey).0;
+    let attacker_lp_wallet = create_associated_token_account(&mut ctx, &attacker_keypair, &lp_mint)
+        .await
+        .expect("create lp ata");
+    let victim_lp_wallet = create_associated_token_account(&mut ctx, &victim_keypair, &lp_mint)
+        .await
+        .expect("create lp ata");
+    let attacker_liquidity_wallet = get_associated_token_address(&attacker_pubkey, &liquidity_mint);
+    let victim_liquidity_wallet = get_associated_token_address(&victim_pubkey, &liquidity_mint);
+    let liquidity_supply = find_liquidity_supply(&reserve_pubkey).0;
+
+    // ATTACKER DEPOSITS 1 LAMPORT
+
+    info!("attacker deposits 1 lamport");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_pubkey,
+        sol_price_feed,
+        irm,
+        &attacker_keypair,
+        attacker_liquidity_wallet,
+        attacker_lp_wallet,
+        1,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let attacker_lp = get_token_account(&mut ctx.banks_client, attacker_lp_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+    assert_eq!(attacker_lp, 1);
+
+    let attacker_liquidity0 = get_token_account(&mut ctx.banks_client, attacker_liquidity_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+
+    // ATTACKER DONATES 10 SOL DIRECTLY TO LIQUIDITY SUPPLY
+
+    info!("attacker transfers 10 SOL to liquidity supply");
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[spl_token::instruction::transfer(
+            &spl_token::id(),
+            &attacker_liquidity_wallet,
+            &liquidity_supply,
+            &attacker_pubkey,
+            &[],
+            10 * LAMPORTS_PER_SOL,
+        )
+        .expect("transfer ix")],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &attacker_keypair],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("sending tx");
+
+    // SYNC RESERVE ACCOUNTS DONATION AS LPs PROFIT. ATTACKER'S 1 LP IS WORTH 10 SOL NOW.
+
+    info!("sync reserve");
+    sync_reserve(&mut ctx, reserve_pubkey)
+        .await
+        .expect("sync_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve.liquidity.available_amount,
+        10 * LAMPORTS_PER_SOL + 1
+    );
+    assert_eq!(reserve.collateral.lp_total_supply, 1);
+    let lp_exchange_rate = reserve.lp_exchange_rate().unwrap();
+
+    // VICTIM DEPOSITS 1 SOL
+
+    let amount = LAMPORTS_PER_SOL;
+    info!("victim deposits 1 SOL");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_pubkey,
+        sol_price_feed,
+        irm,
+        &victim_keypair,
+        victim_liquidity_wallet,
+        victim_lp_wallet,
+        amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let victim_lp = get_token_account(&mut ctx.banks_client, victim_lp_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+    assert_eq!(victim_lp, lp_exchange_rate.liquidity_to_lp(amount).unwrap());
+    // Without virtual amounts 1 LP would cost 10 SOL and victim would get 0 LP for 1 SOL deposit
+    // i.e. whole deposit would go to the attacker.
+    assert!(victim_lp > 0);
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let victim_liquidity = reserve
+        .lp_exchange_rate()
+        .unwrap()
+        .lp_to_liquidity(victim_lp)
+        .unwrap();
+    // Victim loses at most 0.1% on rounding
+    assert!(victim_liquidity > amount / 1000 * 999);
+    assert!(victim_liquidity <= amount);
+
+    // ATTACKER WITHDRAWS
+
+    info!("attacker withdraws");
+    withdraw_liquidity(
+        &mut ctx,
+        reserve_pubkey,
+        sol_price_feed,
+        irm,
+        &attacker_keypair,
+        attacker_liquidity_wallet,
+        attacker_lp_wallet,
+        attacker_lp,
+    )
+    .await
+    .expect("withdraw_liquidity");
+
+    let attacker_liquidity1 = get_token_account(&mut ctx.banks_client, attacker_liquidity_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+
+    // CHECK ATTACKER LOST ALMOST WHOLE DONATION. IT BELONGS TO VIRTUAL LPs.
+    assert!(attacker_liquidity0 - attacker_liquidity1 > 10 * LAMPORTS_PER_SOL / 100 * 99);
+
+    // VICTIM WITHDRAWS
+
+    let victim_liquidity0 = get_token_account(&mut ctx.banks_client, victim_liquidity_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+
+    info!("victim withdraws");
+    withdraw_liquidity(
+        &mut ctx,
+        reserve_pubkey,
+        sol_price_feed,
+        irm,
+        &victim_keypair,
+        victim_liquidity_wallet,
+        victim_lp_wallet,
+        victim_lp,
+    )
+    .await
+    .expect("withdraw_liquidity");
+
+    let victim_liquidity1 = get_token_account(&mut ctx.banks_client, victim_liquidity_wallet)
+        .await
+        .expect("get token acc")
+        .amount;
+
+    // CHECK VICTIM GOT BACK AT LEAST WHAT VICTIM'S LP TOKENS WERE WORTH RIGHT AFTER DEPOSIT
+    assert!(victim_liquidity1 - victim_liquidity0 >= victim_liquidity);
+}
+
 /// See test description in
 /// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#23f7fd906b434bdeb6d4043b10c0bb03
 pub async fn withdraw_success(liquidity_mint: Pubkey) {
@@ -627,6 +1004,9 @@ pub async fn withdraw_success(liquidity_mint: Pubkey) {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -640,7 +1020,8 @@ pub async fn withdraw_success(liquidity_mint: Pubkey) {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -674,6 +1055,7 @@ pub async fn withdraw_success(liquidity_mint: Pubkey) {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -691,6 +1073,9 @@ pub async fn withdraw_success(liquidity_mint: Pubkey) {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -701,12 +1086,18 @@ pub async fn withdraw_success(liquidity_mint: Pubkey) {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
@@ -842,6 +1233,9 @@ async fn withdraw_incorrect_destination() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -855,7 +1249,8 @@ async fn withdraw_incorrect_destination() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -889,6 +1284,7 @@ async fn withdraw_incorrect_destination() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -906,6 +1302,9 @@ async fn withdraw_incorrect_destination() {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -916,12 +1315,18 @@ async fn withdraw_incorrect_destination() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
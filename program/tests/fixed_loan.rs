@@ -0,0 +1,759 @@
+#![cfg(feature = "test-bpf")]
+
+use std::str::FromStr;
+
+use bytemuck::Zeroable;
+use chrono::Utc;
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+use tracing::info;
+
+use super_lendy::pda::find_lp_token_mint;
+use super_lendy::state::position::Position;
+use super_lendy::state::reserve::{Reserve, ReserveFeesConfig};
+use super_lendy::MAX_AMOUNT;
+
+use crate::utils::setup_super_lendy::setup_lendy_env;
+use crate::utils::superlendy_executor::{
+    alter_fixed_rate, alter_reserve, borrow_fixed, deposit_liquidity, liquidate, lock_collateral,
+    refresh_position, repay_fixed, resize_position, write_off_bad_debt, write_price,
+};
+use crate::utils::{
+    add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
+    create_associated_token_account, get_account, init_program_test, init_token_accounts,
+    lender_keypair, price_feed_authority, texture_config_keypair, warp_seconds, Runner, LAMPORTS,
+    LAMPORTS_PER_USDC,
+};
+
+pub mod utils;
+
+const DAY_SEC: u32 = 24 * 60 * 60;
+
+#[tokio::test]
+async fn fixed_loan_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey();
+    let reserve_usdc_keypair = Keypair::new();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // LENDER DEPOSITS 100 SOL TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let lender_liq_wallet_sol = get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        100 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // BORROWER DEPOSITS 10 SOL TO SOL2 RESERVE AND LOCKS COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_sol2_pubkey).0;
+    let dest_borrower_lp_wallet_sol2 =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        &borrower_keypair,
+        borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_sol2,
+        10 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_sol2_pubkey,
+        sol_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_sol2,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("lock_collateral");
+
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    let borrow_amount = 2 * LAMPORTS_PER_SOL;
+
+    info!("SOL1 reserve doesn't offer fixed rate loans yet");
+    let result = borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        borrow_amount,
+        7 * DAY_SEC,
+        1000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // SOL1 RESERVE OFFERS 10% APR FOR UP TO 30 DAYS WITH 1 DAY GRACE PERIOD
+
+    alter_fixed_rate(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1000,
+        30 * DAY_SEC,
+        DAY_SEC,
+    )
+    .await
+    .expect("alter_fixed_rate");
+
+    info!("Position has no room for fixed loans");
+    let result = borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        borrow_amount,
+        7 * DAY_SEC,
+        1000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    resize_position(&mut ctx, position_pubkey, &borrower_keypair, 0, 0, 0, 1)
+        .await
+        .expect("resize_position");
+
+    info!("Term exceeds reserve's max term");
+    let result = borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        borrow_amount,
+        31 * DAY_SEC,
+        1000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    info!("Reserve's rate exceeds borrower's max rate");
+    let result = borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        borrow_amount,
+        7 * DAY_SEC,
+        999,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // BORROW 2 SOL FOR 7 DAYS
+
+    borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        borrow_amount,
+        7 * DAY_SEC,
+        1000,
+    )
+    .await
+    .expect("borrow_fixed");
+
+    info!("Only one fixed loan per reserve");
+    let result = borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        7 * DAY_SEC,
+        1000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    let loan = position.fixed_loans[0];
+    assert!(loan.is_active());
+    assert_eq!(loan.rate_bps, 1000);
+    assert_eq!(loan.liquidity.borrow_reserve, reserve_sol1_pubkey);
+    assert_eq!(
+        loan.maturity_timestamp - loan.origination_timestamp,
+        (7 * DAY_SEC) as i64
+    );
+    assert_eq!(
+        loan.liquidity.borrowed_amount().unwrap(),
+        Decimal::from_lamports(borrow_amount, 9).unwrap()
+    );
+    // Fixed loan doesn't create variable rate borrowing
+    assert!(position
+        .borrows()
+        .all(|borrow| borrow.borrowed_amount().unwrap() == Decimal::ZERO));
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve.liquidity.fixed_borrowed_amount().unwrap(),
+        Decimal::from_lamports(borrow_amount, 9).unwrap()
+    );
+    assert_eq!(reserve.liquidity.borrowed_amount().unwrap(), Decimal::ZERO);
+
+    // REPAY HALF OF THE LOAN
+
+    repay_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        &borrower_keypair,
+        borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("repay_fixed");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    let loan = position.fixed_loans[0];
+    assert!(loan.is_active());
+    assert!(
+        loan.liquidity.borrowed_amount().unwrap()
+            >= Decimal::from_lamports(LAMPORTS_PER_SOL, 9).unwrap()
+    );
+
+    // HEALTHY POSITION WITH NOT YET OVERDUE LOAN CAN NOT BE LIQUIDATED
+
+    let lp_mint = find_lp_token_mint(&reserve_sol2_pubkey).0;
+    let dest_lender_lp_wallet_sol2 =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+
+    let result = liquidate(
+        &mut ctx,
+        lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol2,
+        reserve_sol1_pubkey,
+        reserve_sol2_pubkey,
+        position_pubkey,
+        &lender_keypair,
+        MAX_AMOUNT,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // GRACE PERIOD IS NOT OVER YET
+
+    warp_seconds(&mut ctx, (7 * DAY_SEC + DAY_SEC / 2) as u64).await;
+
+    let result = liquidate(
+        &mut ctx,
+        lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol2,
+        reserve_sol1_pubkey,
+        reserve_sol2_pubkey,
+        position_pubkey,
+        &lender_keypair,
+        MAX_AMOUNT,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // LOAN IS OVERDUE - WHOLE DEBT IS LIQUIDATED REGARDLESS OF POSITION HEALTH
+
+    warp_seconds(&mut ctx, DAY_SEC as u64).await;
+
+    liquidate(
+        &mut ctx,
+        lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol2,
+        reserve_sol1_pubkey,
+        reserve_sol2_pubkey,
+        position_pubkey,
+        &lender_keypair,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("liquidate");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    assert!(!position.fixed_loans[0].is_active());
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve.liquidity.fixed_borrowed_amount().unwrap(),
+        Decimal::ZERO
+    );
+}
+
+#[tokio::test]
+async fn write_off_fixed_loan_bad_debt() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER LIQUIDATION PARAMS
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.max_borrow_ltv_bps = 6000; // 60%
+    params.partly_unhealthy_ltv_bps = 7000; // 70%
+    params.fully_unhealthy_ltv_bps = 8000; // 80%
+    params.liquidation_bonus_bps = 2000; // 20%
+    params.partial_liquidation_factor_bps = 2000; // 20%
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit initial liquidity");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        1_000 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 10_000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 10_000 * LAMPORTS_PER_USDC;
+
+    info!("deposit 10_000 USDC");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    // BORROW 40 SOL WITH FIXED RATE
+
+    alter_fixed_rate(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1000,
+        30 * DAY_SEC,
+        DAY_SEC,
+    )
+    .await
+    .expect("alter_fixed_rate");
+
+    resize_position(&mut ctx, position_pubkey, &borrower_keypair, 0, 0, 0, 1)
+        .await
+        .expect("resize_position");
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow 40 SOL with fixed rate");
+    borrow_fixed(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        40 * LAMPORTS_PER_SOL,
+        7 * DAY_SEC,
+        1000,
+    )
+    .await
+    .expect("borrow_fixed");
+
+    // RAISE SOL PRICE FROM 100 TO 250 USD. BORROWED VALUE EXCEEDS COLLATERAL.
+
+    info!("raise SOL price from 100 to 250");
+    let now = Utc::now().timestamp();
+    write_price(
+        &mut ctx,
+        sol_price_feed,
+        &price_feed_authority(),
+        Decimal::from_i128_with_scale(250, 0).unwrap(),
+        now - 3,
+    )
+    .await
+    .expect("update sol price feed");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    info!("liquidate full deposited amount");
+    liquidate(
+        &mut ctx,
+        dest_borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_usdc,
+        reserve_sol1_pubkey,
+        reserve_usdc_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("liquidate");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    assert!(!position.have_any_deposits());
+    let (loan, _) = position.find_fixed_loan(reserve_sol1_pubkey).unwrap();
+    assert!(loan.liquidity.borrowed_amount().unwrap() > Decimal::ZERO);
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve0 = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert!(reserve0.liquidity.fixed_borrowed_amount().unwrap() > Decimal::ZERO);
+
+    // FIXED-RATE LOAN OF POSITION WITHOUT COLLATERAL IS WRITTEN OFF
+
+    info!("write off bad debt");
+    write_off_bad_debt(
+        &mut ctx,
+        position_pubkey,
+        pool_pubkey,
+        curator_pubkey,
+        &pool_authority_keypair,
+        reserve_sol1_pubkey,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("write_off_bad_debt");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    assert!(!position.fixed_loans[0].is_active());
+    assert!(!position.have_any_borrowings());
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve1 = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // Loan no longer accrues interest for LPs
+    assert_eq!(
+        reserve1.liquidity.fixed_borrowed_amount().unwrap(),
+        Decimal::ZERO
+    );
+    assert_eq!(
+        reserve1.liquidity.fixed_interest_per_year().unwrap(),
+        Decimal::ZERO
+    );
+    assert_eq!(
+        reserve1.liquidity.available_amount,
+        reserve0.liquidity.available_amount
+    );
+}
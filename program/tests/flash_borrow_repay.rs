@@ -21,7 +21,7 @@ use super_lendy::pda::{find_liquidity_supply, find_lp_token_mint};
 use super_lendy::state::position::Position;
 use super_lendy::state::reserve::{Reserve, ReserveFeesConfig, RESERVE_MODE_NORMAL};
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
-use super_lendy::state::SLOTS_PER_YEAR;
+use super_lendy::state::SECONDS_PER_YEAR;
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
@@ -31,7 +31,7 @@ use crate::utils::superlendy_executor::{
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
     create_associated_token_account, get_account, get_token_account, init_program_test,
-    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
+    init_token_accounts, lender_keypair, texture_config_keypair, warp_seconds, Runner, LAMPORTS,
     LAMPORTS_PER_USDC,
 };
 
@@ -218,6 +218,278 @@ async fn flash_success() {
         .expect("sending tx");
 }
 
+#[tokio::test]
+async fn flash_fee_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+
+    let curator_keypair = Keypair::new();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER texture_config.flash_loan_fee_share to 20%
+
+    let params = TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 2000,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
+        fees_authority: texture_owner_keypair.pubkey(),
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    };
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // Set reserve_usdc flash loan fee to 0.09% and curator performance fee to 10%
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 1000,
+        flash_loan_fee_bps: 9,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_keypair.pubkey(),
+        &pool_authority_keypair,
+        curator_keypair.pubkey(),
+        params,
+        RESERVE_MODE_NORMAL,
+    )
+    .await
+    .expect("alter_reserve");
+
+    enable_flash_loans(
+        &mut ctx,
+        vec![reserve_usdc_pubkey],
+        pool_keypair.pubkey(),
+        curator_keypair.pubkey(),
+        &pool_authority_keypair,
+    )
+    .await
+    .expect("enable_flash_loans");
+
+    // DEPOSIT INITIAL LIQUIDITY TO USDC RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+
+    info!("deposit usdc initial liquidity");
+    let reserve_liquidity = 500 * LAMPORTS_PER_USDC;
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        borrower_liq_wallet_usdc,
+        borrower_lp_wallet_usdc,
+        reserve_liquidity,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve_before = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let lp_rate_before = reserve_before.lp_exchange_rate().expect("lp_exchange_rate");
+
+    let liquidity_supply = find_liquidity_supply(&reserve_usdc_pubkey).0;
+    let supply_before = get_token_account(&mut ctx.banks_client, liquidity_supply)
+        .await
+        .expect("get supply")
+        .amount;
+    let borrower_before = get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+        .await
+        .expect("get borrower wallet")
+        .amount;
+
+    // FLASH BORROW 100 USDC AND REPAY IT WITH FEE
+
+    let flash_amount = 100 * LAMPORTS_PER_USDC;
+    // 0.09% of 100 USDC
+    let expected_fee = 90_000;
+
+    let lender_liq_wallet_usdc = get_associated_token_address(&lender_pubkey, &liquidity_usdc_mint);
+    let ixs = vec![
+        RefreshReserve {
+            reserve: reserve_usdc_pubkey,
+            market_price_feed: usdc_price_feed,
+            irm,
+        }
+        .into_instruction(),
+        FlashBorrow {
+            reserve: reserve_usdc_pubkey,
+            destination_wallet: lender_liq_wallet_usdc,
+            liquidity_mint: liquidity_usdc_mint,
+            sysvar_instructions: solana_program::sysvar::instructions::id(),
+            token_program: spl_token::id(),
+            amount: flash_amount,
+        }
+        .into_instruction(),
+        FlashRepay {
+            reserve: reserve_usdc_pubkey,
+            source_wallet: borrower_liq_wallet_usdc,
+            sysvar_instructions: solana_program::sysvar::instructions::id(),
+            amount: flash_amount,
+            user_transfer_authority: borrower_pubkey,
+            token_program: spl_token::id(),
+            liquidity_mint: liquidity_usdc_mint,
+        }
+        .into_instruction(),
+    ];
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &borrower_keypair],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("sending tx");
+
+    let supply_after = get_token_account(&mut ctx.banks_client, liquidity_supply)
+        .await
+        .expect("get supply")
+        .amount;
+    let borrower_after = get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+        .await
+        .expect("get borrower wallet")
+        .amount;
+
+    assert_eq!(supply_after, supply_before + expected_fee);
+    assert_eq!(
+        borrower_before - borrower_after,
+        flash_amount + expected_fee
+    );
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve_after = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    assert_eq!(
+        reserve_after.liquidity.available_amount,
+        reserve_before.liquidity.available_amount + expected_fee
+    );
+
+    // Curator gets 10% of the fee and Texture gets 20%
+    let curator_fee = reserve_after
+        .liquidity
+        .curator_performance_fee()
+        .unwrap()
+        .checked_sub(reserve_before.liquidity.curator_performance_fee().unwrap())
+        .unwrap();
+    assert_eq!(curator_fee, Decimal::from_lamports(9_000, 6).unwrap());
+
+    let texture_fee = reserve_after
+        .liquidity
+        .texture_performance_fee()
+        .unwrap()
+        .checked_sub(reserve_before.liquidity.texture_performance_fee().unwrap())
+        .unwrap();
+    assert_eq!(texture_fee, Decimal::from_lamports(18_000, 6).unwrap());
+
+    // LPs earned the rest
+    let lp_rate_after = reserve_after.lp_exchange_rate().expect("lp_exchange_rate");
+    assert!(
+        lp_rate_after.lp_to_liquidity(reserve_liquidity).unwrap()
+            > lp_rate_before.lp_to_liquidity(reserve_liquidity).unwrap()
+    );
+}
+
 /// See test description in
 /// https://www.notion.so/Super-Lendy-3fc6f2d034dc4ff194c69d6f549217f8?pvs=4
 #[tokio::test]
@@ -292,6 +564,9 @@ async fn flash_withdraw_limit_success() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -305,7 +580,8 @@ async fn flash_withdraw_limit_success() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
@@ -324,6 +600,9 @@ async fn flash_withdraw_limit_success() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
 
@@ -659,6 +938,9 @@ async fn flash_not_affect_interest() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -672,7 +954,8 @@ async fn flash_not_affect_interest() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
@@ -691,6 +974,9 @@ async fn flash_not_affect_interest() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
 
@@ -848,11 +1134,10 @@ async fn flash_not_affect_interest() {
     .await
     .expect("borrow");
 
-    // 1 SOLANA YEAR LATER
+    // 1 YEAR LATER
 
-    let slot = SLOTS_PER_YEAR;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot"); // solana_year = 63072000 slots
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
 
     refresh_reserve(&mut ctx, reserve_usdc_pubkey, usdc_price_feed, irm)
         .await
@@ -866,7 +1151,7 @@ async fn flash_not_affect_interest() {
 
     assert_eq!(
         cumulative_borrow_rate0,
-        Decimal::from_i128_with_scale(1491824686287962198, 18).unwrap() // 1.4918
+        Decimal::from_i128_with_scale(1491824684395744264, 18).unwrap() // 1.4918
     );
 
     // TRANSFER 800 USDC FROM WALLET1 TO WALLET2 BY FLASH LOAN
@@ -2453,6 +2738,9 @@ async fn withdraw_inside_flash() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
 
@@ -3178,6 +3466,9 @@ async fn borrow_inside_flash() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -3191,7 +3482,8 @@ async fn borrow_inside_flash() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
@@ -3212,6 +3504,9 @@ async fn borrow_inside_flash() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
 
@@ -3874,6 +4169,9 @@ async fn repay_inside_flash() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 0,
         performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -3887,7 +4185,8 @@ async fn repay_inside_flash() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     alter_texture_config(&mut ctx, &texture_owner_keypair, params)
@@ -3906,6 +4205,9 @@ async fn repay_inside_flash() {
     params.fees = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 0,
         curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
 
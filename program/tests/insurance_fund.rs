@@ -0,0 +1,642 @@
+#![cfg(feature = "test-bpf")]
+
+use std::str::FromStr;
+
+use bytemuck::Zeroable;
+use chrono::Utc;
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+use tracing::info;
+
+use super_lendy::pda::{find_insurance_fund, find_lp_token_mint};
+use super_lendy::state::position::Position;
+use super_lendy::state::reserve::{Reserve, ReserveFeesConfig};
+use super_lendy::state::SECONDS_PER_YEAR;
+use super_lendy::MAX_AMOUNT;
+
+use crate::utils::setup_super_lendy::setup_lendy_env;
+use crate::utils::superlendy_executor::{
+    alter_reserve, borrow, claim_insurance_fees, deposit_liquidity, init_insurance_fund, liquidate,
+    lock_collateral, refresh_position, write_off_bad_debt, write_price,
+};
+use crate::utils::{
+    add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
+    create_associated_token_account, get_account, get_token_account, init_program_test,
+    init_token_accounts, lender_keypair, price_feed_authority, texture_config_keypair,
+    warp_seconds, Runner, LAMPORTS, LAMPORTS_PER_USDC,
+};
+
+pub mod utils;
+
+#[tokio::test]
+async fn claim_insurance_fees_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // 10% OF SOL1 RESERVE YIELD GOES TO INSURANCE FUND
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees.insurance_fee_rate_bps = 1000;
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit initial liquidity");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        1_000 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 1000 * LAMPORTS_PER_USDC;
+
+    info!("deposit {} into USDC reserve", deposit_usdc_amount);
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("lock {} collateral lp", deposit_usdc_amount);
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    // BORROW 1 SOL
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow 1 SOL");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // 1 YEAR LATER
+
+    info!("warp {} seconds", SECONDS_PER_YEAR);
+    warp_seconds(&mut ctx, SECONDS_PER_YEAR).await;
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve0 = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert!(reserve0.liquidity.insurance_fee().unwrap() > Decimal::from_lamports(1, 9).unwrap());
+
+    // INSURANCE FUND MUST BE INITIALIZED BEFORE CLAIM
+
+    info!("try to claim insurance fees without insurance fund");
+    let result = claim_insurance_fees(&mut ctx, reserve_sol1_pubkey, pool_pubkey).await;
+    assert!(result.is_err());
+
+    info!("init insurance fund");
+    init_insurance_fund(
+        &mut ctx,
+        liquidity_sol_mint,
+        pool_pubkey,
+        curator_pubkey,
+        &pool_authority_keypair,
+    )
+    .await
+    .expect("init_insurance_fund");
+
+    info!("claim insurance fees");
+    claim_insurance_fees(&mut ctx, reserve_sol1_pubkey, pool_pubkey)
+        .await
+        .expect("claim_insurance_fees");
+
+    let insurance_fund = find_insurance_fund(&pool_pubkey, &liquidity_sol_mint).0;
+    let insurance_fund_acc = get_token_account(&mut ctx.banks_client, insurance_fund)
+        .await
+        .expect("get token acc");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve1 = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // Whole lamports of accrued fee are moved out of Reserve's liquidity to the fund
+    assert!(insurance_fund_acc.amount > 0);
+    assert_eq!(
+        reserve1.liquidity.available_amount,
+        reserve0.liquidity.available_amount - insurance_fund_acc.amount
+    );
+    assert!(reserve1.liquidity.insurance_fee().unwrap() < Decimal::from_lamports(1, 9).unwrap());
+
+    // Accrued fee was never LPs' liquidity thus claim doesn't change LP exchange rate
+    assert_eq!(
+        reserve1.lp_exchange_rate().unwrap().0,
+        reserve0.lp_exchange_rate().unwrap().0
+    );
+
+    // Nothing to claim right after claim
+    let result = claim_insurance_fees(&mut ctx, reserve_sol1_pubkey, pool_pubkey).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn write_off_bad_debt_covered_by_insurance_fund() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ALTER LIQUIDATION PARAMS
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.max_borrow_ltv_bps = 6000; // 60%
+    params.partly_unhealthy_ltv_bps = 7000; // 70%
+    params.fully_unhealthy_ltv_bps = 8000; // 80%
+    params.liquidation_bonus_bps = 2000; // 20%
+    params.partial_liquidation_factor_bps = 2000; // 20%
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        0,
+    )
+    .await
+    .expect("alter_reserve");
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    info!("deposit initial liquidity");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        1_000 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 10_000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 10_000 * LAMPORTS_PER_USDC;
+
+    info!("deposit 10_000 USDC");
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        dest_borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    // BORROW 40 SOL
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    info!("borrow 40 SOL");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        40 * LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // RAISE SOL PRICE FROM 100 TO 250 USD. BORROWED VALUE EXCEEDS COLLATERAL.
+
+    info!("raise SOL price from 100 to 250");
+    let now = Utc::now().timestamp();
+    write_price(
+        &mut ctx,
+        sol_price_feed,
+        &price_feed_authority(),
+        Decimal::from_i128_with_scale(250, 0).unwrap(),
+        now - 3,
+    )
+    .await
+    .expect("update sol price feed");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    info!("liquidate full deposited amount");
+    liquidate(
+        &mut ctx,
+        dest_borrower_liq_wallet_sol,
+        dest_borrower_lp_wallet_usdc,
+        reserve_sol1_pubkey,
+        reserve_usdc_pubkey,
+        position_pubkey,
+        &borrower_keypair,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("liquidate");
+
+    info!("refresh position");
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let bad_debt = position
+        .find_borrowed_liquidity(reserve_sol1_pubkey)
+        .unwrap()
+        .0
+        .borrowed_amount()
+        .unwrap()
+        .to_lamports_ceil(9)
+        .unwrap();
+    let insurance_amount = 10 * LAMPORTS_PER_SOL;
+    assert!(bad_debt > insurance_amount);
+
+    // FUND INSURANCE WITH 10 SOL
+
+    info!("init insurance fund");
+    init_insurance_fund(
+        &mut ctx,
+        liquidity_sol_mint,
+        pool_pubkey,
+        curator_pubkey,
+        &pool_authority_keypair,
+    )
+    .await
+    .expect("init_insurance_fund");
+
+    let insurance_fund = find_insurance_fund(&pool_pubkey, &liquidity_sol_mint).0;
+    let blockhash = ctx
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[spl_token::instruction::transfer(
+            &spl_token::ID,
+            &source_lender_liq_wallet_sol,
+            &insurance_fund,
+            &lender_pubkey,
+            &[],
+            insurance_amount,
+        )
+        .expect("transfer ix")],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &lender_keypair],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("fund insurance");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve0 = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // WRITE OFF BAD DEBT
+
+    info!("write off bad debt");
+    write_off_bad_debt(
+        &mut ctx,
+        position_pubkey,
+        pool_pubkey,
+        curator_pubkey,
+        &pool_authority_keypair,
+        reserve_sol1_pubkey,
+        MAX_AMOUNT,
+    )
+    .await
+    .expect("write_off_bad_debt");
+
+    let insurance_fund_acc = get_token_account(&mut ctx.banks_client, insurance_fund)
+        .await
+        .expect("get token acc");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve1 = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // Whole insurance fund is spent. Only the rest of bad debt is socialized on LPs.
+    assert_eq!(insurance_fund_acc.amount, 0);
+    assert_eq!(
+        reserve1.liquidity.available_amount,
+        reserve0.liquidity.available_amount + insurance_amount
+    );
+    assert_eq!(
+        reserve1
+            .liquidity
+            .total_liquidity()
+            .unwrap()
+            .to_lamports_round(9)
+            .unwrap(),
+        reserve0
+            .liquidity
+            .total_liquidity()
+            .unwrap()
+            .to_lamports_round(9)
+            .unwrap()
+            - (bad_debt - insurance_amount)
+    );
+}
@@ -17,7 +17,9 @@ use tracing::info;
 use super_lendy::pda::find_lp_token_mint;
 use super_lendy::state::curator::CuratorParams;
 use super_lendy::state::pool::PoolParams;
-use super_lendy::state::reserve::{ReserveConfig, ReserveFeesConfig, RESERVE_TYPE_NORMAL};
+use super_lendy::state::reserve::{
+    ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE, RESERVE_TYPE_NORMAL,
+};
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
 
 use crate::utils::superlendy_executor::{
@@ -93,6 +95,9 @@ pub async fn max_sum_success(
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -106,7 +111,8 @@ pub async fn max_sum_success(
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -140,6 +146,7 @@ pub async fn max_sum_success(
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -157,6 +164,9 @@ pub async fn max_sum_success(
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let mut config = ReserveConfig {
@@ -167,12 +177,18 @@ pub async fn max_sum_success(
         partly_unhealthy_ltv_bps: 9500,
         fully_unhealthy_ltv_bps: 9700,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 10_000_000_000 * LAMPORTS_PER_SOL,
         max_borrow_utilization_bps: 9900,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 10000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     create_reserve(
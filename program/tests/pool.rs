@@ -2,6 +2,7 @@
 
 use bytemuck::Zeroable;
 use price_proxy::state::utils::str_to_array;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use texture_common::account::PodAccount;
@@ -50,6 +51,9 @@ async fn create_alter_success() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -90,6 +94,7 @@ async fn create_alter_success() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -105,6 +110,10 @@ async fn create_alter_success() {
     macro_rules! validate {
         ($this:ident, $params:ident) => {
             assert_eq!($this.visible, $params.visible);
+            assert_eq!(
+                $this.auto_write_off_bad_debt,
+                $params.auto_write_off_bad_debt
+            );
             assert_eq!($this.name, $params.name);
             assert_eq!($this.curator, curator_pubkey);
         };
@@ -118,7 +127,20 @@ async fn create_alter_success() {
 
     // ALTER POOL
 
+    info!("alter pool with invalid auto_write_off_bad_debt");
+    params.auto_write_off_bad_debt = 2;
+    let result = alter_pool(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+    )
+    .await;
+    assert!(result.is_err());
+
     params.visible = 1;
+    params.auto_write_off_bad_debt = 1;
     params.name = [2; 128];
 
     info!("alter pool");
@@ -168,6 +190,9 @@ async fn create_incorrect_pools_authority() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -208,6 +233,7 @@ async fn create_incorrect_pools_authority() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     let result = create_pool(
@@ -251,6 +277,9 @@ async fn alter_incorrect_pools_authority() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -291,6 +320,7 @@ async fn alter_incorrect_pools_authority() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -307,6 +337,7 @@ async fn alter_incorrect_pools_authority() {
 
     info!("alter pool with incorrect authority");
     params.visible = 1;
+    params.auto_write_off_bad_debt = 1;
     params.name = [2; 128];
 
     let result = alter_pool(
@@ -0,0 +1,945 @@
+#![cfg(feature = "test-bpf")]
+
+use std::str::FromStr;
+
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+use texture_common::account::PodAccount;
+use tracing::info;
+
+use super_lendy::pda::{find_lp_token_mint, find_position};
+use super_lendy::state::position::{
+    Position, DELEGATE_PERMISSIONS_ALL, DELEGATE_PERMISSION_BORROW, DELEGATE_PERMISSION_UNLOCK,
+    MAX_BORROWS, MAX_DEPOSITS, MAX_REWARDS, POSITION_TYPE_CLASSIC, POSITION_TYPE_LONG_SHORT,
+    POSITION_TYPE_LST_LEVERAGE,
+};
+use super_lendy::SUPER_LENDY_ID;
+
+use crate::utils::setup_super_lendy::setup_lendy_env;
+use crate::utils::superlendy_executor::{
+    accept_position_ownership, borrow, close_position, create_position_pda, deposit_liquidity,
+    lock_collateral, refresh_position, resize_position, set_position_delegate,
+    transfer_position_ownership, unlock_collateral,
+};
+use crate::utils::{
+    add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
+    create_associated_token_account, get_account, get_token_account, init_program_test,
+    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
+    LAMPORTS_PER_USDC,
+};
+
+pub mod utils;
+
+#[tokio::test]
+async fn create_position_pda_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    let position0 = find_position(&pool_pubkey, &borrower_pubkey, 0).0;
+    let position1 = find_position(&pool_pubkey, &borrower_pubkey, 1).0;
+
+    create_position_pda(
+        &mut ctx,
+        position0,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        0,
+    )
+    .await
+    .expect("create_position_pda 0");
+
+    create_position_pda(
+        &mut ctx,
+        position1,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        1,
+    )
+    .await
+    .expect("create_position_pda 1");
+
+    for position_pubkey in [position0, position1] {
+        let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+            .await
+            .expect("get position");
+        let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+        assert_eq!(position.owner, borrower_pubkey);
+        assert_eq!(position.pool, pool_pubkey);
+        assert_eq!(position_acc.data.len(), Position::SIZE);
+    }
+
+    // Same index can not be used twice
+    create_position_pda(
+        &mut ctx,
+        position0,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_LST_LEVERAGE,
+        0,
+    )
+    .await
+    .expect_err("create_position_pda with used index");
+
+    // Address must match the index
+    create_position_pda(
+        &mut ctx,
+        position1,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        2,
+    )
+    .await
+    .expect_err("create_position_pda with wrong address");
+
+    // Only owner can pay for its PDA Position
+    let another_position = find_position(&pool_pubkey, &pool_authority_pubkey, 0).0;
+    create_position_pda(
+        &mut ctx,
+        another_position,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        0,
+    )
+    .await
+    .expect_err("create_position_pda for other owner");
+
+    // Closed Position index can be reused
+    close_position(&mut ctx, position0, &borrower_keypair)
+        .await
+        .expect("close_position");
+
+    create_position_pda(
+        &mut ctx,
+        position0,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_LONG_SHORT,
+        0,
+    )
+    .await
+    .expect("create_position_pda 0 again");
+
+    let position_acc = get_account(&mut ctx.banks_client, position0)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert_eq!(position.position_type, POSITION_TYPE_LONG_SHORT);
+
+    // Pre-funded PDA address must not block Position creation
+    let position2 = find_position(&pool_pubkey, &borrower_pubkey, 2).0;
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &ctx.payer.pubkey(),
+            &position2,
+            LAMPORTS_PER_SOL / 100,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("pre-fund position address");
+
+    create_position_pda(
+        &mut ctx,
+        position2,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        2,
+    )
+    .await
+    .expect("create_position_pda 2 pre-funded");
+
+    let position_acc = get_account(&mut ctx.banks_client, position2)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert_eq!(position.owner, borrower_pubkey);
+    assert_eq!(position_acc.data.len(), Position::SIZE);
+    assert_eq!(position_acc.owner, SUPER_LENDY_ID);
+}
+
+#[tokio::test]
+async fn resize_position_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    let position_pubkey = find_position(&pool_pubkey, &borrower_pubkey, 0).0;
+    create_position_pda(
+        &mut ctx,
+        position_pubkey,
+        pool_pubkey,
+        &borrower_keypair,
+        POSITION_TYPE_CLASSIC,
+        0,
+    )
+    .await
+    .expect("create_position_pda");
+
+    let rent = ctx.banks_client.get_rent().await.expect("get rent");
+
+    // Only owner can resize the Position
+    resize_position(
+        &mut ctx,
+        position_pubkey,
+        &pool_authority_keypair,
+        1,
+        2,
+        3,
+        4,
+    )
+    .await
+    .expect_err("resize_position by not an owner");
+
+    resize_position(&mut ctx, position_pubkey, &borrower_keypair, 1, 2, 3, 4)
+        .await
+        .expect("resize_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    assert_eq!(position_acc.data.len(), Position::account_size(1, 2, 3, 4));
+    assert_eq!(
+        position_acc.lamports,
+        rent.minimum_balance(Position::account_size(1, 2, 3, 4))
+    );
+
+    let position = Position::unpack(&position_acc.data).expect("unpack position");
+    assert_eq!(position.max_deposits(), MAX_DEPOSITS + 1);
+    assert_eq!(position.max_borrows(), MAX_BORROWS + 2);
+    assert_eq!(position.max_rewards(), MAX_REWARDS + 3);
+    assert_eq!(position.collateral().count(), MAX_DEPOSITS + 1);
+    assert_eq!(position.fixed_loans.len(), 4);
+
+    // Extended Position is processed as usual
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    // Account can not grow too much at once
+    resize_position(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        u8::MAX,
+        u8::MAX,
+        u8::MAX,
+        u8::MAX,
+    )
+    .await
+    .expect_err("resize_position above realloc limit");
+
+    // Shrinking returns everything above rent exempt balance of the new size to the owner, including
+    // lamports sent to the Position by someone else
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &ctx.payer.pubkey(),
+            &position_pubkey,
+            LAMPORTS_PER_SOL / 100,
+        )],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("fund position");
+
+    let position_lamports_before = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position")
+        .lamports;
+    let owner_balance_before = get_account(&mut ctx.banks_client, borrower_pubkey)
+        .await
+        .expect("get owner")
+        .lamports;
+
+    resize_position(&mut ctx, position_pubkey, &borrower_keypair, 0, 0, 0, 0)
+        .await
+        .expect("resize_position back");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    assert_eq!(position_acc.data.len(), Position::SIZE);
+    assert_eq!(position_acc.lamports, rent.minimum_balance(Position::SIZE));
+
+    let owner_balance_after = get_account(&mut ctx.banks_client, borrower_pubkey)
+        .await
+        .expect("get owner")
+        .lamports;
+    assert_eq!(
+        owner_balance_after - owner_balance_before,
+        position_lamports_before - rent.minimum_balance(Position::SIZE)
+    );
+    assert_eq!(
+        position_lamports_before,
+        rent.minimum_balance(Position::account_size(1, 2, 3, 4)) + LAMPORTS_PER_SOL / 100
+    );
+
+    close_position(&mut ctx, position_pubkey, &borrower_keypair)
+        .await
+        .expect("close_position");
+}
+
+#[tokio::test]
+async fn position_delegate_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+    let delegate_keypair = Keypair::new();
+    let delegate_pubkey = delegate_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+    runner.add_native_wallet(delegate_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let dest_lender_lp_wallet_sol =
+        create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_lender_liq_wallet_sol =
+        get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        source_lender_liq_wallet_sol,
+        dest_lender_lp_wallet_sol,
+        1_000 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 1000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let delegate_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &delegate_keypair, &lp_mint)
+            .await
+            .expect("create delegate lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 1000 * LAMPORTS_PER_USDC;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // SET DELEGATE
+
+    let delegate_permissions = DELEGATE_PERMISSION_BORROW | DELEGATE_PERMISSION_UNLOCK;
+
+    info!("only owner can set delegates");
+    let result = set_position_delegate(
+        &mut ctx,
+        position_pubkey,
+        &delegate_keypair,
+        delegate_pubkey,
+        delegate_permissions,
+        30_000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    info!("unknown permissions are rejected");
+    let result = set_position_delegate(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        delegate_pubkey,
+        DELEGATE_PERMISSIONS_ALL + 1,
+        30_000,
+    )
+    .await;
+    assert!(result.is_err());
+
+    set_position_delegate(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        delegate_pubkey,
+        delegate_permissions,
+        30_000,
+    )
+    .await
+    .expect("set_position_delegate");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let delegate = position.find_delegate(&delegate_pubkey).unwrap();
+    assert_eq!(delegate.permissions, delegate_permissions);
+    assert_eq!(delegate.borrow_allowance_cents(), 30_000);
+
+    // DELEGATE BORROWS WITHIN ITS ALLOWANCE
+
+    let dest_delegate_liq_wallet_sol =
+        create_associated_token_account(&mut ctx, &delegate_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create delegate sol ata");
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    let dest_borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+
+    info!("delegate borrows to its own wallet");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &delegate_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_delegate_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // 1 SOL results in 131 USD borrowed value (including fees)
+    info!("delegate borrows within the allowance");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &delegate_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow by delegate");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let delegate = position.find_delegate(&delegate_pubkey).unwrap();
+    assert!(delegate.borrow_allowance_cents() < 30_000);
+    let remaining_allowance = delegate.borrow_allowance_cents();
+
+    info!("delegate borrows above the allowance");
+    let result = borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &delegate_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        2 * LAMPORTS_PER_SOL,
+        1,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // Owner's borrows don't consume delegate's allowance
+    info!("owner borrows above delegate's allowance");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        2 * LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow by owner");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let delegate = position.find_delegate(&delegate_pubkey).unwrap();
+    assert_eq!(delegate.borrow_allowance_cents(), remaining_allowance);
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("delegate borrows rest of the allowance");
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &delegate_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        dest_borrower_liq_wallet_sol,
+        LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("second borrow by delegate");
+
+    // DELEGATE UNLOCKS TO OWNER'S WALLET ONLY
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("delegate unlocks to its own wallet");
+    let result = unlock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &delegate_keypair,
+        delegate_lp_wallet_usdc,
+        10 * LAMPORTS_PER_USDC,
+    )
+    .await;
+    assert!(result.is_err());
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("delegate unlocks to owner's wallet");
+    unlock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &delegate_keypair,
+        borrower_lp_wallet_usdc,
+        10 * LAMPORTS_PER_USDC,
+    )
+    .await
+    .expect("unlock_collateral by delegate");
+
+    let borrower_lp_wallet = get_token_account(&mut ctx.banks_client, borrower_lp_wallet_usdc)
+        .await
+        .expect("get borrower lp wallet");
+    assert_eq!(borrower_lp_wallet.amount, 10 * LAMPORTS_PER_USDC);
+
+    // DELEGATE WITHOUT LOCK PERMISSION
+
+    info!("delegate locks collateral without permission");
+    let result = lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &delegate_keypair,
+        delegate_lp_wallet_usdc,
+        LAMPORTS_PER_USDC,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // REMOVE DELEGATE
+
+    set_position_delegate(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        delegate_pubkey,
+        0,
+        0,
+    )
+    .await
+    .expect("remove delegate");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert!(position.find_delegate(&delegate_pubkey).is_none());
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    info!("removed delegate unlocks collateral");
+    let result = unlock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &delegate_keypair,
+        borrower_lp_wallet_usdc,
+        10 * LAMPORTS_PER_USDC,
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn transfer_position_ownership_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+    let new_owner_keypair = Keypair::new();
+    let new_owner_pubkey = new_owner_keypair.pubkey();
+    let stranger_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+
+    let curator_keypair = Keypair::new();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+    runner.add_native_wallet(new_owner_pubkey, LAMPORTS);
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    set_position_delegate(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        stranger_keypair.pubkey(),
+        DELEGATE_PERMISSIONS_ALL,
+        0,
+    )
+    .await
+    .expect("set_position_delegate");
+
+    info!("accept without transfer");
+    let result = accept_position_ownership(&mut ctx, position_pubkey, &new_owner_keypair).await;
+    assert!(result.is_err());
+
+    info!("transfer by non-owner");
+    let result = transfer_position_ownership(
+        &mut ctx,
+        position_pubkey,
+        &stranger_keypair,
+        new_owner_pubkey,
+    )
+    .await;
+    assert!(result.is_err());
+
+    transfer_position_ownership(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        new_owner_pubkey,
+    )
+    .await
+    .expect("transfer_position_ownership");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert_eq!(position.owner, borrower_pubkey);
+    assert_eq!(position.pending_owner, new_owner_pubkey);
+
+    info!("accept by stranger");
+    let result = accept_position_ownership(&mut ctx, position_pubkey, &stranger_keypair).await;
+    assert!(result.is_err());
+
+    accept_position_ownership(&mut ctx, position_pubkey, &new_owner_keypair)
+        .await
+        .expect("accept_position_ownership");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    assert_eq!(position.owner, new_owner_pubkey);
+    assert_eq!(position.pending_owner, Pubkey::default());
+    // Delegates of the previous owner are removed
+    assert!(position.find_delegate(&stranger_keypair.pubkey()).is_none());
+
+    info!("previous owner has no control over the position");
+    let result = transfer_position_ownership(
+        &mut ctx,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_pubkey,
+    )
+    .await;
+    assert!(result.is_err());
+
+    close_position(&mut ctx, position_pubkey, &new_owner_keypair)
+        .await
+        .expect("close_position");
+}
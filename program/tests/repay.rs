@@ -26,7 +26,7 @@ use crate::utils::superlendy_executor::{
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
     create_associated_token_account, get_account, init_program_test, init_token_accounts,
-    lender_keypair, texture_config_keypair, Runner, LAMPORTS, LAMPORTS_PER_USDC,
+    lender_keypair, texture_config_keypair, warp_seconds, Runner, LAMPORTS, LAMPORTS_PER_USDC,
 };
 
 pub mod utils;
@@ -226,11 +226,10 @@ async fn repay_success() {
         .await
         .expect("refresh_position");
 
-    // 10 SLOT LATER
+    // 10 SECONDS LATER
 
-    let mut slot = 10_u64;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
+    info!("warp 10 seconds");
+    warp_seconds(&mut ctx, 10).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
@@ -276,11 +275,10 @@ async fn repay_success() {
 
     assert_eq!(reserve.liquidity.borrowed_amount().unwrap(), Decimal::ZERO);
 
-    // 10 SLOT LATER
+    // 10 SECONDS LATER
 
-    slot += 10;
-    info!("wrap to slot {}", slot);
-    ctx.warp_to_slot(slot).expect("warp_to_slot");
+    info!("warp 10 seconds");
+    warp_seconds(&mut ctx, 10).await;
 
     info!("refresh position");
     refresh_position(&mut ctx, position_pubkey)
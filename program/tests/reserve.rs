@@ -5,30 +5,38 @@ use std::str::FromStr;
 use bytemuck::Zeroable;
 use price_proxy::state::utils::str_to_array;
 use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClientError, ProgramTestBanksClientExt, ProgramTestContext};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
 use spl_associated_token_account::get_associated_token_address;
 use texture_common::account::PodAccount;
+use texture_common::math::{CheckedAdd, Decimal};
 use tracing::info;
 
-use super_lendy::pda::find_lp_token_mint;
+use super_lendy::instruction::AlterReserve;
+use super_lendy::pda::{find_liquidity_supply, find_lp_token_mint};
 use super_lendy::state::curator::CuratorParams;
 use super_lendy::state::pool::PoolParams;
 use super_lendy::state::reserve::{
-    Reserve, ReserveConfig, ReserveFeesConfig, RESERVE_MODE_BORROW_DISABLED, RESERVE_MODE_NORMAL,
+    InterestRateModel, KinkedIrm, Reserve, ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE,
+    IRM_KIND_KINKED, RESERVE_MODE_BORROW_DISABLED, RESERVE_MODE_NORMAL,
     RESERVE_MODE_RETAIN_LIQUIDITY, RESERVE_TYPE_NORMAL, RESERVE_TYPE_PROTECTED_COLLATERAL,
+    SURPLUS_TO_CURATOR, SURPLUS_TO_LPS,
 };
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
+use super_lendy::SUPER_LENDY_ID;
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
     alter_reserve, borrow, create_curator, create_pool, create_reserve, create_texture_config,
-    deposit_liquidity, lock_collateral, refresh_position, unlock_collateral, withdraw_liquidity,
+    deposit_liquidity, lock_collateral, refresh_position, refresh_reserve, sync_reserve,
+    unlock_collateral, withdraw_liquidity,
 };
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
     create_associated_token_account, get_account, init_program_test, init_token_accounts,
-    lender_keypair, texture_config_keypair, Runner, LAMPORTS, LAMPORTS_PER_USDC,
+    lender_keypair, texture_config_keypair, warp_seconds, Runner, LAMPORTS, LAMPORTS_PER_USDC,
 };
 
 pub mod utils;
@@ -70,6 +78,9 @@ async fn create_alter_success() {
     let texture_params = TextureConfigParams {
         borrow_fee_rate_bps: 3000,
         performance_fee_rate_bps: 4000,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -83,7 +94,8 @@ async fn create_alter_success() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(
@@ -122,6 +134,7 @@ async fn create_alter_success() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -139,6 +152,9 @@ async fn create_alter_success() {
     let mut fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let mut config = ReserveConfig {
@@ -149,12 +165,18 @@ async fn create_alter_success() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 1,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
     let liquidity_mint = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
 
@@ -494,6 +516,282 @@ async fn alter_reserve_mode() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn sync_reserve_surplus() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // DEPOSIT 1000 USDC
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let dest_borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let source_borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        dest_borrower_lp_wallet_usdc,
+        1_000 * LAMPORTS_PER_USDC,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // SYNC RESERVE WITHOUT SURPLUS
+
+    info!("sync reserve without surplus");
+    sync_reserve(&mut ctx, reserve_usdc_pubkey)
+        .await
+        .expect("sync_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve0 = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve0.liquidity.available_amount,
+        1_000 * LAMPORTS_PER_USDC
+    );
+    assert_eq!(reserve0.surplus_destination, SURPLUS_TO_LPS);
+
+    // TRANSFER 10 USDC DIRECTLY TO LIQUIDITY SUPPLY. SURPLUS GOES TO LPs.
+
+    let liquidity_supply = find_liquidity_supply(&reserve_usdc_pubkey).0;
+    transfer_to_supply(
+        &mut ctx,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        liquidity_supply,
+        10 * LAMPORTS_PER_USDC,
+    )
+    .await;
+
+    info!("sync reserve with surplus to LPs");
+    sync_reserve(&mut ctx, reserve_usdc_pubkey)
+        .await
+        .expect("sync_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve1 = *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve1.liquidity.available_amount,
+        1_010 * LAMPORTS_PER_USDC
+    );
+    assert_eq!(
+        reserve1.liquidity.curator_performance_fee().unwrap(),
+        reserve0.liquidity.curator_performance_fee().unwrap()
+    );
+    // LP price increased
+    assert!(reserve1.lp_exchange_rate().unwrap().0 < reserve0.lp_exchange_rate().unwrap().0);
+
+    // TRY TO SET UNKNOWN SURPLUS DESTINATION
+
+    info!("try to set unknown surplus destination");
+    let result = alter_surplus_destination(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        2,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // SWITCH SURPLUS TO CURATOR AND TRANSFER 5 USDC
+
+    info!("switch surplus destination to curator");
+    alter_surplus_destination(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        SURPLUS_TO_CURATOR,
+    )
+    .await
+    .expect("alter_reserve");
+
+    transfer_to_supply(
+        &mut ctx,
+        &borrower_keypair,
+        source_borrower_liq_wallet_usdc,
+        liquidity_supply,
+        5 * LAMPORTS_PER_USDC,
+    )
+    .await;
+
+    info!("sync reserve with surplus to curator");
+    sync_reserve(&mut ctx, reserve_usdc_pubkey)
+        .await
+        .expect("sync_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve2 = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve2.liquidity.available_amount,
+        1_015 * LAMPORTS_PER_USDC
+    );
+    assert_eq!(
+        reserve2.liquidity.curator_performance_fee().unwrap(),
+        reserve1
+            .liquidity
+            .curator_performance_fee()
+            .unwrap()
+            .checked_add(Decimal::from_lamports(5 * LAMPORTS_PER_USDC, 6).unwrap())
+            .unwrap()
+    );
+}
+
+async fn transfer_to_supply(
+    ctx: &mut ProgramTestContext,
+    authority: &Keypair,
+    source_wallet: Pubkey,
+    liquidity_supply: Pubkey,
+    amount: u64,
+) {
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[spl_token::instruction::transfer(
+            &spl_token::id(),
+            &source_wallet,
+            &liquidity_supply,
+            &authority.pubkey(),
+            &[],
+            amount,
+        )
+        .expect("transfer ix")],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .expect("sending tx");
+}
+
+async fn alter_surplus_destination(
+    ctx: &mut ProgramTestContext,
+    reserve: Pubkey,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    surplus_destination: u8,
+) -> Result<(), BanksClientError> {
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve)
+        .await
+        .expect("get reserve");
+    let unpacked_reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[AlterReserve {
+            reserve,
+            pool,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            params: unpacked_reserve.config,
+            mode: unpacked_reserve.mode,
+            flash_loans_enabled: unpacked_reserve.flash_loans_enabled,
+            surplus_destination,
+            isolation_borrowable: unpacked_reserve.isolation_borrowable,
+            isolated_debt_ceiling: unpacked_reserve.isolated_debt_ceiling,
+            fixed_rate_bps: unpacked_reserve.fixed_rate_bps,
+            fixed_max_term_sec: unpacked_reserve.fixed_max_term_sec,
+            fixed_grace_period_sec: unpacked_reserve.fixed_grace_period_sec,
+        }
+        .into_instruction()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, curator_pools_authority],
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await
+}
+
 /// See test description in
 /// https://www.notion.so/3fc6f2d034dc4ff194c69d6f549217f8?pvs=4#974452077c6b4374935b40aa53109790
 #[tokio::test]
@@ -530,6 +828,9 @@ async fn create_reserve_borrow_enabled_disabled() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -569,6 +870,7 @@ async fn create_reserve_borrow_enabled_disabled() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -586,6 +888,9 @@ async fn create_reserve_borrow_enabled_disabled() {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -596,12 +901,18 @@ async fn create_reserve_borrow_enabled_disabled() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 1,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
     let liquidity_mint = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
 
@@ -699,6 +1010,9 @@ async fn create_incorrect_authority() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -738,6 +1052,7 @@ async fn create_incorrect_authority() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -755,6 +1070,9 @@ async fn create_incorrect_authority() {
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let config = ReserveConfig {
@@ -765,12 +1083,18 @@ async fn create_incorrect_authority() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 1,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
     let liquidity_mint = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
 
@@ -825,6 +1149,9 @@ async fn alter_incorrect_authority() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: Zeroable::zeroed(),
     };
@@ -864,6 +1191,7 @@ async fn alter_incorrect_authority() {
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -881,6 +1209,9 @@ async fn alter_incorrect_authority() {
     let mut fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 22,
         curator_performance_fee_rate_bps: 400,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let mut config = ReserveConfig {
@@ -891,12 +1222,18 @@ async fn alter_incorrect_authority() {
         partly_unhealthy_ltv_bps: 6500,
         fully_unhealthy_ltv_bps: 7000,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1000,
         max_borrow_utilization_bps: 1000,
         price_stale_threshold_sec: 1,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
     let liquidity_mint = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
 
@@ -942,3 +1279,168 @@ async fn alter_incorrect_authority() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn kinked_irm_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut config = reserve.config;
+
+    info!("kinked IRM with zero optimal utilization");
+    config.set_interest_rate_model(InterestRateModel::Kinked(KinkedIrm::new(200, 400, 0, 6000)));
+    let result = alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        config,
+        RESERVE_MODE_NORMAL,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // SWITCH TO BUILT-IN KINKED IRM
+
+    let kinked_irm = KinkedIrm::new(200, 400, 8000, 6000);
+    config.set_interest_rate_model(InterestRateModel::Kinked(kinked_irm));
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        config,
+        RESERVE_MODE_NORMAL,
+    )
+    .await
+    .expect("alter_reserve");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(reserve.config.irm_kind, IRM_KIND_KINKED);
+    assert_eq!(
+        reserve.config.interest_rate_model().unwrap(),
+        InterestRateModel::Kinked(kinked_irm)
+    );
+
+    info!("refresh with Curve account instead of program id");
+    let result = refresh_reserve(&mut ctx, reserve_sol1_pubkey, sol_price_feed, irm).await;
+    assert!(result.is_err());
+
+    warp_seconds(&mut ctx, 10).await;
+
+    refresh_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        SUPER_LENDY_ID,
+    )
+    .await
+    .expect("refresh_reserve");
+
+    // Nothing is borrowed thus borrow rate is the base rate
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(
+        reserve.liquidity.borrow_rate().unwrap(),
+        Decimal::from_basis_points(200).unwrap()
+    );
+
+    // SWITCH BACK TO CURVE IRM
+
+    config.set_interest_rate_model(InterestRateModel::Curve(irm));
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        config,
+        RESERVE_MODE_NORMAL,
+    )
+    .await
+    .expect("alter_reserve");
+
+    warp_seconds(&mut ctx, 10).await;
+
+    let result = refresh_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        SUPER_LENDY_ID,
+    )
+    .await;
+    assert!(result.is_err());
+
+    refresh_reserve(&mut ctx, reserve_sol1_pubkey, sol_price_feed, irm)
+        .await
+        .expect("refresh_reserve");
+}
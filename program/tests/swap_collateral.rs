@@ -0,0 +1,431 @@
+#![cfg(feature = "test-bpf")]
+
+use std::str::FromStr;
+
+use bytemuck::Zeroable;
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+use tracing::info;
+
+use super_lendy::pda::find_lp_token_mint;
+use super_lendy::state::position::Position;
+use super_lendy::state::reserve::{Reserve, ReserveFeesConfig, RESERVE_MODE_NORMAL};
+use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
+
+use crate::utils::mock_swap;
+use crate::utils::setup_super_lendy::setup_lendy_env;
+use crate::utils::superlendy_executor::{
+    alter_reserve, alter_texture_config, borrow, deposit_liquidity, lock_collateral,
+    refresh_position, swap_collateral,
+};
+use crate::utils::{
+    add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair,
+    create_associated_token_account, get_account, get_token_account, init_program_test,
+    init_token_accounts, lender_keypair, texture_config_keypair, Runner, LAMPORTS,
+    LAMPORTS_PER_USDC,
+};
+
+pub mod utils;
+
+fn zero_fees_texture_config_params(
+    fees_authority: Pubkey,
+    swap_program: Pubkey,
+) -> TextureConfigParams {
+    TextureConfigParams {
+        borrow_fee_rate_bps: 0,
+        performance_fee_rate_bps: 0,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program,
+        fees_authority,
+        reserve_timelock: ReserveTimelock {
+            market_price_feed_lock_sec: 0,
+            irm_lock_sec: 0,
+            liquidation_bonus_lock_sec: 0,
+            unhealthy_ltv_lock_sec: 0,
+            partial_liquidation_factor_lock_sec: 0,
+            max_total_liquidity_lock_sec: 0,
+            max_borrow_ltv_lock_sec: 0,
+            max_borrow_utilization_lock_sec: 0,
+            price_stale_threshold_lock_sec: 0,
+            max_withdraw_utilization_lock_sec: 0,
+            fees_lock_sec: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
+        },
+    }
+}
+
+#[tokio::test]
+async fn swap_collateral_success() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+    let position_pubkey = borrower_position_keypair.pubkey();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol1_pubkey = reserve_sol1_keypair.pubkey();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_sol2_pubkey = reserve_sol2_keypair.pubkey();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    let usdc_price_feed = add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    // ZERO FEES
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol1_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut params = reserve.config;
+    params.fees = ReserveFeesConfig {
+        curator_borrow_fee_rate_bps: 0,
+        curator_performance_fee_rate_bps: 0,
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
+        _padding: Zeroable::zeroed(),
+    };
+    alter_reserve(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        params,
+        RESERVE_MODE_NORMAL,
+    )
+    .await
+    .expect("alter_reserve");
+
+    alter_texture_config(
+        &mut ctx,
+        &texture_owner_keypair,
+        zero_fees_texture_config_params(texture_owner_pubkey, Pubkey::default()),
+    )
+    .await
+    .expect("alter_texture_config");
+
+    // DEPOSIT INITIAL LIQUIDITY TO SOL1 RESERVE
+
+    let lp_mint = find_lp_token_mint(&reserve_sol1_pubkey).0;
+    let lender_lp_wallet_sol = create_associated_token_account(&mut ctx, &lender_keypair, &lp_mint)
+        .await
+        .expect("create lp ata");
+    let lender_liq_wallet_sol = get_associated_token_address(&lender_pubkey, &liquidity_sol_mint);
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        &lender_keypair,
+        lender_liq_wallet_sol,
+        lender_lp_wallet_sol,
+        1_000 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    // DEPOSIT 10_000 USDC AND LOCK COLLATERAL
+
+    let lp_mint = find_lp_token_mint(&reserve_usdc_pubkey).0;
+    let borrower_lp_wallet_usdc =
+        create_associated_token_account(&mut ctx, &borrower_keypair, &lp_mint)
+            .await
+            .expect("create lp ata");
+    let borrower_liq_wallet_usdc =
+        get_associated_token_address(&borrower_pubkey, &liquidity_usdc_mint);
+    let deposit_usdc_amount = 10_000 * LAMPORTS_PER_USDC;
+
+    deposit_liquidity(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        &borrower_keypair,
+        borrower_liq_wallet_usdc,
+        borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("deposit_liquidity");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh position");
+
+    lock_collateral(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        usdc_price_feed,
+        irm,
+        position_pubkey,
+        &borrower_keypair,
+        borrower_lp_wallet_usdc,
+        deposit_usdc_amount,
+    )
+    .await
+    .expect("lock_collateral");
+
+    // BORROW 10 SOL
+
+    let borrower_liq_wallet_sol =
+        get_associated_token_address(&borrower_pubkey, &liquidity_sol_mint);
+    let texture_fee_receiver =
+        create_associated_token_account(&mut ctx, &texture_owner_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create texture fee receiver ata");
+    let curator_fee_receiver =
+        create_associated_token_account(&mut ctx, &pool_authority_keypair, &liquidity_sol_mint)
+            .await
+            .expect("create curator fee receiver ata");
+
+    borrow(
+        &mut ctx,
+        position_pubkey,
+        reserve_sol1_pubkey,
+        sol_price_feed,
+        irm,
+        pool_pubkey,
+        &borrower_keypair,
+        curator_pubkey,
+        curator_fee_receiver,
+        texture_fee_receiver,
+        borrower_liq_wallet_sol,
+        10 * LAMPORTS_PER_SOL,
+        1,
+    )
+    .await
+    .expect("borrow");
+
+    // SWAP USDC COLLATERAL TO SOL2 COLLATERAL
+
+    mock_swap::init_vaults(
+        &mut ctx,
+        &liquidity_usdc_mint,
+        &liquidity_sol_mint,
+        &lender_keypair,
+        100 * LAMPORTS_PER_SOL,
+    )
+    .await
+    .expect("init_vaults");
+
+    let swap_accounts = mock_swap::swap_accounts(
+        borrower_liq_wallet_usdc,
+        borrower_liq_wallet_sol,
+        borrower_pubkey,
+        &liquidity_usdc_mint,
+        &liquidity_sol_mint,
+    );
+
+    let lp_amount = 5_000 * LAMPORTS_PER_USDC; // LP exchange rate = 1 as USDC is not borrowed
+    let amount_out = 50 * LAMPORTS_PER_SOL;
+
+    info!("swap program is not configured");
+    swap_collateral(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        borrower_liq_wallet_usdc,
+        reserve_sol2_pubkey,
+        borrower_liq_wallet_sol,
+        &borrower_keypair,
+        mock_swap::ID,
+        swap_accounts.clone(),
+        lp_amount,
+        amount_out,
+        mock_swap::swap_data(lp_amount, amount_out),
+    )
+    .await
+    .expect_err("swap_collateral with disabled swap");
+
+    alter_texture_config(
+        &mut ctx,
+        &texture_owner_keypair,
+        zero_fees_texture_config_params(texture_owner_pubkey, mock_swap::ID),
+    )
+    .await
+    .expect("alter_texture_config");
+
+    info!("swap gives less than min_target_amount");
+    swap_collateral(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        borrower_liq_wallet_usdc,
+        reserve_sol2_pubkey,
+        borrower_liq_wallet_sol,
+        &borrower_keypair,
+        mock_swap::ID,
+        swap_accounts.clone(),
+        lp_amount,
+        amount_out + 1,
+        mock_swap::swap_data(lp_amount, amount_out),
+    )
+    .await
+    .expect_err("swap_collateral with slippage");
+
+    info!("new collateral does not cover the debt");
+    swap_collateral(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        borrower_liq_wallet_usdc,
+        reserve_sol2_pubkey,
+        borrower_liq_wallet_sol,
+        &borrower_keypair,
+        mock_swap::ID,
+        swap_accounts.clone(),
+        deposit_usdc_amount,
+        LAMPORTS_PER_SOL,
+        mock_swap::swap_data(deposit_usdc_amount, LAMPORTS_PER_SOL),
+    )
+    .await
+    .expect_err("swap_collateral to unhealthy position");
+
+    let borrower_liq_token_usdc0 =
+        get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+            .await
+            .expect("get token acc");
+    let borrower_liq_token_sol0 = get_token_account(&mut ctx.banks_client, borrower_liq_wallet_sol)
+        .await
+        .expect("get token acc");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol2_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve_sol2_before =
+        *Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    info!("swap collateral");
+    swap_collateral(
+        &mut ctx,
+        position_pubkey,
+        reserve_usdc_pubkey,
+        borrower_liq_wallet_usdc,
+        reserve_sol2_pubkey,
+        borrower_liq_wallet_sol,
+        &borrower_keypair,
+        mock_swap::ID,
+        swap_accounts,
+        lp_amount,
+        amount_out,
+        mock_swap::swap_data(lp_amount, amount_out),
+    )
+    .await
+    .expect("swap_collateral");
+
+    refresh_position(&mut ctx, position_pubkey)
+        .await
+        .expect("refresh_position");
+
+    let position_acc = get_account(&mut ctx.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::try_from_bytes(&position_acc.data).expect("cast position data");
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_sol2_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve_sol2 = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // Source collateral decreased. Swapped liquidity deposited and locked as target collateral.
+    let usdc_collateral = position.find_collateral(reserve_usdc_pubkey).unwrap().0;
+    assert_eq!(
+        usdc_collateral.deposited_amount,
+        deposit_usdc_amount - lp_amount
+    );
+
+    let target_lp_amount =
+        reserve_sol2.collateral.lp_total_supply - reserve_sol2_before.collateral.lp_total_supply;
+    assert!(target_lp_amount > 0);
+    let sol2_collateral = position.find_collateral(reserve_sol2_pubkey).unwrap().0;
+    assert_eq!(sol2_collateral.deposited_amount, target_lp_amount);
+    assert_eq!(
+        reserve_sol2.liquidity.available_amount,
+        reserve_sol2_before.liquidity.available_amount + amount_out
+    );
+
+    // Debt is untouched and Position stays healthy.
+    assert!(position.borrowed_value().unwrap() > Decimal::ZERO);
+    assert!(position.weighted_borrowed_value().unwrap() < position.allowed_borrow_value().unwrap());
+
+    // Swap input and output just passed through borrower's wallets.
+    let borrower_liq_token_usdc1 =
+        get_token_account(&mut ctx.banks_client, borrower_liq_wallet_usdc)
+            .await
+            .expect("get token acc");
+    let borrower_liq_token_sol1 = get_token_account(&mut ctx.banks_client, borrower_liq_wallet_sol)
+        .await
+        .expect("get token acc");
+
+    assert_eq!(
+        borrower_liq_token_usdc1.amount,
+        borrower_liq_token_usdc0.amount
+    );
+    assert_eq!(
+        borrower_liq_token_sol1.amount,
+        borrower_liq_token_sol0.amount
+    );
+}
@@ -1,5 +1,6 @@
 #![cfg(feature = "test-bpf")]
 
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::transaction::Transaction;
@@ -37,6 +38,9 @@ async fn create_alter_success() {
     let mut params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -50,7 +54,8 @@ async fn create_alter_success() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -110,6 +115,9 @@ async fn create_repeated() {
     let params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -123,7 +131,8 @@ async fn create_repeated() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
@@ -171,6 +180,9 @@ async fn alter_incorrect_owner() {
     let mut params = TextureConfigParams {
         borrow_fee_rate_bps: 100,
         performance_fee_rate_bps: 100,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: owner_pubkey,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -184,7 +196,8 @@ async fn alter_incorrect_owner() {
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(&mut ctx, &owner_keypair, &texture_config_keypair, params)
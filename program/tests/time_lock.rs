@@ -4,21 +4,25 @@ use bytemuck::Zeroable;
 use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use price_proxy::state::utils::str_to_array;
+use solana_program::clock::Clock;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use super_lendy::state::pool::{EmodeCategory, EmodeCategoryProposal, Pool};
 use super_lendy::state::reserve::{ConfigFields, ConfigProposal, Reserve};
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
 use texture_common::account::PodAccount;
 
 use crate::utils::setup_super_lendy::setup_lendy_env;
 use crate::utils::superlendy_executor::{
-    alter_reserve, alter_texture_config, apply_proposal, propose_config,
+    alter_reserve, alter_texture_config, apply_emode_category_proposal, apply_proposal,
+    propose_config, propose_emode_category, set_emode_category,
 };
 use crate::utils::{
     add_curve_acc, add_price_feed_acc, admin_keypair, borrow_keypair, get_account,
-    init_program_test, init_token_accounts, lender_keypair, texture_config_keypair, Runner,
-    LAMPORTS,
+    init_program_test, init_token_accounts, lender_keypair, texture_config_keypair, warp_seconds,
+    Runner, LAMPORTS,
 };
 
 pub mod utils;
@@ -27,6 +31,9 @@ pub fn texture_config_params(fees_authority: Pubkey) -> TextureConfigParams {
     TextureConfigParams {
         borrow_fee_rate_bps: 3000,
         performance_fee_rate_bps: 4000,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority,
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 10,
@@ -40,7 +47,8 @@ pub fn texture_config_params(fees_authority: Pubkey) -> TextureConfigParams {
             price_stale_threshold_lock_sec: 90,
             max_withdraw_utilization_lock_sec: 100,
             fees_lock_sec: 110,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     }
 }
@@ -562,3 +570,323 @@ async fn alter_for_time_locked_param() {
     .await;
     assert!(result.is_err())
 }
+
+#[tokio::test]
+async fn emode_category_time_locked() {
+    let mut runner = init_program_test();
+
+    let admin_keypair = admin_keypair();
+    let admin_pubkey = admin_keypair.pubkey();
+    let borrower_keypair = borrow_keypair();
+    let borrower_pubkey = borrower_keypair.pubkey();
+    let lender_keypair = lender_keypair();
+    let lender_pubkey = lender_keypair.pubkey();
+    let borrower_position_keypair = Keypair::new();
+
+    let texture_owner_keypair = Keypair::new();
+    let texture_owner_pubkey = texture_owner_keypair.pubkey();
+    let texture_config_keypair = texture_config_keypair();
+
+    let pool_authority_keypair = Keypair::new();
+    let pool_authority_pubkey = pool_authority_keypair.pubkey();
+    let pool_keypair = Keypair::new();
+    let pool_pubkey = pool_keypair.pubkey();
+
+    let curator_keypair = Keypair::new();
+    let curator_pubkey = curator_keypair.pubkey();
+
+    let reserve_sol1_keypair = Keypair::new();
+    let reserve_sol2_keypair = Keypair::new();
+    let reserve_usdc_keypair = Keypair::new();
+    let reserve_usdc_pubkey = reserve_usdc_keypair.pubkey();
+
+    runner.add_native_wallet(admin_pubkey, LAMPORTS);
+    runner.add_native_wallet(texture_owner_pubkey, LAMPORTS);
+    runner.add_native_wallet(borrower_pubkey, LAMPORTS);
+    runner.add_native_wallet(lender_pubkey, LAMPORTS);
+    runner.add_native_wallet(pool_authority_pubkey, LAMPORTS);
+
+    // 1 SOL = 100 USD
+    let sol_price_feed = add_price_feed_acc(&mut runner, "sol-usd").await;
+    // 1 USDC = 1.001 USD
+    add_price_feed_acc(&mut runner, "usdc-usd").await;
+
+    let irm = add_curve_acc(&mut runner, "const-40-pct-acc").await;
+    let liquidity_sol_mint =
+        Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+    let liquidity_usdc_mint =
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    init_token_accounts(&mut runner, &liquidity_sol_mint);
+    init_token_accounts(&mut runner, &liquidity_usdc_mint);
+
+    let mut ctx = runner.start_with_context().await;
+
+    setup_lendy_env(
+        &mut ctx,
+        &admin_keypair,
+        &borrower_keypair,
+        &curator_keypair,
+        &pool_keypair,
+        &reserve_sol1_keypair,
+        &reserve_sol2_keypair,
+        &reserve_usdc_keypair,
+        &texture_owner_keypair,
+        &texture_config_keypair,
+        &pool_authority_keypair,
+        &borrower_position_keypair,
+        irm,
+    )
+    .await;
+
+    let mut category = EmodeCategory {
+        name: str_to_array("Stablecoins"),
+        max_borrow_ltv_bps: 9000,
+        partly_unhealthy_ltv_bps: 9300,
+        fully_unhealthy_ltv_bps: 9500,
+        _padding: Zeroable::zeroed(),
+    };
+    set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await
+    .expect("set_emode_category");
+
+    // ALTER TEXTURE CONFIG
+
+    let params = texture_config_params(texture_owner_pubkey);
+    alter_texture_config(&mut ctx, &texture_owner_keypair, params)
+        .await
+        .expect("alter_texture_config");
+
+    // LOWER CATEGORY LTV RIGHT AWAY
+
+    category.max_borrow_ltv_bps = 8900;
+    set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await
+    .expect("set_emode_category");
+
+    // TRY TO RAISE CATEGORY LTV RIGHT AWAY
+
+    category.partly_unhealthy_ltv_bps = 9400;
+    let result = set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // New category can't be configured right away too
+    let result = set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        2,
+        category,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // TRY TO ALTER TIME LOCKED EMODE_CATEGORY
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    let mut config = reserve.config;
+    config.emode_category = 1;
+
+    let result = alter_reserve(
+        &mut ctx,
+        reserve_usdc_pubkey,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        config,
+        reserve.mode,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // PROPOSE CONFIG WITH NEW EMODE_CATEGORY
+
+    let config_proposal = ConfigProposal {
+        can_be_applied_at: 0,
+        change_map: ConfigFields::EMODE_CATEGORY.bits(),
+        config,
+    };
+
+    propose_config(
+        &mut ctx,
+        pool_pubkey,
+        reserve_usdc_pubkey,
+        sol_price_feed,
+        curator_pubkey,
+        &pool_authority_keypair,
+        0,
+        config_proposal,
+    )
+    .await
+    .expect("propose_config");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    // Locked for max of unhealthy_ltv_lock_sec and max_borrow_ltv_lock_sec
+    let config_proposal = reserve.proposed_configs.0.first().unwrap();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("create timestamp in timing")
+        .as_secs();
+    let exp_applied = now + 70;
+    assert!((exp_applied - config_proposal.can_be_applied_at as u64) <= 1); // may be 1 sec delay
+    assert_eq!(reserve.config.emode_category, 0);
+
+    // TRY TO APPLY BEFORE TIME LOCK EXPIRES
+
+    let result = apply_proposal(
+        &mut ctx,
+        pool_pubkey,
+        reserve_usdc_pubkey,
+        sol_price_feed,
+        curator_pubkey,
+        &pool_authority_keypair,
+        0,
+    )
+    .await;
+    assert!(result.is_err());
+
+    warp_seconds(&mut ctx, 71).await;
+
+    apply_proposal(
+        &mut ctx,
+        pool_pubkey,
+        reserve_usdc_pubkey,
+        sol_price_feed,
+        curator_pubkey,
+        &pool_authority_keypair,
+        0,
+    )
+    .await
+    .expect("apply_proposal");
+
+    let reserve_acc = get_account(&mut ctx.banks_client, reserve_usdc_pubkey)
+        .await
+        .expect("get reserve");
+    let reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+    assert_eq!(reserve.config.emode_category, 1);
+
+    // PROPOSE CATEGORY WITH RAISED LTV
+
+    // Change which can be applied right away is not accepted as proposal
+    let mut lowered_category = category;
+    lowered_category.partly_unhealthy_ltv_bps = 9200;
+    let result = propose_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        lowered_category,
+    )
+    .await;
+    assert!(result.is_err());
+
+    propose_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        category,
+    )
+    .await
+    .expect("propose_emode_category");
+
+    let pool_acc = get_account(&mut ctx.banks_client, pool_pubkey)
+        .await
+        .expect("get pool");
+    let pool = Pool::try_from_bytes(&pool_acc.data).expect("cast pool data");
+
+    // Only unhealthy LTV changed thus locked for unhealthy_ltv_lock_sec
+    let clock: Clock = ctx.banks_client.get_sysvar().await.expect("get clock");
+    let proposal = pool.emode_category_proposal;
+    let time_lock = proposal.can_be_applied_at - clock.unix_timestamp;
+    assert!((39..=40).contains(&time_lock)); // may be 1 sec delay
+    assert_eq!(proposal.category_id, 1);
+    assert_eq!(pool.emode_categories[0].partly_unhealthy_ltv_bps, 9300);
+
+    // TRY TO APPLY BEFORE TIME LOCK EXPIRES
+
+    let result = apply_emode_category_proposal(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+    )
+    .await;
+    assert!(result.is_err());
+
+    warp_seconds(&mut ctx, 41).await;
+
+    apply_emode_category_proposal(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+    )
+    .await
+    .expect("apply_emode_category_proposal");
+
+    let pool_acc = get_account(&mut ctx.banks_client, pool_pubkey)
+        .await
+        .expect("get pool");
+    let pool = Pool::try_from_bytes(&pool_acc.data).expect("cast pool data");
+    assert_eq!(pool.emode_categories[0], category);
+    assert_eq!(
+        pool.emode_category_proposal,
+        EmodeCategoryProposal::zeroed()
+    );
+
+    // Applied proposal can't be applied again
+    let result = apply_emode_category_proposal(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // DISABLE CATEGORY RIGHT AWAY
+
+    set_emode_category(
+        &mut ctx,
+        pool_pubkey,
+        &pool_authority_keypair,
+        curator_pubkey,
+        1,
+        EmodeCategory::zeroed(),
+    )
+    .await
+    .expect("set_emode_category");
+}
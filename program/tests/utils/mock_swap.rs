@@ -0,0 +1,158 @@
+//! Minimal swap program used to test swap-based deleveraging. Swaps at the rate given by the
+//! caller: takes `amount_in` from user's source wallet to `vault_in` and gives `amount_out` from
+//! `vault_out` (owned by program's vault authority PDA) to user's destination wallet.
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClientError, ProgramTestBanksClientExt, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+
+pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+pub const VAULT_AUTHORITY_SEED: &[u8] = b"vault";
+
+pub fn vault_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_AUTHORITY_SEED], &ID)
+}
+
+pub fn vault(mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&vault_authority().0, mint)
+}
+
+/// Instruction data of the swap. Passed to RepayWithCollateralSwap as `swap_data`.
+pub fn swap_data(amount_in: u64, amount_out: u64) -> Vec<u8> {
+    [amount_in.to_le_bytes(), amount_out.to_le_bytes()].concat()
+}
+
+/// Accounts of the swap. Passed to RepayWithCollateralSwap as trailing accounts.
+pub fn swap_accounts(
+    source_wallet: Pubkey,
+    destination_wallet: Pubkey,
+    user_authority: Pubkey,
+    mint_in: &Pubkey,
+    mint_out: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(source_wallet, false),
+        AccountMeta::new(destination_wallet, false),
+        AccountMeta::new_readonly(user_authority, true),
+        AccountMeta::new(vault(mint_in), false),
+        AccountMeta::new(vault(mint_out), false),
+        AccountMeta::new_readonly(vault_authority().0, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+    ]
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    if input.len() != 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount_in = u64::from_le_bytes(input[..8].try_into().unwrap());
+    let amount_out = u64::from_le_bytes(input[8..].try_into().unwrap());
+
+    let account_info_iter = &mut accounts.iter();
+    let source_wallet = next_account_info(account_info_iter)?;
+    let destination_wallet = next_account_info(account_info_iter)?;
+    let user_authority = next_account_info(account_info_iter)?;
+    let vault_in = next_account_info(account_info_iter)?;
+    let vault_out = next_account_info(account_info_iter)?;
+    let vault_authority_info = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+
+    let (expected_vault_authority, bump) = vault_authority();
+    if vault_authority_info.key != &expected_vault_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            source_wallet.key,
+            vault_in.key,
+            user_authority.key,
+            &[],
+            amount_in,
+        )?,
+        &[
+            source_wallet.clone(),
+            vault_in.clone(),
+            user_authority.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            vault_out.key,
+            destination_wallet.key,
+            vault_authority_info.key,
+            &[],
+            amount_out,
+        )?,
+        &[
+            vault_out.clone(),
+            destination_wallet.clone(),
+            vault_authority_info.clone(),
+            token_program.clone(),
+        ],
+        &[&[VAULT_AUTHORITY_SEED, &[bump]]],
+    )
+}
+
+/// Creates vaults for both mints and funds `vault(mint_out)` with `amount` tokens from `funder`.
+pub async fn init_vaults(
+    context: &mut ProgramTestContext,
+    mint_in: &Pubkey,
+    mint_out: &Pubkey,
+    funder: &Keypair,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let ixs: Vec<Instruction> = vec![
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            &vault_authority().0,
+            mint_in,
+            &spl_token::ID,
+        ),
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            &vault_authority().0,
+            mint_out,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::transfer(
+            &spl_token::ID,
+            &get_associated_token_address(&funder.pubkey(), mint_out),
+            &vault(mint_out),
+            &funder.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, funder],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
@@ -2,11 +2,12 @@ use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
+use solana_program::clock::{Clock, DEFAULT_MS_PER_SLOT};
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 use solana_program_test::{
-    find_file, read_file, BanksClient, BanksClientError, ProgramTest, ProgramTestContext,
+    find_file, processor, read_file, BanksClient, BanksClientError, ProgramTest, ProgramTestContext,
 };
 use solana_sdk::account::Account;
 use solana_sdk::signature::{read_keypair_file, Keypair};
@@ -16,6 +17,7 @@ use spl_token::state::Account as TokenAccount;
 
 use super_lendy::SUPER_LENDY_ID;
 
+pub mod mock_swap;
 pub mod setup_super_lendy;
 pub mod superlendy_executor;
 
@@ -104,6 +106,12 @@ pub fn init_program_test() -> ProgramTest {
             rent_epoch: 0,
         },
     );
+
+    program_test.add_program(
+        "mock_swap",
+        mock_swap::ID,
+        processor!(mock_swap::process_instruction),
+    );
     program_test
 }
 
@@ -201,6 +209,26 @@ pub async fn get_account(
         .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "account not found"))
 }
 
+/// Moves Solana clock `slots` and `seconds` forward. `warp_to_slot` alone doesn't give any control
+/// over `unix_timestamp` while interest accrues by elapsed seconds.
+pub async fn warp_clock(ctx: &mut ProgramTestContext, slots: u64, seconds: u64) -> Clock {
+    let clock: Clock = ctx.banks_client.get_sysvar().await.expect("get clock");
+    ctx.warp_to_slot(clock.slot + slots).expect("warp_to_slot");
+
+    let mut warped_clock: Clock = ctx.banks_client.get_sysvar().await.expect("get clock");
+    warped_clock.unix_timestamp = clock.unix_timestamp + seconds as i64;
+    ctx.set_sysvar(&warped_clock);
+
+    warped_clock
+}
+
+/// Moves Solana clock `seconds` forward together with the number of slots produced in that time
+/// at default slot duration.
+pub async fn warp_seconds(ctx: &mut ProgramTestContext, seconds: u64) -> Clock {
+    let slots = (seconds * 1000 / DEFAULT_MS_PER_SLOT).max(1);
+    warp_clock(ctx, slots, seconds).await
+}
+
 pub async fn get_token_account(
     banks_client: &mut BanksClient,
     address: Pubkey,
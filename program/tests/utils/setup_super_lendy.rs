@@ -12,8 +12,8 @@ use tracing::info;
 use super_lendy::state::curator::CuratorParams;
 use super_lendy::state::pool::PoolParams;
 use super_lendy::state::reserve::{
-    ReserveConfig, ReserveFeesConfig, RESERVE_TYPE_NORMAL, RESERVE_TYPE_NOT_A_COLLATERAL,
-    RESERVE_TYPE_PROTECTED_COLLATERAL,
+    ReserveConfig, ReserveFeesConfig, IRM_KIND_CURVE, RESERVE_TYPE_NORMAL,
+    RESERVE_TYPE_NOT_A_COLLATERAL, RESERVE_TYPE_PROTECTED_COLLATERAL,
 };
 use super_lendy::state::texture_cfg::{ReserveTimelock, TextureConfigParams};
 
@@ -52,6 +52,9 @@ pub async fn setup_lendy_env(
     let texture_params = TextureConfigParams {
         borrow_fee_rate_bps: 3000,
         performance_fee_rate_bps: 4000,
+        flash_loan_fee_share_bps: 0,
+        liquidation_fee_share_bps: 0,
+        swap_program: Pubkey::default(),
         fees_authority: texture_owner_keypair.pubkey(),
         reserve_timelock: ReserveTimelock {
             market_price_feed_lock_sec: 0,
@@ -65,7 +68,8 @@ pub async fn setup_lendy_env(
             price_stale_threshold_lock_sec: 0,
             max_withdraw_utilization_lock_sec: 0,
             fees_lock_sec: 0,
-            _padding: 0,
+            max_total_borrow_lock_sec: 0,
+            borrow_weight_lock_sec: 0,
         },
     };
     create_texture_config(
@@ -104,6 +108,7 @@ pub async fn setup_lendy_env(
         name: [1; 128],
         market_price_currency_symbol: str_to_array("USD"),
         visible: 0,
+        auto_write_off_bad_debt: 0,
     };
 
     create_pool(
@@ -121,6 +126,9 @@ pub async fn setup_lendy_env(
     let fees_config = ReserveFeesConfig {
         curator_borrow_fee_rate_bps: 100,       // 1%
         curator_performance_fee_rate_bps: 2000, // 20%
+        flash_loan_fee_bps: 0,
+        curator_liquidation_fee_share_bps: 0,
+        insurance_fee_rate_bps: 0,
         _padding: Zeroable::zeroed(),
     };
     let mut reserve_config = ReserveConfig {
@@ -131,12 +139,18 @@ pub async fn setup_lendy_env(
         partly_unhealthy_ltv_bps: 9500,
         fully_unhealthy_ltv_bps: 9700,
         partial_liquidation_factor_bps: 2000,
-        _padding: Zeroable::zeroed(),
         fees: fees_config,
         max_total_liquidity: 1_000_000_000 * LAMPORTS_PER_SOL,
         max_borrow_utilization_bps: 5000,
         price_stale_threshold_sec: 10000000,
         max_withdraw_utilization_bps: 9000,
+        max_total_borrow: 0,
+        borrow_weight_bps: 10_000,
+        max_liquidation_bonus_bps: 0,
+        irm_kind: IRM_KIND_CURVE,
+        min_borrow_rate_bps: 0,
+        max_borrow_rate_bps: 0,
+        emode_category: 0,
     };
 
     info!("create reserve sol borrow enabled");
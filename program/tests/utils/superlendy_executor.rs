@@ -1,7 +1,8 @@
 use chrono::Utc;
 use price_proxy::instruction::WritePrice;
 use price_proxy::state::price_feed::PriceFeed;
-use solana_program::instruction::Instruction;
+use solana_program::clock::Clock;
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
 use solana_program::system_instruction::create_account;
 use solana_program_test::{BanksClientError, ProgramTestBanksClientExt, ProgramTestContext};
@@ -12,17 +13,23 @@ use texture_common::account::PodAccount;
 use texture_common::math::Decimal;
 
 use super_lendy::instruction::{
-    AlterCurator, AlterPool, AlterReserve, AlterTextureConfig, ApplyConfigProposal, Borrow,
-    ClaimCuratorPerformanceFees, ClaimReward, ClaimTexturePerformanceFees, CreateCurator,
-    CreatePool, CreatePosition, CreateReserve, CreateTextureConfig, DepositLiquidity,
-    InitRewardSupply, Liquidate, LockCollateral, ProposeConfig, RefreshPosition, RefreshReserve,
-    Repay, SetRewardRules, UnlockCollateral, WithdrawLiquidity, WithdrawReward, WriteOffBadDebt,
+    AlterCurator, AlterPool, AlterReserve, AlterTextureConfig, ApplyConfigProposal,
+    ApplyEmodeCategoryProposal, Borrow, BorrowFixed, ClaimCuratorLiquidationFees,
+    ClaimCuratorPerformanceFees, ClaimInsuranceFees, ClaimReward, ClaimTextureLiquidationFees,
+    ClaimTexturePerformanceFees, ClosePosition, CreateCurator, CreatePool, CreatePosition,
+    CreatePositionPda, CreateReserve, CreateTextureConfig, DepositAndLockCollateral,
+    DepositLiquidity, InitInsuranceFund, InitRewardSupply, Liquidate, LiquidateAndRedeem,
+    LockCollateral, ProposeConfig, ProposeEmodeCategory, RefreshPosition, RefreshReserve, Repay,
+    RepayFixed, RepayWithCollateral, RepayWithCollateralSwap, ResizePosition, SetEmodeCategory,
+    SetPositionDelegate, SetPositionEmode, SetRewardRules, SocializeLoss, SwapCollateral,
+    SyncReserve, UnlockAndWithdrawLiquidity, UnlockCollateral, WithdrawLiquidity, WithdrawReward,
+    WriteOffBadDebt,
 };
 use super_lendy::state::curator::{Curator, CuratorParams};
-use super_lendy::state::pool::{Pool, PoolParams};
+use super_lendy::state::pool::{EmodeCategory, Pool, PoolParams};
 use super_lendy::state::position::{Position, BORROW_MEMO_LEN, COLLATERAL_MEMO_LEN};
 use super_lendy::state::reserve::{
-    ConfigProposal, Reserve, ReserveConfig, RewardRules, RESERVE_MODE_NORMAL,
+    ConfigProposal, Reserve, ReserveConfig, RewardRules, RESERVE_MODE_NORMAL, SURPLUS_TO_LPS,
 };
 use super_lendy::state::texture_cfg::{TextureConfig, TextureConfigParams};
 use super_lendy::{SUPER_LENDY_ID, TEXTURE_CONFIG_ID};
@@ -229,6 +236,92 @@ pub async fn alter_pool(
     context.banks_client.process_transaction(tx).await
 }
 
+pub async fn set_emode_category(
+    context: &mut ProgramTestContext,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    category_id: u8,
+    category: EmodeCategory,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[SetEmodeCategory {
+            pool,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            category_id,
+            category,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, curator_pools_authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn propose_emode_category(
+    context: &mut ProgramTestContext,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    category_id: u8,
+    category: EmodeCategory,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ProposeEmodeCategory {
+            pool,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            category_id,
+            category,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, curator_pools_authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn apply_emode_category_proposal(
+    context: &mut ProgramTestContext,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ApplyEmodeCategoryProposal {
+            pool,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, curator_pools_authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn create_reserve(
     context: &mut ProgramTestContext,
@@ -311,6 +404,58 @@ pub async fn alter_reserve(
             params,
             mode,
             flash_loans_enabled: 0,
+            surplus_destination: SURPLUS_TO_LPS,
+            isolation_borrowable: 0,
+            isolated_debt_ceiling: 0,
+            fixed_rate_bps: 0,
+            fixed_max_term_sec: 0,
+            fixed_grace_period_sec: 0,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, curator_pools_authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn alter_fixed_rate(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+    pool: Pubkey,
+    curator_pools_authority: &Keypair,
+    curator: Pubkey,
+    fixed_rate_bps: u32,
+    fixed_max_term_sec: u32,
+    fixed_grace_period_sec: u32,
+) -> Result<(), BanksClientError> {
+    let reserve_acc = get_account(&mut context.banks_client, reserve)
+        .await
+        .expect("get reserve");
+    let unpacked_reserve = Reserve::try_from_bytes(&reserve_acc.data).expect("cast reserve data");
+
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+    let tx = Transaction::new_signed_with_payer(
+        &[AlterReserve {
+            reserve,
+            pool,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            params: unpacked_reserve.config,
+            mode: unpacked_reserve.mode,
+            flash_loans_enabled: unpacked_reserve.flash_loans_enabled,
+            surplus_destination: unpacked_reserve.surplus_destination,
+            isolation_borrowable: unpacked_reserve.isolation_borrowable,
+            isolated_debt_ceiling: unpacked_reserve.isolated_debt_ceiling,
+            fixed_rate_bps,
+            fixed_max_term_sec,
+            fixed_grace_period_sec,
         }
         .into_instruction()],
         Some(&context.payer.pubkey()),
@@ -503,6 +648,90 @@ pub async fn unlock_collateral(
     context.banks_client.process_transaction(tx).await
 }
 
+#[allow(clippy::too_many_arguments)]
+pub async fn deposit_and_lock_collateral(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+    position: Pubkey,
+    owner: &Keypair,
+    source_liquidity_wallet: Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    // Reserve may be not in the Position yet thus refreshed separately.
+    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
+    let (refresh_position_ixs, mut reserves) = refresh_position_ix(context, position).await;
+    ixs.extend(refresh_position_ixs);
+    reserves.push(reserve);
+
+    ixs.push(
+        DepositAndLockCollateral {
+            position,
+            reserve,
+            source_liquidity_wallet,
+            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+            owner: owner.pubkey(),
+            liquidity_token_program: spl_token::id(),
+            amount,
+            memo: [0; COLLATERAL_MEMO_LEN],
+        }
+        .into_instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn unlock_and_withdraw_liquidity(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+    position: Pubkey,
+    owner: &Keypair,
+    destination_liquidity_wallet: Pubkey,
+    lp_amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    ixs.push(
+        UnlockAndWithdrawLiquidity {
+            position,
+            reserve,
+            destination_liquidity_wallet,
+            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+            owner: owner.pubkey(),
+            liquidity_token_program: spl_token::id(),
+            lp_amount,
+        }
+        .into_instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
 pub async fn create_position(
     context: &mut ProgramTestContext,
     position_kp: &Keypair,
@@ -544,77 +773,296 @@ pub async fn create_position(
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn refresh_reserves_ix(
+pub async fn create_position_pda(
     context: &mut ProgramTestContext,
-    reserves: &[Pubkey],
-) -> Vec<Instruction> {
-    let mut ixs = Vec::new();
-
-    for reserve in reserves {
-        let reserve_acc = get_account(&mut context.banks_client, *reserve)
-            .await
-            .expect("get Reserve");
-        let unpacked_reserve =
-            Reserve::try_from_bytes(&reserve_acc.data).expect("unpacking Reserve");
+    position: Pubkey,
+    pool: Pubkey,
+    owner: &Keypair,
+    position_type: u8,
+    index: u8,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
 
-        let refresh_reserve = RefreshReserve {
-            reserve: *reserve,
-            market_price_feed: unpacked_reserve.config.market_price_feed,
-            irm: unpacked_reserve.config.irm,
+    let tx = Transaction::new_signed_with_payer(
+        &[CreatePositionPda {
+            position,
+            pool,
+            owner: owner.pubkey(),
+            position_type,
+            index,
         }
-        .into_instruction();
-
-        ixs.push(refresh_reserve);
-    }
-
-    ixs
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
 }
 
-pub async fn refresh_reserve(
+pub async fn close_position(
     context: &mut ProgramTestContext,
-    reserve: Pubkey,
-    market_price_feed: Pubkey,
-    irm: Pubkey,
+    position: Pubkey,
+    owner: &Keypair,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
         .banks_client
-        .get_latest_blockhash()
+        .get_new_latest_blockhash(&context.last_blockhash)
         .await
         .expect("get latest blockhash");
 
-    let ixs = vec![
-        ComputeBudgetInstruction::set_compute_unit_limit(800_000),
-        RefreshReserve {
-            reserve,
-            market_price_feed,
-            irm,
-        }
-        .into_instruction(),
-    ];
-
     let tx = Transaction::new_signed_with_payer(
-        &ixs,
+        &[ClosePosition {
+            position,
+            owner: owner.pubkey(),
+        }
+        .into_instruction()],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, owner],
         blockhash,
     );
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn refresh_position_ix(
+pub async fn resize_position(
     context: &mut ProgramTestContext,
-    position_pubkey: Pubkey,
-) -> (Vec<Instruction>, /* all reserves*/ Vec<Pubkey>) {
-    let position_acc = get_account(&mut context.banks_client, position_pubkey)
+    position: Pubkey,
+    owner: &Keypair,
+    extra_deposits: u8,
+    extra_borrows: u8,
+    extra_rewards: u8,
+    extra_fixed_loans: u8,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
         .await
-        .expect("get position");
-    let position = Position::try_from_bytes(&position_acc.data).expect("unpacking Position");
+        .expect("get latest blockhash");
 
-    let deposits_reserves: Vec<Pubkey> = position
-        .collateral
-        .iter()
-        .filter_map(|dep| {
-            if dep.deposited_amount > 0 {
+    let tx = Transaction::new_signed_with_payer(
+        &[ResizePosition {
+            position,
+            owner: owner.pubkey(),
+            extra_deposits,
+            extra_borrows,
+            extra_rewards,
+            extra_fixed_loans,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_position_delegate(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    owner: &Keypair,
+    delegate: Pubkey,
+    permissions: u8,
+    borrow_allowance: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[SetPositionDelegate {
+            position,
+            owner: owner.pubkey(),
+            delegate,
+            permissions,
+            borrow_allowance,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn transfer_position_ownership(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    owner: &Keypair,
+    new_owner: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[TransferPositionOwnership {
+            position,
+            owner: owner.pubkey(),
+            new_owner,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn accept_position_ownership(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    new_owner: &Keypair,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+    ixs.push(
+        AcceptPositionOwnership {
+            position,
+            new_owner: new_owner.pubkey(),
+        }
+        .into_instruction(),
+    );
+
+    update_prices(context, &reserves).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, new_owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_position_emode(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    pool: Pubkey,
+    owner: &Keypair,
+    category_id: u8,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[SetPositionEmode {
+            position,
+            pool,
+            owner: owner.pubkey(),
+            category_id,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn refresh_reserves_ix(
+    context: &mut ProgramTestContext,
+    reserves: &[Pubkey],
+) -> Vec<Instruction> {
+    let mut ixs = Vec::new();
+
+    for reserve in reserves {
+        let reserve_acc = get_account(&mut context.banks_client, *reserve)
+            .await
+            .expect("get Reserve");
+        let unpacked_reserve =
+            Reserve::try_from_bytes(&reserve_acc.data).expect("unpacking Reserve");
+
+        let refresh_reserve = RefreshReserve {
+            reserve: *reserve,
+            market_price_feed: unpacked_reserve.config.market_price_feed,
+            irm: unpacked_reserve.config.irm_account(),
+        }
+        .into_instruction();
+
+        ixs.push(refresh_reserve);
+    }
+
+    ixs
+}
+
+pub async fn refresh_reserve(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+    market_price_feed: Pubkey,
+    irm: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get latest blockhash");
+
+    let ixs = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(800_000),
+        RefreshReserve {
+            reserve,
+            market_price_feed,
+            irm,
+        }
+        .into_instruction(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn sync_reserve(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[SyncReserve { reserve }.into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn refresh_position_ix(
+    context: &mut ProgramTestContext,
+    position_pubkey: Pubkey,
+) -> (Vec<Instruction>, /* all reserves*/ Vec<Pubkey>) {
+    let position_acc = get_account(&mut context.banks_client, position_pubkey)
+        .await
+        .expect("get position");
+    let position = Position::unpack(&position_acc.data).expect("unpacking Position");
+
+    let deposits_reserves: Vec<Pubkey> = position
+        .collateral()
+        .filter_map(|dep| {
+            if dep.deposited_amount > 0 {
                 Some(dep.deposit_reserve)
             } else {
                 None
@@ -623,8 +1071,8 @@ pub async fn refresh_position_ix(
         .collect();
 
     let borrows_reserves: Vec<Pubkey> = position
-        .borrows
-        .iter()
+        .borrows()
+        .chain(position.fixed_loans.iter().map(|loan| &loan.liquidity))
         .filter_map(|bor| {
             if bor.borrowed_amount().unwrap_or_default() > Decimal::ZERO {
                 Some(bor.borrow_reserve)
@@ -634,13 +1082,19 @@ pub async fn refresh_position_ix(
         })
         .collect();
 
-    let ix = RefreshPosition {
+    let mut ix = RefreshPosition {
         position: position_pubkey,
         deposits: deposits_reserves.clone(),
         borrows: borrows_reserves.clone(),
     }
     .into_instruction();
 
+    if position.emode_category != 0 {
+        // Position in e-mode passes its Pool to get category LTVs
+        ix.accounts
+            .push(AccountMeta::new_readonly(position.pool, false));
+    }
+
     let mut refresh_deposits = refresh_reserves_ix(context, &deposits_reserves).await;
     let refresh_borrows = refresh_reserves_ix(context, &borrows_reserves).await;
 
@@ -709,24 +1163,23 @@ pub async fn borrow(
         .into_instruction(),
     );
 
-    ixs.push(
-        Borrow {
-            position,
-            destination_liquidity_wallet,
-            curator_fee_receiver,
-            borrower: borrower.pubkey(),
-            reserve,
-            pool,
-            curator,
-            texture_fee_receiver,
-            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
-            token_program: spl_token::id(),
-            amount,
-            slippage_limit,
-            memo: [0; BORROW_MEMO_LEN],
-        }
-        .into_instruction(),
-    );
+    let borrow_ix = Borrow {
+        position,
+        destination_liquidity_wallet,
+        curator_fee_receiver,
+        borrower: borrower.pubkey(),
+        reserve,
+        pool,
+        curator,
+        texture_fee_receiver,
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+        token_program: spl_token::id(),
+        amount,
+        slippage_limit,
+        memo: [0; BORROW_MEMO_LEN],
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, borrow_ix).await);
 
     reserves.push(reserve);
     update_prices(context, &reserves).await;
@@ -741,6 +1194,29 @@ pub async fn borrow(
     context.banks_client.process_transaction(tx).await
 }
 
+/// Isolated position passes its isolated collateral Reserve as the last account of Borrow, Repay
+/// and WriteOffBadDebt.
+pub async fn with_isolated_collateral(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    mut ix: Instruction,
+) -> Instruction {
+    let position_acc = get_account(&mut context.banks_client, position)
+        .await
+        .expect("get position");
+    let unpacked_position =
+        Position::try_from_bytes(&position_acc.data).expect("cast position data");
+
+    if unpacked_position.is_isolated() {
+        ix.accounts.push(AccountMeta::new(
+            unpacked_position.isolated_collateral,
+            false,
+        ));
+    }
+
+    ix
+}
+
 pub async fn liquidity_mint_from_reserve(
     context: &mut ProgramTestContext,
     reserve: Pubkey,
@@ -757,13 +1233,309 @@ pub async fn liquidity_mint_from_reserve(
     Ok(reserve.liquidity.mint)
 }
 
-pub async fn repay(
+pub async fn repay(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    reserve: Pubkey,
+    user_authority: &Keypair,
+    source_liquidity_wallet: Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    let repay_ix = Repay {
+        position,
+        source_liquidity_wallet,
+        reserve,
+        amount,
+        user_authority: user_authority.pubkey(),
+        token_program: spl_token::id(),
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, repay_ix).await);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, user_authority],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn borrow_fixed(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    reserve: Pubkey,
+    market_price_feed: Pubkey,
+    irm: Pubkey,
+    pool: Pubkey,
+    borrower: &Keypair,
+    curator: Pubkey,
+    curator_fee_receiver: Pubkey,
+    texture_fee_receiver: Pubkey,
+    destination_liquidity_wallet: Pubkey,
+    amount: u64,
+    term_sec: u32,
+    max_rate_bps: u32,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, mut reserves) = refresh_position_ix(context, position).await;
+
+    ixs.push(
+        RefreshReserve {
+            reserve,
+            market_price_feed,
+            irm,
+        }
+        .into_instruction(),
+    );
+
+    let borrow_ix = BorrowFixed {
+        position,
+        destination_liquidity_wallet,
+        curator_fee_receiver,
+        borrower: borrower.pubkey(),
+        reserve,
+        pool,
+        curator,
+        texture_fee_receiver,
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+        token_program: spl_token::id(),
+        amount,
+        slippage_limit: 0,
+        term_sec,
+        max_rate_bps,
+        memo: [0; BORROW_MEMO_LEN],
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, borrow_ix).await);
+
+    reserves.push(reserve);
+    update_prices(context, &reserves).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, borrower],
+        blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn repay_fixed(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    reserve: Pubkey,
+    user_authority: &Keypair,
+    source_liquidity_wallet: Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    let repay_ix = RepayFixed {
+        position,
+        source_liquidity_wallet,
+        reserve,
+        amount,
+        user_authority: user_authority.pubkey(),
+        token_program: spl_token::id(),
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, repay_ix).await);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, user_authority],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn write_price(
+    context: &mut ProgramTestContext,
+    price_feed: Pubkey,
+    authority: &Keypair,
+    price: Decimal,
+    price_timestamp: i64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[WritePrice {
+            price_feed,
+            authority: authority.pubkey(),
+            price,
+            price_timestamp,
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn update_prices(context: &mut ProgramTestContext, reserves: &[Pubkey]) {
+    let authority = price_feed_authority();
+    // Follow Solana clock when it was warped ahead of the wall clock
+    let clock: Clock = context.banks_client.get_sysvar().await.expect("get clock");
+    let now = Utc::now().timestamp().max(clock.unix_timestamp);
+    for reserve in reserves {
+        let reserve_acc = get_account(&mut context.banks_client, *reserve)
+            .await
+            .expect("get Reserve");
+        let unpacked_reserve =
+            Reserve::try_from_bytes(&reserve_acc.data).expect("unpacking Reserve");
+        let price_feed_key = unpacked_reserve.config.market_price_feed;
+        let price_feed_acc = get_account(&mut context.banks_client, price_feed_key)
+            .await
+            .expect("get PriceFeed");
+        let price_feed =
+            PriceFeed::try_from_bytes(&price_feed_acc.data).expect("unpacking PriceFeed");
+        write_price(
+            context,
+            price_feed_key,
+            &authority,
+            price_feed.try_price().unwrap(),
+            now - 3, // -3 is to avoid appearing in the future
+        )
+        .await
+        .expect("update price feed")
+    }
+}
+
+pub async fn claim_curator_performance_fees(
+    context: &mut ProgramTestContext,
+    curator: Pubkey,
+    reserve: Pubkey,
+    pool: Pubkey,
+    fee_receiver: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
+
+    ixs.push(
+        ClaimCuratorPerformanceFees {
+            reserve,
+            pool,
+            curator,
+            fee_receiver,
+            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+            token_program: spl_token::id(),
+        }
+        .into_instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    update_prices(context, &[reserve]).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn claim_texture_performance_fees(
+    context: &mut ProgramTestContext,
+    reserve: Pubkey,
+    fee_receiver: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
+
+    ixs.push(
+        ClaimTexturePerformanceFees {
+            reserve,
+            fee_receiver,
+            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+            token_program: spl_token::id(),
+        }
+        .into_instruction(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    update_prices(context, &[reserve]).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn init_insurance_fund(
+    context: &mut ProgramTestContext,
+    liquidity_mint: Pubkey,
+    pool: Pubkey,
+    curator: Pubkey,
+    curator_pools_authority: &Keypair,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[InitInsuranceFund {
+            liquidity_mint,
+            pool,
+            curator_pools_authority: curator_pools_authority.pubkey(),
+            curator,
+            token_program: spl_token::id(),
+        }
+        .into_instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, curator_pools_authority],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn claim_insurance_fees(
     context: &mut ProgramTestContext,
-    position: Pubkey,
     reserve: Pubkey,
-    user_authority: &Keypair,
-    source_liquidity_wallet: Pubkey,
-    amount: u64,
+    pool: Pubkey,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
         .banks_client
@@ -771,17 +1543,14 @@ pub async fn repay(
         .await
         .expect("get latest blockhash");
 
-    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
 
     ixs.push(
-        Repay {
-            position,
-            source_liquidity_wallet,
+        ClaimInsuranceFees {
             reserve,
-            amount,
-            user_authority: user_authority.pubkey(),
-            token_program: spl_token::id(),
+            pool,
             liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+            token_program: spl_token::id(),
         }
         .into_instruction(),
     );
@@ -789,19 +1558,19 @@ pub async fn repay(
     let tx = Transaction::new_signed_with_payer(
         &ixs,
         Some(&context.payer.pubkey()),
-        &[&context.payer, user_authority],
+        &[&context.payer],
         blockhash,
     );
-    update_prices(context, &reserves).await;
+    update_prices(context, &[reserve]).await;
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn write_price(
+pub async fn claim_curator_liquidation_fees(
     context: &mut ProgramTestContext,
-    price_feed: Pubkey,
-    authority: &Keypair,
-    price: Decimal,
-    price_timestamp: i64,
+    curator: Pubkey,
+    reserve: Pubkey,
+    pool: Pubkey,
+    fee_receiver: Pubkey,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
         .banks_client
@@ -810,52 +1579,23 @@ pub async fn write_price(
         .expect("get latest blockhash");
 
     let tx = Transaction::new_signed_with_payer(
-        &[WritePrice {
-            price_feed,
-            authority: authority.pubkey(),
-            price,
-            price_timestamp,
+        &[ClaimCuratorLiquidationFees {
+            reserve,
+            pool,
+            curator,
+            fee_receiver,
         }
         .into_instruction()],
         Some(&context.payer.pubkey()),
-        &[&context.payer, authority],
+        &[&context.payer],
         blockhash,
     );
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn update_prices(context: &mut ProgramTestContext, reserves: &[Pubkey]) {
-    let authority = price_feed_authority();
-    let now = Utc::now().timestamp();
-    for reserve in reserves {
-        let reserve_acc = get_account(&mut context.banks_client, *reserve)
-            .await
-            .expect("get Reserve");
-        let unpacked_reserve =
-            Reserve::try_from_bytes(&reserve_acc.data).expect("unpacking Reserve");
-        let price_feed_key = unpacked_reserve.config.market_price_feed;
-        let price_feed_acc = get_account(&mut context.banks_client, price_feed_key)
-            .await
-            .expect("get PriceFeed");
-        let price_feed =
-            PriceFeed::try_from_bytes(&price_feed_acc.data).expect("unpacking PriceFeed");
-        write_price(
-            context,
-            price_feed_key,
-            &authority,
-            price_feed.try_price().unwrap(),
-            now - 3, // -3 is to avoid appearing in the future
-        )
-        .await
-        .expect("update price feed")
-    }
-}
-
-pub async fn claim_curator_performance_fees(
+pub async fn claim_texture_liquidation_fees(
     context: &mut ProgramTestContext,
-    curator: Pubkey,
     reserve: Pubkey,
-    pool: Pubkey,
     fee_receiver: Pubkey,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
@@ -864,34 +1604,29 @@ pub async fn claim_curator_performance_fees(
         .await
         .expect("get latest blockhash");
 
-    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
-
-    ixs.push(
-        ClaimCuratorPerformanceFees {
+    let tx = Transaction::new_signed_with_payer(
+        &[ClaimTextureLiquidationFees {
             reserve,
-            pool,
-            curator,
             fee_receiver,
-            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
-            token_program: spl_token::id(),
         }
-        .into_instruction(),
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &ixs,
+        .into_instruction()],
         Some(&context.payer.pubkey()),
         &[&context.payer],
         blockhash,
     );
-    update_prices(context, &[reserve]).await;
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn claim_texture_performance_fees(
+#[allow(clippy::too_many_arguments)]
+pub async fn liquidate(
     context: &mut ProgramTestContext,
-    reserve: Pubkey,
-    fee_receiver: Pubkey,
+    repayment_source_wallet: Pubkey,
+    destination_lp_wallet: Pubkey,
+    principal_reserve: Pubkey,
+    collateral_reserve: Pubkey,
+    position: Pubkey,
+    liquidator: &Keypair,
+    liquidity_amount: u64,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
         .banks_client
@@ -899,14 +1634,23 @@ pub async fn claim_texture_performance_fees(
         .await
         .expect("get latest blockhash");
 
-    let mut ixs = refresh_reserves_ix(context, &[reserve]).await;
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
 
     ixs.push(
-        ClaimTexturePerformanceFees {
-            reserve,
-            fee_receiver,
-            liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
-            token_program: spl_token::id(),
+        Liquidate {
+            repayment_source_wallet,
+            destination_lp_wallet,
+            principal_reserve,
+            collateral_reserve,
+            position,
+            liquidator: liquidator.pubkey(),
+            principal_reserve_liquidity_mint: liquidity_mint_from_reserve(
+                context,
+                principal_reserve,
+            )
+            .await?,
+            principal_token_program: spl_token::id(),
+            liquidity_amount,
         }
         .into_instruction(),
     );
@@ -914,18 +1658,19 @@ pub async fn claim_texture_performance_fees(
     let tx = Transaction::new_signed_with_payer(
         &ixs,
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, liquidator],
         blockhash,
     );
-    update_prices(context, &[reserve]).await;
+    update_prices(context, &reserves).await;
     context.banks_client.process_transaction(tx).await
 }
 
 #[allow(clippy::too_many_arguments)]
-pub async fn liquidate(
+pub async fn liquidate_and_redeem(
     context: &mut ProgramTestContext,
     repayment_source_wallet: Pubkey,
     destination_lp_wallet: Pubkey,
+    destination_liquidity_wallet: Pubkey,
     principal_reserve: Pubkey,
     collateral_reserve: Pubkey,
     position: Pubkey,
@@ -941,9 +1686,10 @@ pub async fn liquidate(
     let (mut ixs, reserves) = refresh_position_ix(context, position).await;
 
     ixs.push(
-        Liquidate {
+        LiquidateAndRedeem {
             repayment_source_wallet,
             destination_lp_wallet,
+            destination_liquidity_wallet,
             principal_reserve,
             collateral_reserve,
             position,
@@ -953,7 +1699,13 @@ pub async fn liquidate(
                 principal_reserve,
             )
             .await?,
+            collateral_reserve_liquidity_mint: liquidity_mint_from_reserve(
+                context,
+                collateral_reserve,
+            )
+            .await?,
             principal_token_program: spl_token::id(),
+            collateral_liquidity_token_program: spl_token::id(),
             liquidity_amount,
         }
         .into_instruction(),
@@ -969,13 +1721,11 @@ pub async fn liquidate(
     context.banks_client.process_transaction(tx).await
 }
 
-pub async fn write_off_bad_debt(
+pub async fn repay_with_collateral(
     context: &mut ProgramTestContext,
     position: Pubkey,
-    pool: Pubkey,
-    curator: Pubkey,
-    curator_pools_authority: &Keypair,
     reserve: Pubkey,
+    owner: &Keypair,
     amount: u64,
 ) -> Result<(), BanksClientError> {
     let blockhash = context
@@ -987,17 +1737,166 @@ pub async fn write_off_bad_debt(
     let (mut ixs, reserves) = refresh_position_ix(context, position).await;
 
     ixs.push(
-        WriteOffBadDebt {
-            pool,
+        RepayWithCollateral {
             position,
-            curator_pools_authority: curator_pools_authority.pubkey(),
-            curator,
             reserve,
+            owner: owner.pubkey(),
             amount,
         }
         .into_instruction(),
     );
 
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn repay_with_collateral_swap(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    collateral_reserve: Pubkey,
+    collateral_liquidity_wallet: Pubkey,
+    principal_reserve: Pubkey,
+    principal_liquidity_wallet: Pubkey,
+    owner: &Keypair,
+    swap_program: Pubkey,
+    swap_accounts: Vec<AccountMeta>,
+    lp_amount: u64,
+    min_repay_amount: u64,
+    swap_data: Vec<u8>,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    let mut ix = RepayWithCollateralSwap {
+        position,
+        collateral_reserve,
+        collateral_liquidity_wallet,
+        principal_reserve,
+        principal_liquidity_wallet,
+        owner: owner.pubkey(),
+        collateral_reserve_liquidity_mint: liquidity_mint_from_reserve(context, collateral_reserve)
+            .await?,
+        principal_reserve_liquidity_mint: liquidity_mint_from_reserve(context, principal_reserve)
+            .await?,
+        collateral_liquidity_token_program: spl_token::id(),
+        principal_token_program: spl_token::id(),
+        swap_program,
+        lp_amount,
+        min_repay_amount,
+        swap_data,
+    }
+    .into_instruction();
+    ix.accounts.extend(swap_accounts);
+    ixs.push(ix);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn swap_collateral(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    source_reserve: Pubkey,
+    source_liquidity_wallet: Pubkey,
+    target_reserve: Pubkey,
+    target_liquidity_wallet: Pubkey,
+    owner: &Keypair,
+    swap_program: Pubkey,
+    swap_accounts: Vec<AccountMeta>,
+    lp_amount: u64,
+    min_target_amount: u64,
+    swap_data: Vec<u8>,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    // Target Reserve may be not in the Position yet thus refreshed separately.
+    let mut ixs = refresh_reserves_ix(context, &[target_reserve]).await;
+    let (refresh_position_ixs, mut reserves) = refresh_position_ix(context, position).await;
+    ixs.extend(refresh_position_ixs);
+    reserves.push(target_reserve);
+
+    let mut ix = SwapCollateral {
+        position,
+        source_reserve,
+        source_liquidity_wallet,
+        target_reserve,
+        target_liquidity_wallet,
+        owner: owner.pubkey(),
+        source_reserve_liquidity_mint: liquidity_mint_from_reserve(context, source_reserve).await?,
+        target_reserve_liquidity_mint: liquidity_mint_from_reserve(context, target_reserve).await?,
+        source_liquidity_token_program: spl_token::id(),
+        target_liquidity_token_program: spl_token::id(),
+        swap_program,
+        lp_amount,
+        min_target_amount,
+        swap_data,
+    }
+    .into_instruction();
+    ix.accounts.extend(swap_accounts);
+    ixs.push(ix);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn write_off_bad_debt(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    pool: Pubkey,
+    curator: Pubkey,
+    curator_pools_authority: &Keypair,
+    reserve: Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    let write_off_ix = WriteOffBadDebt {
+        pool,
+        position,
+        curator_pools_authority: curator_pools_authority.pubkey(),
+        curator,
+        reserve,
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+        token_program: spl_token::id(),
+        amount,
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, write_off_ix).await);
+
     let tx = Transaction::new_signed_with_payer(
         &ixs,
         Some(&context.payer.pubkey()),
@@ -1008,6 +1907,40 @@ pub async fn write_off_bad_debt(
     context.banks_client.process_transaction(tx).await
 }
 
+pub async fn socialize_loss(
+    context: &mut ProgramTestContext,
+    position: Pubkey,
+    pool: Pubkey,
+    reserve: Pubkey,
+) -> Result<(), BanksClientError> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .expect("get latest blockhash");
+
+    let (mut ixs, reserves) = refresh_position_ix(context, position).await;
+
+    let socialize_loss_ix = SocializeLoss {
+        pool,
+        reserve,
+        position,
+        liquidity_mint: liquidity_mint_from_reserve(context, reserve).await?,
+        token_program: spl_token::id(),
+    }
+    .into_instruction();
+    ixs.push(with_isolated_collateral(context, position, socialize_loss_ix).await);
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    update_prices(context, &reserves).await;
+    context.banks_client.process_transaction(tx).await
+}
+
 pub async fn set_reward_rules(
     context: &mut ProgramTestContext,
     reserve: Pubkey,
@@ -1159,6 +2092,12 @@ pub async fn enable_flash_loans(
                 params,
                 mode: RESERVE_MODE_NORMAL,
                 flash_loans_enabled: 1,
+                surplus_destination: reserve.surplus_destination,
+                isolation_borrowable: reserve.isolation_borrowable,
+                isolated_debt_ceiling: reserve.isolated_debt_ceiling,
+                fixed_rate_bps: reserve.fixed_rate_bps,
+                fixed_max_term_sec: reserve.fixed_max_term_sec,
+                fixed_grace_period_sec: reserve.fixed_grace_period_sec,
             }
             .into_instruction(),
         )
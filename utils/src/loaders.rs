@@ -9,6 +9,7 @@ use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use texture_common::account::PodAccount;
 
+use super_lendy::pda::find_position;
 use super_lendy::state::curator::Curator;
 use super_lendy::state::pool::Pool;
 use super_lendy::state::position::Position;
@@ -145,9 +146,9 @@ pub async fn load_positions(rpc: &RpcClient) -> Result<HashMap<Pubkey, Position>
 
     let mut positions = HashMap::new();
     for (key, account) in &accounts {
-        match Position::try_from_bytes(&account.data) {
+        match Position::unpack(&account.data) {
             Ok(position) => {
-                positions.insert(*key, *position);
+                positions.insert(*key, *position.header);
             }
             Err(err) => {
                 return Err(Error::from(err));
@@ -157,3 +158,42 @@ pub async fn load_positions(rpc: &RpcClient) -> Result<HashMap<Pubkey, Position>
 
     Ok(positions)
 }
+
+/// Loads Positions created by CreatePositionPda for `owner` in the `pool`. Addresses are derived
+/// for every possible index thus no program accounts scan needed.
+pub async fn load_user_positions(
+    rpc: &RpcClient,
+    pool: &Pubkey,
+    owner: &Pubkey,
+) -> Result<HashMap<Pubkey, Position>> {
+    let keys: Vec<Pubkey> = (0..=u8::MAX)
+        .map(|index| find_position(pool, owner, index).0)
+        .collect();
+
+    let mut positions = HashMap::new();
+    // RPC limits getMultipleAccounts to 100 accounts per request
+    for chunk in keys.chunks(100) {
+        let accounts = rpc
+            .get_multiple_accounts_with_commitment(chunk, CommitmentConfig::confirmed())
+            .await?
+            .value;
+
+        // Not existing accounts are returned as None
+        for (key, account) in chunk
+            .iter()
+            .zip(accounts)
+            .filter_map(|(key, account)| account.map(|account| (key, account)))
+        {
+            match Position::unpack(&account.data) {
+                Ok(position) => {
+                    positions.insert(*key, *position.header);
+                }
+                Err(err) => {
+                    return Err(Error::from(err));
+                }
+            }
+        }
+    }
+
+    Ok(positions)
+}